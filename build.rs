@@ -0,0 +1,18 @@
+//! The build script's only job is to let the final application choose a
+//! link section for the character property tables, by setting the
+//! `GRAPHEME_MACHINE_LINK_SECTION` environment variable when building.
+
+fn main() {
+    println!("cargo::rerun-if-env-changed=GRAPHEME_MACHINE_LINK_SECTION");
+    println!("cargo::rustc-check-cfg=cfg(grapheme_machine_link_section)");
+    match std::env::var("GRAPHEME_MACHINE_LINK_SECTION") {
+        Ok(name) if !name.is_empty() => {
+            println!("cargo::rustc-cfg=grapheme_machine_link_section");
+            println!("cargo::rustc-env=GRAPHEME_MACHINE_LINK_SECTION={name}");
+        }
+        Ok(_) | Err(std::env::VarError::NotPresent) => {}
+        Err(std::env::VarError::NotUnicode(_)) => {
+            panic!("GRAPHEME_MACHINE_LINK_SECTION must be valid Unicode");
+        }
+    }
+}
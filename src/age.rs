@@ -0,0 +1,70 @@
+//! The Unicode **Age** property, which records the version of the Unicode
+//! Standard in which each character was first assigned.
+
+use crate::UnicodeVersion;
+
+mod table;
+
+impl UnicodeVersion {
+    /// The size in bytes of the lookup table that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`](crate::CharProperties),
+    /// this table honors the `GRAPHEME_MACHINE_LINK_SECTION` environment
+    /// variable.
+    ///
+    /// This is available only when the `age` feature is enabled.
+    pub const TABLE_SIZE: usize = table::AGE_RUNS_SIZE;
+
+    /// Returns the **Age** of the given character, or `None` if it isn't
+    /// assigned as of [`Self::CURRENT`].
+    ///
+    /// Surrogates, private-use characters and noncharacters all have an
+    /// age, because the Unicode Standard designated them in some version,
+    /// even though none of them are assigned to abstract characters.
+    ///
+    /// This is available only when the `age` feature is enabled.
+    pub const fn of_char(c: char) -> Option<Self> {
+        Self::from_table_value(table::age_lookup(c))
+    }
+
+    /// Decodes a value from the **Age** table, which is `major << 2 | minor`
+    /// or zero for an unassigned character.
+    pub(crate) const fn from_table_value(v: u8) -> Option<Self> {
+        match v {
+            0 => None,
+            v => Some(Self::new(v >> 2, v & 0b11)),
+        }
+    }
+}
+
+/// Returns the newest **Age** of any character in the given grapheme
+/// cluster, which is the earliest version of the Unicode Standard that
+/// includes all of them.
+///
+/// Returns `None` if any of the characters is unassigned as of
+/// [`UnicodeVersion::CURRENT`], in which case the cluster might use
+/// characters from a newer version than this library knows about, or if
+/// the string is empty.
+pub fn cluster_age(cluster: &str) -> Option<UnicodeVersion> {
+    let mut ret = None;
+    for c in cluster.chars() {
+        let age = UnicodeVersion::of_char(c)?;
+        ret = ret.max(Some(age));
+    }
+    ret
+}
+
+/// Returns `true` if every character in the given grapheme cluster was
+/// assigned in `version` or earlier.
+///
+/// This is intended for warning that a recipient whose platform supports
+/// only `version` might not be able to display a cluster correctly, such
+/// as an emoji introduced more recently. Clusters containing unassigned
+/// characters are never supported, because they might be from a version
+/// newer than [`UnicodeVersion::CURRENT`].
+pub fn cluster_supported_by(cluster: &str, version: UnicodeVersion) -> bool {
+    cluster_age(cluster).is_some_and(|age| age <= version)
+}
+
+#[cfg(test)]
+mod tests;
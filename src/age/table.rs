@@ -0,0 +1,340 @@
+/// Returns the encoded **Age** of the character with the given code point,
+/// in the form expected by
+/// [`UnicodeVersion::from_table_value`](super::UnicodeVersion::from_table_value).
+///
+/// [`AGE_RUNS`] is sorted by code point, so this is a binary search for the
+/// last run beginning at or before `c`.
+pub const fn age_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, AGE_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if AGE_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    AGE_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`AGE_RUNS`].
+pub const AGE_RUNS_SIZE: usize = AGE_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static AGE_RUNS: [u32; 2479] = [
+    0x00000005, 0x0001f60c, 0x0001fa05, 0x0002180c, 0x0002200e, 0x00022110, 0x0002220c, 0x00023410,
+    0x00023711, 0x00024214, 0x00025005, 0x0002a90c, 0x0002ae10, 0x0002b005, 0x0002df0c, 0x0002e005,
+    0x0002ea0c, 0x0002ef10, 0x00030005, 0x0003460c, 0x00034f0e, 0x00035010, 0x00035811, 0x00035d10,
+    0x00036005, 0x0003620c, 0x0003630e, 0x00037015, 0x00037405, 0x00037615, 0x00037800, 0x00037a05,
+    0x00037b14, 0x00037e05, 0x00037f1c, 0x00038000, 0x00038405, 0x00038b00, 0x00038c05, 0x00038d00,
+    0x00038e05, 0x0003a200, 0x0003a305, 0x0003cf15, 0x0003d005, 0x0003d70c, 0x0003d80e, 0x0003da05,
+    0x0003db0c, 0x0003dc05, 0x0003dd0c, 0x0003de05, 0x0003df0c, 0x0003e005, 0x0003e10c, 0x0003e205,
+    0x0003f40d, 0x0003f60e, 0x0003f710, 0x0003fc11, 0x0004000c, 0x00040105, 0x00040d0c, 0x00040e05,
+    0x0004500c, 0x00045105, 0x00045d0c, 0x00045e05, 0x00048715, 0x0004880c, 0x00048a0e, 0x00048c0c,
+    0x00049005, 0x0004c50e, 0x0004c705, 0x0004c90e, 0x0004cb05, 0x0004cd0e, 0x0004cf14, 0x0004d005,
+    0x0004ec0c, 0x0004ee05, 0x0004f611, 0x0004f805, 0x0004fa14, 0x0005000e, 0x00051014, 0x00051415,
+    0x00052416, 0x00052618, 0x0005281c, 0x00053000, 0x00053105, 0x00055700, 0x00055905, 0x0005602c,
+    0x00056105, 0x0005882c, 0x00058905, 0x00058a0c, 0x00058b00, 0x00058d1c, 0x00058f19, 0x00059000,
+    0x00059108, 0x0005a211, 0x0005a308, 0x0005b005, 0x0005ba14, 0x0005bb05, 0x0005c408, 0x0005c511,
+    0x0005c800, 0x0005d005, 0x0005eb00, 0x0005ef2c, 0x0005f005, 0x0005f500, 0x00060010, 0x00060419,
+    0x0006051c, 0x00060615, 0x00060b11, 0x00060c05, 0x00060d10, 0x00061615, 0x00061b05, 0x00061c1b,
+    0x00061d38, 0x00061e11, 0x00061f05, 0x00062018, 0x00062105, 0x00063b15, 0x00064005, 0x0006530c,
+    0x00065610, 0x00065911, 0x00065f18, 0x00066005, 0x00066e0e, 0x00067005, 0x0006b80c, 0x0006ba05,
+    0x0006bf0c, 0x0006c005, 0x0006cf0c, 0x0006d005, 0x0006ee10, 0x0006f005, 0x0006fa0c, 0x0006ff10,
+    0x0007000c, 0x00070e00, 0x00070f0c, 0x00072d10, 0x0007300c, 0x00074b00, 0x00074d10, 0x00075011,
+    0x00076e15, 0x0007800c, 0x0007b10e, 0x0007b200, 0x0007c014, 0x0007fb00, 0x0007fd2c, 0x00080016,
+    0x00082e00, 0x00083016, 0x00083f00, 0x00084018, 0x00085c00, 0x00085e18, 0x00085f00, 0x00086028,
+    0x00086b00, 0x00087038, 0x00088f00, 0x00089038, 0x00089200, 0x00089740, 0x00089838, 0x0008a019,
+    0x0008a11c, 0x0008a219, 0x0008ad1c, 0x0008b320, 0x0008b538, 0x0008b624, 0x0008be34, 0x0008c838,
+    0x0008d32c, 0x0008d424, 0x0008e320, 0x0008e419, 0x0008ff1c, 0x00090016, 0x00090105, 0x00090410,
+    0x00090505, 0x00093a18, 0x00093c05, 0x00094e16, 0x00094f18, 0x00095005, 0x00095516, 0x00095618,
+    0x00095805, 0x00097115, 0x00097318, 0x0009781c, 0x00097916, 0x00097b14, 0x00097d11, 0x00097e14,
+    0x0009801c, 0x00098105, 0x00098400, 0x00098505, 0x00098d00, 0x00098f05, 0x00099100, 0x00099305,
+    0x0009a900, 0x0009aa05, 0x0009b100, 0x0009b205, 0x0009b300, 0x0009b605, 0x0009ba00, 0x0009bc05,
+    0x0009bd10, 0x0009be05, 0x0009c500, 0x0009c705, 0x0009c900, 0x0009cb05, 0x0009ce11, 0x0009cf00,
+    0x0009d705, 0x0009d800, 0x0009dc05, 0x0009de00, 0x0009df05, 0x0009e400, 0x0009e605, 0x0009fb16,
+    0x0009fc28, 0x0009fe2c, 0x0009ff00, 0x000a0110, 0x000a0205, 0x000a0310, 0x000a0400, 0x000a0505,
+    0x000a0b00, 0x000a0f05, 0x000a1100, 0x000a1305, 0x000a2900, 0x000a2a05, 0x000a3100, 0x000a3205,
+    0x000a3400, 0x000a3505, 0x000a3700, 0x000a3805, 0x000a3a00, 0x000a3c05, 0x000a3d00, 0x000a3e05,
+    0x000a4300, 0x000a4705, 0x000a4900, 0x000a4b05, 0x000a4e00, 0x000a5115, 0x000a5200, 0x000a5905,
+    0x000a5d00, 0x000a5e05, 0x000a5f00, 0x000a6605, 0x000a7515, 0x000a762c, 0x000a7700, 0x000a8105,
+    0x000a8400, 0x000a8505, 0x000a8c10, 0x000a8d05, 0x000a8e00, 0x000a8f05, 0x000a9200, 0x000a9305,
+    0x000aa900, 0x000aaa05, 0x000ab100, 0x000ab205, 0x000ab400, 0x000ab505, 0x000aba00, 0x000abc05,
+    0x000ac600, 0x000ac705, 0x000aca00, 0x000acb05, 0x000ace00, 0x000ad005, 0x000ad100, 0x000ae005,
+    0x000ae110, 0x000ae400, 0x000ae605, 0x000af019, 0x000af110, 0x000af200, 0x000af920, 0x000afa28,
+    0x000b0000, 0x000b0105, 0x000b0400, 0x000b0505, 0x000b0d00, 0x000b0f05, 0x000b1100, 0x000b1305,
+    0x000b2900, 0x000b2a05, 0x000b3100, 0x000b3205, 0x000b3400, 0x000b3510, 0x000b3605, 0x000b3a00,
+    0x000b3c05, 0x000b4415, 0x000b4500, 0x000b4705, 0x000b4900, 0x000b4b05, 0x000b4e00, 0x000b5534,
+    0x000b5605, 0x000b5800, 0x000b5c05, 0x000b5e00, 0x000b5f05, 0x000b6215, 0x000b6400, 0x000b6605,
+    0x000b7110, 0x000b7218, 0x000b7800, 0x000b8205, 0x000b8400, 0x000b8505, 0x000b8b00, 0x000b8e05,
+    0x000b9100, 0x000b9205, 0x000b9600, 0x000b9905, 0x000b9b00, 0x000b9c05, 0x000b9d00, 0x000b9e05,
+    0x000ba000, 0x000ba305, 0x000ba500, 0x000ba805, 0x000bab00, 0x000bae05, 0x000bb611, 0x000bb705,
+    0x000bba00, 0x000bbe05, 0x000bc300, 0x000bc605, 0x000bc900, 0x000bca05, 0x000bce00, 0x000bd015,
+    0x000bd100, 0x000bd705, 0x000bd800, 0x000be611, 0x000be705, 0x000bf310, 0x000bfb00, 0x000c001c,
+    0x000c0105, 0x000c042c, 0x000c0505, 0x000c0d00, 0x000c0e05, 0x000c1100, 0x000c1205, 0x000c2900,
+    0x000c2a05, 0x000c341c, 0x000c3505, 0x000c3a00, 0x000c3c38, 0x000c3d15, 0x000c3e05, 0x000c4500,
+    0x000c4605, 0x000c4900, 0x000c4a05, 0x000c4e00, 0x000c5505, 0x000c5700, 0x000c5815, 0x000c5a20,
+    0x000c5b00, 0x000c5d38, 0x000c5e00, 0x000c6005, 0x000c6215, 0x000c6400, 0x000c6605, 0x000c7000,
+    0x000c7730, 0x000c7815, 0x000c8024, 0x000c811c, 0x000c8205, 0x000c842c, 0x000c8505, 0x000c8d00,
+    0x000c8e05, 0x000c9100, 0x000c9205, 0x000ca900, 0x000caa05, 0x000cb400, 0x000cb505, 0x000cba00,
+    0x000cbc10, 0x000cbe05, 0x000cc500, 0x000cc605, 0x000cc900, 0x000cca05, 0x000cce00, 0x000cd505,
+    0x000cd700, 0x000cdd38, 0x000cde05, 0x000cdf00, 0x000ce005, 0x000ce214, 0x000ce400, 0x000ce605,
+    0x000cf000, 0x000cf114, 0x000cf33c, 0x000cf400, 0x000d0028, 0x000d011c, 0x000d0205, 0x000d0434,
+    0x000d0505, 0x000d0d00, 0x000d0e05, 0x000d1100, 0x000d1205, 0x000d2918, 0x000d2a05, 0x000d3a18,
+    0x000d3b28, 0x000d3d15, 0x000d3e05, 0x000d4415, 0x000d4500, 0x000d4605, 0x000d4900, 0x000d4a05,
+    0x000d4e18, 0x000d4f24, 0x000d5000, 0x000d5424, 0x000d5705, 0x000d5824, 0x000d5f20, 0x000d6005,
+    0x000d6215, 0x000d6400, 0x000d6605, 0x000d7015, 0x000d7624, 0x000d7915, 0x000d8000, 0x000d8134,
+    0x000d820c, 0x000d8400, 0x000d850c, 0x000d9700, 0x000d9a0c, 0x000db200, 0x000db30c, 0x000dbc00,
+    0x000dbd0c, 0x000dbe00, 0x000dc00c, 0x000dc700, 0x000dca0c, 0x000dcb00, 0x000dcf0c, 0x000dd500,
+    0x000dd60c, 0x000dd700, 0x000dd80c, 0x000de000, 0x000de61c, 0x000df000, 0x000df20c, 0x000df500,
+    0x000e0105, 0x000e3b00, 0x000e3f05, 0x000e5c00, 0x000e8105, 0x000e8300, 0x000e8405, 0x000e8500,
+    0x000e8630, 0x000e8705, 0x000e8930, 0x000e8a05, 0x000e8b00, 0x000e8c30, 0x000e8d05, 0x000e8e30,
+    0x000e9405, 0x000e9830, 0x000e9905, 0x000ea030, 0x000ea105, 0x000ea400, 0x000ea505, 0x000ea600,
+    0x000ea705, 0x000ea830, 0x000eaa05, 0x000eac30, 0x000ead05, 0x000eba30, 0x000ebb05, 0x000ebe00,
+    0x000ec005, 0x000ec500, 0x000ec605, 0x000ec700, 0x000ec805, 0x000ece3c, 0x000ecf00, 0x000ed005,
+    0x000eda00, 0x000edc05, 0x000ede19, 0x000ee000, 0x000f0008, 0x000f4800, 0x000f4908, 0x000f6a0c,
+    0x000f6b15, 0x000f6d00, 0x000f7108, 0x000f8c18, 0x000f9008, 0x000f960c, 0x000f9708, 0x000f9800,
+    0x000f9908, 0x000fae0c, 0x000fb108, 0x000fb80c, 0x000fb908, 0x000fba0c, 0x000fbd00, 0x000fbe0c,
+    0x000fcd00, 0x000fce15, 0x000fcf0c, 0x000fd011, 0x000fd215, 0x000fd516, 0x000fd918, 0x000fdb00,
+    0x0010000c, 0x00102215, 0x0010230c, 0x00102815, 0x0010290c, 0x00102b15, 0x00102c0c, 0x00103315,
+    0x0010360c, 0x00103a15, 0x0010400c, 0x00105a15, 0x00109a16, 0x00109e15, 0x0010a005, 0x0010c600,
+    0x0010c719, 0x0010c800, 0x0010cd19, 0x0010ce00, 0x0010d005, 0x0010f70e, 0x0010f911, 0x0010fb05,
+    0x0010fc11, 0x0010fd19, 0x00110005, 0x00115a16, 0x00115f05, 0x0011a316, 0x0011a805, 0x0011fa16,
+    0x0012000c, 0x00120711, 0x0012080c, 0x00124711, 0x0012480c, 0x00124900, 0x00124a0c, 0x00124e00,
+    0x0012500c, 0x00125700, 0x0012580c, 0x00125900, 0x00125a0c, 0x00125e00, 0x0012600c, 0x00128711,
+    0x0012880c, 0x00128900, 0x00128a0c, 0x00128e00, 0x0012900c, 0x0012af11, 0x0012b00c, 0x0012b100,
+    0x0012b20c, 0x0012b600, 0x0012b80c, 0x0012bf00, 0x0012c00c, 0x0012c100, 0x0012c20c, 0x0012c600,
+    0x0012c80c, 0x0012cf11, 0x0012d00c, 0x0012d700, 0x0012d80c, 0x0012ef11, 0x0012f00c, 0x00130f11,
+    0x0013100c, 0x00131100, 0x0013120c, 0x00131600, 0x0013180c, 0x00131f11, 0x0013200c, 0x00134711,
+    0x0013480c, 0x00135b00, 0x00135d18, 0x00135f11, 0x0013610c, 0x00137d00, 0x00138011, 0x00139a00,
+    0x0013a00c, 0x0013f520, 0x0013f600, 0x0013f820, 0x0013fe00, 0x00140016, 0x0014010c, 0x00167716,
+    0x0016800c, 0x00169d00, 0x0016a00c, 0x0016f11c, 0x0016f900, 0x0017000e, 0x00170d38, 0x00170e0e,
+    0x00171538, 0x00171600, 0x00171f38, 0x0017200e, 0x00173700, 0x0017400e, 0x00175400, 0x0017600e,
+    0x00176d00, 0x00176e0e, 0x00177100, 0x0017720e, 0x00177400, 0x0017800c, 0x0017dd10, 0x0017de00,
+    0x0017e00c, 0x0017ea00, 0x0017f010, 0x0017fa00, 0x0018000c, 0x00180f38, 0x0018100c, 0x00181a00,
+    0x0018200c, 0x0018782c, 0x00187900, 0x0018800c, 0x0018aa15, 0x0018ab00, 0x0018b016, 0x0018f600,
+    0x00190010, 0x00191d1c, 0x00191f00, 0x00192010, 0x00192c00, 0x00193010, 0x00193c00, 0x00194010,
+    0x00194100, 0x00194410, 0x00196e00, 0x00197010, 0x00197500, 0x00198011, 0x0019aa16, 0x0019ac00,
+    0x0019b011, 0x0019ca00, 0x0019d011, 0x0019da16, 0x0019db00, 0x0019de11, 0x0019e010, 0x001a0011,
+    0x001a1c00, 0x001a1e11, 0x001a2016, 0x001a5f00, 0x001a6016, 0x001a7d00, 0x001a7f16, 0x001a8a00,
+    0x001a9016, 0x001a9a00, 0x001aa016, 0x001aae00, 0x001ab01c, 0x001abf34, 0x001ac138, 0x001acf00,
+    0x001b0014, 0x001b4c38, 0x001b4d00, 0x001b4e40, 0x001b5014, 0x001b7d38, 0x001b7f40, 0x001b8015,
+    0x001bab19, 0x001bae15, 0x001bba19, 0x001bc018, 0x001bf400, 0x001bfc18, 0x001c0015, 0x001c3800,
+    0x001c3b15, 0x001c4a00, 0x001c4d15, 0x001c8024, 0x001c8940, 0x001c8b00, 0x001c902c, 0x001cbb00,
+    0x001cbd2c, 0x001cc019, 0x001cc800, 0x001cd016, 0x001cf319, 0x001cf728, 0x001cf81c, 0x001cfa30,
+    0x001cfb00, 0x001d0010, 0x001d6c11, 0x001dc414, 0x001dcb15, 0x001de71c, 0x001df628, 0x001dfa38,
+    0x001dfb24, 0x001dfc18, 0x001dfd16, 0x001dfe14, 0x001e0005, 0x001e9b08, 0x001e9c15, 0x001ea005,
+    0x001efa15, 0x001f0005, 0x001f1600, 0x001f1805, 0x001f1e00, 0x001f2005, 0x001f4600, 0x001f4805,
+    0x001f4e00, 0x001f5005, 0x001f5800, 0x001f5905, 0x001f5a00, 0x001f5b05, 0x001f5c00, 0x001f5d05,
+    0x001f5e00, 0x001f5f05, 0x001f7e00, 0x001f8005, 0x001fb500, 0x001fb605, 0x001fc500, 0x001fc605,
+    0x001fd400, 0x001fd605, 0x001fdc00, 0x001fdd05, 0x001ff000, 0x001ff205, 0x001ff500, 0x001ff605,
+    0x001fff00, 0x00200005, 0x00202f0c, 0x00203005, 0x0020470e, 0x0020480c, 0x00204e0e, 0x00205310,
+    0x00205511, 0x0020570e, 0x00205811, 0x00205f0e, 0x00206415, 0x00206500, 0x0020661b, 0x00206a05,
+    0x0020710e, 0x00207200, 0x00207405, 0x00208f00, 0x00209011, 0x00209518, 0x00209d00, 0x0020a005,
+    0x0020ab08, 0x0020ac09, 0x0020ad0c, 0x0020b00e, 0x0020b211, 0x0020b616, 0x0020b918, 0x0020ba1a,
+    0x0020bb1c, 0x0020be20, 0x0020bf28, 0x0020c038, 0x0020c100, 0x0020d005, 0x0020e20c, 0x0020e40e,
+    0x0020eb11, 0x0020ec14, 0x0020f015, 0x0020f100, 0x00210005, 0x0021390c, 0x00213b10, 0x00213c11,
+    0x00213d0e, 0x00214c11, 0x00214d14, 0x00214f15, 0x00215016, 0x00215305, 0x0021830c, 0x00218414,
+    0x00218515, 0x00218916, 0x00218a20, 0x00218c00, 0x00219005, 0x0021eb0c, 0x0021f40e, 0x00220005,
+    0x0022f20e, 0x00230005, 0x0023010c, 0x00230205, 0x00237b0c, 0x00237c0e, 0x00237d0c, 0x00239b0e,
+    0x0023cf10, 0x0023d111, 0x0023dc14, 0x0023e816, 0x0023e918, 0x0023f41c, 0x0023fb24, 0x0023ff28,
+    0x00240005, 0x0024250c, 0x00242740, 0x00242a00, 0x00244005, 0x00244b00, 0x00246005, 0x0024eb0e,
+    0x0024ff10, 0x00250005, 0x0025960e, 0x0025a005, 0x0025f00c, 0x0025f80e, 0x00260005, 0x00261410,
+    0x0026160e, 0x00261811, 0x0026190c, 0x00261a05, 0x0026700c, 0x0026720e, 0x00267e11, 0x0026800e,
+    0x00268a10, 0x00269211, 0x00269d15, 0x00269e16, 0x0026a010, 0x0026a211, 0x0026b214, 0x0026b315,
+    0x0026bd16, 0x0026c015, 0x0026c416, 0x0026ce18, 0x0026cf16, 0x0026e218, 0x0026e316, 0x0026e418,
+    0x0026e816, 0x0027001c, 0x00270105, 0x00270518, 0x00270605, 0x00270a18, 0x00270c05, 0x00272818,
+    0x00272905, 0x00274c18, 0x00274d05, 0x00274e18, 0x00274f05, 0x00275318, 0x00275605, 0x00275716,
+    0x00275805, 0x00275f18, 0x00276105, 0x0027680e, 0x00277605, 0x00279518, 0x00279805, 0x0027b018,
+    0x0027b105, 0x0027bf18, 0x0027c011, 0x0027c714, 0x0027cb19, 0x0027cc15, 0x0027cd19, 0x0027ce18,
+    0x0027d00e, 0x0027ec15, 0x0027f00e, 0x0028000c, 0x0029000e, 0x002b0010, 0x002b0e11, 0x002b1414,
+    0x002b1b15, 0x002b2014, 0x002b2415, 0x002b4d1c, 0x002b5015, 0x002b5516, 0x002b5a1c, 0x002b7400,
+    0x002b761c, 0x002b9600, 0x002b9734, 0x002b981c, 0x002bba2c, 0x002bbd1c, 0x002bc930, 0x002bca1c,
+    0x002bd228, 0x002bd32c, 0x002bec20, 0x002bf02c, 0x002bff30, 0x002c0011, 0x002c2f38, 0x002c3011,
+    0x002c5f38, 0x002c6014, 0x002c6d15, 0x002c7016, 0x002c7115, 0x002c7414, 0x002c7815, 0x002c7e16,
+    0x002c8011, 0x002ceb16, 0x002cf219, 0x002cf400, 0x002cf911, 0x002d2600, 0x002d2719, 0x002d2800,
+    0x002d2d19, 0x002d2e00, 0x002d3011, 0x002d6619, 0x002d6800, 0x002d6f11, 0x002d7018, 0x002d7100,
+    0x002d7f18, 0x002d8011, 0x002d9700, 0x002da011, 0x002da700, 0x002da811, 0x002daf00, 0x002db011,
+    0x002db700, 0x002db811, 0x002dbf00, 0x002dc011, 0x002dc700, 0x002dc811, 0x002dcf00, 0x002dd011,
+    0x002dd700, 0x002dd811, 0x002ddf00, 0x002de015, 0x002e0011, 0x002e1815, 0x002e1c11, 0x002e1e15,
+    0x002e3116, 0x002e3219, 0x002e3c1c, 0x002e4324, 0x002e4528, 0x002e4a2c, 0x002e4f30, 0x002e5034,
+    0x002e5338, 0x002e5e00, 0x002e800c, 0x002e9a00, 0x002e9b0c, 0x002ef400, 0x002f000c, 0x002fd600,
+    0x002ff00c, 0x002ffc3d, 0x00300005, 0x0030380c, 0x00303b0e, 0x00303e0c, 0x00303f05, 0x00304000,
+    0x00304105, 0x0030950e, 0x00309700, 0x00309905, 0x00309f0e, 0x0030a105, 0x0030ff0e, 0x00310000,
+    0x00310505, 0x00312d15, 0x00312e28, 0x00312f2c, 0x00313000, 0x00313105, 0x00318f00, 0x00319005,
+    0x0031a00c, 0x0031b818, 0x0031bb34, 0x0031c011, 0x0031d015, 0x0031e440, 0x0031e600, 0x0031ef3d,
+    0x0031f00e, 0x00320005, 0x00321d10, 0x00321f00, 0x00322005, 0x00324416, 0x00325010, 0x0032510e,
+    0x00326005, 0x00327c10, 0x00327e11, 0x00327f05, 0x0032b10e, 0x0032c005, 0x0032cc10, 0x0032d005,
+    0x0032ff31, 0x00330005, 0x00337710, 0x00337b05, 0x0033de10, 0x0033e005, 0x0033ff10, 0x0034000c,
+    0x004db634, 0x004dc010, 0x004e0005, 0x009fa611, 0x009fbc15, 0x009fc416, 0x009fcc19, 0x009fcd20,
+    0x009fd628, 0x009feb2c, 0x009ff034, 0x009ffd38, 0x00a0000c, 0x00a48d00, 0x00a4900c, 0x00a4a20e,
+    0x00a4a40c, 0x00a4b40e, 0x00a4b50c, 0x00a4c10e, 0x00a4c20c, 0x00a4c50e, 0x00a4c60c, 0x00a4c700,
+    0x00a4d016, 0x00a50015, 0x00a62c00, 0x00a64015, 0x00a66018, 0x00a66215, 0x00a67419, 0x00a67c15,
+    0x00a6981c, 0x00a69e20, 0x00a69f19, 0x00a6a016, 0x00a6f800, 0x00a70011, 0x00a71714, 0x00a71b15,
+    0x00a72014, 0x00a72215, 0x00a78d18, 0x00a78f20, 0x00a79018, 0x00a79219, 0x00a7941c, 0x00a7a018,
+    0x00a7aa19, 0x00a7ab1c, 0x00a7ae24, 0x00a7af2c, 0x00a7b01c, 0x00a7b220, 0x00a7b82c, 0x00a7ba30,
+    0x00a7c038, 0x00a7c230, 0x00a7c734, 0x00a7cb40, 0x00a7ce00, 0x00a7d038, 0x00a7d200, 0x00a7d338,
+    0x00a7d400, 0x00a7d538, 0x00a7da40, 0x00a7dd00, 0x00a7f238, 0x00a7f534, 0x00a7f71c, 0x00a7f819,
+    0x00a7fa18, 0x00a7fb15, 0x00a80011, 0x00a82c34, 0x00a82d00, 0x00a83016, 0x00a83a00, 0x00a84014,
+    0x00a87800, 0x00a88015, 0x00a8c524, 0x00a8c600, 0x00a8ce15, 0x00a8da00, 0x00a8e016, 0x00a8fc20,
+    0x00a8fe2c, 0x00a90015, 0x00a95400, 0x00a95f15, 0x00a96016, 0x00a97d00, 0x00a98016, 0x00a9ce00,
+    0x00a9cf16, 0x00a9da00, 0x00a9de16, 0x00a9e01c, 0x00a9ff00, 0x00aa0015, 0x00aa3700, 0x00aa4015,
+    0x00aa4e00, 0x00aa5015, 0x00aa5a00, 0x00aa5c15, 0x00aa6016, 0x00aa7c1c, 0x00aa8016, 0x00aac300,
+    0x00aadb16, 0x00aae019, 0x00aaf700, 0x00ab0118, 0x00ab0700, 0x00ab0918, 0x00ab0f00, 0x00ab1118,
+    0x00ab1700, 0x00ab2018, 0x00ab2700, 0x00ab2818, 0x00ab2f00, 0x00ab301c, 0x00ab6020, 0x00ab641c,
+    0x00ab6630, 0x00ab6834, 0x00ab6c00, 0x00ab7020, 0x00abc016, 0x00abee00, 0x00abf016, 0x00abfa00,
+    0x00ac0008, 0x00d7a400, 0x00d7b016, 0x00d7c700, 0x00d7cb16, 0x00d7fc00, 0x00d80008, 0x00e00005,
+    0x00fa2e19, 0x00fa300e, 0x00fa6b16, 0x00fa6e00, 0x00fa7011, 0x00fada00, 0x00fb0005, 0x00fb0700,
+    0x00fb1305, 0x00fb1800, 0x00fb1d0c, 0x00fb1e05, 0x00fb3700, 0x00fb3805, 0x00fb3d00, 0x00fb3e05,
+    0x00fb3f00, 0x00fb4005, 0x00fb4200, 0x00fb4305, 0x00fb4500, 0x00fb4605, 0x00fbb218, 0x00fbc238,
+    0x00fbc300, 0x00fbd305, 0x00fd4038, 0x00fd5005, 0x00fd9000, 0x00fd9205, 0x00fdc800, 0x00fdcf38,
+    0x00fdd00d, 0x00fdf005, 0x00fdfc0e, 0x00fdfd10, 0x00fdfe38, 0x00fe000e, 0x00fe1011, 0x00fe1a00,
+    0x00fe2005, 0x00fe2415, 0x00fe271c, 0x00fe2e20, 0x00fe3005, 0x00fe450e, 0x00fe4710, 0x00fe4905,
+    0x00fe5300, 0x00fe5405, 0x00fe6700, 0x00fe6805, 0x00fe6c00, 0x00fe7005, 0x00fe730e, 0x00fe7405,
+    0x00fe7500, 0x00fe7605, 0x00fefd00, 0x00feff05, 0x00ff0000, 0x00ff0105, 0x00ff5f0e, 0x00ff6105,
+    0x00ffbf00, 0x00ffc205, 0x00ffc800, 0x00ffca05, 0x00ffd000, 0x00ffd205, 0x00ffd800, 0x00ffda05,
+    0x00ffdd00, 0x00ffe005, 0x00ffe700, 0x00ffe805, 0x00ffef00, 0x00fff90c, 0x00fffc09, 0x00fffd05,
+    0x01000010, 0x01000c00, 0x01000d10, 0x01002700, 0x01002810, 0x01003b00, 0x01003c10, 0x01003e00,
+    0x01003f10, 0x01004e00, 0x01005010, 0x01005e00, 0x01008010, 0x0100fb00, 0x01010010, 0x01010300,
+    0x01010710, 0x01013400, 0x01013710, 0x01014011, 0x01018b1c, 0x01018d24, 0x01018f00, 0x01019015,
+    0x01019c34, 0x01019d00, 0x0101a01c, 0x0101a100, 0x0101d015, 0x0101fe00, 0x01028015, 0x01029d00,
+    0x0102a015, 0x0102d100, 0x0102e01c, 0x0102fc00, 0x0103000d, 0x01031f1c, 0x0103200d, 0x01032400,
+    0x01032d28, 0x0103300d, 0x01034b00, 0x0103501c, 0x01037b00, 0x01038010, 0x01039e00, 0x01039f10,
+    0x0103a011, 0x0103c400, 0x0103c811, 0x0103d600, 0x0104000d, 0x01042610, 0x0104280d, 0x01044e10,
+    0x01049e00, 0x0104a010, 0x0104aa00, 0x0104b024, 0x0104d400, 0x0104d824, 0x0104fc00, 0x0105001c,
+    0x01052800, 0x0105301c, 0x01056400, 0x01056f1c, 0x01057038, 0x01057b00, 0x01057c38, 0x01058b00,
+    0x01058c38, 0x01059300, 0x01059438, 0x01059600, 0x01059738, 0x0105a200, 0x0105a338, 0x0105b200,
+    0x0105b338, 0x0105ba00, 0x0105bb38, 0x0105bd00, 0x0105c040, 0x0105f400, 0x0106001c, 0x01073700,
+    0x0107401c, 0x01075600, 0x0107601c, 0x01076800, 0x01078038, 0x01078600, 0x01078738, 0x0107b100,
+    0x0107b238, 0x0107bb00, 0x01080010, 0x01080600, 0x01080810, 0x01080900, 0x01080a10, 0x01083600,
+    0x01083710, 0x01083900, 0x01083c10, 0x01083d00, 0x01083f10, 0x01084016, 0x01085600, 0x01085716,
+    0x0108601c, 0x01089f00, 0x0108a71c, 0x0108b000, 0x0108e020, 0x0108f300, 0x0108f420, 0x0108f600,
+    0x0108fb20, 0x01090014, 0x01091a16, 0x01091c00, 0x01091f14, 0x01092015, 0x01093a00, 0x01093f15,
+    0x01094000, 0x01098019, 0x0109b800, 0x0109bc20, 0x0109be19, 0x0109c020, 0x0109d000, 0x0109d220,
+    0x010a0011, 0x010a0400, 0x010a0511, 0x010a0700, 0x010a0c11, 0x010a1400, 0x010a1511, 0x010a1800,
+    0x010a1911, 0x010a342c, 0x010a3600, 0x010a3811, 0x010a3b00, 0x010a3f11, 0x010a482c, 0x010a4900,
+    0x010a5011, 0x010a5900, 0x010a6016, 0x010a801c, 0x010aa000, 0x010ac01c, 0x010ae700, 0x010aeb1c,
+    0x010af700, 0x010b0016, 0x010b3600, 0x010b3916, 0x010b5600, 0x010b5816, 0x010b7300, 0x010b7816,
+    0x010b801c, 0x010b9200, 0x010b991c, 0x010b9d00, 0x010ba91c, 0x010bb000, 0x010c0016, 0x010c4900,
+    0x010c8020, 0x010cb300, 0x010cc020, 0x010cf300, 0x010cfa20, 0x010d002c, 0x010d2800, 0x010d302c,
+    0x010d3a00, 0x010d4040, 0x010d6600, 0x010d6940, 0x010d8600, 0x010d8e40, 0x010d9000, 0x010e6016,
+    0x010e7f00, 0x010e8034, 0x010eaa00, 0x010eab34, 0x010eae00, 0x010eb034, 0x010eb200, 0x010ec240,
+    0x010ec500, 0x010efc40, 0x010efd3c, 0x010f002c, 0x010f2800, 0x010f302c, 0x010f5a00, 0x010f7038,
+    0x010f8a00, 0x010fb034, 0x010fcc00, 0x010fe030, 0x010ff700, 0x01100018, 0x01104e00, 0x01105218,
+    0x01107038, 0x01107600, 0x01107f1c, 0x01108016, 0x0110c238, 0x0110c300, 0x0110cd2c, 0x0110ce00,
+    0x0110d019, 0x0110e900, 0x0110f019, 0x0110fa00, 0x01110019, 0x01113500, 0x01113619, 0x0111442c,
+    0x01114734, 0x01114800, 0x0111501c, 0x01117700, 0x01118019, 0x0111c920, 0x0111cd1c, 0x0111ce34,
+    0x0111d019, 0x0111da1c, 0x0111db20, 0x0111e000, 0x0111e11c, 0x0111f500, 0x0112001c, 0x01121200,
+    0x0112131c, 0x01123e24, 0x01123f3c, 0x01124200, 0x01128020, 0x01128700, 0x01128820, 0x01128900,
+    0x01128a20, 0x01128e00, 0x01128f20, 0x01129e00, 0x01129f20, 0x0112aa00, 0x0112b01c, 0x0112eb00,
+    0x0112f01c, 0x0112fa00, 0x01130020, 0x0113011c, 0x01130400, 0x0113051c, 0x01130d00, 0x01130f1c,
+    0x01131100, 0x0113131c, 0x01132900, 0x01132a1c, 0x01133100, 0x0113321c, 0x01133400, 0x0113351c,
+    0x01133a00, 0x01133b2c, 0x01133c1c, 0x01134500, 0x0113471c, 0x01134900, 0x01134b1c, 0x01134e00,
+    0x01135020, 0x01135100, 0x0113571c, 0x01135800, 0x01135d1c, 0x01136400, 0x0113661c, 0x01136d00,
+    0x0113701c, 0x01137500, 0x01138040, 0x01138a00, 0x01138b40, 0x01138c00, 0x01138e40, 0x01138f00,
+    0x01139040, 0x0113b600, 0x0113b740, 0x0113c100, 0x0113c240, 0x0113c300, 0x0113c540, 0x0113c600,
+    0x0113c740, 0x0113cb00, 0x0113cc40, 0x0113d600, 0x0113d740, 0x0113d900, 0x0113e140, 0x0113e300,
+    0x01140024, 0x01145a34, 0x01145b24, 0x01145c00, 0x01145d24, 0x01145e2c, 0x01145f30, 0x01146034,
+    0x01146200, 0x0114801c, 0x0114c800, 0x0114d01c, 0x0114da00, 0x0115801c, 0x0115b600, 0x0115b81c,
+    0x0115ca20, 0x0115de00, 0x0116001c, 0x01164500, 0x0116501c, 0x01165a00, 0x01166024, 0x01166d00,
+    0x01168019, 0x0116b830, 0x0116b938, 0x0116ba00, 0x0116c019, 0x0116ca00, 0x0116d040, 0x0116e400,
+    0x01170020, 0x01171a2c, 0x01171b00, 0x01171d20, 0x01172c00, 0x01173020, 0x01174038, 0x01174700,
+    0x0118002c, 0x01183c00, 0x0118a01c, 0x0118f300, 0x0118ff1c, 0x01190034, 0x01190700, 0x01190934,
+    0x01190a00, 0x01190c34, 0x01191400, 0x01191534, 0x01191700, 0x01191834, 0x01193600, 0x01193734,
+    0x01193900, 0x01193b34, 0x01194700, 0x01195034, 0x01195a00, 0x0119a030, 0x0119a800, 0x0119aa30,
+    0x0119d800, 0x0119da30, 0x0119e500, 0x011a0028, 0x011a4800, 0x011a5028, 0x011a8430, 0x011a8628,
+    0x011a9d2c, 0x011a9e28, 0x011aa300, 0x011ab038, 0x011ac01c, 0x011af900, 0x011b003c, 0x011b0a00,
+    0x011bc040, 0x011be200, 0x011bf040, 0x011bfa00, 0x011c0024, 0x011c0900, 0x011c0a24, 0x011c3700,
+    0x011c3824, 0x011c4600, 0x011c5024, 0x011c6d00, 0x011c7024, 0x011c9000, 0x011c9224, 0x011ca800,
+    0x011ca924, 0x011cb700, 0x011d0028, 0x011d0700, 0x011d0828, 0x011d0a00, 0x011d0b28, 0x011d3700,
+    0x011d3a28, 0x011d3b00, 0x011d3c28, 0x011d3e00, 0x011d3f28, 0x011d4800, 0x011d5028, 0x011d5a00,
+    0x011d602c, 0x011d6600, 0x011d672c, 0x011d6900, 0x011d6a2c, 0x011d8f00, 0x011d902c, 0x011d9200,
+    0x011d932c, 0x011d9900, 0x011da02c, 0x011daa00, 0x011ee02c, 0x011ef900, 0x011f003c, 0x011f1100,
+    0x011f123c, 0x011f3b00, 0x011f3e3c, 0x011f5a40, 0x011f5b00, 0x011fb034, 0x011fb100, 0x011fc030,
+    0x011ff200, 0x011fff30, 0x01200014, 0x01236f1c, 0x01239920, 0x01239a00, 0x01240014, 0x0124631c,
+    0x01246f00, 0x01247014, 0x0124741c, 0x01247500, 0x01248020, 0x01254400, 0x012f9038, 0x012ff300,
+    0x01300016, 0x01342f3c, 0x01343030, 0x0134393c, 0x01345600, 0x01346040, 0x0143fb00, 0x01440020,
+    0x01464700, 0x01610040, 0x01613a00, 0x01680018, 0x016a3900, 0x016a401c, 0x016a5f00, 0x016a601c,
+    0x016a6a00, 0x016a6e1c, 0x016a7038, 0x016abf00, 0x016ac038, 0x016aca00, 0x016ad01c, 0x016aee00,
+    0x016af01c, 0x016af600, 0x016b001c, 0x016b4600, 0x016b501c, 0x016b5a00, 0x016b5b1c, 0x016b6200,
+    0x016b631c, 0x016b7800, 0x016b7d1c, 0x016b9000, 0x016d4040, 0x016d7a00, 0x016e402c, 0x016e9b00,
+    0x016f0019, 0x016f4530, 0x016f4b00, 0x016f4f30, 0x016f5019, 0x016f7f30, 0x016f8800, 0x016f8f19,
+    0x016fa000, 0x016fe024, 0x016fe128, 0x016fe230, 0x016fe434, 0x016fe500, 0x016ff034, 0x016ff200,
+    0x01700024, 0x0187ed2c, 0x0187f230, 0x0187f800, 0x01880024, 0x018af334, 0x018cd600, 0x018cff40,
+    0x018d0034, 0x018d0900, 0x01aff038, 0x01aff400, 0x01aff538, 0x01affc00, 0x01affd38, 0x01afff00,
+    0x01b00018, 0x01b00228, 0x01b11f38, 0x01b12300, 0x01b1323c, 0x01b13300, 0x01b15030, 0x01b15300,
+    0x01b1553c, 0x01b15600, 0x01b16430, 0x01b16800, 0x01b17028, 0x01b2fc00, 0x01bc001c, 0x01bc6b00,
+    0x01bc701c, 0x01bc7d00, 0x01bc801c, 0x01bc8900, 0x01bc901c, 0x01bc9a00, 0x01bc9c1c, 0x01bca400,
+    0x01cc0040, 0x01ccfa00, 0x01cd0040, 0x01ceb400, 0x01cf0038, 0x01cf2e00, 0x01cf3038, 0x01cf4700,
+    0x01cf5038, 0x01cfc400, 0x01d0000d, 0x01d0f600, 0x01d1000d, 0x01d12700, 0x01d12915, 0x01d12a0d,
+    0x01d1de20, 0x01d1e938, 0x01d1eb00, 0x01d20011, 0x01d24600, 0x01d2c03c, 0x01d2d400, 0x01d2e02c,
+    0x01d2f400, 0x01d30010, 0x01d35700, 0x01d36014, 0x01d3722c, 0x01d37900, 0x01d4000d, 0x01d45500,
+    0x01d4560d, 0x01d49d00, 0x01d49e0d, 0x01d4a000, 0x01d4a20d, 0x01d4a300, 0x01d4a50d, 0x01d4a700,
+    0x01d4a90d, 0x01d4ad00, 0x01d4ae0d, 0x01d4ba00, 0x01d4bb0d, 0x01d4bc00, 0x01d4bd0d, 0x01d4c110,
+    0x01d4c20d, 0x01d4c400, 0x01d4c50d, 0x01d50600, 0x01d5070d, 0x01d50b00, 0x01d50d0d, 0x01d51500,
+    0x01d5160d, 0x01d51d00, 0x01d51e0d, 0x01d53a00, 0x01d53b0d, 0x01d53f00, 0x01d5400d, 0x01d54500,
+    0x01d5460d, 0x01d54700, 0x01d54a0d, 0x01d55100, 0x01d5520d, 0x01d6a411, 0x01d6a600, 0x01d6a80d,
+    0x01d7ca14, 0x01d7cc00, 0x01d7ce0d, 0x01d80020, 0x01da8c00, 0x01da9b20, 0x01daa000, 0x01daa120,
+    0x01dab000, 0x01df0038, 0x01df1f00, 0x01df253c, 0x01df2b00, 0x01e00024, 0x01e00700, 0x01e00824,
+    0x01e01900, 0x01e01b24, 0x01e02200, 0x01e02324, 0x01e02500, 0x01e02624, 0x01e02b00, 0x01e0303c,
+    0x01e06e00, 0x01e08f3c, 0x01e09000, 0x01e10030, 0x01e12d00, 0x01e13030, 0x01e13e00, 0x01e14030,
+    0x01e14a00, 0x01e14e30, 0x01e15000, 0x01e29038, 0x01e2af00, 0x01e2c030, 0x01e2fa00, 0x01e2ff30,
+    0x01e30000, 0x01e4d03c, 0x01e4fa00, 0x01e5d040, 0x01e5fb00, 0x01e5ff40, 0x01e60000, 0x01e7e038,
+    0x01e7e700, 0x01e7e838, 0x01e7ec00, 0x01e7ed38, 0x01e7ef00, 0x01e7f038, 0x01e7ff00, 0x01e8001c,
+    0x01e8c500, 0x01e8c71c, 0x01e8d700, 0x01e90024, 0x01e94b30, 0x01e94c00, 0x01e95024, 0x01e95a00,
+    0x01e95e24, 0x01e96000, 0x01ec712c, 0x01ecb500, 0x01ed0130, 0x01ed3e00, 0x01ee0019, 0x01ee0400,
+    0x01ee0519, 0x01ee2000, 0x01ee2119, 0x01ee2300, 0x01ee2419, 0x01ee2500, 0x01ee2719, 0x01ee2800,
+    0x01ee2919, 0x01ee3300, 0x01ee3419, 0x01ee3800, 0x01ee3919, 0x01ee3a00, 0x01ee3b19, 0x01ee3c00,
+    0x01ee4219, 0x01ee4300, 0x01ee4719, 0x01ee4800, 0x01ee4919, 0x01ee4a00, 0x01ee4b19, 0x01ee4c00,
+    0x01ee4d19, 0x01ee5000, 0x01ee5119, 0x01ee5300, 0x01ee5419, 0x01ee5500, 0x01ee5719, 0x01ee5800,
+    0x01ee5919, 0x01ee5a00, 0x01ee5b19, 0x01ee5c00, 0x01ee5d19, 0x01ee5e00, 0x01ee5f19, 0x01ee6000,
+    0x01ee6119, 0x01ee6300, 0x01ee6419, 0x01ee6500, 0x01ee6719, 0x01ee6b00, 0x01ee6c19, 0x01ee7300,
+    0x01ee7419, 0x01ee7800, 0x01ee7919, 0x01ee7d00, 0x01ee7e19, 0x01ee7f00, 0x01ee8019, 0x01ee8a00,
+    0x01ee8b19, 0x01ee9c00, 0x01eea119, 0x01eea400, 0x01eea519, 0x01eeaa00, 0x01eeab19, 0x01eebc00,
+    0x01eef019, 0x01eef200, 0x01f00015, 0x01f02c00, 0x01f03015, 0x01f09400, 0x01f0a018, 0x01f0af00,
+    0x01f0b118, 0x01f0bf1c, 0x01f0c000, 0x01f0c118, 0x01f0d000, 0x01f0d118, 0x01f0e01c, 0x01f0f600,
+    0x01f10016, 0x01f10b1c, 0x01f10d34, 0x01f11016, 0x01f12f2c, 0x01f13018, 0x01f13116, 0x01f13218,
+    0x01f13d16, 0x01f13e18, 0x01f13f16, 0x01f14018, 0x01f14216, 0x01f14318, 0x01f14616, 0x01f14718,
+    0x01f14a16, 0x01f14f18, 0x01f15716, 0x01f15818, 0x01f15f16, 0x01f16018, 0x01f16a19, 0x01f16c30,
+    0x01f16d34, 0x01f17018, 0x01f17916, 0x01f17a18, 0x01f17b16, 0x01f17d18, 0x01f17f16, 0x01f18018,
+    0x01f18a16, 0x01f18e18, 0x01f19016, 0x01f19118, 0x01f19b24, 0x01f1ad34, 0x01f1ae00, 0x01f1e618,
+    0x01f20016, 0x01f20118, 0x01f20300, 0x01f21016, 0x01f23218, 0x01f23b24, 0x01f23c00, 0x01f24016,
+    0x01f24900, 0x01f25018, 0x01f25200, 0x01f26028, 0x01f26600, 0x01f30018, 0x01f3211c, 0x01f32d20,
+    0x01f33018, 0x01f3361c, 0x01f33718, 0x01f37d1c, 0x01f37e20, 0x01f38018, 0x01f3941c, 0x01f3a018,
+    0x01f3c51c, 0x01f3c618, 0x01f3cb1c, 0x01f3cf20, 0x01f3d41c, 0x01f3e018, 0x01f3f11c, 0x01f3f820,
+    0x01f40018, 0x01f43f1c, 0x01f44018, 0x01f4411c, 0x01f44218, 0x01f4f81c, 0x01f4f918, 0x01f4fd1c,
+    0x01f4ff20, 0x01f50018, 0x01f53e1c, 0x01f54019, 0x01f5441c, 0x01f54b20, 0x01f55018, 0x01f5681c,
+    0x01f57a24, 0x01f57b1c, 0x01f5a424, 0x01f5a51c, 0x01f5fb18, 0x01f60019, 0x01f60118, 0x01f61119,
+    0x01f61218, 0x01f61519, 0x01f61618, 0x01f61719, 0x01f61818, 0x01f61919, 0x01f61a18, 0x01f61b19,
+    0x01f61c18, 0x01f61f19, 0x01f62018, 0x01f62619, 0x01f62818, 0x01f62c19, 0x01f62d18, 0x01f62e19,
+    0x01f63018, 0x01f63419, 0x01f63518, 0x01f6411c, 0x01f64320, 0x01f64518, 0x01f6501c, 0x01f68018,
+    0x01f6c61c, 0x01f6d020, 0x01f6d124, 0x01f6d328, 0x01f6d530, 0x01f6d634, 0x01f6d800, 0x01f6dc3c,
+    0x01f6dd38, 0x01f6e01c, 0x01f6ed00, 0x01f6f01c, 0x01f6f424, 0x01f6f728, 0x01f6f92c, 0x01f6fa30,
+    0x01f6fb34, 0x01f6fd00, 0x01f70018, 0x01f7743c, 0x01f77700, 0x01f77b3c, 0x01f7801c, 0x01f7d52c,
+    0x01f7d93c, 0x01f7da00, 0x01f7e030, 0x01f7ec00, 0x01f7f038, 0x01f7f100, 0x01f8001c, 0x01f80c00,
+    0x01f8101c, 0x01f84800, 0x01f8501c, 0x01f85a00, 0x01f8601c, 0x01f88800, 0x01f8901c, 0x01f8ae00,
+    0x01f8b034, 0x01f8b240, 0x01f8bc00, 0x01f8c040, 0x01f8c200, 0x01f90028, 0x01f90c34, 0x01f90d30,
+    0x01f91020, 0x01f91924, 0x01f91f28, 0x01f92024, 0x01f92828, 0x01f93024, 0x01f93128, 0x01f93324,
+    0x01f93f30, 0x01f94024, 0x01f94c28, 0x01f94d2c, 0x01f95024, 0x01f95f28, 0x01f96c2c, 0x01f97130,
+    0x01f97234, 0x01f9732c, 0x01f97734, 0x01f97938, 0x01f97a2c, 0x01f97b30, 0x01f97c2c, 0x01f98020,
+    0x01f98524, 0x01f99228, 0x01f9982c, 0x01f9a334, 0x01f9a530, 0x01f9ab34, 0x01f9ae30, 0x01f9b02c,
+    0x01f9ba30, 0x01f9c020, 0x01f9c12c, 0x01f9c330, 0x01f9cb34, 0x01f9cc38, 0x01f9cd30, 0x01f9d028,
+    0x01f9e72c, 0x01fa0030, 0x01fa5400, 0x01fa602c, 0x01fa6e00, 0x01fa7030, 0x01fa7434, 0x01fa753c,
+    0x01fa7830, 0x01fa7b38, 0x01fa7d00, 0x01fa8030, 0x01fa8334, 0x01fa873c, 0x01fa8940, 0x01fa8a00,
+    0x01fa8f40, 0x01fa9030, 0x01fa9634, 0x01faa938, 0x01faad3c, 0x01fab034, 0x01fab738, 0x01fabb3c,
+    0x01fabe40, 0x01fabf3c, 0x01fac034, 0x01fac338, 0x01fac640, 0x01fac700, 0x01face3c, 0x01fad034,
+    0x01fad738, 0x01fada3c, 0x01fadc40, 0x01fadd00, 0x01fadf40, 0x01fae038, 0x01fae83c, 0x01fae940,
+    0x01faea00, 0x01faf038, 0x01faf73c, 0x01faf900, 0x01fb0034, 0x01fb9300, 0x01fb9434, 0x01fbcb40,
+    0x01fbf034, 0x01fbfa00, 0x01fffe08, 0x0200000d, 0x02a6d734, 0x02a6de38, 0x02a6e000, 0x02a70016,
+    0x02b73538, 0x02b7393c, 0x02b73a00, 0x02b74018, 0x02b81e00, 0x02b82020, 0x02cea200, 0x02ceb028,
+    0x02ebe100, 0x02ebf03d, 0x02ee5e00, 0x02f8000d, 0x02fa1e00, 0x02fffe08, 0x03000034, 0x03134b00,
+    0x0313503c, 0x0323b000, 0x03fffe08, 0x04000000, 0x04fffe08, 0x05000000, 0x05fffe08, 0x06000000,
+    0x06fffe08, 0x07000000, 0x07fffe08, 0x08000000, 0x08fffe08, 0x09000000, 0x09fffe08, 0x0a000000,
+    0x0afffe08, 0x0b000000, 0x0bfffe08, 0x0c000000, 0x0cfffe08, 0x0d000000, 0x0dfffe08, 0x0e000000,
+    0x0e00010d, 0x0e000200, 0x0e00200d, 0x0e008000, 0x0e010010, 0x0e01f000, 0x0efffe08,
+];
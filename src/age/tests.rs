@@ -0,0 +1,49 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn char_ages() {
+    let age = |c| UnicodeVersion::of_char(c).map(|v| (v.major(), v.minor()));
+    assert_eq!(age('a'), Some((1, 1)));
+    assert_eq!(age('\u{20AC}'), Some((2, 1))); // EURO SIGN
+    assert_eq!(age('\u{1F600}'), Some((6, 1))); // GRINNING FACE
+    assert_eq!(age('\u{1F972}'), Some((13, 0))); // SMILING FACE WITH TEAR
+    assert_eq!(age('\u{1FAE8}'), Some((15, 0))); // SHAKING FACE
+    assert_eq!(age('\u{2EBF0}'), Some((15, 1))); // CJK Extension I
+    assert_eq!(age('\u{1FAE9}'), Some((16, 0))); // FACE WITH BAGS UNDER EYES
+    assert_eq!(age('\u{1FAEA}'), None);
+    assert_eq!(age('\u{0378}'), None);
+    assert_eq!(age('\u{E000}'), Some((1, 1))); // private use
+    assert_eq!(age('\u{FDD0}'), Some((3, 1))); // noncharacter
+    assert_eq!(age(char::MAX), Some((2, 0))); // noncharacter
+}
+
+#[test]
+fn clusters() {
+    // The family emoji's characters are all from 6.0, but the ZWJ
+    // sequence was only later recommended, which Age doesn't capture.
+    assert_eq!(
+        cluster_age("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}"),
+        Some(UnicodeVersion::new(6, 0))
+    );
+    assert_eq!(cluster_age("e\u{0301}"), Some(UnicodeVersion::new(1, 1)));
+    assert_eq!(
+        cluster_age("\u{1FAE9}\u{FE0F}"),
+        Some(UnicodeVersion::CURRENT)
+    );
+    assert_eq!(cluster_age("a\u{0378}"), None);
+    assert_eq!(cluster_age(""), None);
+
+    let v15 = UnicodeVersion::new(15, 0);
+    assert!(cluster_supported_by("\u{1FAE8}", v15));
+    assert!(!cluster_supported_by("\u{1FAE9}", v15));
+    assert!(!cluster_supported_by("\u{0378}", UnicodeVersion::CURRENT));
+}
+
+#[test]
+fn ordering_and_display() {
+    assert!(UnicodeVersion::new(6, 3) < UnicodeVersion::new(7, 0));
+    assert!(UnicodeVersion::new(15, 1) > UnicodeVersion::new(15, 0));
+    assert_eq!(UnicodeVersion::new(15, 1).to_string(), "15.1");
+}
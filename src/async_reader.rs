@@ -0,0 +1,95 @@
+//! An adapter for reading grapheme clusters from a tokio async reader.
+
+use std::io;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::reader::{ReaderState, ReaderStep};
+use crate::{GraphemeMachine, Segmenter};
+
+/// Reads grapheme clusters from a [`tokio::io::AsyncBufRead`] containing
+/// UTF-8 text.
+///
+/// Call [`Self::next_cluster`] repeatedly to read each cluster in turn. The
+/// returned string borrows a buffer inside the reader that's reused for
+/// every cluster, so reading doesn't allocate once the buffer has grown to
+/// fit the longest cluster.
+///
+/// The reader's internal buffer can end partway through a UTF-8 sequence or
+/// a grapheme cluster, and so this can be used over sockets, pipes, and
+/// other sources that deliver text in arbitrary pieces.
+///
+/// As with [`GraphemeReader`](crate::GraphemeReader), the reader uses a
+/// [`GraphemeMachine`] unless constructed using [`Self::with_machine`].
+///
+/// This is available only when the `tokio` feature is enabled.
+#[derive(Debug)]
+pub struct AsyncClusterReader<R, S = GraphemeMachine> {
+    inner: R,
+    state: ReaderState<S>,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncClusterReader<R> {
+    /// Wraps the given reader, using a new [`GraphemeMachine`] in its
+    /// initial "start of input" state.
+    pub fn new(inner: R) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+}
+
+impl<R: AsyncBufRead + Unpin, S: Segmenter> AsyncClusterReader<R, S> {
+    /// Wraps the given reader using an existing machine, which should be in
+    /// its "start of input" state, such as a [`Segmenter`] other than
+    /// [`GraphemeMachine`].
+    pub fn with_machine(machine: S, inner: R) -> Self {
+        Self {
+            inner,
+            state: ReaderState::new(machine),
+        }
+    }
+
+    /// Reads the next complete grapheme cluster, or returns `None` once the
+    /// reader has reached the end of its input.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] wrapping an
+    /// [`InvalidUtf8Error`](crate::InvalidUtf8Error) if the input isn't valid
+    /// UTF-8, with offsets relative to where this reader began reading.
+    ///
+    /// This is cancellation-safe: if the returned future is dropped before
+    /// it completes then no input is lost, and the next call continues
+    /// reading the same cluster.
+    pub async fn next_cluster(&mut self) -> io::Result<Option<&str>> {
+        let state = &mut self.state;
+        state.begin();
+        loop {
+            let buf = self.inner.fill_buf().await?;
+            match state.feed(buf)? {
+                ReaderStep::More(used) => self.inner.consume(used),
+                ReaderStep::Cluster(used) => {
+                    self.inner.consume(used);
+                    return Ok(Some(&state.cluster));
+                }
+                ReaderStep::End => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns the byte offset in the input where the cluster most recently
+    /// returned by [`Self::next_cluster`] begins, for reporting positions
+    /// within the input, such as for the events of a server-sent event
+    /// stream.
+    ///
+    /// Before the first cluster is returned, and after the end of the
+    /// input, this is the offset just after the last cluster returned.
+    pub fn cluster_offset(&self) -> usize {
+        self.state.cluster_offset
+    }
+
+    /// Consumes the adapter and returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests;
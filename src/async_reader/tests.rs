@@ -0,0 +1,111 @@
+use super::*;
+
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use pretty_assertions::assert_eq;
+use tokio::io::BufReader;
+
+use crate::InvalidUtf8Error;
+
+/// Runs a future that never actually waits, because its reader is in
+/// memory.
+fn now<T>(fut: impl Future<Output = T>) -> T {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    match fut.as_mut().poll(&mut cx) {
+        Poll::Ready(v) => v,
+        Poll::Pending => panic!("future is pending"),
+    }
+}
+
+fn clusters(input: &[u8], capacity: usize) -> io::Result<Vec<String>> {
+    let mut reader = AsyncClusterReader::new(BufReader::with_capacity(capacity, input));
+    let mut ret = Vec::new();
+    while let Some(cluster) = now(reader.next_cluster())? {
+        ret.push(cluster.to_string());
+    }
+    // Once the input is exhausted, it stays exhausted.
+    assert_eq!(now(reader.next_cluster())?, None);
+    Ok(ret)
+}
+
+#[test]
+fn read_clusters() {
+    let input = "a\r\ne\u{0301}🇦🇧🧑‍🌾!".as_bytes();
+    let want = &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"];
+    // A buffer capacity of one byte means that each multi-byte character
+    // arrives in pieces.
+    for capacity in [1, 2, 3, 5, 64] {
+        assert_eq!(
+            clusters(input, capacity).unwrap(),
+            want,
+            "capacity {capacity}"
+        );
+    }
+    assert_eq!(clusters(b"", 8).unwrap(), &[] as &[&str]);
+}
+
+#[test]
+fn invalid_utf8() {
+    let utf8_err = |input: &[u8], capacity| {
+        let err = clusters(input, capacity).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        *err.into_inner()
+            .unwrap()
+            .downcast::<InvalidUtf8Error>()
+            .unwrap()
+    };
+    let want = |valid_up_to, error_len| InvalidUtf8Error {
+        valid_up_to,
+        error_len,
+    };
+    for capacity in [1, 64] {
+        assert_eq!(utf8_err(b"ab\xffc", capacity), want(2, Some(1)));
+        assert_eq!(utf8_err(b"ab\xe2\x82x", capacity), want(2, Some(2)));
+        assert_eq!(utf8_err(b"ab\xe2\x82", capacity), want(2, None));
+    }
+}
+
+#[test]
+fn cluster_offsets() {
+    let input = "a\r\ne\u{0301}🇦🇧".as_bytes();
+    let mut reader = AsyncClusterReader::new(BufReader::with_capacity(1, input));
+    assert_eq!(reader.cluster_offset(), 0);
+    let mut got = Vec::new();
+    while let Some(cluster) = now(reader.next_cluster()).unwrap() {
+        let cluster = cluster.to_string();
+        got.push((reader.cluster_offset(), cluster));
+    }
+    let want = [(0, "a"), (1, "\r\n"), (3, "e\u{0301}"), (6, "🇦🇧")];
+    assert_eq!(got, want.map(|(offset, s)| (offset, s.to_string())));
+    assert_eq!(reader.cluster_offset(), input.len());
+}
+
+#[test]
+fn many_clusters_per_buffer() {
+    let input = "e\u{0301}🇦🇧x".repeat(100).into_bytes();
+    for capacity in [7, 64, 8192] {
+        let got = clusters(&input, capacity).unwrap();
+        assert_eq!(got.len(), 300, "capacity {capacity}");
+        assert_eq!(got.concat().as_bytes(), input, "capacity {capacity}");
+    }
+}
+
+#[test]
+fn with_machine() {
+    use crate::{CharProperties, GCBProperty, InCBProperty, OverlayProperties, TailoredMachine};
+
+    // A tailoring in which a hyphen extends the cluster before it.
+    static OVERRIDES: &[(core::ops::RangeInclusive<char>, CharProperties)] = &[(
+        '-'..='-',
+        CharProperties::new(GCBProperty::Extend, InCBProperty::None),
+    )];
+    let machine = TailoredMachine::new(OverlayProperties::new(OVERRIDES));
+    let inner = BufReader::with_capacity(2, "a--b-\u{0301}c".as_bytes());
+    let mut reader = AsyncClusterReader::with_machine(machine, inner);
+    let mut got = Vec::new();
+    while let Some(cluster) = now(reader.next_cluster()).unwrap() {
+        got.push(cluster.to_string());
+    }
+    assert_eq!(got, ["a--", "b-\u{0301}", "c"]);
+}
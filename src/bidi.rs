@@ -0,0 +1,160 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{StrClusters, clusters_in_str};
+
+/// One of the Unicode bidirectional formatting characters, which are the
+/// characters with the **Bidi_Control** property.
+///
+/// These characters are invisible but change the order in which the text
+/// around them is displayed. That makes them useful for mixing
+/// left-to-right and right-to-left text, but also allows "Trojan Source"
+/// attacks, where source code displays differently than it's compiled.
+///
+/// Each of these characters is a grapheme cluster by itself, since their
+/// **Grapheme_Cluster_Break** property is `Control`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiControl {
+    /// U+061C ARABIC LETTER MARK (ALM).
+    ArabicLetterMark,
+    /// U+200E LEFT-TO-RIGHT MARK (LRM).
+    LeftToRightMark,
+    /// U+200F RIGHT-TO-LEFT MARK (RLM).
+    RightToLeftMark,
+    /// U+202A LEFT-TO-RIGHT EMBEDDING (LRE).
+    LeftToRightEmbedding,
+    /// U+202B RIGHT-TO-LEFT EMBEDDING (RLE).
+    RightToLeftEmbedding,
+    /// U+202C POP DIRECTIONAL FORMATTING (PDF).
+    PopDirectionalFormatting,
+    /// U+202D LEFT-TO-RIGHT OVERRIDE (LRO).
+    LeftToRightOverride,
+    /// U+202E RIGHT-TO-LEFT OVERRIDE (RLO).
+    RightToLeftOverride,
+    /// U+2066 LEFT-TO-RIGHT ISOLATE (LRI).
+    LeftToRightIsolate,
+    /// U+2067 RIGHT-TO-LEFT ISOLATE (RLI).
+    RightToLeftIsolate,
+    /// U+2068 FIRST STRONG ISOLATE (FSI).
+    FirstStrongIsolate,
+    /// U+2069 POP DIRECTIONAL ISOLATE (PDI).
+    PopDirectionalIsolate,
+}
+
+impl BidiControl {
+    /// Returns the bidirectional formatting character that `c` is, or
+    /// `None` if it isn't one.
+    pub const fn of_char(c: char) -> Option<Self> {
+        Some(match c {
+            '\u{061C}' => Self::ArabicLetterMark,
+            '\u{200E}' => Self::LeftToRightMark,
+            '\u{200F}' => Self::RightToLeftMark,
+            '\u{202A}' => Self::LeftToRightEmbedding,
+            '\u{202B}' => Self::RightToLeftEmbedding,
+            '\u{202C}' => Self::PopDirectionalFormatting,
+            '\u{202D}' => Self::LeftToRightOverride,
+            '\u{202E}' => Self::RightToLeftOverride,
+            '\u{2066}' => Self::LeftToRightIsolate,
+            '\u{2067}' => Self::RightToLeftIsolate,
+            '\u{2068}' => Self::FirstStrongIsolate,
+            '\u{2069}' => Self::PopDirectionalIsolate,
+            _ => return None,
+        })
+    }
+
+    /// Returns the character.
+    pub const fn char(self) -> char {
+        match self {
+            Self::ArabicLetterMark => '\u{061C}',
+            Self::LeftToRightMark => '\u{200E}',
+            Self::RightToLeftMark => '\u{200F}',
+            Self::LeftToRightEmbedding => '\u{202A}',
+            Self::RightToLeftEmbedding => '\u{202B}',
+            Self::PopDirectionalFormatting => '\u{202C}',
+            Self::LeftToRightOverride => '\u{202D}',
+            Self::RightToLeftOverride => '\u{202E}',
+            Self::LeftToRightIsolate => '\u{2066}',
+            Self::RightToLeftIsolate => '\u{2067}',
+            Self::FirstStrongIsolate => '\u{2068}',
+            Self::PopDirectionalIsolate => '\u{2069}',
+        }
+    }
+
+    /// Returns the character's conventional abbreviation, such as `"RLO"`,
+    /// for use in diagnostic messages.
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::ArabicLetterMark => "ALM",
+            Self::LeftToRightMark => "LRM",
+            Self::RightToLeftMark => "RLM",
+            Self::LeftToRightEmbedding => "LRE",
+            Self::RightToLeftEmbedding => "RLE",
+            Self::PopDirectionalFormatting => "PDF",
+            Self::LeftToRightOverride => "LRO",
+            Self::RightToLeftOverride => "RLO",
+            Self::LeftToRightIsolate => "LRI",
+            Self::RightToLeftIsolate => "RLI",
+            Self::FirstStrongIsolate => "FSI",
+            Self::PopDirectionalIsolate => "PDI",
+        }
+    }
+}
+
+impl fmt::Display for BidiControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+/// Returns the first bidirectional formatting character in the given
+/// grapheme cluster, or `None` if it contains none.
+pub fn cluster_bidi_control(cluster: &str) -> Option<BidiControl> {
+    cluster.chars().find_map(BidiControl::of_char)
+}
+
+/// Returns an iterator over the grapheme clusters in `s` that contain
+/// bidirectional formatting characters, yielding the byte range of each
+/// along with the first such character in it.
+///
+/// This is for tools such as linters that want to flag only the clusters
+/// that could reorder the display of the surrounding text, without flagging
+/// legitimate right-to-left text that doesn't use these characters. To
+/// remove or replace the clusters instead, use
+/// [`SanitizePolicy::bidi_controls`](crate::SanitizePolicy::bidi_controls).
+pub fn bidi_control_clusters(s: &str) -> BidiControlClusters<'_> {
+    BidiControlClusters {
+        clusters: clusters_in_str(s),
+        offset: 0,
+    }
+}
+
+/// An iterator over the grapheme clusters in a string that contain
+/// bidirectional formatting characters.
+///
+/// Construct this using [`bidi_control_clusters`].
+#[derive(Debug)]
+pub struct BidiControlClusters<'a> {
+    clusters: StrClusters<'a>,
+    offset: usize,
+}
+
+impl<'a> Iterator for BidiControlClusters<'a> {
+    type Item = (Range<usize>, BidiControl);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for cluster in self.clusters.by_ref() {
+            let start = self.offset;
+            self.offset += cluster.len();
+            if let Some(control) = cluster_bidi_control(cluster) {
+                return Some((start..self.offset, control));
+            }
+        }
+        None
+    }
+}
+
+impl<'a> FusedIterator for BidiControlClusters<'a> {}
+
+#[cfg(test)]
+mod tests;
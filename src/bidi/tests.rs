@@ -0,0 +1,43 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn of_char_round_trips() {
+    let mut count = 0;
+    for c in '\0'..='\u{FFFF}' {
+        if let Some(control) = BidiControl::of_char(c) {
+            assert_eq!(control.char(), c);
+            count += 1;
+        }
+    }
+    assert_eq!(count, 12);
+    assert_eq!(BidiControl::of_char('a'), None);
+    assert_eq!(BidiControl::RightToLeftOverride.to_string(), "RLO");
+}
+
+#[test]
+fn clusters() {
+    // The classic "Trojan Source" example hides part of a comment using an
+    // override and an isolate, while the Hebrew text needs no controls.
+    let s = "/* \u{202E} } \u{2066}if (admin)\u{2069} \u{2066} begin */ שלום\u{200F}!";
+    let got: Vec<_> = bidi_control_clusters(s)
+        .map(|(range, control)| (&s[range], control))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            ("\u{202E}", BidiControl::RightToLeftOverride),
+            ("\u{2066}", BidiControl::LeftToRightIsolate),
+            ("\u{2069}", BidiControl::PopDirectionalIsolate),
+            ("\u{2066}", BidiControl::LeftToRightIsolate),
+            ("\u{200F}", BidiControl::RightToLeftMark),
+        ]
+    );
+    assert_eq!(bidi_control_clusters("שלום עולם").next(), None);
+    assert_eq!(cluster_bidi_control("a\u{0301}"), None);
+    assert_eq!(
+        cluster_bidi_control("\u{061C}"),
+        Some(BidiControl::ArabicLetterMark)
+    );
+}
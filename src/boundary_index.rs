@@ -0,0 +1,220 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ::u8char::AsU8Chars;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter, U8CharActions};
+
+/// The number of 64-bit words covered by each entry in the rank directory.
+const BLOCK_WORDS: usize = 8;
+const BLOCK_BITS: usize = BLOCK_WORDS * 64;
+
+/// How many boundaries apart the entries in the select directory are.
+const SELECT_SAMPLE: usize = 512;
+
+/// A precomputed index of all of the grapheme cluster boundaries in a string,
+/// supporting fast translation between byte offsets and cluster indices.
+///
+/// The boundaries are stored as a bitmap with one bit per byte of the
+/// original text, along with some small directories that allow counting the
+/// boundaries before a given offset ("rank") and finding the offset of a
+/// given boundary ("select") without scanning the whole bitmap. In total this
+/// uses a little over one bit per byte of text on 64-bit platforms, which is
+/// considerably smaller than storing each boundary offset as a separate
+/// integer.
+///
+/// [`BoundaryIndex::cluster_index`] takes constant time. The time taken by
+/// [`BoundaryIndex::cluster_offset`] depends on how evenly the boundaries are
+/// distributed, but is constant for typical text where most clusters are
+/// only a few bytes long.
+///
+/// The index does not retain the text it was built from, so callers must
+/// keep track of that separately and take care to rebuild the index whenever
+/// the text changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundaryIndex {
+    /// The bitmap, where bit `n % 64` of word `n / 64` is set if there is a
+    /// grapheme cluster boundary before byte `n`.
+    words: Vec<u64>,
+    /// The number of boundaries before each block of [`BLOCK_WORDS`] words,
+    /// with an extra final element holding the total number of boundaries.
+    ranks: Vec<usize>,
+    /// The index of the block containing every [`SELECT_SAMPLE`]th boundary.
+    samples: Vec<usize>,
+    /// The length of the indexed text in bytes.
+    len: usize,
+}
+
+/// Another name for [`BoundaryIndex`], for callers that think of it as a
+/// map between byte offsets and cluster ordinals.
+///
+/// An index is built once over a string using [`BoundaryIndex::new`], and
+/// then converts in both directions without segmenting the text again:
+///
+/// - [`BoundaryIndex::cluster_index`] converts a byte offset into the
+///   ordinal of the grapheme cluster containing it.
+/// - [`BoundaryIndex::cluster_offset`] converts a cluster ordinal into the
+///   byte offset where that cluster begins, and
+///   [`BoundaryIndex::cluster_range`] into its whole byte range.
+///
+/// Both directions take constant time for typical text, and so are
+/// suitable for cursor movement that does many conversions per edit.
+pub type ClusterIndex = BoundaryIndex;
+
+impl BoundaryIndex {
+    /// Segments the whole of `s` and builds an index of its grapheme cluster
+    /// boundaries.
+    pub fn new(s: &str) -> Self {
+        // This is equivalent to using with_segmenter, but
+        // next_u8chars_from_str can skip the full state machine for runs of
+        // printable ASCII.
+        let mut machine = GraphemeMachine::new();
+        Self::from_actions(s.len(), machine.next_u8chars_from_str(s))
+    }
+
+    /// Segments the whole of `s` using the given [`Segmenter`] and builds an
+    /// index of the boundaries it reports, so that the index can be used with
+    /// a tailored segmentation algorithm.
+    ///
+    /// The methods of the resulting index still describe each segment as a
+    /// "cluster".
+    pub fn with_segmenter(s: &str, segmenter: impl Segmenter) -> Self {
+        Self::from_actions(s.len(), U8CharActions::with_machine(segmenter, s.u8chars()))
+    }
+
+    fn from_actions(
+        len: usize,
+        actions: impl Iterator<Item = (impl SegmentAction, u8char)>,
+    ) -> Self {
+        let mut words = alloc::vec![0u64; len.div_ceil(64)];
+        let mut offset = 0;
+        for (action, c) in actions {
+            if action.is_boundary() {
+                words[offset / 64] |= 1 << (offset % 64);
+            }
+            offset += c.len();
+        }
+
+        let mut ranks = Vec::with_capacity(words.len().div_ceil(BLOCK_WORDS) + 1);
+        let mut samples = Vec::new();
+        let mut count = 0;
+        for (block, chunk) in words.chunks(BLOCK_WORDS).enumerate() {
+            ranks.push(count);
+            let block_count: usize = chunk.iter().map(|w| w.count_ones() as usize).sum();
+            // Record this block for each sampled boundary that falls inside it.
+            while samples.len() * SELECT_SAMPLE < count + block_count {
+                samples.push(block);
+            }
+            count += block_count;
+        }
+        ranks.push(count);
+
+        Self {
+            words,
+            ranks,
+            samples,
+            len,
+        }
+    }
+
+    /// Returns the length in bytes of the text that was indexed.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the indexed text was empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of grapheme clusters in the indexed text.
+    pub fn cluster_count(&self) -> usize {
+        self.ranks[self.ranks.len() - 1]
+    }
+
+    /// Returns `true` if there is a grapheme cluster boundary at the given
+    /// byte offset, which includes the end of the text.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        if offset >= self.len {
+            return offset == self.len;
+        }
+        self.words[offset / 64] & (1 << (offset % 64)) != 0
+    }
+
+    /// Returns the index of the grapheme cluster that contains the byte at
+    /// the given offset, or `None` if the offset is beyond the last byte of
+    /// the text.
+    pub fn cluster_index(&self, offset: usize) -> Option<usize> {
+        if offset >= self.len {
+            return None;
+        }
+        // The first byte of the text is always a boundary, so the rank of
+        // any offset within the text is at least one.
+        Some(self.rank(offset + 1) - 1)
+    }
+
+    /// Returns the byte offset where the grapheme cluster with the given
+    /// index begins, or `None` if there are not that many clusters.
+    pub fn cluster_offset(&self, index: usize) -> Option<usize> {
+        if index >= self.cluster_count() {
+            return None;
+        }
+        Some(self.select(index))
+    }
+
+    /// Returns the byte range of the grapheme cluster with the given index,
+    /// or `None` if there are not that many clusters.
+    pub fn cluster_range(&self, index: usize) -> Option<Range<usize>> {
+        let start = self.cluster_offset(index)?;
+        let end = self.cluster_offset(index + 1).unwrap_or(self.len);
+        Some(start..end)
+    }
+
+    /// Counts the boundaries strictly before the given offset, which must be
+    /// no greater than the length of the text.
+    fn rank(&self, offset: usize) -> usize {
+        let block = offset / BLOCK_BITS;
+        let word = offset / 64;
+        let mut count = self.ranks[block];
+        for w in &self.words[block * BLOCK_WORDS..word] {
+            count += w.count_ones() as usize;
+        }
+        let bits = offset % 64;
+        if bits != 0 {
+            count += (self.words[word] & ((1 << bits) - 1)).count_ones() as usize;
+        }
+        count
+    }
+
+    /// Finds the offset of the boundary with the given zero-based index, which
+    /// must be less than the total number of boundaries.
+    fn select(&self, index: usize) -> usize {
+        // The samples narrow the search to the blocks between two sampled
+        // boundaries, which for typical text is just one or two blocks.
+        let sample = index / SELECT_SAMPLE;
+        let lo = self.samples[sample];
+        let hi = match self.samples.get(sample + 1) {
+            Some(block) => block + 1,
+            None => self.ranks.len() - 1,
+        };
+        let block = lo + self.ranks[lo + 1..=hi].partition_point(|&r| r <= index);
+
+        let mut remain = index - self.ranks[block];
+        for (i, &w) in self.words[block * BLOCK_WORDS..].iter().enumerate() {
+            let count = w.count_ones() as usize;
+            if remain < count {
+                let mut w = w;
+                for _ in 0..remain {
+                    w &= w - 1; // clear the lowest set bit
+                }
+                return (block * BLOCK_WORDS + i) * 64 + w.trailing_zeros() as usize;
+            }
+            remain -= count;
+        }
+        unreachable!("boundary index out of range");
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+use crate::ClusterAction;
+
+use alloc::string::String;
+use pretty_assertions::assert_eq;
+
+use crate::properties::test_table::UNICODE_GRAPHEME_CLUSTER_TESTS;
+
+/// Finds the boundary offsets the slow way, for comparison.
+fn boundaries(s: &str) -> Vec<usize> {
+    let mut machine = GraphemeMachine::new();
+    let mut offset = 0;
+    let mut ret = Vec::new();
+    for (action, c) in machine.next_u8chars_from_str(s) {
+        if action == ClusterAction::Split {
+            ret.push(offset);
+        }
+        offset += c.len();
+    }
+    ret
+}
+
+fn check(s: &str) {
+    let index = BoundaryIndex::new(s);
+    let want = boundaries(s);
+    assert_eq!(index.len(), s.len());
+    assert_eq!(index.cluster_count(), want.len());
+
+    let got: Vec<_> = (0..want.len())
+        .map(|i| index.cluster_offset(i).unwrap())
+        .collect();
+    assert_eq!(got, want);
+    assert_eq!(index.cluster_offset(want.len()), None);
+
+    for offset in 0..s.len() {
+        let cluster = want.partition_point(|&b| b <= offset) - 1;
+        assert_eq!(
+            index.cluster_index(offset),
+            Some(cluster),
+            "offset {offset}"
+        );
+        assert_eq!(index.is_boundary(offset), want[cluster] == offset);
+        let range = index.cluster_range(cluster).unwrap();
+        assert!(range.contains(&offset));
+    }
+    assert_eq!(index.cluster_index(s.len()), None);
+    assert!(index.is_boundary(s.len()));
+    assert!(!index.is_boundary(s.len() + 1));
+}
+
+#[test]
+fn empty() {
+    let index = BoundaryIndex::new("");
+    assert!(index.is_empty());
+    assert_eq!(index.cluster_count(), 0);
+    assert_eq!(index.cluster_index(0), None);
+    assert_eq!(index.cluster_offset(0), None);
+    assert!(index.is_boundary(0));
+}
+
+#[test]
+fn unicode_tests() {
+    let mut all = String::new();
+    for test in UNICODE_GRAPHEME_CLUSTER_TESTS {
+        let input = str::from_utf8(test.input).expect("invalid UTF-8 in test input");
+        check(input);
+        all.push_str(input);
+    }
+    // The concatenation is long enough to span many rank blocks and
+    // select samples.
+    check(&all);
+}
+
+#[test]
+fn sparse_and_dense() {
+    // Long clusters make the boundaries sparse relative to the blocks, while
+    // ASCII makes them dense.
+    let mut s = String::new();
+    for i in 0..600 {
+        s.push('a');
+        if i % 10 == 0 {
+            for _ in 0..400 {
+                s.push('\u{0301}');
+            }
+        }
+        for _ in 0..(i % 5) {
+            s.push('b');
+        }
+    }
+    check(&s);
+}
+
+#[test]
+fn cluster_index_alias() {
+    let s = "a\u{0301}b\r\n\u{1F1E6}\u{1F1E8}";
+    let index = ClusterIndex::new(s);
+    assert_eq!(index, BoundaryIndex::new(s));
+    assert_eq!(index.cluster_count(), 4);
+    for ordinal in 0..index.cluster_count() {
+        let offset = index.cluster_offset(ordinal).unwrap();
+        assert_eq!(index.cluster_index(offset), Some(ordinal));
+        let range = index.cluster_range(ordinal).unwrap();
+        assert_eq!(range.start, offset);
+        assert_eq!(index.cluster_index(range.end - 1), Some(ordinal));
+    }
+    assert_eq!(index.cluster_index(1), Some(0));
+    assert_eq!(index.cluster_index(5), Some(2));
+    assert_eq!(index.cluster_offset(3), Some(6));
+}
@@ -0,0 +1,216 @@
+use core::iter::FusedIterator;
+
+use bytes::{Buf, Bytes};
+use u8char::u8char;
+
+use crate::{GraphemeMachine, InvalidUtf8Error, Segmenter, cluster_at, clusters_in_str};
+
+/// An iterator that takes UTF-8 characters from a [`bytes::Buf`] and feeds
+/// them into a [`Segmenter`], yielding each action along with the character
+/// that caused it.
+///
+/// The buffer is advanced past each character as the iterator returns it,
+/// and so bytes that the iterator hasn't returned yet remain in the buffer.
+/// Characters whose encodings straddle the buffer's internal chunks are
+/// handled transparently.
+///
+/// If the buffer ends partway through a UTF-8 sequence then the iterator
+/// ends without consuming that sequence, so that the caller can append more
+/// bytes and then continue with a new iterator. The buffer has reached the
+/// end of the valid text when [`Buf::has_remaining`] returns `false` after
+/// the iterator ends.
+///
+/// If the iterator finds an invalid UTF-8 sequence then it returns an error
+/// and ends. The error's offsets are relative to the buffer's position when
+/// the iterator was created. An invalid sequence that lies within a single
+/// chunk is not consumed, but one that straddles chunks might have been
+/// partially consumed by the time its problem was detected.
+///
+/// This is available only when the `bytes` feature is enabled.
+#[derive(Debug)]
+pub struct BufChars<'a, B, S = GraphemeMachine> {
+    machine: &'a mut S,
+    buf: &'a mut B,
+    offset: usize,
+    done: bool,
+}
+
+impl<'a, B: Buf, S: Segmenter> BufChars<'a, B, S> {
+    /// Constructs an iterator that feeds characters from `buf` into the
+    /// given machine.
+    ///
+    /// [`GraphemeMachine::next_u8chars_from_buf`] is a shorthand for this
+    /// when using a [`GraphemeMachine`].
+    pub fn new(machine: &'a mut S, buf: &'a mut B) -> Self {
+        Self {
+            machine,
+            buf,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// Returns the number of bytes consumed from the buffer so far.
+    pub fn consumed(&self) -> usize {
+        self.offset
+    }
+
+    /// Takes the next character from the buffer, or returns `Ok(None)` if
+    /// the buffer is empty or has only an incomplete sequence.
+    fn take_u8char(&mut self) -> Result<Option<u8char>, InvalidUtf8Error> {
+        let chunk = self.buf.chunk();
+        let Some(&first) = chunk.first() else {
+            return Ok(None);
+        };
+        let want = match first {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        let local = &chunk[..chunk.len().min(want)];
+        if let Err(err) = core::str::from_utf8(local)
+            && err.error_len().is_some()
+        {
+            return Err(self.error(err.error_len()));
+        }
+        if self.buf.remaining() < want {
+            return Ok(None);
+        }
+        let mut bytes = [0; 4];
+        self.buf.copy_to_slice(&mut bytes[..want]);
+        match core::str::from_utf8(&bytes[..want]) {
+            Ok(s) => {
+                self.offset += want;
+                // from_utf8 succeeded, so there's exactly one character.
+                Ok(u8char::from_string_prefix(s).0)
+            }
+            Err(err) => Err(self.error(err.error_len())),
+        }
+    }
+
+    fn error(&self, error_len: Option<usize>) -> InvalidUtf8Error {
+        InvalidUtf8Error {
+            valid_up_to: self.offset,
+            error_len,
+        }
+    }
+}
+
+impl<'a, B: Buf, S: Segmenter> Iterator for BufChars<'a, B, S> {
+    type Item = Result<(S::Action, u8char), InvalidUtf8Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.take_u8char() {
+            Ok(Some(c)) => Some(Ok((self.machine.next_u8char(c), c))),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // An error or a trailing incomplete sequence could end the iterator
+        // at any point, but each character takes at least one byte.
+        match self.done {
+            true => (0, Some(0)),
+            false => (0, Some(self.buf.remaining())),
+        }
+    }
+}
+
+impl<'a, B: Buf, S: Segmenter> FusedIterator for BufChars<'a, B, S> {}
+
+/// An iterator over the grapheme clusters in some UTF-8 text held in
+/// [`Bytes`], each as a slice of the same shared buffer.
+///
+/// Taking each cluster doesn't copy it, and so this suits programs built
+/// around the `bytes` ecosystem, such as proxies, that would otherwise need
+/// to convert through `&str` and then copy each cluster back into a new
+/// buffer. The text is checked to be valid UTF-8 only once, when the
+/// iterator is constructed.
+///
+/// The iterator is double-ended, in the same way as
+/// [`StrClusters`](crate::StrClusters).
+///
+/// This is available only when the `bytes` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct BytesClusters {
+    // The clusters not yet returned from either end, which are always valid
+    // UTF-8.
+    remain: Bytes,
+}
+
+impl BytesClusters {
+    /// Constructs an iterator over the clusters in `bytes`, or returns an
+    /// error if `bytes` isn't entirely valid UTF-8.
+    pub fn new(bytes: Bytes) -> Result<Self, InvalidUtf8Error> {
+        core::str::from_utf8(&bytes)?;
+        Ok(Self { remain: bytes })
+    }
+
+    /// Takes all of the remaining bytes from `buf` and then behaves as
+    /// [`Self::new`].
+    ///
+    /// This doesn't copy the bytes if `buf` is [`Bytes`] or
+    /// [`BytesMut`](bytes::BytesMut), but a buffer made of several chunks,
+    /// such as one produced by [`Buf::chain`], is copied into contiguous
+    /// storage first. The whole buffer is consumed even if it isn't valid
+    /// UTF-8.
+    pub fn from_buf<B: Buf>(mut buf: B) -> Result<Self, InvalidUtf8Error> {
+        Self::new(buf.copy_to_bytes(buf.remaining()))
+    }
+
+    /// Returns the bytes of the clusters that haven't yet been returned from
+    /// either end.
+    pub fn remaining(&self) -> &Bytes {
+        &self.remain
+    }
+
+    /// Consumes the iterator and returns the bytes of the clusters that
+    /// haven't yet been returned from either end.
+    pub fn into_inner(self) -> Bytes {
+        self.remain
+    }
+
+    fn remain_str(&self) -> &str {
+        // Safety: the constructor checked that the bytes are valid UTF-8,
+        // and the iterator only ever splits them at cluster boundaries,
+        // which are also character boundaries.
+        unsafe { core::str::from_utf8_unchecked(&self.remain) }
+    }
+}
+
+impl Iterator for BytesClusters {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Bytes> {
+        let len = clusters_in_str(self.remain_str()).next()?.len();
+        Some(self.remain.split_to(len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        clusters_in_str(self.remain_str()).size_hint()
+    }
+}
+
+impl DoubleEndedIterator for BytesClusters {
+    fn next_back(&mut self) -> Option<Bytes> {
+        let s = self.remain_str();
+        let (range, _) = cluster_at(s, s.len().checked_sub(1)?)?;
+        Some(self.remain.split_off(range.start))
+    }
+}
+
+impl FusedIterator for BytesClusters {}
+
+#[cfg(test)]
+mod tests;
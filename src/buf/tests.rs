@@ -0,0 +1,96 @@
+use super::*;
+use crate::{ClusterAction, OffsetGraphemeMachine};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn chained_chunks() {
+    use ClusterAction::*;
+
+    // The flag's first character straddles the two chunks.
+    let bytes = "e\u{0301}🇦🇧".as_bytes();
+    let mut buf = (&bytes[..5]).chain(&bytes[5..]);
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine
+        .next_u8chars_from_buf(&mut buf)
+        .map(|r| r.map(|(a, c)| (a, c.to_char())))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            Ok((Split, 'e')),
+            Ok((Continue, '\u{0301}')),
+            Ok((Split, '\u{1F1E6}')),
+            Ok((Continue, '\u{1F1E7}')),
+        ]
+    );
+    assert!(!buf.has_remaining());
+}
+
+#[test]
+fn partial_sequence() {
+    let bytes = "ab€".as_bytes();
+    let mut buf = &bytes[..4];
+    let mut machine = OffsetGraphemeMachine::new();
+    let mut iter = BufChars::new(&mut machine, &mut buf);
+    let got: Vec<_> = iter.by_ref().map(|r| r.unwrap().0.offset).collect();
+    assert_eq!(got, &[0, 1]);
+    assert_eq!(iter.consumed(), 2);
+    assert!(iter.next().is_none());
+
+    // The incomplete sequence is still in the buffer, ready to be completed.
+    assert_eq!(buf, &bytes[2..4]);
+    let mut rest = buf.chain(&bytes[4..]);
+    let got: Vec<_> = BufChars::new(&mut machine, &mut rest)
+        .map(|r| r.unwrap().0.offset)
+        .collect();
+    assert_eq!(got, &[2]);
+    assert_eq!(machine.offset(), 5);
+}
+
+#[test]
+fn invalid() {
+    let mut buf = &b"ab\xffc"[..];
+    let mut machine = GraphemeMachine::new();
+    let mut iter = machine.next_u8chars_from_buf(&mut buf);
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    let err = iter.next().unwrap().unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+    assert!(iter.next().is_none());
+    assert_eq!(buf, b"\xffc");
+
+    // A sequence that straddles chunks is checked once it's complete.
+    let mut buf = (&b"a\xe2"[..]).chain(&b"\x82x"[..]);
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine.next_u8chars_from_buf(&mut buf).collect();
+    assert_eq!(got.len(), 2);
+    let err = got[1].unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (1, Some(2)));
+}
+
+#[test]
+fn bytes_clusters() {
+    let bytes = Bytes::from_static("e\u{0301}🇦🇧x\r\n".as_bytes());
+    let mut iter = BytesClusters::new(bytes.clone()).unwrap();
+    assert_eq!(iter.next().as_deref(), Some("e\u{0301}".as_bytes()));
+    assert_eq!(iter.next_back().as_deref(), Some(&b"\r\n"[..]));
+    assert_eq!(iter.remaining(), "🇦🇧x".as_bytes());
+    let rest: Vec<_> = iter.collect();
+    assert_eq!(rest, ["🇦🇧".as_bytes(), b"x"]);
+
+    // Each cluster shares the original buffer.
+    let first = BytesClusters::new(bytes.clone()).unwrap().next().unwrap();
+    assert_eq!(first.as_ptr(), bytes.as_ptr());
+}
+
+#[test]
+fn bytes_clusters_from_buf() {
+    let bytes = "a\u{1F1E6}\u{1F1E7}".as_bytes();
+    let buf = (&bytes[..3]).chain(&bytes[3..]);
+    let got: Vec<_> = BytesClusters::from_buf(buf).unwrap().collect();
+    assert_eq!(got, [&b"a"[..], "\u{1F1E6}\u{1F1E7}".as_bytes()]);
+
+    let err = BytesClusters::from_buf(&b"ab\xffc"[..]).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+}
@@ -0,0 +1,165 @@
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// A [`GraphemeMachine`] (or any other [`Segmenter`]) combined with a
+/// fixed-capacity buffer of `N` bytes, which accepts characters one at a
+/// time and returns each grapheme cluster as a `&str` once it's complete.
+///
+/// This is for callers that don't need to manage their own buffer for the
+/// cluster in progress. It doesn't allocate, and so it's available without
+/// the `alloc` feature.
+///
+/// A cluster is only known to be complete once the first character of the
+/// next cluster arrives, or at the end of input, and so the methods that
+/// accept a character return the cluster that the character _ended_, if
+/// any. The returned string borrows the buffer, and so it must be dropped
+/// before passing the next character.
+///
+/// If a cluster is longer than `N` bytes then it's returned as a single
+/// U+FFFD REPLACEMENT CHARACTER instead. This protects against unbounded
+/// memory use when the input is malicious or corrupt, while still finding
+/// the correct end of the overlong cluster.
+#[derive(Debug)]
+pub struct BufferedGraphemeMachine<const N: usize, S = GraphemeMachine> {
+    machine: S,
+    buf: [u8; N],
+    len: usize,
+    overflowed: bool,
+    // Set when the buffer holds a cluster that was already returned, and
+    // so must be discarded before accepting any more characters.
+    returned: bool,
+    // The first character of the next cluster, which arrived while the
+    // buffer was still holding the cluster it ended.
+    pending: Option<u8char>,
+}
+
+impl<const N: usize> BufferedGraphemeMachine<N> {
+    /// Constructs a new buffered machine in an initial "start of input"
+    /// state, with an empty buffer.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl<const N: usize> Default for BufferedGraphemeMachine<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, S: Segmenter> BufferedGraphemeMachine<N, S> {
+    /// Constructs a new buffered machine with an empty buffer that uses the
+    /// given machine to find the cluster boundaries.
+    ///
+    /// The given machine should be in its "start of input" state, because
+    /// otherwise the first cluster returned might be only the end of a
+    /// cluster whose beginning was given to the machine some other way.
+    pub fn with_machine(machine: S) -> Self {
+        Self {
+            machine,
+            buf: [0; N],
+            len: 0,
+            overflowed: false,
+            returned: false,
+            pending: None,
+        }
+    }
+
+    /// Accepts the next character of the input, returning the cluster that
+    /// it ended if it begins a new one.
+    pub fn next_u8char(&mut self, c: u8char) -> Option<&str> {
+        self.begin_next();
+        let action = self.machine.next_u8char(c);
+        if action.is_boundary() && !self.is_empty() {
+            self.pending = Some(c);
+            return Some(self.finish_cluster());
+        }
+        self.append(c);
+        None
+    }
+
+    /// Accepts the next character of the input, returning the cluster that
+    /// it ended if it begins a new one.
+    ///
+    /// This converts the character to [`u8char`] first, so prefer
+    /// [`Self::next_u8char`] if you already have that representation.
+    pub fn next_char(&mut self, c: char) -> Option<&str> {
+        self.next_u8char(u8char::from_char(c))
+    }
+
+    /// Signals the end of the input, returning the final cluster if any
+    /// characters have arrived since the previous cluster was returned.
+    ///
+    /// The machine is then back in its "start of input" state, ready to
+    /// begin a new stream.
+    pub fn end_of_input(&mut self) -> Option<&str> {
+        self.begin_next();
+        self.machine.end_of_input();
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.finish_cluster())
+    }
+
+    /// Returns `true` if the cluster most recently returned was longer than
+    /// `N` bytes, and so was replaced by U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// This distinguishes a replaced cluster from one that was literally
+    /// U+FFFD in the input.
+    pub fn overflowed(&self) -> bool {
+        self.returned && self.overflowed
+    }
+
+    /// Consumes the buffered machine and returns the machine it was using.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0 && !self.overflowed
+    }
+
+    /// Discards a cluster that was already returned, and then adds any
+    /// pending character to the buffer to begin the next cluster.
+    fn begin_next(&mut self) {
+        if self.returned {
+            self.len = 0;
+            self.overflowed = false;
+            self.returned = false;
+        }
+        if let Some(c) = self.pending.take() {
+            self.append(c);
+        }
+    }
+
+    fn append(&mut self, c: u8char) {
+        let bytes = c.as_bytes();
+        match self.buf.get_mut(self.len..self.len + bytes.len()) {
+            Some(dst) if !self.overflowed => {
+                dst.copy_from_slice(bytes);
+                self.len += bytes.len();
+            }
+            _ => self.overflowed = true,
+        }
+    }
+
+    fn finish_cluster(&mut self) -> &str {
+        self.returned = true;
+        self.cluster()
+    }
+
+    /// Returns the cluster in the buffer, as most recently returned if the
+    /// buffer isn't yet holding the next one.
+    pub(crate) fn cluster(&self) -> &str {
+        if self.overflowed {
+            return "\u{FFFD}";
+        }
+        // Safety: the buffer is only ever extended by whole characters, all
+        // of which are valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn clusters<const N: usize>(input: &str) -> Vec<String> {
+    let mut machine = BufferedGraphemeMachine::<N>::new();
+    let mut got = Vec::new();
+    for c in input.chars() {
+        if let Some(cluster) = machine.next_char(c) {
+            got.push(cluster.to_string());
+        }
+    }
+    if let Some(cluster) = machine.end_of_input() {
+        got.push(cluster.to_string());
+    }
+    got
+}
+
+#[test]
+fn complete_clusters() {
+    assert_eq!(
+        clusters::<16>("a\r\ne\u{0301}🇦🇧🧑‍🌾!"),
+        &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"],
+    );
+    assert_eq!(clusters::<16>(""), &[] as &[&str]);
+}
+
+#[test]
+fn overflow() {
+    // The farmer emoji is eleven bytes, and so doesn't fit. The flag is
+    // exactly eight, and so does.
+    assert_eq!(clusters::<8>("a🧑‍🌾b🇦🇧c"), &["a", "\u{FFFD}", "b", "🇦🇧", "c"],);
+    // A single character can be too long for a very small buffer.
+    assert_eq!(clusters::<1>("aé"), &["a", "\u{FFFD}"]);
+
+    // A replaced cluster is distinguishable from a literal U+FFFD.
+    let mut machine = BufferedGraphemeMachine::<3>::new();
+    machine.next_char('\u{FFFD}');
+    assert_eq!(machine.next_char('e'), Some("\u{FFFD}"));
+    assert!(!machine.overflowed());
+    machine.next_char('\u{0301}');
+    machine.next_char('\u{0301}');
+    assert_eq!(machine.next_char('x'), Some("\u{FFFD}"));
+    assert!(machine.overflowed());
+}
+
+#[test]
+fn end_of_input_resets() {
+    let mut machine = BufferedGraphemeMachine::<8>::new();
+    assert_eq!(machine.next_char('e'), None);
+    assert_eq!(machine.end_of_input(), Some("e"));
+    assert_eq!(machine.end_of_input(), None);
+    // The combining mark now begins a new cluster of its own.
+    assert_eq!(machine.next_char('\u{0301}'), None);
+    assert_eq!(machine.next_char('x'), Some("\u{0301}"));
+    assert_eq!(machine.end_of_input(), Some("x"));
+}
@@ -0,0 +1,178 @@
+use u8char::u8char;
+
+use crate::state::State;
+use crate::{CharProperties, ClusterAction, GraphemeMachine, Segmenter};
+
+/// The bits of a raw [`CharProperties`] value that the segmentation rules
+/// depend on.
+const SEGMENTATION_BITS: u16 = 0x003f;
+
+/// A [`GraphemeMachine`] combined with a small direct-mapped cache of its
+/// recent decisions, which can make segmenting highly repetitive text
+/// faster.
+///
+/// Each decision depends only on the machine's internal state and the
+/// segmentation properties of the previous and next characters, and so a
+/// workload like redrawing a terminal or deduplicating log lines sees the
+/// same few combinations over and over. The cache has `N` entries, each
+/// remembering one combination, which are filled lazily as combinations
+/// are encountered. Whether that's faster than just following the rules
+/// depends on the text and the CPU, so use [`Self::stats`] to measure how
+/// often the cache is actually helping.
+///
+/// This produces exactly the same results as a plain [`GraphemeMachine`].
+/// `N` should be a power of two, so that finding an entry is cheap.
+#[derive(Debug)]
+pub struct CachedGraphemeMachine<const N: usize = 64> {
+    machine: GraphemeMachine,
+    entries: [Option<CacheEntry>; N],
+    stats: CacheStats,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CacheEntry {
+    key: u16,
+    boundary: bool,
+    next_state: State,
+}
+
+/// Counts how often a [`CachedGraphemeMachine`] found its decisions in its
+/// cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// The number of decisions that were found in the cache.
+    pub hits: u64,
+    /// The number of decisions that had to be made by following the
+    /// segmentation rules, not counting the first character after the
+    /// start of input, which never uses the cache.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Returns the proportion of decisions that were found in the cache, as
+    /// a number between zero and one, or `None` if there haven't been any
+    /// decisions yet.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits.saturating_add(self.misses);
+        (total != 0).then(|| self.hits as f64 / total as f64)
+    }
+}
+
+impl<const N: usize> CachedGraphemeMachine<N> {
+    /// Constructs a new machine in an initial "start of input" state, with
+    /// an empty cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn new() -> Self {
+        assert!(N > 0, "cache must have at least one entry");
+        Self {
+            machine: GraphemeMachine::new(),
+            entries: [None; N],
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Advances the state machine for a character with the given properties,
+    /// in the same way as [`GraphemeMachine::next_char_properties`].
+    pub fn next_char_properties(&mut self, next: CharProperties) -> ClusterAction {
+        let machine = &mut self.machine;
+        if machine.prev.is_no_char() {
+            return machine.next_char_properties(next);
+        }
+        #[cfg(any(debug_assertions, feature = "strict"))]
+        machine.check_contract();
+
+        let key = (machine.state as u16) << 12
+            | (machine.prev.to_raw() & SEGMENTATION_BITS) << 6
+            | (next.to_raw() & SEGMENTATION_BITS);
+        let slot = &mut self.entries[Self::slot(key)];
+        let (boundary, next_state) = match *slot {
+            Some(entry) if entry.key == key => {
+                self.stats.hits = self.stats.hits.saturating_add(1);
+                (entry.boundary, entry.next_state)
+            }
+            _ => {
+                self.stats.misses = self.stats.misses.saturating_add(1);
+                let (boundary, next_state) = machine.state.transition(machine.prev, next);
+                *slot = Some(CacheEntry {
+                    key,
+                    boundary,
+                    next_state,
+                });
+                (boundary, next_state)
+            }
+        };
+        machine.state = next_state;
+        machine.prev = next;
+        match boundary {
+            true => ClusterAction::Split,
+            false => ClusterAction::Continue,
+        }
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// advances the state machine by passing it to
+    /// [`Self::next_char_properties`].
+    pub fn next_u8char(&mut self, c: u8char) -> ClusterAction {
+        self.next_char_properties(CharProperties::for_u8char(c))
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// advances the state machine by passing it to
+    /// [`Self::next_char_properties`].
+    pub fn next_char(&mut self, c: char) -> ClusterAction {
+        self.next_char_properties(CharProperties::for_char(c))
+    }
+
+    /// Tells the state machine that the input stream has ended, in the same
+    /// way as [`GraphemeMachine::end_of_input`]. The cache is kept, since its
+    /// decisions are still valid for the next stream.
+    pub fn end_of_input(&mut self) -> ClusterAction {
+        self.machine.end_of_input()
+    }
+
+    /// Returns the cache statistics collected since the machine was
+    /// constructed or since the most recent call to [`Self::reset_stats`].
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Resets the cache statistics to zero, without clearing the cache.
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+
+    fn slot(key: u16) -> usize {
+        // Multiplicative hashing spreads the keys, which otherwise differ
+        // mostly in their low bits, across the whole table.
+        let hash = u32::from(key).wrapping_mul(0x9e37_79b1) >> 16;
+        hash as usize % N
+    }
+}
+
+impl<const N: usize> Default for CachedGraphemeMachine<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Segmenter for CachedGraphemeMachine<N> {
+    type Action = ClusterAction;
+
+    fn next_u8char(&mut self, c: u8char) -> ClusterAction {
+        CachedGraphemeMachine::next_u8char(self, c)
+    }
+
+    fn next_char(&mut self, c: char) -> ClusterAction {
+        CachedGraphemeMachine::next_char(self, c)
+    }
+
+    fn end_of_input(&mut self) -> ClusterAction {
+        CachedGraphemeMachine::end_of_input(self)
+    }
+}
+
+#[cfg(test)]
+mod tests;
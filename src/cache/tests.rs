@@ -0,0 +1,33 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn same_as_uncached() {
+    // Using a tiny cache makes collisions between entries likely.
+    let mut cached = CachedGraphemeMachine::<4>::new();
+    for test in crate::properties::test_table::UNICODE_GRAPHEME_CLUSTER_TESTS {
+        let input = core::str::from_utf8(test.input).unwrap();
+        let mut machine = GraphemeMachine::new();
+        for c in input.chars() {
+            assert_eq!(cached.next_char(c), machine.next_char(c), "{}", test.desc);
+        }
+        cached.end_of_input();
+    }
+    assert!(cached.stats().hits > 0);
+}
+
+#[test]
+fn stats() {
+    let mut machine = CachedGraphemeMachine::<64>::default();
+    assert_eq!(machine.stats().hit_rate(), None);
+    for c in "ae\u{0301}e\u{0301}e\u{0301}".chars() {
+        machine.next_char(c);
+    }
+    // The first character doesn't use the cache, and then there are only
+    // three distinct combinations.
+    assert_eq!(machine.stats(), CacheStats { hits: 3, misses: 3 });
+    assert_eq!(machine.stats().hit_rate(), Some(0.5));
+    machine.reset_stats();
+    assert_eq!(machine.stats(), CacheStats::default());
+}
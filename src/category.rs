@@ -0,0 +1,182 @@
+//! The Unicode **General_Category** property, which classifies characters
+//! as letters, marks, numbers, punctuation, symbols, separators and others.
+
+mod table;
+
+/// A value of the Unicode **General_Category** property, from
+/// [UAX #44](https://www.unicode.org/reports/tr44/#General_Category_Values).
+///
+/// Each variant is documented with its two-letter short name, which
+/// [`Self::short_name`] also returns. The first letter of that name is the
+/// major class, which methods like [`Self::is_letter`] test for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum GeneralCategory {
+    /// **Cn**: Unassigned characters and noncharacters.
+    Unassigned = 0,
+    /// **Lu**: Uppercase letters.
+    UppercaseLetter = 1,
+    /// **Ll**: Lowercase letters.
+    LowercaseLetter = 2,
+    /// **Lt**: Digraphs whose first part is uppercase, such as U+01C5.
+    TitlecaseLetter = 3,
+    /// **Lm**: Modifier letters.
+    ModifierLetter = 4,
+    /// **Lo**: Other letters, including syllables and ideographs.
+    OtherLetter = 5,
+    /// **Mn**: Nonspacing combining marks.
+    NonspacingMark = 6,
+    /// **Mc**: Spacing combining marks.
+    SpacingMark = 7,
+    /// **Me**: Enclosing combining marks.
+    EnclosingMark = 8,
+    /// **Nd**: Decimal digits.
+    DecimalNumber = 9,
+    /// **Nl**: Letterlike numeric characters, such as Roman numerals.
+    LetterNumber = 10,
+    /// **No**: Other numeric characters, such as fractions.
+    OtherNumber = 11,
+    /// **Pc**: Connecting punctuation, such as the low line.
+    ConnectorPunctuation = 12,
+    /// **Pd**: Dashes and hyphens.
+    DashPunctuation = 13,
+    /// **Ps**: Opening punctuation, such as brackets.
+    OpenPunctuation = 14,
+    /// **Pe**: Closing punctuation, such as brackets.
+    ClosePunctuation = 15,
+    /// **Pi**: Initial quotation marks.
+    InitialPunctuation = 16,
+    /// **Pf**: Final quotation marks.
+    FinalPunctuation = 17,
+    /// **Po**: Other punctuation.
+    OtherPunctuation = 18,
+    /// **Sm**: Mathematical symbols.
+    MathSymbol = 19,
+    /// **Sc**: Currency symbols.
+    CurrencySymbol = 20,
+    /// **Sk**: Non-letterlike modifier symbols.
+    ModifierSymbol = 21,
+    /// **So**: Other symbols, including most emoji.
+    OtherSymbol = 22,
+    /// **Zs**: Space separators.
+    SpaceSeparator = 23,
+    /// **Zl**: U+2028 LINE SEPARATOR.
+    LineSeparator = 24,
+    /// **Zp**: U+2029 PARAGRAPH SEPARATOR.
+    ParagraphSeparator = 25,
+    /// **Cc**: Control characters.
+    Control = 26,
+    /// **Cf**: Format characters, such as the joiners.
+    Format = 27,
+    /// **Cs**: Surrogate code points.
+    Surrogate = 28,
+    /// **Co**: Private-use characters.
+    PrivateUse = 29,
+}
+
+impl GeneralCategory {
+    const ALL: [Self; 30] = [
+        Self::Unassigned,
+        Self::UppercaseLetter,
+        Self::LowercaseLetter,
+        Self::TitlecaseLetter,
+        Self::ModifierLetter,
+        Self::OtherLetter,
+        Self::NonspacingMark,
+        Self::SpacingMark,
+        Self::EnclosingMark,
+        Self::DecimalNumber,
+        Self::LetterNumber,
+        Self::OtherNumber,
+        Self::ConnectorPunctuation,
+        Self::DashPunctuation,
+        Self::OpenPunctuation,
+        Self::ClosePunctuation,
+        Self::InitialPunctuation,
+        Self::FinalPunctuation,
+        Self::OtherPunctuation,
+        Self::MathSymbol,
+        Self::CurrencySymbol,
+        Self::ModifierSymbol,
+        Self::OtherSymbol,
+        Self::SpaceSeparator,
+        Self::LineSeparator,
+        Self::ParagraphSeparator,
+        Self::Control,
+        Self::Format,
+        Self::Surrogate,
+        Self::PrivateUse,
+    ];
+
+    const SHORT_NAMES: [&'static str; 30] = [
+        "Cn", "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps",
+        "Pe", "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co",
+    ];
+
+    /// The size in bytes of the lookup table that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`](crate::CharProperties),
+    /// this table honors the `GRAPHEME_MACHINE_LINK_SECTION` environment
+    /// variable.
+    pub const TABLE_SIZE: usize = table::CATEGORY_RUNS_SIZE;
+
+    /// Returns the **General_Category** of the given character.
+    ///
+    /// This is a separate lookup from [`CharProperties::for_char`](crate::CharProperties::for_char),
+    /// but both can be done for each character in the same pass over some
+    /// text, such as in a tokenizer that needs to know both where the
+    /// grapheme clusters end and which of them are letters.
+    pub const fn of_char(c: char) -> Self {
+        Self::ALL[table::category_lookup(c) as usize]
+    }
+
+    /// Returns the two-letter short name of the category, such as `"Lu"`.
+    pub const fn short_name(self) -> &'static str {
+        Self::SHORT_NAMES[self as usize]
+    }
+
+    /// Returns `true` for the letter categories, whose short names begin
+    /// with `L`.
+    pub const fn is_letter(self) -> bool {
+        matches!(self as u8, 1..=5)
+    }
+
+    /// Returns `true` for the combining mark categories, whose short names
+    /// begin with `M`.
+    pub const fn is_mark(self) -> bool {
+        matches!(self as u8, 6..=8)
+    }
+
+    /// Returns `true` for the number categories, whose short names begin
+    /// with `N`.
+    pub const fn is_number(self) -> bool {
+        matches!(self as u8, 9..=11)
+    }
+
+    /// Returns `true` for the punctuation categories, whose short names
+    /// begin with `P`.
+    pub const fn is_punctuation(self) -> bool {
+        matches!(self as u8, 12..=18)
+    }
+
+    /// Returns `true` for the symbol categories, whose short names begin
+    /// with `S`.
+    pub const fn is_symbol(self) -> bool {
+        matches!(self as u8, 19..=22)
+    }
+
+    /// Returns `true` for the separator categories, whose short names
+    /// begin with `Z`.
+    pub const fn is_separator(self) -> bool {
+        matches!(self as u8, 23..=25)
+    }
+
+    /// Returns `true` for the other categories, whose short names begin
+    /// with `C`, including [`Self::Unassigned`].
+    pub const fn is_other(self) -> bool {
+        matches!(self as u8, 0 | 26..=29)
+    }
+}
+
+#[cfg(test)]
+mod tests;
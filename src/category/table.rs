@@ -0,0 +1,543 @@
+/// Returns the encoded **General_Category** of the given character, as a
+/// discriminant of [`GeneralCategory`](super::GeneralCategory).
+///
+/// [`CATEGORY_RUNS`] is sorted by code point, so this is a binary search for
+/// the last run beginning at or before `c`.
+pub const fn category_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, CATEGORY_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if CATEGORY_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    CATEGORY_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`CATEGORY_RUNS`].
+pub const CATEGORY_RUNS_SIZE: usize = CATEGORY_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static CATEGORY_RUNS: [u32; 4105] = [
+    0x0000001a, 0x00002017, 0x00002112, 0x00002414, 0x00002512, 0x0000280e, 0x0000290f, 0x00002a12,
+    0x00002b13, 0x00002c12, 0x00002d0d, 0x00002e12, 0x00003009, 0x00003a12, 0x00003c13, 0x00003f12,
+    0x00004101, 0x00005b0e, 0x00005c12, 0x00005d0f, 0x00005e15, 0x00005f0c, 0x00006015, 0x00006102,
+    0x00007b0e, 0x00007c13, 0x00007d0f, 0x00007e13, 0x00007f1a, 0x0000a017, 0x0000a112, 0x0000a214,
+    0x0000a616, 0x0000a712, 0x0000a815, 0x0000a916, 0x0000aa05, 0x0000ab10, 0x0000ac13, 0x0000ad1b,
+    0x0000ae16, 0x0000af15, 0x0000b016, 0x0000b113, 0x0000b20b, 0x0000b415, 0x0000b502, 0x0000b612,
+    0x0000b815, 0x0000b90b, 0x0000ba05, 0x0000bb11, 0x0000bc0b, 0x0000bf12, 0x0000c001, 0x0000d713,
+    0x0000d801, 0x0000df02, 0x0000f713, 0x0000f802, 0x00010001, 0x00010102, 0x00010201, 0x00010302,
+    0x00010401, 0x00010502, 0x00010601, 0x00010702, 0x00010801, 0x00010902, 0x00010a01, 0x00010b02,
+    0x00010c01, 0x00010d02, 0x00010e01, 0x00010f02, 0x00011001, 0x00011102, 0x00011201, 0x00011302,
+    0x00011401, 0x00011502, 0x00011601, 0x00011702, 0x00011801, 0x00011902, 0x00011a01, 0x00011b02,
+    0x00011c01, 0x00011d02, 0x00011e01, 0x00011f02, 0x00012001, 0x00012102, 0x00012201, 0x00012302,
+    0x00012401, 0x00012502, 0x00012601, 0x00012702, 0x00012801, 0x00012902, 0x00012a01, 0x00012b02,
+    0x00012c01, 0x00012d02, 0x00012e01, 0x00012f02, 0x00013001, 0x00013102, 0x00013201, 0x00013302,
+    0x00013401, 0x00013502, 0x00013601, 0x00013702, 0x00013901, 0x00013a02, 0x00013b01, 0x00013c02,
+    0x00013d01, 0x00013e02, 0x00013f01, 0x00014002, 0x00014101, 0x00014202, 0x00014301, 0x00014402,
+    0x00014501, 0x00014602, 0x00014701, 0x00014802, 0x00014a01, 0x00014b02, 0x00014c01, 0x00014d02,
+    0x00014e01, 0x00014f02, 0x00015001, 0x00015102, 0x00015201, 0x00015302, 0x00015401, 0x00015502,
+    0x00015601, 0x00015702, 0x00015801, 0x00015902, 0x00015a01, 0x00015b02, 0x00015c01, 0x00015d02,
+    0x00015e01, 0x00015f02, 0x00016001, 0x00016102, 0x00016201, 0x00016302, 0x00016401, 0x00016502,
+    0x00016601, 0x00016702, 0x00016801, 0x00016902, 0x00016a01, 0x00016b02, 0x00016c01, 0x00016d02,
+    0x00016e01, 0x00016f02, 0x00017001, 0x00017102, 0x00017201, 0x00017302, 0x00017401, 0x00017502,
+    0x00017601, 0x00017702, 0x00017801, 0x00017a02, 0x00017b01, 0x00017c02, 0x00017d01, 0x00017e02,
+    0x00018101, 0x00018302, 0x00018401, 0x00018502, 0x00018601, 0x00018802, 0x00018901, 0x00018c02,
+    0x00018e01, 0x00019202, 0x00019301, 0x00019502, 0x00019601, 0x00019902, 0x00019c01, 0x00019e02,
+    0x00019f01, 0x0001a102, 0x0001a201, 0x0001a302, 0x0001a401, 0x0001a502, 0x0001a601, 0x0001a802,
+    0x0001a901, 0x0001aa02, 0x0001ac01, 0x0001ad02, 0x0001ae01, 0x0001b002, 0x0001b101, 0x0001b402,
+    0x0001b501, 0x0001b602, 0x0001b701, 0x0001b902, 0x0001bb05, 0x0001bc01, 0x0001bd02, 0x0001c005,
+    0x0001c401, 0x0001c503, 0x0001c602, 0x0001c701, 0x0001c803, 0x0001c902, 0x0001ca01, 0x0001cb03,
+    0x0001cc02, 0x0001cd01, 0x0001ce02, 0x0001cf01, 0x0001d002, 0x0001d101, 0x0001d202, 0x0001d301,
+    0x0001d402, 0x0001d501, 0x0001d602, 0x0001d701, 0x0001d802, 0x0001d901, 0x0001da02, 0x0001db01,
+    0x0001dc02, 0x0001de01, 0x0001df02, 0x0001e001, 0x0001e102, 0x0001e201, 0x0001e302, 0x0001e401,
+    0x0001e502, 0x0001e601, 0x0001e702, 0x0001e801, 0x0001e902, 0x0001ea01, 0x0001eb02, 0x0001ec01,
+    0x0001ed02, 0x0001ee01, 0x0001ef02, 0x0001f101, 0x0001f203, 0x0001f302, 0x0001f401, 0x0001f502,
+    0x0001f601, 0x0001f902, 0x0001fa01, 0x0001fb02, 0x0001fc01, 0x0001fd02, 0x0001fe01, 0x0001ff02,
+    0x00020001, 0x00020102, 0x00020201, 0x00020302, 0x00020401, 0x00020502, 0x00020601, 0x00020702,
+    0x00020801, 0x00020902, 0x00020a01, 0x00020b02, 0x00020c01, 0x00020d02, 0x00020e01, 0x00020f02,
+    0x00021001, 0x00021102, 0x00021201, 0x00021302, 0x00021401, 0x00021502, 0x00021601, 0x00021702,
+    0x00021801, 0x00021902, 0x00021a01, 0x00021b02, 0x00021c01, 0x00021d02, 0x00021e01, 0x00021f02,
+    0x00022001, 0x00022102, 0x00022201, 0x00022302, 0x00022401, 0x00022502, 0x00022601, 0x00022702,
+    0x00022801, 0x00022902, 0x00022a01, 0x00022b02, 0x00022c01, 0x00022d02, 0x00022e01, 0x00022f02,
+    0x00023001, 0x00023102, 0x00023201, 0x00023302, 0x00023a01, 0x00023c02, 0x00023d01, 0x00023f02,
+    0x00024101, 0x00024202, 0x00024301, 0x00024702, 0x00024801, 0x00024902, 0x00024a01, 0x00024b02,
+    0x00024c01, 0x00024d02, 0x00024e01, 0x00024f02, 0x00029405, 0x00029502, 0x0002b004, 0x0002c215,
+    0x0002c604, 0x0002d215, 0x0002e004, 0x0002e515, 0x0002ec04, 0x0002ed15, 0x0002ee04, 0x0002ef15,
+    0x00030006, 0x00037001, 0x00037102, 0x00037201, 0x00037302, 0x00037404, 0x00037515, 0x00037601,
+    0x00037702, 0x00037800, 0x00037a04, 0x00037b02, 0x00037e12, 0x00037f01, 0x00038000, 0x00038415,
+    0x00038601, 0x00038712, 0x00038801, 0x00038b00, 0x00038c01, 0x00038d00, 0x00038e01, 0x00039002,
+    0x00039101, 0x0003a200, 0x0003a301, 0x0003ac02, 0x0003cf01, 0x0003d002, 0x0003d201, 0x0003d502,
+    0x0003d801, 0x0003d902, 0x0003da01, 0x0003db02, 0x0003dc01, 0x0003dd02, 0x0003de01, 0x0003df02,
+    0x0003e001, 0x0003e102, 0x0003e201, 0x0003e302, 0x0003e401, 0x0003e502, 0x0003e601, 0x0003e702,
+    0x0003e801, 0x0003e902, 0x0003ea01, 0x0003eb02, 0x0003ec01, 0x0003ed02, 0x0003ee01, 0x0003ef02,
+    0x0003f401, 0x0003f502, 0x0003f613, 0x0003f701, 0x0003f802, 0x0003f901, 0x0003fb02, 0x0003fd01,
+    0x00043002, 0x00046001, 0x00046102, 0x00046201, 0x00046302, 0x00046401, 0x00046502, 0x00046601,
+    0x00046702, 0x00046801, 0x00046902, 0x00046a01, 0x00046b02, 0x00046c01, 0x00046d02, 0x00046e01,
+    0x00046f02, 0x00047001, 0x00047102, 0x00047201, 0x00047302, 0x00047401, 0x00047502, 0x00047601,
+    0x00047702, 0x00047801, 0x00047902, 0x00047a01, 0x00047b02, 0x00047c01, 0x00047d02, 0x00047e01,
+    0x00047f02, 0x00048001, 0x00048102, 0x00048216, 0x00048306, 0x00048808, 0x00048a01, 0x00048b02,
+    0x00048c01, 0x00048d02, 0x00048e01, 0x00048f02, 0x00049001, 0x00049102, 0x00049201, 0x00049302,
+    0x00049401, 0x00049502, 0x00049601, 0x00049702, 0x00049801, 0x00049902, 0x00049a01, 0x00049b02,
+    0x00049c01, 0x00049d02, 0x00049e01, 0x00049f02, 0x0004a001, 0x0004a102, 0x0004a201, 0x0004a302,
+    0x0004a401, 0x0004a502, 0x0004a601, 0x0004a702, 0x0004a801, 0x0004a902, 0x0004aa01, 0x0004ab02,
+    0x0004ac01, 0x0004ad02, 0x0004ae01, 0x0004af02, 0x0004b001, 0x0004b102, 0x0004b201, 0x0004b302,
+    0x0004b401, 0x0004b502, 0x0004b601, 0x0004b702, 0x0004b801, 0x0004b902, 0x0004ba01, 0x0004bb02,
+    0x0004bc01, 0x0004bd02, 0x0004be01, 0x0004bf02, 0x0004c001, 0x0004c202, 0x0004c301, 0x0004c402,
+    0x0004c501, 0x0004c602, 0x0004c701, 0x0004c802, 0x0004c901, 0x0004ca02, 0x0004cb01, 0x0004cc02,
+    0x0004cd01, 0x0004ce02, 0x0004d001, 0x0004d102, 0x0004d201, 0x0004d302, 0x0004d401, 0x0004d502,
+    0x0004d601, 0x0004d702, 0x0004d801, 0x0004d902, 0x0004da01, 0x0004db02, 0x0004dc01, 0x0004dd02,
+    0x0004de01, 0x0004df02, 0x0004e001, 0x0004e102, 0x0004e201, 0x0004e302, 0x0004e401, 0x0004e502,
+    0x0004e601, 0x0004e702, 0x0004e801, 0x0004e902, 0x0004ea01, 0x0004eb02, 0x0004ec01, 0x0004ed02,
+    0x0004ee01, 0x0004ef02, 0x0004f001, 0x0004f102, 0x0004f201, 0x0004f302, 0x0004f401, 0x0004f502,
+    0x0004f601, 0x0004f702, 0x0004f801, 0x0004f902, 0x0004fa01, 0x0004fb02, 0x0004fc01, 0x0004fd02,
+    0x0004fe01, 0x0004ff02, 0x00050001, 0x00050102, 0x00050201, 0x00050302, 0x00050401, 0x00050502,
+    0x00050601, 0x00050702, 0x00050801, 0x00050902, 0x00050a01, 0x00050b02, 0x00050c01, 0x00050d02,
+    0x00050e01, 0x00050f02, 0x00051001, 0x00051102, 0x00051201, 0x00051302, 0x00051401, 0x00051502,
+    0x00051601, 0x00051702, 0x00051801, 0x00051902, 0x00051a01, 0x00051b02, 0x00051c01, 0x00051d02,
+    0x00051e01, 0x00051f02, 0x00052001, 0x00052102, 0x00052201, 0x00052302, 0x00052401, 0x00052502,
+    0x00052601, 0x00052702, 0x00052801, 0x00052902, 0x00052a01, 0x00052b02, 0x00052c01, 0x00052d02,
+    0x00052e01, 0x00052f02, 0x00053000, 0x00053101, 0x00055700, 0x00055904, 0x00055a12, 0x00056002,
+    0x00058912, 0x00058a0d, 0x00058b00, 0x00058d16, 0x00058f14, 0x00059000, 0x00059106, 0x0005be0d,
+    0x0005bf06, 0x0005c012, 0x0005c106, 0x0005c312, 0x0005c406, 0x0005c612, 0x0005c706, 0x0005c800,
+    0x0005d005, 0x0005eb00, 0x0005ef05, 0x0005f312, 0x0005f500, 0x0006001b, 0x00060613, 0x00060912,
+    0x00060b14, 0x00060c12, 0x00060e16, 0x00061006, 0x00061b12, 0x00061c1b, 0x00061d12, 0x00062005,
+    0x00064004, 0x00064105, 0x00064b06, 0x00066009, 0x00066a12, 0x00066e05, 0x00067006, 0x00067105,
+    0x0006d412, 0x0006d505, 0x0006d606, 0x0006dd1b, 0x0006de16, 0x0006df06, 0x0006e504, 0x0006e706,
+    0x0006e916, 0x0006ea06, 0x0006ee05, 0x0006f009, 0x0006fa05, 0x0006fd16, 0x0006ff05, 0x00070012,
+    0x00070e00, 0x00070f1b, 0x00071005, 0x00071106, 0x00071205, 0x00073006, 0x00074b00, 0x00074d05,
+    0x0007a606, 0x0007b105, 0x0007b200, 0x0007c009, 0x0007ca05, 0x0007eb06, 0x0007f404, 0x0007f616,
+    0x0007f712, 0x0007fa04, 0x0007fb00, 0x0007fd06, 0x0007fe14, 0x00080005, 0x00081606, 0x00081a04,
+    0x00081b06, 0x00082404, 0x00082506, 0x00082804, 0x00082906, 0x00082e00, 0x00083012, 0x00083f00,
+    0x00084005, 0x00085906, 0x00085c00, 0x00085e12, 0x00085f00, 0x00086005, 0x00086b00, 0x00087005,
+    0x00088815, 0x00088905, 0x00088f00, 0x0008901b, 0x00089200, 0x00089706, 0x0008a005, 0x0008c904,
+    0x0008ca06, 0x0008e21b, 0x0008e306, 0x00090307, 0x00090405, 0x00093a06, 0x00093b07, 0x00093c06,
+    0x00093d05, 0x00093e07, 0x00094106, 0x00094907, 0x00094d06, 0x00094e07, 0x00095005, 0x00095106,
+    0x00095805, 0x00096206, 0x00096412, 0x00096609, 0x00097012, 0x00097104, 0x00097205, 0x00098106,
+    0x00098207, 0x00098400, 0x00098505, 0x00098d00, 0x00098f05, 0x00099100, 0x00099305, 0x0009a900,
+    0x0009aa05, 0x0009b100, 0x0009b205, 0x0009b300, 0x0009b605, 0x0009ba00, 0x0009bc06, 0x0009bd05,
+    0x0009be07, 0x0009c106, 0x0009c500, 0x0009c707, 0x0009c900, 0x0009cb07, 0x0009cd06, 0x0009ce05,
+    0x0009cf00, 0x0009d707, 0x0009d800, 0x0009dc05, 0x0009de00, 0x0009df05, 0x0009e206, 0x0009e400,
+    0x0009e609, 0x0009f005, 0x0009f214, 0x0009f40b, 0x0009fa16, 0x0009fb14, 0x0009fc05, 0x0009fd12,
+    0x0009fe06, 0x0009ff00, 0x000a0106, 0x000a0307, 0x000a0400, 0x000a0505, 0x000a0b00, 0x000a0f05,
+    0x000a1100, 0x000a1305, 0x000a2900, 0x000a2a05, 0x000a3100, 0x000a3205, 0x000a3400, 0x000a3505,
+    0x000a3700, 0x000a3805, 0x000a3a00, 0x000a3c06, 0x000a3d00, 0x000a3e07, 0x000a4106, 0x000a4300,
+    0x000a4706, 0x000a4900, 0x000a4b06, 0x000a4e00, 0x000a5106, 0x000a5200, 0x000a5905, 0x000a5d00,
+    0x000a5e05, 0x000a5f00, 0x000a6609, 0x000a7006, 0x000a7205, 0x000a7506, 0x000a7612, 0x000a7700,
+    0x000a8106, 0x000a8307, 0x000a8400, 0x000a8505, 0x000a8e00, 0x000a8f05, 0x000a9200, 0x000a9305,
+    0x000aa900, 0x000aaa05, 0x000ab100, 0x000ab205, 0x000ab400, 0x000ab505, 0x000aba00, 0x000abc06,
+    0x000abd05, 0x000abe07, 0x000ac106, 0x000ac600, 0x000ac706, 0x000ac907, 0x000aca00, 0x000acb07,
+    0x000acd06, 0x000ace00, 0x000ad005, 0x000ad100, 0x000ae005, 0x000ae206, 0x000ae400, 0x000ae609,
+    0x000af012, 0x000af114, 0x000af200, 0x000af905, 0x000afa06, 0x000b0000, 0x000b0106, 0x000b0207,
+    0x000b0400, 0x000b0505, 0x000b0d00, 0x000b0f05, 0x000b1100, 0x000b1305, 0x000b2900, 0x000b2a05,
+    0x000b3100, 0x000b3205, 0x000b3400, 0x000b3505, 0x000b3a00, 0x000b3c06, 0x000b3d05, 0x000b3e07,
+    0x000b3f06, 0x000b4007, 0x000b4106, 0x000b4500, 0x000b4707, 0x000b4900, 0x000b4b07, 0x000b4d06,
+    0x000b4e00, 0x000b5506, 0x000b5707, 0x000b5800, 0x000b5c05, 0x000b5e00, 0x000b5f05, 0x000b6206,
+    0x000b6400, 0x000b6609, 0x000b7016, 0x000b7105, 0x000b720b, 0x000b7800, 0x000b8206, 0x000b8305,
+    0x000b8400, 0x000b8505, 0x000b8b00, 0x000b8e05, 0x000b9100, 0x000b9205, 0x000b9600, 0x000b9905,
+    0x000b9b00, 0x000b9c05, 0x000b9d00, 0x000b9e05, 0x000ba000, 0x000ba305, 0x000ba500, 0x000ba805,
+    0x000bab00, 0x000bae05, 0x000bba00, 0x000bbe07, 0x000bc006, 0x000bc107, 0x000bc300, 0x000bc607,
+    0x000bc900, 0x000bca07, 0x000bcd06, 0x000bce00, 0x000bd005, 0x000bd100, 0x000bd707, 0x000bd800,
+    0x000be609, 0x000bf00b, 0x000bf316, 0x000bf914, 0x000bfa16, 0x000bfb00, 0x000c0006, 0x000c0107,
+    0x000c0406, 0x000c0505, 0x000c0d00, 0x000c0e05, 0x000c1100, 0x000c1205, 0x000c2900, 0x000c2a05,
+    0x000c3a00, 0x000c3c06, 0x000c3d05, 0x000c3e06, 0x000c4107, 0x000c4500, 0x000c4606, 0x000c4900,
+    0x000c4a06, 0x000c4e00, 0x000c5506, 0x000c5700, 0x000c5805, 0x000c5b00, 0x000c5d05, 0x000c5e00,
+    0x000c6005, 0x000c6206, 0x000c6400, 0x000c6609, 0x000c7000, 0x000c7712, 0x000c780b, 0x000c7f16,
+    0x000c8005, 0x000c8106, 0x000c8207, 0x000c8412, 0x000c8505, 0x000c8d00, 0x000c8e05, 0x000c9100,
+    0x000c9205, 0x000ca900, 0x000caa05, 0x000cb400, 0x000cb505, 0x000cba00, 0x000cbc06, 0x000cbd05,
+    0x000cbe07, 0x000cbf06, 0x000cc007, 0x000cc500, 0x000cc606, 0x000cc707, 0x000cc900, 0x000cca07,
+    0x000ccc06, 0x000cce00, 0x000cd507, 0x000cd700, 0x000cdd05, 0x000cdf00, 0x000ce005, 0x000ce206,
+    0x000ce400, 0x000ce609, 0x000cf000, 0x000cf105, 0x000cf307, 0x000cf400, 0x000d0006, 0x000d0207,
+    0x000d0405, 0x000d0d00, 0x000d0e05, 0x000d1100, 0x000d1205, 0x000d3b06, 0x000d3d05, 0x000d3e07,
+    0x000d4106, 0x000d4500, 0x000d4607, 0x000d4900, 0x000d4a07, 0x000d4d06, 0x000d4e05, 0x000d4f16,
+    0x000d5000, 0x000d5405, 0x000d5707, 0x000d580b, 0x000d5f05, 0x000d6206, 0x000d6400, 0x000d6609,
+    0x000d700b, 0x000d7916, 0x000d7a05, 0x000d8000, 0x000d8106, 0x000d8207, 0x000d8400, 0x000d8505,
+    0x000d9700, 0x000d9a05, 0x000db200, 0x000db305, 0x000dbc00, 0x000dbd05, 0x000dbe00, 0x000dc005,
+    0x000dc700, 0x000dca06, 0x000dcb00, 0x000dcf07, 0x000dd206, 0x000dd500, 0x000dd606, 0x000dd700,
+    0x000dd807, 0x000de000, 0x000de609, 0x000df000, 0x000df207, 0x000df412, 0x000df500, 0x000e0105,
+    0x000e3106, 0x000e3205, 0x000e3406, 0x000e3b00, 0x000e3f14, 0x000e4005, 0x000e4604, 0x000e4706,
+    0x000e4f12, 0x000e5009, 0x000e5a12, 0x000e5c00, 0x000e8105, 0x000e8300, 0x000e8405, 0x000e8500,
+    0x000e8605, 0x000e8b00, 0x000e8c05, 0x000ea400, 0x000ea505, 0x000ea600, 0x000ea705, 0x000eb106,
+    0x000eb205, 0x000eb406, 0x000ebd05, 0x000ebe00, 0x000ec005, 0x000ec500, 0x000ec604, 0x000ec700,
+    0x000ec806, 0x000ecf00, 0x000ed009, 0x000eda00, 0x000edc05, 0x000ee000, 0x000f0005, 0x000f0116,
+    0x000f0412, 0x000f1316, 0x000f1412, 0x000f1516, 0x000f1806, 0x000f1a16, 0x000f2009, 0x000f2a0b,
+    0x000f3416, 0x000f3506, 0x000f3616, 0x000f3706, 0x000f3816, 0x000f3906, 0x000f3a0e, 0x000f3b0f,
+    0x000f3c0e, 0x000f3d0f, 0x000f3e07, 0x000f4005, 0x000f4800, 0x000f4905, 0x000f6d00, 0x000f7106,
+    0x000f7f07, 0x000f8006, 0x000f8512, 0x000f8606, 0x000f8805, 0x000f8d06, 0x000f9800, 0x000f9906,
+    0x000fbd00, 0x000fbe16, 0x000fc606, 0x000fc716, 0x000fcd00, 0x000fce16, 0x000fd012, 0x000fd516,
+    0x000fd912, 0x000fdb00, 0x00100005, 0x00102b07, 0x00102d06, 0x00103107, 0x00103206, 0x00103807,
+    0x00103906, 0x00103b07, 0x00103d06, 0x00103f05, 0x00104009, 0x00104a12, 0x00105005, 0x00105607,
+    0x00105806, 0x00105a05, 0x00105e06, 0x00106105, 0x00106207, 0x00106505, 0x00106707, 0x00106e05,
+    0x00107106, 0x00107505, 0x00108206, 0x00108307, 0x00108506, 0x00108707, 0x00108d06, 0x00108e05,
+    0x00108f07, 0x00109009, 0x00109a07, 0x00109d06, 0x00109e16, 0x0010a001, 0x0010c600, 0x0010c701,
+    0x0010c800, 0x0010cd01, 0x0010ce00, 0x0010d002, 0x0010fb12, 0x0010fc04, 0x0010fd02, 0x00110005,
+    0x00124900, 0x00124a05, 0x00124e00, 0x00125005, 0x00125700, 0x00125805, 0x00125900, 0x00125a05,
+    0x00125e00, 0x00126005, 0x00128900, 0x00128a05, 0x00128e00, 0x00129005, 0x0012b100, 0x0012b205,
+    0x0012b600, 0x0012b805, 0x0012bf00, 0x0012c005, 0x0012c100, 0x0012c205, 0x0012c600, 0x0012c805,
+    0x0012d700, 0x0012d805, 0x00131100, 0x00131205, 0x00131600, 0x00131805, 0x00135b00, 0x00135d06,
+    0x00136012, 0x0013690b, 0x00137d00, 0x00138005, 0x00139016, 0x00139a00, 0x0013a001, 0x0013f600,
+    0x0013f802, 0x0013fe00, 0x0014000d, 0x00140105, 0x00166d16, 0x00166e12, 0x00166f05, 0x00168017,
+    0x00168105, 0x00169b0e, 0x00169c0f, 0x00169d00, 0x0016a005, 0x0016eb12, 0x0016ee0a, 0x0016f105,
+    0x0016f900, 0x00170005, 0x00171206, 0x00171507, 0x00171600, 0x00171f05, 0x00173206, 0x00173407,
+    0x00173512, 0x00173700, 0x00174005, 0x00175206, 0x00175400, 0x00176005, 0x00176d00, 0x00176e05,
+    0x00177100, 0x00177206, 0x00177400, 0x00178005, 0x0017b406, 0x0017b607, 0x0017b706, 0x0017be07,
+    0x0017c606, 0x0017c707, 0x0017c906, 0x0017d412, 0x0017d704, 0x0017d812, 0x0017db14, 0x0017dc05,
+    0x0017dd06, 0x0017de00, 0x0017e009, 0x0017ea00, 0x0017f00b, 0x0017fa00, 0x00180012, 0x0018060d,
+    0x00180712, 0x00180b06, 0x00180e1b, 0x00180f06, 0x00181009, 0x00181a00, 0x00182005, 0x00184304,
+    0x00184405, 0x00187900, 0x00188005, 0x00188506, 0x00188705, 0x0018a906, 0x0018aa05, 0x0018ab00,
+    0x0018b005, 0x0018f600, 0x00190005, 0x00191f00, 0x00192006, 0x00192307, 0x00192706, 0x00192907,
+    0x00192c00, 0x00193007, 0x00193206, 0x00193307, 0x00193906, 0x00193c00, 0x00194016, 0x00194100,
+    0x00194412, 0x00194609, 0x00195005, 0x00196e00, 0x00197005, 0x00197500, 0x00198005, 0x0019ac00,
+    0x0019b005, 0x0019ca00, 0x0019d009, 0x0019da0b, 0x0019db00, 0x0019de16, 0x001a0005, 0x001a1706,
+    0x001a1907, 0x001a1b06, 0x001a1c00, 0x001a1e12, 0x001a2005, 0x001a5507, 0x001a5606, 0x001a5707,
+    0x001a5806, 0x001a5f00, 0x001a6006, 0x001a6107, 0x001a6206, 0x001a6307, 0x001a6506, 0x001a6d07,
+    0x001a7306, 0x001a7d00, 0x001a7f06, 0x001a8009, 0x001a8a00, 0x001a9009, 0x001a9a00, 0x001aa012,
+    0x001aa704, 0x001aa812, 0x001aae00, 0x001ab006, 0x001abe08, 0x001abf06, 0x001acf00, 0x001b0006,
+    0x001b0407, 0x001b0505, 0x001b3406, 0x001b3507, 0x001b3606, 0x001b3b07, 0x001b3c06, 0x001b3d07,
+    0x001b4206, 0x001b4307, 0x001b4505, 0x001b4d00, 0x001b4e12, 0x001b5009, 0x001b5a12, 0x001b6116,
+    0x001b6b06, 0x001b7416, 0x001b7d12, 0x001b8006, 0x001b8207, 0x001b8305, 0x001ba107, 0x001ba206,
+    0x001ba607, 0x001ba806, 0x001baa07, 0x001bab06, 0x001bae05, 0x001bb009, 0x001bba05, 0x001be606,
+    0x001be707, 0x001be806, 0x001bea07, 0x001bed06, 0x001bee07, 0x001bef06, 0x001bf207, 0x001bf400,
+    0x001bfc12, 0x001c0005, 0x001c2407, 0x001c2c06, 0x001c3407, 0x001c3606, 0x001c3800, 0x001c3b12,
+    0x001c4009, 0x001c4a00, 0x001c4d05, 0x001c5009, 0x001c5a05, 0x001c7804, 0x001c7e12, 0x001c8002,
+    0x001c8901, 0x001c8a02, 0x001c8b00, 0x001c9001, 0x001cbb00, 0x001cbd01, 0x001cc012, 0x001cc800,
+    0x001cd006, 0x001cd312, 0x001cd406, 0x001ce107, 0x001ce206, 0x001ce905, 0x001ced06, 0x001cee05,
+    0x001cf406, 0x001cf505, 0x001cf707, 0x001cf806, 0x001cfa05, 0x001cfb00, 0x001d0002, 0x001d2c04,
+    0x001d6b02, 0x001d7804, 0x001d7902, 0x001d9b04, 0x001dc006, 0x001e0001, 0x001e0102, 0x001e0201,
+    0x001e0302, 0x001e0401, 0x001e0502, 0x001e0601, 0x001e0702, 0x001e0801, 0x001e0902, 0x001e0a01,
+    0x001e0b02, 0x001e0c01, 0x001e0d02, 0x001e0e01, 0x001e0f02, 0x001e1001, 0x001e1102, 0x001e1201,
+    0x001e1302, 0x001e1401, 0x001e1502, 0x001e1601, 0x001e1702, 0x001e1801, 0x001e1902, 0x001e1a01,
+    0x001e1b02, 0x001e1c01, 0x001e1d02, 0x001e1e01, 0x001e1f02, 0x001e2001, 0x001e2102, 0x001e2201,
+    0x001e2302, 0x001e2401, 0x001e2502, 0x001e2601, 0x001e2702, 0x001e2801, 0x001e2902, 0x001e2a01,
+    0x001e2b02, 0x001e2c01, 0x001e2d02, 0x001e2e01, 0x001e2f02, 0x001e3001, 0x001e3102, 0x001e3201,
+    0x001e3302, 0x001e3401, 0x001e3502, 0x001e3601, 0x001e3702, 0x001e3801, 0x001e3902, 0x001e3a01,
+    0x001e3b02, 0x001e3c01, 0x001e3d02, 0x001e3e01, 0x001e3f02, 0x001e4001, 0x001e4102, 0x001e4201,
+    0x001e4302, 0x001e4401, 0x001e4502, 0x001e4601, 0x001e4702, 0x001e4801, 0x001e4902, 0x001e4a01,
+    0x001e4b02, 0x001e4c01, 0x001e4d02, 0x001e4e01, 0x001e4f02, 0x001e5001, 0x001e5102, 0x001e5201,
+    0x001e5302, 0x001e5401, 0x001e5502, 0x001e5601, 0x001e5702, 0x001e5801, 0x001e5902, 0x001e5a01,
+    0x001e5b02, 0x001e5c01, 0x001e5d02, 0x001e5e01, 0x001e5f02, 0x001e6001, 0x001e6102, 0x001e6201,
+    0x001e6302, 0x001e6401, 0x001e6502, 0x001e6601, 0x001e6702, 0x001e6801, 0x001e6902, 0x001e6a01,
+    0x001e6b02, 0x001e6c01, 0x001e6d02, 0x001e6e01, 0x001e6f02, 0x001e7001, 0x001e7102, 0x001e7201,
+    0x001e7302, 0x001e7401, 0x001e7502, 0x001e7601, 0x001e7702, 0x001e7801, 0x001e7902, 0x001e7a01,
+    0x001e7b02, 0x001e7c01, 0x001e7d02, 0x001e7e01, 0x001e7f02, 0x001e8001, 0x001e8102, 0x001e8201,
+    0x001e8302, 0x001e8401, 0x001e8502, 0x001e8601, 0x001e8702, 0x001e8801, 0x001e8902, 0x001e8a01,
+    0x001e8b02, 0x001e8c01, 0x001e8d02, 0x001e8e01, 0x001e8f02, 0x001e9001, 0x001e9102, 0x001e9201,
+    0x001e9302, 0x001e9401, 0x001e9502, 0x001e9e01, 0x001e9f02, 0x001ea001, 0x001ea102, 0x001ea201,
+    0x001ea302, 0x001ea401, 0x001ea502, 0x001ea601, 0x001ea702, 0x001ea801, 0x001ea902, 0x001eaa01,
+    0x001eab02, 0x001eac01, 0x001ead02, 0x001eae01, 0x001eaf02, 0x001eb001, 0x001eb102, 0x001eb201,
+    0x001eb302, 0x001eb401, 0x001eb502, 0x001eb601, 0x001eb702, 0x001eb801, 0x001eb902, 0x001eba01,
+    0x001ebb02, 0x001ebc01, 0x001ebd02, 0x001ebe01, 0x001ebf02, 0x001ec001, 0x001ec102, 0x001ec201,
+    0x001ec302, 0x001ec401, 0x001ec502, 0x001ec601, 0x001ec702, 0x001ec801, 0x001ec902, 0x001eca01,
+    0x001ecb02, 0x001ecc01, 0x001ecd02, 0x001ece01, 0x001ecf02, 0x001ed001, 0x001ed102, 0x001ed201,
+    0x001ed302, 0x001ed401, 0x001ed502, 0x001ed601, 0x001ed702, 0x001ed801, 0x001ed902, 0x001eda01,
+    0x001edb02, 0x001edc01, 0x001edd02, 0x001ede01, 0x001edf02, 0x001ee001, 0x001ee102, 0x001ee201,
+    0x001ee302, 0x001ee401, 0x001ee502, 0x001ee601, 0x001ee702, 0x001ee801, 0x001ee902, 0x001eea01,
+    0x001eeb02, 0x001eec01, 0x001eed02, 0x001eee01, 0x001eef02, 0x001ef001, 0x001ef102, 0x001ef201,
+    0x001ef302, 0x001ef401, 0x001ef502, 0x001ef601, 0x001ef702, 0x001ef801, 0x001ef902, 0x001efa01,
+    0x001efb02, 0x001efc01, 0x001efd02, 0x001efe01, 0x001eff02, 0x001f0801, 0x001f1002, 0x001f1600,
+    0x001f1801, 0x001f1e00, 0x001f2002, 0x001f2801, 0x001f3002, 0x001f3801, 0x001f4002, 0x001f4600,
+    0x001f4801, 0x001f4e00, 0x001f5002, 0x001f5800, 0x001f5901, 0x001f5a00, 0x001f5b01, 0x001f5c00,
+    0x001f5d01, 0x001f5e00, 0x001f5f01, 0x001f6002, 0x001f6801, 0x001f7002, 0x001f7e00, 0x001f8002,
+    0x001f8803, 0x001f9002, 0x001f9803, 0x001fa002, 0x001fa803, 0x001fb002, 0x001fb500, 0x001fb602,
+    0x001fb801, 0x001fbc03, 0x001fbd15, 0x001fbe02, 0x001fbf15, 0x001fc202, 0x001fc500, 0x001fc602,
+    0x001fc801, 0x001fcc03, 0x001fcd15, 0x001fd002, 0x001fd400, 0x001fd602, 0x001fd801, 0x001fdc00,
+    0x001fdd15, 0x001fe002, 0x001fe801, 0x001fed15, 0x001ff000, 0x001ff202, 0x001ff500, 0x001ff602,
+    0x001ff801, 0x001ffc03, 0x001ffd15, 0x001fff00, 0x00200017, 0x00200b1b, 0x0020100d, 0x00201612,
+    0x00201810, 0x00201911, 0x00201a0e, 0x00201b10, 0x00201d11, 0x00201e0e, 0x00201f10, 0x00202012,
+    0x00202818, 0x00202919, 0x00202a1b, 0x00202f17, 0x00203012, 0x00203910, 0x00203a11, 0x00203b12,
+    0x00203f0c, 0x00204112, 0x00204413, 0x0020450e, 0x0020460f, 0x00204712, 0x00205213, 0x00205312,
+    0x0020540c, 0x00205512, 0x00205f17, 0x0020601b, 0x00206500, 0x0020661b, 0x0020700b, 0x00207104,
+    0x00207200, 0x0020740b, 0x00207a13, 0x00207d0e, 0x00207e0f, 0x00207f04, 0x0020800b, 0x00208a13,
+    0x00208d0e, 0x00208e0f, 0x00208f00, 0x00209004, 0x00209d00, 0x0020a014, 0x0020c100, 0x0020d006,
+    0x0020dd08, 0x0020e106, 0x0020e208, 0x0020e506, 0x0020f100, 0x00210016, 0x00210201, 0x00210316,
+    0x00210701, 0x00210816, 0x00210a02, 0x00210b01, 0x00210e02, 0x00211001, 0x00211302, 0x00211416,
+    0x00211501, 0x00211616, 0x00211813, 0x00211901, 0x00211e16, 0x00212401, 0x00212516, 0x00212601,
+    0x00212716, 0x00212801, 0x00212916, 0x00212a01, 0x00212e16, 0x00212f02, 0x00213001, 0x00213402,
+    0x00213505, 0x00213902, 0x00213a16, 0x00213c02, 0x00213e01, 0x00214013, 0x00214501, 0x00214602,
+    0x00214a16, 0x00214b13, 0x00214c16, 0x00214e02, 0x00214f16, 0x0021500b, 0x0021600a, 0x00218301,
+    0x00218402, 0x0021850a, 0x0021890b, 0x00218a16, 0x00218c00, 0x00219013, 0x00219516, 0x00219a13,
+    0x00219c16, 0x0021a013, 0x0021a116, 0x0021a313, 0x0021a416, 0x0021a613, 0x0021a716, 0x0021ae13,
+    0x0021af16, 0x0021ce13, 0x0021d016, 0x0021d213, 0x0021d316, 0x0021d413, 0x0021d516, 0x0021f413,
+    0x00230016, 0x0023080e, 0x0023090f, 0x00230a0e, 0x00230b0f, 0x00230c16, 0x00232013, 0x00232216,
+    0x0023290e, 0x00232a0f, 0x00232b16, 0x00237c13, 0x00237d16, 0x00239b13, 0x0023b416, 0x0023dc13,
+    0x0023e216, 0x00242a00, 0x00244016, 0x00244b00, 0x0024600b, 0x00249c16, 0x0024ea0b, 0x00250016,
+    0x0025b713, 0x0025b816, 0x0025c113, 0x0025c216, 0x0025f813, 0x00260016, 0x00266f13, 0x00267016,
+    0x0027680e, 0x0027690f, 0x00276a0e, 0x00276b0f, 0x00276c0e, 0x00276d0f, 0x00276e0e, 0x00276f0f,
+    0x0027700e, 0x0027710f, 0x0027720e, 0x0027730f, 0x0027740e, 0x0027750f, 0x0027760b, 0x00279416,
+    0x0027c013, 0x0027c50e, 0x0027c60f, 0x0027c713, 0x0027e60e, 0x0027e70f, 0x0027e80e, 0x0027e90f,
+    0x0027ea0e, 0x0027eb0f, 0x0027ec0e, 0x0027ed0f, 0x0027ee0e, 0x0027ef0f, 0x0027f013, 0x00280016,
+    0x00290013, 0x0029830e, 0x0029840f, 0x0029850e, 0x0029860f, 0x0029870e, 0x0029880f, 0x0029890e,
+    0x00298a0f, 0x00298b0e, 0x00298c0f, 0x00298d0e, 0x00298e0f, 0x00298f0e, 0x0029900f, 0x0029910e,
+    0x0029920f, 0x0029930e, 0x0029940f, 0x0029950e, 0x0029960f, 0x0029970e, 0x0029980f, 0x00299913,
+    0x0029d80e, 0x0029d90f, 0x0029da0e, 0x0029db0f, 0x0029dc13, 0x0029fc0e, 0x0029fd0f, 0x0029fe13,
+    0x002b0016, 0x002b3013, 0x002b4516, 0x002b4713, 0x002b4d16, 0x002b7400, 0x002b7616, 0x002b9600,
+    0x002b9716, 0x002c0001, 0x002c3002, 0x002c6001, 0x002c6102, 0x002c6201, 0x002c6502, 0x002c6701,
+    0x002c6802, 0x002c6901, 0x002c6a02, 0x002c6b01, 0x002c6c02, 0x002c6d01, 0x002c7102, 0x002c7201,
+    0x002c7302, 0x002c7501, 0x002c7602, 0x002c7c04, 0x002c7e01, 0x002c8102, 0x002c8201, 0x002c8302,
+    0x002c8401, 0x002c8502, 0x002c8601, 0x002c8702, 0x002c8801, 0x002c8902, 0x002c8a01, 0x002c8b02,
+    0x002c8c01, 0x002c8d02, 0x002c8e01, 0x002c8f02, 0x002c9001, 0x002c9102, 0x002c9201, 0x002c9302,
+    0x002c9401, 0x002c9502, 0x002c9601, 0x002c9702, 0x002c9801, 0x002c9902, 0x002c9a01, 0x002c9b02,
+    0x002c9c01, 0x002c9d02, 0x002c9e01, 0x002c9f02, 0x002ca001, 0x002ca102, 0x002ca201, 0x002ca302,
+    0x002ca401, 0x002ca502, 0x002ca601, 0x002ca702, 0x002ca801, 0x002ca902, 0x002caa01, 0x002cab02,
+    0x002cac01, 0x002cad02, 0x002cae01, 0x002caf02, 0x002cb001, 0x002cb102, 0x002cb201, 0x002cb302,
+    0x002cb401, 0x002cb502, 0x002cb601, 0x002cb702, 0x002cb801, 0x002cb902, 0x002cba01, 0x002cbb02,
+    0x002cbc01, 0x002cbd02, 0x002cbe01, 0x002cbf02, 0x002cc001, 0x002cc102, 0x002cc201, 0x002cc302,
+    0x002cc401, 0x002cc502, 0x002cc601, 0x002cc702, 0x002cc801, 0x002cc902, 0x002cca01, 0x002ccb02,
+    0x002ccc01, 0x002ccd02, 0x002cce01, 0x002ccf02, 0x002cd001, 0x002cd102, 0x002cd201, 0x002cd302,
+    0x002cd401, 0x002cd502, 0x002cd601, 0x002cd702, 0x002cd801, 0x002cd902, 0x002cda01, 0x002cdb02,
+    0x002cdc01, 0x002cdd02, 0x002cde01, 0x002cdf02, 0x002ce001, 0x002ce102, 0x002ce201, 0x002ce302,
+    0x002ce516, 0x002ceb01, 0x002cec02, 0x002ced01, 0x002cee02, 0x002cef06, 0x002cf201, 0x002cf302,
+    0x002cf400, 0x002cf912, 0x002cfd0b, 0x002cfe12, 0x002d0002, 0x002d2600, 0x002d2702, 0x002d2800,
+    0x002d2d02, 0x002d2e00, 0x002d3005, 0x002d6800, 0x002d6f04, 0x002d7012, 0x002d7100, 0x002d7f06,
+    0x002d8005, 0x002d9700, 0x002da005, 0x002da700, 0x002da805, 0x002daf00, 0x002db005, 0x002db700,
+    0x002db805, 0x002dbf00, 0x002dc005, 0x002dc700, 0x002dc805, 0x002dcf00, 0x002dd005, 0x002dd700,
+    0x002dd805, 0x002ddf00, 0x002de006, 0x002e0012, 0x002e0210, 0x002e0311, 0x002e0410, 0x002e0511,
+    0x002e0612, 0x002e0910, 0x002e0a11, 0x002e0b12, 0x002e0c10, 0x002e0d11, 0x002e0e12, 0x002e170d,
+    0x002e1812, 0x002e1a0d, 0x002e1b12, 0x002e1c10, 0x002e1d11, 0x002e1e12, 0x002e2010, 0x002e2111,
+    0x002e220e, 0x002e230f, 0x002e240e, 0x002e250f, 0x002e260e, 0x002e270f, 0x002e280e, 0x002e290f,
+    0x002e2a12, 0x002e2f04, 0x002e3012, 0x002e3a0d, 0x002e3c12, 0x002e400d, 0x002e4112, 0x002e420e,
+    0x002e4312, 0x002e5016, 0x002e5212, 0x002e550e, 0x002e560f, 0x002e570e, 0x002e580f, 0x002e590e,
+    0x002e5a0f, 0x002e5b0e, 0x002e5c0f, 0x002e5d0d, 0x002e5e00, 0x002e8016, 0x002e9a00, 0x002e9b16,
+    0x002ef400, 0x002f0016, 0x002fd600, 0x002ff016, 0x00300017, 0x00300112, 0x00300416, 0x00300504,
+    0x00300605, 0x0030070a, 0x0030080e, 0x0030090f, 0x00300a0e, 0x00300b0f, 0x00300c0e, 0x00300d0f,
+    0x00300e0e, 0x00300f0f, 0x0030100e, 0x0030110f, 0x00301216, 0x0030140e, 0x0030150f, 0x0030160e,
+    0x0030170f, 0x0030180e, 0x0030190f, 0x00301a0e, 0x00301b0f, 0x00301c0d, 0x00301d0e, 0x00301e0f,
+    0x00302016, 0x0030210a, 0x00302a06, 0x00302e07, 0x0030300d, 0x00303104, 0x00303616, 0x0030380a,
+    0x00303b04, 0x00303c05, 0x00303d12, 0x00303e16, 0x00304000, 0x00304105, 0x00309700, 0x00309906,
+    0x00309b15, 0x00309d04, 0x00309f05, 0x0030a00d, 0x0030a105, 0x0030fb12, 0x0030fc04, 0x0030ff05,
+    0x00310000, 0x00310505, 0x00313000, 0x00313105, 0x00318f00, 0x00319016, 0x0031920b, 0x00319616,
+    0x0031a005, 0x0031c016, 0x0031e600, 0x0031ef16, 0x0031f005, 0x00320016, 0x00321f00, 0x0032200b,
+    0x00322a16, 0x0032480b, 0x00325016, 0x0032510b, 0x00326016, 0x0032800b, 0x00328a16, 0x0032b10b,
+    0x0032c016, 0x00340005, 0x004dc016, 0x004e0005, 0x00a01504, 0x00a01605, 0x00a48d00, 0x00a49016,
+    0x00a4c700, 0x00a4d005, 0x00a4f804, 0x00a4fe12, 0x00a50005, 0x00a60c04, 0x00a60d12, 0x00a61005,
+    0x00a62009, 0x00a62a05, 0x00a62c00, 0x00a64001, 0x00a64102, 0x00a64201, 0x00a64302, 0x00a64401,
+    0x00a64502, 0x00a64601, 0x00a64702, 0x00a64801, 0x00a64902, 0x00a64a01, 0x00a64b02, 0x00a64c01,
+    0x00a64d02, 0x00a64e01, 0x00a64f02, 0x00a65001, 0x00a65102, 0x00a65201, 0x00a65302, 0x00a65401,
+    0x00a65502, 0x00a65601, 0x00a65702, 0x00a65801, 0x00a65902, 0x00a65a01, 0x00a65b02, 0x00a65c01,
+    0x00a65d02, 0x00a65e01, 0x00a65f02, 0x00a66001, 0x00a66102, 0x00a66201, 0x00a66302, 0x00a66401,
+    0x00a66502, 0x00a66601, 0x00a66702, 0x00a66801, 0x00a66902, 0x00a66a01, 0x00a66b02, 0x00a66c01,
+    0x00a66d02, 0x00a66e05, 0x00a66f06, 0x00a67008, 0x00a67312, 0x00a67406, 0x00a67e12, 0x00a67f04,
+    0x00a68001, 0x00a68102, 0x00a68201, 0x00a68302, 0x00a68401, 0x00a68502, 0x00a68601, 0x00a68702,
+    0x00a68801, 0x00a68902, 0x00a68a01, 0x00a68b02, 0x00a68c01, 0x00a68d02, 0x00a68e01, 0x00a68f02,
+    0x00a69001, 0x00a69102, 0x00a69201, 0x00a69302, 0x00a69401, 0x00a69502, 0x00a69601, 0x00a69702,
+    0x00a69801, 0x00a69902, 0x00a69a01, 0x00a69b02, 0x00a69c04, 0x00a69e06, 0x00a6a005, 0x00a6e60a,
+    0x00a6f006, 0x00a6f212, 0x00a6f800, 0x00a70015, 0x00a71704, 0x00a72015, 0x00a72201, 0x00a72302,
+    0x00a72401, 0x00a72502, 0x00a72601, 0x00a72702, 0x00a72801, 0x00a72902, 0x00a72a01, 0x00a72b02,
+    0x00a72c01, 0x00a72d02, 0x00a72e01, 0x00a72f02, 0x00a73201, 0x00a73302, 0x00a73401, 0x00a73502,
+    0x00a73601, 0x00a73702, 0x00a73801, 0x00a73902, 0x00a73a01, 0x00a73b02, 0x00a73c01, 0x00a73d02,
+    0x00a73e01, 0x00a73f02, 0x00a74001, 0x00a74102, 0x00a74201, 0x00a74302, 0x00a74401, 0x00a74502,
+    0x00a74601, 0x00a74702, 0x00a74801, 0x00a74902, 0x00a74a01, 0x00a74b02, 0x00a74c01, 0x00a74d02,
+    0x00a74e01, 0x00a74f02, 0x00a75001, 0x00a75102, 0x00a75201, 0x00a75302, 0x00a75401, 0x00a75502,
+    0x00a75601, 0x00a75702, 0x00a75801, 0x00a75902, 0x00a75a01, 0x00a75b02, 0x00a75c01, 0x00a75d02,
+    0x00a75e01, 0x00a75f02, 0x00a76001, 0x00a76102, 0x00a76201, 0x00a76302, 0x00a76401, 0x00a76502,
+    0x00a76601, 0x00a76702, 0x00a76801, 0x00a76902, 0x00a76a01, 0x00a76b02, 0x00a76c01, 0x00a76d02,
+    0x00a76e01, 0x00a76f02, 0x00a77004, 0x00a77102, 0x00a77901, 0x00a77a02, 0x00a77b01, 0x00a77c02,
+    0x00a77d01, 0x00a77f02, 0x00a78001, 0x00a78102, 0x00a78201, 0x00a78302, 0x00a78401, 0x00a78502,
+    0x00a78601, 0x00a78702, 0x00a78804, 0x00a78915, 0x00a78b01, 0x00a78c02, 0x00a78d01, 0x00a78e02,
+    0x00a78f05, 0x00a79001, 0x00a79102, 0x00a79201, 0x00a79302, 0x00a79601, 0x00a79702, 0x00a79801,
+    0x00a79902, 0x00a79a01, 0x00a79b02, 0x00a79c01, 0x00a79d02, 0x00a79e01, 0x00a79f02, 0x00a7a001,
+    0x00a7a102, 0x00a7a201, 0x00a7a302, 0x00a7a401, 0x00a7a502, 0x00a7a601, 0x00a7a702, 0x00a7a801,
+    0x00a7a902, 0x00a7aa01, 0x00a7af02, 0x00a7b001, 0x00a7b502, 0x00a7b601, 0x00a7b702, 0x00a7b801,
+    0x00a7b902, 0x00a7ba01, 0x00a7bb02, 0x00a7bc01, 0x00a7bd02, 0x00a7be01, 0x00a7bf02, 0x00a7c001,
+    0x00a7c102, 0x00a7c201, 0x00a7c302, 0x00a7c401, 0x00a7c802, 0x00a7c901, 0x00a7ca02, 0x00a7cb01,
+    0x00a7cd02, 0x00a7ce00, 0x00a7d001, 0x00a7d102, 0x00a7d200, 0x00a7d302, 0x00a7d400, 0x00a7d502,
+    0x00a7d601, 0x00a7d702, 0x00a7d801, 0x00a7d902, 0x00a7da01, 0x00a7db02, 0x00a7dc01, 0x00a7dd00,
+    0x00a7f204, 0x00a7f501, 0x00a7f602, 0x00a7f705, 0x00a7f804, 0x00a7fa02, 0x00a7fb05, 0x00a80206,
+    0x00a80305, 0x00a80606, 0x00a80705, 0x00a80b06, 0x00a80c05, 0x00a82307, 0x00a82506, 0x00a82707,
+    0x00a82816, 0x00a82c06, 0x00a82d00, 0x00a8300b, 0x00a83616, 0x00a83814, 0x00a83916, 0x00a83a00,
+    0x00a84005, 0x00a87412, 0x00a87800, 0x00a88007, 0x00a88205, 0x00a8b407, 0x00a8c406, 0x00a8c600,
+    0x00a8ce12, 0x00a8d009, 0x00a8da00, 0x00a8e006, 0x00a8f205, 0x00a8f812, 0x00a8fb05, 0x00a8fc12,
+    0x00a8fd05, 0x00a8ff06, 0x00a90009, 0x00a90a05, 0x00a92606, 0x00a92e12, 0x00a93005, 0x00a94706,
+    0x00a95207, 0x00a95400, 0x00a95f12, 0x00a96005, 0x00a97d00, 0x00a98006, 0x00a98307, 0x00a98405,
+    0x00a9b306, 0x00a9b407, 0x00a9b606, 0x00a9ba07, 0x00a9bc06, 0x00a9be07, 0x00a9c112, 0x00a9ce00,
+    0x00a9cf04, 0x00a9d009, 0x00a9da00, 0x00a9de12, 0x00a9e005, 0x00a9e506, 0x00a9e604, 0x00a9e705,
+    0x00a9f009, 0x00a9fa05, 0x00a9ff00, 0x00aa0005, 0x00aa2906, 0x00aa2f07, 0x00aa3106, 0x00aa3307,
+    0x00aa3506, 0x00aa3700, 0x00aa4005, 0x00aa4306, 0x00aa4405, 0x00aa4c06, 0x00aa4d07, 0x00aa4e00,
+    0x00aa5009, 0x00aa5a00, 0x00aa5c12, 0x00aa6005, 0x00aa7004, 0x00aa7105, 0x00aa7716, 0x00aa7a05,
+    0x00aa7b07, 0x00aa7c06, 0x00aa7d07, 0x00aa7e05, 0x00aab006, 0x00aab105, 0x00aab206, 0x00aab505,
+    0x00aab706, 0x00aab905, 0x00aabe06, 0x00aac005, 0x00aac106, 0x00aac205, 0x00aac300, 0x00aadb05,
+    0x00aadd04, 0x00aade12, 0x00aae005, 0x00aaeb07, 0x00aaec06, 0x00aaee07, 0x00aaf012, 0x00aaf205,
+    0x00aaf304, 0x00aaf507, 0x00aaf606, 0x00aaf700, 0x00ab0105, 0x00ab0700, 0x00ab0905, 0x00ab0f00,
+    0x00ab1105, 0x00ab1700, 0x00ab2005, 0x00ab2700, 0x00ab2805, 0x00ab2f00, 0x00ab3002, 0x00ab5b15,
+    0x00ab5c04, 0x00ab6002, 0x00ab6904, 0x00ab6a15, 0x00ab6c00, 0x00ab7002, 0x00abc005, 0x00abe307,
+    0x00abe506, 0x00abe607, 0x00abe806, 0x00abe907, 0x00abeb12, 0x00abec07, 0x00abed06, 0x00abee00,
+    0x00abf009, 0x00abfa00, 0x00ac0005, 0x00d7a400, 0x00d7b005, 0x00d7c700, 0x00d7cb05, 0x00d7fc00,
+    0x00d8001c, 0x00e0001d, 0x00f90005, 0x00fa6e00, 0x00fa7005, 0x00fada00, 0x00fb0002, 0x00fb0700,
+    0x00fb1302, 0x00fb1800, 0x00fb1d05, 0x00fb1e06, 0x00fb1f05, 0x00fb2913, 0x00fb2a05, 0x00fb3700,
+    0x00fb3805, 0x00fb3d00, 0x00fb3e05, 0x00fb3f00, 0x00fb4005, 0x00fb4200, 0x00fb4305, 0x00fb4500,
+    0x00fb4605, 0x00fbb215, 0x00fbc300, 0x00fbd305, 0x00fd3e0f, 0x00fd3f0e, 0x00fd4016, 0x00fd5005,
+    0x00fd9000, 0x00fd9205, 0x00fdc800, 0x00fdcf16, 0x00fdd000, 0x00fdf005, 0x00fdfc14, 0x00fdfd16,
+    0x00fe0006, 0x00fe1012, 0x00fe170e, 0x00fe180f, 0x00fe1912, 0x00fe1a00, 0x00fe2006, 0x00fe3012,
+    0x00fe310d, 0x00fe330c, 0x00fe350e, 0x00fe360f, 0x00fe370e, 0x00fe380f, 0x00fe390e, 0x00fe3a0f,
+    0x00fe3b0e, 0x00fe3c0f, 0x00fe3d0e, 0x00fe3e0f, 0x00fe3f0e, 0x00fe400f, 0x00fe410e, 0x00fe420f,
+    0x00fe430e, 0x00fe440f, 0x00fe4512, 0x00fe470e, 0x00fe480f, 0x00fe4912, 0x00fe4d0c, 0x00fe5012,
+    0x00fe5300, 0x00fe5412, 0x00fe580d, 0x00fe590e, 0x00fe5a0f, 0x00fe5b0e, 0x00fe5c0f, 0x00fe5d0e,
+    0x00fe5e0f, 0x00fe5f12, 0x00fe6213, 0x00fe630d, 0x00fe6413, 0x00fe6700, 0x00fe6812, 0x00fe6914,
+    0x00fe6a12, 0x00fe6c00, 0x00fe7005, 0x00fe7500, 0x00fe7605, 0x00fefd00, 0x00feff1b, 0x00ff0000,
+    0x00ff0112, 0x00ff0414, 0x00ff0512, 0x00ff080e, 0x00ff090f, 0x00ff0a12, 0x00ff0b13, 0x00ff0c12,
+    0x00ff0d0d, 0x00ff0e12, 0x00ff1009, 0x00ff1a12, 0x00ff1c13, 0x00ff1f12, 0x00ff2101, 0x00ff3b0e,
+    0x00ff3c12, 0x00ff3d0f, 0x00ff3e15, 0x00ff3f0c, 0x00ff4015, 0x00ff4102, 0x00ff5b0e, 0x00ff5c13,
+    0x00ff5d0f, 0x00ff5e13, 0x00ff5f0e, 0x00ff600f, 0x00ff6112, 0x00ff620e, 0x00ff630f, 0x00ff6412,
+    0x00ff6605, 0x00ff7004, 0x00ff7105, 0x00ff9e04, 0x00ffa005, 0x00ffbf00, 0x00ffc205, 0x00ffc800,
+    0x00ffca05, 0x00ffd000, 0x00ffd205, 0x00ffd800, 0x00ffda05, 0x00ffdd00, 0x00ffe014, 0x00ffe213,
+    0x00ffe315, 0x00ffe416, 0x00ffe514, 0x00ffe700, 0x00ffe816, 0x00ffe913, 0x00ffed16, 0x00ffef00,
+    0x00fff91b, 0x00fffc16, 0x00fffe00, 0x01000005, 0x01000c00, 0x01000d05, 0x01002700, 0x01002805,
+    0x01003b00, 0x01003c05, 0x01003e00, 0x01003f05, 0x01004e00, 0x01005005, 0x01005e00, 0x01008005,
+    0x0100fb00, 0x01010012, 0x01010300, 0x0101070b, 0x01013400, 0x01013716, 0x0101400a, 0x0101750b,
+    0x01017916, 0x01018a0b, 0x01018c16, 0x01018f00, 0x01019016, 0x01019d00, 0x0101a016, 0x0101a100,
+    0x0101d016, 0x0101fd06, 0x0101fe00, 0x01028005, 0x01029d00, 0x0102a005, 0x0102d100, 0x0102e006,
+    0x0102e10b, 0x0102fc00, 0x01030005, 0x0103200b, 0x01032400, 0x01032d05, 0x0103410a, 0x01034205,
+    0x01034a0a, 0x01034b00, 0x01035005, 0x01037606, 0x01037b00, 0x01038005, 0x01039e00, 0x01039f12,
+    0x0103a005, 0x0103c400, 0x0103c805, 0x0103d012, 0x0103d10a, 0x0103d600, 0x01040001, 0x01042802,
+    0x01045005, 0x01049e00, 0x0104a009, 0x0104aa00, 0x0104b001, 0x0104d400, 0x0104d802, 0x0104fc00,
+    0x01050005, 0x01052800, 0x01053005, 0x01056400, 0x01056f12, 0x01057001, 0x01057b00, 0x01057c01,
+    0x01058b00, 0x01058c01, 0x01059300, 0x01059401, 0x01059600, 0x01059702, 0x0105a200, 0x0105a302,
+    0x0105b200, 0x0105b302, 0x0105ba00, 0x0105bb02, 0x0105bd00, 0x0105c005, 0x0105f400, 0x01060005,
+    0x01073700, 0x01074005, 0x01075600, 0x01076005, 0x01076800, 0x01078004, 0x01078600, 0x01078704,
+    0x0107b100, 0x0107b204, 0x0107bb00, 0x01080005, 0x01080600, 0x01080805, 0x01080900, 0x01080a05,
+    0x01083600, 0x01083705, 0x01083900, 0x01083c05, 0x01083d00, 0x01083f05, 0x01085600, 0x01085712,
+    0x0108580b, 0x01086005, 0x01087716, 0x0108790b, 0x01088005, 0x01089f00, 0x0108a70b, 0x0108b000,
+    0x0108e005, 0x0108f300, 0x0108f405, 0x0108f600, 0x0108fb0b, 0x01090005, 0x0109160b, 0x01091c00,
+    0x01091f12, 0x01092005, 0x01093a00, 0x01093f12, 0x01094000, 0x01098005, 0x0109b800, 0x0109bc0b,
+    0x0109be05, 0x0109c00b, 0x0109d000, 0x0109d20b, 0x010a0005, 0x010a0106, 0x010a0400, 0x010a0506,
+    0x010a0700, 0x010a0c06, 0x010a1005, 0x010a1400, 0x010a1505, 0x010a1800, 0x010a1905, 0x010a3600,
+    0x010a3806, 0x010a3b00, 0x010a3f06, 0x010a400b, 0x010a4900, 0x010a5012, 0x010a5900, 0x010a6005,
+    0x010a7d0b, 0x010a7f12, 0x010a8005, 0x010a9d0b, 0x010aa000, 0x010ac005, 0x010ac816, 0x010ac905,
+    0x010ae506, 0x010ae700, 0x010aeb0b, 0x010af012, 0x010af700, 0x010b0005, 0x010b3600, 0x010b3912,
+    0x010b4005, 0x010b5600, 0x010b580b, 0x010b6005, 0x010b7300, 0x010b780b, 0x010b8005, 0x010b9200,
+    0x010b9912, 0x010b9d00, 0x010ba90b, 0x010bb000, 0x010c0005, 0x010c4900, 0x010c8001, 0x010cb300,
+    0x010cc002, 0x010cf300, 0x010cfa0b, 0x010d0005, 0x010d2406, 0x010d2800, 0x010d3009, 0x010d3a00,
+    0x010d4009, 0x010d4a05, 0x010d4e04, 0x010d4f05, 0x010d5001, 0x010d6600, 0x010d6906, 0x010d6e0d,
+    0x010d6f04, 0x010d7002, 0x010d8600, 0x010d8e13, 0x010d9000, 0x010e600b, 0x010e7f00, 0x010e8005,
+    0x010eaa00, 0x010eab06, 0x010ead0d, 0x010eae00, 0x010eb005, 0x010eb200, 0x010ec205, 0x010ec500,
+    0x010efc06, 0x010f0005, 0x010f1d0b, 0x010f2705, 0x010f2800, 0x010f3005, 0x010f4606, 0x010f510b,
+    0x010f5512, 0x010f5a00, 0x010f7005, 0x010f8206, 0x010f8612, 0x010f8a00, 0x010fb005, 0x010fc50b,
+    0x010fcc00, 0x010fe005, 0x010ff700, 0x01100007, 0x01100106, 0x01100207, 0x01100305, 0x01103806,
+    0x01104712, 0x01104e00, 0x0110520b, 0x01106609, 0x01107006, 0x01107105, 0x01107306, 0x01107505,
+    0x01107600, 0x01107f06, 0x01108207, 0x01108305, 0x0110b007, 0x0110b306, 0x0110b707, 0x0110b906,
+    0x0110bb12, 0x0110bd1b, 0x0110be12, 0x0110c206, 0x0110c300, 0x0110cd1b, 0x0110ce00, 0x0110d005,
+    0x0110e900, 0x0110f009, 0x0110fa00, 0x01110006, 0x01110305, 0x01112706, 0x01112c07, 0x01112d06,
+    0x01113500, 0x01113609, 0x01114012, 0x01114405, 0x01114507, 0x01114705, 0x01114800, 0x01115005,
+    0x01117306, 0x01117412, 0x01117605, 0x01117700, 0x01118006, 0x01118207, 0x01118305, 0x0111b307,
+    0x0111b606, 0x0111bf07, 0x0111c105, 0x0111c512, 0x0111c906, 0x0111cd12, 0x0111ce07, 0x0111cf06,
+    0x0111d009, 0x0111da05, 0x0111db12, 0x0111dc05, 0x0111dd12, 0x0111e000, 0x0111e10b, 0x0111f500,
+    0x01120005, 0x01121200, 0x01121305, 0x01122c07, 0x01122f06, 0x01123207, 0x01123406, 0x01123507,
+    0x01123606, 0x01123812, 0x01123e06, 0x01123f05, 0x01124106, 0x01124200, 0x01128005, 0x01128700,
+    0x01128805, 0x01128900, 0x01128a05, 0x01128e00, 0x01128f05, 0x01129e00, 0x01129f05, 0x0112a912,
+    0x0112aa00, 0x0112b005, 0x0112df06, 0x0112e007, 0x0112e306, 0x0112eb00, 0x0112f009, 0x0112fa00,
+    0x01130006, 0x01130207, 0x01130400, 0x01130505, 0x01130d00, 0x01130f05, 0x01131100, 0x01131305,
+    0x01132900, 0x01132a05, 0x01133100, 0x01133205, 0x01133400, 0x01133505, 0x01133a00, 0x01133b06,
+    0x01133d05, 0x01133e07, 0x01134006, 0x01134107, 0x01134500, 0x01134707, 0x01134900, 0x01134b07,
+    0x01134e00, 0x01135005, 0x01135100, 0x01135707, 0x01135800, 0x01135d05, 0x01136207, 0x01136400,
+    0x01136606, 0x01136d00, 0x01137006, 0x01137500, 0x01138005, 0x01138a00, 0x01138b05, 0x01138c00,
+    0x01138e05, 0x01138f00, 0x01139005, 0x0113b600, 0x0113b705, 0x0113b807, 0x0113bb06, 0x0113c100,
+    0x0113c207, 0x0113c300, 0x0113c507, 0x0113c600, 0x0113c707, 0x0113cb00, 0x0113cc07, 0x0113ce06,
+    0x0113cf07, 0x0113d006, 0x0113d105, 0x0113d206, 0x0113d305, 0x0113d412, 0x0113d600, 0x0113d712,
+    0x0113d900, 0x0113e106, 0x0113e300, 0x01140005, 0x01143507, 0x01143806, 0x01144007, 0x01144206,
+    0x01144507, 0x01144606, 0x01144705, 0x01144b12, 0x01145009, 0x01145a12, 0x01145c00, 0x01145d12,
+    0x01145e06, 0x01145f05, 0x01146200, 0x01148005, 0x0114b007, 0x0114b306, 0x0114b907, 0x0114ba06,
+    0x0114bb07, 0x0114bf06, 0x0114c107, 0x0114c206, 0x0114c405, 0x0114c612, 0x0114c705, 0x0114c800,
+    0x0114d009, 0x0114da00, 0x01158005, 0x0115af07, 0x0115b206, 0x0115b600, 0x0115b807, 0x0115bc06,
+    0x0115be07, 0x0115bf06, 0x0115c112, 0x0115d805, 0x0115dc06, 0x0115de00, 0x01160005, 0x01163007,
+    0x01163306, 0x01163b07, 0x01163d06, 0x01163e07, 0x01163f06, 0x01164112, 0x01164405, 0x01164500,
+    0x01165009, 0x01165a00, 0x01166012, 0x01166d00, 0x01168005, 0x0116ab06, 0x0116ac07, 0x0116ad06,
+    0x0116ae07, 0x0116b006, 0x0116b607, 0x0116b706, 0x0116b805, 0x0116b912, 0x0116ba00, 0x0116c009,
+    0x0116ca00, 0x0116d009, 0x0116e400, 0x01170005, 0x01171b00, 0x01171d06, 0x01172007, 0x01172206,
+    0x01172607, 0x01172706, 0x01172c00, 0x01173009, 0x01173a0b, 0x01173c12, 0x01173f16, 0x01174005,
+    0x01174700, 0x01180005, 0x01182c07, 0x01182f06, 0x01183807, 0x01183906, 0x01183b12, 0x01183c00,
+    0x0118a001, 0x0118c002, 0x0118e009, 0x0118ea0b, 0x0118f300, 0x0118ff05, 0x01190700, 0x01190905,
+    0x01190a00, 0x01190c05, 0x01191400, 0x01191505, 0x01191700, 0x01191805, 0x01193007, 0x01193600,
+    0x01193707, 0x01193900, 0x01193b06, 0x01193d07, 0x01193e06, 0x01193f05, 0x01194007, 0x01194105,
+    0x01194207, 0x01194306, 0x01194412, 0x01194700, 0x01195009, 0x01195a00, 0x0119a005, 0x0119a800,
+    0x0119aa05, 0x0119d107, 0x0119d406, 0x0119d800, 0x0119da06, 0x0119dc07, 0x0119e006, 0x0119e105,
+    0x0119e212, 0x0119e305, 0x0119e407, 0x0119e500, 0x011a0005, 0x011a0106, 0x011a0b05, 0x011a3306,
+    0x011a3907, 0x011a3a05, 0x011a3b06, 0x011a3f12, 0x011a4706, 0x011a4800, 0x011a5005, 0x011a5106,
+    0x011a5707, 0x011a5906, 0x011a5c05, 0x011a8a06, 0x011a9707, 0x011a9806, 0x011a9a12, 0x011a9d05,
+    0x011a9e12, 0x011aa300, 0x011ab005, 0x011af900, 0x011b0012, 0x011b0a00, 0x011bc005, 0x011be112,
+    0x011be200, 0x011bf009, 0x011bfa00, 0x011c0005, 0x011c0900, 0x011c0a05, 0x011c2f07, 0x011c3006,
+    0x011c3700, 0x011c3806, 0x011c3e07, 0x011c3f06, 0x011c4005, 0x011c4112, 0x011c4600, 0x011c5009,
+    0x011c5a0b, 0x011c6d00, 0x011c7012, 0x011c7205, 0x011c9000, 0x011c9206, 0x011ca800, 0x011ca907,
+    0x011caa06, 0x011cb107, 0x011cb206, 0x011cb407, 0x011cb506, 0x011cb700, 0x011d0005, 0x011d0700,
+    0x011d0805, 0x011d0a00, 0x011d0b05, 0x011d3106, 0x011d3700, 0x011d3a06, 0x011d3b00, 0x011d3c06,
+    0x011d3e00, 0x011d3f06, 0x011d4605, 0x011d4706, 0x011d4800, 0x011d5009, 0x011d5a00, 0x011d6005,
+    0x011d6600, 0x011d6705, 0x011d6900, 0x011d6a05, 0x011d8a07, 0x011d8f00, 0x011d9006, 0x011d9200,
+    0x011d9307, 0x011d9506, 0x011d9607, 0x011d9706, 0x011d9805, 0x011d9900, 0x011da009, 0x011daa00,
+    0x011ee005, 0x011ef306, 0x011ef507, 0x011ef712, 0x011ef900, 0x011f0006, 0x011f0205, 0x011f0307,
+    0x011f0405, 0x011f1100, 0x011f1205, 0x011f3407, 0x011f3606, 0x011f3b00, 0x011f3e07, 0x011f4006,
+    0x011f4107, 0x011f4206, 0x011f4312, 0x011f5009, 0x011f5a06, 0x011f5b00, 0x011fb005, 0x011fb100,
+    0x011fc00b, 0x011fd516, 0x011fdd14, 0x011fe116, 0x011ff200, 0x011fff12, 0x01200005, 0x01239a00,
+    0x0124000a, 0x01246f00, 0x01247012, 0x01247500, 0x01248005, 0x01254400, 0x012f9005, 0x012ff112,
+    0x012ff300, 0x01300005, 0x0134301b, 0x01344006, 0x01344105, 0x01344706, 0x01345600, 0x01346005,
+    0x0143fb00, 0x01440005, 0x01464700, 0x01610005, 0x01611e06, 0x01612a07, 0x01612d06, 0x01613009,
+    0x01613a00, 0x01680005, 0x016a3900, 0x016a4005, 0x016a5f00, 0x016a6009, 0x016a6a00, 0x016a6e12,
+    0x016a7005, 0x016abf00, 0x016ac009, 0x016aca00, 0x016ad005, 0x016aee00, 0x016af006, 0x016af512,
+    0x016af600, 0x016b0005, 0x016b3006, 0x016b3712, 0x016b3c16, 0x016b4004, 0x016b4412, 0x016b4516,
+    0x016b4600, 0x016b5009, 0x016b5a00, 0x016b5b0b, 0x016b6200, 0x016b6305, 0x016b7800, 0x016b7d05,
+    0x016b9000, 0x016d4004, 0x016d4305, 0x016d6b04, 0x016d6d12, 0x016d7009, 0x016d7a00, 0x016e4001,
+    0x016e6002, 0x016e800b, 0x016e9712, 0x016e9b00, 0x016f0005, 0x016f4b00, 0x016f4f06, 0x016f5005,
+    0x016f5107, 0x016f8800, 0x016f8f06, 0x016f9304, 0x016fa000, 0x016fe004, 0x016fe212, 0x016fe304,
+    0x016fe406, 0x016fe500, 0x016ff007, 0x016ff200, 0x01700005, 0x0187f800, 0x01880005, 0x018cd600,
+    0x018cff05, 0x018d0900, 0x01aff004, 0x01aff400, 0x01aff504, 0x01affc00, 0x01affd04, 0x01afff00,
+    0x01b00005, 0x01b12300, 0x01b13205, 0x01b13300, 0x01b15005, 0x01b15300, 0x01b15505, 0x01b15600,
+    0x01b16405, 0x01b16800, 0x01b17005, 0x01b2fc00, 0x01bc0005, 0x01bc6b00, 0x01bc7005, 0x01bc7d00,
+    0x01bc8005, 0x01bc8900, 0x01bc9005, 0x01bc9a00, 0x01bc9c16, 0x01bc9d06, 0x01bc9f12, 0x01bca01b,
+    0x01bca400, 0x01cc0016, 0x01ccf009, 0x01ccfa00, 0x01cd0016, 0x01ceb400, 0x01cf0006, 0x01cf2e00,
+    0x01cf3006, 0x01cf4700, 0x01cf5016, 0x01cfc400, 0x01d00016, 0x01d0f600, 0x01d10016, 0x01d12700,
+    0x01d12916, 0x01d16507, 0x01d16706, 0x01d16a16, 0x01d16d07, 0x01d1731b, 0x01d17b06, 0x01d18316,
+    0x01d18506, 0x01d18c16, 0x01d1aa06, 0x01d1ae16, 0x01d1eb00, 0x01d20016, 0x01d24206, 0x01d24516,
+    0x01d24600, 0x01d2c00b, 0x01d2d400, 0x01d2e00b, 0x01d2f400, 0x01d30016, 0x01d35700, 0x01d3600b,
+    0x01d37900, 0x01d40001, 0x01d41a02, 0x01d43401, 0x01d44e02, 0x01d45500, 0x01d45602, 0x01d46801,
+    0x01d48202, 0x01d49c01, 0x01d49d00, 0x01d49e01, 0x01d4a000, 0x01d4a201, 0x01d4a300, 0x01d4a501,
+    0x01d4a700, 0x01d4a901, 0x01d4ad00, 0x01d4ae01, 0x01d4b602, 0x01d4ba00, 0x01d4bb02, 0x01d4bc00,
+    0x01d4bd02, 0x01d4c400, 0x01d4c502, 0x01d4d001, 0x01d4ea02, 0x01d50401, 0x01d50600, 0x01d50701,
+    0x01d50b00, 0x01d50d01, 0x01d51500, 0x01d51601, 0x01d51d00, 0x01d51e02, 0x01d53801, 0x01d53a00,
+    0x01d53b01, 0x01d53f00, 0x01d54001, 0x01d54500, 0x01d54601, 0x01d54700, 0x01d54a01, 0x01d55100,
+    0x01d55202, 0x01d56c01, 0x01d58602, 0x01d5a001, 0x01d5ba02, 0x01d5d401, 0x01d5ee02, 0x01d60801,
+    0x01d62202, 0x01d63c01, 0x01d65602, 0x01d67001, 0x01d68a02, 0x01d6a600, 0x01d6a801, 0x01d6c113,
+    0x01d6c202, 0x01d6db13, 0x01d6dc02, 0x01d6e201, 0x01d6fb13, 0x01d6fc02, 0x01d71513, 0x01d71602,
+    0x01d71c01, 0x01d73513, 0x01d73602, 0x01d74f13, 0x01d75002, 0x01d75601, 0x01d76f13, 0x01d77002,
+    0x01d78913, 0x01d78a02, 0x01d79001, 0x01d7a913, 0x01d7aa02, 0x01d7c313, 0x01d7c402, 0x01d7ca01,
+    0x01d7cb02, 0x01d7cc00, 0x01d7ce09, 0x01d80016, 0x01da0006, 0x01da3716, 0x01da3b06, 0x01da6d16,
+    0x01da7506, 0x01da7616, 0x01da8406, 0x01da8516, 0x01da8712, 0x01da8c00, 0x01da9b06, 0x01daa000,
+    0x01daa106, 0x01dab000, 0x01df0002, 0x01df0a05, 0x01df0b02, 0x01df1f00, 0x01df2502, 0x01df2b00,
+    0x01e00006, 0x01e00700, 0x01e00806, 0x01e01900, 0x01e01b06, 0x01e02200, 0x01e02306, 0x01e02500,
+    0x01e02606, 0x01e02b00, 0x01e03004, 0x01e06e00, 0x01e08f06, 0x01e09000, 0x01e10005, 0x01e12d00,
+    0x01e13006, 0x01e13704, 0x01e13e00, 0x01e14009, 0x01e14a00, 0x01e14e05, 0x01e14f16, 0x01e15000,
+    0x01e29005, 0x01e2ae06, 0x01e2af00, 0x01e2c005, 0x01e2ec06, 0x01e2f009, 0x01e2fa00, 0x01e2ff14,
+    0x01e30000, 0x01e4d005, 0x01e4eb04, 0x01e4ec06, 0x01e4f009, 0x01e4fa00, 0x01e5d005, 0x01e5ee06,
+    0x01e5f005, 0x01e5f109, 0x01e5fb00, 0x01e5ff12, 0x01e60000, 0x01e7e005, 0x01e7e700, 0x01e7e805,
+    0x01e7ec00, 0x01e7ed05, 0x01e7ef00, 0x01e7f005, 0x01e7ff00, 0x01e80005, 0x01e8c500, 0x01e8c70b,
+    0x01e8d006, 0x01e8d700, 0x01e90001, 0x01e92202, 0x01e94406, 0x01e94b04, 0x01e94c00, 0x01e95009,
+    0x01e95a00, 0x01e95e12, 0x01e96000, 0x01ec710b, 0x01ecac16, 0x01ecad0b, 0x01ecb014, 0x01ecb10b,
+    0x01ecb500, 0x01ed010b, 0x01ed2e16, 0x01ed2f0b, 0x01ed3e00, 0x01ee0005, 0x01ee0400, 0x01ee0505,
+    0x01ee2000, 0x01ee2105, 0x01ee2300, 0x01ee2405, 0x01ee2500, 0x01ee2705, 0x01ee2800, 0x01ee2905,
+    0x01ee3300, 0x01ee3405, 0x01ee3800, 0x01ee3905, 0x01ee3a00, 0x01ee3b05, 0x01ee3c00, 0x01ee4205,
+    0x01ee4300, 0x01ee4705, 0x01ee4800, 0x01ee4905, 0x01ee4a00, 0x01ee4b05, 0x01ee4c00, 0x01ee4d05,
+    0x01ee5000, 0x01ee5105, 0x01ee5300, 0x01ee5405, 0x01ee5500, 0x01ee5705, 0x01ee5800, 0x01ee5905,
+    0x01ee5a00, 0x01ee5b05, 0x01ee5c00, 0x01ee5d05, 0x01ee5e00, 0x01ee5f05, 0x01ee6000, 0x01ee6105,
+    0x01ee6300, 0x01ee6405, 0x01ee6500, 0x01ee6705, 0x01ee6b00, 0x01ee6c05, 0x01ee7300, 0x01ee7405,
+    0x01ee7800, 0x01ee7905, 0x01ee7d00, 0x01ee7e05, 0x01ee7f00, 0x01ee8005, 0x01ee8a00, 0x01ee8b05,
+    0x01ee9c00, 0x01eea105, 0x01eea400, 0x01eea505, 0x01eeaa00, 0x01eeab05, 0x01eebc00, 0x01eef013,
+    0x01eef200, 0x01f00016, 0x01f02c00, 0x01f03016, 0x01f09400, 0x01f0a016, 0x01f0af00, 0x01f0b116,
+    0x01f0c000, 0x01f0c116, 0x01f0d000, 0x01f0d116, 0x01f0f600, 0x01f1000b, 0x01f10d16, 0x01f1ae00,
+    0x01f1e616, 0x01f20300, 0x01f21016, 0x01f23c00, 0x01f24016, 0x01f24900, 0x01f25016, 0x01f25200,
+    0x01f26016, 0x01f26600, 0x01f30016, 0x01f3fb15, 0x01f40016, 0x01f6d800, 0x01f6dc16, 0x01f6ed00,
+    0x01f6f016, 0x01f6fd00, 0x01f70016, 0x01f77700, 0x01f77b16, 0x01f7da00, 0x01f7e016, 0x01f7ec00,
+    0x01f7f016, 0x01f7f100, 0x01f80016, 0x01f80c00, 0x01f81016, 0x01f84800, 0x01f85016, 0x01f85a00,
+    0x01f86016, 0x01f88800, 0x01f89016, 0x01f8ae00, 0x01f8b016, 0x01f8bc00, 0x01f8c016, 0x01f8c200,
+    0x01f90016, 0x01fa5400, 0x01fa6016, 0x01fa6e00, 0x01fa7016, 0x01fa7d00, 0x01fa8016, 0x01fa8905,
+    0x01fa8a00, 0x01fa8f05, 0x01fa9016, 0x01fabe05, 0x01fabf16, 0x01fac605, 0x01fac700, 0x01face16,
+    0x01fadc05, 0x01fadd00, 0x01fadf05, 0x01fae016, 0x01fae905, 0x01faea00, 0x01faf016, 0x01faf900,
+    0x01fb0016, 0x01fb9300, 0x01fb9416, 0x01fbf009, 0x01fbfa00, 0x02000005, 0x02a6e000, 0x02a70005,
+    0x02b73a00, 0x02b74005, 0x02b81e00, 0x02b82005, 0x02cea200, 0x02ceb005, 0x02ebe100, 0x02ebf005,
+    0x02ee5e00, 0x02f80005, 0x02fa1e00, 0x03000005, 0x03134b00, 0x03135005, 0x0323b000, 0x0e00011b,
+    0x0e000200, 0x0e00201b, 0x0e008000, 0x0e010006, 0x0e01f000, 0x0f00001d, 0x0ffffe00, 0x1000001d,
+    0x10fffe00,
+];
@@ -0,0 +1,49 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn of_char() {
+    let cases = [
+        ('A', GeneralCategory::UppercaseLetter),
+        ('a', GeneralCategory::LowercaseLetter),
+        ('\u{01C5}', GeneralCategory::TitlecaseLetter),
+        ('中', GeneralCategory::OtherLetter),
+        ('\u{0301}', GeneralCategory::NonspacingMark),
+        ('7', GeneralCategory::DecimalNumber),
+        ('\u{2167}', GeneralCategory::LetterNumber),
+        ('_', GeneralCategory::ConnectorPunctuation),
+        ('(', GeneralCategory::OpenPunctuation),
+        ('!', GeneralCategory::OtherPunctuation),
+        ('+', GeneralCategory::MathSymbol),
+        ('$', GeneralCategory::CurrencySymbol),
+        ('\u{1F600}', GeneralCategory::OtherSymbol),
+        (' ', GeneralCategory::SpaceSeparator),
+        ('\u{2029}', GeneralCategory::ParagraphSeparator),
+        ('\n', GeneralCategory::Control),
+        ('\u{200D}', GeneralCategory::Format),
+        ('\u{E000}', GeneralCategory::PrivateUse),
+        ('\u{FFFF}', GeneralCategory::Unassigned),
+        ('\u{10FFFD}', GeneralCategory::PrivateUse),
+        ('\u{E0080}', GeneralCategory::Unassigned),
+    ];
+    for (c, want) in cases {
+        assert_eq!(GeneralCategory::of_char(c), want, "{c:?}");
+    }
+}
+
+#[test]
+fn classes() {
+    for (i, &category) in GeneralCategory::ALL.iter().enumerate() {
+        assert_eq!(category as usize, i);
+        let name = category.short_name();
+        let major = name.chars().next().unwrap();
+        assert_eq!(category.is_letter(), major == 'L', "{name}");
+        assert_eq!(category.is_mark(), major == 'M', "{name}");
+        assert_eq!(category.is_number(), major == 'N', "{name}");
+        assert_eq!(category.is_punctuation(), major == 'P', "{name}");
+        assert_eq!(category.is_symbol(), major == 'S', "{name}");
+        assert_eq!(category.is_separator(), major == 'Z', "{name}");
+        assert_eq!(category.is_other(), major == 'C', "{name}");
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::borrow::Cow;
+use core::iter::{Fuse, FusedIterator};
+use core::ops::Range;
+
+use ::u8char::AsU8Chars;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// Returns an iterator over the grapheme clusters in text that's stored as
+/// a series of non-contiguous `&str` chunks, such as the leaves of a rope
+/// or a sequence of network buffers.
+///
+/// Each cluster that lies entirely within one chunk is borrowed from that
+/// chunk, and so this allocates only for clusters that straddle a boundary
+/// between chunks. Empty chunks are allowed, and don't affect the result.
+///
+/// This is available only when the `alloc` feature is enabled.
+pub fn clusters_in_str_chunks<'a, I>(chunks: I) -> StrChunkClusters<'a, I::IntoIter>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    StrChunkClusters::with_machine(GraphemeMachine::new(), chunks)
+}
+
+/// An iterator over the grapheme clusters in a series of `&str` chunks,
+/// each as a [`Cow`] that borrows from the chunks where possible.
+///
+/// Construct this using [`clusters_in_str_chunks`], or using
+/// [`Self::with_machine`] to segment the chunks with a [`Segmenter`] other
+/// than [`GraphemeMachine`].
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct StrChunkClusters<'a, I, S = GraphemeMachine> {
+    machine: S,
+    chunks: Fuse<I>,
+    // The chunk currently being segmented.
+    chunk: &'a str,
+    // The start of the next cluster to return within the current chunk.
+    start: usize,
+    // The end of the characters in the current chunk already given to the
+    // machine, as for StrClusters.
+    scanned: usize,
+    // The part of the cluster in progress that came from earlier chunks,
+    // which stays borrowed for as long as it came from only one.
+    carry: Cow<'a, str>,
+}
+
+impl<'a, I, S> StrChunkClusters<'a, I, S>
+where
+    I: Iterator<Item = &'a str>,
+    S: Segmenter,
+{
+    /// Segments the given chunks using an existing machine, which should be
+    /// in its "start of input" state.
+    pub fn with_machine(machine: S, chunks: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            machine,
+            chunks: chunks.into_iter().fuse(),
+            chunk: "",
+            start: 0,
+            scanned: 0,
+            carry: Cow::Borrowed(""),
+        }
+    }
+
+    /// Converts this iterator into one that also reports the range of
+    /// bytes and the range of characters that each cluster occupies in the
+    /// text as a whole, counting from the start of the first chunk.
+    ///
+    /// Ropes and similar structures usually index their content by
+    /// character as well as by byte, and so the character ranges can be
+    /// passed to their slicing methods directly.
+    pub fn with_ranges(self) -> StrChunkClusterRanges<'a, I, S> {
+        StrChunkClusterRanges {
+            inner: self,
+            bytes: 0,
+            chars: 0,
+        }
+    }
+}
+
+impl<'a, I, S> Iterator for StrChunkClusters<'a, I, S>
+where
+    I: Iterator<Item = &'a str>,
+    S: Segmenter,
+{
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Cow<'a, str>> {
+        loop {
+            let chunk = self.chunk;
+            let start = self.start;
+            let mut end = self.scanned;
+            for c in chunk[self.scanned..].u8chars() {
+                let split = self.machine.next_u8char(c).is_boundary();
+                if split && (end > start || !self.carry.is_empty()) {
+                    self.start = end;
+                    self.scanned = end + c.len();
+                    append(&mut self.carry, &chunk[start..end]);
+                    return Some(core::mem::take(&mut self.carry));
+                }
+                end += c.len();
+            }
+            append(&mut self.carry, &chunk[start..]);
+            let Some(next) = self.chunks.next() else {
+                self.chunk = "";
+                self.start = 0;
+                self.scanned = 0;
+                self.machine.end_of_input();
+                if self.carry.is_empty() {
+                    return None;
+                }
+                return Some(core::mem::take(&mut self.carry));
+            };
+            self.chunk = next;
+            self.start = 0;
+            self.scanned = 0;
+        }
+    }
+}
+
+impl<'a, I, S> FusedIterator for StrChunkClusters<'a, I, S>
+where
+    I: Iterator<Item = &'a str>,
+    S: Segmenter,
+{
+}
+
+/// An iterator over the grapheme clusters in a series of `&str` chunks along
+/// with where each one lies in the text as a whole.
+///
+/// Construct this using [`StrChunkClusters::with_ranges`].
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct StrChunkClusterRanges<'a, I, S = GraphemeMachine> {
+    inner: StrChunkClusters<'a, I, S>,
+    bytes: usize,
+    chars: usize,
+}
+
+/// A grapheme cluster returned by [`StrChunkClusterRanges`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkCluster<'a> {
+    /// The text of the cluster, which is borrowed from its chunk unless it
+    /// straddles a boundary between chunks.
+    pub text: Cow<'a, str>,
+    /// The range of UTF-8 bytes that the cluster occupies.
+    pub bytes: Range<usize>,
+    /// The range of characters that the cluster occupies.
+    pub chars: Range<usize>,
+}
+
+impl<'a, I, S> Iterator for StrChunkClusterRanges<'a, I, S>
+where
+    I: Iterator<Item = &'a str>,
+    S: Segmenter,
+{
+    type Item = ChunkCluster<'a>;
+
+    fn next(&mut self) -> Option<ChunkCluster<'a>> {
+        let text = self.inner.next()?;
+        let bytes = self.bytes..self.bytes + text.len();
+        let chars = self.chars..self.chars + text.chars().count();
+        self.bytes = bytes.end;
+        self.chars = chars.end;
+        Some(ChunkCluster { text, bytes, chars })
+    }
+}
+
+impl<'a, I, S> FusedIterator for StrChunkClusterRanges<'a, I, S>
+where
+    I: Iterator<Item = &'a str>,
+    S: Segmenter,
+{
+}
+
+/// Appends `s` to `carry`, borrowing it instead if `carry` is empty.
+fn append<'a>(carry: &mut Cow<'a, str>, s: &'a str) {
+    if s.is_empty() {
+        return;
+    }
+    if carry.is_empty() {
+        *carry = Cow::Borrowed(s);
+    } else {
+        carry.to_mut().push_str(s);
+    }
+}
+
+#[cfg(test)]
+mod tests;
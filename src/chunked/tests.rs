@@ -0,0 +1,75 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn clusters<'a>(chunks: &[&'a str]) -> Vec<Cow<'a, str>> {
+    clusters_in_str_chunks(chunks.iter().copied()).collect()
+}
+
+#[test]
+fn borrows_within_chunks() {
+    let got = clusters(&["ab", "", "c\r\n"]);
+    assert_eq!(got, ["a", "b", "c", "\r\n"]);
+    assert!(
+        got.iter()
+            .all(|cluster| matches!(cluster, Cow::Borrowed(_)))
+    );
+}
+
+#[test]
+fn straddling_chunks() {
+    let got = clusters(&[
+        "a\u{1F1E6}",
+        "\u{1F1E8}b\r",
+        "",
+        "\n",
+        "e\u{301}",
+        "\u{302}",
+    ]);
+    assert_eq!(
+        got,
+        ["a", "\u{1F1E6}\u{1F1E8}", "b", "\r\n", "e\u{301}\u{302}"]
+    );
+    let owned: Vec<_> = got
+        .iter()
+        .map(|cluster| matches!(cluster, Cow::Owned(_)))
+        .collect();
+    assert_eq!(owned, [false, true, false, true, true]);
+}
+
+#[test]
+fn matches_contiguous() {
+    let s = "x\u{0915}\u{094D}\u{0924}\u{1F468}\u{200D}\u{1F469}\u{1F1E6}\u{1F1E8}\u{1F1E6}\r\n";
+    let want: Vec<_> = crate::clusters_in_str(s).collect();
+    for split in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+        let got = clusters(&[&s[..split], &s[split..]]);
+        assert_eq!(got, want, "split at {split}");
+    }
+}
+
+#[test]
+fn empty() {
+    assert_eq!(clusters(&[]), Vec::<Cow<'_, str>>::new());
+    assert_eq!(clusters(&["", ""]), Vec::<Cow<'_, str>>::new());
+    let mut iter = clusters_in_str_chunks(["a"]);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn with_ranges() {
+    let got: Vec<_> = clusters_in_str_chunks(["a\u{1F1E6}", "\u{1F1E8}\u{1F1E6}", "e\u{301}"])
+        .with_ranges()
+        .map(|cluster| (cluster.text.into_owned(), cluster.bytes, cluster.chars))
+        .collect();
+    assert_eq!(
+        got,
+        [
+            ("a".to_string(), 0..1, 0..1),
+            ("\u{1F1E6}\u{1F1E8}".to_string(), 1..9, 1..3),
+            ("\u{1F1E6}".to_string(), 9..13, 3..4),
+            ("e\u{301}".to_string(), 13..16, 4..6),
+        ]
+    );
+}
@@ -0,0 +1,96 @@
+use core::iter::{Fuse, FusedIterator};
+
+/// An iterator adapter that groups the items of some other iterator, such
+/// as the grapheme clusters from [`StrClusters`](crate::StrClusters), into
+/// chunks of up to `N` items at a time.
+///
+/// Every chunk except the last has exactly `N` items. This is useful for
+/// amortizing some per-item cost downstream, such as a display driver that
+/// draws several glyphs in one update. It doesn't allocate, and so is
+/// available without the `alloc` feature.
+#[derive(Debug, Clone)]
+pub struct ClusterChunks<I, const N: usize> {
+    inner: Fuse<I>,
+}
+
+impl<I: Iterator, const N: usize> ClusterChunks<I, N> {
+    /// Wraps the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero, because a chunk must hold at least one item.
+    pub fn new(inner: impl IntoIterator<IntoIter = I>) -> Self {
+        assert!(N > 0, "chunk size must be at least one");
+        Self {
+            inner: inner.into_iter().fuse(),
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ClusterChunks<I, N> {
+    type Item = ClusterChunk<I::Item, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let mut first = Some(first);
+        let items = core::array::from_fn(|_| first.take().or_else(|| self.inner.next()));
+        Some(ClusterChunk { items })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(N), upper.map(|n| n.div_ceil(N)))
+    }
+}
+
+impl<I: Iterator, const N: usize> FusedIterator for ClusterChunks<I, N> {}
+
+/// A group of up to `N` items produced by [`ClusterChunks`].
+///
+/// The items are stored as `[Option<T>; N]`, in which the present items
+/// always come before any absent ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterChunk<T, const N: usize> {
+    items: [Option<T>; N],
+}
+
+impl<T, const N: usize> ClusterChunk<T, N> {
+    /// Returns the number of items in the chunk.
+    pub fn len(&self) -> usize {
+        self.items.iter().take_while(|item| item.is_some()).count()
+    }
+
+    /// Returns `true` if the chunk has no items, which is never true of the
+    /// chunks returned by [`ClusterChunks`].
+    pub fn is_empty(&self) -> bool {
+        self.items.first().is_none_or(Option::is_none)
+    }
+
+    /// Returns the item at the given index, or `None` if the chunk doesn't
+    /// have that many items.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)?.as_ref()
+    }
+
+    /// Returns an iterator over the items in the chunk.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().map_while(Option::as_ref)
+    }
+
+    /// Returns the underlying array of items.
+    pub fn into_array(self) -> [Option<T>; N] {
+        self.items
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ClusterChunk<T, N> {
+    type Item = T;
+    type IntoIter = core::iter::Flatten<core::array::IntoIter<Option<T>, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests;
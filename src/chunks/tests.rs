@@ -0,0 +1,37 @@
+use super::*;
+use crate::clusters_in_str;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn chunks_of_clusters() {
+    let chunks: Vec<_> = clusters_in_str("ab\r\ne\u{0301}🇦🇧!")
+        .chunks::<2>()
+        .collect();
+    assert_eq!(
+        chunks
+            .iter()
+            .map(|c| c.iter().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        &[&["a", "b"][..], &["\r\n", "e\u{0301}"], &["🇦🇧", "!"]],
+    );
+
+    let mut chunks = clusters_in_str("abc").chunks::<2>();
+    assert_eq!(chunks.size_hint(), (1, Some(2)));
+    let first = chunks.next().unwrap();
+    assert_eq!((first.len(), first.is_empty()), (2, false));
+    let last = chunks.next().unwrap();
+    assert_eq!(last.len(), 1);
+    assert_eq!(last.get(0), Some(&"c"));
+    assert_eq!(last.get(1), None);
+    assert_eq!(last.clone().into_array(), [Some("c"), None]);
+    assert_eq!(last.into_iter().collect::<Vec<_>>(), &["c"]);
+    assert!(chunks.next().is_none());
+}
+
+#[test]
+fn exact_chunks() {
+    let chunks: Vec<_> = ClusterChunks::<_, 3>::new(0..6).map(|c| c.len()).collect();
+    assert_eq!(chunks, &[3, 3]);
+    assert_eq!(ClusterChunks::<_, 3>::new(0..0).next(), None);
+}
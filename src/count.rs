@@ -0,0 +1,149 @@
+use ::u8char::{AsU8Chars, u8char};
+use core::ops::Range;
+
+use crate::{ClusterAction, GraphemeMachine, SegmentAction, Segmenter};
+
+/// Returns the number of grapheme clusters in `s`.
+///
+/// This is the number of user-visible characters in the string, for
+/// enforcing limits such as a maximum length for a user name.
+pub fn grapheme_count(s: &str) -> usize {
+    GraphemeMachine::new()
+        .next_u8chars_from_str(s)
+        .filter(|(action, _)| *action == ClusterAction::Split)
+        .count()
+}
+
+/// Returns the longest prefix of `s` that contains at most `n` grapheme
+/// clusters.
+///
+/// This is for enforcing a maximum length measured in user-visible
+/// characters, such as for a user name, without splitting a character
+/// such as an emoji ZWJ sequence or a letter with combining accents.
+pub fn truncate_clusters(s: &str, n: usize) -> &str {
+    nth_cluster_offset(s, n).map_or(s, |end| &s[..end])
+}
+
+/// Returns the byte offset in `s` at which the grapheme cluster with index
+/// `n` begins, counting from zero.
+///
+/// If `n` is the number of clusters in `s` then the result is `s.len()`,
+/// so that the result can always be used as the end of a range of
+/// clusters. Returns `None` if `s` has fewer clusters than that.
+///
+/// This segments only as much of the string as it needs to, and so is
+/// suitable for indexing by cluster lazily without first collecting all of
+/// the boundaries.
+pub fn nth_cluster_offset(s: &str, n: usize) -> Option<usize> {
+    let mut count = 0;
+    let mut end = 0;
+    for (action, c) in GraphemeMachine::new().next_u8chars_from_str(s) {
+        if action == ClusterAction::Split {
+            if count == n {
+                return Some(end);
+            }
+            count += 1;
+        }
+        end += c.len();
+    }
+    (count == n).then_some(s.len())
+}
+
+/// Returns the range of bytes in `s` occupied by the range of grapheme
+/// clusters with the given indices, or `None` if the range is reversed or
+/// its end is beyond the number of clusters in `s`.
+///
+/// This is for operations like SQL's `SUBSTR` that select part of a string
+/// by cluster indices. As with [`nth_cluster_offset`], it segments only as
+/// far as the end of the range.
+pub fn cluster_range_offsets(s: &str, clusters: Range<usize>) -> Option<Range<usize>> {
+    let len = clusters.end.checked_sub(clusters.start)?;
+    let start = nth_cluster_offset(s, clusters.start)?;
+    // The start is at a boundary, so segmenting can begin there.
+    let end = start + nth_cluster_offset(&s[start..], len)?;
+    Some(start..end)
+}
+
+/// Counts the grapheme clusters in text that arrives incrementally, without
+/// keeping the text itself.
+///
+/// The count includes the cluster in progress, so it's the number of
+/// clusters that the text received so far would have if it ended there.
+/// Text that arrives later can only extend that cluster or begin new ones,
+/// and so the count never decreases, which makes it suitable for enforcing
+/// a maximum length on streaming input as soon as it's exceeded.
+#[derive(Debug)]
+pub struct ClusterCounter<S = GraphemeMachine> {
+    machine: S,
+    count: u64,
+}
+
+impl ClusterCounter {
+    /// Constructs a new counter in an initial "start of input" state, with
+    /// a count of zero.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl Default for ClusterCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Segmenter> ClusterCounter<S> {
+    /// Wraps the given machine, which should be in its "start of input"
+    /// state.
+    pub fn with_machine(machine: S) -> Self {
+        Self { machine, count: 0 }
+    }
+
+    /// Returns the number of grapheme clusters seen so far, including the
+    /// one in progress.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Counts the next character of input, returning `true` if it began a
+    /// new grapheme cluster.
+    pub fn push_u8char(&mut self, c: u8char) -> bool {
+        let boundary = self.machine.next_u8char(c).is_boundary();
+        self.count = self.count.saturating_add(u64::from(boundary));
+        boundary
+    }
+
+    /// Counts the next character of input, returning `true` if it began a
+    /// new grapheme cluster.
+    pub fn push_char(&mut self, c: char) -> bool {
+        let boundary = self.machine.next_char(c).is_boundary();
+        self.count = self.count.saturating_add(u64::from(boundary));
+        boundary
+    }
+
+    /// Counts the characters in the next chunk of input, returning the
+    /// updated count.
+    ///
+    /// A grapheme cluster can straddle chunks, in which case it's counted
+    /// only once.
+    pub fn push_str(&mut self, s: &str) -> u64 {
+        for c in s.u8chars() {
+            self.push_u8char(c);
+        }
+        self.count
+    }
+
+    /// Tells the counter that the input has ended, so that the next
+    /// character always begins a new cluster. The count is not reset.
+    pub fn end_of_input(&mut self) {
+        self.machine.end_of_input();
+    }
+
+    /// Consumes the counter and returns the machine it was using.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,61 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn count_in_str() {
+    assert_eq!(grapheme_count(""), 0);
+    assert_eq!(grapheme_count("abc"), 3);
+    assert_eq!(
+        grapheme_count("e\u{301}\r\n\u{1F1E6}\u{1F1E8}\u{1F468}\u{200D}\u{1F469}"),
+        4
+    );
+}
+
+#[test]
+fn truncate() {
+    let s = "ae\u{301}\u{1F468}\u{200D}\u{1F469}\r\n";
+    assert_eq!(truncate_clusters(s, 0), "");
+    assert_eq!(truncate_clusters(s, 1), "a");
+    assert_eq!(truncate_clusters(s, 2), "ae\u{301}");
+    assert_eq!(
+        truncate_clusters(s, 3),
+        "ae\u{301}\u{1F468}\u{200D}\u{1F469}"
+    );
+    assert_eq!(truncate_clusters(s, 4), s);
+    assert_eq!(truncate_clusters(s, 5), s);
+    assert_eq!(truncate_clusters("", 1), "");
+}
+
+#[test]
+fn nth_cluster() {
+    let s = "ae\u{301}\u{1F1E6}\u{1F1E8}b";
+    let got: Vec<_> = (0..6).map(|n| nth_cluster_offset(s, n)).collect();
+    assert_eq!(got, [Some(0), Some(1), Some(4), Some(12), Some(13), None]);
+    assert_eq!(nth_cluster_offset("", 0), Some(0));
+    assert_eq!(nth_cluster_offset("", 1), None);
+
+    assert_eq!(cluster_range_offsets(s, 1..3), Some(1..12));
+    assert_eq!(cluster_range_offsets(s, 2..2), Some(4..4));
+    assert_eq!(cluster_range_offsets(s, 0..4), Some(0..13));
+    assert_eq!(cluster_range_offsets(s, 3..5), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 2..1;
+    assert_eq!(cluster_range_offsets(s, reversed), None);
+}
+
+#[test]
+fn counter_across_chunks() {
+    let mut counter = ClusterCounter::new();
+    assert_eq!(counter.push_str("ae"), 2);
+    assert_eq!(counter.push_str("\u{301}\u{1F1E6}"), 3);
+    assert!(!counter.push_char('\u{1F1E8}'));
+    assert_eq!(counter.count(), 3);
+    assert!(counter.push_char('\u{1F1E6}'));
+    assert_eq!(counter.push_str(""), 4);
+
+    // Ending the input forces the next character to begin a new cluster.
+    counter.end_of_input();
+    assert!(counter.push_char('\u{301}'));
+    assert_eq!(counter.count(), 5);
+}
@@ -0,0 +1,125 @@
+use core::ops::Range;
+
+use crate::{ClusterAction, GraphemeMachine, LineColumn, LineColumnTracker, SegmentAction};
+
+/// Describes the location of a span of source text in a form suitable for
+/// rendering diagnostic messages, such as those produced by compilers and
+/// other language tooling.
+///
+/// The byte range is compatible with crates like `codespan-reporting` and
+/// `ariadne`, which accept spans as byte offsets into the source text. The
+/// other fields describe where the span appears in terms of lines, grapheme
+/// clusters, and display columns, so that a renderer can position carets or
+/// underlines correctly even when the text includes characters that occupy
+/// more or less than one column, such as emoji sequences and CJK ideographs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticSpan {
+    /// The byte range of the span in the source text, widened as necessary so
+    /// that both ends are on grapheme cluster boundaries.
+    pub bytes: Range<usize>,
+    /// The line and cluster column where the span begins.
+    pub start: LineColumn,
+    /// The line and cluster column where the span ends.
+    pub end: LineColumn,
+    /// The total display width of the clusters between the start of the line
+    /// containing the start of the span and the start of the span.
+    pub start_display_column: usize,
+    /// The total display width of the clusters between the start of the line
+    /// containing the end of the span and the end of the span.
+    pub end_display_column: usize,
+}
+
+impl DiagnosticSpan {
+    /// Calculates the [`DiagnosticSpan`] for the given byte range in `source`,
+    /// or returns `None` if the range is reversed or extends beyond the end
+    /// of `source`.
+    ///
+    /// `width` is called for each grapheme cluster on the lines covered by the
+    /// span, up to its end, and must return the number of display columns that
+    /// the given cluster occupies, such as the number of terminal cells.
+    ///
+    /// This needs to segment all of the text before the end of the range, but
+    /// calls `width` only for the clusters on the lines that the span covers.
+    pub fn new(
+        source: &str,
+        range: Range<usize>,
+        mut width: impl FnMut(&str) -> usize,
+    ) -> Option<Self> {
+        if range.start > range.end || range.end > source.len() {
+            return None;
+        }
+
+        // First we find the line that the span starts on, so that we'll only
+        // need to measure the widths of clusters on that line and later ones.
+        let mut line_begin = 0;
+        let mut line = 0;
+        each_boundary(source, |offset, pos| {
+            if offset > range.start {
+                return false;
+            }
+            if pos.column == 0 {
+                line_begin = offset;
+                line = pos.line;
+            }
+            true
+        });
+
+        // The start of a line always follows a line break cluster, after which
+        // there is always a boundary and no earlier context matters, so we can
+        // begin segmenting there with a fresh state machine.
+        let line_start = (line_begin, LineColumn { line, column: 0 }, 0);
+        let (mut start, mut end) = (line_start, line_start);
+        let mut prev: Option<(usize, LineColumn)> = None;
+        let mut display = 0;
+        each_boundary(&source[line_begin..], |offset, mut pos| {
+            let offset = line_begin + offset;
+            pos.line += line;
+            if let Some((prev_offset, prev_pos)) = prev {
+                if prev_pos.line == pos.line {
+                    display += width(&source[prev_offset..offset]);
+                } else {
+                    display = 0;
+                }
+            }
+            prev = Some((offset, pos));
+
+            if offset <= range.start {
+                start = (offset, pos, display);
+            }
+            if offset >= range.end {
+                end = (offset, pos, display);
+                return false;
+            }
+            true
+        });
+
+        Some(Self {
+            bytes: start.0..end.0,
+            start: start.1,
+            end: end.1,
+            start_display_column: start.2,
+            end_display_column: end.2,
+        })
+    }
+}
+
+/// Calls `f` with the offset and position of each grapheme cluster boundary in
+/// `text`, including the one at the end of the text, until it returns `false`.
+fn each_boundary(text: &str, mut f: impl FnMut(usize, LineColumn) -> bool) {
+    let mut machine = GraphemeMachine::new();
+    let mut tracker = LineColumnTracker::new();
+    let mut offset = 0;
+    for (action, c) in machine.next_u8chars_from_str(text) {
+        let pos = tracker.next_u8char(action, c);
+        if action.is_boundary() && !f(offset, pos) {
+            return;
+        }
+        offset += c.len();
+    }
+    // The tracker can tell us where the end of the text is if we pretend
+    // that there's another cluster after it.
+    f(offset, tracker.next_char(ClusterAction::Split, ' '));
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,118 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+/// A very rough approximation of terminal display width that's good enough
+/// for these tests.
+fn width(cluster: &str) -> usize {
+    match cluster.chars().next() {
+        Some(c) if c >= '\u{1100}' => 2,
+        _ => 1,
+    }
+}
+
+fn lc(line: usize, column: usize) -> LineColumn {
+    LineColumn { line, column }
+}
+
+#[test]
+fn single_line() {
+    let source = "let 🧑‍🌾 = 漢字;\nnext line";
+    let start = source.find('漢').unwrap();
+    let got = DiagnosticSpan::new(source, start..start + 6, width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: start..start + 6,
+            start: lc(0, 8),
+            end: lc(0, 10),
+            start_display_column: 9,
+            end_display_column: 13,
+        }
+    );
+}
+
+#[test]
+fn widened_to_clusters() {
+    let source = "ab\ncde\u{0301}f\r\ng";
+    // Starting in the middle of the multi-byte "e" cluster and ending
+    // in the middle of its combining accent.
+    let got = DiagnosticSpan::new(source, 6..8, width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: 5..8,
+            start: lc(1, 2),
+            end: lc(1, 3),
+            start_display_column: 2,
+            end_display_column: 3,
+        }
+    );
+
+    // An empty range between CR and LF covers the whole CRLF cluster.
+    let got = DiagnosticSpan::new(source, 10..10, width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: 9..11,
+            start: lc(1, 4),
+            end: lc(2, 0),
+            start_display_column: 4,
+            end_display_column: 0,
+        }
+    );
+}
+
+#[test]
+fn multi_line() {
+    let source = "one\r\ntwo\rthree\nfour";
+    let got = DiagnosticSpan::new(source, 6..source.len() - 2, width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: 6..source.len() - 2,
+            start: lc(1, 1),
+            end: lc(3, 2),
+            start_display_column: 1,
+            end_display_column: 2,
+        }
+    );
+
+    let got = DiagnosticSpan::new(source, source.len()..source.len(), width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: source.len()..source.len(),
+            start: lc(3, 4),
+            end: lc(3, 4),
+            start_display_column: 4,
+            end_display_column: 4,
+        }
+    );
+}
+
+#[test]
+fn invalid_range() {
+    let source = "abc";
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 2..1;
+    assert_eq!(DiagnosticSpan::new(source, reversed, width), None);
+    assert_eq!(DiagnosticSpan::new(source, 2..4, width), None);
+    assert_eq!(DiagnosticSpan::new(source, 4..4, width), None);
+}
+
+#[test]
+fn ends_after_final_line_break() {
+    let source = "ab\n";
+    let got = DiagnosticSpan::new(source, 1..3, width).unwrap();
+    assert_eq!(
+        got,
+        DiagnosticSpan {
+            bytes: 1..3,
+            start: lc(0, 1),
+            end: lc(1, 0),
+            start_display_column: 1,
+            end_display_column: 0,
+        }
+    );
+}
@@ -0,0 +1,67 @@
+//! Edit distances measured in grapheme clusters.
+
+use alloc::vec::Vec;
+
+use crate::{CompareMode, clusters_equal, clusters_in_str};
+
+/// Returns the Levenshtein distance between `a` and `b` measured in
+/// grapheme clusters: the smallest number of clusters that must be
+/// inserted, deleted, or substituted to turn one into the other.
+///
+/// Measuring in clusters gives the distance a human reader would expect.
+/// For example, "👩‍👩‍👦" and "👩‍👩‍👧" are one substitution apart rather than
+/// the two that comparing characters would report, and replacing a
+/// Devanagari syllable counts as one edit however many characters it's
+/// made of.
+///
+/// Each pair of clusters is compared using [`clusters_equal`] with the
+/// given mode. This takes time proportional to the product of the numbers
+/// of clusters.
+///
+/// This is available only when the `alloc` feature is enabled.
+pub fn cluster_levenshtein(a: &str, b: &str, mode: CompareMode) -> usize {
+    edit_distance(a, b, mode, false)
+}
+
+/// Returns the Damerau-Levenshtein distance between `a` and `b` measured
+/// in grapheme clusters, which is like [`cluster_levenshtein`] except that
+/// swapping two adjacent clusters also counts as a single edit.
+///
+/// This is the "optimal string alignment" variant of the distance, in which
+/// no part of the string is edited more than once. For example, "ca" and
+/// "abc" are three edits apart, rather than the two that would be possible
+/// by transposing and then inserting between the transposed clusters.
+///
+/// This is available only when the `alloc` feature is enabled.
+pub fn cluster_damerau_levenshtein(a: &str, b: &str, mode: CompareMode) -> usize {
+    edit_distance(a, b, mode, true)
+}
+
+fn edit_distance(a: &str, b: &str, mode: CompareMode, transpose: bool) -> usize {
+    let a: Vec<_> = clusters_in_str(a).collect();
+    let b: Vec<_> = clusters_in_str(b).collect();
+    let eq = |i: usize, j: usize| clusters_equal(a[i], b[j], mode);
+
+    // Only the two rows of the table before the current one are needed.
+    let mut prev2 = alloc::vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut row = alloc::vec![0; b.len() + 1];
+    for i in 0..a.len() {
+        row[0] = i + 1;
+        for j in 0..b.len() {
+            let cost = usize::from(!eq(i, j));
+            let mut best = (prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1);
+            if transpose && i > 0 && j > 0 && eq(i, j - 1) && eq(i - 1, j) {
+                best = best.min(prev2[j - 1] + 1);
+            }
+            row[j + 1] = best;
+        }
+        // Rotate the rows, reusing the oldest for the next one.
+        core::mem::swap(&mut prev2, &mut prev);
+        core::mem::swap(&mut prev, &mut row);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn levenshtein() {
+    let lev = |a, b| cluster_levenshtein(a, b, CompareMode::Exact);
+    assert_eq!(lev("", ""), 0);
+    assert_eq!(lev("abc", ""), 3);
+    assert_eq!(lev("", "e\u{0301}"), 1);
+    assert_eq!(lev("kitten", "sitting"), 3);
+    // Changing the last member of a family is one substitution.
+    assert_eq!(lev("👩‍👩‍👦", "👩‍👩‍👧"), 1);
+    assert_eq!(lev("नमस्ते", "नमस्कार"), 2);
+    assert_eq!(lev("ab", "ba"), 2);
+}
+
+#[test]
+fn damerau_levenshtein() {
+    let dl = |a, b| cluster_damerau_levenshtein(a, b, CompareMode::Exact);
+    assert_eq!(dl("ab", "ba"), 1);
+    assert_eq!(dl("🇦🇧🇨🇩", "🇨🇩🇦🇧"), 1);
+    assert_eq!(dl("ca", "abc"), 3);
+    assert_eq!(dl("kitten", "sitting"), 3);
+    assert_eq!(dl("abcdef", "badcfe"), 3);
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn canonical_equivalence() {
+    assert_eq!(
+        cluster_levenshtein("caf\u{e9}", "cafe\u{0301}", CompareMode::Exact),
+        1
+    );
+    assert_eq!(
+        cluster_levenshtein("caf\u{e9}", "cafe\u{0301}", CompareMode::Nfc),
+        0
+    );
+}
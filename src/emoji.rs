@@ -0,0 +1,130 @@
+//! The Unicode emoji properties, for recognizing emoji and choosing between
+//! their text and emoji presentations.
+
+mod table;
+
+/// The emoji properties of a character, from
+/// [UTS #51](https://www.unicode.org/reports/tr51/#Emoji_Properties).
+///
+/// These are binary properties that a character can have in any
+/// combination, such as a smiley face being both **Emoji** and
+/// **Emoji_Presentation**, and so this is a set of flags rather than an
+/// enumeration like [`GCBProperty`](crate::GCBProperty).
+///
+/// **Extended_Pictographic** is also available as
+/// [`GCBProperty::ExtendedPictographic`](crate::GCBProperty::ExtendedPictographic)
+/// for segmentation, but is included here too so that this is a complete
+/// set of the properties in the emoji data files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EmojiProperties {
+    bits: u8,
+}
+
+impl EmojiProperties {
+    const EMOJI: u8 = 0x01;
+    const EMOJI_PRESENTATION: u8 = 0x02;
+    const EMOJI_MODIFIER: u8 = 0x04;
+    const EMOJI_MODIFIER_BASE: u8 = 0x08;
+    const EMOJI_COMPONENT: u8 = 0x10;
+    const EXTENDED_PICTOGRAPHIC: u8 = 0x20;
+
+    /// The size in bytes of the lookup table that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`](crate::CharProperties),
+    /// this table honors the `GRAPHEME_MACHINE_LINK_SECTION` environment
+    /// variable.
+    pub const TABLE_SIZE: usize = table::EMOJI_RUNS_SIZE;
+
+    /// Returns the emoji properties of the given character, which are all
+    /// `false` for most characters.
+    pub const fn of_char(c: char) -> Self {
+        Self {
+            bits: table::emoji_lookup(c),
+        }
+    }
+
+    /// Returns `true` if the character has the **Emoji** property, meaning
+    /// that it is an emoji in at least one of its presentations.
+    ///
+    /// This includes characters such as the digits and `#` that are
+    /// displayed as text unless they begin an emoji sequence, so
+    /// [`is_emoji_cluster`] is usually a better way to decide whether
+    /// some text is an emoji.
+    pub const fn is_emoji(self) -> bool {
+        self.bits & Self::EMOJI != 0
+    }
+
+    /// Returns `true` if the character has the **Emoji_Presentation**
+    /// property, meaning that it is displayed as an emoji by default rather
+    /// than only when followed by U+FE0F VARIATION SELECTOR-16.
+    pub const fn is_emoji_presentation(self) -> bool {
+        self.bits & Self::EMOJI_PRESENTATION != 0
+    }
+
+    /// Returns `true` if the character has the **Emoji_Modifier** property,
+    /// as for the five skin tone modifiers.
+    pub const fn is_emoji_modifier(self) -> bool {
+        self.bits & Self::EMOJI_MODIFIER != 0
+    }
+
+    /// Returns `true` if the character has the **Emoji_Modifier_Base**
+    /// property, meaning that an emoji modifier after it changes its
+    /// appearance instead of being displayed separately.
+    pub const fn is_emoji_modifier_base(self) -> bool {
+        self.bits & Self::EMOJI_MODIFIER_BASE != 0
+    }
+
+    /// Returns `true` if the character has the **Emoji_Component**
+    /// property, meaning that it can appear as part of an emoji sequence,
+    /// such as the regional indicators, the keycap base characters and the
+    /// tag characters.
+    pub const fn is_emoji_component(self) -> bool {
+        self.bits & Self::EMOJI_COMPONENT != 0
+    }
+
+    /// Returns `true` if the character has the **Extended_Pictographic**
+    /// property, which includes all of the pictographic emoji along with
+    /// the unassigned characters reserved for future ones.
+    pub const fn is_extended_pictographic(self) -> bool {
+        self.bits & Self::EXTENDED_PICTOGRAPHIC != 0
+    }
+}
+
+/// Returns `true` if the given grapheme cluster is displayed as an emoji,
+/// according to the emoji properties of its first two characters.
+///
+/// That's the case when the first character has the **Emoji** property and
+/// either has the **Emoji_Presentation** property or is followed by
+/// U+FE0F VARIATION SELECTOR-16, an emoji modifier, or U+20E3 COMBINING
+/// ENCLOSING KEYCAP. U+FE0E VARIATION SELECTOR-15 after the first
+/// character requests the text presentation instead, and so makes the
+/// result `false`.
+///
+/// Emoji ZWJ sequences, flags and tag sequences are then recognized by
+/// their first character, so the result is `true` for a whole cluster such
+/// as a family of several people, while plain digits and the other
+/// characters that are only emoji in sequences are not.
+pub fn is_emoji_cluster(cluster: &str) -> bool {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let props = EmojiProperties::of_char(first);
+    if !props.is_emoji() {
+        return false;
+    }
+    match chars.next() {
+        Some('\u{FE0E}') => false,
+        Some('\u{FE0F}' | '\u{20E3}') => true,
+        Some(c)
+            if props.is_emoji_modifier_base()
+                && EmojiProperties::of_char(c).is_emoji_modifier() =>
+        {
+            true
+        }
+        _ => props.is_emoji_presentation(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
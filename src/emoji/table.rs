@@ -0,0 +1,87 @@
+/// Returns the encoded emoji properties of the given character, as the
+/// bits of [`EmojiProperties`](super::EmojiProperties).
+///
+/// [`EMOJI_RUNS`] is sorted by code point, so this is a binary search for
+/// the last run beginning at or before `c`.
+pub const fn emoji_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, EMOJI_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if EMOJI_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    EMOJI_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`EMOJI_RUNS`].
+pub const EMOJI_RUNS_SIZE: usize = EMOJI_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static EMOJI_RUNS: [u32; 462] = [
+    0x00000000, 0x00002311, 0x00002400, 0x00002a11, 0x00002b00, 0x00003011, 0x00003a00, 0x0000a921,
+    0x0000aa00, 0x0000ae21, 0x0000af00, 0x00200d10, 0x00200e00, 0x00203c21, 0x00203d00, 0x00204921,
+    0x00204a00, 0x0020e310, 0x0020e400, 0x00212221, 0x00212300, 0x00213921, 0x00213a00, 0x00219421,
+    0x00219a00, 0x0021a921, 0x0021ab00, 0x00231a23, 0x00231c00, 0x00232821, 0x00232900, 0x00238820,
+    0x00238900, 0x0023cf21, 0x0023d000, 0x0023e923, 0x0023ed21, 0x0023f023, 0x0023f121, 0x0023f323,
+    0x0023f400, 0x0023f821, 0x0023fb00, 0x0024c221, 0x0024c300, 0x0025aa21, 0x0025ac00, 0x0025b621,
+    0x0025b700, 0x0025c021, 0x0025c100, 0x0025fb21, 0x0025fd23, 0x0025ff00, 0x00260021, 0x00260520,
+    0x00260600, 0x00260720, 0x00260e21, 0x00260f20, 0x00261121, 0x00261220, 0x00261300, 0x00261423,
+    0x00261620, 0x00261821, 0x00261920, 0x00261d29, 0x00261e20, 0x00262021, 0x00262120, 0x00262221,
+    0x00262420, 0x00262621, 0x00262720, 0x00262a21, 0x00262b20, 0x00262e21, 0x00263020, 0x00263821,
+    0x00263b20, 0x00264021, 0x00264120, 0x00264221, 0x00264320, 0x00264823, 0x00265420, 0x00265f21,
+    0x00266120, 0x00266321, 0x00266420, 0x00266521, 0x00266720, 0x00266821, 0x00266920, 0x00267b21,
+    0x00267c20, 0x00267e21, 0x00267f23, 0x00268020, 0x00268600, 0x00269020, 0x00269221, 0x00269323,
+    0x00269421, 0x00269820, 0x00269921, 0x00269a20, 0x00269b21, 0x00269d20, 0x0026a021, 0x0026a123,
+    0x0026a220, 0x0026a721, 0x0026a820, 0x0026aa23, 0x0026ac20, 0x0026b021, 0x0026b220, 0x0026bd23,
+    0x0026bf20, 0x0026c423, 0x0026c620, 0x0026c821, 0x0026c920, 0x0026ce23, 0x0026cf21, 0x0026d020,
+    0x0026d121, 0x0026d220, 0x0026d321, 0x0026d423, 0x0026d520, 0x0026e921, 0x0026ea23, 0x0026eb20,
+    0x0026f021, 0x0026f223, 0x0026f421, 0x0026f523, 0x0026f620, 0x0026f721, 0x0026f929, 0x0026fa23,
+    0x0026fb20, 0x0026fd23, 0x0026fe20, 0x00270221, 0x00270320, 0x00270523, 0x00270600, 0x00270821,
+    0x00270a2b, 0x00270c29, 0x00270e20, 0x00270f21, 0x00271020, 0x00271221, 0x00271300, 0x00271421,
+    0x00271500, 0x00271621, 0x00271700, 0x00271d21, 0x00271e00, 0x00272121, 0x00272200, 0x00272823,
+    0x00272900, 0x00273321, 0x00273500, 0x00274421, 0x00274500, 0x00274721, 0x00274800, 0x00274c23,
+    0x00274d00, 0x00274e23, 0x00274f00, 0x00275323, 0x00275600, 0x00275723, 0x00275800, 0x00276321,
+    0x00276520, 0x00276800, 0x00279523, 0x00279800, 0x0027a121, 0x0027a200, 0x0027b023, 0x0027b100,
+    0x0027bf23, 0x0027c000, 0x00293421, 0x00293600, 0x002b0521, 0x002b0800, 0x002b1b23, 0x002b1d00,
+    0x002b5023, 0x002b5100, 0x002b5523, 0x002b5600, 0x00303021, 0x00303100, 0x00303d21, 0x00303e00,
+    0x00329721, 0x00329800, 0x00329921, 0x00329a00, 0x00fe0f10, 0x00fe1000, 0x01f00020, 0x01f00423,
+    0x01f00520, 0x01f0cf23, 0x01f0d020, 0x01f10000, 0x01f10d20, 0x01f11000, 0x01f12f20, 0x01f13000,
+    0x01f16c20, 0x01f17021, 0x01f17200, 0x01f17e21, 0x01f18000, 0x01f18e23, 0x01f18f00, 0x01f19123,
+    0x01f19b00, 0x01f1ad20, 0x01f1e613, 0x01f20000, 0x01f20123, 0x01f20221, 0x01f20320, 0x01f21000,
+    0x01f21a23, 0x01f21b00, 0x01f22f23, 0x01f23000, 0x01f23223, 0x01f23721, 0x01f23823, 0x01f23b00,
+    0x01f23c20, 0x01f24000, 0x01f24920, 0x01f25023, 0x01f25220, 0x01f30023, 0x01f32121, 0x01f32220,
+    0x01f32421, 0x01f32d23, 0x01f33621, 0x01f33723, 0x01f37d21, 0x01f37e23, 0x01f3852b, 0x01f38623,
+    0x01f39420, 0x01f39621, 0x01f39820, 0x01f39921, 0x01f39c20, 0x01f39e21, 0x01f3a023, 0x01f3c22b,
+    0x01f3c523, 0x01f3c72b, 0x01f3c823, 0x01f3ca2b, 0x01f3cb29, 0x01f3cd21, 0x01f3cf23, 0x01f3d421,
+    0x01f3e023, 0x01f3f120, 0x01f3f321, 0x01f3f423, 0x01f3f521, 0x01f3f620, 0x01f3f721, 0x01f3f823,
+    0x01f3fb17, 0x01f40023, 0x01f43f21, 0x01f44023, 0x01f44121, 0x01f4422b, 0x01f44423, 0x01f4462b,
+    0x01f45123, 0x01f4662b, 0x01f47923, 0x01f47c2b, 0x01f47d23, 0x01f4812b, 0x01f48423, 0x01f4852b,
+    0x01f48823, 0x01f48f2b, 0x01f49023, 0x01f4912b, 0x01f49223, 0x01f4aa2b, 0x01f4ab23, 0x01f4fd21,
+    0x01f4fe20, 0x01f4ff23, 0x01f53e00, 0x01f54620, 0x01f54921, 0x01f54b23, 0x01f54f20, 0x01f55023,
+    0x01f56820, 0x01f56f21, 0x01f57120, 0x01f57321, 0x01f57429, 0x01f57621, 0x01f57a2b, 0x01f57b20,
+    0x01f58721, 0x01f58820, 0x01f58a21, 0x01f58e20, 0x01f59029, 0x01f59120, 0x01f5952b, 0x01f59720,
+    0x01f5a423, 0x01f5a521, 0x01f5a620, 0x01f5a821, 0x01f5a920, 0x01f5b121, 0x01f5b320, 0x01f5bc21,
+    0x01f5bd20, 0x01f5c221, 0x01f5c520, 0x01f5d121, 0x01f5d420, 0x01f5dc21, 0x01f5df20, 0x01f5e121,
+    0x01f5e220, 0x01f5e321, 0x01f5e420, 0x01f5e821, 0x01f5e920, 0x01f5ef21, 0x01f5f020, 0x01f5f321,
+    0x01f5f420, 0x01f5fa21, 0x01f5fb23, 0x01f6452b, 0x01f64823, 0x01f64b2b, 0x01f65000, 0x01f68023,
+    0x01f6a32b, 0x01f6a423, 0x01f6b42b, 0x01f6b723, 0x01f6c02b, 0x01f6c123, 0x01f6c620, 0x01f6cb21,
+    0x01f6cc2b, 0x01f6cd21, 0x01f6d023, 0x01f6d320, 0x01f6d523, 0x01f6d820, 0x01f6dc23, 0x01f6e021,
+    0x01f6e620, 0x01f6e921, 0x01f6ea20, 0x01f6eb23, 0x01f6ed20, 0x01f6f021, 0x01f6f120, 0x01f6f321,
+    0x01f6f423, 0x01f6fd20, 0x01f70000, 0x01f77420, 0x01f78000, 0x01f7d520, 0x01f7e023, 0x01f7ec20,
+    0x01f7f023, 0x01f7f120, 0x01f80000, 0x01f80c20, 0x01f81000, 0x01f84820, 0x01f85000, 0x01f85a20,
+    0x01f86000, 0x01f88820, 0x01f89000, 0x01f8ae20, 0x01f90000, 0x01f90c2b, 0x01f90d23, 0x01f90f2b,
+    0x01f91023, 0x01f9182b, 0x01f92023, 0x01f9262b, 0x01f92723, 0x01f9302b, 0x01f93a23, 0x01f93b00,
+    0x01f93c2b, 0x01f93f23, 0x01f94600, 0x01f94723, 0x01f9772b, 0x01f97823, 0x01f9b033, 0x01f9b423,
+    0x01f9b52b, 0x01f9b723, 0x01f9b82b, 0x01f9ba23, 0x01f9bb2b, 0x01f9bc23, 0x01f9cd2b, 0x01f9d023,
+    0x01f9d12b, 0x01f9de23, 0x01fa0020, 0x01fa7023, 0x01fa7d20, 0x01fa8023, 0x01fa8a20, 0x01fa8f23,
+    0x01fac32b, 0x01fac623, 0x01fac720, 0x01face23, 0x01fadd20, 0x01fadf23, 0x01faea20, 0x01faf02b,
+    0x01faf920, 0x01fb0000, 0x01fc0020, 0x01fffe00, 0x0e002010, 0x0e008000,
+];
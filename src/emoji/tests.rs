@@ -0,0 +1,74 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn properties() {
+    let smiley = EmojiProperties::of_char('\u{1F600}');
+    assert!(smiley.is_emoji());
+    assert!(smiley.is_emoji_presentation());
+    assert!(!smiley.is_emoji_modifier_base());
+    assert!(!smiley.is_emoji_component());
+    assert!(smiley.is_extended_pictographic());
+
+    let hash = EmojiProperties::of_char('#');
+    assert!(hash.is_emoji());
+    assert!(!hash.is_emoji_presentation());
+    assert!(hash.is_emoji_component());
+    assert!(!hash.is_extended_pictographic());
+
+    let tone = EmojiProperties::of_char('\u{1F3FD}');
+    assert!(tone.is_emoji_modifier());
+    assert!(tone.is_emoji_component());
+
+    assert!(EmojiProperties::of_char('\u{261D}').is_emoji_modifier_base());
+    assert!(EmojiProperties::of_char('\u{1F1E6}').is_emoji_component());
+    assert!(EmojiProperties::of_char('\u{E0041}').is_emoji_component());
+    assert!(EmojiProperties::of_char('\u{1FC00}').is_extended_pictographic());
+    assert!(!EmojiProperties::of_char('\u{1FC00}').is_emoji());
+    assert_eq!(EmojiProperties::of_char('a'), EmojiProperties::default());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn extended_pictographic_matches_segmentation() {
+    use crate::{CharProperties, GCBProperty};
+
+    for c in '\0'..=char::MAX {
+        let gcb = CharProperties::for_char(c).gcb_property();
+        assert_eq!(
+            EmojiProperties::of_char(c).is_extended_pictographic(),
+            gcb == GCBProperty::ExtendedPictographic,
+            "{c:?}",
+        );
+    }
+}
+
+#[test]
+fn emoji_clusters() {
+    let cases = [
+        ("", false),
+        ("a", false),
+        ("1", false),
+        ("\u{1F600}", true),
+        ("\u{1F600}\u{FE0E}", false),
+        ("\u{2764}", false),
+        ("\u{2764}\u{FE0F}", true),
+        ("\u{231A}", true),
+        ("\u{231A}\u{FE0E}", false),
+        ("1\u{FE0F}\u{20E3}", true),
+        ("1\u{20E3}", true),
+        ("\u{261D}", false),
+        ("\u{261D}\u{1F3FD}", true),
+        ("\u{1F44D}\u{1F3FD}", true),
+        ("\u{1F1E6}\u{1F1E8}", true),
+        ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", true),
+        (
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+            true,
+        ),
+    ];
+    for (cluster, want) in cases {
+        assert_eq!(is_emoji_cluster(cluster), want, "{cluster:?}");
+    }
+}
@@ -0,0 +1,265 @@
+use core::fmt;
+
+/// The error type for all of the fallible operations in this library.
+///
+/// Each fallible operation returns a more specific error type describing
+/// only the failures it can actually produce, but all of those types can be
+/// converted into this one, so that callers who use several of them can
+/// handle their errors with a single type.
+///
+/// This and all of the more specific error types implement
+/// [`core::error::Error`], which is the same trait as `std::error::Error`,
+/// so they work with error handling libraries like `anyhow` and `thiserror`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input was not valid UTF-8.
+    InvalidUtf8(InvalidUtf8Error),
+    /// A raw value did not represent a valid [`CharProperties`](crate::CharProperties).
+    InvalidProperties(InvalidPropertiesError),
+    /// A string that was expected to be a single grapheme cluster was not.
+    NotACluster(NotAClusterError),
+    /// The source of a JSON string was not valid.
+    InvalidJsonString(InvalidJsonStringError),
+    /// Some bytes did not represent a valid [`GraphemeMachine`](crate::GraphemeMachine).
+    InvalidMachine(InvalidMachineError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8(err) => err.fmt(f),
+            Self::InvalidProperties(err) => err.fmt(f),
+            Self::NotACluster(err) => err.fmt(f),
+            Self::InvalidJsonString(err) => err.fmt(f),
+            Self::InvalidMachine(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidUtf8(err) => Some(err),
+            Self::InvalidProperties(err) => Some(err),
+            Self::NotACluster(err) => Some(err),
+            Self::InvalidJsonString(err) => Some(err),
+            Self::InvalidMachine(err) => Some(err),
+        }
+    }
+}
+
+impl From<InvalidUtf8Error> for Error {
+    fn from(err: InvalidUtf8Error) -> Self {
+        Self::InvalidUtf8(err)
+    }
+}
+
+impl From<InvalidPropertiesError> for Error {
+    fn from(err: InvalidPropertiesError) -> Self {
+        Self::InvalidProperties(err)
+    }
+}
+
+impl From<NotAClusterError> for Error {
+    fn from(err: NotAClusterError) -> Self {
+        Self::NotACluster(err)
+    }
+}
+
+impl From<InvalidJsonStringError> for Error {
+    fn from(err: InvalidJsonStringError) -> Self {
+        Self::InvalidJsonString(err)
+    }
+}
+
+impl From<InvalidMachineError> for Error {
+    fn from(err: InvalidMachineError) -> Self {
+        Self::InvalidMachine(err)
+    }
+}
+
+/// Describes where some input that was expected to be UTF-8 first failed
+/// to be valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtf8Error {
+    pub(crate) valid_up_to: usize,
+    pub(crate) error_len: Option<usize>,
+}
+
+impl InvalidUtf8Error {
+    /// Returns the byte offset of the first byte that isn't part of a valid
+    /// UTF-8 sequence. All of the bytes before that are valid.
+    pub const fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the length of the invalid byte sequence at
+    /// [`Self::valid_up_to`], or `None` if the input ended partway through
+    /// what could have been a valid sequence had there been more bytes.
+    pub const fn error_len(&self) -> Option<usize> {
+        self.error_len
+    }
+}
+
+impl From<core::str::Utf8Error> for InvalidUtf8Error {
+    fn from(err: core::str::Utf8Error) -> Self {
+        Self {
+            valid_up_to: err.valid_up_to(),
+            error_len: err.error_len(),
+        }
+    }
+}
+
+impl fmt::Display for InvalidUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid UTF-8 sequence of {len} bytes at offset {}",
+                self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete UTF-8 sequence at offset {}",
+                self.valid_up_to
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InvalidUtf8Error {}
+
+/// Returned by [`CharProperties::from_raw`](crate::CharProperties::from_raw)
+/// when given a value that doesn't represent any valid property tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPropertiesError {
+    pub(crate) raw: u16,
+}
+
+impl InvalidPropertiesError {
+    /// Returns the invalid raw value.
+    pub const fn raw(&self) -> u16 {
+        self.raw
+    }
+}
+
+impl fmt::Display for InvalidPropertiesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid raw character properties {:#06x}", self.raw)
+    }
+}
+
+impl core::error::Error for InvalidPropertiesError {}
+
+/// Returned by [`GraphemeMachine::from_bytes`](crate::GraphemeMachine::from_bytes)
+/// when given bytes that don't represent any state a machine could be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidMachineError {
+    pub(crate) bytes: [u8; 4],
+}
+
+impl InvalidMachineError {
+    /// Returns the invalid bytes.
+    pub const fn bytes(&self) -> [u8; 4] {
+        self.bytes
+    }
+}
+
+impl fmt::Display for InvalidMachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid grapheme machine state {:02x?}", self.bytes)
+    }
+}
+
+impl core::error::Error for InvalidMachineError {}
+
+/// Returned by [`GraphemeSet::new`](crate::GraphemeSet::new) when one of
+/// the given strings is empty or contains more than one grapheme cluster,
+/// and by [`Grapheme::new`](crate::Grapheme::new) and
+/// `GraphemeBuf::new` when the given string is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotAClusterError {
+    pub(crate) index: usize,
+}
+
+impl NotAClusterError {
+    /// Returns the index of the offending string in the input list, which
+    /// is always zero for the constructors that take a single string.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NotAClusterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {} is not exactly one grapheme cluster", self.index)
+    }
+}
+
+impl core::error::Error for NotAClusterError {}
+
+/// Returned by [`JsonStringMachine`](crate::JsonStringMachine) when the
+/// source of a JSON string is not valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidJsonStringError {
+    pub(crate) offset: u64,
+    pub(crate) kind: JsonStringErrorKind,
+}
+
+impl InvalidJsonStringError {
+    /// Returns the source offset where the invalid escape sequence or
+    /// character begins.
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns what was wrong with the source at [`Self::offset`].
+    pub const fn kind(&self) -> JsonStringErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for InvalidJsonStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            JsonStringErrorKind::InvalidEscape => "invalid escape sequence",
+            JsonStringErrorKind::InvalidHexDigit => "invalid hex digit in \\u escape",
+            JsonStringErrorKind::UnpairedSurrogate => "unpaired surrogate escape",
+            JsonStringErrorKind::InvalidUtf8 => "invalid UTF-8 sequence",
+            JsonStringErrorKind::ControlCharacter => "unescaped control character",
+            JsonStringErrorKind::UnescapedQuote => "unescaped quote",
+            JsonStringErrorKind::Incomplete => "incomplete sequence",
+        };
+        write!(f, "{what} in JSON string at offset {}", self.offset)
+    }
+}
+
+impl core::error::Error for InvalidJsonStringError {}
+
+/// The ways that the source of a JSON string can be invalid, as reported
+/// by [`InvalidJsonStringError::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsonStringErrorKind {
+    /// A backslash was followed by a character that doesn't begin an
+    /// escape sequence.
+    InvalidEscape,
+    /// A `\u` escape contained something other than four hex digits.
+    InvalidHexDigit,
+    /// A `\u` escape represented a surrogate that wasn't part of a high
+    /// surrogate followed immediately by a low surrogate.
+    UnpairedSurrogate,
+    /// The unescaped text was not valid UTF-8.
+    InvalidUtf8,
+    /// A control character appeared without being escaped.
+    ControlCharacter,
+    /// A quote appeared without being escaped, which would end the string.
+    UnescapedQuote,
+    /// The source ended partway through an escape sequence or UTF-8
+    /// sequence.
+    Incomplete,
+}
+
+#[cfg(all(test, feature = "machine", feature = "tables"))]
+mod tests;
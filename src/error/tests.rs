@@ -0,0 +1,41 @@
+use super::*;
+use crate::CharProperties;
+
+use pretty_assertions::assert_eq;
+
+fn utf8_error(bytes: &[u8]) -> InvalidUtf8Error {
+    let mut machine = crate::GraphemeMachine::new();
+    machine.next_u8chars_from_utf8(bytes).err().unwrap()
+}
+
+#[test]
+fn display() {
+    let err = utf8_error(b"ab\xffc");
+    assert_eq!(err.valid_up_to(), 2);
+    assert_eq!(err.error_len(), Some(1));
+    assert_eq!(
+        err.to_string(),
+        "invalid UTF-8 sequence of 1 bytes at offset 2"
+    );
+    assert_eq!(Error::from(err).to_string(), err.to_string());
+
+    let err = utf8_error(b"ab\xe2\x82");
+    assert_eq!(err.error_len(), None);
+    assert_eq!(err.to_string(), "incomplete UTF-8 sequence at offset 2");
+
+    let err = CharProperties::from_raw(0x4f).unwrap_err();
+    assert_eq!(err.raw(), 0x4f);
+    assert_eq!(err.to_string(), "invalid raw character properties 0x004f");
+    assert_eq!(Error::from(err), Error::InvalidProperties(err));
+}
+
+#[test]
+fn source() {
+    use core::error::Error as _;
+    let err = Error::from(CharProperties::from_raw(0x800).unwrap_err());
+    let source = err.source().expect("no source");
+    assert_eq!(
+        source.to_string(),
+        "invalid raw character properties 0x0800"
+    );
+}
@@ -0,0 +1,87 @@
+use crate::{ClusterAction, InvalidUtf8Error, OffsetAction};
+
+/// A self-contained record of one step of segmentation output, for
+/// pipelines where segmentation happens in a different process or worker
+/// than whatever consumes the results.
+///
+/// `T` is the representation of cluster text, such as `String` for events
+/// that own their text or `&str` for events that borrow it from a buffer. Consumers that already
+/// have the text can use only the offset-based events and never produce
+/// [`BoundaryEvent::Cluster`] at all.
+///
+/// Offsets are byte offsets into the UTF-8 stream, as reported by
+/// [`OffsetGraphemeMachine`](crate::OffsetGraphemeMachine).
+///
+/// When the `serde` feature is enabled, this implements `Serialize` and
+/// `Deserialize` whenever `T` does, so events can be sent with any serde
+/// format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BoundaryEvent<T> {
+    /// The complete text of a grapheme cluster.
+    Cluster(T),
+    /// A grapheme cluster boundary found by the segmentation rules, at the
+    /// given offset.
+    Boundary(u64),
+    /// A boundary at the given offset that was forced by something other
+    /// than the segmentation rules, such as the end of the input or a region
+    /// skipped using
+    /// [`OffsetGraphemeMachine::skip_non_text`](crate::OffsetGraphemeMachine::skip_non_text).
+    ForcedBreak(u64),
+    /// The input at the given offset was not valid UTF-8.
+    InvalidUtf8 {
+        /// The offset of the first byte that isn't part of a valid UTF-8
+        /// sequence.
+        offset: u64,
+        /// The length of the invalid sequence, or `None` if the input ended
+        /// partway through a sequence. This has the same meaning as
+        /// [`InvalidUtf8Error::error_len`].
+        len: Option<u8>,
+    },
+}
+
+impl<T> BoundaryEvent<T> {
+    /// Returns a [`BoundaryEvent::Boundary`] event if the given action
+    /// represents a boundary, or `None` otherwise.
+    pub const fn from_action(action: OffsetAction) -> Option<Self> {
+        match action.action {
+            ClusterAction::Split => Some(Self::Boundary(action.offset)),
+            ClusterAction::Continue => None,
+        }
+    }
+
+    /// Returns a [`BoundaryEvent::InvalidUtf8`] event describing the given
+    /// error, whose offsets are relative to the given offset in the stream.
+    pub fn from_utf8_error(err: InvalidUtf8Error, base: u64) -> Self {
+        Self::InvalidUtf8 {
+            offset: base.saturating_add(err.valid_up_to() as u64),
+            // A UTF-8 error is never more than three bytes long.
+            len: err.error_len().map(|len| len as u8),
+        }
+    }
+
+    /// Returns the offset that the event refers to, or `None` for
+    /// [`BoundaryEvent::Cluster`] since cluster events don't carry one.
+    pub const fn offset(&self) -> Option<u64> {
+        match self {
+            Self::Cluster(_) => None,
+            Self::Boundary(offset) | Self::ForcedBreak(offset) => Some(*offset),
+            Self::InvalidUtf8 { offset, .. } => Some(*offset),
+        }
+    }
+
+    /// Converts the cluster text of a [`BoundaryEvent::Cluster`] event using
+    /// the given function, leaving the other events unchanged.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> BoundaryEvent<U> {
+        match self {
+            Self::Cluster(text) => BoundaryEvent::Cluster(f(text)),
+            Self::Boundary(offset) => BoundaryEvent::Boundary(offset),
+            Self::ForcedBreak(offset) => BoundaryEvent::ForcedBreak(offset),
+            Self::InvalidUtf8 { offset, len } => BoundaryEvent::InvalidUtf8 { offset, len },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn from_machine() {
+    let mut machine = crate::OffsetGraphemeMachine::with_offset(10);
+    let mut events: Vec<BoundaryEvent<String>> = Vec::new();
+    for c in "ae\u{0301}".chars() {
+        events.extend(BoundaryEvent::from_action(machine.next_char(c)));
+    }
+    events.push(BoundaryEvent::ForcedBreak(machine.end_of_input().offset));
+    let err = InvalidUtf8Error {
+        valid_up_to: 2,
+        error_len: Some(1),
+    };
+    events.push(BoundaryEvent::from_utf8_error(err, 14));
+    assert_eq!(
+        events,
+        &[
+            BoundaryEvent::Boundary(10),
+            BoundaryEvent::Boundary(11),
+            BoundaryEvent::ForcedBreak(14),
+            BoundaryEvent::InvalidUtf8 {
+                offset: 16,
+                len: Some(1)
+            },
+        ]
+    );
+    assert_eq!(
+        events.iter().map(BoundaryEvent::offset).collect::<Vec<_>>(),
+        &[Some(10), Some(11), Some(14), Some(16)],
+    );
+}
+
+#[test]
+fn map() {
+    let event = BoundaryEvent::Cluster("e\u{0301}");
+    assert_eq!(event.offset(), None);
+    assert_eq!(event.map(str::len), BoundaryEvent::Cluster(3));
+    assert_eq!(
+        BoundaryEvent::<&str>::Boundary(4).map(str::len),
+        BoundaryEvent::Boundary(4)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_test::{Token, assert_tokens};
+
+    assert_tokens(
+        &BoundaryEvent::Cluster("🇦🇧"),
+        &[
+            Token::NewtypeVariant {
+                name: "BoundaryEvent",
+                variant: "Cluster",
+            },
+            Token::BorrowedStr("🇦🇧"),
+        ],
+    );
+    assert_tokens(
+        &BoundaryEvent::<String>::ForcedBreak(7),
+        &[
+            Token::NewtypeVariant {
+                name: "BoundaryEvent",
+                variant: "ForcedBreak",
+            },
+            Token::U64(7),
+        ],
+    );
+    assert_tokens(
+        &BoundaryEvent::<String>::InvalidUtf8 {
+            offset: 2,
+            len: None,
+        },
+        &[
+            Token::StructVariant {
+                name: "BoundaryEvent",
+                variant: "InvalidUtf8",
+                len: 2,
+            },
+            Token::Str("offset"),
+            Token::U64(2),
+            Token::Str("len"),
+            Token::None,
+            Token::StructVariantEnd,
+        ],
+    );
+}
@@ -0,0 +1,102 @@
+use core::iter::FusedIterator;
+
+use crate::floor_boundary;
+
+/// The longest UTF-8 sequence, and so the smallest frame size that can
+/// always hold at least one character.
+const MIN_FRAME: usize = 4;
+
+/// Returns an iterator that splits `s` into frames of at most `max_bytes`
+/// bytes each, cutting only at grapheme cluster boundaries.
+///
+/// This is for dividing text into protocol frames, SMS-style message
+/// segments or fixed-size records without splitting a user-visible
+/// character between two of them. Each frame holds as many whole clusters
+/// as fit, and so only the last frame can be shorter than that allows.
+///
+/// A single cluster longer than `max_bytes`, such as a long run of
+/// combining marks, can't fit in any frame, and so it's cut at a character
+/// boundary instead as a last resort.
+///
+/// # Panics
+///
+/// Panics if `max_bytes` is less than four, because every frame must be
+/// able to hold at least one character.
+pub fn frames_in_str(s: &str, max_bytes: usize) -> StrFrames<'_> {
+    assert!(
+        max_bytes >= MIN_FRAME,
+        "frame size must be at least {MIN_FRAME} bytes"
+    );
+    StrFrames {
+        remain: s,
+        max_bytes,
+        carry_over: false,
+    }
+}
+
+/// An iterator over frames of a string, each as a slice of that string.
+///
+/// Construct this using [`frames_in_str`].
+#[derive(Debug, Clone)]
+pub struct StrFrames<'a> {
+    remain: &'a str,
+    max_bytes: usize,
+    carry_over: bool,
+}
+
+impl<'a> StrFrames<'a> {
+    /// Makes the iterator stop before the last frame instead of returning
+    /// it, leaving it in [`Self::remainder`].
+    ///
+    /// This is for text that arrives as a stream: the last frame might not
+    /// be full, and its last cluster might continue in text that hasn't
+    /// arrived yet, so the caller should carry the remainder over by
+    /// prepending it to the next chunk of text and segmenting that.
+    pub fn carry_over(self) -> Self {
+        Self {
+            carry_over: true,
+            ..self
+        }
+    }
+
+    /// Returns the text that the iterator hasn't yet returned as frames.
+    pub fn remainder(&self) -> &'a str {
+        self.remain
+    }
+}
+
+impl<'a> Iterator for StrFrames<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.remain;
+        if s.is_empty() || (self.carry_over && s.len() <= self.max_bytes) {
+            return None;
+        }
+        let mut end = floor_boundary(s, self.max_bytes);
+        if end == 0 {
+            // The first cluster alone is too long, so cut it at the last
+            // character boundary that fits instead.
+            end = s.floor_char_boundary(self.max_bytes);
+        }
+        let (frame, rest) = s.split_at(end);
+        self.remain = rest;
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each frame holds at most max_bytes but at least one byte, and with
+        // carry_over at most max_bytes are left over at the end.
+        let len = self.remain.len();
+        let framed = match self.carry_over {
+            true => len.saturating_sub(self.max_bytes),
+            false => len,
+        };
+        (framed.div_ceil(self.max_bytes), Some(framed))
+    }
+}
+
+impl<'a> FusedIterator for StrFrames<'a> {}
+
+#[cfg(test)]
+mod tests;
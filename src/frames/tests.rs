@@ -0,0 +1,49 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn whole_clusters() {
+    // "e" + U+0301 is three bytes, and the flag is eight.
+    let s = "ae\u{0301}🇦🇧b\r\n";
+    let got: Vec<_> = frames_in_str(s, 4).collect();
+    assert_eq!(got, &["ae\u{0301}", "🇦", "🇧", "b\r\n"]);
+    let got: Vec<_> = frames_in_str(s, 9).collect();
+    assert_eq!(got, &["ae\u{0301}", "🇦🇧b", "\r\n"]);
+    let got: Vec<_> = frames_in_str(s, 100).collect();
+    assert_eq!(got, &[s]);
+    assert_eq!(frames_in_str("", 4).count(), 0);
+}
+
+#[test]
+fn oversized_cluster() {
+    let got: Vec<_> = frames_in_str("e\u{0301}\u{0302}x", 4).collect();
+    assert_eq!(got, &["e\u{0301}", "\u{0302}x"]);
+}
+
+#[test]
+fn carry_over() {
+    let s = "abce\u{0301}";
+    let mut frames = frames_in_str(s, 4).carry_over();
+    assert_eq!(frames.next(), Some("abc"));
+    assert_eq!(frames.next(), None);
+    assert_eq!(frames.remainder(), "e\u{0301}");
+
+    let mut frames = frames_in_str("abcd", 4).carry_over();
+    assert_eq!(frames.next(), None);
+    assert_eq!(frames.remainder(), "abcd");
+}
+
+#[test]
+fn size_hint() {
+    let (lower, upper) = frames_in_str("abcdefghij", 4).size_hint();
+    assert_eq!((lower, upper), (3, Some(10)));
+    let (lower, upper) = frames_in_str("abcdefghij", 4).carry_over().size_hint();
+    assert_eq!((lower, upper), (2, Some(6)));
+}
+
+#[test]
+#[should_panic(expected = "frame size must be at least 4 bytes")]
+fn too_small() {
+    frames_in_str("abc", 3);
+}
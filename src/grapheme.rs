@@ -0,0 +1,205 @@
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{NotAClusterError, clusters_in_str};
+
+/// A string slice that is exactly one grapheme cluster, for APIs that want
+/// to accept or return a single user-perceived character.
+///
+/// Constructing a `Grapheme` checks the invariant once, so that functions
+/// accepting one don't need to check it again. It dereferences to [`str`],
+/// and compares, orders and hashes the same way as its string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grapheme<'a>(&'a str);
+
+impl<'a> Grapheme<'a> {
+    /// Wraps the given string, or returns an error if it's empty or
+    /// contains more than one grapheme cluster, in which case the error's
+    /// [`NotAClusterError::index`] is zero.
+    pub fn new(s: &'a str) -> Result<Self, NotAClusterError> {
+        if is_one_cluster(s) {
+            Ok(Self(s))
+        } else {
+            Err(NotAClusterError { index: 0 })
+        }
+    }
+
+    /// Returns the grapheme cluster as a string slice.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Copies the grapheme cluster into a new [`GraphemeBuf`].
+    ///
+    /// This is available only when the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    pub fn to_grapheme_buf(&self) -> GraphemeBuf {
+        GraphemeBuf(self.0.into())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Grapheme<'a> {
+    type Error = NotAClusterError;
+
+    fn try_from(s: &'a str) -> Result<Self, NotAClusterError> {
+        Self::new(s)
+    }
+}
+
+impl Deref for Grapheme<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for Grapheme<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl fmt::Display for Grapheme<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl PartialEq<str> for Grapheme<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Grapheme<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// An owned string that is exactly one grapheme cluster, like [`Grapheme`]
+/// but for callers that need to keep the cluster beyond the lifetime of the
+/// text it came from.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GraphemeBuf(alloc::string::String);
+
+#[cfg(feature = "alloc")]
+impl GraphemeBuf {
+    /// Wraps the given string, or returns an error if it's empty or
+    /// contains more than one grapheme cluster, in which case the error's
+    /// [`NotAClusterError::index`] is zero.
+    pub fn new(s: alloc::string::String) -> Result<Self, NotAClusterError> {
+        Grapheme::new(&s)?;
+        Ok(Self(s))
+    }
+
+    /// Borrows the grapheme cluster as a [`Grapheme`].
+    pub fn as_grapheme(&self) -> Grapheme<'_> {
+        Grapheme(&self.0)
+    }
+
+    /// Returns the grapheme cluster as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the `GraphemeBuf` and returns its string.
+    pub fn into_string(self) -> alloc::string::String {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::string::String> for GraphemeBuf {
+    type Error = NotAClusterError;
+
+    fn try_from(s: alloc::string::String) -> Result<Self, NotAClusterError> {
+        Self::new(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&str> for GraphemeBuf {
+    type Error = NotAClusterError;
+
+    fn try_from(s: &str) -> Result<Self, NotAClusterError> {
+        Grapheme::new(s).map(|g| g.to_grapheme_buf())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Grapheme<'_>> for GraphemeBuf {
+    fn from(g: Grapheme<'_>) -> Self {
+        g.to_grapheme_buf()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<GraphemeBuf> for alloc::string::String {
+    fn from(g: GraphemeBuf) -> Self {
+        g.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for GraphemeBuf {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for GraphemeBuf {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for GraphemeBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<str> for GraphemeBuf {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<&str> for GraphemeBuf {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<Grapheme<'_>> for GraphemeBuf {
+    fn eq(&self, other: &Grapheme<'_>) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<GraphemeBuf> for Grapheme<'_> {
+    fn eq(&self, other: &GraphemeBuf) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// Returns `true` if `s` is exactly one grapheme cluster.
+pub(crate) fn is_one_cluster(s: &str) -> bool {
+    !s.is_empty() && clusters_in_str(s).nth(1).is_none()
+}
+
+#[cfg(test)]
+mod tests;
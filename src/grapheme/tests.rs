@@ -0,0 +1,48 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn borrowed() {
+    for s in [
+        "a",
+        "e\u{0301}",
+        "🇦🇧",
+        "🧑\u{200D}🌾",
+        "\r\n",
+        "\u{1112}\u{1161}\u{11AB}",
+    ] {
+        let g = Grapheme::new(s).unwrap();
+        assert_eq!(g.as_str(), s);
+        assert_eq!(g, s);
+        assert_eq!(g.to_string(), s);
+        assert_eq!(g.len(), s.len());
+    }
+    for s in ["", "ab", "🇦🇧🇨", "\n\r", "e\u{0301}x"] {
+        assert_eq!(
+            Grapheme::new(s),
+            Err(NotAClusterError { index: 0 }),
+            "{s:?}"
+        );
+    }
+    assert_eq!(Grapheme::try_from("é"), Grapheme::new("é"));
+    assert!(Grapheme::new("a").unwrap() < Grapheme::new("b").unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn owned() {
+    let g = GraphemeBuf::new(String::from("❤\u{FE0F}")).unwrap();
+    assert_eq!(g, "❤\u{FE0F}");
+    assert_eq!(g.as_grapheme(), Grapheme::new("❤\u{FE0F}").unwrap());
+    assert_eq!(Grapheme::new("❤\u{FE0F}").unwrap(), g);
+    assert_eq!(g.to_string(), "❤\u{FE0F}");
+    assert_eq!(GraphemeBuf::try_from("❤\u{FE0F}").as_ref(), Ok(&g));
+    assert_eq!(GraphemeBuf::from(Grapheme::new("❤\u{FE0F}").unwrap()), g);
+    assert_eq!(g.clone().into_string(), "❤\u{FE0F}");
+    assert_eq!(String::from(g), "❤\u{FE0F}");
+
+    let err = GraphemeBuf::try_from(String::from("ab")).unwrap_err();
+    assert_eq!(err.index(), 0);
+    assert!(GraphemeBuf::try_from("").is_err());
+}
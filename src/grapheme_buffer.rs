@@ -0,0 +1,216 @@
+use alloc::string::String;
+
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// A [`GraphemeMachine`] (or any other [`Segmenter`]) combined with a buffer
+/// of at most a given number of bytes, which accepts characters one at a
+/// time and returns each grapheme cluster once it's complete.
+///
+/// This is the approach described in the crate documentation for callers
+/// that only care about clusters up to some reasonable length: a cluster
+/// longer than the limit, which is most likely malicious or corrupt input,
+/// is handled according to an [`OverflowPolicy`] while the machine still
+/// finds its correct end. Unlike
+/// [`BufferedGraphemeMachine`](crate::BufferedGraphemeMachine), the limit is
+/// chosen at runtime and the overflow policy is configurable, at the cost of
+/// allocating the buffer.
+///
+/// A cluster is only known to be complete once the first character of the
+/// next cluster arrives, or at the end of input, and so the methods that
+/// accept a character return the cluster that the character _ended_, if
+/// any.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct GraphemeBuffer<S = GraphemeMachine> {
+    machine: S,
+    max_len: usize,
+    policy: OverflowPolicy,
+    buf: String,
+    // The length of the whole cluster in progress, including any bytes that
+    // didn't fit in the buffer.
+    len: usize,
+    // Set when the buffer holds a cluster that was already returned, and
+    // so must be discarded before accepting any more characters.
+    returned: bool,
+    // The first character of the next cluster, which arrived while the
+    // buffer was still holding the cluster it ended.
+    pending: Option<u8char>,
+}
+
+/// What a [`GraphemeBuffer`] returns for a cluster that's longer than its
+/// limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Returns as many whole characters from the start of the cluster as
+    /// fit within the limit.
+    Truncate,
+    /// Returns a single U+FFFD REPLACEMENT CHARACTER instead of the cluster.
+    #[default]
+    Replace,
+    /// Returns the same characters as [`Self::Truncate`] followed by U+FFFD
+    /// REPLACEMENT CHARACTER to mark where the rest was removed, which can
+    /// make the result up to three bytes longer than the limit.
+    Mark,
+}
+
+/// A complete grapheme cluster returned by a [`GraphemeBuffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompletedCluster<'a> {
+    text: &'a str,
+    len: usize,
+    overflowed: bool,
+}
+
+impl<'a> CompletedCluster<'a> {
+    /// Returns the text of the cluster, after applying the buffer's
+    /// [`OverflowPolicy`] if the cluster was too long.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns `true` if the cluster was longer than the buffer's limit,
+    /// and so [`Self::as_str`] isn't the whole cluster.
+    ///
+    /// This distinguishes a replaced or marked cluster from one that
+    /// literally contained U+FFFD in the input.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Returns the length in bytes of the whole cluster as it appeared in
+    /// the input, even if it overflowed.
+    pub fn original_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl GraphemeBuffer {
+    /// Constructs a new buffer in an initial "start of input" state that
+    /// holds clusters of up to `max_len` bytes, replacing any longer cluster
+    /// with U+FFFD REPLACEMENT CHARACTER.
+    pub fn new(max_len: usize) -> Self {
+        Self::with_policy(max_len, OverflowPolicy::Replace)
+    }
+
+    /// Constructs a new buffer in an initial "start of input" state that
+    /// holds clusters of up to `max_len` bytes, handling any longer cluster
+    /// according to the given policy.
+    pub fn with_policy(max_len: usize, policy: OverflowPolicy) -> Self {
+        Self::with_machine(GraphemeMachine::new(), max_len, policy)
+    }
+}
+
+impl<S: Segmenter> GraphemeBuffer<S> {
+    /// Constructs a new buffer that uses the given machine to find the
+    /// cluster boundaries, holding clusters of up to `max_len` bytes and
+    /// handling any longer cluster according to the given policy.
+    ///
+    /// The given machine should be in its "start of input" state.
+    pub fn with_machine(machine: S, max_len: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            machine,
+            max_len,
+            policy,
+            buf: String::new(),
+            len: 0,
+            returned: false,
+            pending: None,
+        }
+    }
+
+    /// Accepts the next character of the input, returning the cluster that
+    /// it ended if it begins a new one.
+    pub fn push(&mut self, c: u8char) -> Option<CompletedCluster<'_>> {
+        self.begin_next();
+        let action = self.machine.next_u8char(c);
+        if action.is_boundary() && self.len > 0 {
+            self.pending = Some(c);
+            return Some(self.finish_cluster());
+        }
+        self.append(c);
+        None
+    }
+
+    /// Accepts the next character of the input, returning the cluster that
+    /// it ended if it begins a new one.
+    ///
+    /// This converts the character to [`u8char`] first, so prefer
+    /// [`Self::push`] if you already have that representation.
+    pub fn push_char(&mut self, c: char) -> Option<CompletedCluster<'_>> {
+        self.push(u8char::from_char(c))
+    }
+
+    /// Signals the end of the input, returning the final cluster if any
+    /// characters have arrived since the previous cluster was returned.
+    ///
+    /// The buffer is then back in its "start of input" state, ready to
+    /// begin a new stream.
+    pub fn end_of_input(&mut self) -> Option<CompletedCluster<'_>> {
+        self.begin_next();
+        self.machine.end_of_input();
+        if self.len == 0 {
+            return None;
+        }
+        Some(self.finish_cluster())
+    }
+
+    /// Returns the maximum length in bytes of the clusters that the buffer
+    /// holds.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Consumes the buffer and returns the machine it was using.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+
+    /// Discards a cluster that was already returned, and then adds any
+    /// pending character to the buffer to begin the next cluster.
+    fn begin_next(&mut self) {
+        if self.returned {
+            self.buf.clear();
+            self.len = 0;
+            self.returned = false;
+        }
+        if let Some(c) = self.pending.take() {
+            self.append(c);
+        }
+    }
+
+    fn append(&mut self, c: u8char) {
+        // Once one character doesn't fit, none of the later ones are kept
+        // either, even if they're short enough.
+        if self.len == self.buf.len() && self.len + c.len() <= self.max_len {
+            self.buf.push_str(c.as_str());
+        }
+        self.len += c.len();
+    }
+
+    fn finish_cluster(&mut self) -> CompletedCluster<'_> {
+        self.returned = true;
+        let overflowed = self.len > self.buf.len();
+        if overflowed {
+            match self.policy {
+                OverflowPolicy::Truncate => {}
+                OverflowPolicy::Replace => {
+                    self.buf.clear();
+                    self.buf.push('\u{FFFD}');
+                }
+                OverflowPolicy::Mark => self.buf.push('\u{FFFD}'),
+            }
+        }
+        CompletedCluster {
+            text: &self.buf,
+            len: self.len,
+            overflowed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,65 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn clusters(input: &str, max_len: usize, policy: OverflowPolicy) -> Vec<(String, bool)> {
+    let mut buffer = GraphemeBuffer::with_policy(max_len, policy);
+    let mut got = Vec::new();
+    for c in input.chars() {
+        if let Some(cluster) = buffer.push_char(c) {
+            got.push((cluster.as_str().to_string(), cluster.overflowed()));
+        }
+    }
+    if let Some(cluster) = buffer.end_of_input() {
+        got.push((cluster.as_str().to_string(), cluster.overflowed()));
+    }
+    got
+}
+
+#[test]
+fn complete_clusters() {
+    let got = clusters("a\r\ne\u{0301}🇦🇧", 16, OverflowPolicy::Replace);
+    let want = ["a", "\r\n", "e\u{0301}", "🇦🇧"];
+    assert_eq!(got, want.map(|s| (s.to_string(), false)));
+    assert_eq!(clusters("", 16, OverflowPolicy::Replace), &[]);
+}
+
+#[test]
+fn overflow_policies() {
+    // The farmer emoji is eleven bytes: the four-byte farmer, the
+    // three-byte joiner and then the four-byte plant.
+    let input = "a🧑\u{200D}🌾b";
+    let got = |policy| {
+        clusters(input, 8, policy)
+            .into_iter()
+            .filter_map(|(s, overflowed)| overflowed.then_some(s))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(got(OverflowPolicy::Truncate), &["🧑\u{200D}"]);
+    assert_eq!(got(OverflowPolicy::Replace), &["\u{FFFD}"]);
+    assert_eq!(got(OverflowPolicy::Mark), &["🧑\u{200D}\u{FFFD}"]);
+
+    // A character that doesn't fit isn't followed by shorter ones that do.
+    let got = clusters("e\u{1F3FB}\u{0301}", 4, OverflowPolicy::Truncate);
+    assert_eq!(got, &[("e".to_string(), true)]);
+}
+
+#[test]
+fn original_len() {
+    let mut buffer = GraphemeBuffer::new(4);
+    for c in "🧑\u{200D}🌾".chars() {
+        assert_eq!(buffer.push_char(c), None);
+    }
+    let cluster = buffer.push_char('x').unwrap();
+    assert_eq!(
+        (
+            cluster.as_str(),
+            cluster.overflowed(),
+            cluster.original_len()
+        ),
+        ("\u{FFFD}", true, 11)
+    );
+    assert_eq!(buffer.end_of_input().map(|c| c.original_len()), Some(1));
+    assert_eq!(buffer.end_of_input(), None);
+    assert_eq!(buffer.max_len(), 4);
+}
@@ -0,0 +1,60 @@
+use crate::NotAClusterError;
+use crate::grapheme::is_one_cluster;
+
+/// A set of grapheme clusters, for checking whether each cluster from a
+/// stream is in an allowlist or denylist.
+///
+/// Matching whole clusters means that, for example, an allowlist
+/// containing "❤\u{FE0F}" doesn't accept the cluster "❤\u{FE0F}\u{200D}🔥"
+/// just because it begins with the same characters, as a test of individual
+/// characters would.
+///
+/// The set borrows its clusters from a caller-provided slice, which it
+/// sorts so that [`Self::contains`] can use binary search. Neither
+/// constructing the set nor testing membership allocates, and so this is
+/// available without the `alloc` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphemeSet<'a> {
+    clusters: &'a [&'a str],
+}
+
+impl<'a> GraphemeSet<'a> {
+    /// Builds a set from the given clusters, sorting the slice in place.
+    ///
+    /// Returns an error if any of the strings is not exactly one grapheme
+    /// cluster, since such a string could never match a cluster from a
+    /// stream.
+    pub fn new(clusters: &'a mut [&'a str]) -> Result<Self, NotAClusterError> {
+        for (index, cluster) in clusters.iter().enumerate() {
+            if !is_one_cluster(cluster) {
+                return Err(NotAClusterError { index });
+            }
+        }
+        clusters.sort_unstable();
+        Ok(Self { clusters })
+    }
+
+    /// Returns `true` if the given cluster is in the set.
+    pub fn contains(&self, cluster: &str) -> bool {
+        self.clusters.binary_search(&cluster).is_ok()
+    }
+
+    /// Returns the number of clusters in the set, counting any duplicates
+    /// in the list it was built from.
+    pub fn len(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Returns `true` if the set contains no clusters.
+    pub fn is_empty(&self) -> bool {
+        self.clusters.is_empty()
+    }
+
+    /// Returns an iterator over the clusters in the set, in sorted order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a str> + use<'a> {
+        self.clusters.iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests;
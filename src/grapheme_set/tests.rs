@@ -0,0 +1,44 @@
+use super::*;
+use crate::{BufferedGraphemeMachine, Error};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn allowlist() {
+    let mut list = ["👍", "❤\u{FE0F}", "🇦🇧", "e\u{0301}"];
+    let set = GraphemeSet::new(&mut list).unwrap();
+    assert_eq!(set.len(), 4);
+    assert!(!set.is_empty());
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        &["e\u{0301}", "❤\u{FE0F}", "🇦🇧", "👍"]
+    );
+
+    // Match completed clusters from a stream.
+    let mut machine = BufferedGraphemeMachine::<16>::new();
+    let mut got = Vec::new();
+    for c in "👍❤\u{FE0F}\u{200D}🔥🇦🇧e".chars() {
+        if let Some(cluster) = machine.next_char(c) {
+            got.push(set.contains(cluster));
+        }
+    }
+    got.push(set.contains(machine.end_of_input().unwrap()));
+    assert_eq!(got, &[true, false, true, false]);
+}
+
+#[test]
+fn not_clusters() {
+    let mut list = ["a", "bc"];
+    let err = GraphemeSet::new(&mut list).unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert_eq!(
+        err.to_string(),
+        "item 1 is not exactly one grapheme cluster"
+    );
+    assert_eq!(Error::from(err), Error::NotACluster(err));
+
+    let mut list = [""];
+    assert_eq!(GraphemeSet::new(&mut list).unwrap_err().index(), 0);
+    let mut list: [&str; 0] = [];
+    assert!(GraphemeSet::new(&mut list).unwrap().is_empty());
+}
@@ -0,0 +1,369 @@
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+use crate::{ClusterAction, GraphemeMachine};
+
+/// A wrapper around [`GraphemeMachine`] that decodes HTML character
+/// references in its input before segmenting it, and reports each grapheme
+/// cluster boundary as an offset into the original source.
+///
+/// This is for segmenting text taken directly from HTML, where characters
+/// are often written as references like `&amp;`, `&#233;` or `&#x1F600;`.
+/// A reference decodes to the character it names, so "e&#x301;" is a
+/// single cluster, and the boundary before a decoded character is reported
+/// at the `&` that begins its reference.
+///
+/// Numeric references always decode, as in a web browser: one whose number
+/// isn't a valid Unicode scalar value decodes to U+FFFD REPLACEMENT
+/// CHARACTER, and the closing `;` is optional. Named references must end
+/// with `;`, and are looked up using a function which defaults to
+/// [`html_named_reference`]. Anything that looks like the start of a
+/// reference but isn't one is segmented as literal text.
+///
+/// The input can be provided in a series of chunks, which can split a
+/// reference at any point. The machine keeps at most a reference's worth of
+/// source bytes while waiting to learn whether they are a reference.
+#[derive(Debug)]
+pub struct HtmlEntityMachine {
+    machine: GraphemeMachine,
+    lookup: fn(&str) -> Option<&'static str>,
+    // The offset of the next source byte.
+    offset: u64,
+    reference: Reference,
+    // The source of the reference in progress, excluding the digits of a
+    // numeric reference because those never need to be replayed.
+    buf: [u8; MAX_BUFFERED],
+    len: usize,
+    start: u64,
+    // The position in buf of the next byte to segment as literal text,
+    // when the buffered bytes turned out not to be a reference.
+    replay: Option<usize>,
+}
+
+// The "&" and the longest name of an HTML named character reference, which
+// is "CounterClockwiseContourIntegral".
+const MAX_BUFFERED: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reference {
+    None,
+    Ampersand,
+    Named,
+    Hash,
+    Numeric {
+        radix: u32,
+        value: u32,
+        digits: bool,
+    },
+}
+
+impl HtmlEntityMachine {
+    /// Constructs a new machine in an initial "start of input" state, at
+    /// offset zero, that recognizes the named references known to
+    /// [`html_named_reference`].
+    pub fn new() -> Self {
+        Self::with_named_references(html_named_reference)
+    }
+
+    /// Constructs a new machine that uses the given function to decode
+    /// named references, such as one covering the full HTML named
+    /// character reference table.
+    ///
+    /// The function receives the name without the `&` and `;`, and returns
+    /// the text that it represents, or `None` if it isn't a known name.
+    pub fn with_named_references(lookup: fn(&str) -> Option<&'static str>) -> Self {
+        Self {
+            machine: GraphemeMachine::new(),
+            lookup,
+            offset: 0,
+            reference: Reference::None,
+            buf: [0; MAX_BUFFERED],
+            len: 0,
+            start: 0,
+            replay: None,
+        }
+    }
+
+    /// Returns the offset of the next source byte the machine will read.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns an iterator which feeds the HTML source in `s` into the
+    /// machine and yields the source offset of each grapheme cluster
+    /// boundary it finds.
+    ///
+    /// As with
+    /// [`OffsetGraphemeMachine::boundaries_in_str`](crate::OffsetGraphemeMachine::boundaries_in_str),
+    /// there is no automatic call to [`Self::end_of_input`] at the end, so
+    /// the source can be provided in a series of chunks. A reference that's
+    /// still incomplete at the end of a chunk is held until the next one.
+    pub fn boundaries_in_str<'a>(&'a mut self, s: &'a str) -> impl FusedIterator<Item = u64> + 'a {
+        let mut remain = s;
+        core::iter::from_fn(move || self.next_boundary(&mut remain, false)).fuse()
+    }
+
+    /// Tells the machine that the input has ended, returning an iterator
+    /// over the boundaries that were waiting for the end of an incomplete
+    /// reference, followed by the boundary at the end of the input.
+    ///
+    /// The iterator must be read to the end before using the machine again,
+    /// which can then segment a new stream with offsets continuing from the
+    /// end of this one.
+    pub fn end_of_input(&mut self) -> impl FusedIterator<Item = u64> + '_ {
+        let mut done = false;
+        core::iter::from_fn(move || {
+            if let Some(offset) = self.next_boundary(&mut "", true) {
+                return Some(offset);
+            }
+            if done {
+                return None;
+            }
+            done = true;
+            self.machine.end_of_input();
+            Some(self.offset)
+        })
+        .fuse()
+    }
+
+    fn next_boundary(&mut self, remain: &mut &str, at_end: bool) -> Option<u64> {
+        loop {
+            if let Some(i) = self.replay {
+                if i < self.len {
+                    self.replay = Some(i + 1);
+                    let offset = self.start + i as u64;
+                    // All of the buffered bytes are ASCII.
+                    if let Some(boundary) = self.feed(self.buf[i] as char, offset) {
+                        return Some(boundary);
+                    }
+                    continue;
+                }
+                self.replay = None;
+                self.reference = Reference::None;
+                self.len = 0;
+            }
+
+            if self.reference == Reference::None {
+                let (Some(c), rest) = u8char::from_string_prefix(remain) else {
+                    return None;
+                };
+                *remain = rest;
+                let offset = self.offset;
+                self.offset = self.offset.saturating_add(c.len() as u64);
+                if c.as_str() == "&" {
+                    self.reference = Reference::Ampersand;
+                    self.buf[0] = b'&';
+                    self.len = 1;
+                    self.start = offset;
+                    continue;
+                }
+                if let Some(boundary) = self.feed(c.to_char(), offset) {
+                    return Some(boundary);
+                }
+                continue;
+            }
+
+            let b = remain.as_bytes().first().copied();
+            if b.is_none() && !at_end {
+                return None;
+            }
+            match self.advance_reference(b) {
+                Step::Consumed => {
+                    *remain = &remain[1..];
+                    self.offset = self.offset.saturating_add(1);
+                }
+                Step::Decoded {
+                    consumed,
+                    first,
+                    rest,
+                } => {
+                    if consumed {
+                        *remain = &remain[1..];
+                        self.offset = self.offset.saturating_add(1);
+                    }
+                    self.reference = Reference::None;
+                    self.len = 0;
+                    let boundary = self.feed(first, self.start);
+                    // There's no source offset between the characters of a
+                    // single reference, and so no boundary to report there.
+                    for c in rest.chars() {
+                        self.machine.next_char(c);
+                    }
+                    if boundary.is_some() {
+                        return boundary;
+                    }
+                }
+                Step::NotAReference => self.replay = Some(0),
+            }
+        }
+    }
+
+    /// Decides what to do with the next byte of a possible reference, or
+    /// with the end of the input if `b` is `None`.
+    fn advance_reference(&mut self, b: Option<u8>) -> Step {
+        match (self.reference, b) {
+            (Reference::Ampersand, Some(b'#')) => {
+                self.push(b'#');
+                self.reference = Reference::Hash;
+                Step::Consumed
+            }
+            (Reference::Ampersand | Reference::Named, Some(b)) if b.is_ascii_alphanumeric() => {
+                if self.len == MAX_BUFFERED {
+                    return Step::NotAReference;
+                }
+                self.push(b);
+                self.reference = Reference::Named;
+                Step::Consumed
+            }
+            (Reference::Named, Some(b';')) => {
+                // The buffer holds only ASCII alphanumerics after the "&".
+                let name = core::str::from_utf8(&self.buf[1..self.len]).unwrap();
+                let mut chars = (self.lookup)(name).unwrap_or("").chars();
+                match chars.next() {
+                    Some(first) => Step::Decoded {
+                        consumed: true,
+                        first,
+                        rest: chars.as_str(),
+                    },
+                    None => Step::NotAReference,
+                }
+            }
+            (Reference::Hash, Some(b @ (b'x' | b'X'))) => {
+                self.push(b);
+                self.reference = Reference::Numeric {
+                    radix: 16,
+                    value: 0,
+                    digits: false,
+                };
+                Step::Consumed
+            }
+            (Reference::Hash, Some(b)) if b.is_ascii_digit() => {
+                self.reference = Reference::Numeric {
+                    radix: 10,
+                    value: 0,
+                    digits: false,
+                };
+                self.advance_reference(Some(b))
+            }
+            (Reference::Numeric { radix, value, .. }, Some(b)) if (b as char).is_digit(radix) => {
+                let digit = (b as char).to_digit(radix).unwrap();
+                // Saturate just beyond the valid range, so that a long run
+                // of digits can't wrap around into a valid value.
+                let value = value
+                    .saturating_mul(radix)
+                    .saturating_add(digit)
+                    .min(0x110000);
+                self.reference = Reference::Numeric {
+                    radix,
+                    value,
+                    digits: true,
+                };
+                Step::Consumed
+            }
+            (
+                Reference::Numeric {
+                    value,
+                    digits: true,
+                    ..
+                },
+                b,
+            ) => Step::Decoded {
+                consumed: b == Some(b';'),
+                first: match value {
+                    0 => '\u{FFFD}',
+                    value => char::from_u32(value).unwrap_or('\u{FFFD}'),
+                },
+                rest: "",
+            },
+            _ => Step::NotAReference,
+        }
+    }
+
+    fn push(&mut self, b: u8) {
+        self.buf[self.len] = b;
+        self.len += 1;
+    }
+
+    fn feed(&mut self, c: char, offset: u64) -> Option<u64> {
+        match self.machine.next_char(c) {
+            ClusterAction::Split => Some(offset),
+            ClusterAction::Continue => None,
+        }
+    }
+}
+
+impl Default for HtmlEntityMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+enum Step {
+    Consumed,
+    Decoded {
+        consumed: bool,
+        first: char,
+        rest: &'static str,
+    },
+    NotAReference,
+}
+
+/// Returns the text represented by a commonly-used HTML named character
+/// reference, given its name without the `&` and `;`, or `None` if the
+/// name isn't one of those recognized.
+///
+/// This covers the references for markup-significant characters, spacing
+/// and formatting characters, and common typographic symbols, but not the
+/// full table of over two thousand names from the HTML standard. Use
+/// [`HtmlEntityMachine::with_named_references`] to supply a more complete
+/// table when needed.
+pub fn html_named_reference(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "amp" => "&",
+        "lt" => "<",
+        "gt" => ">",
+        "quot" => "\"",
+        "apos" => "'",
+        "nbsp" => "\u{00A0}",
+        "ensp" => "\u{2002}",
+        "emsp" => "\u{2003}",
+        "thinsp" => "\u{2009}",
+        "shy" => "\u{00AD}",
+        "zwnj" => "\u{200C}",
+        "zwj" => "\u{200D}",
+        "lrm" => "\u{200E}",
+        "rlm" => "\u{200F}",
+        "copy" => "\u{00A9}",
+        "reg" => "\u{00AE}",
+        "trade" => "\u{2122}",
+        "deg" => "\u{00B0}",
+        "plusmn" => "\u{00B1}",
+        "times" => "\u{00D7}",
+        "divide" => "\u{00F7}",
+        "micro" => "\u{00B5}",
+        "middot" => "\u{00B7}",
+        "para" => "\u{00B6}",
+        "sect" => "\u{00A7}",
+        "cent" => "\u{00A2}",
+        "pound" => "\u{00A3}",
+        "yen" => "\u{00A5}",
+        "euro" => "\u{20AC}",
+        "laquo" => "\u{00AB}",
+        "raquo" => "\u{00BB}",
+        "lsquo" => "\u{2018}",
+        "rsquo" => "\u{2019}",
+        "ldquo" => "\u{201C}",
+        "rdquo" => "\u{201D}",
+        "ndash" => "\u{2013}",
+        "mdash" => "\u{2014}",
+        "hellip" => "\u{2026}",
+        "bull" => "\u{2022}",
+        "iexcl" => "\u{00A1}",
+        "iquest" => "\u{00BF}",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,83 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn boundaries(src: &str) -> Vec<u64> {
+    let mut machine = HtmlEntityMachine::new();
+    let mut got: Vec<_> = machine.boundaries_in_str(src).collect();
+    got.extend(machine.end_of_input());
+    got
+}
+
+#[test]
+fn plain_text() {
+    assert_eq!(boundaries("ab"), &[0, 1, 2]);
+    assert_eq!(boundaries(""), &[0]);
+}
+
+#[test]
+fn named_references() {
+    assert_eq!(boundaries("a&amp;b"), &[0, 1, 6, 7]);
+    // "&zwj;" joins the two emoji into one cluster.
+    assert_eq!(boundaries("\u{1F469}&zwj;\u{1F466}!"), &[0, 13, 14]);
+    // An unknown name is literal text, and so is one without ";".
+    assert_eq!(boundaries("&bogus;"), &[0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(boundaries("&amp x"), &[0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn numeric_references() {
+    // The decoded combining mark continues the "e".
+    assert_eq!(boundaries("e&#x301;x"), &[0, 8, 9]);
+    assert_eq!(boundaries("e&#769;x"), &[0, 7, 8]);
+    assert_eq!(boundaries("&#x1F1E6;&#x1F1E7;."), &[0, 18, 19]);
+    // The ";" is optional for numeric references.
+    assert_eq!(boundaries("e&#769 x"), &[0, 6, 7, 8]);
+    // But there must be at least one digit.
+    assert_eq!(boundaries("&#;"), &[0, 1, 2, 3]);
+    assert_eq!(boundaries("&#x"), &[0, 1, 2, 3]);
+}
+
+#[test]
+fn invalid_numbers() {
+    let mut machine = HtmlEntityMachine::with_named_references(|_| None);
+    machine.boundaries_in_str("ab").count();
+    // Each of these decodes to U+FFFD, which starts its own cluster.
+    let got: Vec<_> = machine
+        .boundaries_in_str("&#0;&#xD800;&#99999999999;")
+        .collect();
+    assert_eq!(got, &[2, 6, 14]);
+}
+
+#[test]
+fn reference_after_literal_ampersand() {
+    assert_eq!(boundaries("&&lt;"), &[0, 1, 5]);
+    assert_eq!(boundaries("&x&#65;"), &[0, 1, 2, 7]);
+}
+
+#[test]
+fn chunked_source() {
+    // The longest name in the HTML table isn't in the default one, so it's
+    // buffered in full and then segmented as 33 literal characters.
+    let src = "a&#x301;&amp;&CounterClockwiseContourIntegral;";
+    for split in 0..=src.len() {
+        let mut machine = HtmlEntityMachine::new();
+        let (a, b) = src.split_at(split);
+        let mut got: Vec<_> = machine.boundaries_in_str(a).collect();
+        got.extend(machine.boundaries_in_str(b));
+        got.extend(machine.end_of_input());
+        assert_eq!(got.len(), 36, "split at {split}");
+        assert_eq!(got[..3], [0, 8, 13], "split at {split}");
+    }
+}
+
+#[test]
+fn custom_lookup() {
+    let mut machine = HtmlEntityMachine::with_named_references(|name| match name {
+        "family" => Some("\u{1F469}\u{200D}\u{1F466}"),
+        _ => None,
+    });
+    let mut got: Vec<_> = machine.boundaries_in_str("&family;&amp;").collect();
+    got.extend(machine.end_of_input());
+    assert_eq!(got, &[0, 8, 9, 10, 11, 12, 13]);
+}
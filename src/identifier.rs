@@ -0,0 +1,228 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{CharProperties, GCBProperty, InCBProperty, StrClusters, clusters_in_str};
+
+/// Selects which of the rules checked by [`identifier_violations`] apply.
+///
+/// These rules are intended to catch strings that use grapheme cluster
+/// tricks to imitate other strings, such as when validating user names
+/// or other identifiers chosen by one person and displayed to others.
+///
+/// Start from [`Self::STRICT`] and then disable individual rules as needed,
+/// since more rules may be added in future versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IdentifierPolicy {
+    /// Require every grapheme cluster to have exactly one base character,
+    /// reporting [`IdentifierViolationKind::MultipleBases`] otherwise.
+    ///
+    /// Characters that extend the cluster, such as combining marks, spacing
+    /// marks, and joiners, are not bases. The consonants that follow a
+    /// virama in an Indic conjunct and the vowel and trailing jamo of a
+    /// decomposed Hangul syllable are not counted separately. Flags and
+    /// emoji sequences joined by zero width joiners do have multiple bases,
+    /// and so this rule rejects them.
+    pub single_base: bool,
+    /// Reject grapheme clusters that would be invisible when displayed,
+    /// reporting [`IdentifierViolationKind::Invisible`].
+    ///
+    /// That includes whitespace, control and format characters, the
+    /// default ignorable characters described for
+    /// [`CharProperties::is_default_ignorable`], and clusters made only of
+    /// extending characters.
+    pub no_invisible: bool,
+    /// Reject strings whose first grapheme cluster begins with a combining
+    /// mark or other extending character, reporting
+    /// [`IdentifierViolationKind::LeadingExtender`].
+    ///
+    /// Such a character would combine with whatever text is displayed
+    /// immediately before the identifier.
+    pub no_leading_extender: bool,
+}
+
+impl IdentifierPolicy {
+    /// A policy with all of the rules enabled.
+    pub const STRICT: Self = Self {
+        single_base: true,
+        no_invisible: true,
+        no_leading_extender: true,
+    };
+}
+
+impl Default for IdentifierPolicy {
+    fn default() -> Self {
+        Self::STRICT
+    }
+}
+
+/// A rule from an [`IdentifierPolicy`] that a grapheme cluster violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdentifierViolationKind {
+    /// The cluster has more than one base character.
+    MultipleBases,
+    /// The cluster would be invisible.
+    Invisible,
+    /// The first cluster begins with an extending character.
+    LeadingExtender,
+}
+
+/// Describes a grapheme cluster that violates the rules of an
+/// [`IdentifierPolicy`], as returned by [`identifier_violations`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentifierViolation {
+    /// Which rule the cluster violates.
+    pub kind: IdentifierViolationKind,
+    /// The byte range of the cluster in the string that was checked.
+    pub cluster: Range<usize>,
+}
+
+impl fmt::Display for IdentifierViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            IdentifierViolationKind::MultipleBases => "has multiple base characters",
+            IdentifierViolationKind::Invisible => "is invisible",
+            IdentifierViolationKind::LeadingExtender => "begins with an extending character",
+        };
+        write!(
+            f,
+            "grapheme cluster at offset {} {what}",
+            self.cluster.start
+        )
+    }
+}
+
+impl core::error::Error for IdentifierViolation {}
+
+/// Returns an iterator over the ways that `s` violates the given policy, in
+/// order of the grapheme clusters that violate them.
+///
+/// The string is acceptable under the policy if the iterator produces no
+/// items at all, as checked by [`is_valid_identifier`]. A cluster that
+/// violates several rules produces one item for each.
+///
+/// These checks address only the structure of grapheme clusters. They
+/// aren't a complete defense against spoofing, which also requires
+/// checking for confusable characters and mixed scripts, as described in
+/// [UTS #39](https://www.unicode.org/reports/tr39/).
+pub fn identifier_violations(s: &str, policy: IdentifierPolicy) -> IdentifierViolations<'_> {
+    IdentifierViolations {
+        clusters: clusters_in_str(s),
+        policy,
+        start: 0,
+        end: 0,
+        pending: [None; 3],
+    }
+}
+
+/// Returns `true` if `s` is acceptable under the given policy, meaning that
+/// [`identifier_violations`] would find no violations.
+pub fn is_valid_identifier(s: &str, policy: IdentifierPolicy) -> bool {
+    identifier_violations(s, policy).next().is_none()
+}
+
+/// An iterator over the violations of an [`IdentifierPolicy`] in a string.
+///
+/// Construct this using [`identifier_violations`].
+#[derive(Debug)]
+pub struct IdentifierViolations<'a> {
+    clusters: StrClusters<'a>,
+    policy: IdentifierPolicy,
+    // The range of the cluster that the pending violations belong to.
+    start: usize,
+    end: usize,
+    pending: [Option<IdentifierViolationKind>; 3],
+}
+
+impl<'a> Iterator for IdentifierViolations<'a> {
+    type Item = IdentifierViolation;
+
+    fn next(&mut self) -> Option<IdentifierViolation> {
+        loop {
+            if let Some(kind) = self.pending.iter_mut().find_map(Option::take) {
+                return Some(IdentifierViolation {
+                    kind,
+                    cluster: self.start..self.end,
+                });
+            }
+            let cluster = self.clusters.next()?;
+            let first = self.end == 0;
+            self.start = self.end;
+            self.end += cluster.len();
+            self.pending = self.check(cluster, first);
+        }
+    }
+}
+
+impl<'a> FusedIterator for IdentifierViolations<'a> {}
+
+impl<'a> IdentifierViolations<'a> {
+    fn check(&self, cluster: &str, first: bool) -> [Option<IdentifierViolationKind>; 3] {
+        let policy = &self.policy;
+        let mut ret = [None; 3];
+        let leading = cluster
+            .chars()
+            .next()
+            .is_some_and(|c| is_extender(CharProperties::for_char(c)));
+        if policy.no_leading_extender && first && leading {
+            ret[0] = Some(IdentifierViolationKind::LeadingExtender);
+        }
+        if policy.no_invisible && cluster.chars().all(is_invisible) {
+            ret[1] = Some(IdentifierViolationKind::Invisible);
+        }
+        if policy.single_base && count_bases(cluster) > 1 {
+            ret[2] = Some(IdentifierViolationKind::MultipleBases);
+        }
+        ret
+    }
+}
+
+fn is_extender(props: CharProperties) -> bool {
+    matches!(
+        props.gcb_property(),
+        GCBProperty::Extend | GCBProperty::ZWJ | GCBProperty::SpacingMark
+    )
+}
+
+fn is_invisible(c: char) -> bool {
+    let props = CharProperties::for_char(c);
+    if c.is_whitespace() || props.is_default_ignorable() {
+        return true;
+    }
+    matches!(
+        props.gcb_property(),
+        GCBProperty::Control
+            | GCBProperty::CR
+            | GCBProperty::LF
+            | GCBProperty::Extend
+            | GCBProperty::ZWJ
+    )
+}
+
+fn count_bases(cluster: &str) -> usize {
+    let mut count = 0;
+    let mut after_linker = false;
+    for c in cluster.chars() {
+        let props = CharProperties::for_char(c);
+        let incb = props.incb_property();
+        let counted = match props.gcb_property() {
+            // The vowel and trailing jamo complete a syllable begun by
+            // a leading jamo or precomposed syllable.
+            GCBProperty::V | GCBProperty::T => false,
+            _ if is_extender(props) => false,
+            _ => !(after_linker && incb == InCBProperty::Consonant),
+        };
+        match incb {
+            InCBProperty::Linker => after_linker = true,
+            InCBProperty::Extend => {}
+            _ => after_linker = false,
+        }
+        count += usize::from(counted);
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn violations(s: &str) -> Vec<(IdentifierViolationKind, Range<usize>)> {
+    identifier_violations(s, IdentifierPolicy::STRICT)
+        .map(|v| (v.kind, v.cluster))
+        .collect()
+}
+
+#[test]
+fn valid() {
+    for s in [
+        "alice",
+        "José",
+        "jose\u{0301}",
+        "नमस्ते",
+        "한국어",
+        "\u{1112}\u{1161}\u{11AB}",
+    ] {
+        assert!(is_valid_identifier(s, IdentifierPolicy::STRICT), "{s:?}");
+    }
+}
+
+#[test]
+fn violations_found() {
+    use IdentifierViolationKind::*;
+
+    assert_eq!(
+        violations("\u{0301}bob"),
+        &[(LeadingExtender, 0..2), (Invisible, 0..2)]
+    );
+    assert_eq!(violations("bo\u{200B}b"), &[(Invisible, 2..5)]);
+    assert_eq!(violations("a b"), &[(Invisible, 1..2)]);
+    assert_eq!(violations("\u{3164}admin"), &[(Invisible, 0..3)]);
+    assert_eq!(violations("x🇦🇧"), &[(MultipleBases, 1..9)]);
+    assert_eq!(violations("🧑‍🌾"), &[(MultipleBases, 0..11)]);
+    assert!(violations("").is_empty());
+
+    // Disabling a rule stops it from being reported.
+    let mut policy = IdentifierPolicy::STRICT;
+    policy.single_base = false;
+    assert!(is_valid_identifier("🧑‍🌾", policy));
+    policy.no_leading_extender = false;
+    let got: Vec<_> = identifier_violations("\u{0301}bob", policy).collect();
+    assert_eq!(
+        got,
+        &[IdentifierViolation {
+            kind: Invisible,
+            cluster: 0..2
+        }]
+    );
+    assert_eq!(
+        got[0].to_string(),
+        "grapheme cluster at offset 0 is invisible"
+    );
+}
@@ -0,0 +1,306 @@
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+use crate::{GraphemeMachine, Segmenter};
+
+#[cfg(feature = "alloc")]
+use crate::SegmentAction;
+
+/// An iterator adapter that feeds each [`char`] from some other iterator
+/// into a [`GraphemeMachine`] (or any other [`Segmenter`]), yielding each
+/// character along with the [`ClusterAction`](crate::ClusterAction) the
+/// machine chose for it.
+///
+/// This is a convenience wrapper for callers whose characters are already
+/// coming from an iterator, such as [`str::chars`], a decoder for some
+/// other character encoding, or a generator. It performs the same
+/// bookkeeping a caller would otherwise do by hand with
+/// [`GraphemeMachine::next_char`].
+///
+/// As with [`GraphemeMachine::next_chars_from_str`], there is no automatic
+/// call to [`GraphemeMachine::end_of_input`] when the inner iterator is
+/// exhausted.
+#[derive(Debug)]
+pub struct CharActions<I, S = GraphemeMachine> {
+    machine: S,
+    inner: I,
+}
+
+impl<I> CharActions<I>
+where
+    I: Iterator<Item = char>,
+{
+    /// Wraps the given iterator with a new [`GraphemeMachine`] in its
+    /// initial "start of input" state.
+    pub fn new(inner: impl IntoIterator<IntoIter = I>) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+}
+
+impl<I, S> CharActions<I, S>
+where
+    I: Iterator<Item = char>,
+    S: Segmenter,
+{
+    /// Wraps the given iterator using an existing machine, which allows
+    /// continuing segmentation of a stream that was previously being fed
+    /// into the machine some other way, or using a [`Segmenter`] other
+    /// than [`GraphemeMachine`].
+    pub fn with_machine(machine: S, inner: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            machine,
+            inner: inner.into_iter(),
+        }
+    }
+
+    /// Consumes the adapter and returns the machine and the inner iterator,
+    /// both in whatever state they were left in by the most recent call to
+    /// [`Iterator::next`].
+    pub fn into_parts(self) -> (S, I) {
+        (self.machine, self.inner)
+    }
+
+    /// Converts this adapter into one that groups the characters into
+    /// complete grapheme clusters, each represented as a
+    /// [`String`](alloc::string::String).
+    #[cfg(feature = "alloc")]
+    pub fn clusters(self) -> CharClusters<I, S> {
+        CharClusters {
+            actions: self,
+            pending: None,
+        }
+    }
+}
+
+impl<I, S> Iterator for CharActions<I, S>
+where
+    I: Iterator<Item = char>,
+    S: Segmenter,
+{
+    type Item = (S::Action, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.inner.next()?;
+        Some((self.machine.next_char(c), c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, S> FusedIterator for CharActions<I, S>
+where
+    I: FusedIterator<Item = char>,
+    S: Segmenter,
+{
+}
+
+/// An iterator adapter that groups the [`char`] values from some other
+/// iterator into grapheme clusters, yielding each one as a
+/// [`String`](alloc::string::String) once it is complete.
+///
+/// A cluster is only known to be complete once the first character of the
+/// next cluster has arrived, or once the inner iterator is exhausted, and
+/// so this adapter reads one character ahead of the cluster it returns.
+///
+/// Construct this using [`CharActions::clusters`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct CharClusters<I, S = GraphemeMachine> {
+    actions: CharActions<I, S>,
+    pending: Option<char>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Iterator for CharClusters<I, S>
+where
+    I: Iterator<Item = char>,
+    S: Segmenter,
+{
+    type Item = alloc::string::String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(c) => c,
+            None => self.actions.next()?.1,
+        };
+        let mut cluster = alloc::string::String::new();
+        cluster.push(first);
+        for (action, c) in self.actions.by_ref() {
+            if action.is_boundary() {
+                self.pending = Some(c);
+                break;
+            }
+            cluster.push(c);
+        }
+        Some(cluster)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining character could begin a new cluster, but all of
+        // them could also belong to just one.
+        let (lower, upper) = self.actions.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        let lower = usize::from(pending + lower > 0);
+        (lower, upper.and_then(|n| n.checked_add(pending)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> FusedIterator for CharClusters<I, S>
+where
+    I: FusedIterator<Item = char>,
+    S: Segmenter,
+{
+}
+
+/// An iterator adapter that feeds each [`u8char`] from some other iterator
+/// into a [`GraphemeMachine`] (or any other [`Segmenter`]), yielding each
+/// character along with the [`ClusterAction`](crate::ClusterAction) the
+/// machine chose for it.
+///
+/// This is the [`u8char`] equivalent of [`CharActions`], for callers whose
+/// characters are coming from something like
+/// [`AsU8Chars::u8chars`](::u8char::AsU8Chars::u8chars). Staying in the
+/// [`u8char`] representation avoids converting each character to [`char`]
+/// and back again just to look up its properties.
+///
+/// As with [`GraphemeMachine::next_u8chars_from_str`], there is no automatic
+/// call to [`GraphemeMachine::end_of_input`] when the inner iterator is
+/// exhausted.
+#[derive(Debug)]
+pub struct U8CharActions<I, S = GraphemeMachine> {
+    machine: S,
+    inner: I,
+}
+
+impl<I> U8CharActions<I>
+where
+    I: Iterator<Item = u8char>,
+{
+    /// Wraps the given iterator with a new [`GraphemeMachine`] in its
+    /// initial "start of input" state.
+    pub fn new(inner: impl IntoIterator<IntoIter = I>) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+}
+
+impl<I, S> U8CharActions<I, S>
+where
+    I: Iterator<Item = u8char>,
+    S: Segmenter,
+{
+    /// Wraps the given iterator using an existing machine, which allows
+    /// continuing segmentation of a stream that was previously being fed
+    /// into the machine some other way, or using a [`Segmenter`] other
+    /// than [`GraphemeMachine`].
+    pub fn with_machine(machine: S, inner: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            machine,
+            inner: inner.into_iter(),
+        }
+    }
+
+    /// Consumes the adapter and returns the machine and the inner iterator,
+    /// both in whatever state they were left in by the most recent call to
+    /// [`Iterator::next`].
+    pub fn into_parts(self) -> (S, I) {
+        (self.machine, self.inner)
+    }
+
+    /// Converts this adapter into one that groups the characters into
+    /// complete grapheme clusters, each represented as a
+    /// [`String`](alloc::string::String).
+    #[cfg(feature = "alloc")]
+    pub fn clusters(self) -> U8CharClusters<I, S> {
+        U8CharClusters {
+            actions: self,
+            pending: None,
+        }
+    }
+}
+
+impl<I, S> Iterator for U8CharActions<I, S>
+where
+    I: Iterator<Item = u8char>,
+    S: Segmenter,
+{
+    type Item = (S::Action, u8char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.inner.next()?;
+        Some((self.machine.next_u8char(c), c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, S> FusedIterator for U8CharActions<I, S>
+where
+    I: FusedIterator<Item = u8char>,
+    S: Segmenter,
+{
+}
+
+/// An iterator adapter that groups the [`u8char`] values from some other
+/// iterator into grapheme clusters, yielding each one as a
+/// [`String`](alloc::string::String) once it is complete.
+///
+/// This behaves the same way as [`CharClusters`], including reading one
+/// character ahead of the cluster it returns.
+///
+/// Construct this using [`U8CharActions::clusters`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct U8CharClusters<I, S = GraphemeMachine> {
+    actions: U8CharActions<I, S>,
+    pending: Option<u8char>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> Iterator for U8CharClusters<I, S>
+where
+    I: Iterator<Item = u8char>,
+    S: Segmenter,
+{
+    type Item = alloc::string::String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(c) => c,
+            None => self.actions.next()?.1,
+        };
+        let mut cluster = alloc::string::String::new();
+        cluster.push_str(first.as_str());
+        for (action, c) in self.actions.by_ref() {
+            if action.is_boundary() {
+                self.pending = Some(c);
+                break;
+            }
+            cluster.push_str(c.as_str());
+        }
+        Some(cluster)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining character could begin a new cluster, but all of
+        // them could also belong to just one.
+        let (lower, upper) = self.actions.size_hint();
+        let pending = usize::from(self.pending.is_some());
+        let lower = usize::from(pending + lower > 0);
+        (lower, upper.and_then(|n| n.checked_add(pending)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, S> FusedIterator for U8CharClusters<I, S>
+where
+    I: FusedIterator<Item = u8char>,
+    S: Segmenter,
+{
+}
+
+#[cfg(test)]
+mod tests;
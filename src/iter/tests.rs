@@ -0,0 +1,108 @@
+use super::*;
+use crate::ClusterAction;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn char_actions() {
+    use ClusterAction::*;
+    let got: Vec<_> = CharActions::new("a\r\n🇦🇧".chars()).collect();
+    assert_eq!(
+        got,
+        &[
+            (Split, 'a'),
+            (Split, '\r'),
+            (Continue, '\n'),
+            (Split, '\u{1F1E6}'),
+            (Continue, '\u{1F1E7}'),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn char_clusters() {
+    let got: Vec<_> = CharActions::new("Hello!\r\nBeep 🧑‍🌾".chars())
+        .clusters()
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            "H",
+            "e",
+            "l",
+            "l",
+            "o",
+            "!",
+            "\r\n",
+            "B",
+            "e",
+            "e",
+            "p",
+            " ",
+            "🧑‍🌾"
+        ]
+    );
+
+    let got: Vec<_> = CharActions::new("".chars()).clusters().collect();
+    assert_eq!(got, Vec::<String>::new());
+}
+
+#[test]
+fn u8char_actions() {
+    use ::u8char::AsU8Chars;
+    use ClusterAction::*;
+    let got: Vec<_> = U8CharActions::new("a\r\n🇦🇧".u8chars())
+        .map(|(a, c)| (a, c.to_char()))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            (Split, 'a'),
+            (Split, '\r'),
+            (Continue, '\n'),
+            (Split, '\u{1F1E6}'),
+            (Continue, '\u{1F1E7}'),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn u8char_clusters() {
+    use ::u8char::AsU8Chars;
+    let got: Vec<_> = U8CharActions::new("Hello!\r\nBeep 🧑‍🌾".u8chars())
+        .clusters()
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            "H",
+            "e",
+            "l",
+            "l",
+            "o",
+            "!",
+            "\r\n",
+            "B",
+            "e",
+            "e",
+            "p",
+            " ",
+            "🧑‍🌾"
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn clusters_size_hint() {
+    let chars: Vec<char> = "ab\u{0301}".chars().collect();
+    let mut clusters = CharActions::new(chars).clusters();
+    assert_eq!(clusters.size_hint(), (1, Some(3)));
+    assert_eq!(clusters.next().as_deref(), Some("a"));
+    // The "b" is now pending, so there's definitely at least one more.
+    assert_eq!(clusters.size_hint(), (1, Some(2)));
+    assert_eq!(clusters.next().as_deref(), Some("b\u{0301}"));
+    assert_eq!(clusters.size_hint(), (0, Some(0)));
+}
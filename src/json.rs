@@ -0,0 +1,278 @@
+use core::iter::FusedIterator;
+
+use crate::{GraphemeMachine, InvalidJsonStringError, JsonStringErrorKind, OffsetAction};
+
+/// A wrapper around [`GraphemeMachine`] that accepts the raw source of a
+/// JSON string, decoding escape sequences as it goes, and reports each
+/// grapheme cluster boundary as an offset into that source.
+///
+/// This is for segmenting string values while streaming-parsing JSON,
+/// without first making an unescaped copy of each value. The input is the
+/// content between the quotes: UTF-8 text in which `\"`, `\\`, `\/`, `\b`,
+/// `\f`, `\n`, `\r`, `\t` and `\uXXXX` escapes each represent a single
+/// character, and in which a `\uXXXX` escape of a high surrogate must be
+/// followed immediately by another for a low surrogate, the pair together
+/// representing one supplementary character.
+///
+/// The input can be split into chunks at arbitrary points, including
+/// partway through an escape sequence or a UTF-8 sequence, and so the
+/// offsets count all of the source bytes so far. The offset reported for
+/// each character is where its escape sequence or UTF-8 sequence begins.
+///
+/// Unlike a general JSON parser, this doesn't look for the closing quote.
+/// An unescaped `"` or control character is reported as an error, because
+/// neither can appear inside a JSON string.
+#[derive(Debug, Default)]
+pub struct JsonStringMachine {
+    machine: GraphemeMachine,
+    // The offset of the next source byte.
+    offset: u64,
+    // The offset where the character being decoded begins.
+    char_start: u64,
+    decode: Decode,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Decode {
+    #[default]
+    Ready,
+    Utf8 {
+        buf: [u8; 4],
+        len: u8,
+        want: u8,
+    },
+    Backslash,
+    Hex {
+        high: Option<u16>,
+        value: u16,
+        digits: u8,
+    },
+    // A high surrogate was decoded, and so the next bytes must be "\u".
+    AfterHigh {
+        high: u16,
+    },
+    AfterHighBackslash {
+        high: u16,
+    },
+}
+
+impl JsonStringMachine {
+    /// Constructs a new machine in an initial "start of input" state, at
+    /// offset zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the offset of the next source byte the machine will read.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Accepts the next byte of the JSON string source, returning the
+    /// action for the character it completes, if any, along with the
+    /// offset where that character's representation begins.
+    ///
+    /// If the byte makes the source invalid then the escape or UTF-8
+    /// sequence it was part of is discarded, along with the byte itself,
+    /// and the machine resumes decoding with the next byte.
+    pub fn next_byte(&mut self, b: u8) -> Result<Option<OffsetAction>, InvalidJsonStringError> {
+        let offset = self.offset;
+        self.offset = self.offset.saturating_add(1);
+        match self.decode {
+            Decode::Ready => {
+                self.char_start = offset;
+                match b {
+                    b'\\' => self.decode = Decode::Backslash,
+                    b'"' => return Err(self.error(JsonStringErrorKind::UnescapedQuote)),
+                    0x00..=0x1f => return Err(self.error(JsonStringErrorKind::ControlCharacter)),
+                    0x20..=0x7f => return Ok(Some(self.emit(b as u32))),
+                    lead => {
+                        let want = match lead {
+                            0xc2..=0xdf => 2,
+                            0xe0..=0xef => 3,
+                            0xf0..=0xf4 => 4,
+                            _ => return Err(self.error(JsonStringErrorKind::InvalidUtf8)),
+                        };
+                        self.decode = Decode::Utf8 {
+                            buf: [lead, 0, 0, 0],
+                            len: 1,
+                            want,
+                        };
+                    }
+                }
+            }
+            Decode::Utf8 { mut buf, len, want } => {
+                if b & 0xc0 != 0x80 {
+                    return Err(self.error(JsonStringErrorKind::InvalidUtf8));
+                }
+                buf[len as usize] = b;
+                let len = len + 1;
+                if len < want {
+                    self.decode = Decode::Utf8 { buf, len, want };
+                    return Ok(None);
+                }
+                // This rejects overlong encodings and surrogates, which the
+                // lead byte alone can't rule out.
+                return match core::str::from_utf8(&buf[..len as usize]) {
+                    Ok(s) => {
+                        self.decode = Decode::Ready;
+                        // There's exactly one character in s.
+                        Ok(Some(self.emit(s.chars().next().unwrap() as u32)))
+                    }
+                    Err(_) => Err(self.error(JsonStringErrorKind::InvalidUtf8)),
+                };
+            }
+            Decode::Backslash => {
+                let c = match b {
+                    b'"' | b'\\' | b'/' => b,
+                    b'b' => 0x08,
+                    b'f' => 0x0c,
+                    b'n' => b'\n',
+                    b'r' => b'\r',
+                    b't' => b'\t',
+                    b'u' => {
+                        self.decode = Decode::Hex {
+                            high: None,
+                            value: 0,
+                            digits: 0,
+                        };
+                        return Ok(None);
+                    }
+                    _ => return Err(self.error(JsonStringErrorKind::InvalidEscape)),
+                };
+                self.decode = Decode::Ready;
+                return Ok(Some(self.emit(c as u32)));
+            }
+            Decode::Hex {
+                high,
+                value,
+                digits,
+            } => {
+                let Some(digit) = (b as char).to_digit(16) else {
+                    return Err(self.error(JsonStringErrorKind::InvalidHexDigit));
+                };
+                let value = value << 4 | digit as u16;
+                let digits = digits + 1;
+                if digits < 4 {
+                    self.decode = Decode::Hex {
+                        high,
+                        value,
+                        digits,
+                    };
+                    return Ok(None);
+                }
+                let c = match (high, value) {
+                    (None, 0xd800..=0xdbff) => {
+                        self.decode = Decode::AfterHigh { high: value };
+                        return Ok(None);
+                    }
+                    (None, 0xdc00..=0xdfff) => {
+                        return Err(self.error(JsonStringErrorKind::UnpairedSurrogate));
+                    }
+                    (None, c) => c as u32,
+                    (Some(high), 0xdc00..=0xdfff) => {
+                        0x10000 + ((high as u32 - 0xd800) << 10 | (value as u32 - 0xdc00))
+                    }
+                    (Some(_), _) => {
+                        return Err(self.error(JsonStringErrorKind::UnpairedSurrogate));
+                    }
+                };
+                self.decode = Decode::Ready;
+                return Ok(Some(self.emit(c)));
+            }
+            Decode::AfterHigh { high } => match b {
+                b'\\' => self.decode = Decode::AfterHighBackslash { high },
+                _ => return Err(self.error(JsonStringErrorKind::UnpairedSurrogate)),
+            },
+            Decode::AfterHighBackslash { high } => match b {
+                b'u' => {
+                    self.decode = Decode::Hex {
+                        high: Some(high),
+                        value: 0,
+                        digits: 0,
+                    }
+                }
+                _ => return Err(self.error(JsonStringErrorKind::UnpairedSurrogate)),
+            },
+        }
+        Ok(None)
+    }
+
+    /// Returns an iterator which feeds each byte of `src` into the machine
+    /// in turn and yields the source offset of each grapheme cluster
+    /// boundary it finds, including the one at the start of the chunk if
+    /// any.
+    ///
+    /// The iterator ends after returning the first error. As with
+    /// [`OffsetGraphemeMachine::boundaries_in_str`](crate::OffsetGraphemeMachine::boundaries_in_str),
+    /// there is no automatic call to [`Self::end_of_input`] at the end, so
+    /// the source can be provided in a series of chunks.
+    pub fn boundaries_in_source<'a>(
+        &'a mut self,
+        src: &'a [u8],
+    ) -> impl FusedIterator<Item = Result<u64, InvalidJsonStringError>> + 'a {
+        let mut bytes = src.iter();
+        let mut failed = false;
+        core::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            for &b in bytes.by_ref() {
+                match self.next_byte(b) {
+                    Ok(Some(action)) => {
+                        if let Some(offset) = action.boundary() {
+                            return Some(Ok(offset));
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        failed = true;
+                        return Some(Err(err));
+                    }
+                }
+            }
+            None
+        })
+        .fuse()
+    }
+
+    /// Tells the machine that the string has ended, returning a
+    /// [`ClusterAction::Split`](crate::ClusterAction::Split) at the current offset to mark the end of the
+    /// final grapheme cluster.
+    ///
+    /// Returns an error if the source ended partway through an escape
+    /// sequence or UTF-8 sequence, in which case that incomplete sequence
+    /// is discarded. Either way the machine can then be used for another
+    /// string, with offsets continuing from the end of this one.
+    pub fn end_of_input(&mut self) -> Result<OffsetAction, InvalidJsonStringError> {
+        let incomplete = !matches!(self.decode, Decode::Ready);
+        let action = OffsetAction {
+            action: self.machine.end_of_input(),
+            offset: self.offset,
+        };
+        match incomplete {
+            true => Err(self.error(JsonStringErrorKind::Incomplete)),
+            false => Ok(action),
+        }
+    }
+
+    fn emit(&mut self, c: u32) -> OffsetAction {
+        // Every caller has already excluded the surrogates.
+        let c = char::from_u32(c).unwrap();
+        OffsetAction {
+            action: self.machine.next_char(c),
+            offset: self.char_start,
+        }
+    }
+
+    fn error(&mut self, kind: JsonStringErrorKind) -> InvalidJsonStringError {
+        self.decode = Decode::Ready;
+        InvalidJsonStringError {
+            offset: self.char_start,
+            kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn boundaries(src: &str) -> Vec<u64> {
+    let mut machine = JsonStringMachine::new();
+    let got: Result<Vec<_>, _> = machine.boundaries_in_source(src.as_bytes()).collect();
+    let mut got = got.unwrap();
+    got.push(machine.end_of_input().unwrap().offset);
+    got
+}
+
+fn error(src: &[u8]) -> (JsonStringErrorKind, u64) {
+    let mut machine = JsonStringMachine::new();
+    let found = machine.boundaries_in_source(src).find_map(Result::err);
+    let err = found.unwrap_or_else(|| machine.end_of_input().unwrap_err());
+    (err.kind(), err.offset())
+}
+
+#[test]
+fn plain_text() {
+    assert_eq!(boundaries("ab"), &[0, 1, 2]);
+    assert_eq!(boundaries("e\u{0301}🇦🇧"), &[0, 3, 11]);
+}
+
+#[test]
+fn simple_escapes() {
+    // The escaped CR LF is still one cluster.
+    assert_eq!(boundaries(r#"a\r\nb"#), &[0, 1, 5, 6]);
+    assert_eq!(boundaries(r#"\"\\\/\b\f\t"#), &[0, 2, 4, 6, 8, 10, 12]);
+}
+
+#[test]
+fn unicode_escapes() {
+    // An escaped combining mark continues the cluster before it.
+    assert_eq!(boundaries(r#"e\u0301x"#), &[0, 7, 8]);
+    // A surrogate pair is a single character.
+    assert_eq!(boundaries(r#"\ud83d\ude00!"#), &[0, 12, 13]);
+    // The family emoji, mixing escaped and raw characters.
+    assert_eq!(
+        boundaries(r#"\ud83d\udc69\u200d👩\u200D\uD83D\uDC66."#),
+        &[0, 40, 41]
+    );
+}
+
+#[test]
+fn chunked_source() {
+    let src = r#"a\u0301\ud83c\udde6\ud83c\udde7"#.as_bytes();
+    for split in 0..src.len() {
+        let mut machine = JsonStringMachine::new();
+        let (a, b) = src.split_at(split);
+        let mut got: Vec<_> = machine
+            .boundaries_in_source(a)
+            .map(Result::unwrap)
+            .collect();
+        got.extend(machine.boundaries_in_source(b).map(Result::unwrap));
+        assert_eq!(got, &[0, 7], "split at {split}");
+        assert_eq!(machine.end_of_input().unwrap().offset, 31);
+    }
+}
+
+#[test]
+fn errors() {
+    use JsonStringErrorKind::*;
+    assert_eq!(error(br#"ab\x"#), (InvalidEscape, 2));
+    assert_eq!(error(br#"a\u12g4"#), (InvalidHexDigit, 1));
+    assert_eq!(error(br#"\ud83dx"#), (UnpairedSurrogate, 0));
+    assert_eq!(error(br#"\ud83dA"#), (UnpairedSurrogate, 0));
+    assert_eq!(error(br#"a\ude00"#), (UnpairedSurrogate, 1));
+    assert_eq!(error(b"a\xc3("), (InvalidUtf8, 1));
+    assert_eq!(error(b"\xed\xa0\x80"), (InvalidUtf8, 0));
+    assert_eq!(error(b"a\tb"), (ControlCharacter, 1));
+    assert_eq!(error(br#"a"b"#), (UnescapedQuote, 1));
+    assert_eq!(error(br#"ab\u00"#), (Incomplete, 2));
+    assert_eq!(error(b"\xf0\x9f"), (Incomplete, 0));
+}
+
+#[test]
+fn recovers_after_error() {
+    let mut machine = JsonStringMachine::new();
+    assert!(machine.next_byte(b'\\').unwrap().is_none());
+    assert!(machine.next_byte(b'q').is_err());
+    let action = machine.next_byte(b'z').unwrap().unwrap();
+    assert_eq!(action.offset, 2);
+}
+
+#[test]
+fn error_display() {
+    let err = error_value(b"ab\\q");
+    assert_eq!(
+        err.to_string(),
+        "invalid escape sequence in JSON string at offset 2"
+    );
+    assert_eq!(
+        crate::Error::from(err),
+        crate::Error::InvalidJsonString(err)
+    );
+}
+
+fn error_value(src: &[u8]) -> InvalidJsonStringError {
+    let mut machine = JsonStringMachine::new();
+    machine
+        .boundaries_in_source(src)
+        .find_map(Result::err)
+        .unwrap()
+}
@@ -62,11 +62,12 @@
 //!   defined in terms of groups of characters that share similar properties.
 //!
 //!   This library exposes those categories as part of its public API using
-//!   [`CharProperties`], [`GCBProperty`], and [`InCBProperty`], and so it
-//!   could be useful purely as a character property lookup library even
-//!   if you don't use [`GraphemeMachine`], or you could even choose to use
-//!   your own tailored character property tables and pass [`CharProperties`]
-//!   values directly to a [`GraphemeMachine`] object.
+//!   [`CharProperties`], [`GCBProperty`], [`InCBProperty`], and
+//!   [`EastAsianWidth`], and so it could be useful purely as a character
+//!   property lookup library even if you don't use [`GraphemeMachine`], or
+//!   you could even choose to use your own tailored character property
+//!   tables and pass [`CharProperties`] values directly to a
+//!   [`GraphemeMachine`] object.
 //!
 //! Unless you have a good reason to prefer this library though, it's probably
 //! better to use
@@ -85,27 +86,409 @@
 //! a separate unit for release.
 //!
 //! Unfortunately the code that generated the trie used for character property
-//! lookup seems to be missing, and so this library will probably be tethered
-//! to Unicode 16.0.0 indefinitely unless the author gets somehow inspired
-//! to recreate that generation program. 😖 If staying up-to-date with new
-//! Unicode versions is important to you then you should probably use
+//! lookup went missing along the way, and so for a while this library was
+//! tethered to Unicode 16.0.0. The trie can now be regenerated from the
+//! `GraphemeBreakProperty.txt`, `emoji-data.txt`, `DerivedCoreProperties.txt`
+//! and `PropList.txt` files of any Unicode release by running
+//! `cargo xtask generate-property-table <version> <dir>` in this library's
+//! repository, and the `unicode-*` features described below choose between
+//! the tables for the versions that have been generated so far. New Unicode
+//! versions will still only arrive here as quickly as the author gets around
+//! to doing that, though. If staying up-to-date with new Unicode versions is
+//! important to you then you should probably use
 //! [`unicode_segmentation`](https://docs.rs/unicode-segmentation/latest/unicode_segmentation/)
 //! instead.
 //!
 //! It would in principle be possible to use a property lookup table maintained
 //! outside of this crate and then produce [`CharProperties`] values to pass
 //! into a [`GraphemeMachine`] without using this library's lookup tables at
-//! all, though I expect few would be motivated to do that.
-#![cfg_attr(not(test), no_std)]
+//! all, though I expect few would be motivated to do that. For anyone who is,
+//! `TailoredMachine` drives a [`GraphemeMachine`] using any implementation
+//! of [`PropertyLookup`], and `TailoredTableBuilder` constructs one at
+//! runtime from ranges of characters and their properties, such as those
+//! from newer Unicode character database files loaded at startup. When only
+//! a few characters need different properties, such as for a terminal that
+//! treats U+00A0 NO-BREAK SPACE as a control character, [`OverlayProperties`]
+//! overrides them on top of this library's own tables instead. Disabling
+//! the `tables` feature described below leaves this library's own tables
+//! out of the build.
+//!
+//! # Optional features
+//!
+//! This library is `no_std`-compatible, but some parts of its API are
+//! available only when certain Cargo features are enabled.
+//!
+//! The `machine` and `tables` features are enabled by default, and most of
+//! the API needs both of them. Disable the default features and then enable
+//! only `tables` to use this library purely for character property lookup,
+//! or only `machine` to use [`GraphemeMachine`] with your own property
+//! tables, so that the build includes only the code and data for that use.
+//! The `bytes`, `normalization`, `stream`, `tokio` and `width` features
+//! imply both.
+//!
+//! - `age`: Adds `UnicodeVersion` and the functions for finding the
+//!   Unicode **Age** property of characters and grapheme clusters, such as
+//!   `cluster_supported_by`, at the cost of another lookup table of
+//!   `UnicodeVersion::TABLE_SIZE` bytes.
+//! - `alloc`: Enables the API features that need to allocate memory, such
+//!   as the iterator adapters that produce each grapheme cluster as a
+//!   separate `String`, `BoundaryIndex` for random access to the
+//!   grapheme clusters of a string, `ClusterSink` for passing each cluster
+//!   of chunked text to a closure, `clusters_in_str_chunks` for text stored
+//!   in non-contiguous chunks, such as the leaves of a rope, `GraphemeBuffer` for limiting the length
+//!   of clusters, and `Preedit` for tracking the composing cluster of an
+//!   input method. It also adds `TailoredTableBuilder` for constructing a
+//!   character property lookup table at runtime.
+//! - `bytes`: Adds `BufChars` for feeding characters into a machine from
+//!   any `bytes::Buf`, and `BytesClusters` for splitting `bytes::Bytes` into
+//!   grapheme clusters that share its buffer, using the `bytes` crate.
+//! - `category`: Adds `GeneralCategory` for finding the Unicode
+//!   **General_Category** property of characters, at the cost of another
+//!   lookup table of `GeneralCategory::TABLE_SIZE` bytes.
+//! - `emoji`: Adds `EmojiProperties` for looking up the Unicode emoji
+//!   properties of characters, and `is_emoji_cluster` for deciding whether
+//!   a grapheme cluster is displayed as an emoji, at the cost of another
+//!   lookup table of `EmojiProperties::TABLE_SIZE` bytes.
+//! - `line`: Adds `LineBreakMachine`, a sibling of [`GraphemeMachine`] for
+//!   finding the line break opportunities of the Unicode line breaking
+//!   algorithm in streaming input, along with `line_breaks_in_str` for a
+//!   string that's already in memory. This adds a lookup table of the
+//!   **Line_Break** property, of `LBProperty::TABLE_SIZE` bytes.
+//! - `machine` (enabled by default): Adds [`GraphemeMachine`] itself, along
+//!   with [`PairBoundary`] and [`boundary_before`] for reasoning about
+//!   boundaries using only character properties. Without `tables`, the
+//!   machine accepts only the [`CharProperties`] values given to
+//!   [`GraphemeMachine::next_char_properties`].
+//! - `names`: Adds `CharName` for finding the Unicode **Name** property of
+//!   characters, and `cluster_names` for listing the names of the
+//!   characters in a grapheme cluster. This embeds a large table of
+//!   compressed names, of `CharName::TABLE_SIZE` bytes.
+//! - `normalization`: Implies `alloc`, and adds [`CompareMode`]'s option
+//!   for comparing grapheme clusters by canonical equivalence, using the
+//!   `unicode-normalization` crate.
+//! - `script`: Adds `Script` for finding the Unicode **Script** and
+//!   **Script_Extensions** properties of characters, `cluster_script`
+//!   for choosing a font for each grapheme cluster, and `script_runs` for
+//!   splitting text into runs of clusters in the same script for shaping,
+//!   at the cost of more lookup tables of `Script::TABLE_SIZE` bytes.
+//! - `stream`: Implies `alloc`, and adds `ClusterStream` for segmenting an
+//!   asynchronous `Stream` of `Bytes` chunks, using the `futures-core` and
+//!   `bytes` crates, along with `StrClusterStream` for a `Stream` of
+//!   chunks that are already text.
+//! - `strict`: Makes [`GraphemeMachine`] check its internal consistency
+//!   before each character even in release builds, panicking with a clear
+//!   message instead of silently producing incorrect results. These checks
+//!   always run when debug assertions are enabled.
+//! - `tables` (enabled by default): Embeds the character property lookup
+//!   tables described under [Embedded targets](#embedded-targets), and
+//!   adds [`CharProperties::for_char`] and [`CharProperties::for_u8char`]
+//!   for using them, along with [`GCBProperty::ranges`] and
+//!   [`InCBProperty::ranges`] for listing the characters that have each
+//!   property value.
+//! - `testgen`: Implies `alloc`, and adds `TestStrings` for generating
+//!   realistic test strings from a mix of the kinds of grapheme clusters
+//!   that text handling most often gets wrong, such as emoji ZWJ sequences,
+//!   flags, Devanagari conjuncts and Hangul syllables.
+//! - `tokio`: Implies `std`, and adds `AsyncClusterReader` for reading
+//!   grapheme clusters from a `tokio::io::AsyncBufRead`.
+//! - `unicode-15-1` and `unicode-16-0`: Each imply `tables`, and add the
+//!   character property lookup table for that version of the Unicode
+//!   Standard. The `CharProperties` lookup functions use the newest table
+//!   that's enabled, and the Unicode 16.0 table is included when neither
+//!   feature is, so enable only `unicode-15-1` to pin grapheme cluster
+//!   segmentation to an older platform's Unicode version. The table in use
+//!   is reported by [`CharProperties::UNICODE_VERSION`], and
+//!   [`PropertyTable`] chooses between all of the included tables at
+//!   runtime instead, such as to serve clients pinned to different
+//!   versions from one program. The Unicode 15.1 table differs from the
+//!   16.0 one for the characters first assigned in 16.0, and because
+//!   Unicode 16.0 gave **Indic_Conjunct_Break**=Extend to the extending
+//!   characters with canonical combining class zero, such as U+0941
+//!   DEVANAGARI VOWEL SIGN U, so that they no longer end a conjunct. The
+//!   other lookup tables, such as those added by `age` and `width`, are
+//!   for Unicode 16.0 regardless.
+//! - `width`: Adds `cluster_width` and `str_width` for measuring the
+//!   terminal display width of grapheme clusters, along with `WidthCounter`
+//!   for measuring text that arrives in chunks. This adds a lookup table of
+//!   the **East_Asian_Width** property, of `EastAsianWidth::TABLE_SIZE`
+//!   bytes, which `EastAsianWidth::of_char` consults. It's separate from
+//!   the `CharProperties` lookup table, so enabling this feature doesn't
+//!   change the values that the `CharProperties` lookup functions return.
+//! - `word`: Adds `WordMachine`, a sibling of [`GraphemeMachine`] for
+//!   finding the word boundaries from UAX #29 in streaming input, along with
+//!   `words_in_str` for splitting a string into words. This adds a lookup
+//!   table of the **Word_Break** property, of `WBProperty::TABLE_SIZE`
+//!   bytes.
+//! - `serde`: Implements `Serialize` and `Deserialize` for
+//!   [`BoundaryEvent`], [`GraphemeMachine`], [`ClusterAction`],
+//!   [`CharProperties`], [`GCBProperty`], [`InCBProperty`] and
+//!   [`EastAsianWidth`], using the
+//!   `serde` crate. A machine serializes as its
+//!   [`GraphemeMachine::to_stable_bytes`] encoding.
+//! - `std` (enabled by default): Implies `alloc`, and additionally allows
+//!   the whole-buffer methods like [`GraphemeMachine::count_splits_in_str`]
+//!   and [`GraphemeMachine::next_u8chars_from_str`] to detect at runtime
+//!   which SIMD instructions the current CPU supports, instead of relying
+//!   only on what was enabled at compile time. It also adds
+//!   `GraphemeReader` for reading the grapheme clusters from any
+//!   `std::io::Read`, and `read_tail` for reading the last grapheme
+//!   clusters of a seekable source like a file.
+//!
+//! # Embedded targets
+//!
+//! The character property lookup tables take [`CharProperties::TABLE_SIZE`]
+//! bytes of read-only data. To place them in a specific link section, such as
+//! one in an external flash region that the target can execute in place,
+//! set the `GRAPHEME_MACHINE_LINK_SECTION` environment variable to the
+//! section name when building. The tables are otherwise placed wherever the
+//! target puts immutable statics by default.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+// The documentation is written for the default features, and so links to
+// items that only some of them provide.
+#![cfg_attr(
+    not(all(feature = "machine", feature = "tables")),
+    allow(rustdoc::broken_intra_doc_links)
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "age")]
+mod age;
+#[cfg(feature = "tokio")]
+mod async_reader;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod bidi;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod boundary_index;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod buffered;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod cache;
+#[cfg(feature = "category")]
+mod category;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod chunked;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod chunks;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod count;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod diagnostic;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod distance;
+#[cfg(feature = "emoji")]
+mod emoji;
+mod error;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod event;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod frames;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod grapheme;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod grapheme_buffer;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod grapheme_set;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod html;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod identifier;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod iter;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod json;
+#[cfg(feature = "line")]
+mod line;
+#[cfg(feature = "names")]
+mod names;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod offset;
+#[cfg(feature = "tables")]
+mod overlay;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod pad;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod position;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod preedit;
 mod properties;
+#[cfg(feature = "tables")]
+mod ranges;
+#[cfg(all(feature = "std", feature = "machine", feature = "tables"))]
+mod reader;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod render;
+#[cfg(feature = "machine")]
+mod restart;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod sanitize;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod scan;
+#[cfg(feature = "script")]
+mod script;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod search;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod segmenter;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod sink;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+mod split;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod split_str;
+#[cfg(feature = "machine")]
 mod state;
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod tail;
+#[cfg(feature = "alloc")]
+mod tailored;
+#[cfg(feature = "machine")]
+mod tailored_machine;
+#[cfg(feature = "testgen")]
+mod testgen;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod text;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod utf16;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod utf8;
+mod version;
+#[cfg(feature = "width")]
+mod width;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod windows;
+#[cfg(feature = "word")]
+mod word;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod writer;
+#[cfg(all(feature = "machine", feature = "tables"))]
+mod wtf8;
 
+#[cfg(all(feature = "machine", feature = "tables"))]
 use core::iter::FusedIterator;
 
+#[cfg(feature = "age")]
+pub use age::*;
+#[cfg(feature = "tokio")]
+pub use async_reader::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use bidi::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use boundary_index::*;
+#[cfg(feature = "bytes")]
+pub use buf::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use buffered::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use cache::*;
+#[cfg(feature = "category")]
+pub use category::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use chunked::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use chunks::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use count::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use diagnostic::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use distance::*;
+#[cfg(feature = "emoji")]
+pub use emoji::*;
+pub use error::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use event::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use frames::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use grapheme::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use grapheme_buffer::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use grapheme_set::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use html::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use identifier::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use iter::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use json::*;
+#[cfg(feature = "line")]
+pub use line::*;
+#[cfg(feature = "names")]
+pub use names::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use offset::*;
+#[cfg(feature = "tables")]
+pub use overlay::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use pad::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use position::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use preedit::*;
 pub use properties::*;
+#[cfg(feature = "tables")]
+pub use ranges::*;
+#[cfg(all(feature = "std", feature = "machine", feature = "tables"))]
+pub use reader::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use render::*;
+#[cfg(feature = "machine")]
+pub use restart::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use sanitize::*;
+#[cfg(feature = "script")]
+pub use script::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use search::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use segmenter::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use sink::*;
+#[cfg(all(feature = "alloc", feature = "machine", feature = "tables"))]
+pub use split::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use split_str::*;
+#[cfg(feature = "stream")]
+pub use stream::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use tail::*;
+#[cfg(feature = "alloc")]
+pub use tailored::*;
+#[cfg(feature = "machine")]
+pub use tailored_machine::*;
+#[cfg(feature = "testgen")]
+pub use testgen::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use text::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use utf8::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use utf16::*;
+pub use version::*;
+#[cfg(feature = "width")]
+pub use width::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use windows::*;
+#[cfg(feature = "word")]
+pub use word::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use writer::*;
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub use wtf8::*;
 
+#[cfg(feature = "machine")]
 use state::State;
+#[cfg(feature = "machine")]
+pub use state::{BoundaryState, boundary_between};
+#[cfg(all(feature = "machine", feature = "tables"))]
 use u8char::u8char;
 
 /// A finite state machine for detecting grapheme cluster boundaries.
@@ -130,19 +513,61 @@ use u8char::u8char;
 /// byte. Each newly-submitted character therefore updates the record of
 /// the most recent character and advances the internal state machine based
 /// on the new character.
+///
+/// For the common case of a whole string that's already in memory,
+/// [`clusters_in_str`] drives a machine internally and returns each grapheme
+/// cluster as a slice of the string, so that callers don't need to write
+/// their own loop to buffer each cluster.
+///
+/// # FFI layout
+///
+/// A `GraphemeMachine` is a `#[repr(C)]` struct of four bytes, with an
+/// alignment of two bytes. It's equivalent to the following C declaration:
+///
+/// ```c
+/// struct grapheme_machine {
+///     uint16_t prev;
+///     uint8_t state;
+///     uint8_t reserved;
+/// };
+/// ```
+///
+/// The fields are opaque to foreign code, which should only allocate space
+/// for the struct and copy it as a whole. A machine in its initial state,
+/// as returned by [`Self::new`], has `prev` set to `0x000f` and all of the
+/// other bytes set to zero.
+///
+/// The same four bytes, with `prev` in the target's native byte order, are
+/// available from safe Rust using [`Self::to_bytes`] and
+/// [`Self::from_bytes`]. This layout won't change within a major version of
+/// this library, so those bytes can be kept in shared memory or in a
+/// memory-mapped state file, such as to let a supervisor process resume
+/// segmenting a stream exactly where a crashed worker left off.
+///
+/// For state that's persisted or sent between hosts, which might differ in
+/// byte order or in which version of this library they use, prefer the
+/// versioned encoding from [`Self::to_stable_bytes`] instead.
+#[cfg(feature = "machine")]
+#[repr(C)]
 #[derive(Debug)]
 pub struct GraphemeMachine {
+    // The properties of the previous character, or CharProperties::NO_CHAR
+    // at the start of input. This isn't an Option because Option's layout
+    // isn't suitable for FFI.
+    prev: CharProperties,
     state: State,
-    prev: Option<CharProperties>,
+    reserved: u8,
 }
 
+#[cfg(feature = "machine")]
 impl GraphemeMachine {
     /// Constructs a new [`GraphemeMachine`] in an initial "start of input"
     /// state.
     pub fn new() -> Self {
         GraphemeMachine {
+            prev: CharProperties::NO_CHAR,
             state: State::Base,
-            prev: None,
+            reserved: 0,
         }
     }
 
@@ -158,10 +583,19 @@ impl GraphemeMachine {
     /// At the start of input when there is no previous character the action
     /// is always [`ClusterAction::Split`], because there is no current
     /// grapheme cluster to possibly extend.
+    ///
+    /// When debug assertions or the `strict` feature are enabled, this
+    /// panics if the machine's internal state is inconsistent with the
+    /// properties of the previous character it recorded, which can only
+    /// happen if the machine's memory was modified or restored from
+    /// somewhere other than this library. Without either, the results would
+    /// be unspecified but memory-safe.
     pub fn next_char_properties(&mut self, next: CharProperties) -> ClusterAction {
+        #[cfg(any(debug_assertions, feature = "strict"))]
+        self.check_contract();
         let (boundary, next_state) = self.state.transition(self.prev, next);
         self.state = next_state;
-        self.prev = Some(next);
+        self.prev = next;
         if boundary {
             ClusterAction::Split
         } else {
@@ -174,6 +608,9 @@ impl GraphemeMachine {
     ///
     /// Refer to the documentation of that function for information on the
     /// meaning of the result.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub fn next_u8char(&mut self, c: u8char) -> ClusterAction {
         let props = CharProperties::for_u8char(c);
         self.next_char_properties(props)
@@ -190,11 +627,77 @@ impl GraphemeMachine {
     /// first convert the given character to the `u8char` representation. If
     /// you already have the character in `u8char` form then you can avoid
     /// unnecessary conversions by calling [`Self::next_u8char`] instead.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub fn next_char(&mut self, c: char) -> ClusterAction {
         let props = CharProperties::for_char(c);
         self.next_char_properties(props)
     }
 
+    /// Returns the action that [`Self::next_char_properties`] would return
+    /// for a character with the given properties, without advancing the
+    /// state machine.
+    ///
+    /// This answers "would this character begin a new grapheme cluster?"
+    /// for parsers that need lookahead, without taking a
+    /// [`Self::snapshot`] to restore afterwards.
+    pub fn peek_char_properties(&self, next: CharProperties) -> ClusterAction {
+        #[cfg(any(debug_assertions, feature = "strict"))]
+        self.check_contract();
+        match self.state.transition(self.prev, next) {
+            (true, _) => ClusterAction::Split,
+            (false, _) => ClusterAction::Continue,
+        }
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// passes it to [`Self::peek_char_properties`], without advancing the
+    /// state machine.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn peek_u8char(&self, c: u8char) -> ClusterAction {
+        self.peek_char_properties(CharProperties::for_u8char(c))
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// passes it to [`Self::peek_char_properties`], without advancing the
+    /// state machine.
+    ///
+    /// As with [`Self::next_char`], prefer [`Self::peek_u8char`] if you
+    /// already have the character in `u8char` form.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn peek_char(&self, c: char) -> ClusterAction {
+        self.peek_char_properties(CharProperties::for_char(c))
+    }
+
+    /// Advances the state machine over a batch of characters, writing the
+    /// action for each one into the corresponding element of `actions`.
+    ///
+    /// If `actions` is shorter than `chars` then only as many characters
+    /// as there is room for are processed, and so the result is the number
+    /// of characters that were consumed from the start of `chars` and
+    /// also the number of elements of `actions` that were written. Any
+    /// remaining characters must be passed again in a subsequent call.
+    ///
+    /// The result is the same as passing each character in turn to
+    /// [`Self::next_u8char`], but avoids the per-call overhead for callers
+    /// that already have batches of characters available, such as the
+    /// output of a bulk UTF-8 decoder.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn next_u8chars(&mut self, chars: &[u8char], actions: &mut [ClusterAction]) -> usize {
+        let n = chars.len().min(actions.len());
+        for (c, action) in chars[..n].iter().zip(&mut actions[..n]) {
+            *action = self.next_u8char(*c);
+        }
+        n
+    }
+
     /// Returns an iterator which, on each call to [`Iterator::next`],
     /// takes another [`u8char`] from the prefix of `s`, feeds it into
     /// the state machine using [`Self::next_u8char`], and then returns
@@ -213,44 +716,244 @@ impl GraphemeMachine {
     /// end of the string is reached, so it's okay to provide streaming
     /// input in a series of [`str`] chunks even if there are grapheme
     /// clusters straddling across the buffer boundaries.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub fn next_u8chars_from_str<'a>(
         &'a mut self,
         s: &'a str,
-    ) -> impl Iterator<Item = (ClusterAction, u8char)> + FusedIterator + 'a {
+    ) -> impl FusedIterator<Item = (ClusterAction, u8char)> + 'a {
         struct Iter<'a> {
             machine: &'a mut GraphemeMachine,
             remain: &'a str,
+            // The number of printable ASCII characters at the start of
+            // `remain` that are known to each begin a new cluster without
+            // changing the machine's state.
+            ascii_run: usize,
         }
         impl<'a> Iterator for Iter<'a> {
             type Item = (ClusterAction, u8char);
             fn next(&mut self) -> Option<Self::Item> {
                 let (next, rest) = u8char::from_string_prefix(self.remain);
-                let Some(next) = next else {
-                    return None;
-                };
-                let action = self.machine.next_u8char(next);
+                let next = next?;
                 self.remain = rest;
+                if self.ascii_run > 0 {
+                    // See the similar optimization in count_splits_in_str.
+                    self.ascii_run -= 1;
+                    return Some((ClusterAction::Split, next));
+                }
+                let action = self.machine.next_u8char(next);
+                if scan::is_printable_ascii(next.first_byte()) {
+                    self.ascii_run = scan::printable_ascii_prefix_len(rest.as_bytes());
+                }
                 Some((action, next))
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = scan::lead_byte_count(self.remain.as_bytes());
+                (len, Some(len))
+            }
         }
         impl<'a> FusedIterator for Iter<'a> {}
         Iter {
             machine: self,
             remain: s,
+            ascii_run: 0,
         }
     }
 
+    /// Behaves the same as [`Self::next_u8chars_from_str`] except that it
+    /// takes a byte slice that must be valid UTF-8, which it checks before
+    /// processing any characters.
+    ///
+    /// If any part of `bytes` is invalid then this returns an error without
+    /// advancing the state machine at all, even for the valid prefix.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn next_u8chars_from_utf8<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> Result<impl FusedIterator<Item = (ClusterAction, u8char)> + 'a, InvalidUtf8Error> {
+        let s = core::str::from_utf8(bytes)?;
+        Ok(self.next_u8chars_from_str(s))
+    }
+
+    /// Behaves the same as [`Self::next_u8chars_from_str`] except that it
+    /// takes a byte slice that might not be valid UTF-8, substituting
+    /// U+FFFD REPLACEMENT CHARACTER for each invalid sequence and then
+    /// segmenting the replacement like any other character.
+    ///
+    /// Each maximal subpart of an ill-formed sequence becomes one
+    /// replacement character, as recommended by the Unicode Standard and
+    /// the WHATWG Encoding Standard, which is the same substitution that
+    /// `String::from_utf8_lossy` makes. That makes this suitable for text
+    /// such as logs where some corruption is expected and shouldn't stop
+    /// the rest from being segmented.
+    ///
+    /// An incomplete sequence at the end of `bytes` is replaced too, so
+    /// streaming input should be split only between characters, such as by
+    /// [`SplitStr::from_utf8_partial`].
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn next_u8chars_from_utf8_lossy<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> impl FusedIterator<Item = (ClusterAction, u8char)> + 'a {
+        use ::u8char::AsU8Chars;
+
+        bytes
+            .utf8_chunks()
+            .flat_map(|chunk| {
+                let replacement = (!chunk.invalid().is_empty())
+                    .then_some(u8char::from_char(char::REPLACEMENT_CHARACTER));
+                chunk.valid().u8chars().chain(replacement)
+            })
+            .map(|c| (self.next_u8char(c), c))
+            .fuse()
+    }
+
+    /// Behaves the same as [`Self::next_u8chars_from_str`] except that it
+    /// takes a byte slice that the caller promises is valid UTF-8, so that
+    /// callers who have already validated their input (or who got it from
+    /// a [`str`] in the first place) need not pay for validation again.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must contain only valid UTF-8, as would be required by
+    /// [`core::str::from_utf8_unchecked`]. The behavior is undefined
+    /// otherwise.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub unsafe fn next_u8chars_from_utf8_unchecked<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> impl FusedIterator<Item = (ClusterAction, u8char)> + 'a {
+        // Safety: The caller must guarantee that bytes is valid UTF-8.
+        let s = unsafe { core::str::from_utf8_unchecked(bytes) };
+        self.next_u8chars_from_str(s)
+    }
+
+    /// Returns an iterator which takes characters from `buf` and feeds each
+    /// into the state machine in turn, yielding the indicated
+    /// [`ClusterAction`] along with the character that caused it.
+    ///
+    /// The buffer is advanced only past the characters that the iterator
+    /// has returned. Refer to [`BufChars`] for how the iterator handles
+    /// incomplete and invalid UTF-8 sequences.
+    ///
+    /// As with [`Self::next_u8chars_from_str`], there is no automatic call
+    /// to [`Self::end_of_input`] once the buffer is exhausted.
+    ///
+    /// This is available only when the `bytes` feature is enabled.
+    #[cfg(feature = "bytes")]
+    pub fn next_u8chars_from_buf<'a, B: bytes::Buf>(
+        &'a mut self,
+        buf: &'a mut B,
+    ) -> BufChars<'a, B> {
+        BufChars::new(self, buf)
+    }
+
+    /// Behaves the same as [`Self::next_u8chars_from_str`] except that it
+    /// takes text stored across two slices, such as the contents of a ring
+    /// buffer, without the caller first copying it into contiguous storage.
+    ///
+    /// As with [`Self::next_u8chars_from_str`], there is no automatic call
+    /// to [`Self::end_of_input`] at the end, so a ring buffer that's
+    /// repeatedly refilled can be segmented one batch at a time. Use
+    /// [`SplitStr::from_utf8_partial`] to leave any incomplete character at
+    /// the end of a batch for the next one.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn next_u8chars_from_split_str<'a>(
+        &'a mut self,
+        s: SplitStr<'a>,
+    ) -> impl FusedIterator<Item = (ClusterAction, u8char)> + 'a {
+        s.u8chars().map(|c| (self.next_u8char(c), c))
+    }
+
     /// Behaves the same as [`Self::next_u8chars_from_str`] except that it
     /// also converts the characters to [`char`], for more convenient use
     /// by callers who are interacting with something that only supports
     /// Rust's standard character representation.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub fn next_chars_from_str<'a>(
         &'a mut self,
         s: &'a str,
-    ) -> impl Iterator<Item = (ClusterAction, char)> + FusedIterator + 'a {
+    ) -> impl FusedIterator<Item = (ClusterAction, char)> + 'a {
         self.next_u8chars_from_str(s).map(|(a, c)| (a, c.to_char()))
     }
 
+    /// Returns an iterator which feeds each character of `chars` into the
+    /// state machine in turn and yields the index in `chars` of each one
+    /// that begins a new grapheme cluster.
+    ///
+    /// This is for callers that have already decoded their text into a
+    /// buffer of code points, such as the cells of a terminal grid. The
+    /// first index is zero only if the first character doesn't continue a
+    /// cluster from earlier input.
+    ///
+    /// As with [`Self::next_u8chars_from_str`], there is no automatic call
+    /// to [`Self::end_of_input`] at the end, so the buffer can be provided
+    /// in a series of slices, with the indices of each counting from the
+    /// start of that slice.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn boundaries_in_chars<'a>(
+        &'a mut self,
+        chars: &'a [char],
+    ) -> impl FusedIterator<Item = usize> + 'a {
+        chars
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &c)| (self.next_char(c) == ClusterAction::Split).then_some(i))
+            .fuse()
+    }
+
+    /// Advances the state machine over every character in `s`, returning
+    /// how many of them would have produced [`ClusterAction::Split`].
+    ///
+    /// This is equivalent to counting the [`ClusterAction::Split`] results
+    /// from [`Self::next_u8chars_from_str`], but is optimized for whole
+    /// buffers: runs of printable ASCII characters, which can never combine
+    /// with one another, are skipped over using a bulk byte scan rather
+    /// than being passed through the state machine one at a time.
+    ///
+    /// As with [`Self::next_u8chars_from_str`], there is no automatic call
+    /// to [`Self::end_of_input`] once the end of the string is reached, so
+    /// this can be used to count grapheme clusters across a series of
+    /// [`str`] chunks.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub fn count_splits_in_str(&mut self, s: &str) -> usize {
+        let mut count = 0;
+        let mut remain = s;
+        while let (Some(c), rest) = u8char::from_string_prefix(remain) {
+            if self.next_u8char(c) == ClusterAction::Split {
+                count += 1;
+            }
+            remain = rest;
+            if scan::is_printable_ascii(c.first_byte()) {
+                // Every printable ASCII character has the same properties
+                // and leaves the machine in its base state, so after
+                // processing one of them normally any that immediately
+                // follow it are guaranteed to each begin a new cluster
+                // without changing the machine's state at all.
+                let run = scan::printable_ascii_prefix_len(rest.as_bytes());
+                count += run;
+                remain = &rest[run..];
+            }
+        }
+        count
+    }
+
     /// Tells the state machine that the input stream has ended.
     ///
     /// This resets the state machine to the "start of input" state so that
@@ -271,20 +974,187 @@ impl GraphemeMachine {
     /// [`ClusterAction::Split`] to mark the end of the final grapheme cluster.
     pub fn end_of_input(&mut self) -> ClusterAction {
         self.state = State::Base;
-        self.prev = None;
+        self.prev = CharProperties::NO_CHAR;
         ClusterAction::Split
     }
+
+    /// Records the machine's current state, so that [`Self::restore`] can
+    /// later return this or another machine to it.
+    ///
+    /// An editor can record a snapshot at the start of each line, and then
+    /// after an edit resume segmenting from the start of the edited line
+    /// without feeding in everything before it again.
+    pub const fn snapshot(&self) -> MachineSnapshot {
+        MachineSnapshot {
+            prev: self.prev,
+            state: self.state,
+        }
+    }
+
+    /// Returns the machine to the state recorded by [`Self::snapshot`], so
+    /// that it treats the next character exactly as the machine the
+    /// snapshot was taken from would have.
+    pub const fn restore(&mut self, snapshot: MachineSnapshot) {
+        self.prev = snapshot.prev;
+        self.state = snapshot.state;
+    }
+
+    /// Returns the machine's state as the four bytes described in
+    /// [FFI layout](#ffi-layout), which can be converted back using
+    /// [`Self::from_bytes`].
+    pub const fn to_bytes(&self) -> [u8; 4] {
+        let [a, b] = self.prev.to_raw().to_ne_bytes();
+        [a, b, self.state as u8, self.reserved]
+    }
+
+    /// Reconstructs a machine from bytes previously returned by
+    /// [`Self::to_bytes`] on a target with the same byte order.
+    ///
+    /// Returns [`InvalidMachineError`] if the bytes don't represent a state
+    /// that a machine could be in, such as if they were corrupted or were
+    /// written by a different major version of this library, rather than
+    /// producing a machine that would give incorrect results.
+    pub const fn from_bytes(bytes: [u8; 4]) -> Result<Self, InvalidMachineError> {
+        let err = InvalidMachineError { bytes };
+        let [a, b, state, reserved] = bytes;
+        let raw = u16::from_ne_bytes([a, b]);
+        let prev = if raw == CharProperties::NO_CHAR.to_raw() {
+            CharProperties::NO_CHAR
+        } else {
+            match CharProperties::from_raw(raw) {
+                Ok(prev) => prev,
+                Err(_) => return Err(err),
+            }
+        };
+        let Some(state) = State::from_raw(state) else {
+            return Err(err);
+        };
+        if reserved != 0 || !state.accepts_prev(prev) {
+            return Err(err);
+        }
+        Ok(Self {
+            prev,
+            state,
+            reserved,
+        })
+    }
+
+    /// Returns the machine's state in a versioned encoding of four bytes,
+    /// which can be converted back using [`Self::from_stable_bytes`].
+    ///
+    /// Unlike [`Self::to_bytes`], the encoding doesn't depend on the
+    /// target's byte order, and its first byte is a version number that
+    /// any later version of this library will either still understand or
+    /// reject, so the bytes are suitable for persisting a checkpoint across
+    /// process restarts or migrating a stream between hosts partway through
+    /// a cluster.
+    ///
+    /// The current version is 1, in which the remaining bytes are the
+    /// internal state and then the properties of the previous character in
+    /// little-endian byte order.
+    pub const fn to_stable_bytes(&self) -> [u8; 4] {
+        let [lo, hi] = self.prev.to_raw().to_le_bytes();
+        [STABLE_BYTES_VERSION, self.state as u8, lo, hi]
+    }
+
+    /// Reconstructs a machine from bytes previously returned by
+    /// [`Self::to_stable_bytes`], on any target.
+    ///
+    /// Returns [`InvalidMachineError`] if the bytes use an encoding version
+    /// this library doesn't understand or don't represent a state that a
+    /// machine could be in.
+    pub const fn from_stable_bytes(bytes: [u8; 4]) -> Result<Self, InvalidMachineError> {
+        let [version, state, lo, hi] = bytes;
+        if version != STABLE_BYTES_VERSION {
+            return Err(InvalidMachineError { bytes });
+        }
+        let [a, b] = u16::from_le_bytes([lo, hi]).to_ne_bytes();
+        match Self::from_bytes([a, b, state, 0]) {
+            Ok(machine) => Ok(machine),
+            Err(_) => Err(InvalidMachineError { bytes }),
+        }
+    }
+
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[track_caller]
+    fn check_contract(&self) {
+        if !self.state.accepts_prev(self.prev) || self.reserved != 0 {
+            let prev = (!self.prev.is_no_char()).then_some(self.prev);
+            panic!(
+                "GraphemeMachine is corrupt: state {:?} cannot follow a character with properties {prev:?}",
+                self.state,
+            );
+        }
+    }
+}
+
+/// The state of a [`GraphemeMachine`] as recorded by
+/// [`GraphemeMachine::snapshot`].
+///
+/// Snapshots are opaque, but they're small and can be copied and compared
+/// freely. Two machines with equal snapshots treat all subsequent input in
+/// the same way.
+#[cfg(feature = "machine")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MachineSnapshot {
+    prev: CharProperties,
+    state: State,
+}
+
+// The version of the encoding used by GraphemeMachine::to_stable_bytes,
+// which must change whenever the meaning of the other bytes does.
+#[cfg(feature = "machine")]
+const STABLE_BYTES_VERSION: u8 = 1;
+
+#[cfg(feature = "machine")]
+impl Default for GraphemeMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serializes as the four bytes returned by
+/// [`GraphemeMachine::to_stable_bytes`], so that the serialized state
+/// remains valid for later versions of this library and on other targets.
+///
+/// This is available only when the `serde` feature is enabled.
+#[cfg(all(feature = "machine", feature = "serde"))]
+impl serde::Serialize for GraphemeMachine {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.to_stable_bytes(), serializer)
+    }
+}
+
+/// Deserializes from the four bytes returned by
+/// [`GraphemeMachine::to_stable_bytes`], failing for bytes that
+/// [`GraphemeMachine::from_stable_bytes`] rejects.
+///
+/// This is available only when the `serde` feature is enabled.
+#[cfg(all(feature = "machine", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for GraphemeMachine {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = <[u8; 4] as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_stable_bytes(bytes).map_err(serde::de::Error::custom)
+    }
 }
 
 /// What to do with a new character after presenting it to a [GraphemeMachine].
+///
+/// This is represented as a single byte, with [`ClusterAction::Continue`] as
+/// zero and [`ClusterAction::Split`] as one, so that it can be passed across
+/// an FFI boundary as a `uint8_t`.
+#[cfg(feature = "machine")]
+#[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClusterAction {
     /// Treat the new character as an extension of the current grapheme cluster.
-    Continue,
+    Continue = 0,
     /// Treat the current grapheme cluster as complete and begin a new one
     /// that initially consists only of the new character.
-    Split,
+    Split = 1,
 }
 
 #[cfg(test)]
+#[cfg(all(feature = "machine", feature = "tables"))]
 mod tests;
@@ -0,0 +1,892 @@
+//! Line break opportunities from the
+//! [Unicode line breaking algorithm](https://www.unicode.org/reports/tr14/),
+//! found by a state machine that accepts one character at a time in the
+//! same way as `GraphemeMachine`.
+
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+mod table;
+
+/// Enumeration of **Line_Break** property values, from
+/// [UAX #14 Section 5.1](https://www.unicode.org/reports/tr14/#Table1).
+///
+/// Each variant is named after the short alias of its value, which is how
+/// the line breaking rules refer to them.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LBProperty {
+    /// **Unknown**, for unassigned and private use characters, which the
+    /// rules treat as `AL`.
+    XX = 0,
+    /// **Ambiguous**, for characters whose width differs in East Asian
+    /// contexts, which the rules treat as `AL`.
+    AI = 1,
+    /// **Aksara**.
+    AK = 2,
+    /// **Alphabetic**.
+    AL = 3,
+    /// **Aksara_Prebase**.
+    AP = 4,
+    /// **Aksara_Start**.
+    AS = 5,
+    /// **Break_Both**.
+    B2 = 6,
+    /// **Break_After**.
+    BA = 7,
+    /// **Break_Before**.
+    BB = 8,
+    /// **Mandatory_Break**.
+    BK = 9,
+    /// **Contingent_Break**.
+    CB = 10,
+    /// **Conditional_Japanese_Starter**, which the rules treat as `NS`.
+    CJ = 11,
+    /// **Close_Punctuation**.
+    CL = 12,
+    /// **Combining_Mark**.
+    CM = 13,
+    /// **Close_Parenthesis**.
+    CP = 14,
+    /// **Carriage_Return**.
+    CR = 15,
+    /// **E_Base**.
+    EB = 16,
+    /// **E_Modifier**.
+    EM = 17,
+    /// **Exclamation**.
+    EX = 18,
+    /// **Glue**.
+    GL = 19,
+    /// **H2**, for Hangul LV syllables.
+    H2 = 20,
+    /// **H3**, for Hangul LVT syllables.
+    H3 = 21,
+    /// **Hebrew_Letter**.
+    HL = 22,
+    /// **Hyphen**.
+    HY = 23,
+    /// **Ideographic**.
+    ID = 24,
+    /// **Inseparable**.
+    IN = 25,
+    /// **Infix_Numeric**.
+    IS = 26,
+    /// **JL**, for Hangul leading jamo.
+    JL = 27,
+    /// **JT**, for Hangul trailing jamo.
+    JT = 28,
+    /// **JV**, for Hangul vowel jamo.
+    JV = 29,
+    /// **Line_Feed**.
+    LF = 30,
+    /// **Next_Line**.
+    NL = 31,
+    /// **Nonstarter**.
+    NS = 32,
+    /// **Numeric**.
+    NU = 33,
+    /// **Open_Punctuation**.
+    OP = 34,
+    /// **Postfix_Numeric**.
+    PO = 35,
+    /// **Prefix_Numeric**.
+    PR = 36,
+    /// **Quotation**.
+    QU = 37,
+    /// **Regional_Indicator**.
+    RI = 38,
+    /// **Complex_Context**, for characters of scripts like Thai that need
+    /// a dictionary to find line breaks, which the rules treat as `AL`, or
+    /// as `CM` for combining marks.
+    SA = 39,
+    /// **Surrogate**, which the rules treat as `AL`.
+    SG = 40,
+    /// **Space**.
+    SP = 41,
+    /// **Break_Symbols**.
+    SY = 42,
+    /// **Virama_Final**.
+    VF = 43,
+    /// **Virama**.
+    VI = 44,
+    /// **Word_Joiner**.
+    WJ = 45,
+    /// **ZWSpace**.
+    ZW = 46,
+    /// **ZWJ**, for U+200D ZERO WIDTH JOINER.
+    ZWJ = 47,
+}
+
+impl LBProperty {
+    const ALL: [Self; 48] = [
+        Self::XX,
+        Self::AI,
+        Self::AK,
+        Self::AL,
+        Self::AP,
+        Self::AS,
+        Self::B2,
+        Self::BA,
+        Self::BB,
+        Self::BK,
+        Self::CB,
+        Self::CJ,
+        Self::CL,
+        Self::CM,
+        Self::CP,
+        Self::CR,
+        Self::EB,
+        Self::EM,
+        Self::EX,
+        Self::GL,
+        Self::H2,
+        Self::H3,
+        Self::HL,
+        Self::HY,
+        Self::ID,
+        Self::IN,
+        Self::IS,
+        Self::JL,
+        Self::JT,
+        Self::JV,
+        Self::LF,
+        Self::NL,
+        Self::NS,
+        Self::NU,
+        Self::OP,
+        Self::PO,
+        Self::PR,
+        Self::QU,
+        Self::RI,
+        Self::SA,
+        Self::SG,
+        Self::SP,
+        Self::SY,
+        Self::VF,
+        Self::VI,
+        Self::WJ,
+        Self::ZW,
+        Self::ZWJ,
+    ];
+
+    /// The size in bytes of the lookup table that [`Self::of_char`] and
+    /// [`LineBreakProperties::for_char`] use.
+    ///
+    /// Like the tables behind `CharProperties`, this table honors the
+    /// `GRAPHEME_MACHINE_LINK_SECTION` environment variable.
+    pub const TABLE_SIZE: usize = table::LINE_BREAK_RUNS_SIZE;
+
+    /// Returns the **Line_Break** property of the given character.
+    pub const fn of_char(c: char) -> Self {
+        LineBreakProperties::for_char(c).lb_property()
+    }
+}
+
+/// The properties of a character that [`LineBreakMachine`] uses: its
+/// [`LBProperty`], whether it's East Asian, and the few other details of
+/// individual characters that the rules refer to, such as whether a
+/// quotation mark is an initial or final one.
+///
+/// These all come from the same lookup table, and are represented as a
+/// single byte in the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LineBreakProperties {
+    // The LBProperty in the low six bits, and a variant whose meaning
+    // depends on it in the top two, as described in xtask/src/line.rs.
+    raw: u8,
+}
+
+impl LineBreakProperties {
+    const CLASS_MASK: u8 = 0x3f;
+    const VARIANT_SHIFT: u32 = 6;
+
+    /// Returns the properties of a character that has the given
+    /// **Line_Break** property and isn't East Asian.
+    pub const fn new(lb: LBProperty) -> Self {
+        Self { raw: lb as u8 }
+    }
+
+    /// Looks up the properties of the given character.
+    pub const fn for_char(c: char) -> Self {
+        Self {
+            raw: table::line_break_lookup(c),
+        }
+    }
+
+    /// Looks up the properties of the given character.
+    pub fn for_u8char(c: u8char) -> Self {
+        Self::for_char(c.to_char())
+    }
+
+    /// Returns the **Line_Break** property.
+    pub const fn lb_property(self) -> LBProperty {
+        LBProperty::ALL[(self.raw & Self::CLASS_MASK) as usize]
+    }
+
+    /// Returns the properties of a character with the given **Line_Break**
+    /// property that's otherwise like this one, for tailoring the rules.
+    ///
+    /// This keeps whether the character is East Asian, but loses the other
+    /// details, which are specific to the original property value.
+    pub const fn with_lb_property(self, lb: LBProperty) -> Self {
+        let east_asian = match self.is_east_asian() {
+            true => 1 << Self::VARIANT_SHIFT,
+            false => 0,
+        };
+        Self {
+            raw: lb as u8 | east_asian,
+        }
+    }
+
+    /// Returns `true` if the character has an **East_Asian_Width** of
+    /// **Fullwidth**, **Wide** or **Halfwidth**, which some of the rules
+    /// take into account.
+    pub const fn is_east_asian(self) -> bool {
+        match self.variant() {
+            1 => true,
+            3 => self.is(LBProperty::ID),
+            _ => false,
+        }
+    }
+
+    const fn variant(self) -> u8 {
+        self.raw >> Self::VARIANT_SHIFT
+    }
+
+    const fn is(self, lb: LBProperty) -> bool {
+        self.raw & Self::CLASS_MASK == lb as u8
+    }
+
+    // A QU with a General_Category of Pi.
+    const fn is_initial_quote(self) -> bool {
+        self.is(LBProperty::QU) && self.variant() == 2
+    }
+
+    // A QU with a General_Category of Pf.
+    const fn is_final_quote(self) -> bool {
+        self.is(LBProperty::QU) && self.variant() == 3
+    }
+
+    // An SA with a General_Category of Mn or Mc.
+    const fn is_complex_mark(self) -> bool {
+        self.is(LBProperty::SA) && self.variant() == 2
+    }
+
+    // U+2010 HYPHEN, which rule LB20a treats like HY.
+    const fn is_hyphen(self) -> bool {
+        self.is(LBProperty::BA) && self.variant() == 2
+    }
+
+    // U+25CC DOTTED CIRCLE, which rule LB28a treats like AK.
+    const fn is_dotted_circle(self) -> bool {
+        self.is(LBProperty::AL) && self.variant() == 2
+    }
+
+    // An unassigned Extended_Pictographic code point, for rule LB30b.
+    const fn is_unassigned_pictographic(self) -> bool {
+        self.is(LBProperty::ID) && self.variant() >= 2
+    }
+
+    // AK, AS or DOTTED CIRCLE, for rule LB28a.
+    const fn is_aksara(self) -> bool {
+        matches!(self.lb_property(), LBProperty::AK | LBProperty::AS) || self.is_dotted_circle()
+    }
+
+    /// Applies rule LB1 to resolve the property values that the other
+    /// rules don't use.
+    const fn resolve(self) -> Self {
+        use LBProperty::*;
+
+        match self.lb_property() {
+            AI | SG | XX => self.with_lb_property(AL),
+            SA if self.is_complex_mark() => self.with_lb_property(CM),
+            SA => self.with_lb_property(AL),
+            CJ => self.with_lb_property(NS),
+            _ => self,
+        }
+    }
+
+    /// Applies rule LB10 to treat the combining marks that rule LB9 didn't
+    /// merge with the character before them as `AL`.
+    const fn lb10(self) -> Self {
+        match self.lb_property() {
+            LBProperty::CM | LBProperty::ZWJ => self.with_lb_property(LBProperty::AL),
+            _ => self,
+        }
+    }
+}
+
+/// Whether there's a line break opportunity at a particular position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakOpportunity {
+    /// A line must not break at the position.
+    Prohibited,
+    /// A line may break at the position.
+    Allowed,
+    /// A line must break at the position, because it's after a line
+    /// separator such as LF or is the end of the input.
+    Mandatory,
+    /// Whether a line may break at the position depends on the next
+    /// character other than any combining marks, which a later
+    /// [`LineAction::resolved`] reports.
+    ///
+    /// The break opportunity is never [`BreakOpportunity::Mandatory`] in
+    /// this case.
+    Undecided,
+}
+
+/// What a [`LineBreakMachine`] found before a new character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineAction {
+    /// Whether a line may break at the most recent position whose
+    /// opportunity was [`BreakOpportunity::Undecided`], if the new
+    /// character settled it.
+    pub resolved: Option<bool>,
+    /// The break opportunity before the new character.
+    pub opportunity: BreakOpportunity,
+}
+
+/// The rules that depend on the character after the one they precede, in
+/// the order they appear in UAX #14.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Lookahead {
+    // LB15b: × [\p{Pf}&QU] ( SP | GL | WJ | CL | QU | CP | EX | IS | SY
+    // | BK | CR | LF | NL | ZW | eot)
+    FinalQuote,
+    // LB15c: SP ÷ IS NU
+    SpaceInfix,
+    // LB19a: × QU ( [^$EastAsian] | eot )
+    EastAsianQuote,
+    // LB25: (PR | PO) × OP NU and (PR | PO) × OP IS NU
+    PrefixOpen,
+    // The same after seeing the IS, which needs another character.
+    PrefixOpenInfix,
+    // LB28a: (AK | ◌ | AS) × (AK | ◌ | AS) VF
+    Aksara,
+}
+
+impl Lookahead {
+    /// Returns `true` if `next`, or the end of the input if it's `None`,
+    /// completes the rule's pattern.
+    fn matches(self, next: Option<LineBreakProperties>) -> bool {
+        use LBProperty::*;
+
+        let Some(next) = next else {
+            return matches!(self, Self::FinalQuote | Self::EastAsianQuote);
+        };
+        match self {
+            Self::FinalQuote => matches!(
+                next.lb_property(),
+                SP | GL | WJ | CL | QU | CP | EX | IS | SY | BK | CR | LF | NL | ZW
+            ),
+            Self::SpaceInfix | Self::PrefixOpen | Self::PrefixOpenInfix => next.is(NU),
+            Self::EastAsianQuote => !next.is_east_asian(),
+            Self::Aksara => next.is(VF),
+        }
+    }
+
+    /// Returns whether the rule allows a break when its pattern matches.
+    fn allows_break(self) -> bool {
+        self == Self::SpaceInfix
+    }
+}
+
+/// A position whose break opportunity depends on the next character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pending {
+    lookahead: Lookahead,
+    // Whether the later rules allow a break if the lookahead doesn't match.
+    fallback: bool,
+}
+
+impl Pending {
+    fn allows_break(self, next: Option<LineBreakProperties>) -> bool {
+        match self.lookahead.matches(next) {
+            true => self.lookahead.allows_break(),
+            false => self.fallback,
+        }
+    }
+}
+
+/// The decision for a position according to rules LB11 and later.
+enum Decision {
+    Prohibited,
+    Allowed,
+    Undecided(Pending),
+}
+
+/// A state machine for finding line break opportunities, using the default
+/// rules of the
+/// [Unicode line breaking algorithm](https://www.unicode.org/reports/tr14/#Algorithm).
+///
+/// This is a sibling of `GraphemeMachine` for streaming input: feed it each
+/// character in turn using [`Self::next_u8char`] or [`Self::next_char`],
+/// and it returns a [`LineAction`] describing the break opportunity before
+/// that character. A text widget or terminal pager can then wrap each line
+/// at the last opportunity that fits, measuring the grapheme clusters
+/// between opportunities with `cluster_width`.
+///
+/// A few of the rules depend on the character after the position they
+/// describe, such as the rule against breaking between `$` and `(` only in
+/// `$(1)`. The machine reports those positions as
+/// [`BreakOpportunity::Undecided`] and settles them with a later
+/// [`LineAction::resolved`], so a caller that must decide immediately can
+/// treat them as prohibited.
+///
+/// For a whole string that's already in memory, [`line_breaks_in_str`]
+/// drives a machine internally.
+///
+/// This doesn't find the breaks within words of scripts like Thai, since
+/// that needs a dictionary, but a caller with a dictionary can tailor the
+/// properties that it passes to [`Self::next_properties`].
+///
+/// This is available only when the `line` feature is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct LineBreakMachine {
+    // The resolved property of the previous character, or None at the start
+    // of input.
+    prev_raw: Option<LBProperty>,
+    // The previous two characters that rule LB9 doesn't merge with the
+    // character before them, after applying rule LB10, which are what most
+    // of the rules are written in terms of.
+    prev: Option<LineBreakProperties>,
+    prev_prev: Option<LineBreakProperties>,
+    // The last of those characters other than SP, for the rules that apply
+    // across any number of spaces.
+    before_spaces: Option<LineBreakProperties>,
+    // Whether `before_spaces` is an initial quotation mark that rule LB15a
+    // applies to.
+    opening_quote: bool,
+    // Whether `prev` ends `NU (NU | SY | IS)*`, and whether it's a CL or CP
+    // after such a sequence, for rule LB25.
+    numeric: bool,
+    numeric_close: bool,
+    // Whether `prev` ends an odd number of consecutive regional indicators.
+    odd_regional_indicators: bool,
+    pending: Option<Pending>,
+}
+
+impl LineBreakMachine {
+    /// Constructs a new machine in an initial "start of input" state.
+    pub const fn new() -> Self {
+        Self {
+            prev_raw: None,
+            prev: None,
+            prev_prev: None,
+            before_spaces: None,
+            opening_quote: false,
+            numeric: false,
+            numeric_close: false,
+            odd_regional_indicators: false,
+            pending: None,
+        }
+    }
+
+    /// Looks up the properties of the given character and then advances
+    /// the machine by passing them to [`Self::next_properties`].
+    pub fn next_u8char(&mut self, c: u8char) -> LineAction {
+        self.next_properties(LineBreakProperties::for_u8char(c))
+    }
+
+    /// Looks up the properties of the given character and then advances
+    /// the machine by passing them to [`Self::next_properties`].
+    pub fn next_char(&mut self, c: char) -> LineAction {
+        self.next_properties(LineBreakProperties::for_char(c))
+    }
+
+    /// Advances the machine for a character with the given properties,
+    /// returning what the machine found before that character.
+    ///
+    /// This is for callers with tailored properties, in the same way as
+    /// `GraphemeMachine::next_char_properties`.
+    pub fn next_properties(&mut self, next: LineBreakProperties) -> LineAction {
+        use LBProperty::*;
+
+        let next = next.resolve();
+        let class = next.lb_property();
+        // LB2: Never break at the start of text.
+        let (Some(prev_raw), Some(prev)) = (self.prev_raw.replace(class), self.prev) else {
+            self.advance(next.lb10());
+            return LineAction {
+                resolved: None,
+                opportunity: BreakOpportunity::Prohibited,
+            };
+        };
+
+        let decision = if prev_raw == BK || (prev_raw == CR && class != LF) {
+            // LB4 and LB5
+            BreakOpportunity::Mandatory
+        } else if matches!(prev_raw, LF | NL) {
+            // LB5
+            BreakOpportunity::Mandatory
+        } else if matches!(class, BK | CR | LF | NL | SP | ZW) {
+            // LB5 through LB7
+            BreakOpportunity::Prohibited
+        } else if self.before_spaces.is_some_and(|p| p.is(ZW)) {
+            // LB8
+            BreakOpportunity::Allowed
+        } else if matches!(class, CM | ZWJ)
+            && !matches!(prev.lb_property(), BK | CR | LF | NL | SP | ZW)
+        {
+            // LB9: Treat a combining mark as part of the character before
+            // it, so that it doesn't change the state and leaves any
+            // undecided position undecided. LB8a also applies after ZWJ.
+            return LineAction {
+                resolved: None,
+                opportunity: BreakOpportunity::Prohibited,
+            };
+        } else if prev_raw == ZWJ {
+            // LB8a
+            BreakOpportunity::Prohibited
+        } else {
+            match self.decide(next.lb10(), None) {
+                Decision::Prohibited => BreakOpportunity::Prohibited,
+                Decision::Allowed => BreakOpportunity::Allowed,
+                Decision::Undecided(pending) => {
+                    // An undecided position always settles the one before
+                    // it, because nothing that extends a lookahead is ever
+                    // undecided itself.
+                    let resolved = self.resolve_pending(Some(next.lb10()));
+                    self.advance(next.lb10());
+                    debug_assert!(self.pending.is_none());
+                    self.pending = Some(pending);
+                    return LineAction {
+                        resolved,
+                        opportunity: BreakOpportunity::Undecided,
+                    };
+                }
+            }
+        };
+        let resolved = self.resolve_pending(Some(next.lb10()));
+        self.advance(next.lb10());
+        LineAction {
+            resolved,
+            opportunity: decision,
+        }
+    }
+
+    /// Settles any undecided position now that `next` follows it, or the
+    /// end of the input if it's `None`, returning whether a line may break
+    /// there.
+    ///
+    /// This returns `None` if there was no undecided position or if it
+    /// still depends on the character after `next`.
+    fn resolve_pending(&mut self, next: Option<LineBreakProperties>) -> Option<bool> {
+        let pending = self.pending.take()?;
+        if pending.lookahead == Lookahead::PrefixOpen
+            && next.is_some_and(|next| next.is(LBProperty::IS))
+        {
+            self.pending = Some(Pending {
+                lookahead: Lookahead::PrefixOpenInfix,
+                ..pending
+            });
+            return None;
+        }
+        Some(pending.allows_break(next))
+    }
+
+    /// Updates the state for a character that rule LB9 doesn't merge with
+    /// the one before it.
+    fn advance(&mut self, next: LineBreakProperties) {
+        use LBProperty::*;
+
+        let class = next.lb_property();
+        if class != SP {
+            // LB15a
+            self.opening_quote = next.is_initial_quote()
+                && self.prev.is_none_or(|p| {
+                    matches!(p.lb_property(), BK | CR | LF | NL | OP | QU | GL | SP | ZW)
+                });
+            self.before_spaces = Some(next);
+        }
+        let numeric = self.numeric;
+        self.numeric = class == NU || (matches!(class, SY | IS) && numeric);
+        self.numeric_close = matches!(class, CL | CP) && numeric;
+        let odd = self.prev.is_some_and(|p| p.is(RI)) && self.odd_regional_indicators;
+        self.odd_regional_indicators = class == RI && !odd;
+        self.prev_prev = self.prev;
+        self.prev = Some(next);
+    }
+
+    /// Applies rules LB11 through LB31 to the position before `next`,
+    /// ignoring the rules that depend on the following character up to and
+    /// including `skip`.
+    fn decide(&self, next: LineBreakProperties, skip: Option<Lookahead>) -> Decision {
+        use Decision::*;
+        use LBProperty::*;
+
+        // There's always a previous character after the start of input.
+        let p = self.prev.unwrap_or_default();
+        let (pc, nc) = (p.lb_property(), next.lb_property());
+        let pp = self.prev_prev;
+        let before_spaces = self.before_spaces.map(LineBreakProperties::lb_property);
+        let lookahead = |rule: Lookahead| skip < Some(rule);
+
+        if nc == WJ || pc == WJ {
+            // LB11
+            Prohibited
+        } else if pc == GL || (nc == GL && !matches!(pc, SP | BA | HY)) {
+            // LB12 and LB12a
+            Prohibited
+        } else if matches!(nc, CL | CP | EX | SY) {
+            // LB13
+            Prohibited
+        } else if before_spaces == Some(OP) || self.opening_quote {
+            // LB14 and LB15a
+            Prohibited
+        } else if next.is_final_quote() && lookahead(Lookahead::FinalQuote) {
+            // LB15b
+            self.undecided(next, Lookahead::FinalQuote)
+        } else if pc == SP && nc == IS && lookahead(Lookahead::SpaceInfix) {
+            // LB15c
+            self.undecided(next, Lookahead::SpaceInfix)
+        } else if nc == IS {
+            // LB15d
+            Prohibited
+        } else if matches!(before_spaces, Some(CL | CP)) && nc == NS {
+            // LB16
+            Prohibited
+        } else if before_spaces == Some(B2) && nc == B2 {
+            // LB17
+            Prohibited
+        } else if pc == SP {
+            // LB18
+            Allowed
+        } else if (nc == QU && !next.is_initial_quote()) || (pc == QU && !p.is_final_quote()) {
+            // LB19
+            Prohibited
+        } else if nc == QU && !p.is_east_asian() {
+            // LB19a
+            Prohibited
+        } else if nc == QU && lookahead(Lookahead::EastAsianQuote) {
+            // LB19a
+            self.undecided(next, Lookahead::EastAsianQuote)
+        } else if pc == QU && (!next.is_east_asian() || pp.is_none_or(|pp| !pp.is_east_asian())) {
+            // LB19a
+            Prohibited
+        } else if nc == CB || pc == CB {
+            // LB20
+            Allowed
+        } else if (pc == HY || p.is_hyphen())
+            && matches!(nc, AL | HL)
+            && pp.is_none_or(|pp| matches!(pp.lb_property(), BK | CR | LF | NL | SP | ZW | CB | GL))
+        {
+            // LB20a
+            Prohibited
+        } else if matches!(nc, BA | HY | NS) || pc == BB {
+            // LB21
+            Prohibited
+        } else if pp.is_some_and(|pp| pp.is(HL))
+            && (pc == HY || (pc == BA && !p.is_east_asian()))
+            && nc != HL
+        {
+            // LB21a
+            Prohibited
+        } else if (pc == SY && nc == HL) || nc == IN {
+            // LB21b and LB22
+            Prohibited
+        } else if (matches!(pc, AL | HL) && nc == NU) || (pc == NU && matches!(nc, AL | HL)) {
+            // LB23
+            Prohibited
+        } else if (pc == PR && matches!(nc, ID | EB | EM))
+            || (matches!(pc, ID | EB | EM) && nc == PO)
+        {
+            // LB23a
+            Prohibited
+        } else if (matches!(pc, PR | PO) && matches!(nc, AL | HL))
+            || (matches!(pc, AL | HL) && matches!(nc, PR | PO))
+        {
+            // LB24
+            Prohibited
+        } else if matches!(pc, PR | PO | HY | IS) && nc == NU {
+            // LB25
+            Prohibited
+        } else if matches!(pc, PR | PO) && nc == OP && lookahead(Lookahead::PrefixOpen) {
+            // LB25
+            self.undecided(next, Lookahead::PrefixOpen)
+        } else if (self.numeric && nc == NU)
+            || ((self.numeric || self.numeric_close) && matches!(nc, PO | PR))
+        {
+            // LB25
+            Prohibited
+        } else if (pc == JL && matches!(nc, JL | JV | H2 | H3))
+            || (matches!(pc, JV | H2) && matches!(nc, JV | JT))
+            || (matches!(pc, JT | H3) && nc == JT)
+        {
+            // LB26
+            Prohibited
+        } else if (matches!(pc, JL | JV | JT | H2 | H3) && nc == PO)
+            || (pc == PR && matches!(nc, JL | JV | JT | H2 | H3))
+        {
+            // LB27
+            Prohibited
+        } else if matches!(pc, AL | HL) && matches!(nc, AL | HL) {
+            // LB28
+            Prohibited
+        } else if (pc == AP && next.is_aksara())
+            || (p.is_aksara() && matches!(nc, VF | VI))
+            || (pp.is_some_and(LineBreakProperties::is_aksara)
+                && pc == VI
+                && (nc == AK || next.is_dotted_circle()))
+        {
+            // LB28a
+            Prohibited
+        } else if p.is_aksara() && next.is_aksara() && lookahead(Lookahead::Aksara) {
+            // LB28a
+            self.undecided(next, Lookahead::Aksara)
+        } else if pc == IS && matches!(nc, AL | HL) {
+            // LB29
+            Prohibited
+        } else if (matches!(pc, AL | HL | NU) && nc == OP && !next.is_east_asian())
+            || (pc == CP && !p.is_east_asian() && matches!(nc, AL | HL | NU))
+        {
+            // LB30
+            Prohibited
+        } else if pc == RI && nc == RI && self.odd_regional_indicators {
+            // LB30a
+            Prohibited
+        } else if nc == EM && (pc == EB || p.is_unassigned_pictographic()) {
+            // LB30b
+            Prohibited
+        } else {
+            // LB31
+            Allowed
+        }
+    }
+
+    /// Returns the decision for a rule that depends on the character after
+    /// `next`, which is the same as for the later rules if it doesn't match.
+    fn undecided(&self, next: LineBreakProperties, lookahead: Lookahead) -> Decision {
+        let fallback = match self.decide(next, Some(lookahead)) {
+            Decision::Prohibited => false,
+            Decision::Allowed => true,
+            // The rules that depend on the following character never apply
+            // to the same character.
+            Decision::Undecided(pending) => pending.fallback,
+        };
+        match (fallback, lookahead.allows_break()) {
+            (false, false) => Decision::Prohibited,
+            (true, true) => Decision::Allowed,
+            _ => Decision::Undecided(Pending {
+                lookahead,
+                fallback,
+            }),
+        }
+    }
+
+    /// Tells the machine that the input has ended, returning the action to
+    /// mark the mandatory break at the end of the final line.
+    ///
+    /// This settles any undecided position. As with
+    /// `GraphemeMachine::end_of_input`, the machine then returns to its
+    /// initial state, so that it can be used for new input.
+    pub fn end_of_input(&mut self) -> LineAction {
+        let resolved = self.resolve_pending(None);
+        *self = Self::new();
+        LineAction {
+            resolved,
+            opportunity: BreakOpportunity::Mandatory,
+        }
+    }
+}
+
+/// Returns an iterator over the line break opportunities in `s`, each as a
+/// byte offset into `s` along with whether it's
+/// [`BreakOpportunity::Allowed`] or [`BreakOpportunity::Mandatory`].
+///
+/// Unless `s` is empty, the last item is always a mandatory break at the
+/// end of the string.
+///
+/// This is available only when the `line` feature is enabled.
+pub fn line_breaks_in_str(s: &str) -> StrLineBreaks<'_> {
+    StrLineBreaks {
+        machine: LineBreakMachine::new(),
+        s,
+        scanned: 0,
+        undecided: 0,
+        queued: None,
+        finished: false,
+    }
+}
+
+/// An iterator over the line break opportunities in a string.
+///
+/// Construct this using [`line_breaks_in_str`].
+#[derive(Debug, Clone)]
+pub struct StrLineBreaks<'a> {
+    machine: LineBreakMachine,
+    s: &'a str,
+    // The end of the characters already given to the machine.
+    scanned: usize,
+    // The offset of the character whose opportunity was most recently
+    // BreakOpportunity::Undecided.
+    undecided: usize,
+    // An opportunity found along with the one returned before it.
+    queued: Option<(usize, BreakOpportunity)>,
+    finished: bool,
+}
+
+impl StrLineBreaks<'_> {
+    /// Returns the opportunities for a [`LineAction`] at `offset`, in order.
+    fn opportunities(
+        &mut self,
+        offset: usize,
+        action: LineAction,
+    ) -> Option<(usize, BreakOpportunity)> {
+        let earlier = match action.resolved {
+            Some(true) => Some((self.undecided, BreakOpportunity::Allowed)),
+            _ => None,
+        };
+        let here = match action.opportunity {
+            BreakOpportunity::Allowed | BreakOpportunity::Mandatory => {
+                Some((offset, action.opportunity))
+            }
+            BreakOpportunity::Undecided => {
+                self.undecided = offset;
+                None
+            }
+            BreakOpportunity::Prohibited => None,
+        };
+        match earlier {
+            Some(earlier) => {
+                self.queued = here;
+                Some(earlier)
+            }
+            None => here,
+        }
+    }
+}
+
+impl Iterator for StrLineBreaks<'_> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.queued.take() {
+            return Some(item);
+        }
+        while let (Some(c), _) = u8char::from_string_prefix(&self.s[self.scanned..]) {
+            let offset = self.scanned;
+            self.scanned += c.len();
+            let action = self.machine.next_u8char(c);
+            if let Some(item) = self.opportunities(offset, action) {
+                return Some(item);
+            }
+        }
+        if self.finished || self.s.is_empty() {
+            return None;
+        }
+        self.finished = true;
+        let action = self.machine.end_of_input();
+        self.opportunities(self.s.len(), action)
+    }
+}
+
+impl FusedIterator for StrLineBreaks<'_> {}
+
+#[cfg(test)]
+mod test_table;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,503 @@
+/// Returns the encoded **Line_Break** property of the given character, as
+/// described for [`LineBreakProperties`](super::LineBreakProperties).
+///
+/// [`LINE_BREAK_RUNS`] is sorted by code point, so this is a binary search
+/// for the last run beginning at or before `c`.
+pub const fn line_break_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, LINE_BREAK_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if LINE_BREAK_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    LINE_BREAK_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`LINE_BREAK_RUNS`].
+pub const LINE_BREAK_RUNS_SIZE: usize = LINE_BREAK_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static LINE_BREAK_RUNS: [u32; 3788] = [
+    0x0000000d, 0x00000907, 0x00000a1e, 0x00000b09, 0x00000d0f, 0x00000e0d, 0x00002029, 0x00002112,
+    0x00002225, 0x00002303, 0x00002424, 0x00002523, 0x00002603, 0x00002725, 0x00002822, 0x0000290e,
+    0x00002a03, 0x00002b24, 0x00002c1a, 0x00002d17, 0x00002e1a, 0x00002f2a, 0x00003021, 0x00003a1a,
+    0x00003c03, 0x00003f12, 0x00004003, 0x00005b22, 0x00005c24, 0x00005d0e, 0x00005e03, 0x00007b22,
+    0x00007c07, 0x00007d0c, 0x00007e03, 0x00007f0d, 0x0000851f, 0x0000860d, 0x0000a013, 0x0000a122,
+    0x0000a223, 0x0000a324, 0x0000a603, 0x0000a701, 0x0000a903, 0x0000aa01, 0x0000aba5, 0x0000ac03,
+    0x0000ad07, 0x0000ae03, 0x0000b023, 0x0000b124, 0x0000b201, 0x0000b408, 0x0000b503, 0x0000b601,
+    0x0000bbe5, 0x0000bc01, 0x0000bf22, 0x0000c003, 0x0000d701, 0x0000d803, 0x0000f701, 0x0000f803,
+    0x0002c701, 0x0002c808, 0x0002c901, 0x0002cc08, 0x0002cd01, 0x0002ce03, 0x0002d001, 0x0002d103,
+    0x0002d801, 0x0002dc03, 0x0002dd01, 0x0002de03, 0x0002df08, 0x0002e003, 0x0003000d, 0x00035c13,
+    0x0003630d, 0x00037003, 0x00037800, 0x00037a03, 0x00037e1a, 0x00037f03, 0x00038000, 0x00038403,
+    0x00038b00, 0x00038c03, 0x00038d00, 0x00038e03, 0x0003a200, 0x0003a303, 0x0004830d, 0x00048a03,
+    0x00053000, 0x00053103, 0x00055700, 0x00055903, 0x0005891a, 0x00058a07, 0x00058b00, 0x00058d03,
+    0x00058f24, 0x00059000, 0x0005910d, 0x0005be07, 0x0005bf0d, 0x0005c003, 0x0005c10d, 0x0005c303,
+    0x0005c40d, 0x0005c612, 0x0005c70d, 0x0005c800, 0x0005d016, 0x0005eb00, 0x0005ef16, 0x0005f303,
+    0x0005f500, 0x00060021, 0x00060603, 0x00060923, 0x00060c1a, 0x00060e03, 0x0006100d, 0x00061b12,
+    0x00061c0d, 0x00061d12, 0x00062003, 0x00064b0d, 0x00066021, 0x00066a23, 0x00066b21, 0x00066d03,
+    0x0006700d, 0x00067103, 0x0006d412, 0x0006d503, 0x0006d60d, 0x0006dd21, 0x0006de03, 0x0006df0d,
+    0x0006e503, 0x0006e70d, 0x0006e903, 0x0006ea0d, 0x0006ee03, 0x0006f021, 0x0006fa03, 0x00070e00,
+    0x00070f03, 0x0007110d, 0x00071203, 0x0007300d, 0x00074b00, 0x00074d03, 0x0007a60d, 0x0007b103,
+    0x0007b200, 0x0007c021, 0x0007ca03, 0x0007eb0d, 0x0007f403, 0x0007f81a, 0x0007f912, 0x0007fa03,
+    0x0007fb00, 0x0007fd0d, 0x0007fe24, 0x00080003, 0x0008160d, 0x00081a03, 0x00081b0d, 0x00082403,
+    0x0008250d, 0x00082803, 0x0008290d, 0x00082e00, 0x00083003, 0x00083f00, 0x00084003, 0x0008590d,
+    0x00085c00, 0x00085e03, 0x00085f00, 0x00086003, 0x00086b00, 0x00087003, 0x00088f00, 0x00089021,
+    0x00089200, 0x0008970d, 0x0008a003, 0x0008ca0d, 0x0008e221, 0x0008e30d, 0x00090403, 0x00093a0d,
+    0x00093d03, 0x00093e0d, 0x00095003, 0x0009510d, 0x00095803, 0x0009620d, 0x00096407, 0x00096621,
+    0x00097003, 0x0009810d, 0x00098400, 0x00098503, 0x00098d00, 0x00098f03, 0x00099100, 0x00099303,
+    0x0009a900, 0x0009aa03, 0x0009b100, 0x0009b203, 0x0009b300, 0x0009b603, 0x0009ba00, 0x0009bc0d,
+    0x0009bd03, 0x0009be0d, 0x0009c500, 0x0009c70d, 0x0009c900, 0x0009cb0d, 0x0009ce03, 0x0009cf00,
+    0x0009d70d, 0x0009d800, 0x0009dc03, 0x0009de00, 0x0009df03, 0x0009e20d, 0x0009e400, 0x0009e621,
+    0x0009f003, 0x0009f223, 0x0009f403, 0x0009f923, 0x0009fa03, 0x0009fb24, 0x0009fc03, 0x0009fe0d,
+    0x0009ff00, 0x000a010d, 0x000a0400, 0x000a0503, 0x000a0b00, 0x000a0f03, 0x000a1100, 0x000a1303,
+    0x000a2900, 0x000a2a03, 0x000a3100, 0x000a3203, 0x000a3400, 0x000a3503, 0x000a3700, 0x000a3803,
+    0x000a3a00, 0x000a3c0d, 0x000a3d00, 0x000a3e0d, 0x000a4300, 0x000a470d, 0x000a4900, 0x000a4b0d,
+    0x000a4e00, 0x000a510d, 0x000a5200, 0x000a5903, 0x000a5d00, 0x000a5e03, 0x000a5f00, 0x000a6621,
+    0x000a700d, 0x000a7203, 0x000a750d, 0x000a7603, 0x000a7700, 0x000a810d, 0x000a8400, 0x000a8503,
+    0x000a8e00, 0x000a8f03, 0x000a9200, 0x000a9303, 0x000aa900, 0x000aaa03, 0x000ab100, 0x000ab203,
+    0x000ab400, 0x000ab503, 0x000aba00, 0x000abc0d, 0x000abd03, 0x000abe0d, 0x000ac600, 0x000ac70d,
+    0x000aca00, 0x000acb0d, 0x000ace00, 0x000ad003, 0x000ad100, 0x000ae003, 0x000ae20d, 0x000ae400,
+    0x000ae621, 0x000af003, 0x000af124, 0x000af200, 0x000af903, 0x000afa0d, 0x000b0000, 0x000b010d,
+    0x000b0400, 0x000b0503, 0x000b0d00, 0x000b0f03, 0x000b1100, 0x000b1303, 0x000b2900, 0x000b2a03,
+    0x000b3100, 0x000b3203, 0x000b3400, 0x000b3503, 0x000b3a00, 0x000b3c0d, 0x000b3d03, 0x000b3e0d,
+    0x000b4500, 0x000b470d, 0x000b4900, 0x000b4b0d, 0x000b4e00, 0x000b550d, 0x000b5800, 0x000b5c03,
+    0x000b5e00, 0x000b5f03, 0x000b620d, 0x000b6400, 0x000b6621, 0x000b7003, 0x000b7800, 0x000b820d,
+    0x000b8303, 0x000b8400, 0x000b8503, 0x000b8b00, 0x000b8e03, 0x000b9100, 0x000b9203, 0x000b9600,
+    0x000b9903, 0x000b9b00, 0x000b9c03, 0x000b9d00, 0x000b9e03, 0x000ba000, 0x000ba303, 0x000ba500,
+    0x000ba803, 0x000bab00, 0x000bae03, 0x000bba00, 0x000bbe0d, 0x000bc300, 0x000bc60d, 0x000bc900,
+    0x000bca0d, 0x000bce00, 0x000bd003, 0x000bd100, 0x000bd70d, 0x000bd800, 0x000be621, 0x000bf003,
+    0x000bf924, 0x000bfa03, 0x000bfb00, 0x000c000d, 0x000c0503, 0x000c0d00, 0x000c0e03, 0x000c1100,
+    0x000c1203, 0x000c2900, 0x000c2a03, 0x000c3a00, 0x000c3c0d, 0x000c3d03, 0x000c3e0d, 0x000c4500,
+    0x000c460d, 0x000c4900, 0x000c4a0d, 0x000c4e00, 0x000c550d, 0x000c5700, 0x000c5803, 0x000c5b00,
+    0x000c5d03, 0x000c5e00, 0x000c6003, 0x000c620d, 0x000c6400, 0x000c6621, 0x000c7000, 0x000c7708,
+    0x000c7803, 0x000c810d, 0x000c8408, 0x000c8503, 0x000c8d00, 0x000c8e03, 0x000c9100, 0x000c9203,
+    0x000ca900, 0x000caa03, 0x000cb400, 0x000cb503, 0x000cba00, 0x000cbc0d, 0x000cbd03, 0x000cbe0d,
+    0x000cc500, 0x000cc60d, 0x000cc900, 0x000cca0d, 0x000cce00, 0x000cd50d, 0x000cd700, 0x000cdd03,
+    0x000cdf00, 0x000ce003, 0x000ce20d, 0x000ce400, 0x000ce621, 0x000cf000, 0x000cf103, 0x000cf30d,
+    0x000cf400, 0x000d000d, 0x000d0403, 0x000d0d00, 0x000d0e03, 0x000d1100, 0x000d1203, 0x000d3b0d,
+    0x000d3d03, 0x000d3e0d, 0x000d4500, 0x000d460d, 0x000d4900, 0x000d4a0d, 0x000d4e03, 0x000d5000,
+    0x000d5403, 0x000d570d, 0x000d5803, 0x000d620d, 0x000d6400, 0x000d6621, 0x000d7003, 0x000d7923,
+    0x000d7a03, 0x000d8000, 0x000d810d, 0x000d8400, 0x000d8503, 0x000d9700, 0x000d9a03, 0x000db200,
+    0x000db303, 0x000dbc00, 0x000dbd03, 0x000dbe00, 0x000dc003, 0x000dc700, 0x000dca0d, 0x000dcb00,
+    0x000dcf0d, 0x000dd500, 0x000dd60d, 0x000dd700, 0x000dd80d, 0x000de000, 0x000de621, 0x000df000,
+    0x000df20d, 0x000df403, 0x000df500, 0x000e0127, 0x000e31a7, 0x000e3227, 0x000e34a7, 0x000e3b00,
+    0x000e3f24, 0x000e4027, 0x000e47a7, 0x000e4f03, 0x000e5021, 0x000e5a07, 0x000e5c00, 0x000e8127,
+    0x000e8300, 0x000e8427, 0x000e8500, 0x000e8627, 0x000e8b00, 0x000e8c27, 0x000ea400, 0x000ea527,
+    0x000ea600, 0x000ea727, 0x000eb1a7, 0x000eb227, 0x000eb4a7, 0x000ebd27, 0x000ebe00, 0x000ec027,
+    0x000ec500, 0x000ec627, 0x000ec700, 0x000ec8a7, 0x000ecf00, 0x000ed021, 0x000eda00, 0x000edc27,
+    0x000ee000, 0x000f0003, 0x000f0108, 0x000f0503, 0x000f0608, 0x000f0813, 0x000f0908, 0x000f0b07,
+    0x000f0c13, 0x000f0d12, 0x000f1213, 0x000f1303, 0x000f1412, 0x000f1503, 0x000f180d, 0x000f1a03,
+    0x000f2021, 0x000f2a03, 0x000f3407, 0x000f350d, 0x000f3603, 0x000f370d, 0x000f3803, 0x000f390d,
+    0x000f3a22, 0x000f3b0c, 0x000f3c22, 0x000f3d0c, 0x000f3e0d, 0x000f4003, 0x000f4800, 0x000f4903,
+    0x000f6d00, 0x000f710d, 0x000f7f07, 0x000f800d, 0x000f8507, 0x000f860d, 0x000f8803, 0x000f8d0d,
+    0x000f9800, 0x000f990d, 0x000fbd00, 0x000fbe07, 0x000fc003, 0x000fc60d, 0x000fc703, 0x000fcd00,
+    0x000fce03, 0x000fd008, 0x000fd207, 0x000fd308, 0x000fd403, 0x000fd913, 0x000fdb00, 0x00100027,
+    0x00102ba7, 0x00103f27, 0x00104021, 0x00104a07, 0x00104c03, 0x00105027, 0x001056a7, 0x00105a27,
+    0x00105ea7, 0x00106127, 0x001062a7, 0x00106527, 0x001067a7, 0x00106e27, 0x001071a7, 0x00107527,
+    0x001082a7, 0x00108e27, 0x00108fa7, 0x00109021, 0x00109aa7, 0x00109e27, 0x0010a003, 0x0010c600,
+    0x0010c703, 0x0010c800, 0x0010cd03, 0x0010ce00, 0x0010d003, 0x0011005b, 0x0011601d, 0x0011a81c,
+    0x00120003, 0x00124900, 0x00124a03, 0x00124e00, 0x00125003, 0x00125700, 0x00125803, 0x00125900,
+    0x00125a03, 0x00125e00, 0x00126003, 0x00128900, 0x00128a03, 0x00128e00, 0x00129003, 0x0012b100,
+    0x0012b203, 0x0012b600, 0x0012b803, 0x0012bf00, 0x0012c003, 0x0012c100, 0x0012c203, 0x0012c600,
+    0x0012c803, 0x0012d700, 0x0012d803, 0x00131100, 0x00131203, 0x00131600, 0x00131803, 0x00135b00,
+    0x00135d0d, 0x00136003, 0x00136107, 0x00136203, 0x00137d00, 0x00138003, 0x00139a00, 0x0013a003,
+    0x0013f600, 0x0013f803, 0x0013fe00, 0x00140007, 0x00140103, 0x00168007, 0x00168103, 0x00169b22,
+    0x00169c0c, 0x00169d00, 0x0016a003, 0x0016eb07, 0x0016ee03, 0x0016f900, 0x00170003, 0x0017120d,
+    0x00171600, 0x00171f03, 0x0017320d, 0x00173507, 0x00173700, 0x00174003, 0x0017520d, 0x00175400,
+    0x00176003, 0x00176d00, 0x00176e03, 0x00177100, 0x0017720d, 0x00177400, 0x00178027, 0x0017b4a7,
+    0x0017d407, 0x0017d620, 0x0017d727, 0x0017d807, 0x0017d903, 0x0017da07, 0x0017db24, 0x0017dc27,
+    0x0017dda7, 0x0017de00, 0x0017e021, 0x0017ea00, 0x0017f003, 0x0017fa00, 0x00180003, 0x00180212,
+    0x00180407, 0x00180608, 0x00180703, 0x00180812, 0x00180a03, 0x00180b0d, 0x00180e13, 0x00180f0d,
+    0x00181021, 0x00181a00, 0x00182003, 0x00187900, 0x00188003, 0x0018850d, 0x00188703, 0x0018a90d,
+    0x0018aa03, 0x0018ab00, 0x0018b003, 0x0018f600, 0x00190003, 0x00191f00, 0x0019200d, 0x00192c00,
+    0x0019300d, 0x00193c00, 0x00194003, 0x00194100, 0x00194412, 0x00194621, 0x00195027, 0x00196e00,
+    0x00197027, 0x00197500, 0x00198027, 0x0019ac00, 0x0019b027, 0x0019ca00, 0x0019d021, 0x0019db00,
+    0x0019de27, 0x0019e003, 0x001a170d, 0x001a1c00, 0x001a1e03, 0x001a2027, 0x001a55a7, 0x001a5f00,
+    0x001a60a7, 0x001a7d00, 0x001a7f0d, 0x001a8021, 0x001a8a00, 0x001a9021, 0x001a9a00, 0x001aa027,
+    0x001aae00, 0x001ab00d, 0x001acf00, 0x001b000d, 0x001b0502, 0x001b340d, 0x001b442c, 0x001b4502,
+    0x001b4d00, 0x001b4e07, 0x001b5005, 0x001b5a07, 0x001b5c18, 0x001b5d07, 0x001b6118, 0x001b6b0d,
+    0x001b7418, 0x001b7d07, 0x001b800d, 0x001b8303, 0x001ba10d, 0x001bae03, 0x001bb021, 0x001bba03,
+    0x001bc005, 0x001be60d, 0x001bf22b, 0x001bf400, 0x001bfc03, 0x001c240d, 0x001c3800, 0x001c3b07,
+    0x001c4021, 0x001c4a00, 0x001c4d03, 0x001c5021, 0x001c5a03, 0x001c7e07, 0x001c8003, 0x001c8b00,
+    0x001c9003, 0x001cbb00, 0x001cbd03, 0x001cc800, 0x001cd00d, 0x001cd303, 0x001cd40d, 0x001ce903,
+    0x001ced0d, 0x001cee03, 0x001cf40d, 0x001cf503, 0x001cf70d, 0x001cfa03, 0x001cfb00, 0x001d0003,
+    0x001dc00d, 0x001dcd13, 0x001dce0d, 0x001dfc13, 0x001dfd0d, 0x001e0003, 0x001f1600, 0x001f1803,
+    0x001f1e00, 0x001f2003, 0x001f4600, 0x001f4803, 0x001f4e00, 0x001f5003, 0x001f5800, 0x001f5903,
+    0x001f5a00, 0x001f5b03, 0x001f5c00, 0x001f5d03, 0x001f5e00, 0x001f5f03, 0x001f7e00, 0x001f8003,
+    0x001fb500, 0x001fb603, 0x001fc500, 0x001fc603, 0x001fd400, 0x001fd603, 0x001fdc00, 0x001fdd03,
+    0x001ff000, 0x001ff203, 0x001ff500, 0x001ff603, 0x001ffd08, 0x001ffe03, 0x001fff00, 0x00200007,
+    0x00200713, 0x00200807, 0x00200b2e, 0x00200c0d, 0x00200d2f, 0x00200e0d, 0x00201087, 0x00201113,
+    0x00201207, 0x00201406, 0x00201501, 0x00201703, 0x002018a5, 0x002019e5, 0x00201a22, 0x00201ba5,
+    0x00201de5, 0x00201e22, 0x00201fa5, 0x00202001, 0x00202203, 0x00202419, 0x00202707, 0x00202809,
+    0x00202a0d, 0x00202f13, 0x00203023, 0x00203803, 0x002039a5, 0x00203ae5, 0x00203b01, 0x00203c20,
+    0x00203e03, 0x0020441a, 0x00204522, 0x0020460c, 0x00204720, 0x00204a03, 0x00205607, 0x00205723,
+    0x00205807, 0x00205c03, 0x00205d07, 0x0020602d, 0x00206103, 0x00206500, 0x0020660d, 0x00207003,
+    0x00207200, 0x00207401, 0x00207503, 0x00207d22, 0x00207e0c, 0x00207f01, 0x00208003, 0x00208101,
+    0x00208503, 0x00208d22, 0x00208e0c, 0x00208f00, 0x00209003, 0x00209d00, 0x0020a024, 0x0020a723,
+    0x0020a824, 0x0020a964, 0x0020aa24, 0x0020b623, 0x0020b724, 0x0020bb23, 0x0020bc24, 0x0020be23,
+    0x0020bf24, 0x0020c023, 0x0020c124, 0x0020d00d, 0x0020f100, 0x00210003, 0x00210323, 0x00210403,
+    0x00210501, 0x00210603, 0x00210923, 0x00210a03, 0x00211301, 0x00211403, 0x00211624, 0x00211703,
+    0x00212101, 0x00212303, 0x00212b01, 0x00212c03, 0x00215001, 0x00215f03, 0x00216001, 0x00216c03,
+    0x00217001, 0x00217a03, 0x00218901, 0x00218a03, 0x00218c00, 0x00219001, 0x00219a03, 0x0021d201,
+    0x0021d303, 0x0021d401, 0x0021d503, 0x00220001, 0x00220103, 0x00220201, 0x00220403, 0x00220701,
+    0x00220903, 0x00220b01, 0x00220c03, 0x00220f01, 0x00221003, 0x00221101, 0x00221224, 0x00221403,
+    0x00221501, 0x00221603, 0x00221a01, 0x00221b03, 0x00221d01, 0x00222103, 0x00222301, 0x00222403,
+    0x00222501, 0x00222603, 0x00222701, 0x00222d03, 0x00222e01, 0x00222f03, 0x00223401, 0x00223803,
+    0x00223c01, 0x00223e03, 0x00224801, 0x00224903, 0x00224c01, 0x00224d03, 0x00225201, 0x00225303,
+    0x00226001, 0x00226203, 0x00226401, 0x00226803, 0x00226a01, 0x00226c03, 0x00226e01, 0x00227003,
+    0x00228201, 0x00228403, 0x00228601, 0x00228803, 0x00229501, 0x00229603, 0x00229901, 0x00229a03,
+    0x0022a501, 0x0022a603, 0x0022bf01, 0x0022c003, 0x0022ef19, 0x0022f003, 0x00230822, 0x0023090c,
+    0x00230a22, 0x00230b0c, 0x00230c03, 0x00231201, 0x00231303, 0x00231a58, 0x00231c03, 0x00232962,
+    0x00232a4c, 0x00232b03, 0x0023e943, 0x0023ed03, 0x0023f058, 0x0023f118, 0x0023f358, 0x0023f403,
+    0x00242a00, 0x00244003, 0x00244b00, 0x00246001, 0x0024ff03, 0x00250001, 0x00254c03, 0x00255001,
+    0x00257503, 0x00258001, 0x00259003, 0x00259201, 0x00259603, 0x0025a001, 0x0025a203, 0x0025a301,
+    0x0025aa03, 0x0025b201, 0x0025b403, 0x0025b601, 0x0025b803, 0x0025bc01, 0x0025be03, 0x0025c001,
+    0x0025c203, 0x0025c601, 0x0025c903, 0x0025cb01, 0x0025cc83, 0x0025cd03, 0x0025ce01, 0x0025d203,
+    0x0025e201, 0x0025e603, 0x0025ef01, 0x0025f003, 0x0025fd43, 0x0025ff03, 0x00260018, 0x00260403,
+    0x00260501, 0x00260703, 0x00260901, 0x00260a03, 0x00260e01, 0x00261003, 0x00261458, 0x00261601,
+    0x00261818, 0x00261903, 0x00261a18, 0x00261d10, 0x00261e18, 0x00262003, 0x00263043, 0x00263803,
+    0x00263918, 0x00263c03, 0x00264001, 0x00264103, 0x00264201, 0x00264303, 0x00264843, 0x00265403,
+    0x00266001, 0x00266203, 0x00266301, 0x00266603, 0x00266701, 0x00266818, 0x00266901, 0x00266b03,
+    0x00266c01, 0x00266e03, 0x00266f01, 0x00267003, 0x00267f58, 0x00268003, 0x00268a43, 0x00269003,
+    0x00269343, 0x00269403, 0x00269e01, 0x0026a003, 0x0026a143, 0x0026a203, 0x0026aa43, 0x0026ac03,
+    0x0026bd58, 0x0026bf18, 0x0026c458, 0x0026c618, 0x0026c901, 0x0026cd18, 0x0026ce43, 0x0026cf18,
+    0x0026d201, 0x0026d318, 0x0026d458, 0x0026d501, 0x0026d818, 0x0026da01, 0x0026dc18, 0x0026dd01,
+    0x0026df18, 0x0026e203, 0x0026e301, 0x0026e403, 0x0026e801, 0x0026ea58, 0x0026eb01, 0x0026f118,
+    0x0026f258, 0x0026f418, 0x0026f558, 0x0026f601, 0x0026f718, 0x0026f910, 0x0026fa58, 0x0026fb01,
+    0x0026fd58, 0x0026fe18, 0x00270543, 0x00270603, 0x00270818, 0x00270a50, 0x00270c10, 0x00270e03,
+    0x00272843, 0x00272903, 0x00274c43, 0x00274d03, 0x00274e43, 0x00274f03, 0x00275343, 0x00275603,
+    0x00275741, 0x00275803, 0x00275b25, 0x00276103, 0x00276212, 0x00276418, 0x00276503, 0x00276822,
+    0x0027690c, 0x00276a22, 0x00276b0c, 0x00276c22, 0x00276d0c, 0x00276e22, 0x00276f0c, 0x00277022,
+    0x0027710c, 0x00277222, 0x0027730c, 0x00277422, 0x0027750c, 0x00277601, 0x00279403, 0x00279543,
+    0x00279803, 0x0027b043, 0x0027b103, 0x0027bf43, 0x0027c003, 0x0027c522, 0x0027c60c, 0x0027c703,
+    0x0027e622, 0x0027e70c, 0x0027e822, 0x0027e90c, 0x0027ea22, 0x0027eb0c, 0x0027ec22, 0x0027ed0c,
+    0x0027ee22, 0x0027ef0c, 0x0027f003, 0x00280007, 0x00280103, 0x00298322, 0x0029840c, 0x00298522,
+    0x0029860c, 0x00298722, 0x0029880c, 0x00298922, 0x00298a0c, 0x00298b22, 0x00298c0c, 0x00298d22,
+    0x00298e0c, 0x00298f22, 0x0029900c, 0x00299122, 0x0029920c, 0x00299322, 0x0029940c, 0x00299522,
+    0x0029960c, 0x00299722, 0x0029980c, 0x00299903, 0x0029d822, 0x0029d90c, 0x0029da22, 0x0029db0c,
+    0x0029dc03, 0x0029fc22, 0x0029fd0c, 0x0029fe03, 0x002b1b43, 0x002b1d03, 0x002b5043, 0x002b5103,
+    0x002b5541, 0x002b5601, 0x002b5a03, 0x002b7400, 0x002b7603, 0x002b9600, 0x002b9703, 0x002cef0d,
+    0x002cf203, 0x002cf400, 0x002cf912, 0x002cfa07, 0x002cfd03, 0x002cfe12, 0x002cff07, 0x002d0003,
+    0x002d2600, 0x002d2703, 0x002d2800, 0x002d2d03, 0x002d2e00, 0x002d3003, 0x002d6800, 0x002d6f03,
+    0x002d7007, 0x002d7100, 0x002d7f0d, 0x002d8003, 0x002d9700, 0x002da003, 0x002da700, 0x002da803,
+    0x002daf00, 0x002db003, 0x002db700, 0x002db803, 0x002dbf00, 0x002dc003, 0x002dc700, 0x002dc803,
+    0x002dcf00, 0x002dd003, 0x002dd700, 0x002dd803, 0x002ddf00, 0x002de00d, 0x002e0025, 0x002e02a5,
+    0x002e03e5, 0x002e04a5, 0x002e05e5, 0x002e0625, 0x002e09a5, 0x002e0ae5, 0x002e0b25, 0x002e0ca5,
+    0x002e0de5, 0x002e0e07, 0x002e1603, 0x002e1707, 0x002e1822, 0x002e1907, 0x002e1a03, 0x002e1ca5,
+    0x002e1de5, 0x002e1e03, 0x002e20a5, 0x002e21e5, 0x002e2222, 0x002e230c, 0x002e2422, 0x002e250c,
+    0x002e2622, 0x002e270c, 0x002e2822, 0x002e290c, 0x002e2a07, 0x002e2e12, 0x002e2f03, 0x002e3007,
+    0x002e3203, 0x002e3307, 0x002e3503, 0x002e3a06, 0x002e3c07, 0x002e3f03, 0x002e4007, 0x002e4222,
+    0x002e4307, 0x002e4b03, 0x002e4c07, 0x002e4d03, 0x002e4e07, 0x002e5003, 0x002e5312, 0x002e5522,
+    0x002e560e, 0x002e5722, 0x002e580e, 0x002e5922, 0x002e5a0e, 0x002e5b22, 0x002e5c0e, 0x002e5d07,
+    0x002e5e00, 0x002e8058, 0x002e9a00, 0x002e9b58, 0x002ef400, 0x002f0058, 0x002fd600, 0x002ff058,
+    0x00300047, 0x0030014c, 0x00300358, 0x00300560, 0x00300658, 0x00300862, 0x0030094c, 0x00300a62,
+    0x00300b4c, 0x00300c62, 0x00300d4c, 0x00300e62, 0x00300f4c, 0x00301062, 0x0030114c, 0x00301258,
+    0x00301462, 0x0030154c, 0x00301662, 0x0030174c, 0x00301862, 0x0030194c, 0x00301a62, 0x00301b4c,
+    0x00301c60, 0x00301d62, 0x00301e4c, 0x00302058, 0x00302a4d, 0x00303058, 0x0030354d, 0x00303658,
+    0x00303b60, 0x00303d58, 0x00303f18, 0x00304000, 0x0030414b, 0x00304258, 0x0030434b, 0x00304458,
+    0x0030454b, 0x00304658, 0x0030474b, 0x00304858, 0x0030494b, 0x00304a58, 0x0030634b, 0x00306458,
+    0x0030834b, 0x00308458, 0x0030854b, 0x00308658, 0x0030874b, 0x00308858, 0x00308e4b, 0x00308f58,
+    0x0030954b, 0x00309700, 0x0030994d, 0x00309b60, 0x00309f58, 0x0030a060, 0x0030a14b, 0x0030a258,
+    0x0030a34b, 0x0030a458, 0x0030a54b, 0x0030a658, 0x0030a74b, 0x0030a858, 0x0030a94b, 0x0030aa58,
+    0x0030c34b, 0x0030c458, 0x0030e34b, 0x0030e458, 0x0030e54b, 0x0030e658, 0x0030e74b, 0x0030e858,
+    0x0030ee4b, 0x0030ef58, 0x0030f54b, 0x0030f758, 0x0030fb60, 0x0030fc4b, 0x0030fd60, 0x0030ff58,
+    0x00310000, 0x00310558, 0x00313000, 0x00313158, 0x00318f00, 0x00319058, 0x0031e600, 0x0031ef58,
+    0x0031f04b, 0x00320058, 0x00321f00, 0x00322058, 0x00324801, 0x00325058, 0x004dc043, 0x004e0058,
+    0x00a01560, 0x00a01658, 0x00a48d00, 0x00a49058, 0x00a4c700, 0x00a4d003, 0x00a4fe07, 0x00a50003,
+    0x00a60d07, 0x00a60e12, 0x00a60f07, 0x00a61003, 0x00a62021, 0x00a62a03, 0x00a62c00, 0x00a64003,
+    0x00a66f0d, 0x00a67303, 0x00a6740d, 0x00a67e03, 0x00a69e0d, 0x00a6a003, 0x00a6f00d, 0x00a6f203,
+    0x00a6f307, 0x00a6f800, 0x00a70003, 0x00a7ce00, 0x00a7d003, 0x00a7d200, 0x00a7d303, 0x00a7d400,
+    0x00a7d503, 0x00a7dd00, 0x00a7f203, 0x00a8020d, 0x00a80303, 0x00a8060d, 0x00a80703, 0x00a80b0d,
+    0x00a80c03, 0x00a8230d, 0x00a82803, 0x00a82c0d, 0x00a82d00, 0x00a83003, 0x00a83823, 0x00a83903,
+    0x00a83a00, 0x00a84003, 0x00a87408, 0x00a87612, 0x00a87800, 0x00a8800d, 0x00a88203, 0x00a8b40d,
+    0x00a8c600, 0x00a8ce07, 0x00a8d021, 0x00a8da00, 0x00a8e00d, 0x00a8f203, 0x00a8fc08, 0x00a8fd03,
+    0x00a8ff0d, 0x00a90021, 0x00a90a03, 0x00a9260d, 0x00a92e07, 0x00a93003, 0x00a9470d, 0x00a95400,
+    0x00a95f03, 0x00a9605b, 0x00a97d00, 0x00a9800d, 0x00a98402, 0x00a9b30d, 0x00a9c02c, 0x00a9c118,
+    0x00a9c707, 0x00a9ca18, 0x00a9ce00, 0x00a9cf07, 0x00a9d005, 0x00a9da00, 0x00a9de18, 0x00a9e027,
+    0x00a9e5a7, 0x00a9e627, 0x00a9f021, 0x00a9fa27, 0x00a9ff00, 0x00aa0005, 0x00aa290d, 0x00aa3700,
+    0x00aa4007, 0x00aa430d, 0x00aa4407, 0x00aa4c0d, 0x00aa4e00, 0x00aa5005, 0x00aa5a00, 0x00aa5c18,
+    0x00aa5d07, 0x00aa6027, 0x00aa7ba7, 0x00aa7e27, 0x00aab0a7, 0x00aab127, 0x00aab2a7, 0x00aab527,
+    0x00aab7a7, 0x00aab927, 0x00aabea7, 0x00aac027, 0x00aac1a7, 0x00aac227, 0x00aac300, 0x00aadb27,
+    0x00aae003, 0x00aaeb0d, 0x00aaf007, 0x00aaf203, 0x00aaf50d, 0x00aaf700, 0x00ab0103, 0x00ab0700,
+    0x00ab0903, 0x00ab0f00, 0x00ab1103, 0x00ab1700, 0x00ab2003, 0x00ab2700, 0x00ab2803, 0x00ab2f00,
+    0x00ab3003, 0x00ab6c00, 0x00ab7003, 0x00abe30d, 0x00abeb07, 0x00abec0d, 0x00abee00, 0x00abf021,
+    0x00abfa00, 0x00ac0054, 0x00ac0155, 0x00ac1c54, 0x00ac1d55, 0x00ac3854, 0x00ac3955, 0x00ac5454,
+    0x00ac5555, 0x00ac7054, 0x00ac7155, 0x00ac8c54, 0x00ac8d55, 0x00aca854, 0x00aca955, 0x00acc454,
+    0x00acc555, 0x00ace054, 0x00ace155, 0x00acfc54, 0x00acfd55, 0x00ad1854, 0x00ad1955, 0x00ad3454,
+    0x00ad3555, 0x00ad5054, 0x00ad5155, 0x00ad6c54, 0x00ad6d55, 0x00ad8854, 0x00ad8955, 0x00ada454,
+    0x00ada555, 0x00adc054, 0x00adc155, 0x00addc54, 0x00addd55, 0x00adf854, 0x00adf955, 0x00ae1454,
+    0x00ae1555, 0x00ae3054, 0x00ae3155, 0x00ae4c54, 0x00ae4d55, 0x00ae6854, 0x00ae6955, 0x00ae8454,
+    0x00ae8555, 0x00aea054, 0x00aea155, 0x00aebc54, 0x00aebd55, 0x00aed854, 0x00aed955, 0x00aef454,
+    0x00aef555, 0x00af1054, 0x00af1155, 0x00af2c54, 0x00af2d55, 0x00af4854, 0x00af4955, 0x00af6454,
+    0x00af6555, 0x00af8054, 0x00af8155, 0x00af9c54, 0x00af9d55, 0x00afb854, 0x00afb955, 0x00afd454,
+    0x00afd555, 0x00aff054, 0x00aff155, 0x00b00c54, 0x00b00d55, 0x00b02854, 0x00b02955, 0x00b04454,
+    0x00b04555, 0x00b06054, 0x00b06155, 0x00b07c54, 0x00b07d55, 0x00b09854, 0x00b09955, 0x00b0b454,
+    0x00b0b555, 0x00b0d054, 0x00b0d155, 0x00b0ec54, 0x00b0ed55, 0x00b10854, 0x00b10955, 0x00b12454,
+    0x00b12555, 0x00b14054, 0x00b14155, 0x00b15c54, 0x00b15d55, 0x00b17854, 0x00b17955, 0x00b19454,
+    0x00b19555, 0x00b1b054, 0x00b1b155, 0x00b1cc54, 0x00b1cd55, 0x00b1e854, 0x00b1e955, 0x00b20454,
+    0x00b20555, 0x00b22054, 0x00b22155, 0x00b23c54, 0x00b23d55, 0x00b25854, 0x00b25955, 0x00b27454,
+    0x00b27555, 0x00b29054, 0x00b29155, 0x00b2ac54, 0x00b2ad55, 0x00b2c854, 0x00b2c955, 0x00b2e454,
+    0x00b2e555, 0x00b30054, 0x00b30155, 0x00b31c54, 0x00b31d55, 0x00b33854, 0x00b33955, 0x00b35454,
+    0x00b35555, 0x00b37054, 0x00b37155, 0x00b38c54, 0x00b38d55, 0x00b3a854, 0x00b3a955, 0x00b3c454,
+    0x00b3c555, 0x00b3e054, 0x00b3e155, 0x00b3fc54, 0x00b3fd55, 0x00b41854, 0x00b41955, 0x00b43454,
+    0x00b43555, 0x00b45054, 0x00b45155, 0x00b46c54, 0x00b46d55, 0x00b48854, 0x00b48955, 0x00b4a454,
+    0x00b4a555, 0x00b4c054, 0x00b4c155, 0x00b4dc54, 0x00b4dd55, 0x00b4f854, 0x00b4f955, 0x00b51454,
+    0x00b51555, 0x00b53054, 0x00b53155, 0x00b54c54, 0x00b54d55, 0x00b56854, 0x00b56955, 0x00b58454,
+    0x00b58555, 0x00b5a054, 0x00b5a155, 0x00b5bc54, 0x00b5bd55, 0x00b5d854, 0x00b5d955, 0x00b5f454,
+    0x00b5f555, 0x00b61054, 0x00b61155, 0x00b62c54, 0x00b62d55, 0x00b64854, 0x00b64955, 0x00b66454,
+    0x00b66555, 0x00b68054, 0x00b68155, 0x00b69c54, 0x00b69d55, 0x00b6b854, 0x00b6b955, 0x00b6d454,
+    0x00b6d555, 0x00b6f054, 0x00b6f155, 0x00b70c54, 0x00b70d55, 0x00b72854, 0x00b72955, 0x00b74454,
+    0x00b74555, 0x00b76054, 0x00b76155, 0x00b77c54, 0x00b77d55, 0x00b79854, 0x00b79955, 0x00b7b454,
+    0x00b7b555, 0x00b7d054, 0x00b7d155, 0x00b7ec54, 0x00b7ed55, 0x00b80854, 0x00b80955, 0x00b82454,
+    0x00b82555, 0x00b84054, 0x00b84155, 0x00b85c54, 0x00b85d55, 0x00b87854, 0x00b87955, 0x00b89454,
+    0x00b89555, 0x00b8b054, 0x00b8b155, 0x00b8cc54, 0x00b8cd55, 0x00b8e854, 0x00b8e955, 0x00b90454,
+    0x00b90555, 0x00b92054, 0x00b92155, 0x00b93c54, 0x00b93d55, 0x00b95854, 0x00b95955, 0x00b97454,
+    0x00b97555, 0x00b99054, 0x00b99155, 0x00b9ac54, 0x00b9ad55, 0x00b9c854, 0x00b9c955, 0x00b9e454,
+    0x00b9e555, 0x00ba0054, 0x00ba0155, 0x00ba1c54, 0x00ba1d55, 0x00ba3854, 0x00ba3955, 0x00ba5454,
+    0x00ba5555, 0x00ba7054, 0x00ba7155, 0x00ba8c54, 0x00ba8d55, 0x00baa854, 0x00baa955, 0x00bac454,
+    0x00bac555, 0x00bae054, 0x00bae155, 0x00bafc54, 0x00bafd55, 0x00bb1854, 0x00bb1955, 0x00bb3454,
+    0x00bb3555, 0x00bb5054, 0x00bb5155, 0x00bb6c54, 0x00bb6d55, 0x00bb8854, 0x00bb8955, 0x00bba454,
+    0x00bba555, 0x00bbc054, 0x00bbc155, 0x00bbdc54, 0x00bbdd55, 0x00bbf854, 0x00bbf955, 0x00bc1454,
+    0x00bc1555, 0x00bc3054, 0x00bc3155, 0x00bc4c54, 0x00bc4d55, 0x00bc6854, 0x00bc6955, 0x00bc8454,
+    0x00bc8555, 0x00bca054, 0x00bca155, 0x00bcbc54, 0x00bcbd55, 0x00bcd854, 0x00bcd955, 0x00bcf454,
+    0x00bcf555, 0x00bd1054, 0x00bd1155, 0x00bd2c54, 0x00bd2d55, 0x00bd4854, 0x00bd4955, 0x00bd6454,
+    0x00bd6555, 0x00bd8054, 0x00bd8155, 0x00bd9c54, 0x00bd9d55, 0x00bdb854, 0x00bdb955, 0x00bdd454,
+    0x00bdd555, 0x00bdf054, 0x00bdf155, 0x00be0c54, 0x00be0d55, 0x00be2854, 0x00be2955, 0x00be4454,
+    0x00be4555, 0x00be6054, 0x00be6155, 0x00be7c54, 0x00be7d55, 0x00be9854, 0x00be9955, 0x00beb454,
+    0x00beb555, 0x00bed054, 0x00bed155, 0x00beec54, 0x00beed55, 0x00bf0854, 0x00bf0955, 0x00bf2454,
+    0x00bf2555, 0x00bf4054, 0x00bf4155, 0x00bf5c54, 0x00bf5d55, 0x00bf7854, 0x00bf7955, 0x00bf9454,
+    0x00bf9555, 0x00bfb054, 0x00bfb155, 0x00bfcc54, 0x00bfcd55, 0x00bfe854, 0x00bfe955, 0x00c00454,
+    0x00c00555, 0x00c02054, 0x00c02155, 0x00c03c54, 0x00c03d55, 0x00c05854, 0x00c05955, 0x00c07454,
+    0x00c07555, 0x00c09054, 0x00c09155, 0x00c0ac54, 0x00c0ad55, 0x00c0c854, 0x00c0c955, 0x00c0e454,
+    0x00c0e555, 0x00c10054, 0x00c10155, 0x00c11c54, 0x00c11d55, 0x00c13854, 0x00c13955, 0x00c15454,
+    0x00c15555, 0x00c17054, 0x00c17155, 0x00c18c54, 0x00c18d55, 0x00c1a854, 0x00c1a955, 0x00c1c454,
+    0x00c1c555, 0x00c1e054, 0x00c1e155, 0x00c1fc54, 0x00c1fd55, 0x00c21854, 0x00c21955, 0x00c23454,
+    0x00c23555, 0x00c25054, 0x00c25155, 0x00c26c54, 0x00c26d55, 0x00c28854, 0x00c28955, 0x00c2a454,
+    0x00c2a555, 0x00c2c054, 0x00c2c155, 0x00c2dc54, 0x00c2dd55, 0x00c2f854, 0x00c2f955, 0x00c31454,
+    0x00c31555, 0x00c33054, 0x00c33155, 0x00c34c54, 0x00c34d55, 0x00c36854, 0x00c36955, 0x00c38454,
+    0x00c38555, 0x00c3a054, 0x00c3a155, 0x00c3bc54, 0x00c3bd55, 0x00c3d854, 0x00c3d955, 0x00c3f454,
+    0x00c3f555, 0x00c41054, 0x00c41155, 0x00c42c54, 0x00c42d55, 0x00c44854, 0x00c44955, 0x00c46454,
+    0x00c46555, 0x00c48054, 0x00c48155, 0x00c49c54, 0x00c49d55, 0x00c4b854, 0x00c4b955, 0x00c4d454,
+    0x00c4d555, 0x00c4f054, 0x00c4f155, 0x00c50c54, 0x00c50d55, 0x00c52854, 0x00c52955, 0x00c54454,
+    0x00c54555, 0x00c56054, 0x00c56155, 0x00c57c54, 0x00c57d55, 0x00c59854, 0x00c59955, 0x00c5b454,
+    0x00c5b555, 0x00c5d054, 0x00c5d155, 0x00c5ec54, 0x00c5ed55, 0x00c60854, 0x00c60955, 0x00c62454,
+    0x00c62555, 0x00c64054, 0x00c64155, 0x00c65c54, 0x00c65d55, 0x00c67854, 0x00c67955, 0x00c69454,
+    0x00c69555, 0x00c6b054, 0x00c6b155, 0x00c6cc54, 0x00c6cd55, 0x00c6e854, 0x00c6e955, 0x00c70454,
+    0x00c70555, 0x00c72054, 0x00c72155, 0x00c73c54, 0x00c73d55, 0x00c75854, 0x00c75955, 0x00c77454,
+    0x00c77555, 0x00c79054, 0x00c79155, 0x00c7ac54, 0x00c7ad55, 0x00c7c854, 0x00c7c955, 0x00c7e454,
+    0x00c7e555, 0x00c80054, 0x00c80155, 0x00c81c54, 0x00c81d55, 0x00c83854, 0x00c83955, 0x00c85454,
+    0x00c85555, 0x00c87054, 0x00c87155, 0x00c88c54, 0x00c88d55, 0x00c8a854, 0x00c8a955, 0x00c8c454,
+    0x00c8c555, 0x00c8e054, 0x00c8e155, 0x00c8fc54, 0x00c8fd55, 0x00c91854, 0x00c91955, 0x00c93454,
+    0x00c93555, 0x00c95054, 0x00c95155, 0x00c96c54, 0x00c96d55, 0x00c98854, 0x00c98955, 0x00c9a454,
+    0x00c9a555, 0x00c9c054, 0x00c9c155, 0x00c9dc54, 0x00c9dd55, 0x00c9f854, 0x00c9f955, 0x00ca1454,
+    0x00ca1555, 0x00ca3054, 0x00ca3155, 0x00ca4c54, 0x00ca4d55, 0x00ca6854, 0x00ca6955, 0x00ca8454,
+    0x00ca8555, 0x00caa054, 0x00caa155, 0x00cabc54, 0x00cabd55, 0x00cad854, 0x00cad955, 0x00caf454,
+    0x00caf555, 0x00cb1054, 0x00cb1155, 0x00cb2c54, 0x00cb2d55, 0x00cb4854, 0x00cb4955, 0x00cb6454,
+    0x00cb6555, 0x00cb8054, 0x00cb8155, 0x00cb9c54, 0x00cb9d55, 0x00cbb854, 0x00cbb955, 0x00cbd454,
+    0x00cbd555, 0x00cbf054, 0x00cbf155, 0x00cc0c54, 0x00cc0d55, 0x00cc2854, 0x00cc2955, 0x00cc4454,
+    0x00cc4555, 0x00cc6054, 0x00cc6155, 0x00cc7c54, 0x00cc7d55, 0x00cc9854, 0x00cc9955, 0x00ccb454,
+    0x00ccb555, 0x00ccd054, 0x00ccd155, 0x00ccec54, 0x00cced55, 0x00cd0854, 0x00cd0955, 0x00cd2454,
+    0x00cd2555, 0x00cd4054, 0x00cd4155, 0x00cd5c54, 0x00cd5d55, 0x00cd7854, 0x00cd7955, 0x00cd9454,
+    0x00cd9555, 0x00cdb054, 0x00cdb155, 0x00cdcc54, 0x00cdcd55, 0x00cde854, 0x00cde955, 0x00ce0454,
+    0x00ce0555, 0x00ce2054, 0x00ce2155, 0x00ce3c54, 0x00ce3d55, 0x00ce5854, 0x00ce5955, 0x00ce7454,
+    0x00ce7555, 0x00ce9054, 0x00ce9155, 0x00ceac54, 0x00cead55, 0x00cec854, 0x00cec955, 0x00cee454,
+    0x00cee555, 0x00cf0054, 0x00cf0155, 0x00cf1c54, 0x00cf1d55, 0x00cf3854, 0x00cf3955, 0x00cf5454,
+    0x00cf5555, 0x00cf7054, 0x00cf7155, 0x00cf8c54, 0x00cf8d55, 0x00cfa854, 0x00cfa955, 0x00cfc454,
+    0x00cfc555, 0x00cfe054, 0x00cfe155, 0x00cffc54, 0x00cffd55, 0x00d01854, 0x00d01955, 0x00d03454,
+    0x00d03555, 0x00d05054, 0x00d05155, 0x00d06c54, 0x00d06d55, 0x00d08854, 0x00d08955, 0x00d0a454,
+    0x00d0a555, 0x00d0c054, 0x00d0c155, 0x00d0dc54, 0x00d0dd55, 0x00d0f854, 0x00d0f955, 0x00d11454,
+    0x00d11555, 0x00d13054, 0x00d13155, 0x00d14c54, 0x00d14d55, 0x00d16854, 0x00d16955, 0x00d18454,
+    0x00d18555, 0x00d1a054, 0x00d1a155, 0x00d1bc54, 0x00d1bd55, 0x00d1d854, 0x00d1d955, 0x00d1f454,
+    0x00d1f555, 0x00d21054, 0x00d21155, 0x00d22c54, 0x00d22d55, 0x00d24854, 0x00d24955, 0x00d26454,
+    0x00d26555, 0x00d28054, 0x00d28155, 0x00d29c54, 0x00d29d55, 0x00d2b854, 0x00d2b955, 0x00d2d454,
+    0x00d2d555, 0x00d2f054, 0x00d2f155, 0x00d30c54, 0x00d30d55, 0x00d32854, 0x00d32955, 0x00d34454,
+    0x00d34555, 0x00d36054, 0x00d36155, 0x00d37c54, 0x00d37d55, 0x00d39854, 0x00d39955, 0x00d3b454,
+    0x00d3b555, 0x00d3d054, 0x00d3d155, 0x00d3ec54, 0x00d3ed55, 0x00d40854, 0x00d40955, 0x00d42454,
+    0x00d42555, 0x00d44054, 0x00d44155, 0x00d45c54, 0x00d45d55, 0x00d47854, 0x00d47955, 0x00d49454,
+    0x00d49555, 0x00d4b054, 0x00d4b155, 0x00d4cc54, 0x00d4cd55, 0x00d4e854, 0x00d4e955, 0x00d50454,
+    0x00d50555, 0x00d52054, 0x00d52155, 0x00d53c54, 0x00d53d55, 0x00d55854, 0x00d55955, 0x00d57454,
+    0x00d57555, 0x00d59054, 0x00d59155, 0x00d5ac54, 0x00d5ad55, 0x00d5c854, 0x00d5c955, 0x00d5e454,
+    0x00d5e555, 0x00d60054, 0x00d60155, 0x00d61c54, 0x00d61d55, 0x00d63854, 0x00d63955, 0x00d65454,
+    0x00d65555, 0x00d67054, 0x00d67155, 0x00d68c54, 0x00d68d55, 0x00d6a854, 0x00d6a955, 0x00d6c454,
+    0x00d6c555, 0x00d6e054, 0x00d6e155, 0x00d6fc54, 0x00d6fd55, 0x00d71854, 0x00d71955, 0x00d73454,
+    0x00d73555, 0x00d75054, 0x00d75155, 0x00d76c54, 0x00d76d55, 0x00d78854, 0x00d78955, 0x00d7a400,
+    0x00d7b01d, 0x00d7c700, 0x00d7cb1c, 0x00d7fc00, 0x00d80028, 0x00e00000, 0x00f90058, 0x00fb0003,
+    0x00fb0700, 0x00fb1303, 0x00fb1800, 0x00fb1d16, 0x00fb1e0d, 0x00fb1f16, 0x00fb2903, 0x00fb2a16,
+    0x00fb3700, 0x00fb3816, 0x00fb3d00, 0x00fb3e16, 0x00fb3f00, 0x00fb4016, 0x00fb4200, 0x00fb4316,
+    0x00fb4500, 0x00fb4616, 0x00fb5003, 0x00fbc300, 0x00fbd303, 0x00fd3e0c, 0x00fd3f22, 0x00fd4003,
+    0x00fd9000, 0x00fd9203, 0x00fdc800, 0x00fdcf03, 0x00fdd000, 0x00fdf003, 0x00fdfc23, 0x00fdfd03,
+    0x00fe000d, 0x00fe104c, 0x00fe1360, 0x00fe1552, 0x00fe1762, 0x00fe184c, 0x00fe1959, 0x00fe1a00,
+    0x00fe2013, 0x00fe210d, 0x00fe2213, 0x00fe230d, 0x00fe2413, 0x00fe250d, 0x00fe2613, 0x00fe280d,
+    0x00fe2913, 0x00fe2a0d, 0x00fe2b13, 0x00fe2c0d, 0x00fe2d13, 0x00fe2f0d, 0x00fe3058, 0x00fe3562,
+    0x00fe364c, 0x00fe3762, 0x00fe384c, 0x00fe3962, 0x00fe3a4c, 0x00fe3b62, 0x00fe3c4c, 0x00fe3d62,
+    0x00fe3e4c, 0x00fe3f62, 0x00fe404c, 0x00fe4162, 0x00fe424c, 0x00fe4362, 0x00fe444c, 0x00fe4558,
+    0x00fe4762, 0x00fe484c, 0x00fe4958, 0x00fe504c, 0x00fe5158, 0x00fe524c, 0x00fe5300, 0x00fe5460,
+    0x00fe5652, 0x00fe5858, 0x00fe5962, 0x00fe5a4c, 0x00fe5b62, 0x00fe5c4c, 0x00fe5d62, 0x00fe5e4c,
+    0x00fe5f58, 0x00fe6700, 0x00fe6858, 0x00fe6964, 0x00fe6a63, 0x00fe6b58, 0x00fe6c00, 0x00fe7003,
+    0x00fe7500, 0x00fe7603, 0x00fefd00, 0x00feff2d, 0x00ff0000, 0x00ff0152, 0x00ff0258, 0x00ff0464,
+    0x00ff0563, 0x00ff0658, 0x00ff0862, 0x00ff094c, 0x00ff0a58, 0x00ff0c4c, 0x00ff0d58, 0x00ff0e4c,
+    0x00ff0f58, 0x00ff1a60, 0x00ff1c58, 0x00ff1f52, 0x00ff2058, 0x00ff3b62, 0x00ff3c58, 0x00ff3d4c,
+    0x00ff3e58, 0x00ff5b62, 0x00ff5c58, 0x00ff5d4c, 0x00ff5e58, 0x00ff5f62, 0x00ff604c, 0x00ff6262,
+    0x00ff634c, 0x00ff6560, 0x00ff6658, 0x00ff674b, 0x00ff7158, 0x00ff9e60, 0x00ffa058, 0x00ffbf00,
+    0x00ffc258, 0x00ffc800, 0x00ffca58, 0x00ffd000, 0x00ffd258, 0x00ffd800, 0x00ffda58, 0x00ffdd00,
+    0x00ffe063, 0x00ffe164, 0x00ffe258, 0x00ffe564, 0x00ffe700, 0x00ffe843, 0x00ffef00, 0x00fff90d,
+    0x00fffc0a, 0x00fffd01, 0x00fffe00, 0x01000003, 0x01000c00, 0x01000d03, 0x01002700, 0x01002803,
+    0x01003b00, 0x01003c03, 0x01003e00, 0x01003f03, 0x01004e00, 0x01005003, 0x01005e00, 0x01008003,
+    0x0100fb00, 0x01010007, 0x01010300, 0x01010703, 0x01013400, 0x01013703, 0x01018f00, 0x01019003,
+    0x01019d00, 0x0101a003, 0x0101a100, 0x0101d003, 0x0101fd0d, 0x0101fe00, 0x01028003, 0x01029d00,
+    0x0102a003, 0x0102d100, 0x0102e00d, 0x0102e103, 0x0102fc00, 0x01030003, 0x01032400, 0x01032d03,
+    0x01034b00, 0x01035003, 0x0103760d, 0x01037b00, 0x01038003, 0x01039e00, 0x01039f07, 0x0103a003,
+    0x0103c400, 0x0103c803, 0x0103d007, 0x0103d103, 0x0103d600, 0x01040003, 0x01049e00, 0x0104a021,
+    0x0104aa00, 0x0104b003, 0x0104d400, 0x0104d803, 0x0104fc00, 0x01050003, 0x01052800, 0x01053003,
+    0x01056400, 0x01056f03, 0x01057b00, 0x01057c03, 0x01058b00, 0x01058c03, 0x01059300, 0x01059403,
+    0x01059600, 0x01059703, 0x0105a200, 0x0105a303, 0x0105b200, 0x0105b303, 0x0105ba00, 0x0105bb03,
+    0x0105bd00, 0x0105c003, 0x0105f400, 0x01060003, 0x01073700, 0x01074003, 0x01075600, 0x01076003,
+    0x01076800, 0x01078003, 0x01078600, 0x01078703, 0x0107b100, 0x0107b203, 0x0107bb00, 0x01080003,
+    0x01080600, 0x01080803, 0x01080900, 0x01080a03, 0x01083600, 0x01083703, 0x01083900, 0x01083c03,
+    0x01083d00, 0x01083f03, 0x01085600, 0x01085707, 0x01085803, 0x01089f00, 0x0108a703, 0x0108b000,
+    0x0108e003, 0x0108f300, 0x0108f403, 0x0108f600, 0x0108fb03, 0x01091c00, 0x01091f07, 0x01092003,
+    0x01093a00, 0x01093f03, 0x01094000, 0x01098003, 0x0109b800, 0x0109bc03, 0x0109d000, 0x0109d203,
+    0x010a010d, 0x010a0400, 0x010a050d, 0x010a0700, 0x010a0c0d, 0x010a1003, 0x010a1400, 0x010a1503,
+    0x010a1800, 0x010a1903, 0x010a3600, 0x010a380d, 0x010a3b00, 0x010a3f0d, 0x010a4003, 0x010a4900,
+    0x010a5007, 0x010a5803, 0x010a5900, 0x010a6003, 0x010aa000, 0x010ac003, 0x010ae50d, 0x010ae700,
+    0x010aeb03, 0x010af007, 0x010af619, 0x010af700, 0x010b0003, 0x010b3600, 0x010b3907, 0x010b4003,
+    0x010b5600, 0x010b5803, 0x010b7300, 0x010b7803, 0x010b9200, 0x010b9903, 0x010b9d00, 0x010ba903,
+    0x010bb000, 0x010c0003, 0x010c4900, 0x010c8003, 0x010cb300, 0x010cc003, 0x010cf300, 0x010cfa03,
+    0x010d240d, 0x010d2800, 0x010d3021, 0x010d3a00, 0x010d4021, 0x010d4a03, 0x010d6600, 0x010d690d,
+    0x010d6e07, 0x010d6f03, 0x010d8600, 0x010d8e03, 0x010d9000, 0x010e6003, 0x010e7f00, 0x010e8003,
+    0x010eaa00, 0x010eab0d, 0x010ead07, 0x010eae00, 0x010eb003, 0x010eb200, 0x010ec203, 0x010ec500,
+    0x010efc0d, 0x010f0003, 0x010f2800, 0x010f3003, 0x010f460d, 0x010f5103, 0x010f5a00, 0x010f7003,
+    0x010f820d, 0x010f8603, 0x010f8a00, 0x010fb003, 0x010fcc00, 0x010fe003, 0x010ff700, 0x0110000d,
+    0x01100304, 0x01100502, 0x0110380d, 0x0110462c, 0x01104707, 0x01104918, 0x01104e00, 0x01105218,
+    0x01106605, 0x0110700d, 0x01107102, 0x0110730d, 0x01107502, 0x01107600, 0x01107f13, 0x0110800d,
+    0x01108303, 0x0110b00d, 0x0110bb03, 0x0110bd21, 0x0110be07, 0x0110c20d, 0x0110c300, 0x0110cd21,
+    0x0110ce00, 0x0110d003, 0x0110e900, 0x0110f021, 0x0110fa00, 0x0111000d, 0x01110303, 0x0111270d,
+    0x01113500, 0x01113621, 0x01114007, 0x01114403, 0x0111450d, 0x01114703, 0x01114800, 0x01115003,
+    0x0111730d, 0x01117403, 0x01117508, 0x01117603, 0x01117700, 0x0111800d, 0x01118303, 0x0111b30d,
+    0x0111c103, 0x0111c507, 0x0111c703, 0x0111c807, 0x0111c90d, 0x0111cd03, 0x0111ce0d, 0x0111d021,
+    0x0111da03, 0x0111db08, 0x0111dc03, 0x0111dd07, 0x0111e000, 0x0111e103, 0x0111f500, 0x01120003,
+    0x01121200, 0x01121303, 0x01122c0d, 0x01123807, 0x01123a03, 0x01123b07, 0x01123d03, 0x01123e0d,
+    0x01123f03, 0x0112410d, 0x01124200, 0x01128003, 0x01128700, 0x01128803, 0x01128900, 0x01128a03,
+    0x01128e00, 0x01128f03, 0x01129e00, 0x01129f03, 0x0112a907, 0x0112aa00, 0x0112b003, 0x0112df0d,
+    0x0112eb00, 0x0112f021, 0x0112fa00, 0x0113000d, 0x01130400, 0x01130502, 0x01130d00, 0x01130f02,
+    0x01131100, 0x01131302, 0x01132900, 0x01132a02, 0x01133100, 0x01133202, 0x01133400, 0x01133502,
+    0x01133a00, 0x01133b0d, 0x01133d07, 0x01133e0d, 0x01134500, 0x0113470d, 0x01134900, 0x01134b0d,
+    0x01134d2c, 0x01134e00, 0x01135005, 0x01135100, 0x0113570d, 0x01135800, 0x01135d07, 0x01135e05,
+    0x01136002, 0x0113620d, 0x01136400, 0x0113660d, 0x01136d00, 0x0113700d, 0x01137500, 0x01138005,
+    0x01138a00, 0x01138b05, 0x01138c00, 0x01138e05, 0x01138f00, 0x01139005, 0x01139202, 0x0113b600,
+    0x0113b718, 0x0113b80d, 0x0113c100, 0x0113c20d, 0x0113c300, 0x0113c50d, 0x0113c600, 0x0113c70d,
+    0x0113cb00, 0x0113cc0d, 0x0113d02c, 0x0113d104, 0x0113d20d, 0x0113d318, 0x0113d600, 0x0113d718,
+    0x0113d900, 0x0113e10d, 0x0113e300, 0x01140003, 0x0114350d, 0x01144703, 0x01144b07, 0x01144f03,
+    0x01145021, 0x01145a07, 0x01145c00, 0x01145d03, 0x01145e0d, 0x01145f03, 0x01146200, 0x01148003,
+    0x0114b00d, 0x0114c403, 0x0114c800, 0x0114d021, 0x0114da00, 0x01158003, 0x0115af0d, 0x0115b600,
+    0x0115b80d, 0x0115c108, 0x0115c207, 0x0115c412, 0x0115c603, 0x0115c907, 0x0115d803, 0x0115dc0d,
+    0x0115de00, 0x01160003, 0x0116300d, 0x01164107, 0x01164303, 0x01164500, 0x01165021, 0x01165a00,
+    0x01166008, 0x01166d00, 0x01168003, 0x0116ab0d, 0x0116b803, 0x0116ba00, 0x0116c021, 0x0116ca00,
+    0x0116d021, 0x0116e400, 0x01170027, 0x01171b00, 0x01171da7, 0x01172c00, 0x01173021, 0x01173a27,
+    0x01173c07, 0x01173f27, 0x01174700, 0x01180003, 0x01182c0d, 0x01183b03, 0x01183c00, 0x0118a003,
+    0x0118e021, 0x0118ea03, 0x0118f300, 0x0118ff03, 0x01190002, 0x01190700, 0x01190902, 0x01190a00,
+    0x01190c02, 0x01191400, 0x01191502, 0x01191700, 0x01191802, 0x0119300d, 0x01193600, 0x0119370d,
+    0x01193900, 0x01193b0d, 0x01193e2c, 0x01193f04, 0x0119400d, 0x01194104, 0x0119420d, 0x01194407,
+    0x01194700, 0x01195005, 0x01195a00, 0x0119a003, 0x0119a800, 0x0119aa03, 0x0119d10d, 0x0119d800,
+    0x0119da0d, 0x0119e103, 0x0119e208, 0x0119e303, 0x0119e40d, 0x0119e500, 0x011a0003, 0x011a010d,
+    0x011a0b03, 0x011a330d, 0x011a3a03, 0x011a3b0d, 0x011a3f08, 0x011a4003, 0x011a4107, 0x011a4508,
+    0x011a4603, 0x011a470d, 0x011a4800, 0x011a5003, 0x011a510d, 0x011a5c03, 0x011a8a0d, 0x011a9a07,
+    0x011a9d03, 0x011a9e08, 0x011aa107, 0x011aa300, 0x011ab003, 0x011af900, 0x011b0008, 0x011b0a00,
+    0x011bc003, 0x011be200, 0x011bf021, 0x011bfa00, 0x011c0003, 0x011c0900, 0x011c0a03, 0x011c2f0d,
+    0x011c3700, 0x011c380d, 0x011c4003, 0x011c4107, 0x011c4600, 0x011c5021, 0x011c5a03, 0x011c6d00,
+    0x011c7008, 0x011c7112, 0x011c7203, 0x011c9000, 0x011c920d, 0x011ca800, 0x011ca90d, 0x011cb700,
+    0x011d0003, 0x011d0700, 0x011d0803, 0x011d0a00, 0x011d0b03, 0x011d310d, 0x011d3700, 0x011d3a0d,
+    0x011d3b00, 0x011d3c0d, 0x011d3e00, 0x011d3f0d, 0x011d4603, 0x011d470d, 0x011d4800, 0x011d5021,
+    0x011d5a00, 0x011d6003, 0x011d6600, 0x011d6703, 0x011d6900, 0x011d6a03, 0x011d8a0d, 0x011d8f00,
+    0x011d900d, 0x011d9200, 0x011d930d, 0x011d9803, 0x011d9900, 0x011da021, 0x011daa00, 0x011ee005,
+    0x011ef207, 0x011ef30d, 0x011ef707, 0x011ef900, 0x011f000d, 0x011f0204, 0x011f030d, 0x011f0402,
+    0x011f1100, 0x011f1202, 0x011f340d, 0x011f3b00, 0x011f3e0d, 0x011f422c, 0x011f4307, 0x011f4518,
+    0x011f5005, 0x011f5a0d, 0x011f5b00, 0x011fb003, 0x011fb100, 0x011fc003, 0x011fdd23, 0x011fe103,
+    0x011ff200, 0x011fff07, 0x01200003, 0x01239a00, 0x01240003, 0x01246f00, 0x01247007, 0x01247500,
+    0x01248003, 0x01254400, 0x012f9003, 0x012ff300, 0x01300003, 0x01325822, 0x01325b0c, 0x01325e03,
+    0x0132820c, 0x01328303, 0x01328622, 0x0132870c, 0x01328822, 0x0132890c, 0x01328a03, 0x01337922,
+    0x01337a0c, 0x01337c03, 0x01342f22, 0x01343013, 0x01343722, 0x0134380c, 0x01343913, 0x01343c22,
+    0x01343d0c, 0x01343e22, 0x01343f0c, 0x0134400d, 0x01344103, 0x0134470d, 0x01345600, 0x01346003,
+    0x0143fb00, 0x01440003, 0x0145ce22, 0x0145cf0c, 0x0145d003, 0x01464700, 0x01610005, 0x01611e0d,
+    0x01613005, 0x01613a00, 0x01680003, 0x016a3900, 0x016a4003, 0x016a5f00, 0x016a6021, 0x016a6a00,
+    0x016a6e07, 0x016a7003, 0x016abf00, 0x016ac021, 0x016aca00, 0x016ad003, 0x016aee00, 0x016af00d,
+    0x016af507, 0x016af600, 0x016b0003, 0x016b300d, 0x016b3707, 0x016b3a03, 0x016b4407, 0x016b4503,
+    0x016b4600, 0x016b5021, 0x016b5a00, 0x016b5b03, 0x016b6200, 0x016b6303, 0x016b7800, 0x016b7d03,
+    0x016b9000, 0x016d4003, 0x016d6e07, 0x016d7021, 0x016d7a00, 0x016e4003, 0x016e9707, 0x016e9903,
+    0x016e9b00, 0x016f0003, 0x016f4b00, 0x016f4f0d, 0x016f5003, 0x016f510d, 0x016f8800, 0x016f8f0d,
+    0x016f9303, 0x016fa000, 0x016fe060, 0x016fe453, 0x016fe500, 0x016ff04d, 0x016ff200, 0x01700058,
+    0x0187f800, 0x01880058, 0x018b0043, 0x018cd600, 0x018cff43, 0x018d0058, 0x018d0900, 0x01aff043,
+    0x01aff400, 0x01aff543, 0x01affc00, 0x01affd43, 0x01afff00, 0x01b00058, 0x01b12300, 0x01b1324b,
+    0x01b13300, 0x01b1504b, 0x01b15300, 0x01b1554b, 0x01b15600, 0x01b1644b, 0x01b16800, 0x01b17058,
+    0x01b2fc00, 0x01bc0003, 0x01bc6b00, 0x01bc7003, 0x01bc7d00, 0x01bc8003, 0x01bc8900, 0x01bc9003,
+    0x01bc9a00, 0x01bc9c03, 0x01bc9d0d, 0x01bc9f07, 0x01bca00d, 0x01bca400, 0x01cc0003, 0x01ccf021,
+    0x01ccfa00, 0x01cd0003, 0x01ceb400, 0x01cf000d, 0x01cf2e00, 0x01cf300d, 0x01cf4700, 0x01cf5003,
+    0x01cfc400, 0x01d00003, 0x01d0f600, 0x01d10003, 0x01d12700, 0x01d12903, 0x01d1650d, 0x01d16a03,
+    0x01d16d0d, 0x01d18303, 0x01d1850d, 0x01d18c03, 0x01d1aa0d, 0x01d1ae03, 0x01d1eb00, 0x01d20003,
+    0x01d2420d, 0x01d24503, 0x01d24600, 0x01d2c003, 0x01d2d400, 0x01d2e003, 0x01d2f400, 0x01d30043,
+    0x01d35700, 0x01d36043, 0x01d37703, 0x01d37900, 0x01d40003, 0x01d45500, 0x01d45603, 0x01d49d00,
+    0x01d49e03, 0x01d4a000, 0x01d4a203, 0x01d4a300, 0x01d4a503, 0x01d4a700, 0x01d4a903, 0x01d4ad00,
+    0x01d4ae03, 0x01d4ba00, 0x01d4bb03, 0x01d4bc00, 0x01d4bd03, 0x01d4c400, 0x01d4c503, 0x01d50600,
+    0x01d50703, 0x01d50b00, 0x01d50d03, 0x01d51500, 0x01d51603, 0x01d51d00, 0x01d51e03, 0x01d53a00,
+    0x01d53b03, 0x01d53f00, 0x01d54003, 0x01d54500, 0x01d54603, 0x01d54700, 0x01d54a03, 0x01d55100,
+    0x01d55203, 0x01d6a600, 0x01d6a803, 0x01d7cc00, 0x01d7ce21, 0x01d80003, 0x01da000d, 0x01da3703,
+    0x01da3b0d, 0x01da6d03, 0x01da750d, 0x01da7603, 0x01da840d, 0x01da8503, 0x01da8707, 0x01da8b03,
+    0x01da8c00, 0x01da9b0d, 0x01daa000, 0x01daa10d, 0x01dab000, 0x01df0003, 0x01df1f00, 0x01df2503,
+    0x01df2b00, 0x01e0000d, 0x01e00700, 0x01e0080d, 0x01e01900, 0x01e01b0d, 0x01e02200, 0x01e0230d,
+    0x01e02500, 0x01e0260d, 0x01e02b00, 0x01e03003, 0x01e06e00, 0x01e08f0d, 0x01e09000, 0x01e10003,
+    0x01e12d00, 0x01e1300d, 0x01e13703, 0x01e13e00, 0x01e14021, 0x01e14a00, 0x01e14e03, 0x01e15000,
+    0x01e29003, 0x01e2ae0d, 0x01e2af00, 0x01e2c003, 0x01e2ec0d, 0x01e2f021, 0x01e2fa00, 0x01e2ff24,
+    0x01e30000, 0x01e4d003, 0x01e4ec0d, 0x01e4f021, 0x01e4fa00, 0x01e5d003, 0x01e5ee0d, 0x01e5f003,
+    0x01e5f121, 0x01e5fb00, 0x01e5ff03, 0x01e60000, 0x01e7e003, 0x01e7e700, 0x01e7e803, 0x01e7ec00,
+    0x01e7ed03, 0x01e7ef00, 0x01e7f003, 0x01e7ff00, 0x01e80003, 0x01e8c500, 0x01e8c703, 0x01e8d00d,
+    0x01e8d700, 0x01e90003, 0x01e9440d, 0x01e94b03, 0x01e94c00, 0x01e95021, 0x01e95a00, 0x01e95e22,
+    0x01e96000, 0x01ec7103, 0x01ecac23, 0x01ecad03, 0x01ecb023, 0x01ecb103, 0x01ecb500, 0x01ed0103,
+    0x01ed3e00, 0x01ee0003, 0x01ee0400, 0x01ee0503, 0x01ee2000, 0x01ee2103, 0x01ee2300, 0x01ee2403,
+    0x01ee2500, 0x01ee2703, 0x01ee2800, 0x01ee2903, 0x01ee3300, 0x01ee3403, 0x01ee3800, 0x01ee3903,
+    0x01ee3a00, 0x01ee3b03, 0x01ee3c00, 0x01ee4203, 0x01ee4300, 0x01ee4703, 0x01ee4800, 0x01ee4903,
+    0x01ee4a00, 0x01ee4b03, 0x01ee4c00, 0x01ee4d03, 0x01ee5000, 0x01ee5103, 0x01ee5300, 0x01ee5403,
+    0x01ee5500, 0x01ee5703, 0x01ee5800, 0x01ee5903, 0x01ee5a00, 0x01ee5b03, 0x01ee5c00, 0x01ee5d03,
+    0x01ee5e00, 0x01ee5f03, 0x01ee6000, 0x01ee6103, 0x01ee6300, 0x01ee6403, 0x01ee6500, 0x01ee6703,
+    0x01ee6b00, 0x01ee6c03, 0x01ee7300, 0x01ee7403, 0x01ee7800, 0x01ee7903, 0x01ee7d00, 0x01ee7e03,
+    0x01ee7f00, 0x01ee8003, 0x01ee8a00, 0x01ee8b03, 0x01ee9c00, 0x01eea103, 0x01eea400, 0x01eea503,
+    0x01eeaa00, 0x01eeab03, 0x01eebc00, 0x01eef003, 0x01eef200, 0x01f00018, 0x01f00458, 0x01f00518,
+    0x01f02c98, 0x01f03018, 0x01f09498, 0x01f0a018, 0x01f0af98, 0x01f0b118, 0x01f0c098, 0x01f0c118,
+    0x01f0cf58, 0x01f0d098, 0x01f0d118, 0x01f0f698, 0x01f10001, 0x01f10d03, 0x01f11001, 0x01f12e03,
+    0x01f13001, 0x01f16a03, 0x01f17001, 0x01f18e41, 0x01f18f01, 0x01f19141, 0x01f19b01, 0x01f1ad03,
+    0x01f1ae98, 0x01f1e626, 0x01f20058, 0x01f20398, 0x01f21058, 0x01f23c98, 0x01f24058, 0x01f24998,
+    0x01f25058, 0x01f25298, 0x01f26058, 0x01f26698, 0x01f30058, 0x01f32118, 0x01f32d58, 0x01f33618,
+    0x01f33758, 0x01f37d18, 0x01f37e58, 0x01f38550, 0x01f38658, 0x01f39418, 0x01f39c03, 0x01f39e18,
+    0x01f3a058, 0x01f3b543, 0x01f3b758, 0x01f3bc43, 0x01f3bd58, 0x01f3c250, 0x01f3c558, 0x01f3c750,
+    0x01f3c858, 0x01f3ca50, 0x01f3cb10, 0x01f3cd18, 0x01f3cf58, 0x01f3d418, 0x01f3e058, 0x01f3f118,
+    0x01f3f458, 0x01f3f518, 0x01f3f858, 0x01f3fb51, 0x01f40058, 0x01f43f18, 0x01f44058, 0x01f44118,
+    0x01f44250, 0x01f44458, 0x01f44650, 0x01f45158, 0x01f46650, 0x01f47958, 0x01f47c50, 0x01f47d58,
+    0x01f48150, 0x01f48458, 0x01f48550, 0x01f48858, 0x01f48f50, 0x01f49058, 0x01f49150, 0x01f49258,
+    0x01f4a043, 0x01f4a158, 0x01f4a243, 0x01f4a358, 0x01f4a443, 0x01f4a558, 0x01f4aa50, 0x01f4ab58,
+    0x01f4af43, 0x01f4b058, 0x01f4b143, 0x01f4b358, 0x01f4fd18, 0x01f4ff58, 0x01f50043, 0x01f50758,
+    0x01f51743, 0x01f52558, 0x01f53243, 0x01f53e03, 0x01f54a18, 0x01f54b58, 0x01f54f18, 0x01f55058,
+    0x01f56818, 0x01f57410, 0x01f57618, 0x01f57a50, 0x01f57b18, 0x01f59010, 0x01f59118, 0x01f59550,
+    0x01f59718, 0x01f5a458, 0x01f5a518, 0x01f5d403, 0x01f5dc18, 0x01f5f403, 0x01f5fa18, 0x01f5fb58,
+    0x01f64550, 0x01f64858, 0x01f64b50, 0x01f65003, 0x01f67625, 0x01f67920, 0x01f67c03, 0x01f68058,
+    0x01f6a350, 0x01f6a458, 0x01f6b450, 0x01f6b758, 0x01f6c050, 0x01f6c158, 0x01f6c618, 0x01f6cc50,
+    0x01f6cd18, 0x01f6d058, 0x01f6d318, 0x01f6d558, 0x01f6d898, 0x01f6dc58, 0x01f6e018, 0x01f6eb58,
+    0x01f6ed98, 0x01f6f018, 0x01f6f458, 0x01f6fd98, 0x01f70003, 0x01f77418, 0x01f77798, 0x01f77b18,
+    0x01f78003, 0x01f7d518, 0x01f7da98, 0x01f7e058, 0x01f7ec98, 0x01f7f058, 0x01f7f198, 0x01f80003,
+    0x01f80c98, 0x01f81003, 0x01f84898, 0x01f85003, 0x01f85a98, 0x01f86003, 0x01f88898, 0x01f89003,
+    0x01f8ae98, 0x01f8b003, 0x01f8bc98, 0x01f8c003, 0x01f8c298, 0x01f90003, 0x01f90c50, 0x01f90d58,
+    0x01f90f50, 0x01f91058, 0x01f91850, 0x01f92058, 0x01f92650, 0x01f92758, 0x01f93050, 0x01f93a58,
+    0x01f93b18, 0x01f93c50, 0x01f93f58, 0x01f94618, 0x01f94758, 0x01f97750, 0x01f97858, 0x01f9b550,
+    0x01f9b758, 0x01f9b850, 0x01f9ba58, 0x01f9bb50, 0x01f9bc58, 0x01f9cd50, 0x01f9d058, 0x01f9d150,
+    0x01f9de58, 0x01fa0003, 0x01fa5498, 0x01fa6018, 0x01fa6e98, 0x01fa7058, 0x01fa7d98, 0x01fa8058,
+    0x01fa8a98, 0x01fa8f58, 0x01fac350, 0x01fac658, 0x01fac798, 0x01face58, 0x01fadd98, 0x01fadf58,
+    0x01faea98, 0x01faf050, 0x01faf998, 0x01fb0003, 0x01fb9300, 0x01fb9403, 0x01fbf021, 0x01fbfa00,
+    0x01fc0098, 0x01fffe00, 0x02000058, 0x02fffe00, 0x03000058, 0x03fffe00, 0x0e00010d, 0x0e000200,
+    0x0e00200d, 0x0e008000, 0x0e01000d, 0x0e01f000,
+];
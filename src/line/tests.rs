@@ -0,0 +1,188 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+/// Splits `s` at each of its line break opportunities.
+fn lines(s: &str) -> Vec<&str> {
+    let mut start = 0;
+    let mut ret = Vec::new();
+    for (offset, _) in line_breaks_in_str(s) {
+        ret.push(&s[start..offset]);
+        start = offset;
+    }
+    assert_eq!(start, s.len());
+    ret
+}
+
+#[test]
+fn properties() {
+    assert_eq!(LBProperty::of_char('a'), LBProperty::AL);
+    assert_eq!(LBProperty::of_char(' '), LBProperty::SP);
+    assert_eq!(LBProperty::of_char('\n'), LBProperty::LF);
+    assert_eq!(LBProperty::of_char('5'), LBProperty::NU);
+    assert_eq!(LBProperty::of_char('$'), LBProperty::PR);
+    assert_eq!(LBProperty::of_char('('), LBProperty::OP);
+    assert_eq!(LBProperty::of_char('日'), LBProperty::ID);
+    assert_eq!(LBProperty::of_char('ก'), LBProperty::SA);
+    assert_eq!(LBProperty::of_char('\u{200D}'), LBProperty::ZWJ);
+    assert_eq!(LBProperty::of_char('\u{10FFFF}'), LBProperty::XX);
+
+    assert!(LineBreakProperties::for_char('日').is_east_asian());
+    assert!(!LineBreakProperties::for_char('a').is_east_asian());
+    assert!(LineBreakProperties::for_char('«').is_initial_quote());
+    assert!(LineBreakProperties::for_char('»').is_final_quote());
+    assert!(LineBreakProperties::for_char('\u{E31}').is_complex_mark());
+    assert!(LineBreakProperties::for_char('\u{2010}').is_hyphen());
+    assert!(LineBreakProperties::for_char('\u{25CC}').is_dotted_circle());
+    assert!(LineBreakProperties::for_char('\u{1FAFF}').is_unassigned_pictographic());
+
+    let tailored = LineBreakProperties::for_char('日').with_lb_property(LBProperty::NS);
+    assert_eq!(tailored.lb_property(), LBProperty::NS);
+    assert!(tailored.is_east_asian());
+}
+
+#[test]
+fn basic() {
+    assert_eq!(lines("Hello world"), ["Hello ", "world"]);
+    assert_eq!(lines("foo-bar"), ["foo-", "bar"]);
+    assert_eq!(lines("a -b -3"), ["a ", "-b ", "-3"]);
+    assert_eq!(lines("日本語。"), ["日", "本", "語。"]);
+    assert_eq!(lines("a\u{200B}b"), ["a\u{200B}", "b"]);
+    assert_eq!(lines("a\u{2060}b"), ["a\u{2060}b"]);
+    assert_eq!(lines("a \u{301}b"), ["a ", "\u{301}b"]);
+    assert_eq!(lines("ภาษาไทย"), ["ภาษาไทย"]);
+    assert_eq!(lines(""), [] as [&str; 0]);
+}
+
+#[test]
+fn mandatory() {
+    let breaks: Vec<_> = line_breaks_in_str("a\r\nb c\n").collect();
+    assert_eq!(
+        breaks,
+        [
+            (3, BreakOpportunity::Mandatory),
+            (5, BreakOpportunity::Allowed),
+            (7, BreakOpportunity::Mandatory),
+        ]
+    );
+    assert_eq!(lines("a\rb\u{85}c"), ["a\r", "b\u{85}", "c"]);
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(
+        lines("$(12.35) 12,345.67% x"),
+        ["$(12.35) ", "12,345.67% ", "x"]
+    );
+    assert_eq!(lines("$(a"), ["$", "(a"]);
+    assert_eq!(lines("$(.5) $(.a"), ["$(.5) ", "$", "(.a"]);
+    assert_eq!(lines("a .5 a .b"), ["a ", ".5 ", "a .b"]);
+}
+
+#[test]
+fn quotes() {
+    assert_eq!(lines("x « y » z"), ["x ", "« y » ", "z"]);
+    assert_eq!(lines("a »b"), ["a ", "»b"]);
+    assert_eq!(lines("漢“字”"), ["漢", "“字”"]);
+    assert_eq!(lines("漢“a”"), ["漢“a”"]);
+    assert_eq!(lines("a“b”"), ["a“b”"]);
+}
+
+#[test]
+fn other_rules() {
+    assert_eq!(lines("👍🏽😀😀"), ["👍🏽", "😀", "😀"]);
+    assert_eq!(lines("🇦🇨🇦🇨"), ["🇦🇨", "🇦🇨"]);
+    assert_eq!(lines("א-ב א-a"), ["א-", "ב ", "א-a"]);
+    // Batak letters, which are aksaras, and a final virama.
+    assert_eq!(lines("\u{1BC0}\u{1BC0}"), ["\u{1BC0}", "\u{1BC0}"]);
+    assert_eq!(
+        lines("\u{1BC0}\u{1BC0}\u{1BF2}"),
+        ["\u{1BC0}\u{1BC0}\u{1BF2}"]
+    );
+}
+
+#[test]
+fn actions() {
+    let action = |resolved, opportunity| LineAction {
+        resolved,
+        opportunity,
+    };
+    let mut machine = LineBreakMachine::new();
+    assert_eq!(
+        machine.next_char('$'),
+        action(None, BreakOpportunity::Prohibited)
+    );
+    assert_eq!(
+        machine.next_char('('),
+        action(None, BreakOpportunity::Undecided)
+    );
+    assert_eq!(
+        machine.next_char('\u{301}'),
+        action(None, BreakOpportunity::Prohibited)
+    );
+    assert_eq!(
+        machine.next_char('1'),
+        action(Some(false), BreakOpportunity::Prohibited)
+    );
+    assert_eq!(
+        machine.end_of_input(),
+        action(None, BreakOpportunity::Mandatory)
+    );
+
+    // A position that settles an undecided one can itself be undecided.
+    assert_eq!(
+        machine.next_char('\u{1BC0}'),
+        action(None, BreakOpportunity::Prohibited)
+    );
+    assert_eq!(
+        machine.next_char('\u{1BC0}'),
+        action(None, BreakOpportunity::Undecided)
+    );
+    assert_eq!(
+        machine.next_char('\u{1BC0}'),
+        action(Some(true), BreakOpportunity::Undecided)
+    );
+    assert_eq!(
+        machine.next_char('\u{1BF2}'),
+        action(Some(false), BreakOpportunity::Prohibited)
+    );
+
+    assert_eq!(
+        machine.next_char(' '),
+        action(None, BreakOpportunity::Prohibited)
+    );
+    assert_eq!(
+        machine.next_char('»'),
+        action(None, BreakOpportunity::Undecided)
+    );
+    assert_eq!(
+        machine.end_of_input(),
+        action(Some(false), BreakOpportunity::Mandatory)
+    );
+}
+
+#[test]
+fn unicode_test_table() {
+    // `test_table.rs` is generated from `LineBreakTest.txt` by
+    // `cargo xtask generate-line-break-tests`.
+    let mut failures = 0;
+    for test in test_table::UNICODE_LINE_BREAK_TESTS {
+        let input = str::from_utf8(test.input).expect("invalid UTF-8 in test input");
+        let got = lines(input);
+        let want: Vec<&str> = test
+            .expected
+            .iter()
+            .map(|s| str::from_utf8(s).expect("invalid UTF-8 in expected result"))
+            .collect();
+        if got != want {
+            println!("- test failed: {}", test.desc);
+            println!("  input: {:x?}", test.input);
+            println!("  got:   {:?}", got);
+            println!("  want:  {:?}", want);
+            failures += 1;
+        }
+    }
+    if failures != 0 {
+        panic!("{failures} tests failed");
+    }
+}
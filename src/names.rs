@@ -0,0 +1,196 @@
+//! The Unicode **Name** property, for describing the characters in a
+//! grapheme cluster.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+mod table;
+
+/// The name of a character, such as `LATIN SMALL LETTER A`, or its code
+/// point label, such as `<control-000A>`, if it has no name.
+///
+/// Most names are stored compressed, and so this is a lightweight handle
+/// that writes the name when formatted with [`fmt::Display`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharName {
+    c: char,
+    kind: NameKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum NameKind {
+    /// A name stored as words in the name tables, starting at the given
+    /// offset.
+    Words(u32),
+    /// A name that is the given prefix followed by the code point.
+    Prefixed(&'static str),
+    /// The name of a Hangul syllable, derived from its decomposition.
+    Hangul,
+    /// A code point label of the given kind, for a character with no name.
+    Label(&'static str),
+}
+
+impl CharName {
+    /// The size in bytes of the lookup tables that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`](crate::CharProperties),
+    /// most of these tables honor the `GRAPHEME_MACHINE_LINK_SECTION`
+    /// environment variable.
+    pub const TABLE_SIZE: usize = table::NAME_TABLES_SIZE;
+
+    /// Returns the **Name** of the given character, or `None` if it has
+    /// none.
+    ///
+    /// Control characters, private-use characters, noncharacters and
+    /// unassigned characters have no name.
+    pub fn of_char(c: char) -> Option<Self> {
+        let kind = if let Some(offset) = table::name_offset(c) {
+            NameKind::Words(offset)
+        } else if let Some(prefix) = table::name_prefix(c) {
+            NameKind::Prefixed(prefix)
+        } else if (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&(c as u32)) {
+            NameKind::Hangul
+        } else {
+            return None;
+        };
+        Some(Self { c, kind })
+    }
+
+    /// Returns the **Name** of the given character, or its code point label
+    /// if it has none.
+    ///
+    /// Code point labels are those recommended by the Unicode Standard,
+    /// like `<control-0009>`, `<private-use-E000>`, `<noncharacter-FFFF>`
+    /// and `<reserved-0378>`. The angle brackets distinguish them from
+    /// names.
+    pub fn or_label(c: char) -> Self {
+        if let Some(name) = Self::of_char(c) {
+            return name;
+        }
+        let kind = match c as u32 {
+            0x00..=0x1f | 0x7f..=0x9f => "control",
+            0xe000..=0xf8ff | 0xf0000..=0xffffd | 0x100000..=0x10fffd => "private-use",
+            0xfdd0..=0xfdef => "noncharacter",
+            cp if cp & 0xfffe == 0xfffe => "noncharacter",
+            _ => "reserved",
+        };
+        Self {
+            c,
+            kind: NameKind::Label(kind),
+        }
+    }
+
+    /// Returns the character that this is the name of.
+    pub fn char(&self) -> char {
+        self.c
+    }
+
+    /// Returns `true` if this is a code point label rather than a name.
+    pub fn is_label(&self) -> bool {
+        matches!(self.kind, NameKind::Label(_))
+    }
+}
+
+impl fmt::Display for CharName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cp = self.c as u32;
+        match self.kind {
+            NameKind::Words(offset) => {
+                for (i, word) in table::name_words(offset).enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    f.write_str(word)?;
+                }
+                Ok(())
+            }
+            NameKind::Prefixed(prefix) => write!(f, "{prefix}{cp:04X}"),
+            NameKind::Hangul => {
+                let i = (cp - HANGUL_BASE) as usize;
+                let (l, v, t) = (i / (21 * 28), i % (21 * 28) / 28, i % 28);
+                write!(f, "HANGUL SYLLABLE {}{}{}", JAMO_L[l], JAMO_V[v], JAMO_T[t])
+            }
+            NameKind::Label(kind) => write!(f, "<{kind}-{cp:04X}>"),
+        }
+    }
+}
+
+impl fmt::Debug for CharName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Names and labels never contain characters that would need
+        // escaping.
+        write!(f, "CharName(\"{self}\")")
+    }
+}
+
+/// Returns an iterator over the names of the characters in the given
+/// grapheme cluster, using code point labels for any that have no name.
+///
+/// The iterator can also be formatted with [`fmt::Display`] to write all
+/// of the names separated by commas, like
+/// `WOMAN, ZERO WIDTH JOINER, ROCKET`, such as for accessibility
+/// descriptions or debugging output.
+pub fn cluster_names(cluster: &str) -> ClusterNames<'_> {
+    ClusterNames {
+        chars: cluster.chars(),
+    }
+}
+
+/// The iterator returned by [`cluster_names`].
+#[derive(Debug, Clone)]
+pub struct ClusterNames<'a> {
+    chars: core::str::Chars<'a>,
+}
+
+impl Iterator for ClusterNames<'_> {
+    type Item = CharName;
+
+    fn next(&mut self) -> Option<CharName> {
+        self.chars.next().map(CharName::or_label)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ClusterNames<'_> {
+    fn next_back(&mut self) -> Option<CharName> {
+        self.chars.next_back().map(CharName::or_label)
+    }
+}
+
+impl FusedIterator for ClusterNames<'_> {}
+
+impl fmt::Display for ClusterNames<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.clone().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}")?;
+        }
+        Ok(())
+    }
+}
+
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_COUNT: u32 = 19 * 21 * 28;
+
+// The short names of the leading consonant, vowel and trailing consonant
+// jamo that a Hangul syllable decomposes into, from `Jamo.txt`.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,93 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn name(c: char) -> Option<String> {
+    CharName::of_char(c).map(|name| name.to_string())
+}
+
+#[test]
+fn names() {
+    assert_eq!(name('a').as_deref(), Some("LATIN SMALL LETTER A"));
+    assert_eq!(name(' ').as_deref(), Some("SPACE"));
+    assert_eq!(name('\u{200D}').as_deref(), Some("ZERO WIDTH JOINER"));
+    assert_eq!(name('\u{1F469}').as_deref(), Some("WOMAN"));
+    assert_eq!(
+        name('\u{1F9D1}').as_deref(),
+        Some("ADULT") // the start of a long run of emoji
+    );
+    assert_eq!(
+        name('\u{FDFA}').as_deref(), // the longest name
+        Some("ARABIC LIGATURE SALLALLAHOU ALAYHE WASALLAM")
+    );
+    assert_eq!(
+        name('\u{1FAE9}').as_deref(),
+        Some("FACE WITH BAGS UNDER EYES")
+    );
+    assert_eq!(
+        name('\u{13460}').as_deref(), // new in Unicode 16.0
+        Some("EGYPTIAN HIEROGLYPH-13460")
+    );
+}
+
+#[test]
+fn derived_names() {
+    assert_eq!(
+        name('\u{4E00}').as_deref(),
+        Some("CJK UNIFIED IDEOGRAPH-4E00")
+    );
+    assert_eq!(
+        name('\u{2EBF0}').as_deref(),
+        Some("CJK UNIFIED IDEOGRAPH-2EBF0")
+    );
+    assert_eq!(
+        name('\u{F900}').as_deref(),
+        Some("CJK COMPATIBILITY IDEOGRAPH-F900")
+    );
+    assert_eq!(name('\u{17000}').as_deref(), Some("TANGUT IDEOGRAPH-17000"));
+    assert_eq!(name('\u{AC00}').as_deref(), Some("HANGUL SYLLABLE GA"));
+    assert_eq!(name('\u{D4DB}').as_deref(), Some("HANGUL SYLLABLE PWILH"));
+    assert_eq!(name('\u{C544}').as_deref(), Some("HANGUL SYLLABLE A"));
+    assert_eq!(name('\u{D7A3}').as_deref(), Some("HANGUL SYLLABLE HIH"));
+}
+
+#[test]
+fn labels() {
+    let label = |c| {
+        let name = CharName::or_label(c);
+        assert!(name.is_label(), "{c:?} has a name");
+        name.to_string()
+    };
+    assert_eq!(label('\n'), "<control-000A>");
+    assert_eq!(label('\u{9F}'), "<control-009F>");
+    assert_eq!(label('\u{E000}'), "<private-use-E000>");
+    assert_eq!(label('\u{10FFFD}'), "<private-use-10FFFD>");
+    assert_eq!(label('\u{FDD0}'), "<noncharacter-FDD0>");
+    assert_eq!(label('\u{1FFFE}'), "<noncharacter-1FFFE>");
+    assert_eq!(label('\u{0378}'), "<reserved-0378>");
+    assert_eq!(name('\n'), None);
+
+    let a = CharName::or_label('a');
+    assert!(!a.is_label());
+    assert_eq!(a.char(), 'a');
+    assert_eq!(format!("{a:?}"), "CharName(\"LATIN SMALL LETTER A\")");
+}
+
+#[test]
+fn clusters() {
+    assert_eq!(
+        cluster_names("\u{1F469}\u{200D}\u{1F680}").to_string(),
+        "WOMAN, ZERO WIDTH JOINER, ROCKET"
+    );
+    assert_eq!(
+        cluster_names("\r\n").to_string(),
+        "<control-000D>, <control-000A>"
+    );
+    assert_eq!(cluster_names("").to_string(), "");
+
+    let got: Vec<_> = cluster_names("e\u{0301}")
+        .rev()
+        .map(|name| name.to_string())
+        .collect();
+    assert_eq!(got, &["COMBINING ACUTE ACCENT", "LATIN SMALL LETTER E"]);
+}
@@ -0,0 +1,236 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use u8char::u8char;
+
+use crate::{
+    CharProperties, ClusterAction, GraphemeMachine, InvalidUtf8Error, SegmentAction, Segmenter,
+    SplitStr,
+};
+
+/// A wrapper around [`GraphemeMachine`] that also counts how many bytes of
+/// UTF-8 text have passed through it, so that it can report the position of
+/// each grapheme cluster boundary as an absolute offset into the stream.
+///
+/// Offsets are tracked as [`u64`] so that they can represent positions in
+/// streams longer than would fit in memory on the current platform. A stream
+/// can't realistically reach the end of that range, but a caller-provided
+/// starting offset or skipped region could, and so offsets saturate at
+/// [`u64::MAX`] rather than overflowing.
+///
+/// The offset always counts the length of each character as encoded in UTF-8,
+/// even when characters are submitted as [`char`] values. Callers whose
+/// streams use some other encoding must translate offsets themselves.
+#[derive(Debug, Default)]
+pub struct OffsetGraphemeMachine {
+    machine: GraphemeMachine,
+    offset: u64,
+}
+
+/// What to do with a new character after presenting it to an
+/// [`OffsetGraphemeMachine`], along with the offset where that character
+/// begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetAction {
+    /// The action chosen by the underlying [`GraphemeMachine`].
+    pub action: ClusterAction,
+    /// The offset of the first byte of the new character in the stream, which
+    /// is therefore the offset of the boundary if `action` is
+    /// [`ClusterAction::Split`].
+    pub offset: u64,
+}
+
+impl OffsetAction {
+    /// Returns the offset of the boundary before the new character if there
+    /// is one, or `None` if the new character continues the current grapheme
+    /// cluster.
+    pub const fn boundary(self) -> Option<u64> {
+        match self.action {
+            ClusterAction::Split => Some(self.offset),
+            ClusterAction::Continue => None,
+        }
+    }
+}
+
+impl OffsetGraphemeMachine {
+    /// Constructs a new [`OffsetGraphemeMachine`] in an initial "start of input"
+    /// state, at offset zero.
+    pub fn new() -> Self {
+        Self::with_offset(0)
+    }
+
+    /// Constructs a new [`OffsetGraphemeMachine`] in an initial "start of input"
+    /// state, whose first character will be treated as beginning at the given
+    /// offset.
+    pub fn with_offset(offset: u64) -> Self {
+        Self {
+            machine: GraphemeMachine::new(),
+            offset,
+        }
+    }
+
+    /// Returns the offset where the next character submitted to the machine
+    /// will be treated as beginning.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Advances the state machine for a character with the given properties
+    /// and UTF-8 encoded length, returning the action to take at the boundary
+    /// between this and the previous character (if any) along with the
+    /// offset where this character begins.
+    ///
+    /// Since [`CharProperties`] doesn't carry any information about the
+    /// character it was derived from, the caller must provide the character's
+    /// encoded length separately.
+    pub fn next_char_properties(&mut self, next: CharProperties, len: usize) -> OffsetAction {
+        let action = self.machine.next_char_properties(next);
+        let offset = self.offset;
+        self.offset = self.offset.saturating_add(len as u64);
+        OffsetAction { action, offset }
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// advances the state machine by passing it to
+    /// [`Self::next_char_properties`].
+    pub fn next_u8char(&mut self, c: u8char) -> OffsetAction {
+        self.next_char_properties(CharProperties::for_u8char(c), c.len())
+    }
+
+    /// Looks up the [`CharProperties`] for the given character and then
+    /// advances the state machine by passing it to
+    /// [`Self::next_char_properties`].
+    pub fn next_char(&mut self, c: char) -> OffsetAction {
+        self.next_char_properties(CharProperties::for_char(c), c.len_utf8())
+    }
+
+    /// Returns an iterator which feeds each character in `s` into the state
+    /// machine in turn and yields the offset of each grapheme cluster boundary
+    /// it finds, including the one at the start of the string if any.
+    ///
+    /// As with [`GraphemeMachine::next_u8chars_from_str`], the state machine
+    /// only processes the characters up to the most recently-returned boundary
+    /// unless you keep reading the iterator until it returns `None`, and there
+    /// is no automatic call to [`Self::end_of_input`] at the end.
+    pub fn boundaries_in_str<'a>(&'a mut self, s: &'a str) -> impl FusedIterator<Item = u64> + 'a {
+        struct Iter<'a> {
+            machine: &'a mut OffsetGraphemeMachine,
+            remain: &'a str,
+        }
+        impl<'a> Iterator for Iter<'a> {
+            type Item = u64;
+            fn next(&mut self) -> Option<Self::Item> {
+                while let (Some(next), rest) = u8char::from_string_prefix(self.remain) {
+                    self.remain = rest;
+                    if let Some(offset) = self.machine.next_u8char(next).boundary() {
+                        return Some(offset);
+                    }
+                }
+                None
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                // The first character might or might not begin a new cluster,
+                // depending on what came before it.
+                let (lower, upper) = crate::scan::inner_boundary_bounds(self.remain);
+                let first = usize::from(!self.remain.is_empty());
+                (lower, Some(upper + first))
+            }
+        }
+        impl<'a> FusedIterator for Iter<'a> {}
+        Iter {
+            machine: self,
+            remain: s,
+        }
+    }
+
+    /// Behaves the same as [`Self::boundaries_in_str`] except that it takes
+    /// a byte slice that must be valid UTF-8, which it checks before
+    /// processing any characters.
+    ///
+    /// If any part of `bytes` is invalid then this returns an error without
+    /// advancing the state machine or its offset at all, even for the valid
+    /// prefix, so that a caller can reject a whole malformed frame of a
+    /// protocol. The error's offsets are relative to the start of `bytes`,
+    /// and so are relative to [`Self::offset`] in the stream;
+    /// [`BoundaryEvent::from_utf8_error`](crate::BoundaryEvent::from_utf8_error)
+    /// can describe it with an absolute offset.
+    pub fn boundaries_in_utf8<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> Result<impl FusedIterator<Item = u64> + 'a, InvalidUtf8Error> {
+        let s = core::str::from_utf8(bytes)?;
+        Ok(self.boundaries_in_str(s))
+    }
+
+    /// Behaves the same as [`Self::boundaries_in_str`] except that it takes
+    /// text stored across two slices, such as the contents of a ring buffer.
+    ///
+    /// The offsets count across both slices as if they were contiguous, so
+    /// a caller that consumes each batch from its buffer can convert them
+    /// back to buffer positions by subtracting the offset from before the
+    /// batch.
+    pub fn boundaries_in_split_str<'a>(
+        &'a mut self,
+        s: SplitStr<'a>,
+    ) -> impl FusedIterator<Item = u64> + 'a {
+        s.u8chars()
+            .filter_map(|c| OffsetGraphemeMachine::next_u8char(self, c).boundary())
+    }
+
+    /// Accounts for a region of `len` bytes in the stream that isn't literal
+    /// text to be segmented, such as a markup tag or a lexical token that a
+    /// syntax highlighter handles separately, returning the range of offsets
+    /// that the region occupies.
+    ///
+    /// This forces a grapheme cluster boundary at the start of the region in
+    /// the same way as [`Self::end_of_input`] and then advances the offset past
+    /// the region, so that the next character submitted is treated as beginning
+    /// a new grapheme cluster at the offset immediately after the region. The
+    /// start of the returned range is therefore the offset of the end of the
+    /// last cluster before the region, if any.
+    pub fn skip_non_text(&mut self, len: u64) -> Range<u64> {
+        let start = self.end_of_input().offset;
+        self.offset = self.offset.saturating_add(len);
+        start..self.offset
+    }
+
+    /// Tells the state machine that the input stream has ended, returning
+    /// a [`ClusterAction::Split`] at the current offset to mark the end of
+    /// the final grapheme cluster.
+    ///
+    /// As with [`GraphemeMachine::end_of_input`], the machine can continue to
+    /// be used afterwards. The offset is not reset, so the next character is
+    /// treated as beginning immediately after the previous one.
+    pub fn end_of_input(&mut self) -> OffsetAction {
+        OffsetAction {
+            action: self.machine.end_of_input(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl Segmenter for OffsetGraphemeMachine {
+    type Action = OffsetAction;
+
+    fn next_u8char(&mut self, c: u8char) -> OffsetAction {
+        OffsetGraphemeMachine::next_u8char(self, c)
+    }
+
+    fn next_char(&mut self, c: char) -> OffsetAction {
+        OffsetGraphemeMachine::next_char(self, c)
+    }
+
+    fn end_of_input(&mut self) -> OffsetAction {
+        OffsetGraphemeMachine::end_of_input(self)
+    }
+}
+
+impl SegmentAction for OffsetAction {
+    fn is_boundary(self) -> bool {
+        self.action.is_boundary()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,131 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn boundaries_in_str() {
+    let mut machine = OffsetGraphemeMachine::new();
+    let got: Vec<_> = machine.boundaries_in_str("a\r\né🧑‍🌾").collect();
+    assert_eq!(got, &[0, 1, 3, 5]);
+    assert_eq!(machine.offset(), 16);
+
+    // The offsets continue across chunks, including for clusters that
+    // straddle the chunk boundary.
+    let got: Vec<_> = machine.boundaries_in_str("\u{0301}x").collect();
+    assert_eq!(got, &[18]);
+    assert_eq!(machine.end_of_input().boundary(), Some(19));
+    let got: Vec<_> = machine.boundaries_in_str("\u{0301}").collect();
+    assert_eq!(got, &[19]);
+}
+
+#[test]
+fn boundaries_in_utf8() {
+    let mut machine = OffsetGraphemeMachine::with_offset(10);
+    let got: Vec<_> = machine.boundaries_in_utf8(b"a\r").unwrap().collect();
+    assert_eq!(got, &[10, 11]);
+
+    // An invalid frame is rejected without advancing the machine at all.
+    let err = match machine.boundaries_in_utf8(b"\nb\xc3(c") {
+        Ok(_) => panic!("invalid UTF-8 was accepted"),
+        Err(err) => err,
+    };
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+    assert_eq!(machine.offset(), 12);
+    let err = machine.boundaries_in_utf8(b"\xe2\x82").err();
+    assert_eq!(err.map(|err| err.error_len()), Some(None));
+
+    // The CR from the first frame still joins the LF at the start of this one.
+    let got: Vec<_> = machine.boundaries_in_utf8(b"\nb").unwrap().collect();
+    assert_eq!(got, &[13]);
+}
+
+#[test]
+fn next_char() {
+    use ClusterAction::*;
+    let mut machine = OffsetGraphemeMachine::with_offset(100);
+    let got: Vec<_> = "e\u{0301}🇦🇧"
+        .chars()
+        .map(|c| machine.next_char(c))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            OffsetAction {
+                action: Split,
+                offset: 100
+            },
+            OffsetAction {
+                action: Continue,
+                offset: 101
+            },
+            OffsetAction {
+                action: Split,
+                offset: 103
+            },
+            OffsetAction {
+                action: Continue,
+                offset: 107
+            },
+        ]
+    );
+    assert_eq!(machine.offset(), 111);
+}
+
+#[test]
+fn skip_non_text() {
+    // Simulates a syntax highlighter that handles "<b>" as a tag and
+    // segments only the literal text around it.
+    let mut machine = OffsetGraphemeMachine::new();
+    let got: Vec<_> = machine.boundaries_in_str("ab").collect();
+    assert_eq!(got, &[0, 1]);
+    assert_eq!(machine.skip_non_text(3), 2..5);
+
+    // A combining character after the tag can't combine with the "b"
+    // before it, so it begins a new cluster at the end of the tag.
+    let got: Vec<_> = machine.boundaries_in_str("\u{0301}c").collect();
+    assert_eq!(got, &[5, 7]);
+    assert_eq!(machine.offset(), 8);
+}
+
+#[test]
+fn offset_saturates() {
+    let mut machine = OffsetGraphemeMachine::with_offset(u64::MAX - 1);
+    assert_eq!(machine.next_char('€').offset, u64::MAX - 1);
+    assert_eq!(machine.offset(), u64::MAX);
+    assert_eq!(machine.skip_non_text(10), u64::MAX..u64::MAX);
+}
+
+#[test]
+fn boundaries_in_str_size_hint() {
+    let inputs = [
+        "",
+        "a",
+        "plain ASCII",
+        "ab\u{0301}cd",
+        "🇦🇧🇨",
+        "😀\u{200D}😀 x",
+    ];
+    for input in inputs {
+        for mut machine in [OffsetGraphemeMachine::new(), {
+            let mut continuing = OffsetGraphemeMachine::new();
+            continuing.next_char('a');
+            continuing
+        }] {
+            let iter = machine.boundaries_in_str(input);
+            let (lower, upper) = iter.size_hint();
+            let count = iter.count();
+            assert!(
+                lower <= count,
+                "lower bound {lower} > {count} for {input:?}"
+            );
+            assert!(
+                upper.unwrap() >= count,
+                "upper bound {upper:?} < {count} for {input:?}"
+            );
+        }
+    }
+
+    let mut machine = OffsetGraphemeMachine::new();
+    let iter = machine.boundaries_in_str("plain ASCII");
+    assert_eq!(iter.size_hint(), (10, Some(11)));
+}
@@ -0,0 +1,109 @@
+use core::ops::RangeInclusive;
+
+use u8char::u8char;
+
+use crate::{CharProperties, PropertyLookup, PropertyTable};
+
+/// A [`PropertyLookup`] that uses one of this library's tables, or some
+/// other lookup, except for the characters in a list of overrides.
+///
+/// This suits applications that need only a few tailorings, such as a
+/// terminal treating U+00A0 NO-BREAK SPACE as a control character, without
+/// building a whole table at runtime. Pass the overlay to
+/// [`TailoredMachine::new`](crate::TailoredMachine::new) to segment text
+/// using it, in the same way as any other lookup.
+///
+/// Each override gives the complete [`CharProperties`] for a range of
+/// characters, and replaces all of the properties from the base lookup.
+/// The overrides are searched in order from last to first, so later ones
+/// win where ranges overlap, and each lookup takes time proportional to
+/// the number of overrides.
+///
+/// ```
+/// # use grapheme_machine::*;
+/// static TERMINAL: &[(core::ops::RangeInclusive<char>, CharProperties)] = &[(
+///     '\u{A0}'..='\u{A0}',
+///     CharProperties::new(GCBProperty::Control, InCBProperty::None),
+/// )];
+///
+/// let overlay = OverlayProperties::new(TERMINAL);
+/// assert_eq!(overlay.for_char('\u{A0}').gcb_property(), GCBProperty::Control);
+/// assert_eq!(overlay.for_char('a'), CharProperties::for_char('a'));
+/// ```
+///
+/// This is available only when the `tables` feature is enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct OverlayProperties<'a, P = PropertyTable> {
+    base: P,
+    overrides: &'a [(RangeInclusive<char>, CharProperties)],
+}
+
+impl<'a> OverlayProperties<'a> {
+    /// Constructs an overlay of the given overrides on
+    /// [`PropertyTable::DEFAULT`].
+    pub const fn new(overrides: &'a [(RangeInclusive<char>, CharProperties)]) -> Self {
+        Self::with_base(PropertyTable::DEFAULT, overrides)
+    }
+}
+
+impl<'a, P> OverlayProperties<'a, P> {
+    /// Constructs an overlay of the given overrides on some other lookup,
+    /// such as a [`PropertyTable`] for a specific Unicode version.
+    pub const fn with_base(
+        base: P,
+        overrides: &'a [(RangeInclusive<char>, CharProperties)],
+    ) -> Self {
+        Self { base, overrides }
+    }
+
+    /// Returns the lookup used for characters that aren't overridden.
+    pub const fn base(&self) -> &P {
+        &self.base
+    }
+
+    /// Returns the overrides.
+    pub const fn overrides(&self) -> &'a [(RangeInclusive<char>, CharProperties)] {
+        self.overrides
+    }
+}
+
+impl<P: PropertyLookup> OverlayProperties<'_, P> {
+    /// Returns the properties of the given character, represented as a
+    /// [`u8char`] value.
+    pub fn for_u8char(&self, c: u8char) -> CharProperties {
+        match self.find(c.to_char()) {
+            Some(props) => props,
+            None => self.base.for_u8char(c),
+        }
+    }
+
+    /// Returns the properties of the given character, represented as a
+    /// [`char`] value.
+    pub fn for_char(&self, c: char) -> CharProperties {
+        match self.find(c) {
+            Some(props) => props,
+            None => self.base.for_char(c),
+        }
+    }
+
+    fn find(&self, c: char) -> Option<CharProperties> {
+        self.overrides
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(&c))
+            .map(|&(_, props)| props)
+    }
+}
+
+impl<P: PropertyLookup> PropertyLookup for OverlayProperties<'_, P> {
+    fn for_u8char(&self, c: u8char) -> CharProperties {
+        OverlayProperties::for_u8char(self, c)
+    }
+
+    fn for_char(&self, c: char) -> CharProperties {
+        OverlayProperties::for_char(self, c)
+    }
+}
+
+#[cfg(all(test, feature = "machine"))]
+mod tests;
@@ -0,0 +1,68 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::{ClusterAction, GCBProperty, InCBProperty, TailoredMachine, UnicodeVersion};
+
+const CONTROL: CharProperties = CharProperties::new(GCBProperty::Control, InCBProperty::None);
+const EXTEND: CharProperties = CharProperties::new(GCBProperty::Extend, InCBProperty::None);
+
+static OVERRIDES: &[(RangeInclusive<char>, CharProperties)] = &[
+    ('\u{A0}'..='\u{A0}', CONTROL),
+    ('x'..='z', EXTEND),
+    ('y'..='y', CONTROL),
+];
+
+#[test]
+fn lookup() {
+    let overlay = OverlayProperties::new(OVERRIDES);
+    assert_eq!(overlay.for_char('\u{A0}'), CONTROL);
+    assert_eq!(overlay.for_u8char(u8char::from_char('\u{A0}')), CONTROL);
+    assert_eq!(overlay.for_char('x'), EXTEND);
+    assert_eq!(overlay.for_char('y'), CONTROL);
+    assert_eq!(overlay.for_char('z'), EXTEND);
+    for c in ['a', '\u{A1}', '\u{301}', '👍'] {
+        assert_eq!(overlay.for_char(c), CharProperties::for_char(c), "{c:?}");
+    }
+    assert_eq!(overlay.overrides().len(), 3);
+    assert_eq!(*overlay.base(), PropertyTable::DEFAULT);
+
+    let version = PropertyTable::ALL[0].version();
+    let base = PropertyTable::for_version(version).unwrap();
+    let overlay = OverlayProperties::with_base(base, OVERRIDES);
+    assert_eq!(overlay.for_char('\u{A0}'), CONTROL);
+    assert_eq!(
+        overlay.for_char('a'),
+        CharProperties::for_char_in(version, 'a').unwrap()
+    );
+    assert!(version <= UnicodeVersion::CURRENT);
+}
+
+#[test]
+fn machine() {
+    let split = |overlay| {
+        let mut machine = TailoredMachine::new(overlay);
+        "a\u{A0}\u{301}"
+            .chars()
+            .map(|c| machine.next_char(c))
+            .collect::<Vec<_>>()
+    };
+    // The combining mark normally extends the no-break space, but not once
+    // it's a control character.
+    assert_eq!(
+        split(OverlayProperties::new(&[])),
+        [
+            ClusterAction::Split,
+            ClusterAction::Split,
+            ClusterAction::Continue
+        ]
+    );
+    assert_eq!(
+        split(OverlayProperties::new(OVERRIDES)),
+        [
+            ClusterAction::Split,
+            ClusterAction::Split,
+            ClusterAction::Split
+        ]
+    );
+}
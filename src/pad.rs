@@ -0,0 +1,99 @@
+use core::fmt;
+
+use crate::clusters_in_str;
+
+/// Where [`pad_to_width`] places text within the padded field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Put the text at the start of the field, with padding after it.
+    Left,
+    /// Put the text at the end of the field, with padding before it.
+    Right,
+    /// Put the text in the middle of the field, with any odd column of
+    /// padding after it.
+    Center,
+}
+
+/// Returns a value that displays `s` padded with spaces to fill `width`
+/// display columns, for building aligned tables of text in a terminal.
+///
+/// `measure` is called for each grapheme cluster in `s` and must return
+/// the number of display columns that the given cluster occupies, such as
+/// the number of terminal cells. Measuring whole clusters rather than
+/// counting bytes or characters keeps the columns aligned even when the
+/// text includes emoji sequences, combining marks and CJK ideographs.
+///
+/// Text that is already at least `width` columns wide is displayed
+/// unchanged, without truncating it.
+pub fn pad_to_width(
+    s: &str,
+    width: usize,
+    align: Alignment,
+    measure: impl FnMut(&str) -> usize,
+) -> Padded<'_> {
+    let used: usize = clusters_in_str(s).map(measure).sum();
+    let padding = width.saturating_sub(used);
+    let (before, after) = match align {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    Padded { s, before, after }
+}
+
+/// Shorthand for [`pad_to_width`] with [`Alignment::Left`].
+pub fn align_left(s: &str, width: usize, measure: impl FnMut(&str) -> usize) -> Padded<'_> {
+    pad_to_width(s, width, Alignment::Left, measure)
+}
+
+/// Shorthand for [`pad_to_width`] with [`Alignment::Right`].
+pub fn align_right(s: &str, width: usize, measure: impl FnMut(&str) -> usize) -> Padded<'_> {
+    pad_to_width(s, width, Alignment::Right, measure)
+}
+
+/// Shorthand for [`pad_to_width`] with [`Alignment::Center`].
+pub fn align_center(s: &str, width: usize, measure: impl FnMut(&str) -> usize) -> Padded<'_> {
+    pad_to_width(s, width, Alignment::Center, measure)
+}
+
+/// Some text along with the padding to display around it, as returned by
+/// [`pad_to_width`].
+///
+/// This implements [`fmt::Display`], and so it can be written directly to
+/// a formatter without allocating, or converted to a `String` using
+/// `to_string` when the `alloc` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Padded<'a> {
+    s: &'a str,
+    before: usize,
+    after: usize,
+}
+
+impl<'a> Padded<'a> {
+    /// Returns the text being padded.
+    pub fn text(&self) -> &'a str {
+        self.s
+    }
+
+    /// Returns the number of spaces to display before and after the text.
+    pub fn padding(&self) -> (usize, usize) {
+        (self.before, self.after)
+    }
+}
+
+impl<'a> fmt::Display for Padded<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:before$}{}{:after$}",
+            "",
+            self.s,
+            "",
+            before = self.before,
+            after = self.after
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
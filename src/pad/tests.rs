@@ -0,0 +1,32 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+// A rough measure for the tests: flags and CJK are two columns wide.
+fn measure(cluster: &str) -> usize {
+    match cluster.chars().next() {
+        Some('\u{1F1E6}'..='\u{1F1FF}' | '\u{4E00}'..='\u{9FFF}') => 2,
+        _ => 1,
+    }
+}
+
+#[test]
+fn alignments() {
+    let s = "e\u{0301}🇦🇧中";
+    assert_eq!(align_left(s, 7, measure).to_string(), "e\u{0301}🇦🇧中  ");
+    assert_eq!(align_right(s, 7, measure).to_string(), "  e\u{0301}🇦🇧中");
+    assert_eq!(align_center(s, 8, measure).to_string(), " e\u{0301}🇦🇧中  ");
+    assert_eq!(align_center(s, 8, measure).padding(), (1, 2));
+    assert_eq!(
+        pad_to_width("", 2, Alignment::Left, measure).to_string(),
+        "  "
+    );
+}
+
+#[test]
+fn already_wide() {
+    let padded = align_right("中中", 3, measure);
+    assert_eq!(padded.padding(), (0, 0));
+    assert_eq!(padded.to_string(), "中中");
+    assert_eq!(padded.text(), "中中");
+}
@@ -0,0 +1,97 @@
+use u8char::u8char;
+
+use crate::SegmentAction;
+
+/// A zero-based line and column position, where the column is measured in
+/// grapheme clusters rather than in bytes or characters.
+///
+/// Measuring columns in grapheme clusters means that the column number
+/// corresponds to what a reader would consider to be the number of
+/// "characters" before the position on the same line, which is typically
+/// what's expected for compiler-style diagnostics and for the cursor
+/// position shown in an editor's status bar.
+///
+/// Many user interfaces count lines and columns from one instead of zero,
+/// so callers will often need to add one to each field before displaying
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LineColumn {
+    /// The number of line breaks before the position.
+    pub line: usize,
+    /// The number of grapheme clusters between the most recent line break
+    /// (or the start of input) and the position.
+    pub column: usize,
+}
+
+/// Tracks the [`LineColumn`] position of each grapheme cluster in a stream,
+/// based on the actions chosen for each character by a
+/// [`GraphemeMachine`](crate::GraphemeMachine) or other [`Segmenter`](crate::Segmenter).
+///
+/// This doesn't include a state machine of its own, and so must be used
+/// alongside one. Pass each character to the state machine first and then
+/// pass the character and the resulting action to
+/// [`LineColumnTracker::next_u8char`] or [`LineColumnTracker::next_char`].
+///
+/// A line break is any grapheme cluster consisting of a carriage return, a
+/// line feed, or a carriage return followed by a line feed. Other characters
+/// that are sometimes treated as line breaks, such as U+2028 LINE SEPARATOR,
+/// are counted as normal clusters.
+#[derive(Debug, Clone, Default)]
+pub struct LineColumnTracker {
+    current: LineColumn,
+    started: bool,
+    after_line_break: bool,
+}
+
+impl LineColumnTracker {
+    /// Constructs a new [`LineColumnTracker`] which will treat the first
+    /// cluster it encounters as being at line zero, column zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the tracker for a new character and the action that was chosen
+    /// for it, returning the position of the grapheme cluster that the
+    /// character belongs to.
+    pub fn next_char(&mut self, action: impl SegmentAction, c: char) -> LineColumn {
+        if action.is_boundary() {
+            if !self.started {
+                self.started = true;
+            } else if self.after_line_break {
+                self.current.line += 1;
+                self.current.column = 0;
+            } else {
+                self.current.column += 1;
+            }
+            // Line breaks are always their own grapheme clusters, so we only
+            // need to check the first character of each cluster. (A CR LF
+            // sequence is a single cluster beginning with CR.)
+            self.after_line_break = matches!(c, '\r' | '\n');
+        }
+        self.current
+    }
+
+    /// Updates the tracker for a new character and the action that was chosen
+    /// for it, returning the position of the grapheme cluster that the
+    /// character belongs to.
+    pub fn next_u8char(&mut self, action: impl SegmentAction, c: u8char) -> LineColumn {
+        // Line break characters are ASCII, so we can avoid fully decoding
+        // any character that isn't.
+        let c = if c.is_ascii() {
+            c.first_byte() as char
+        } else {
+            '\0'
+        };
+        self.next_char(action, c)
+    }
+
+    /// Returns the position of the grapheme cluster that the most recent
+    /// character belonged to, or of the first cluster if no characters have
+    /// been seen yet.
+    pub fn position(&self) -> LineColumn {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests;
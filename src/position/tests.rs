@@ -0,0 +1,54 @@
+use super::*;
+use crate::GraphemeMachine;
+
+use pretty_assertions::assert_eq;
+
+fn positions(s: &str) -> Vec<(char, LineColumn)> {
+    let mut machine = GraphemeMachine::new();
+    let mut tracker = LineColumnTracker::new();
+    machine
+        .next_u8chars_from_str(s)
+        .map(|(action, c)| (c.to_char(), tracker.next_u8char(action, c)))
+        .collect()
+}
+
+fn lc(line: usize, column: usize) -> LineColumn {
+    LineColumn { line, column }
+}
+
+#[test]
+fn line_breaks() {
+    assert_eq!(
+        positions("ab\ncd\r\ne\rf\n\ng"),
+        &[
+            ('a', lc(0, 0)),
+            ('b', lc(0, 1)),
+            ('\n', lc(0, 2)),
+            ('c', lc(1, 0)),
+            ('d', lc(1, 1)),
+            ('\r', lc(1, 2)),
+            ('\n', lc(1, 2)),
+            ('e', lc(2, 0)),
+            ('\r', lc(2, 1)),
+            ('f', lc(3, 0)),
+            ('\n', lc(3, 1)),
+            ('\n', lc(4, 0)),
+            ('g', lc(5, 0)),
+        ]
+    );
+}
+
+#[test]
+fn columns_count_clusters() {
+    assert_eq!(
+        positions("e\u{0301}🧑‍🌾x"),
+        &[
+            ('e', lc(0, 0)),
+            ('\u{0301}', lc(0, 0)),
+            ('🧑', lc(0, 1)),
+            ('\u{200D}', lc(0, 1)),
+            ('🌾', lc(0, 1)),
+            ('x', lc(0, 2)),
+        ]
+    );
+}
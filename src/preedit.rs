@@ -0,0 +1,156 @@
+use alloc::string::String;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// Tracks the "preedit" text of an input method: a composing grapheme
+/// cluster that is still being typed, kept separate from the text that
+/// has already been committed.
+///
+/// Each typed character either extends the composing cluster or, if a
+/// [`GraphemeMachine`] (or any other [`Segmenter`]) finds a boundary
+/// before it, commits the composing cluster and begins a new one. That
+/// covers conjoining Hangul jamo, which extend a syllable until the next
+/// syllable begins, as well as combining marks typed after their base
+/// character, emoji ZWJ sequences and pairs of regional indicators.
+///
+/// The committed text accumulates until it's taken with
+/// [`Self::take_committed`], typically to pass it on to the application.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct Preedit<S = GraphemeMachine> {
+    machine: S,
+    composing: String,
+    committed: String,
+}
+
+/// Describes what [`Preedit::next_char`] did with a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreeditChange {
+    /// The character was added to the composing cluster, which is a new
+    /// cluster if nothing was composing before.
+    Extended,
+    /// The character began a new cluster, and so the cluster that was
+    /// composing before it was committed.
+    Committed,
+}
+
+impl Preedit {
+    /// Constructs a new preedit with nothing composing or committed.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl Default for Preedit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Segmenter> Preedit<S> {
+    /// Constructs a new preedit with nothing composing or committed that
+    /// uses the given machine to decide where each composing cluster ends.
+    ///
+    /// The given machine should be in its "start of input" state.
+    pub fn with_machine(machine: S) -> Self {
+        Self {
+            machine,
+            composing: String::new(),
+            committed: String::new(),
+        }
+    }
+
+    /// Accepts the next typed character, either adding it to the composing
+    /// cluster or committing that cluster and beginning a new one.
+    pub fn next_u8char(&mut self, c: u8char) -> PreeditChange {
+        let action = self.machine.next_u8char(c);
+        let change = if action.is_boundary() && !self.composing.is_empty() {
+            self.committed.push_str(&self.composing);
+            self.composing.clear();
+            PreeditChange::Committed
+        } else {
+            PreeditChange::Extended
+        };
+        self.composing.push_str(c.as_str());
+        change
+    }
+
+    /// Accepts the next typed character, either adding it to the composing
+    /// cluster or committing that cluster and beginning a new one.
+    ///
+    /// This converts the character to [`u8char`] first, so prefer
+    /// [`Self::next_u8char`] if you already have that representation.
+    pub fn next_char(&mut self, c: char) -> PreeditChange {
+        self.next_u8char(u8char::from_char(c))
+    }
+
+    /// Commits the composing cluster even though no boundary has been
+    /// found after it, such as when the user confirms the composition or
+    /// the input loses focus. Returns `false` if nothing was composing.
+    ///
+    /// The next typed character then always begins a new cluster, even if
+    /// it would otherwise have extended the committed one.
+    pub fn commit(&mut self) -> bool {
+        self.machine.end_of_input();
+        if self.composing.is_empty() {
+            return false;
+        }
+        self.committed.push_str(&self.composing);
+        self.composing.clear();
+        true
+    }
+
+    /// Discards the composing cluster, returning its text.
+    ///
+    /// As with [`Self::commit`], the next typed character then always begins
+    /// a new cluster.
+    pub fn cancel(&mut self) -> String {
+        self.machine.end_of_input();
+        core::mem::take(&mut self.composing)
+    }
+
+    /// Removes the last character of the composing cluster, returning it,
+    /// or returns `None` if nothing was composing.
+    ///
+    /// The committed text is never changed, so any characters typed next
+    /// are judged only against what remains of the composing cluster, as
+    /// though the removed character had never been typed.
+    pub fn backspace(&mut self) -> Option<char> {
+        let c = self.composing.pop()?;
+        // The composing cluster began at a boundary, so replaying it from
+        // the start of input leaves the machine in the same state as if
+        // the removed character had never been typed.
+        self.machine.end_of_input();
+        for c in self.composing.chars() {
+            self.machine.next_char(c);
+        }
+        Some(c)
+    }
+
+    /// Returns the text of the composing cluster, which is empty if nothing
+    /// is composing.
+    pub fn composing(&self) -> &str {
+        &self.composing
+    }
+
+    /// Returns the text committed since it was last taken.
+    pub fn committed(&self) -> &str {
+        &self.committed
+    }
+
+    /// Takes the text committed since it was last taken, leaving the
+    /// composing cluster unchanged.
+    pub fn take_committed(&mut self) -> String {
+        core::mem::take(&mut self.committed)
+    }
+
+    /// Consumes the preedit and returns the machine it was using.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+}
+
+#[cfg(test)]
+mod tests;
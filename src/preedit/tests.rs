@@ -0,0 +1,79 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use PreeditChange::*;
+
+fn type_chars(preedit: &mut Preedit, s: &str) -> Vec<PreeditChange> {
+    s.chars().map(|c| preedit.next_char(c)).collect()
+}
+
+#[test]
+fn hangul_jamo() {
+    let mut preedit = Preedit::new();
+    // HIEUH, A, NIEUN make one syllable, and KIYEOK begins the next.
+    assert_eq!(
+        type_chars(&mut preedit, "\u{1112}\u{1161}\u{11AB}"),
+        &[Extended, Extended, Extended]
+    );
+    assert_eq!(preedit.composing(), "\u{1112}\u{1161}\u{11AB}");
+    assert_eq!(preedit.committed(), "");
+    assert_eq!(preedit.next_char('\u{1100}'), Committed);
+    assert_eq!(preedit.composing(), "\u{1100}");
+    assert_eq!(preedit.committed(), "\u{1112}\u{1161}\u{11AB}");
+}
+
+#[test]
+fn combining_marks_and_emoji() {
+    let mut preedit = Preedit::new();
+    assert_eq!(
+        type_chars(&mut preedit, "e\u{0301}x🧑\u{200D}🌾!"),
+        &[
+            Extended, Extended, Committed, Committed, Extended, Extended, Committed
+        ]
+    );
+    assert_eq!(preedit.committed(), "e\u{0301}x🧑\u{200D}🌾");
+    assert_eq!(preedit.take_committed(), "e\u{0301}x🧑\u{200D}🌾");
+    assert_eq!(preedit.committed(), "");
+    assert_eq!(preedit.composing(), "!");
+}
+
+#[test]
+fn commit_and_cancel() {
+    let mut preedit = Preedit::new();
+    assert!(!preedit.commit());
+    preedit.next_char('e');
+    assert!(preedit.commit());
+    assert_eq!((preedit.committed(), preedit.composing()), ("e", ""));
+
+    // A combining mark after an explicit commit begins a new composition
+    // rather than extending the committed cluster.
+    assert_eq!(preedit.next_char('\u{0301}'), Extended);
+    assert_eq!(preedit.next_char('\u{0308}'), Extended);
+    assert_eq!(preedit.cancel(), "\u{0301}\u{0308}");
+    assert_eq!((preedit.committed(), preedit.composing()), ("e", ""));
+    assert_eq!(preedit.next_char('a'), Extended);
+    assert_eq!(preedit.next_char('b'), Committed);
+    assert_eq!(preedit.committed(), "ea");
+}
+
+#[test]
+fn backspace() {
+    let mut preedit = Preedit::new();
+    assert_eq!(preedit.backspace(), None);
+
+    // Regional indicators pair up, so removing the second one of a pair
+    // means that the next one pairs with the first instead.
+    assert_eq!(type_chars(&mut preedit, "🇦🇧"), &[Extended, Extended]);
+    assert_eq!(preedit.backspace(), Some('🇧'));
+    assert_eq!(preedit.next_char('🇨'), Extended);
+    assert_eq!(preedit.composing(), "🇦🇨");
+    assert_eq!(preedit.next_char('🇩'), Committed);
+
+    assert_eq!(preedit.backspace(), Some('🇩'));
+    assert_eq!(preedit.backspace(), None);
+    assert_eq!(preedit.committed(), "🇦🇨");
+    assert_eq!(preedit.next_char('🇪'), Extended);
+    assert_eq!(preedit.next_char('🇫'), Extended);
+    assert_eq!(preedit.composing(), "🇪🇫");
+}
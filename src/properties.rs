@@ -1,6 +1,26 @@
 use u8char::u8char;
 
-mod table;
+use crate::InvalidPropertiesError;
+#[cfg(feature = "tables")]
+use crate::UnicodeVersion;
+
+#[cfg(feature = "unicode-15-1")]
+mod table_15_1;
+#[cfg(all(
+    feature = "tables",
+    any(feature = "unicode-16-0", not(feature = "unicode-15-1"))
+))]
+mod table_16_0;
+
+// The lookup functions use the table for the newest Unicode version whose
+// feature is enabled, or the newest of all if none of them are.
+#[cfg(all(feature = "unicode-15-1", not(feature = "unicode-16-0")))]
+use table_15_1 as table;
+#[cfg(all(
+    feature = "tables",
+    any(feature = "unicode-16-0", not(feature = "unicode-15-1"))
+))]
+use table_16_0 as table;
 
 /// Enumeration of **Grapheme_Cluster_Break** property values, from
 /// [UAX#29 Section 3.1](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Break_Property_Values).
@@ -10,11 +30,13 @@ mod table;
 /// grapheme cluster break property value and so included in this enumeration
 /// for simplicity's sake.
 ///
-/// Do not depend on the specific values currently used in this enumeration;
-/// they are an implementation detail subject to change in future versions of
-/// this library.
+/// The enumeration is represented as a single byte whose values are part of
+/// the FFI layout of [`CharProperties`], and so foreign code can rely on
+/// them. They will change only in a semver-incompatible release of this
+/// library.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GCBProperty {
     /// Represents that none of the grapheme cluster break property values
     /// apply to a particular character at all.
@@ -42,11 +64,11 @@ pub enum GCBProperty {
 /// These are used in the rule that avoids splitting orthographic syllables in
 /// inappropriate ways, [GB9c](https://www.unicode.org/reports/tr29/#GB9c).
 ///
-/// Do not depend on the specific values currently used in this enumeration;
-/// they are an implementation detail subject to change in future versions of
-/// this library.
+/// As with [`GCBProperty`], the enumeration is represented as a single byte
+/// whose values are part of the FFI layout of [`CharProperties`].
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InCBProperty {
     /// Represents that none of the Indic_Conjunct_Break property values
     /// apply to a particular character at all.
@@ -56,6 +78,46 @@ pub enum InCBProperty {
     Linker = 0x30,
 }
 
+/// The Unicode **East_Asian_Width** property, which classifies characters
+/// by how wide they are in East Asian typography.
+///
+/// Terminal emulators use this to decide which characters take two
+/// columns rather than one, although `cluster_width` is usually a better
+/// guide for that because it also accounts for emoji and for the other
+/// characters in a grapheme cluster.
+///
+/// As with [`GCBProperty`], the enumeration is represented as a single byte
+/// whose values are part of the FFI layout of [`CharProperties`], although
+/// they are shifted into place there.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EastAsianWidth {
+    /// **N**: Characters that don't occur in East Asian typography, and
+    /// which are therefore neither wide nor narrow.
+    Neutral = 0,
+    /// **A**: Characters that are wide in East Asian contexts and narrow
+    /// elsewhere, such as Greek and Cyrillic letters.
+    Ambiguous = 1,
+    /// **H**: Halfwidth forms, such as halfwidth katakana.
+    Halfwidth = 2,
+    /// **Na**: Narrow characters that have fullwidth counterparts, such as
+    /// ASCII letters.
+    Narrow = 3,
+    /// **W**: Wide characters, such as CJK ideographs and most emoji.
+    Wide = 4,
+    /// **F**: Fullwidth forms, such as fullwidth ASCII letters.
+    Fullwidth = 5,
+}
+
+impl EastAsianWidth {
+    /// Returns `true` for [`Self::Wide`] and [`Self::Fullwidth`], the
+    /// characters that always take two columns.
+    pub const fn is_wide(self) -> bool {
+        matches!(self, Self::Wide | Self::Fullwidth)
+    }
+}
+
 /// Represents selections from the two derived Unicode character properties
 /// used for grapheme cluster segmenttion:
 ///
@@ -65,6 +127,27 @@ pub enum InCBProperty {
 /// The [Grapheme Cluster Boundary Rules](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules)
 /// are defined in terms of both sets of property values, and so this type
 /// serves as a compact tuple of one selection from each.
+///
+/// # FFI layout
+///
+/// A `CharProperties` has the same layout as a `u16`, which is the value
+/// returned by [`Self::to_raw`]:
+///
+/// - Bits 0 through 3 hold a [`GCBProperty`] value.
+/// - Bits 4 and 5 hold an [`InCBProperty`] value, which is already shifted
+///   into place by its enumeration values.
+/// - Bit 6 is set for characters with the **Default_Ignorable_Code_Point**
+///   property, as returned by [`Self::is_default_ignorable`].
+/// - Bit 7 is set for characters with the **Variation_Selector** property,
+///   as returned by [`Self::is_variation_selector`].
+/// - Bits 8 through 10 hold an [`EastAsianWidth`] value, as returned by
+///   [`Self::eaw_property`]. The lookup functions never set these bits.
+/// - Bit 11 is reserved and is always zero.
+/// - Bits 12 through 15 are the caller-defined bits from [`Self::user_bits`].
+///
+/// Foreign code can therefore pass these values across an FFI boundary as a
+/// plain 16-bit integer, but should only construct new ones by combining
+/// the enumeration values as described above.
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CharProperties {
@@ -72,30 +155,168 @@ pub struct CharProperties {
     /// of [`GCBProperty`] and [`InCBProperty`] are defined such that one
     /// of each can be bitwise-ORed together without collisions, with
     /// the former in the low nybble and the latter in the least significant
-    /// two bits of the high nybble.
+    /// two bits of the next nybble. The next two bits are the
+    /// **Default_Ignorable_Code_Point** and **Variation_Selector**
+    /// properties.
+    ///
+    /// The low three bits of the high byte are the **East_Asian_Width**
+    /// property, which can't take the values 6 or 7. The next bit is
+    /// reserved for additional properties to be added in future versions,
+    /// and must currently be zero. The top nybble
+    /// is available for callers to use for their own purposes; see
+    /// [`Self::user_bits`].
+    ///
+    /// The embedded lookup table stores only the low byte, so that adding
+    /// more properties later doesn't make the existing table any larger.
+    /// The lookup functions leave the **East_Asian_Width** bits as zero, so
+    /// that enabling the `width` feature doesn't change the values they
+    /// return, but callers can store it here using
+    /// [`Self::with_eaw_property`].
     ///
     /// The soundness of the property accessor methods depends on this field
     /// only containing valid encodings of each of the two enums.
-    raw: u8,
+    raw: u16,
 }
 
+/// The bits of [`CharProperties::raw`] that are not yet allocated to any
+/// property, and so must be zero.
+const RESERVED_BITS: u16 = 0x0800;
+
+/// The bits of [`CharProperties::raw`] representing the
+/// **East_Asian_Width** property.
+const EAW_BITS: u16 = 0x0700;
+const EAW_BITS_SHIFT: u32 = 8;
+
+/// The bit of [`CharProperties::raw`] representing the
+/// **Default_Ignorable_Code_Point** property.
+const DEFAULT_IGNORABLE_BIT: u16 = 0x0040;
+
+/// The bit of [`CharProperties::raw`] representing the
+/// **Variation_Selector** property.
+const VARIATION_SELECTOR_BIT: u16 = 0x0080;
+
+/// The bits of [`CharProperties::raw`] that are available for callers'
+/// own use.
+const USER_BITS: u16 = 0xf000;
+const USER_BITS_SHIFT: u32 = 12;
+
 impl CharProperties {
+    /// A value that doesn't represent any valid property tuple, used as a
+    /// sentinel where a `CharProperties` field represents the absence of a
+    /// character. The accessor methods must never be called on this value,
+    /// because its low nybble isn't a valid [`GCBProperty`].
+    #[cfg(feature = "machine")]
+    pub(crate) const NO_CHAR: Self = Self { raw: 0x000f };
+
+    /// Returns `true` if this is [`Self::NO_CHAR`].
+    #[cfg(feature = "machine")]
+    pub(crate) const fn is_no_char(self) -> bool {
+        self.raw == Self::NO_CHAR.raw
+    }
+
     /// Returns a new [CharProperties] value representing a tuple of the
     /// two given property values.
     pub const fn new(gcb: GCBProperty, incb: InCBProperty) -> Self {
         Self {
-            raw: gcb as u8 | incb as u8,
+            raw: gcb as u16 | incb as u16,
+        }
+    }
+
+    /// Returns the [CharProperties] value with the given raw representation,
+    /// as previously returned by [`Self::to_raw`], or an error if the value
+    /// doesn't represent a valid tuple of property values.
+    ///
+    /// This is intended for callers that maintain their own property lookup
+    /// tables, or that receive property values from foreign code. The raw
+    /// representation is described under "FFI layout" above, and will change
+    /// only in a semver-incompatible release of this library.
+    pub const fn from_raw(raw: u16) -> Result<Self, InvalidPropertiesError> {
+        // The low nybble is a GCBProperty, which has no 0xf value.
+        // The East_Asian_Width bits have no values above Fullwidth.
+        let eaw = (raw & EAW_BITS) >> EAW_BITS_SHIFT;
+        if raw & 0xf == 0xf || raw & RESERVED_BITS != 0 || eaw > EastAsianWidth::Fullwidth as u16 {
+            return Err(InvalidPropertiesError { raw });
+        }
+        Ok(Self { raw })
+    }
+
+    /// Returns the raw representation of this value, which can be converted
+    /// back using [`Self::from_raw`].
+    pub const fn to_raw(self) -> u16 {
+        self.raw
+    }
+
+    /// Returns the four bits of this value that are reserved for use by
+    /// callers, as the low four bits of the result.
+    ///
+    /// These bits are always zero in the values returned by this library's
+    /// own lookup functions, and are ignored by [`GraphemeMachine`](crate::GraphemeMachine),
+    /// so callers with tailored property tables can use them to carry extra
+    /// information alongside the standard properties, such as for a
+    /// third-party [`Segmenter`](crate::Segmenter).
+    pub const fn user_bits(self) -> u8 {
+        (self.raw >> USER_BITS_SHIFT) as u8
+    }
+
+    /// Returns a copy of this value with its user bits replaced by the
+    /// low four bits of `bits`. The other bits of `bits` are ignored.
+    pub const fn with_user_bits(self, bits: u8) -> Self {
+        let user = ((bits as u16) << USER_BITS_SHIFT) & USER_BITS;
+        Self {
+            raw: (self.raw & !USER_BITS) | user,
         }
     }
 
+    /// The version of the Unicode Standard that [`Self::for_u8char`] and
+    /// [`Self::for_char`] look up properties for, which is the newest one
+    /// whose `unicode-*` feature is enabled, or [`UnicodeVersion::CURRENT`]
+    /// if none of them are.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const UNICODE_VERSION: UnicodeVersion = table::UNICODE_VERSION;
+
+    /// The total size in bytes of the lookup tables that
+    /// [`Self::for_u8char`] and [`Self::for_char`] use, which is the sum of
+    /// [`Self::TABLE_VALUES_SIZE`] and [`Self::TABLE_INDEX_SIZE`].
+    ///
+    /// The tables are immutable statics, so on embedded targets they are
+    /// normally placed in read-only memory along with the rest of the
+    /// program's constant data. Set the `GRAPHEME_MACHINE_LINK_SECTION`
+    /// environment variable to a link section name when building to place
+    /// them in that section instead, such as one mapped to external flash.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const TABLE_SIZE: usize = Self::TABLE_VALUES_SIZE + Self::TABLE_INDEX_SIZE;
+
+    /// The size in bytes of the part of the lookup tables holding property
+    /// values. This is the part that grows with the number of distinct
+    /// property patterns across the Unicode code space.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const TABLE_VALUES_SIZE: usize = table::GRAPHEMES_VALUES_SIZE;
+
+    /// The size in bytes of the part of the lookup tables holding the
+    /// interior nodes of the UTF-8 trie.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const TABLE_INDEX_SIZE: usize = table::GRAPHEMES_INDEX_SIZE;
+
     /// Returns the relevant character properties for the given character,
     /// represented as a [`u8char`] value.
     ///
     /// This performs a table lookup using a trie embedded in this library.
+    /// The result's [`Self::eaw_property`] is always
+    /// [`EastAsianWidth::Neutral`]; with the `width` feature enabled, use
+    /// `EastAsianWidth::of_char` to look up that property separately.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub const fn for_u8char(c: u8char) -> Self {
-        Self {
-            raw: table::graphemes_lookup(c),
-        }
+        Self::from_table_value(table::graphemes_lookup(c))
     }
 
     /// Returns the relevant character properties for the given character,
@@ -105,26 +326,138 @@ impl CharProperties {
     /// The trie is optimized for lookup by [`u8char`], so this function
     /// converts to that representation first as a convenience but it's
     /// better to pass an existing `u8char` value if you happen to have one.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
     pub const fn for_char(c: char) -> Self {
-        Self {
-            raw: table::graphemes_lookup(u8char::from_char(c)),
+        Self::for_u8char(u8char::from_char(c))
+    }
+
+    /// Returns the relevant character properties for the given character as
+    /// of the given version of the Unicode Standard, or `None` if this
+    /// library wasn't built with a lookup table for that version.
+    ///
+    /// This is a shorthand for [`PropertyTable::for_version`] followed by
+    /// [`PropertyTable::for_u8char`]. Callers that look up many characters
+    /// in the same version should keep the [`PropertyTable`] instead, to
+    /// check for the table only once.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const fn for_u8char_in(version: UnicodeVersion, c: u8char) -> Option<Self> {
+        match PropertyTable::for_version(version) {
+            Some(table) => Some(table.for_u8char(c)),
+            None => None,
         }
     }
 
+    /// Like [`Self::for_u8char_in`], but for a [`char`] value.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    #[cfg(feature = "tables")]
+    pub const fn for_char_in(version: UnicodeVersion, c: char) -> Option<Self> {
+        Self::for_u8char_in(version, u8char::from_char(c))
+    }
+
+    /// Wraps a value from one of the lookup tables.
+    #[cfg(feature = "tables")]
+    const fn from_table_value(raw: u8) -> Self {
+        Self { raw: raw as u16 }
+    }
+
     /// Returns the [`GCBProperty`] value from this tuple.
     pub const fn gcb_property(self) -> GCBProperty {
         // Safety: The low nybble of our raw repr matches its GCBProperty repr.
-        let raw = self.raw & 0xf;
+        let raw = (self.raw & 0xf) as u8;
         unsafe { core::mem::transmute(raw) }
     }
 
     /// Returns the [`InCBProperty`] value from this tuple.
     pub const fn incb_property(self) -> InCBProperty {
         // Safety: The selected bits of our raw repr matches its InCBCategory repr.
-        let raw = self.raw & 0x30;
+        let raw = (self.raw & 0x30) as u8;
+        unsafe { core::mem::transmute(raw) }
+    }
+
+    /// Returns `true` if the character has the
+    /// **Default_Ignorable_Code_Point** property, from
+    /// [UAX #44](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point).
+    ///
+    /// Such characters are displayed as nothing at all when a font or
+    /// renderer doesn't otherwise support them, which includes the zero
+    /// width space and joiners, the variation selectors, the bidirectional
+    /// formatting characters, and the tag characters.
+    ///
+    /// This property isn't used for segmentation, and so it's ignored by
+    /// [`GraphemeMachine`](crate::GraphemeMachine).
+    pub const fn is_default_ignorable(self) -> bool {
+        self.raw & DEFAULT_IGNORABLE_BIT != 0
+    }
+
+    /// Returns a copy of this value with the
+    /// **Default_Ignorable_Code_Point** property set as given.
+    ///
+    /// [`Self::new`] always returns values without this property, so callers
+    /// using tailored property tables can use this to add it.
+    pub const fn with_default_ignorable(self, ignorable: bool) -> Self {
+        let raw = self.raw & !DEFAULT_IGNORABLE_BIT;
+        Self {
+            raw: match ignorable {
+                true => raw | DEFAULT_IGNORABLE_BIT,
+                false => raw,
+            },
+        }
+    }
+
+    /// Returns `true` if the character has the **Variation_Selector**
+    /// property, from [UAX #44](https://www.unicode.org/reports/tr44/#Variation_Selector).
+    ///
+    /// Variation selectors choose between alternative glyphs for the
+    /// preceding character, such as between the text and emoji
+    /// presentations of a symbol. As with
+    /// [`Self::is_default_ignorable`], this property isn't used for
+    /// segmentation.
+    pub const fn is_variation_selector(self) -> bool {
+        self.raw & VARIATION_SELECTOR_BIT != 0
+    }
+
+    /// Returns a copy of this value with the **Variation_Selector**
+    /// property set as given.
+    pub const fn with_variation_selector(self, selector: bool) -> Self {
+        let raw = self.raw & !VARIATION_SELECTOR_BIT;
+        Self {
+            raw: match selector {
+                true => raw | VARIATION_SELECTOR_BIT,
+                false => raw,
+            },
+        }
+    }
+
+    /// Returns the [`EastAsianWidth`] value from this tuple.
+    ///
+    /// The lookup functions don't include this property, and so it's
+    /// [`EastAsianWidth::Neutral`] unless set using
+    /// [`Self::with_eaw_property`], such as by a tailored property table.
+    /// It isn't used for segmentation. With the `width` feature enabled,
+    /// `EastAsianWidth::of_char` looks up the property of a character.
+    pub const fn eaw_property(self) -> EastAsianWidth {
+        // Safety: from_raw and with_eaw_property allow only the values
+        // of EastAsianWidth in these bits.
+        let raw = ((self.raw & EAW_BITS) >> EAW_BITS_SHIFT) as u8;
         unsafe { core::mem::transmute(raw) }
     }
 
+    /// Returns a copy of this value with the **East_Asian_Width** property
+    /// set as given.
+    ///
+    /// [`Self::new`] always returns values with [`EastAsianWidth::Neutral`],
+    /// so callers using tailored property tables can use this to add it.
+    pub const fn with_eaw_property(self, eaw: EastAsianWidth) -> Self {
+        Self {
+            raw: (self.raw & !EAW_BITS) | (eaw as u16) << EAW_BITS_SHIFT,
+        }
+    }
+
     /// Returns `true` if the [`GCBProperty`] describes a control character.
     ///
     /// Specifically, this includes [`GCBProperty::Control`],
@@ -139,10 +472,176 @@ impl CharProperties {
     }
 }
 
+/// One of the character property lookup tables embedded in this library,
+/// each of which is for a different version of the Unicode Standard.
+///
+/// [`CharProperties::for_u8char`] and [`CharProperties::for_char`] always
+/// use [`Self::DEFAULT`], which is chosen when building. A program that
+/// serves clients pinned to different Unicode versions can instead choose a
+/// table at runtime and pass the properties it returns to
+/// [`GraphemeMachine::next_char_properties`](crate::GraphemeMachine::next_char_properties).
+/// Each `unicode-*` feature adds one table, and [`Self::ALL`] lists the
+/// ones that are available.
+///
+/// Only the grapheme cluster break properties come in multiple versions.
+/// The tables for other properties, such as the **East_Asian_Width** table
+/// that the `width` feature adds, are for [`UnicodeVersion::CURRENT`]
+/// regardless.
+///
+/// This is available only when the `tables` feature is enabled.
+#[cfg(feature = "tables")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PropertyTable {
+    version: UnicodeVersion,
+}
+
+#[cfg(feature = "tables")]
+impl PropertyTable {
+    /// The table that [`CharProperties::for_u8char`] and
+    /// [`CharProperties::for_char`] use, for
+    /// [`CharProperties::UNICODE_VERSION`].
+    pub const DEFAULT: Self = Self {
+        version: table::UNICODE_VERSION,
+    };
+
+    /// All of the tables that this library was built with, from the oldest
+    /// Unicode version to the newest.
+    pub const ALL: &[Self] = &[
+        #[cfg(feature = "unicode-15-1")]
+        Self {
+            version: table_15_1::UNICODE_VERSION,
+        },
+        #[cfg(any(feature = "unicode-16-0", not(feature = "unicode-15-1")))]
+        Self {
+            version: table_16_0::UNICODE_VERSION,
+        },
+    ];
+
+    /// Returns the table for the given version of the Unicode Standard, or
+    /// `None` if this library wasn't built with one.
+    pub const fn for_version(version: UnicodeVersion) -> Option<Self> {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            let table = Self::ALL[i];
+            if table.version.major() == version.major() && table.version.minor() == version.minor()
+            {
+                return Some(table);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the version of the Unicode Standard that this table is for.
+    pub const fn version(self) -> UnicodeVersion {
+        self.version
+    }
+
+    /// The size in bytes of this table, in the same sense as
+    /// [`CharProperties::TABLE_SIZE`].
+    pub const fn size(self) -> usize {
+        match (self.version.major(), self.version.minor()) {
+            #[cfg(feature = "unicode-15-1")]
+            (15, 1) => table_15_1::GRAPHEMES_VALUES_SIZE + table_15_1::GRAPHEMES_INDEX_SIZE,
+            #[cfg(any(feature = "unicode-16-0", not(feature = "unicode-15-1")))]
+            (16, 0) => table_16_0::GRAPHEMES_VALUES_SIZE + table_16_0::GRAPHEMES_INDEX_SIZE,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the relevant character properties for the given character,
+    /// represented as a [`u8char`] value, from this table.
+    pub const fn for_u8char(self, c: u8char) -> CharProperties {
+        let raw = match (self.version.major(), self.version.minor()) {
+            #[cfg(feature = "unicode-15-1")]
+            (15, 1) => table_15_1::graphemes_lookup(c),
+            #[cfg(any(feature = "unicode-16-0", not(feature = "unicode-15-1")))]
+            (16, 0) => table_16_0::graphemes_lookup(c),
+            _ => unreachable!(),
+        };
+        CharProperties::from_table_value(raw)
+    }
+
+    /// Returns the relevant character properties for the given character,
+    /// represented as a [`char`] value, from this table.
+    pub const fn for_char(self, c: char) -> CharProperties {
+        self.for_u8char(u8char::from_char(c))
+    }
+}
+
+/// A source of [`CharProperties`] for each character, for use in place of
+/// the table that [`CharProperties::for_u8char`] uses.
+///
+/// This library implements it for each [`PropertyTable`] and for the
+/// `TailoredTable` built at runtime when the `alloc` feature is enabled,
+/// and `TailoredMachine` drives a [`GraphemeMachine`](crate::GraphemeMachine)
+/// using any implementation of it. Implementations for a caller's own
+/// tables should return the same properties each time they are asked about
+/// the same character.
+pub trait PropertyLookup {
+    /// Returns the properties of the given character, represented as a
+    /// [`u8char`] value.
+    fn for_u8char(&self, c: u8char) -> CharProperties;
+
+    /// Returns the properties of the given character, represented as a
+    /// [`char`] value.
+    ///
+    /// The default implementation converts the character to [`u8char`] and
+    /// passes it to [`Self::for_u8char`].
+    fn for_char(&self, c: char) -> CharProperties {
+        self.for_u8char(u8char::from_char(c))
+    }
+}
+
+impl<P: PropertyLookup + ?Sized> PropertyLookup for &P {
+    fn for_u8char(&self, c: u8char) -> CharProperties {
+        P::for_u8char(self, c)
+    }
+
+    fn for_char(&self, c: char) -> CharProperties {
+        P::for_char(self, c)
+    }
+}
+
+#[cfg(feature = "tables")]
+impl PropertyLookup for PropertyTable {
+    fn for_u8char(&self, c: u8char) -> CharProperties {
+        PropertyTable::for_u8char(*self, c)
+    }
+
+    fn for_char(&self, c: char) -> CharProperties {
+        PropertyTable::for_char(*self, c)
+    }
+}
+
+/// Serializes as the 16-bit integer returned by [`CharProperties::to_raw`].
+///
+/// This is available only when the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CharProperties {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.to_raw())
+    }
+}
+
+/// Deserializes from the 16-bit integer returned by
+/// [`CharProperties::to_raw`], failing for values that
+/// [`CharProperties::from_raw`] rejects.
+///
+/// This is available only when the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CharProperties {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = <u16 as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_raw(raw).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 #[allow(unused, non_upper_case_globals)]
 impl CharProperties {
     pub(crate) const None: Self = Self::gcb_only(GCBProperty::None);
+
     pub(crate) const CR: Self = Self::gcb_only(GCBProperty::CR);
     pub(crate) const Control: Self = Self::gcb_only(GCBProperty::Control);
     pub(crate) const Extend: Self = Self::gcb_only(GCBProperty::Extend);
@@ -163,5 +662,8 @@ impl CharProperties {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "machine", feature = "tables"))]
 pub(crate) mod test_table;
+
+#[cfg(all(test, feature = "tables"))]
+mod tests;
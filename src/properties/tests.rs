@@ -0,0 +1,244 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn properties_raw_round_trip() {
+    let mut valid = 0;
+    for raw in 0..=u16::MAX {
+        if let Ok(props) = CharProperties::from_raw(raw) {
+            assert_eq!(props.to_raw(), raw);
+            valid += 1;
+        }
+    }
+    // Every combination of the three enums, the two boolean properties,
+    // and the user bits, and nothing else.
+    assert_eq!(valid, 15 * 4 * 2 * 2 * 6 * 16);
+
+    let props = CharProperties::new(GCBProperty::ZWJ, InCBProperty::Linker);
+    assert_eq!(CharProperties::from_raw(props.to_raw()), Ok(props));
+}
+
+#[test]
+fn user_bits() {
+    let props = CharProperties::for_char('a');
+    assert_eq!(props.user_bits(), 0);
+    let tagged = props.with_user_bits(0xfa);
+    assert_eq!(tagged.user_bits(), 0xa);
+    assert_eq!(tagged.gcb_property(), props.gcb_property());
+    assert_eq!(tagged.incb_property(), props.incb_property());
+    assert_eq!(CharProperties::from_raw(tagged.to_raw()), Ok(tagged));
+    assert_eq!(tagged.with_user_bits(0), props);
+}
+
+#[test]
+fn eaw_property() {
+    let props = CharProperties::new(GCBProperty::None, InCBProperty::None);
+    assert_eq!(props.eaw_property(), EastAsianWidth::Neutral);
+    let wide = props.with_eaw_property(EastAsianWidth::Fullwidth);
+    assert_eq!(wide.eaw_property(), EastAsianWidth::Fullwidth);
+    assert_eq!(wide.gcb_property(), GCBProperty::None);
+    assert_eq!(CharProperties::from_raw(wide.to_raw()), Ok(wide));
+    assert_eq!(wide.with_eaw_property(EastAsianWidth::Neutral), props);
+    assert!(CharProperties::from_raw(0x0600).is_err());
+    assert!(CharProperties::from_raw(0x0800).is_err());
+
+    #[cfg(feature = "width")]
+    for (c, want) in [
+        ('a', EastAsianWidth::Narrow),
+        ('\u{0301}', EastAsianWidth::Ambiguous),
+        ('中', EastAsianWidth::Wide),
+        ('\u{1F469}', EastAsianWidth::Wide),
+    ] {
+        // The lookup functions leave the property out, so that enabling
+        // the feature doesn't change their results.
+        let props = CharProperties::for_char(c);
+        assert_eq!(props.eaw_property(), EastAsianWidth::Neutral, "{c:?}");
+        assert_eq!(EastAsianWidth::of_char(c), want, "{c:?}");
+    }
+}
+
+#[test]
+fn default_ignorable() {
+    for c in [
+        '\u{00AD}',
+        '\u{200B}',
+        '\u{200D}',
+        '\u{FE0F}',
+        '\u{3164}',
+        '\u{E0041}',
+    ] {
+        let props = CharProperties::for_char(c);
+        assert!(props.is_default_ignorable(), "{c:?}");
+        assert!(!props.with_default_ignorable(false).is_default_ignorable());
+    }
+    for c in ['a', ' ', '\u{0301}', '\u{1F33E}', '\u{FFFD}'] {
+        assert!(!CharProperties::for_char(c).is_default_ignorable(), "{c:?}");
+    }
+
+    // The property is independent of the others.
+    let zwj = CharProperties::for_char('\u{200D}');
+    assert_eq!(zwj.gcb_property(), GCBProperty::ZWJ);
+    assert_eq!(
+        zwj,
+        CharProperties::new(GCBProperty::ZWJ, InCBProperty::Extend).with_default_ignorable(true),
+    );
+}
+
+#[test]
+fn variation_selector() {
+    for c in ['\u{180B}', '\u{FE0E}', '\u{FE0F}', '\u{E0100}'] {
+        let props = CharProperties::for_char(c);
+        assert!(props.is_variation_selector(), "{c:?}");
+        assert!(props.is_default_ignorable(), "{c:?}");
+        assert_eq!(props.gcb_property(), GCBProperty::Extend, "{c:?}");
+    }
+    for c in ['a', '\u{200D}', '\u{0301}', '\u{180E}'] {
+        assert!(
+            !CharProperties::for_char(c).is_variation_selector(),
+            "{c:?}"
+        );
+    }
+    let props = CharProperties::new(GCBProperty::Extend, InCBProperty::None);
+    let vs = props.with_variation_selector(true);
+    assert!(vs.is_variation_selector());
+    assert!(!vs.is_default_ignorable());
+    assert_eq!(vs.with_variation_selector(false), props);
+}
+
+#[test]
+fn table_size() {
+    // The tables are made of 64-entry trie nodes, and so the sizes should
+    // always be whole multiples of that.
+    assert_eq!(CharProperties::TABLE_VALUES_SIZE % 64, 0);
+    assert_eq!(CharProperties::TABLE_INDEX_SIZE % 64, 0);
+    assert_eq!(
+        CharProperties::TABLE_SIZE,
+        CharProperties::TABLE_VALUES_SIZE + CharProperties::TABLE_INDEX_SIZE,
+    );
+}
+
+#[test]
+fn unicode_version() {
+    // U+0897 ARABIC PEPET was first assigned in Unicode 16.0.
+    let pepet = CharProperties::for_char('\u{897}').gcb_property();
+    if CharProperties::UNICODE_VERSION >= UnicodeVersion::new(16, 0) {
+        assert_eq!(pepet, GCBProperty::Extend);
+    } else {
+        assert_eq!(pepet, GCBProperty::None);
+    }
+    assert!(CharProperties::UNICODE_VERSION <= UnicodeVersion::CURRENT);
+}
+
+#[test]
+#[cfg(feature = "unicode-15-1")]
+fn unicode_15_1_changes() {
+    // Unicode 16.0 extended Indic_Conjunct_Break=Extend to the extending
+    // characters whose canonical combining class is zero, like U+0941
+    // DEVANAGARI VOWEL SIGN U, which have no InCB value in Unicode 15.1.
+    let props = |version, c| CharProperties::for_char_in(version, c).unwrap();
+    let v15_1 = UnicodeVersion::new(15, 1);
+    assert_eq!(props(v15_1, '\u{941}').gcb_property(), GCBProperty::Extend);
+    assert_eq!(props(v15_1, '\u{941}').incb_property(), InCBProperty::None);
+    assert_eq!(props(v15_1, '\u{34F}').incb_property(), InCBProperty::None);
+    assert_eq!(
+        props(v15_1, '\u{300}').incb_property(),
+        InCBProperty::Extend
+    );
+    assert_eq!(
+        props(v15_1, '\u{200D}').incb_property(),
+        InCBProperty::Extend
+    );
+    assert_eq!(
+        props(v15_1, '\u{94D}').incb_property(),
+        InCBProperty::Linker
+    );
+    assert_eq!(
+        props(v15_1, '\u{915}').incb_property(),
+        InCBProperty::Consonant
+    );
+
+    #[cfg(feature = "unicode-16-0")]
+    {
+        let v16_0 = UnicodeVersion::new(16, 0);
+        assert_eq!(
+            props(v16_0, '\u{941}').incb_property(),
+            InCBProperty::Extend
+        );
+        assert_eq!(
+            props(v16_0, '\u{34F}').incb_property(),
+            InCBProperty::Extend
+        );
+        assert_eq!(
+            props(v16_0, '\u{300}').incb_property(),
+            InCBProperty::Extend
+        );
+    }
+}
+
+#[test]
+fn property_tables() {
+    assert_eq!(
+        PropertyTable::DEFAULT.version(),
+        CharProperties::UNICODE_VERSION
+    );
+    assert!(PropertyTable::ALL.contains(&PropertyTable::DEFAULT));
+    assert!(PropertyTable::ALL.is_sorted_by_key(|table| table.version()));
+    for &table in PropertyTable::ALL {
+        assert_eq!(PropertyTable::for_version(table.version()), Some(table));
+        assert_eq!(table.size() % 64, 0);
+    }
+    assert_eq!(PropertyTable::DEFAULT.size(), CharProperties::TABLE_SIZE);
+    assert_eq!(PropertyTable::for_version(UnicodeVersion::new(1, 0)), None);
+
+    let version = CharProperties::UNICODE_VERSION;
+    for c in ['a', '\u{301}', '\u{897}', '👍'] {
+        assert_eq!(
+            CharProperties::for_char_in(version, c),
+            Some(CharProperties::for_char(c))
+        );
+    }
+    assert_eq!(
+        CharProperties::for_char_in(UnicodeVersion::new(1, 0), 'a'),
+        None
+    );
+}
+
+#[cfg(all(feature = "unicode-15-1", feature = "unicode-16-0"))]
+#[test]
+fn property_tables_differ() {
+    let pepet = |major, minor| {
+        CharProperties::for_char_in(UnicodeVersion::new(major, minor), '\u{897}')
+            .unwrap()
+            .gcb_property()
+    };
+    assert_eq!(pepet(15, 1), GCBProperty::None);
+    assert_eq!(pepet(16, 0), GCBProperty::Extend);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+    let props = CharProperties::new(GCBProperty::Extend, InCBProperty::Linker);
+    assert_tokens(&props, &[Token::U16(props.to_raw())]);
+    assert_de_tokens_error::<CharProperties>(
+        &[Token::U16(0xffff)],
+        &InvalidPropertiesError { raw: 0xffff }.to_string(),
+    );
+    assert_tokens(
+        &GCBProperty::ZWJ,
+        &[Token::UnitVariant {
+            name: "GCBProperty",
+            variant: "ZWJ",
+        }],
+    );
+    assert_tokens(
+        &InCBProperty::Consonant,
+        &[Token::UnitVariant {
+            name: "InCBProperty",
+            variant: "Consonant",
+        }],
+    );
+}
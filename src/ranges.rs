@@ -0,0 +1,122 @@
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+use crate::{GCBProperty, InCBProperty, PropertyTable};
+
+impl GCBProperty {
+    /// Returns an iterator over the ranges of characters that have this
+    /// property value in [`PropertyTable::DEFAULT`], in order, such as for
+    /// generating a regular expression character class or a table for some
+    /// other language from this library's data.
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    pub const fn ranges(self) -> PropertyRanges {
+        PropertyTable::DEFAULT.gcb_ranges(self)
+    }
+}
+
+impl InCBProperty {
+    /// Returns an iterator over the ranges of characters that have this
+    /// property value in [`PropertyTable::DEFAULT`], in order, in the same
+    /// way as [`GCBProperty::ranges`].
+    ///
+    /// This is available only when the `tables` feature is enabled.
+    pub const fn ranges(self) -> PropertyRanges {
+        PropertyTable::DEFAULT.incb_ranges(self)
+    }
+}
+
+impl PropertyTable {
+    /// Returns an iterator over the ranges of characters that have the
+    /// given **Grapheme_Cluster_Break** property value in this table.
+    pub const fn gcb_ranges(self, gcb: GCBProperty) -> PropertyRanges {
+        PropertyRanges::new(self, Matcher::Gcb(gcb))
+    }
+
+    /// Returns an iterator over the ranges of characters that have the
+    /// given **Indic_Conjunct_Break** property value in this table.
+    pub const fn incb_ranges(self, incb: InCBProperty) -> PropertyRanges {
+        PropertyRanges::new(self, Matcher::Incb(incb))
+    }
+}
+
+/// An iterator over the ranges of characters that have a particular
+/// property value, reconstructed from one of this library's lookup tables.
+///
+/// Each range is as long as possible, so consecutive ranges are always
+/// separated by at least one character that lacks the property. The
+/// surrogate code points can't be looked up, and so a range containing
+/// both U+D7FF and U+E000 also spans the surrogates between them.
+///
+/// Construct this using [`GCBProperty::ranges`], [`InCBProperty::ranges`],
+/// or the similar methods of [`PropertyTable`]. Each complete iteration
+/// looks up every character, and so callers that need the ranges more than
+/// once should collect them.
+///
+/// This is available only when the `tables` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct PropertyRanges {
+    table: PropertyTable,
+    matcher: Matcher,
+    /// The first character not yet examined, or `None` once all of them
+    /// have been.
+    next: Option<char>,
+}
+
+/// The property value that a [`PropertyRanges`] is looking for.
+#[derive(Debug, Clone, Copy)]
+enum Matcher {
+    Gcb(GCBProperty),
+    Incb(InCBProperty),
+}
+
+impl PropertyRanges {
+    const fn new(table: PropertyTable, matcher: Matcher) -> Self {
+        Self {
+            table,
+            matcher,
+            next: Some('\0'),
+        }
+    }
+
+    fn matches(&self, c: char) -> bool {
+        let props = self.table.for_char(c);
+        match self.matcher {
+            Matcher::Gcb(gcb) => props.gcb_property() == gcb,
+            Matcher::Incb(incb) => props.incb_property() == incb,
+        }
+    }
+}
+
+impl Iterator for PropertyRanges {
+    type Item = RangeInclusive<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = self.next?;
+        while !self.matches(start) {
+            self.next = successor(start);
+            start = self.next?;
+        }
+        let mut end = start;
+        self.next = loop {
+            match successor(end) {
+                Some(c) if self.matches(c) => end = c,
+                next => break next,
+            }
+        };
+        Some(start..=end)
+    }
+}
+
+impl FusedIterator for PropertyRanges {}
+
+/// Returns the character after `c`, skipping the surrogates.
+fn successor(c: char) -> Option<char> {
+    match c {
+        '\u{D7FF}' => Some('\u{E000}'),
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::CharProperties;
+
+#[test]
+fn gcb_ranges() {
+    let regional: Vec<_> = GCBProperty::RegionalIndicator.ranges().collect();
+    assert_eq!(regional, ['\u{1F1E6}'..='\u{1F1FF}']);
+
+    let mut pictographic = GCBProperty::ExtendedPictographic.ranges();
+    assert_eq!(pictographic.next(), Some('\u{A9}'..='\u{A9}'));
+    assert_eq!(pictographic.next(), Some('\u{AE}'..='\u{AE}'));
+
+    let cr: Vec<_> = GCBProperty::CR.ranges().collect();
+    assert_eq!(cr, ['\r'..='\r']);
+}
+
+#[test]
+fn incb_ranges() {
+    let linkers: Vec<_> = InCBProperty::Linker.ranges().collect();
+    assert!(linkers.contains(&('\u{94D}'..='\u{94D}')));
+    for range in linkers {
+        for c in range {
+            assert_eq!(
+                CharProperties::for_char(c).incb_property(),
+                InCBProperty::Linker,
+                "{c:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn cover_every_character() {
+    use GCBProperty::*;
+
+    let mut count = 0;
+    for gcb in [
+        None,
+        CR,
+        Control,
+        Extend,
+        ExtendedPictographic,
+        L,
+        LF,
+        LV,
+        LVT,
+        Prepend,
+        RegionalIndicator,
+        SpacingMark,
+        T,
+        V,
+        ZWJ,
+    ] {
+        let mut prev_end: Option<char> = Option::None;
+        for range in PropertyTable::DEFAULT.gcb_ranges(gcb) {
+            assert!(range.start() <= range.end());
+            // Ranges are in order and never adjacent.
+            if let Some(prev_end) = prev_end {
+                assert!(successor(prev_end).unwrap() < *range.start());
+            }
+            prev_end = Some(*range.end());
+            count += range.count();
+        }
+    }
+    assert_eq!(count, char::MAX as usize + 1 - 0x800);
+}
+
+#[test]
+fn successors() {
+    assert_eq!(successor('a'), Some('b'));
+    assert_eq!(successor('\u{D7FF}'), Some('\u{E000}'));
+    assert_eq!(successor(char::MAX), Option::None);
+}
@@ -0,0 +1,328 @@
+//! An adapter for reading grapheme clusters from a [`std::io::Read`].
+
+use std::io::{self, BufRead, BufReader, Read};
+
+use ::u8char::AsU8Chars;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, InvalidUtf8Error, SegmentAction, Segmenter};
+
+/// Reads grapheme clusters from a [`std::io::Read`] containing UTF-8 text,
+/// such as standard input or a file.
+///
+/// [`Self::next_cluster`] borrows each cluster from a buffer inside the
+/// reader that's reused for every cluster, [`Self::read_cluster`] appends
+/// each cluster to a buffer provided by the caller, and the reader is also
+/// an [`Iterator`] over owned clusters, for convenience when allocating a
+/// string for each cluster doesn't matter.
+///
+/// The reader is wrapped in a [`BufReader`], so there's no need to wrap it
+/// in another one first. The source can deliver text in arbitrary pieces,
+/// even ending partway through a UTF-8 sequence or a grapheme cluster.
+///
+/// The reader uses a [`GraphemeMachine`] by default, but
+/// [`Self::with_machine`] accepts any other [`Segmenter`], in which case
+/// each "cluster" is one of that machine's segments.
+///
+/// This is available only when the `std` feature is enabled.
+#[derive(Debug)]
+pub struct GraphemeReader<R, S = GraphemeMachine> {
+    inner: BufReader<R>,
+    state: ReaderState<S>,
+    failed: bool,
+}
+
+impl<R: Read> GraphemeReader<R> {
+    /// Wraps the given reader, using a new [`GraphemeMachine`] in its
+    /// initial "start of input" state.
+    pub fn new(inner: R) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+
+    /// Wraps the given reader with a buffer of the given capacity, which
+    /// is how many bytes at most are read from it at once.
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self::with_capacity_and_machine(capacity, GraphemeMachine::new(), inner)
+    }
+}
+
+impl<R: Read, S: Segmenter> GraphemeReader<R, S> {
+    /// Wraps the given reader using an existing machine, which should be in
+    /// its "start of input" state, such as a [`Segmenter`] other than
+    /// [`GraphemeMachine`].
+    pub fn with_machine(machine: S, inner: R) -> Self {
+        Self::with_buffer(BufReader::new(inner), machine)
+    }
+
+    /// Like [`Self::with_capacity`], but using an existing machine as for
+    /// [`Self::with_machine`].
+    pub fn with_capacity_and_machine(capacity: usize, machine: S, inner: R) -> Self {
+        Self::with_buffer(BufReader::with_capacity(capacity, inner), machine)
+    }
+
+    fn with_buffer(inner: BufReader<R>, machine: S) -> Self {
+        Self {
+            inner,
+            state: ReaderState::new(machine),
+            failed: false,
+        }
+    }
+
+    /// Reads the next complete grapheme cluster, or returns `None` once the
+    /// reader has reached the end of its input.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] wrapping an
+    /// [`InvalidUtf8Error`] if the input isn't valid UTF-8, with offsets
+    /// relative to where this reader began reading. Errors from the inner
+    /// reader are returned as-is.
+    pub fn next_cluster(&mut self) -> io::Result<Option<&str>> {
+        let state = &mut self.state;
+        state.begin();
+        loop {
+            let buf = self.inner.fill_buf()?;
+            match state.feed(buf)? {
+                ReaderStep::More(used) => self.inner.consume(used),
+                ReaderStep::Cluster(used) => {
+                    self.inner.consume(used);
+                    return Ok(Some(&state.cluster));
+                }
+                ReaderStep::End => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns the byte offset in the input where the cluster most recently
+    /// returned by [`Self::next_cluster`] begins, for reporting positions
+    /// within the input.
+    ///
+    /// Before the first cluster is returned, and after the end of the
+    /// input, this is the offset just after the last cluster returned.
+    pub fn cluster_offset(&self) -> usize {
+        self.state.cluster_offset
+    }
+
+    /// Reads the next complete grapheme cluster and appends it to `buf`,
+    /// returning its length in bytes, or zero once the reader has reached
+    /// the end of its input.
+    ///
+    /// This returns errors in the same way as [`Self::next_cluster`], in
+    /// which case `buf` is unchanged.
+    pub fn read_cluster(&mut self, buf: &mut String) -> io::Result<usize> {
+        let cluster = self.next_cluster()?.unwrap_or("");
+        buf.push_str(cluster);
+        Ok(cluster.len())
+    }
+
+    /// Consumes the adapter and returns the inner reader.
+    ///
+    /// Any bytes that have been read from the inner reader but not yet
+    /// returned as part of a cluster are lost.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+/// Yields each cluster as a separate [`String`].
+///
+/// The iterator ends after returning an error, rather than returning the
+/// same error again.
+impl<R: Read, S: Segmenter> Iterator for GraphemeReader<R, S> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let next = self.next_cluster().map(|cluster| cluster.map(String::from));
+        self.failed = next.is_err();
+        next.transpose()
+    }
+}
+
+impl<R: Read, S: Segmenter> core::iter::FusedIterator for GraphemeReader<R, S> {}
+
+/// What [`ReaderState::feed`] did with a buffer from the reader.
+pub(crate) enum ReaderStep {
+    /// The given number of bytes were used without completing a cluster.
+    More(usize),
+    /// The given number of bytes were used and then a cluster was complete.
+    Cluster(usize),
+    /// The input ended with no cluster left to return.
+    End,
+}
+
+/// The part of a cluster reader other than the inner reader, separated
+/// so that it can be updated while borrowing the reader's buffer and shared
+/// between [`GraphemeReader`] and the `tokio` feature's
+/// `AsyncClusterReader`.
+#[derive(Debug)]
+pub(crate) struct ReaderState<S> {
+    machine: S,
+    // The cluster in progress, or the cluster most recently returned if
+    // `returned` is set.
+    pub(crate) cluster: String,
+    returned: bool,
+    // The first character of the next cluster, read while finding the end
+    // of the one in `cluster`.
+    pending: Option<u8char>,
+    // An incomplete UTF-8 sequence from the end of the previous buffer.
+    partial: [u8; 4],
+    partial_len: usize,
+    // How many bytes at the start of the reader's current buffer are
+    // already known to be valid UTF-8, so that each buffer is validated
+    // only once however many clusters it contains.
+    validated: usize,
+    // The number of bytes consumed from the reader so far, for reporting
+    // the positions of errors.
+    offset: usize,
+    // The offset of the start of `cluster` in the input.
+    pub(crate) cluster_offset: usize,
+}
+
+impl<S: Segmenter> ReaderState<S> {
+    pub(crate) fn new(machine: S) -> Self {
+        Self {
+            machine,
+            cluster: String::new(),
+            returned: false,
+            pending: None,
+            partial: [0; 4],
+            partial_len: 0,
+            validated: 0,
+            offset: 0,
+            cluster_offset: 0,
+        }
+    }
+
+    /// Prepares to read a new cluster, discarding the one most recently
+    /// returned, if any.
+    pub(crate) fn begin(&mut self) {
+        if self.returned {
+            self.returned = false;
+            self.cluster_offset += self.cluster.len();
+            self.cluster.clear();
+            if let Some(c) = self.pending.take() {
+                self.cluster.push_str(c.as_str());
+            }
+        }
+    }
+
+    /// Processes bytes from the start of `buf`, the reader's current buffer,
+    /// which is empty only at the end of the input.
+    ///
+    /// The caller must consume exactly the number of bytes reported as used
+    /// before calling this again, because the state remembers how much of
+    /// the rest of the buffer it has already validated.
+    ///
+    /// Once this returns [`ReaderStep::Cluster`], [`Self::cluster`] is the
+    /// complete cluster until the next call to [`Self::begin`].
+    pub(crate) fn feed(&mut self, buf: &[u8]) -> io::Result<ReaderStep> {
+        if buf.is_empty() {
+            if self.partial_len > 0 {
+                return Err(invalid_utf8(self.offset - self.partial_len, None));
+            }
+            self.machine.end_of_input();
+            if self.cluster.is_empty() {
+                return Ok(ReaderStep::End);
+            }
+            self.returned = true;
+            return Ok(ReaderStep::Cluster(0));
+        }
+
+        let (used, done) = if self.partial_len > 0 {
+            self.complete_partial(buf)?
+        } else {
+            self.process(buf)?
+        };
+        self.offset += used;
+        if done {
+            self.returned = true;
+            return Ok(ReaderStep::Cluster(used));
+        }
+        Ok(ReaderStep::More(used))
+    }
+
+    /// Processes characters from the start of `buf`, returning how many
+    /// bytes were used and whether a cluster is now complete.
+    fn process(&mut self, buf: &[u8]) -> io::Result<(usize, bool)> {
+        if self.validated == 0 {
+            self.validated = match core::str::from_utf8(buf) {
+                Ok(_) => buf.len(),
+                Err(err) if err.valid_up_to() > 0 => err.valid_up_to(),
+                Err(err) => {
+                    if err.error_len().is_some() {
+                        return Err(invalid_utf8(self.offset, err.error_len()));
+                    }
+                    // An incomplete sequence at the end of the buffer.
+                    self.partial[..buf.len()].copy_from_slice(buf);
+                    self.partial_len = buf.len();
+                    return Ok((buf.len(), false));
+                }
+            };
+        }
+        // Safety: these bytes were validated either just now or by an
+        // earlier call that used only some of them.
+        let valid = unsafe { core::str::from_utf8_unchecked(&buf[..self.validated]) };
+        let mut used = 0;
+        let mut done = false;
+        for c in valid.u8chars() {
+            used += c.len();
+            if self.machine.next_u8char(c).is_boundary() && !self.cluster.is_empty() {
+                self.pending = Some(c);
+                done = true;
+                break;
+            }
+            self.cluster.push_str(c.as_str());
+        }
+        self.validated -= used;
+        Ok((used, done))
+    }
+
+    /// Adds bytes from the start of `buf` to the incomplete sequence left
+    /// over from the previous buffer, processing the resulting character
+    /// if it's now complete.
+    fn complete_partial(&mut self, buf: &[u8]) -> io::Result<(usize, bool)> {
+        let want = match self.partial[0] {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        let used = buf.len().min(want - self.partial_len);
+        self.partial[self.partial_len..self.partial_len + used].copy_from_slice(&buf[..used]);
+        let len = self.partial_len + used;
+        match core::str::from_utf8(&self.partial[..len]) {
+            Ok(s) => {
+                self.partial_len = 0;
+                // from_utf8 succeeded, so there's exactly one character.
+                let c = u8char::from_string_prefix(s).0.unwrap();
+                if self.machine.next_u8char(c).is_boundary() && !self.cluster.is_empty() {
+                    self.pending = Some(c);
+                    return Ok((used, true));
+                }
+                self.cluster.push_str(c.as_str());
+                Ok((used, false))
+            }
+            Err(err) if err.error_len().is_some() => Err(invalid_utf8(
+                self.offset - self.partial_len + err.valid_up_to(),
+                err.error_len(),
+            )),
+            Err(_) => {
+                self.partial_len = len;
+                Ok((used, false))
+            }
+        }
+    }
+}
+
+fn invalid_utf8(valid_up_to: usize, error_len: Option<usize>) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        InvalidUtf8Error {
+            valid_up_to,
+            error_len,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,108 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn clusters(input: &[u8], capacity: usize) -> io::Result<Vec<String>> {
+    let mut reader = GraphemeReader::with_capacity(capacity, input);
+    let mut ret = Vec::new();
+    while let Some(cluster) = reader.next_cluster()? {
+        ret.push(cluster.to_string());
+    }
+    // Once the input is exhausted, it stays exhausted.
+    assert_eq!(reader.next_cluster()?, None);
+    Ok(ret)
+}
+
+#[test]
+fn read_clusters() {
+    let input = "a\r\ne\u{0301}🇦🇧🧑‍🌾!".as_bytes();
+    let want = &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"];
+    // A buffer capacity of one byte means that each multi-byte character
+    // arrives in pieces.
+    for capacity in [1, 2, 3, 5, 64] {
+        assert_eq!(
+            clusters(input, capacity).unwrap(),
+            want,
+            "capacity {capacity}"
+        );
+    }
+    assert_eq!(clusters(b"", 8).unwrap(), &[] as &[&str]);
+}
+
+#[test]
+fn read_cluster() {
+    let mut reader = GraphemeReader::new("e\u{0301}x".as_bytes());
+    let mut buf = String::from(">");
+    assert_eq!(reader.read_cluster(&mut buf).unwrap(), 3);
+    assert_eq!(reader.read_cluster(&mut buf).unwrap(), 1);
+    assert_eq!(reader.read_cluster(&mut buf).unwrap(), 0);
+    assert_eq!(buf, ">e\u{0301}x");
+}
+
+#[test]
+fn iterator() {
+    let reader = GraphemeReader::with_capacity(2, "🇦🇧\r\n".as_bytes());
+    let got: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+    assert_eq!(got, &["🇦🇧", "\r\n"]);
+
+    // The iterator ends after an error instead of repeating it.
+    let mut reader = GraphemeReader::new(&b"ab\xffc"[..]);
+    assert_eq!(reader.next().unwrap().unwrap(), "a");
+    assert_eq!(
+        reader.next().unwrap().unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn invalid_utf8() {
+    let utf8_err = |input: &[u8], capacity| {
+        let err = clusters(input, capacity).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        *err.into_inner()
+            .unwrap()
+            .downcast::<InvalidUtf8Error>()
+            .unwrap()
+    };
+    let want = |valid_up_to, error_len| InvalidUtf8Error {
+        valid_up_to,
+        error_len,
+    };
+    for capacity in [1, 64] {
+        assert_eq!(utf8_err(b"ab\xffc", capacity), want(2, Some(1)));
+        assert_eq!(utf8_err(b"ab\xe2\x82x", capacity), want(2, Some(2)));
+        assert_eq!(utf8_err(b"ab\xe2\x82", capacity), want(2, None));
+    }
+}
+
+#[test]
+fn cluster_offsets() {
+    let input = "a\r\ne\u{0301}🇦🇧".as_bytes();
+    let mut reader = GraphemeReader::with_capacity(1, input);
+    let mut got = Vec::new();
+    while let Some(cluster) = reader.next_cluster().unwrap() {
+        let cluster = cluster.to_string();
+        got.push((reader.cluster_offset(), cluster));
+    }
+    let want = [(0, "a"), (1, "\r\n"), (3, "e\u{0301}"), (6, "🇦🇧")];
+    assert_eq!(got, want.map(|(offset, s)| (offset, s.to_string())));
+    assert_eq!(reader.cluster_offset(), input.len());
+}
+
+#[test]
+fn many_clusters_per_buffer() {
+    // Each buffer is validated once and then shared by all of the clusters
+    // in it, including when it ends partway through a character.
+    let mut input = "e\u{0301}🇦🇧x".repeat(100).into_bytes();
+    for capacity in [7, 64, 8192] {
+        let got = clusters(&input, capacity).unwrap();
+        assert_eq!(got.len(), 300, "capacity {capacity}");
+        assert_eq!(got.concat().as_bytes(), input, "capacity {capacity}");
+    }
+
+    input.extend_from_slice(b"ab\xff");
+    let err = clusters(&input, 64).unwrap_err();
+    let err = err.into_inner().unwrap().downcast::<InvalidUtf8Error>();
+    assert_eq!(err.unwrap().valid_up_to, input.len() - 1);
+}
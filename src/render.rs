@@ -0,0 +1,85 @@
+use core::iter::{Fuse, FusedIterator};
+
+/// An iterator adapter that passes through the grapheme clusters that a
+/// caller can render, and replaces the others with a fallback chosen by a
+/// callback.
+///
+/// This is a policy layer between segmentation and drawing, for output
+/// devices that support only some clusters, such as a terminal without
+/// emoji support or a display with a font covering only a few scripts.
+/// The `can_render` predicate decides whether each cluster is supported,
+/// and `fallback` returns what to draw instead of each one that isn't.
+/// The fallback for a cluster can be a part of the cluster itself, such as
+/// from [`first_char_fallback`], or a static string like `"\u{FFFD}"`.
+///
+/// The inner iterator can produce clusters from any source, such as
+/// [`StrClusters`](crate::StrClusters), whose
+/// [`render_fallback`](crate::StrClusters::render_fallback) method is a
+/// shorthand for constructing this adapter.
+#[derive(Debug, Clone)]
+pub struct RenderFallback<I, P, F> {
+    inner: Fuse<I>,
+    can_render: P,
+    fallback: F,
+}
+
+impl<'a, I, P, F> RenderFallback<I, P, F>
+where
+    I: Iterator<Item = &'a str>,
+    P: FnMut(&str) -> bool,
+    F: FnMut(&'a str) -> &'a str,
+{
+    /// Wraps the given iterator of grapheme clusters.
+    pub fn new(inner: impl IntoIterator<IntoIter = I>, can_render: P, fallback: F) -> Self {
+        Self {
+            inner: inner.into_iter().fuse(),
+            can_render,
+            fallback,
+        }
+    }
+}
+
+impl<'a, I, P, F> Iterator for RenderFallback<I, P, F>
+where
+    I: Iterator<Item = &'a str>,
+    P: FnMut(&str) -> bool,
+    F: FnMut(&'a str) -> &'a str,
+{
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let cluster = self.inner.next()?;
+        match (self.can_render)(cluster) {
+            true => Some(cluster),
+            false => Some((self.fallback)(cluster)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I, P, F> FusedIterator for RenderFallback<I, P, F>
+where
+    I: Iterator<Item = &'a str>,
+    P: FnMut(&str) -> bool,
+    F: FnMut(&'a str) -> &'a str,
+{
+}
+
+/// A fallback for use with [`RenderFallback`] that returns only the first
+/// character of the cluster, discarding any combining marks, joined
+/// characters, and other extensions of it.
+///
+/// This often gives a reasonable approximation of the cluster, such as
+/// "e" for "e\u{0301}" or "🧑" for "🧑‍🌾", but might itself be
+/// unrenderable. Wrap it in a closure that checks the result to fall back
+/// further, such as to U+FFFD REPLACEMENT CHARACTER.
+pub fn first_char_fallback(cluster: &str) -> &str {
+    let len = cluster.chars().next().map_or(0, char::len_utf8);
+    &cluster[..len]
+}
+
+#[cfg(test)]
+mod tests;
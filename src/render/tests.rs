@@ -0,0 +1,33 @@
+use super::*;
+use crate::clusters_in_str;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn fallbacks() {
+    // A device that can only draw single characters from the Latin-1 range.
+    let latin1 = |c: &str| c.chars().count() == 1 && c.chars().all(|c| c <= '\u{FF}');
+
+    let got: String = clusters_in_str("café e\u{0301} 🧑‍🌾")
+        .render_fallback(latin1, first_char_fallback)
+        .collect();
+    assert_eq!(got, "café e 🧑");
+
+    let got: String = clusters_in_str("café e\u{0301} 🧑‍🌾")
+        .render_fallback(latin1, |c| match first_char_fallback(c) {
+            first if latin1(first) => first,
+            _ => "?",
+        })
+        .collect();
+    assert_eq!(got, "café e ?");
+}
+
+#[test]
+fn any_clusters() {
+    let clusters = ["a", "\u{1F1E6}\u{1F1E7}", "b"];
+    let mut iter = RenderFallback::new(clusters, |c: &str| c.is_ascii(), |_| "\u{FFFD}");
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), &["a", "\u{FFFD}", "b"]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(first_char_fallback(""), "");
+}
@@ -0,0 +1,214 @@
+#[cfg(feature = "tables")]
+use u8char::u8char;
+
+use crate::state::State;
+use crate::{CharProperties, GCBProperty, InCBProperty};
+
+/// Describes what can be determined about whether there's a grapheme cluster
+/// boundary between two adjacent characters, based only on the properties of
+/// those two characters.
+///
+/// Most of the segmentation rules need only the two characters on either side
+/// of a position, but a few of them need to know about arbitrarily-many
+/// earlier characters:
+///
+/// - [GB9c](https://www.unicode.org/reports/tr29/#GB9c), for Indic conjuncts,
+///   which needs to know whether there was a consonant and a linker before
+///   a run of extending characters.
+/// - [GB11](https://www.unicode.org/reports/tr29/#GB11), for emoji ZWJ
+///   sequences, which needs to know whether there was an extended pictographic
+///   character before a run of extending characters.
+/// - [GB12 and GB13](https://www.unicode.org/reports/tr29/#GB12), for emoji
+///   flags, which need to know whether an odd or even number of regional
+///   indicators preceded the current pair.
+///
+/// This is useful for starting segmentation from an arbitrary position in
+/// some larger text, such as when dividing a large buffer into chunks to
+/// process in parallel, or when segmenting backwards from some point in
+/// a buffer. Whenever the result is [`PairBoundary::Boundary`] a new
+/// [`GraphemeMachine`](crate::GraphemeMachine) can begin segmenting from the
+/// second character and its results will be identical to those of a machine
+/// that had been fed all of the preceding text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairBoundary {
+    /// There is definitely a grapheme cluster boundary between the two
+    /// characters, regardless of what came before them.
+    Boundary,
+    /// There is definitely not a grapheme cluster boundary between the two
+    /// characters, regardless of what came before them.
+    NoBoundary,
+    /// Whether there's a boundary depends on characters that appeared before
+    /// the first of the two. Use [`boundary_before`] to resolve it.
+    NeedsContext,
+}
+
+impl PairBoundary {
+    /// Determines what can be known about the boundary between two adjacent
+    /// characters with the given properties, without any further context.
+    pub fn between(prev: CharProperties, next: CharProperties) -> Self {
+        // Only the states where one of the context-sensitive rules is active
+        // can change the outcome relative to the base state, so if all of
+        // them agree then the outcome can't depend on earlier characters.
+        let (boundary, _) = State::Base.transition(prev, next);
+        let depends = [
+            State::GB9cLinker,
+            State::GB11AfterZWJ,
+            State::AwaitEmojiFlag,
+        ]
+        .into_iter()
+        .any(|state| state.transition(prev, next).0 != boundary);
+        match (depends, boundary) {
+            (true, _) => Self::NeedsContext,
+            (false, true) => Self::Boundary,
+            (false, false) => Self::NoBoundary,
+        }
+    }
+
+    /// Returns `true` if it's safe to begin segmenting from the second of
+    /// the two characters using a fresh state machine.
+    pub const fn is_safe_restart(self) -> bool {
+        matches!(self, Self::Boundary)
+    }
+}
+
+/// Determines whether there's a grapheme cluster boundary immediately before
+/// a character with properties `next`, given the properties of the characters
+/// that preceded it in **reverse order**, starting with the character
+/// immediately before `next`.
+///
+/// This consumes only as many items from `preceding` as are needed to make a
+/// decision, which in most cases is just one. If `preceding` is exhausted then
+/// that is treated as the start of the text, so callers that have only a
+/// window of a larger text should use [`PairBoundary::between`] to find a
+/// position where no further context is needed.
+pub fn boundary_before(
+    next: CharProperties,
+    preceding: impl IntoIterator<Item = CharProperties>,
+) -> bool {
+    let mut preceding = preceding.into_iter();
+    let Some(prev) = preceding.next() else {
+        // At start of input there's always a boundary.
+        return true;
+    };
+    match PairBoundary::between(prev, next) {
+        PairBoundary::Boundary => true,
+        PairBoundary::NoBoundary => false,
+        PairBoundary::NeedsContext => {
+            use GCBProperty::*;
+            match (prev.gcb_property(), next.gcb_property()) {
+                (RegionalIndicator, RegionalIndicator) => {
+                    // GB12 and GB13: the new character completes a flag only
+                    // if there's an odd number of regional indicators before
+                    // it, counting `prev`.
+                    let count = 1 + preceding
+                        .take_while(|p| matches!(p.gcb_property(), RegionalIndicator))
+                        .count();
+                    count % 2 == 0
+                }
+                (ZWJ, ExtendedPictographic) => {
+                    // GB11: \p{ExtPict} Extend* ZWJ × \p{ExtPict}
+                    let before = preceding.find(|p| !matches!(p.gcb_property(), Extend));
+                    !matches!(
+                        before.map(CharProperties::gcb_property),
+                        Some(ExtendedPictographic)
+                    )
+                }
+                _ => {
+                    // GB9c: Consonant [Extend Linker]* Linker [Extend Linker]* × Consonant
+                    let mut linker = matches!(prev.incb_property(), InCBProperty::Linker);
+                    for p in preceding {
+                        match p.incb_property() {
+                            InCBProperty::Linker => linker = true,
+                            InCBProperty::Extend => {}
+                            InCBProperty::Consonant => return !linker,
+                            InCBProperty::None => return true,
+                        }
+                    }
+                    true
+                }
+            }
+        }
+    }
+}
+
+/// Returns the smallest byte offset that is greater than or equal to `index`
+/// at which a new [`GraphemeMachine`](crate::GraphemeMachine) could begin
+/// segmenting `s` without any knowledge of the text before that offset,
+/// and get the same results as if it had segmented all of `s`.
+///
+/// The start and the end of the string are always safe restart points, so
+/// this always returns a value no greater than `s.len()`. If `index` is not
+/// on a character boundary then the search begins at the next character
+/// boundary.
+///
+/// This is intended for dividing a large text into chunks that can be
+/// segmented independently, such as in parallel: choose approximate split
+/// points and then adjust each one using this function. The search only
+/// looks forward, and usually needs to examine only one or two characters.
+///
+/// This is available only when the `tables` feature is enabled.
+#[cfg(feature = "tables")]
+pub fn next_safe_restart(s: &str, index: usize) -> usize {
+    let mut index = index;
+    while index < s.len() && !s.is_char_boundary(index) {
+        index += 1;
+    }
+    if index >= s.len() {
+        return s.len();
+    }
+    let Some(prev) = s[..index].chars().next_back() else {
+        // The start of the string is always a safe restart point.
+        return 0;
+    };
+    let mut prev = u8char::from_char(prev);
+    let mut remain = &s[index..];
+    while let (Some(next), rest) = u8char::from_string_prefix(remain) {
+        let pair = PairBoundary::between(
+            CharProperties::for_u8char(prev),
+            CharProperties::for_u8char(next),
+        );
+        if pair.is_safe_restart() {
+            return s.len() - remain.len();
+        }
+        prev = next;
+        remain = rest;
+    }
+    s.len()
+}
+
+/// Returns the largest byte offset that is less than or equal to `index` at
+/// which a new [`GraphemeMachine`](crate::GraphemeMachine) could begin
+/// segmenting `s` without any knowledge of the text before that offset,
+/// and get the same results as if it had segmented all of `s`.
+///
+/// This is the backward-searching equivalent of [`next_safe_restart`], useful
+/// for finding somewhere to begin segmenting when the goal is to learn about
+/// the cluster boundaries around a particular offset. If `index` is not on a
+/// character boundary then the search begins at the previous character
+/// boundary, and if `index` is beyond the end of the string then the search
+/// begins at the end of the string.
+///
+/// This is available only when the `tables` feature is enabled.
+#[cfg(feature = "tables")]
+pub fn prev_safe_restart(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    let Some(next) = s[index..].chars().next() else {
+        // The end of the string is always a safe restart point.
+        return index;
+    };
+    let mut next = CharProperties::for_char(next);
+    for (i, c) in s[..index].char_indices().rev() {
+        let prev = CharProperties::for_char(c);
+        if PairBoundary::between(prev, next).is_safe_restart() {
+            return i + c.len_utf8();
+        }
+        next = prev;
+    }
+    0
+}
+
+#[cfg(all(test, feature = "tables"))]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::{ClusterAction, GraphemeMachine};
+
+use pretty_assertions::assert_eq;
+
+fn test_inputs() -> impl Iterator<Item = &'static str> {
+    crate::properties::test_table::UNICODE_GRAPHEME_CLUSTER_TESTS
+        .iter()
+        .map(|test| str::from_utf8(test.input).expect("invalid UTF-8 in test input"))
+        .chain([
+            "🇦🇧🇨🇩🇪",
+            "a🇦🇧🇨",
+            "😀\u{0308}\u{0308}\u{200D}😀\u{200D}😀",
+            "\u{0915}\u{094D}\u{200D}\u{094D}\u{0924}\u{094D}\u{0915}",
+        ])
+}
+
+fn splits(s: &str) -> Vec<bool> {
+    let mut machine = GraphemeMachine::new();
+    machine
+        .next_u8chars_from_str(s)
+        .map(|(a, _)| a == ClusterAction::Split)
+        .collect()
+}
+
+#[test]
+fn boundary_before_matches_machine() {
+    for input in test_inputs() {
+        let props: Vec<_> = input.chars().map(CharProperties::for_char).collect();
+        let want = splits(input);
+        let got: Vec<_> = (0..props.len())
+            .map(|i| boundary_before(props[i], props[..i].iter().rev().copied()))
+            .collect();
+        assert_eq!(got, want, "wrong boundaries for {input:?}");
+    }
+}
+
+#[test]
+fn pair_boundary() {
+    use PairBoundary::*;
+    let p = CharProperties::for_char;
+    assert_eq!(PairBoundary::between(p('a'), p('b')), Boundary);
+    assert_eq!(PairBoundary::between(p('\r'), p('\n')), NoBoundary);
+    assert_eq!(PairBoundary::between(p('e'), p('\u{0301}')), NoBoundary);
+    assert_eq!(PairBoundary::between(p('🇦'), p('🇧')), NeedsContext);
+    assert_eq!(PairBoundary::between(p('\u{200D}'), p('😀')), NeedsContext);
+    assert_eq!(
+        PairBoundary::between(p('\u{094D}'), p('\u{0924}')),
+        NeedsContext
+    );
+    assert_eq!(PairBoundary::between(p('😀'), p('🇦')), Boundary);
+}
+
+#[test]
+fn next_safe_restart_is_safe() {
+    for input in test_inputs() {
+        let want = splits(input);
+        let char_starts: Vec<_> = input.char_indices().map(|(i, _)| i).collect();
+        for index in 0..=input.len() {
+            let restart = next_safe_restart(input, index);
+            assert!(
+                restart >= index,
+                "restart {restart} before {index} in {input:?}"
+            );
+            if restart == input.len() {
+                continue;
+            }
+            // Segmenting from the restart point with a fresh machine must
+            // agree with segmenting the whole input.
+            let skip = char_starts.iter().position(|i| *i == restart).unwrap();
+            let got = splits(&input[restart..]);
+            assert_eq!(got, want[skip..], "restart at {restart} in {input:?}");
+        }
+    }
+
+    assert_eq!(next_safe_restart("ab", 1), 1);
+    assert_eq!(next_safe_restart("e\u{0301}f", 1), 3);
+    assert_eq!(next_safe_restart("a🇦🇧🇨🇩b", 5), 17);
+    assert_eq!(next_safe_restart("a🇦🇧", 5), 9);
+}
+
+#[test]
+fn prev_safe_restart_is_safe() {
+    for input in test_inputs() {
+        let want = splits(input);
+        let char_starts: Vec<_> = input.char_indices().map(|(i, _)| i).collect();
+        for index in 0..=input.len() {
+            let restart = prev_safe_restart(input, index);
+            assert!(
+                restart <= index,
+                "restart {restart} after {index} in {input:?}"
+            );
+            if restart == input.len() {
+                continue;
+            }
+            let skip = char_starts.iter().position(|i| *i == restart).unwrap();
+            let got = splits(&input[restart..]);
+            assert_eq!(got, want[skip..], "restart at {restart} in {input:?}");
+        }
+    }
+
+    assert_eq!(prev_safe_restart("ab", 1), 1);
+    assert_eq!(prev_safe_restart("ab", 5), 2);
+    assert_eq!(prev_safe_restart("e\u{0301}f", 2), 0);
+    assert_eq!(prev_safe_restart("a🇦🇧🇨🇩b", 13), 1);
+}
@@ -0,0 +1,281 @@
+use core::fmt;
+
+use crate::{
+    BufferedGraphemeMachine, CharProperties, GCBProperty, GraphemeMachine, Segmenter,
+    cluster_bidi_control,
+};
+
+/// What a [`SanitizingWriter`] should do with a grapheme cluster that its
+/// [`SanitizePolicy`] selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterTreatment {
+    /// Write the cluster unchanged.
+    Keep,
+    /// Discard the cluster.
+    Remove,
+    /// Write the given string instead of the cluster.
+    Replace(&'static str),
+}
+
+/// Describes how a [`SanitizingWriter`] treats each kind of grapheme
+/// cluster that can be problematic in terminal or log output.
+///
+/// Start from one of the predefined policies and then change individual
+/// fields as needed, since more fields may be added in future versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SanitizePolicy {
+    /// Clusters consisting of a carriage return, a line feed, or a carriage
+    /// return followed by a line feed. Only line breaks that are kept end a
+    /// line for the purpose of the per-line cluster limit.
+    pub line_breaks: ClusterTreatment,
+    /// Clusters beginning with any other character whose
+    /// **Grapheme_Cluster_Break** property is `Control`, which includes the
+    /// C0 and C1 control characters such as the escape character that
+    /// begins terminal control sequences, and the horizontal tab.
+    pub control: ClusterTreatment,
+    /// Clusters containing any of the bidirectional formatting characters
+    /// described by [`BidiControl`](crate::BidiControl), which can make text display in a
+    /// misleading order.
+    ///
+    /// These characters are also `Control` characters, but clusters
+    /// containing them are treated according to this field instead of
+    /// [`Self::control`], so that they can be stripped from text whose other
+    /// control characters are kept.
+    pub bidi_controls: ClusterTreatment,
+    /// Clusters beginning with a zero width joiner or a character whose
+    /// **Grapheme_Cluster_Break** property is `Extend`, such as a variation
+    /// selector or a combining mark.
+    ///
+    /// Such characters normally extend the preceding cluster, and so they
+    /// begin a cluster only when isolated at the start of the text or after
+    /// a control character. They can then combine with whatever text gets
+    /// displayed before them.
+    pub isolated_extenders: ClusterTreatment,
+    /// Clusters longer than the writer's buffer.
+    pub overlong: ClusterTreatment,
+}
+
+impl SanitizePolicy {
+    /// A policy that keeps every cluster except the overlong ones, which
+    /// are replaced with U+FFFD REPLACEMENT CHARACTER.
+    pub const PASS_THROUGH: Self = Self {
+        line_breaks: ClusterTreatment::Keep,
+        control: ClusterTreatment::Keep,
+        bidi_controls: ClusterTreatment::Keep,
+        isolated_extenders: ClusterTreatment::Keep,
+        overlong: ClusterTreatment::Replace("\u{FFFD}"),
+    };
+
+    /// A policy suitable for untrusted text written to a terminal or a
+    /// log, which keeps line breaks, replaces control clusters (including
+    /// the bidirectional formatting characters) and overlong clusters with
+    /// U+FFFD REPLACEMENT CHARACTER, and removes isolated extenders.
+    pub const UNTRUSTED: Self = Self {
+        line_breaks: ClusterTreatment::Keep,
+        control: ClusterTreatment::Replace("\u{FFFD}"),
+        bidi_controls: ClusterTreatment::Replace("\u{FFFD}"),
+        isolated_extenders: ClusterTreatment::Remove,
+        overlong: ClusterTreatment::Replace("\u{FFFD}"),
+    };
+
+    /// Returns how this policy treats the given cluster, along with whether
+    /// the cluster is a line break.
+    fn treatment(&self, cluster: &str, overflowed: bool) -> (ClusterTreatment, bool) {
+        if overflowed {
+            return (self.overlong, false);
+        }
+        if matches!(cluster, "\r" | "\n" | "\r\n") {
+            return (self.line_breaks, true);
+        }
+        if cluster_bidi_control(cluster).is_some() {
+            return (self.bidi_controls, false);
+        }
+        let first = cluster.chars().next().map(CharProperties::for_char);
+        let treatment = match first.map(CharProperties::gcb_property) {
+            Some(GCBProperty::Control | GCBProperty::CR | GCBProperty::LF) => self.control,
+            Some(GCBProperty::Extend | GCBProperty::ZWJ) => self.isolated_extenders,
+            _ => ClusterTreatment::Keep,
+        };
+        (treatment, false)
+    }
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        Self::PASS_THROUGH
+    }
+}
+
+/// Returns a copy of `s` with its grapheme clusters treated according to
+/// the given policy, using a [`SanitizingWriter`] with its default
+/// buffer size and no limit on the length of lines.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn sanitize(s: &str, policy: SanitizePolicy) -> alloc::string::String {
+    use fmt::Write;
+
+    let mut w: SanitizingWriter<_> =
+        SanitizingWriter::new(alloc::string::String::with_capacity(s.len()), usize::MAX)
+            .with_policy(policy);
+    // Writing to a String never fails.
+    w.write_str(s).unwrap();
+    w.finish().unwrap()
+}
+
+/// A [`fmt::Write`] implementation that passes text through to another
+/// writer while limiting the number of grapheme clusters on each line and
+/// treating problematic clusters according to a [`SanitizePolicy`].
+///
+/// This is intended for writing untrusted strings into logs and similar
+/// output, where a hostile string could otherwise make a single line
+/// arbitrarily wide or include a cluster made of thousands of combining
+/// marks. Because it implements [`fmt::Write`], it can be used directly as
+/// the destination of [`write!`].
+///
+/// Clusters beyond the per-line limit are discarded until the next line
+/// break, with the truncation marker (if any) written once in their place.
+/// A line break is any grapheme cluster consisting of a carriage return, a
+/// line feed, or a carriage return followed by a line feed, just as for
+/// [`LineColumnTracker`](crate::LineColumnTracker).
+///
+/// The policy decides what to write for each cluster that is a control
+/// character, an isolated extender, or longer than `N` bytes. The default
+/// policy, [`SanitizePolicy::PASS_THROUGH`], replaces only the overlong
+/// clusters; use [`SanitizePolicy::UNTRUSTED`] for output displayed to
+/// people who might be misled by hostile control characters.
+///
+/// A cluster is only known to be complete once the first character of the
+/// next cluster arrives, and so the final cluster of the text remains
+/// buffered until [`Self::finish`] is called. Dropping the writer without
+/// calling that method discards the final cluster.
+///
+/// The writer finds the clusters using a [`GraphemeMachine`] unless
+/// constructed using [`Self::with_machine`], which accepts any other
+/// [`Segmenter`]. The policy and the line limit then apply to that
+/// machine's segments instead.
+#[derive(Debug)]
+pub struct SanitizingWriter<W, const N: usize = 64, S = GraphemeMachine> {
+    inner: W,
+    buffered: BufferedGraphemeMachine<N, S>,
+    policy: SanitizePolicy,
+    max_line_clusters: usize,
+    line_clusters: usize,
+    truncation_marker: &'static str,
+}
+
+impl<W: fmt::Write, const N: usize> SanitizingWriter<W, N> {
+    /// Wraps the given writer, allowing at most `max_line_clusters` grapheme
+    /// clusters on each line. Use [`usize::MAX`] to only replace overlong
+    /// clusters, without limiting the length of lines.
+    pub fn new(inner: W, max_line_clusters: usize) -> Self {
+        Self::with_machine(inner, max_line_clusters, GraphemeMachine::new())
+    }
+}
+
+impl<W: fmt::Write, const N: usize, S: Segmenter> SanitizingWriter<W, N, S> {
+    /// Like [`Self::new`], but finding the clusters using an existing
+    /// machine, which should be in its "start of input" state.
+    pub fn with_machine(inner: W, max_line_clusters: usize, machine: S) -> Self {
+        Self {
+            inner,
+            buffered: BufferedGraphemeMachine::with_machine(machine),
+            policy: SanitizePolicy::PASS_THROUGH,
+            max_line_clusters,
+            line_clusters: 0,
+            truncation_marker: "",
+        }
+    }
+
+    /// Sets a string to write in place of the clusters discarded from the
+    /// end of each overlong line, such as `"…"`. By default nothing is
+    /// written in their place.
+    ///
+    /// The marker isn't subject to the line length limit, and so should be
+    /// short.
+    pub fn with_truncation_marker(mut self, marker: &'static str) -> Self {
+        self.truncation_marker = marker;
+        self
+    }
+
+    /// Sets the policy for treating problematic clusters.
+    pub fn with_policy(mut self, policy: SanitizePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Writes the final grapheme cluster, if any, and then returns the
+    /// inner writer.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        if self.buffered.end_of_input().is_some() {
+            let mut line = LineState {
+                inner: &mut self.inner,
+                policy: self.policy,
+                max_line_clusters: self.max_line_clusters,
+                line_clusters: &mut self.line_clusters,
+                truncation_marker: self.truncation_marker,
+            };
+            line.write_cluster(self.buffered.cluster(), self.buffered.overflowed())?;
+        }
+        Ok(self.inner)
+    }
+}
+
+impl<W: fmt::Write, const N: usize, S: Segmenter> fmt::Write for SanitizingWriter<W, N, S> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut line = LineState {
+            inner: &mut self.inner,
+            policy: self.policy,
+            max_line_clusters: self.max_line_clusters,
+            line_clusters: &mut self.line_clusters,
+            truncation_marker: self.truncation_marker,
+        };
+        for c in s.chars() {
+            if self.buffered.next_char(c).is_some() {
+                line.write_cluster(self.buffered.cluster(), self.buffered.overflowed())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The parts of [`SanitizingWriter`] needed for writing a cluster, borrowed
+/// separately from its buffer so that the cluster can borrow that.
+struct LineState<'a, W> {
+    inner: &'a mut W,
+    policy: SanitizePolicy,
+    max_line_clusters: usize,
+    line_clusters: &'a mut usize,
+    truncation_marker: &'static str,
+}
+
+impl<'a, W: fmt::Write> LineState<'a, W> {
+    fn write_cluster(&mut self, cluster: &str, overflowed: bool) -> fmt::Result {
+        let cluster = match self.policy.treatment(cluster, overflowed) {
+            (ClusterTreatment::Keep, true) => {
+                *self.line_clusters = 0;
+                return self.inner.write_str(cluster);
+            }
+            (ClusterTreatment::Keep, false) => cluster,
+            (ClusterTreatment::Remove, _) => return Ok(()),
+            (ClusterTreatment::Replace(s), _) => s,
+        };
+        *self.line_clusters = self.line_clusters.saturating_add(1);
+        if *self.line_clusters <= self.max_line_clusters {
+            self.inner.write_str(cluster)
+        } else if *self.line_clusters == self.max_line_clusters.saturating_add(1) {
+            self.inner.write_str(self.truncation_marker)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
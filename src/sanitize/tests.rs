@@ -0,0 +1,82 @@
+use super::*;
+
+use core::fmt::Write;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn line_limits() {
+    let mut w = SanitizingWriter::<_, 16>::new(String::new(), 3).with_truncation_marker("…");
+    write!(w, "ab\u{0301}c").unwrap();
+    write!(w, "defg\r").unwrap();
+    write!(w, "\nxy\nlong").unwrap();
+    assert_eq!(w.get_ref(), "ab\u{0301}c…\r\nxy\nlon");
+    assert_eq!(w.finish().unwrap(), "ab\u{0301}c…\r\nxy\nlon…");
+
+    // Without a marker, the excess clusters just vanish.
+    let mut w = SanitizingWriter::<_, 16>::new(String::new(), 1);
+    write!(w, "abc\ndef").unwrap();
+    assert_eq!(w.finish().unwrap(), "a\nd");
+}
+
+#[test]
+fn overlong_clusters() {
+    let mut w = SanitizingWriter::<_, 8>::new(String::new(), usize::MAX);
+    write!(w, "ae\u{0301}b🧑‍🌾").unwrap();
+    write!(w, "x{}", "\u{0301}".repeat(100)).unwrap();
+    assert_eq!(w.finish().unwrap(), "ae\u{0301}b\u{FFFD}\u{FFFD}");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn untrusted_policy() {
+    let policy = SanitizePolicy::UNTRUSTED;
+    assert_eq!(
+        sanitize("\u{0301}ok\x1b[31m\u{202E}\r\nné\t\u{FE0F}", policy),
+        "ok\u{FFFD}[31m\u{FFFD}\r\nné\u{FFFD}",
+    );
+    // Legitimate international text is untouched, including clusters that
+    // use joiners and variation selectors.
+    let text = "नमस्ते 🧑‍🌾 ❤\u{FE0F} 🇦🇧";
+    assert_eq!(sanitize(text, policy), text);
+
+    let mut policy = SanitizePolicy::UNTRUSTED;
+    policy.line_breaks = ClusterTreatment::Replace(" ");
+    policy.control = ClusterTreatment::Remove;
+    assert_eq!(sanitize("a\r\nb\x07c\n", policy), "a bc ");
+    assert_eq!(sanitize("a\r\nb", SanitizePolicy::default()), "a\r\nb");
+}
+
+#[test]
+fn policy_with_line_limits() {
+    // Replacements count toward the limit, but removed clusters don't, and
+    // only kept line breaks reset it.
+    let mut policy = SanitizePolicy::UNTRUSTED;
+    policy.line_breaks = ClusterTreatment::Replace("⏎");
+    let mut w = SanitizingWriter::<_, 4>::new(String::new(), 3)
+        .with_policy(policy)
+        .with_truncation_marker("…");
+    write!(w, "\u{200D}a\x1b\nb\u{0301}\u{0301}c").unwrap();
+    assert_eq!(w.finish().unwrap(), "a\u{FFFD}⏎…");
+
+    // An overlong cluster is distinct from a literal U+FFFD.
+    let mut policy = SanitizePolicy::PASS_THROUGH;
+    policy.overlong = ClusterTreatment::Remove;
+    let mut w = SanitizingWriter::<_, 4>::new(String::new(), usize::MAX).with_policy(policy);
+    write!(w, "\u{FFFD}e\u{0301}\u{0301}x").unwrap();
+    assert_eq!(w.finish().unwrap(), "\u{FFFD}x");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn bidi_controls() {
+    let mut policy = SanitizePolicy::PASS_THROUGH;
+    policy.bidi_controls = ClusterTreatment::Remove;
+    assert_eq!(
+        sanitize("a\u{202E}b\tc\u{2066}d\u{2069} עברית", policy),
+        "ab\tcd עברית"
+    );
+    assert_eq!(
+        sanitize("a\u{202E}b\tc", SanitizePolicy::UNTRUSTED),
+        "a\u{FFFD}b\u{FFFD}c"
+    );
+}
@@ -0,0 +1,293 @@
+//! Byte-scanning helpers used by the whole-buffer APIs to skip quickly
+//! over runs of input that don't need the full state machine, and to count
+//! the characters in a buffer without decoding them.
+//!
+//! When the `std` feature is enabled these select a SIMD implementation at
+//! runtime based on what the current CPU supports, so that distribution
+//! builds that can't assume anything beyond the target's baseline can
+//! still benefit. Without `std` there is no way to detect CPU features at
+//! runtime, and so the selection is made at compile time based on the
+//! target features enabled for the build, falling back to a scalar
+//! implementation.
+
+/// Returns the length of the longest prefix of `bytes` that consists only
+/// of printable ASCII characters, U+0020 through U+007E inclusive.
+///
+/// All of those characters have no Grapheme_Cluster_Break or
+/// Indic_Conjunct_Break property value, and so there is always a boundary
+/// between any two of them and they leave the state machine in its base
+/// state.
+#[inline]
+#[allow(unreachable_code)]
+pub(crate) fn printable_ascii_prefix_len(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            // Safety: We've just checked that the CPU supports AVX2.
+            return unsafe { x86::printable_ascii_prefix_len_avx2(bytes) };
+        }
+        if std::is_x86_feature_detected!("sse2") {
+            // Safety: We've just checked that the CPU supports SSE2.
+            return unsafe { x86::printable_ascii_prefix_len_sse2(bytes) };
+        }
+    }
+    #[cfg(all(
+        not(feature = "std"),
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        // Safety: SSE2 is enabled for the whole build.
+        return unsafe { x86::printable_ascii_prefix_len_sse2(bytes) };
+    }
+    #[cfg(all(feature = "std", target_arch = "aarch64"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            // Safety: We've just checked that the CPU supports NEON.
+            return unsafe { aarch64::printable_ascii_prefix_len_neon(bytes) };
+        }
+    }
+    #[cfg(all(not(feature = "std"), target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // Safety: NEON is enabled for the whole build.
+        return unsafe { aarch64::printable_ascii_prefix_len_neon(bytes) };
+    }
+    printable_ascii_prefix_len_scalar(bytes)
+}
+
+/// Returns the number of bytes in `bytes` that are not UTF-8 continuation
+/// bytes, which for valid UTF-8 is the number of characters it contains.
+#[inline]
+#[allow(unreachable_code)]
+pub(crate) fn lead_byte_count(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            // Safety: We've just checked that the CPU supports AVX2.
+            return unsafe { x86::lead_byte_count_avx2(bytes) };
+        }
+        if std::is_x86_feature_detected!("sse2") {
+            // Safety: We've just checked that the CPU supports SSE2.
+            return unsafe { x86::lead_byte_count_sse2(bytes) };
+        }
+    }
+    #[cfg(all(
+        not(feature = "std"),
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        // Safety: SSE2 is enabled for the whole build.
+        return unsafe { x86::lead_byte_count_sse2(bytes) };
+    }
+    #[cfg(all(feature = "std", target_arch = "aarch64"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            // Safety: We've just checked that the CPU supports NEON.
+            return unsafe { aarch64::lead_byte_count_neon(bytes) };
+        }
+    }
+    #[cfg(all(not(feature = "std"), target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // Safety: NEON is enabled for the whole build.
+        return unsafe { aarch64::lead_byte_count_neon(bytes) };
+    }
+    lead_byte_count_scalar(bytes)
+}
+
+#[inline]
+fn printable_ascii_prefix_len_scalar(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|b| !is_printable_ascii(*b))
+        .unwrap_or(bytes.len())
+}
+
+/// Returns a lower and upper bound on the number of grapheme cluster
+/// boundaries between the characters of `s`, not counting any boundary
+/// before the first character, which depends on what preceded `s`.
+///
+/// The upper bound is the number of characters minus one, while the lower
+/// bound relies on there always being a boundary between two printable
+/// ASCII characters and so examines only the printable ASCII prefix of `s`.
+pub(crate) fn inner_boundary_bounds(s: &str) -> (usize, usize) {
+    let bytes = s.as_bytes();
+    let ascii = printable_ascii_prefix_len(bytes);
+    let chars = if ascii == bytes.len() {
+        ascii
+    } else {
+        ascii + lead_byte_count(&bytes[ascii..])
+    };
+    (ascii.saturating_sub(1), chars.saturating_sub(1))
+}
+
+#[inline]
+fn lead_byte_count_scalar(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|b| !is_continuation_byte(**b)).count()
+}
+
+#[inline(always)]
+const fn is_continuation_byte(b: u8) -> bool {
+    b & 0xc0 == 0x80
+}
+
+#[inline(always)]
+pub(crate) const fn is_printable_ascii(b: u8) -> bool {
+    matches!(b, 0x20..=0x7e)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// # Safety
+    ///
+    /// The CPU must support SSE2.
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn printable_ascii_prefix_len_sse2(bytes: &[u8]) -> usize {
+        const LANES: usize = 16;
+        let mut i = 0;
+        // The comparisons are signed, which conveniently means that all
+        // bytes with the high bit set compare as less than 0x20.
+        let lo = _mm_set1_epi8(0x1f);
+        let hi = _mm_set1_epi8(0x7f);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and loadu has no alignment requirement.
+            let v = unsafe { _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i) };
+            let ok = _mm_and_si128(_mm_cmpgt_epi8(v, lo), _mm_cmplt_epi8(v, hi));
+            let mask = _mm_movemask_epi8(ok) as u32;
+            if mask != 0xffff {
+                return i + mask.trailing_ones() as usize;
+            }
+            i += LANES;
+        }
+        i + super::printable_ascii_prefix_len_scalar(&bytes[i..])
+    }
+
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[cfg(any(feature = "std", test))]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn printable_ascii_prefix_len_avx2(bytes: &[u8]) -> usize {
+        const LANES: usize = 32;
+        let mut i = 0;
+        let lo = _mm256_set1_epi8(0x1f);
+        let hi = _mm256_set1_epi8(0x7f);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and loadu has no alignment requirement.
+            let v = unsafe { _mm256_loadu_si256(bytes.as_ptr().add(i) as *const __m256i) };
+            let ok = _mm256_and_si256(_mm256_cmpgt_epi8(v, lo), _mm256_cmpgt_epi8(hi, v));
+            let mask = _mm256_movemask_epi8(ok) as u32;
+            if mask != 0xffff_ffff {
+                return i + mask.trailing_ones() as usize;
+            }
+            i += LANES;
+        }
+        // Safety: The caller guarantees AVX2, which implies SSE2.
+        i + unsafe { printable_ascii_prefix_len_sse2(&bytes[i..]) }
+    }
+
+    /// # Safety
+    ///
+    /// The CPU must support SSE2.
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn lead_byte_count_sse2(bytes: &[u8]) -> usize {
+        const LANES: usize = 16;
+        let mut i = 0;
+        let mut count = 0;
+        // Continuation bytes are 0x80 through 0xbf, which are the signed
+        // values -128 through -65, so all other bytes compare greater.
+        let max_continuation = _mm_set1_epi8(-65);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and loadu has no alignment requirement.
+            let v = unsafe { _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i) };
+            let mask = _mm_movemask_epi8(_mm_cmpgt_epi8(v, max_continuation)) as u32;
+            count += mask.count_ones() as usize;
+            i += LANES;
+        }
+        count + super::lead_byte_count_scalar(&bytes[i..])
+    }
+
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[cfg(any(feature = "std", test))]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn lead_byte_count_avx2(bytes: &[u8]) -> usize {
+        const LANES: usize = 32;
+        let mut i = 0;
+        let mut count = 0;
+        let max_continuation = _mm256_set1_epi8(-65);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and loadu has no alignment requirement.
+            let v = unsafe { _mm256_loadu_si256(bytes.as_ptr().add(i) as *const __m256i) };
+            let mask = _mm256_movemask_epi8(_mm256_cmpgt_epi8(v, max_continuation)) as u32;
+            count += mask.count_ones() as usize;
+            i += LANES;
+        }
+        // Safety: The caller guarantees AVX2, which implies SSE2.
+        count + unsafe { lead_byte_count_sse2(&bytes[i..]) }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use core::arch::aarch64::*;
+
+    /// # Safety
+    ///
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn printable_ascii_prefix_len_neon(bytes: &[u8]) -> usize {
+        const LANES: usize = 16;
+        let mut i = 0;
+        let lo = vdupq_n_u8(0x1f);
+        let hi = vdupq_n_u8(0x7f);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and vld1q_u8 has no alignment requirement.
+            let v = unsafe { vld1q_u8(bytes.as_ptr().add(i)) };
+            let ok = vandq_u8(vcgtq_u8(v, lo), vcltq_u8(v, hi));
+            if vminvq_u8(ok) != 0xff {
+                // NEON has no cheap equivalent of movemask, so we'll let
+                // the scalar implementation find the exact position within
+                // this block.
+                return i + super::printable_ascii_prefix_len_scalar(&bytes[i..i + LANES]);
+            }
+            i += LANES;
+        }
+        i + super::printable_ascii_prefix_len_scalar(&bytes[i..])
+    }
+
+    /// # Safety
+    ///
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn lead_byte_count_neon(bytes: &[u8]) -> usize {
+        const LANES: usize = 16;
+        let mut i = 0;
+        let mut count = 0;
+        let max_continuation = vdupq_n_s8(-65);
+        let one = vdupq_n_u8(1);
+        while i + LANES <= bytes.len() {
+            // Safety: We've checked that there are at least LANES bytes
+            // remaining, and vld1q_s8 has no alignment requirement.
+            let v = unsafe { vld1q_s8(bytes.as_ptr().add(i) as *const i8) };
+            let lead = vandq_u8(vcgtq_s8(v, max_continuation), one);
+            count += vaddvq_u8(lead) as usize;
+            i += LANES;
+        }
+        count + super::lead_byte_count_scalar(&bytes[i..])
+    }
+}
+
+#[cfg(test)]
+mod tests;
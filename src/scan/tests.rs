@@ -0,0 +1,100 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn inputs() -> Vec<Vec<u8>> {
+    let mut ret = Vec::new();
+    ret.push(Vec::new());
+    for len in [1, 15, 16, 17, 31, 32, 33, 64, 100] {
+        let clean: Vec<u8> = (0..len).map(|i| 0x20 + (i % 0x5f) as u8).collect();
+        ret.push(clean.clone());
+        for stop in [0, len / 2, len - 1] {
+            for bad in [0x00, 0x0a, 0x1f, 0x7f, 0x80, 0xc3, 0xff] {
+                let mut v = clean.clone();
+                v[stop] = bad;
+                ret.push(v);
+            }
+        }
+    }
+    ret
+}
+
+#[test]
+fn printable_ascii_prefix_len_matches_scalar() {
+    for input in inputs() {
+        let want = printable_ascii_prefix_len_scalar(&input);
+        assert_eq!(printable_ascii_prefix_len(&input), want, "{input:x?}");
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if std::is_x86_feature_detected!("sse2") {
+                let got = unsafe { x86::printable_ascii_prefix_len_sse2(&input) };
+                assert_eq!(got, want, "SSE2 with {input:x?}");
+            }
+            if std::is_x86_feature_detected!("avx2") {
+                let got = unsafe { x86::printable_ascii_prefix_len_avx2(&input) };
+                assert_eq!(got, want, "AVX2 with {input:x?}");
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                let got = unsafe { aarch64::printable_ascii_prefix_len_neon(&input) };
+                assert_eq!(got, want, "NEON with {input:x?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn lead_byte_count_matches_scalar() {
+    let mut all = inputs();
+    for len in [1, 15, 16, 17, 31, 32, 33, 64, 100] {
+        let text: String = "aé€😀".chars().cycle().take(len).collect();
+        all.push(text.into_bytes());
+    }
+    for input in all {
+        let want = lead_byte_count_scalar(&input);
+        assert_eq!(lead_byte_count(&input), want, "{input:x?}");
+        if let Ok(s) = str::from_utf8(&input) {
+            assert_eq!(want, s.chars().count(), "{s:?}");
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if std::is_x86_feature_detected!("sse2") {
+                let got = unsafe { x86::lead_byte_count_sse2(&input) };
+                assert_eq!(got, want, "SSE2 with {input:x?}");
+            }
+            if std::is_x86_feature_detected!("avx2") {
+                let got = unsafe { x86::lead_byte_count_avx2(&input) };
+                assert_eq!(got, want, "AVX2 with {input:x?}");
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                let got = unsafe { aarch64::lead_byte_count_neon(&input) };
+                assert_eq!(got, want, "NEON with {input:x?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn printable_ascii_properties_are_uniform() {
+    // The whole-buffer fast paths rely on every printable ASCII character
+    // having the same properties, and on those properties leaving the
+    // state machine in its base state with a boundary between each pair.
+    let want = crate::CharProperties::for_char(' ');
+    for b in 0x20..=0x7e_u8 {
+        assert!(is_printable_ascii(b));
+        assert_eq!(crate::CharProperties::for_char(b as char), want, "{b:#x}");
+    }
+    assert!(!is_printable_ascii(0x1f));
+    assert!(!is_printable_ascii(0x7f));
+
+    let (boundary, next_state) = crate::State::Base.transition(want, want);
+    assert!(boundary);
+    assert_eq!(next_state, crate::State::Base);
+}
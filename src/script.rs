@@ -0,0 +1,160 @@
+//! The Unicode **Script** and **Script_Extensions** properties, for choosing
+//! a font for each grapheme cluster.
+//!
+//! These use data from the same version of the Unicode character database
+//! as the grapheme cluster break properties, so that a renderer using both
+//! never disagrees with the segmenter about which characters exist.
+
+#[cfg(all(feature = "machine", feature = "tables"))]
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+mod table;
+
+pub use table::Script;
+
+impl Script {
+    /// The size in bytes of the lookup tables that [`Self::of_char`] and
+    /// [`Self::extensions_of_char`] use.
+    ///
+    /// Like the tables behind [`CharProperties`](crate::CharProperties),
+    /// these tables honor the `GRAPHEME_MACHINE_LINK_SECTION` environment
+    /// variable.
+    pub const TABLE_SIZE: usize = table::SCRIPT_TABLES_SIZE;
+
+    /// Returns the **Script** of the given character, or [`Script::Unknown`]
+    /// if it isn't assigned.
+    pub const fn of_char(c: char) -> Self {
+        table::script_lookup(c)
+    }
+
+    /// Returns the **Script** of the given character, represented as a
+    /// [`u8char`] value, in the same way as `CharProperties::for_u8char`
+    /// for the segmentation properties.
+    ///
+    /// The table is indexed by code point, so this is the same as
+    /// [`Self::of_char`] after converting to [`char`], but it's convenient
+    /// for looking up both in the same pass over some text.
+    pub const fn of_u8char(c: u8char) -> Self {
+        Self::of_char(c.to_char())
+    }
+
+    /// Returns the **Script_Extensions** of the given character, which are
+    /// the scripts it's commonly used with, in the order of their short
+    /// names.
+    ///
+    /// For most characters this is just their [`Self::of_char`] result,
+    /// but, for example, the Arabic comma is also used with Syriac, Thaana
+    /// and several other scripts, and so a font for any of those scripts
+    /// should be able to render it. Even [`Script::Common`] and
+    /// [`Script::Inherited`] characters can be limited to particular
+    /// scripts in this way, which makes this the better choice for
+    /// deciding whether a font covers a character.
+    pub const fn extensions_of_char(c: char) -> &'static [Self] {
+        table::script_extensions_lookup(c)
+    }
+
+    /// Returns the script's four-letter ISO 15924 code, such as `"Latn"`,
+    /// which is also its short **Script** property value alias.
+    pub const fn short_name(self) -> &'static str {
+        table::script_names(self).0
+    }
+
+    /// Returns the script's long **Script** property value alias, such as
+    /// `"Latin"` or `"Old_Italic"`.
+    pub const fn name(self) -> &'static str {
+        table::script_names(self).1
+    }
+}
+
+/// Returns the script that a renderer should choose a font for when
+/// drawing the given grapheme cluster.
+///
+/// This is the **Script** of the first character in the cluster that isn't
+/// [`Script::Common`] or [`Script::Inherited`], so that a cluster made of
+/// a base character and combining marks takes the script of the base. A
+/// cluster made only of characters from those two, like most emoji, takes
+/// the script of its first character, and the empty string is
+/// [`Script::Unknown`].
+///
+/// A cluster can contain characters from several scripts, so a renderer
+/// that needs one font for the whole cluster should also check that the
+/// font covers the [`Script::extensions_of_char`] of each character.
+pub fn cluster_script(cluster: &str) -> Script {
+    let mut chars = cluster.chars().map(Script::of_char);
+    let Some(first) = chars.next() else {
+        return Script::Unknown;
+    };
+    core::iter::once(first)
+        .chain(chars)
+        .find(|script| !matches!(script, Script::Common | Script::Inherited))
+        .unwrap_or(first)
+}
+
+/// Returns an iterator over the runs of grapheme clusters in `s` that
+/// share a [`cluster_script`], along with that script, as for choosing a
+/// font and shaping each run separately.
+///
+/// Clusters whose script is [`Script::Common`] or [`Script::Inherited`],
+/// such as spaces, punctuation and emoji, join the run before them, or the
+/// run after them at the start of the text, so that they don't split a run
+/// of words in one script. Text made only of such clusters is a single run
+/// with the script of its first cluster.
+///
+/// This doesn't implement the paired bracket rule of
+/// [UAX #24](https://www.unicode.org/reports/tr24/), and so a closing
+/// bracket joins the run before it even if its opening bracket is in an
+/// earlier run.
+///
+/// This is available only when the `machine` and `tables` features are
+/// enabled, as they are by default.
+#[cfg(all(feature = "machine", feature = "tables"))]
+pub fn script_runs(s: &str) -> ScriptRuns<'_> {
+    ScriptRuns { remain: s }
+}
+
+/// The iterator returned by [`script_runs`].
+#[cfg(all(feature = "machine", feature = "tables"))]
+#[derive(Debug, Clone)]
+pub struct ScriptRuns<'a> {
+    remain: &'a str,
+}
+
+#[cfg(all(feature = "machine", feature = "tables"))]
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (Script, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remain.is_empty() {
+            return None;
+        }
+        let mut run_script = None;
+        let mut first_script = Script::Unknown;
+        let mut len = 0;
+        // Each run ends at a cluster boundary, so the remaining text always
+        // begins with a new cluster.
+        for cluster in crate::clusters_in_str(self.remain) {
+            let script = cluster_script(cluster);
+            if len == 0 {
+                first_script = script;
+            }
+            match (run_script, script) {
+                (_, Script::Common | Script::Inherited) => {}
+                (None, script) => run_script = Some(script),
+                (Some(run), script) if run == script => {}
+                (Some(_), _) => break,
+            }
+            len += cluster.len();
+        }
+        let (run, rest) = self.remain.split_at(len);
+        self.remain = rest;
+        Some((run_script.unwrap_or(first_script), run))
+    }
+}
+
+#[cfg(all(feature = "machine", feature = "tables"))]
+impl FusedIterator for ScriptRuns<'_> {}
+
+#[cfg(test)]
+mod tests;
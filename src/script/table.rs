@@ -0,0 +1,1297 @@
+/// Returns the **Script** of the character with the given code point.
+pub const fn script_lookup(c: char) -> Script {
+    SCRIPTS[lookup_runs(&SCRIPT_RUNS, c) as usize]
+}
+
+/// Returns the **Script_Extensions** of the character with the given code
+/// point, which is just its **Script** unless [`SCRIPT_EXTENSIONS_RUNS`]
+/// says otherwise.
+pub const fn script_extensions_lookup(c: char) -> &'static [Script] {
+    match lookup_runs(&SCRIPT_EXTENSIONS_RUNS, c) {
+        0 => core::slice::from_ref(&SCRIPTS[lookup_runs(&SCRIPT_RUNS, c) as usize]),
+        i => SCRIPT_EXTENSIONS[i as usize - 1],
+    }
+}
+
+/// Returns the short and long names of the given script.
+pub const fn script_names(script: Script) -> (&'static str, &'static str) {
+    SCRIPT_NAMES[script as usize]
+}
+
+/// Returns the value of the last run in `runs` beginning at or before `c`,
+/// using the same run encoding as the **Age** table.
+const fn lookup_runs(runs: &[u32], c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, runs.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if runs[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    runs[lo] as u8
+}
+
+/// The size in bytes of the tables that [`script_lookup`] and
+/// [`script_extensions_lookup`] use.
+pub const SCRIPT_TABLES_SIZE: usize = {
+    let mut size = core::mem::size_of_val(&SCRIPTS)
+        + core::mem::size_of_val(&SCRIPT_EXTENSIONS)
+        + core::mem::size_of_val(&SCRIPT_RUNS)
+        + core::mem::size_of_val(&SCRIPT_EXTENSIONS_RUNS);
+    let mut i = 0;
+    while i < SCRIPT_EXTENSIONS.len() {
+        size += core::mem::size_of_val(SCRIPT_EXTENSIONS[i]);
+        i += 1;
+    }
+    size
+};
+
+// Everything below this line is generated by `cargo xtask generate-script-tables`.
+
+/// A value of the Unicode **Script** property, which is the script that a
+/// character belongs to.
+///
+/// Characters that are used with several scripts, like most punctuation and
+/// digits, belong to [`Script::Common`], and combining marks that take the
+/// script of the character they are applied to belong to
+/// [`Script::Inherited`]. Unassigned characters belong to
+/// [`Script::Unknown`].
+///
+/// Each variant is documented with the script's four-letter ISO 15924
+/// code, which [`Script::short_name`] also returns. New versions of the
+/// Unicode Standard add more scripts, so this enum is non-exhaustive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Script {
+    /// `Adlm`
+    Adlam,
+    /// `Aghb`
+    CaucasianAlbanian,
+    /// `Ahom`
+    Ahom,
+    /// `Arab`
+    Arabic,
+    /// `Armi`
+    ImperialAramaic,
+    /// `Armn`
+    Armenian,
+    /// `Avst`
+    Avestan,
+    /// `Bali`
+    Balinese,
+    /// `Bamu`
+    Bamum,
+    /// `Bass`
+    BassaVah,
+    /// `Batk`
+    Batak,
+    /// `Beng`
+    Bengali,
+    /// `Bhks`
+    Bhaiksuki,
+    /// `Bopo`
+    Bopomofo,
+    /// `Brah`
+    Brahmi,
+    /// `Brai`
+    Braille,
+    /// `Bugi`
+    Buginese,
+    /// `Buhd`
+    Buhid,
+    /// `Cakm`
+    Chakma,
+    /// `Cans`
+    CanadianAboriginal,
+    /// `Cari`
+    Carian,
+    /// `Cham`
+    Cham,
+    /// `Cher`
+    Cherokee,
+    /// `Chrs`
+    Chorasmian,
+    /// `Copt`
+    Coptic,
+    /// `Cpmn`
+    CyproMinoan,
+    /// `Cprt`
+    Cypriot,
+    /// `Cyrl`
+    Cyrillic,
+    /// `Deva`
+    Devanagari,
+    /// `Diak`
+    DivesAkuru,
+    /// `Dogr`
+    Dogra,
+    /// `Dsrt`
+    Deseret,
+    /// `Dupl`
+    Duployan,
+    /// `Egyp`
+    EgyptianHieroglyphs,
+    /// `Elba`
+    Elbasan,
+    /// `Elym`
+    Elymaic,
+    /// `Ethi`
+    Ethiopic,
+    /// `Gara`
+    Garay,
+    /// `Geor`
+    Georgian,
+    /// `Glag`
+    Glagolitic,
+    /// `Gong`
+    GunjalaGondi,
+    /// `Gonm`
+    MasaramGondi,
+    /// `Goth`
+    Gothic,
+    /// `Gran`
+    Grantha,
+    /// `Grek`
+    Greek,
+    /// `Gujr`
+    Gujarati,
+    /// `Gukh`
+    GurungKhema,
+    /// `Guru`
+    Gurmukhi,
+    /// `Hang`
+    Hangul,
+    /// `Hani`
+    Han,
+    /// `Hano`
+    Hanunoo,
+    /// `Hatr`
+    Hatran,
+    /// `Hebr`
+    Hebrew,
+    /// `Hira`
+    Hiragana,
+    /// `Hluw`
+    AnatolianHieroglyphs,
+    /// `Hmng`
+    PahawhHmong,
+    /// `Hmnp`
+    NyiakengPuachueHmong,
+    /// `Hrkt`
+    KatakanaOrHiragana,
+    /// `Hung`
+    OldHungarian,
+    /// `Ital`
+    OldItalic,
+    /// `Java`
+    Javanese,
+    /// `Kali`
+    KayahLi,
+    /// `Kana`
+    Katakana,
+    /// `Kawi`
+    Kawi,
+    /// `Khar`
+    Kharoshthi,
+    /// `Khmr`
+    Khmer,
+    /// `Khoj`
+    Khojki,
+    /// `Kits`
+    KhitanSmallScript,
+    /// `Knda`
+    Kannada,
+    /// `Krai`
+    KiratRai,
+    /// `Kthi`
+    Kaithi,
+    /// `Lana`
+    TaiTham,
+    /// `Laoo`
+    Lao,
+    /// `Latn`
+    Latin,
+    /// `Lepc`
+    Lepcha,
+    /// `Limb`
+    Limbu,
+    /// `Lina`
+    LinearA,
+    /// `Linb`
+    LinearB,
+    /// `Lisu`
+    Lisu,
+    /// `Lyci`
+    Lycian,
+    /// `Lydi`
+    Lydian,
+    /// `Mahj`
+    Mahajani,
+    /// `Maka`
+    Makasar,
+    /// `Mand`
+    Mandaic,
+    /// `Mani`
+    Manichaean,
+    /// `Marc`
+    Marchen,
+    /// `Medf`
+    Medefaidrin,
+    /// `Mend`
+    MendeKikakui,
+    /// `Merc`
+    MeroiticCursive,
+    /// `Mero`
+    MeroiticHieroglyphs,
+    /// `Mlym`
+    Malayalam,
+    /// `Modi`
+    Modi,
+    /// `Mong`
+    Mongolian,
+    /// `Mroo`
+    Mro,
+    /// `Mtei`
+    MeeteiMayek,
+    /// `Mult`
+    Multani,
+    /// `Mymr`
+    Myanmar,
+    /// `Nagm`
+    NagMundari,
+    /// `Nand`
+    Nandinagari,
+    /// `Narb`
+    OldNorthArabian,
+    /// `Nbat`
+    Nabataean,
+    /// `Newa`
+    Newa,
+    /// `Nkoo`
+    Nko,
+    /// `Nshu`
+    Nushu,
+    /// `Ogam`
+    Ogham,
+    /// `Olck`
+    OlChiki,
+    /// `Onao`
+    OlOnal,
+    /// `Orkh`
+    OldTurkic,
+    /// `Orya`
+    Oriya,
+    /// `Osge`
+    Osage,
+    /// `Osma`
+    Osmanya,
+    /// `Ougr`
+    OldUyghur,
+    /// `Palm`
+    Palmyrene,
+    /// `Pauc`
+    PauCinHau,
+    /// `Perm`
+    OldPermic,
+    /// `Phag`
+    PhagsPa,
+    /// `Phli`
+    InscriptionalPahlavi,
+    /// `Phlp`
+    PsalterPahlavi,
+    /// `Phnx`
+    Phoenician,
+    /// `Plrd`
+    Miao,
+    /// `Prti`
+    InscriptionalParthian,
+    /// `Rjng`
+    Rejang,
+    /// `Rohg`
+    HanifiRohingya,
+    /// `Runr`
+    Runic,
+    /// `Samr`
+    Samaritan,
+    /// `Sarb`
+    OldSouthArabian,
+    /// `Saur`
+    Saurashtra,
+    /// `Sgnw`
+    SignWriting,
+    /// `Shaw`
+    Shavian,
+    /// `Shrd`
+    Sharada,
+    /// `Sidd`
+    Siddham,
+    /// `Sind`
+    Khudawadi,
+    /// `Sinh`
+    Sinhala,
+    /// `Sogd`
+    Sogdian,
+    /// `Sogo`
+    OldSogdian,
+    /// `Sora`
+    SoraSompeng,
+    /// `Soyo`
+    Soyombo,
+    /// `Sund`
+    Sundanese,
+    /// `Sunu`
+    Sunuwar,
+    /// `Sylo`
+    SylotiNagri,
+    /// `Syrc`
+    Syriac,
+    /// `Tagb`
+    Tagbanwa,
+    /// `Takr`
+    Takri,
+    /// `Tale`
+    TaiLe,
+    /// `Talu`
+    NewTaiLue,
+    /// `Taml`
+    Tamil,
+    /// `Tang`
+    Tangut,
+    /// `Tavt`
+    TaiViet,
+    /// `Telu`
+    Telugu,
+    /// `Tfng`
+    Tifinagh,
+    /// `Tglg`
+    Tagalog,
+    /// `Thaa`
+    Thaana,
+    /// `Thai`
+    Thai,
+    /// `Tibt`
+    Tibetan,
+    /// `Tirh`
+    Tirhuta,
+    /// `Tnsa`
+    Tangsa,
+    /// `Todr`
+    Todhri,
+    /// `Toto`
+    Toto,
+    /// `Tutg`
+    TuluTigalari,
+    /// `Ugar`
+    Ugaritic,
+    /// `Vaii`
+    Vai,
+    /// `Vith`
+    Vithkuqi,
+    /// `Wara`
+    WarangCiti,
+    /// `Wcho`
+    Wancho,
+    /// `Xpeo`
+    OldPersian,
+    /// `Xsux`
+    Cuneiform,
+    /// `Yezi`
+    Yezidi,
+    /// `Yiii`
+    Yi,
+    /// `Zanb`
+    ZanabazarSquare,
+    /// `Zinh`
+    Inherited,
+    /// `Zyyy`
+    Common,
+    /// `Zzzz`
+    Unknown,
+}
+
+/// Every script, in the order of their table values.
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static SCRIPTS: [Script; 172] = [
+    Script::Adlam,
+    Script::CaucasianAlbanian,
+    Script::Ahom,
+    Script::Arabic,
+    Script::ImperialAramaic,
+    Script::Armenian,
+    Script::Avestan,
+    Script::Balinese,
+    Script::Bamum,
+    Script::BassaVah,
+    Script::Batak,
+    Script::Bengali,
+    Script::Bhaiksuki,
+    Script::Bopomofo,
+    Script::Brahmi,
+    Script::Braille,
+    Script::Buginese,
+    Script::Buhid,
+    Script::Chakma,
+    Script::CanadianAboriginal,
+    Script::Carian,
+    Script::Cham,
+    Script::Cherokee,
+    Script::Chorasmian,
+    Script::Coptic,
+    Script::CyproMinoan,
+    Script::Cypriot,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::DivesAkuru,
+    Script::Dogra,
+    Script::Deseret,
+    Script::Duployan,
+    Script::EgyptianHieroglyphs,
+    Script::Elbasan,
+    Script::Elymaic,
+    Script::Ethiopic,
+    Script::Garay,
+    Script::Georgian,
+    Script::Glagolitic,
+    Script::GunjalaGondi,
+    Script::MasaramGondi,
+    Script::Gothic,
+    Script::Grantha,
+    Script::Greek,
+    Script::Gujarati,
+    Script::GurungKhema,
+    Script::Gurmukhi,
+    Script::Hangul,
+    Script::Han,
+    Script::Hanunoo,
+    Script::Hatran,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::AnatolianHieroglyphs,
+    Script::PahawhHmong,
+    Script::NyiakengPuachueHmong,
+    Script::KatakanaOrHiragana,
+    Script::OldHungarian,
+    Script::OldItalic,
+    Script::Javanese,
+    Script::KayahLi,
+    Script::Katakana,
+    Script::Kawi,
+    Script::Kharoshthi,
+    Script::Khmer,
+    Script::Khojki,
+    Script::KhitanSmallScript,
+    Script::Kannada,
+    Script::KiratRai,
+    Script::Kaithi,
+    Script::TaiTham,
+    Script::Lao,
+    Script::Latin,
+    Script::Lepcha,
+    Script::Limbu,
+    Script::LinearA,
+    Script::LinearB,
+    Script::Lisu,
+    Script::Lycian,
+    Script::Lydian,
+    Script::Mahajani,
+    Script::Makasar,
+    Script::Mandaic,
+    Script::Manichaean,
+    Script::Marchen,
+    Script::Medefaidrin,
+    Script::MendeKikakui,
+    Script::MeroiticCursive,
+    Script::MeroiticHieroglyphs,
+    Script::Malayalam,
+    Script::Modi,
+    Script::Mongolian,
+    Script::Mro,
+    Script::MeeteiMayek,
+    Script::Multani,
+    Script::Myanmar,
+    Script::NagMundari,
+    Script::Nandinagari,
+    Script::OldNorthArabian,
+    Script::Nabataean,
+    Script::Newa,
+    Script::Nko,
+    Script::Nushu,
+    Script::Ogham,
+    Script::OlChiki,
+    Script::OlOnal,
+    Script::OldTurkic,
+    Script::Oriya,
+    Script::Osage,
+    Script::Osmanya,
+    Script::OldUyghur,
+    Script::Palmyrene,
+    Script::PauCinHau,
+    Script::OldPermic,
+    Script::PhagsPa,
+    Script::InscriptionalPahlavi,
+    Script::PsalterPahlavi,
+    Script::Phoenician,
+    Script::Miao,
+    Script::InscriptionalParthian,
+    Script::Rejang,
+    Script::HanifiRohingya,
+    Script::Runic,
+    Script::Samaritan,
+    Script::OldSouthArabian,
+    Script::Saurashtra,
+    Script::SignWriting,
+    Script::Shavian,
+    Script::Sharada,
+    Script::Siddham,
+    Script::Khudawadi,
+    Script::Sinhala,
+    Script::Sogdian,
+    Script::OldSogdian,
+    Script::SoraSompeng,
+    Script::Soyombo,
+    Script::Sundanese,
+    Script::Sunuwar,
+    Script::SylotiNagri,
+    Script::Syriac,
+    Script::Tagbanwa,
+    Script::Takri,
+    Script::TaiLe,
+    Script::NewTaiLue,
+    Script::Tamil,
+    Script::Tangut,
+    Script::TaiViet,
+    Script::Telugu,
+    Script::Tifinagh,
+    Script::Tagalog,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+    Script::Tirhuta,
+    Script::Tangsa,
+    Script::Todhri,
+    Script::Toto,
+    Script::TuluTigalari,
+    Script::Ugaritic,
+    Script::Vai,
+    Script::Vithkuqi,
+    Script::WarangCiti,
+    Script::Wancho,
+    Script::OldPersian,
+    Script::Cuneiform,
+    Script::Yezidi,
+    Script::Yi,
+    Script::ZanabazarSquare,
+    Script::Inherited,
+    Script::Common,
+    Script::Unknown,
+];
+
+/// The short and long names of every script, in the order of their
+/// table values.
+static SCRIPT_NAMES: [(&str, &str); 172] = [
+    ("Adlm", "Adlam"),
+    ("Aghb", "Caucasian_Albanian"),
+    ("Ahom", "Ahom"),
+    ("Arab", "Arabic"),
+    ("Armi", "Imperial_Aramaic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa_Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cakm", "Chakma"),
+    ("Cans", "Canadian_Aboriginal"),
+    ("Cari", "Carian"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Copt", "Coptic"),
+    ("Cpmn", "Cypro_Minoan"),
+    ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives_Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian_Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Gara", "Garay"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Gong", "Gunjala_Gondi"),
+    ("Gonm", "Masaram_Gondi"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gukh", "Gurung_Khema"),
+    ("Guru", "Gurmukhi"),
+    ("Hang", "Hangul"),
+    ("Hani", "Han"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Hluw", "Anatolian_Hieroglyphs"),
+    ("Hmng", "Pahawh_Hmong"),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong"),
+    ("Hrkt", "Katakana_Or_Hiragana"),
+    ("Hung", "Old_Hungarian"),
+    ("Ital", "Old_Italic"),
+    ("Java", "Javanese"),
+    ("Kali", "Kayah_Li"),
+    ("Kana", "Katakana"),
+    ("Kawi", "Kawi"),
+    ("Khar", "Kharoshthi"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Kits", "Khitan_Small_Script"),
+    ("Knda", "Kannada"),
+    ("Krai", "Kirat_Rai"),
+    ("Kthi", "Kaithi"),
+    ("Lana", "Tai_Tham"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear_A"),
+    ("Linb", "Linear_B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Medf", "Medefaidrin"),
+    ("Mend", "Mende_Kikakui"),
+    ("Merc", "Meroitic_Cursive"),
+    ("Mero", "Meroitic_Hieroglyphs"),
+    ("Mlym", "Malayalam"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mtei", "Meetei_Mayek"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nagm", "Nag_Mundari"),
+    ("Nand", "Nandinagari"),
+    ("Narb", "Old_North_Arabian"),
+    ("Nbat", "Nabataean"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol_Chiki"),
+    ("Onao", "Ol_Onal"),
+    ("Orkh", "Old_Turkic"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Ougr", "Old_Uyghur"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau_Cin_Hau"),
+    ("Perm", "Old_Permic"),
+    ("Phag", "Phags_Pa"),
+    ("Phli", "Inscriptional_Pahlavi"),
+    ("Phlp", "Psalter_Pahlavi"),
+    ("Phnx", "Phoenician"),
+    ("Plrd", "Miao"),
+    ("Prti", "Inscriptional_Parthian"),
+    ("Rjng", "Rejang"),
+    ("Rohg", "Hanifi_Rohingya"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Sarb", "Old_South_Arabian"),
+    ("Saur", "Saurashtra"),
+    ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"),
+    ("Shrd", "Sharada"),
+    ("Sidd", "Siddham"),
+    ("Sind", "Khudawadi"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sogo", "Old_Sogdian"),
+    ("Sora", "Sora_Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sunu", "Sunuwar"),
+    ("Sylo", "Syloti_Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tagb", "Tagbanwa"),
+    ("Takr", "Takri"),
+    ("Tale", "Tai_Le"),
+    ("Talu", "New_Tai_Lue"),
+    ("Taml", "Tamil"),
+    ("Tang", "Tangut"),
+    ("Tavt", "Tai_Viet"),
+    ("Telu", "Telugu"),
+    ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"),
+    ("Tnsa", "Tangsa"),
+    ("Todr", "Todhri"),
+    ("Toto", "Toto"),
+    ("Tutg", "Tulu_Tigalari"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wara", "Warang_Citi"),
+    ("Wcho", "Wancho"),
+    ("Xpeo", "Old_Persian"),
+    ("Xsux", "Cuneiform"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar_Square"),
+    ("Zinh", "Inherited"),
+    ("Zyyy", "Common"),
+    ("Zzzz", "Unknown"),
+];
+
+/// The distinct sets of **Script_Extensions** that differ from the
+/// **Script** of the characters they belong to.
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static SCRIPT_EXTENSIONS: [&[Script]; 67] = [
+    &[Script::Sunuwar],
+    &[Script::Sunuwar, Script::Todhri],
+    &[Script::Todhri],
+    &[Script::Greek],
+    &[Script::Latin],
+    &[Script::Cyrillic, Script::OldPermic],
+    &[Script::Cyrillic, Script::Glagolitic],
+    &[Script::Cyrillic, Script::Latin],
+    &[
+        Script::Arabic,
+        Script::Garay,
+        Script::Nko,
+        Script::HanifiRohingya,
+        Script::Syriac,
+        Script::Thaana,
+        Script::Yezidi,
+    ],
+    &[Script::Arabic, Script::Syriac, Script::Thaana],
+    &[
+        Script::Adlam,
+        Script::Arabic,
+        Script::Garay,
+        Script::Nko,
+        Script::HanifiRohingya,
+        Script::Syriac,
+        Script::Thaana,
+        Script::Yezidi,
+    ],
+    &[
+        Script::Adlam,
+        Script::Arabic,
+        Script::Mandaic,
+        Script::Manichaean,
+        Script::OldUyghur,
+        Script::PsalterPahlavi,
+        Script::HanifiRohingya,
+        Script::Sogdian,
+        Script::Syriac,
+    ],
+    &[Script::Arabic, Script::Syriac],
+    &[Script::Arabic, Script::Thaana, Script::Yezidi],
+    &[Script::Arabic, Script::HanifiRohingya],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Grantha,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Kannada,
+        Script::Latin,
+        Script::Malayalam,
+        Script::Oriya,
+        Script::Sharada,
+        Script::Tamil,
+        Script::Telugu,
+        Script::Tirhuta,
+    ],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Grantha,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Kannada,
+        Script::Latin,
+        Script::Malayalam,
+        Script::Oriya,
+        Script::Tamil,
+        Script::Telugu,
+        Script::Tirhuta,
+    ],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Dogra,
+        Script::GunjalaGondi,
+        Script::MasaramGondi,
+        Script::Grantha,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Kannada,
+        Script::Mahajani,
+        Script::Malayalam,
+        Script::Nandinagari,
+        Script::OlOnal,
+        Script::Oriya,
+        Script::Khudawadi,
+        Script::Sinhala,
+        Script::SylotiNagri,
+        Script::Takri,
+        Script::Tamil,
+        Script::Telugu,
+        Script::Tirhuta,
+    ],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Dogra,
+        Script::GunjalaGondi,
+        Script::MasaramGondi,
+        Script::Grantha,
+        Script::Gujarati,
+        Script::GurungKhema,
+        Script::Gurmukhi,
+        Script::Kannada,
+        Script::Limbu,
+        Script::Mahajani,
+        Script::Malayalam,
+        Script::Nandinagari,
+        Script::OlOnal,
+        Script::Oriya,
+        Script::Khudawadi,
+        Script::Sinhala,
+        Script::SylotiNagri,
+        Script::Takri,
+        Script::Tamil,
+        Script::Telugu,
+        Script::Tirhuta,
+    ],
+    &[
+        Script::Devanagari,
+        Script::Dogra,
+        Script::Kaithi,
+        Script::Mahajani,
+    ],
+    &[Script::Bengali, Script::Chakma, Script::SylotiNagri],
+    &[Script::Gurmukhi, Script::Multani],
+    &[Script::Gujarati, Script::Khojki],
+    &[Script::Grantha, Script::Tamil],
+    &[Script::Kannada, Script::Nandinagari, Script::TuluTigalari],
+    &[Script::Chakma, Script::Myanmar, Script::TaiLe],
+    &[Script::Georgian, Script::Latin],
+    &[
+        Script::Buhid,
+        Script::Hanunoo,
+        Script::Tagbanwa,
+        Script::Tagalog,
+    ],
+    &[Script::Mongolian, Script::PhagsPa],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Grantha,
+        Script::Kannada,
+    ],
+    &[Script::Devanagari],
+    &[Script::Devanagari, Script::Grantha],
+    &[Script::Bengali, Script::Devanagari],
+    &[Script::Devanagari, Script::Sharada],
+    &[
+        Script::Devanagari,
+        Script::Kannada,
+        Script::Malayalam,
+        Script::Oriya,
+        Script::Tamil,
+        Script::Telugu,
+    ],
+    &[Script::Devanagari, Script::Nandinagari],
+    &[
+        Script::Bengali,
+        Script::Devanagari,
+        Script::Grantha,
+        Script::Kannada,
+        Script::Nandinagari,
+        Script::Oriya,
+        Script::Telugu,
+        Script::Tirhuta,
+        Script::TuluTigalari,
+    ],
+    &[
+        Script::Devanagari,
+        Script::Grantha,
+        Script::Kannada,
+        Script::TuluTigalari,
+    ],
+    &[Script::Bengali],
+    &[Script::Nandinagari],
+    &[Script::Cyrillic, Script::Syriac],
+    &[Script::Syriac],
+    &[Script::Latin, Script::Mongolian],
+    &[Script::Devanagari, Script::Grantha, Script::Latin],
+    &[Script::Han, Script::Tangut],
+    &[
+        Script::Bopomofo,
+        Script::Hangul,
+        Script::Han,
+        Script::Hiragana,
+        Script::Katakana,
+        Script::Yi,
+    ],
+    &[
+        Script::Bopomofo,
+        Script::Hangul,
+        Script::Han,
+        Script::Hiragana,
+        Script::Katakana,
+    ],
+    &[Script::Han],
+    &[Script::Bopomofo, Script::Han],
+    &[Script::Hiragana, Script::Katakana],
+    &[Script::Han, Script::Hiragana, Script::Katakana],
+    &[Script::Han, Script::Latin],
+    &[
+        Script::Devanagari,
+        Script::Dogra,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Khojki,
+        Script::Kannada,
+        Script::Kaithi,
+        Script::Mahajani,
+        Script::Malayalam,
+        Script::Modi,
+        Script::Nandinagari,
+        Script::Khudawadi,
+        Script::Takri,
+        Script::Tirhuta,
+        Script::TuluTigalari,
+    ],
+    &[
+        Script::Devanagari,
+        Script::Dogra,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Khojki,
+        Script::Kannada,
+        Script::Kaithi,
+        Script::Mahajani,
+        Script::Modi,
+        Script::Nandinagari,
+        Script::Khudawadi,
+        Script::Takri,
+        Script::Tirhuta,
+        Script::TuluTigalari,
+    ],
+    &[
+        Script::Devanagari,
+        Script::Dogra,
+        Script::Gujarati,
+        Script::Gurmukhi,
+        Script::Khojki,
+        Script::Kaithi,
+        Script::Mahajani,
+        Script::Modi,
+        Script::Khudawadi,
+        Script::Takri,
+        Script::Tirhuta,
+    ],
+    &[Script::Bengali, Script::Devanagari, Script::TuluTigalari],
+    &[Script::Devanagari, Script::Tamil],
+    &[Script::KayahLi, Script::Latin, Script::Myanmar],
+    &[Script::Buginese, Script::Javanese],
+    &[Script::Arabic, Script::Nko],
+    &[Script::Arabic, Script::Thaana],
+    &[Script::CyproMinoan, Script::Cypriot, Script::LinearB],
+    &[Script::Cypriot, Script::LinearB],
+    &[Script::Cypriot, Script::LinearA, Script::LinearB],
+    &[Script::Arabic, Script::Coptic],
+    &[Script::Manichaean, Script::OldUyghur],
+    &[Script::Duployan],
+];
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static SCRIPT_RUNS: [u32; 1708] = [
+    0x000000aa, 0x00004149, 0x00005baa, 0x00006149, 0x00007baa, 0x0000aa49, 0x0000abaa, 0x0000ba49,
+    0x0000bbaa, 0x0000c049, 0x0000d7aa, 0x0000d849, 0x0000f7aa, 0x0000f849, 0x0002b9aa, 0x0002e049,
+    0x0002e5aa, 0x0002ea0d, 0x0002ecaa, 0x000300a9, 0x0003702c, 0x000374aa, 0x0003752c, 0x000378ab,
+    0x00037a2c, 0x00037eaa, 0x00037f2c, 0x000380ab, 0x0003842c, 0x000385aa, 0x0003862c, 0x000387aa,
+    0x0003882c, 0x00038bab, 0x00038c2c, 0x00038dab, 0x00038e2c, 0x0003a2ab, 0x0003a32c, 0x0003e218,
+    0x0003f02c, 0x0004001b, 0x000485a9, 0x0004871b, 0x000530ab, 0x00053105, 0x000557ab, 0x00055905,
+    0x00058bab, 0x00058d05, 0x000590ab, 0x00059134, 0x0005c8ab, 0x0005d034, 0x0005ebab, 0x0005ef34,
+    0x0005f5ab, 0x00060003, 0x000605aa, 0x00060603, 0x00060caa, 0x00060d03, 0x00061baa, 0x00061c03,
+    0x00061faa, 0x00062003, 0x000640aa, 0x00064103, 0x00064ba9, 0x00065603, 0x000670a9, 0x00067103,
+    0x0006ddaa, 0x0006de03, 0x0007008c, 0x00070eab, 0x00070f8c, 0x00074bab, 0x00074d8c, 0x00075003,
+    0x00078097, 0x0007b2ab, 0x0007c066, 0x0007fbab, 0x0007fd66, 0x0008007c, 0x00082eab, 0x0008307c,
+    0x00083fab, 0x00084053, 0x00085cab, 0x00085e53, 0x00085fab, 0x0008608c, 0x00086bab, 0x00087003,
+    0x00088fab, 0x00089003, 0x000892ab, 0x00089703, 0x0008e2aa, 0x0008e303, 0x0009001c, 0x000951a9,
+    0x0009551c, 0x000964aa, 0x0009661c, 0x0009800b, 0x000984ab, 0x0009850b, 0x00098dab, 0x00098f0b,
+    0x000991ab, 0x0009930b, 0x0009a9ab, 0x0009aa0b, 0x0009b1ab, 0x0009b20b, 0x0009b3ab, 0x0009b60b,
+    0x0009baab, 0x0009bc0b, 0x0009c5ab, 0x0009c70b, 0x0009c9ab, 0x0009cb0b, 0x0009cfab, 0x0009d70b,
+    0x0009d8ab, 0x0009dc0b, 0x0009deab, 0x0009df0b, 0x0009e4ab, 0x0009e60b, 0x0009ffab, 0x000a012f,
+    0x000a04ab, 0x000a052f, 0x000a0bab, 0x000a0f2f, 0x000a11ab, 0x000a132f, 0x000a29ab, 0x000a2a2f,
+    0x000a31ab, 0x000a322f, 0x000a34ab, 0x000a352f, 0x000a37ab, 0x000a382f, 0x000a3aab, 0x000a3c2f,
+    0x000a3dab, 0x000a3e2f, 0x000a43ab, 0x000a472f, 0x000a49ab, 0x000a4b2f, 0x000a4eab, 0x000a512f,
+    0x000a52ab, 0x000a592f, 0x000a5dab, 0x000a5e2f, 0x000a5fab, 0x000a662f, 0x000a77ab, 0x000a812d,
+    0x000a84ab, 0x000a852d, 0x000a8eab, 0x000a8f2d, 0x000a92ab, 0x000a932d, 0x000aa9ab, 0x000aaa2d,
+    0x000ab1ab, 0x000ab22d, 0x000ab4ab, 0x000ab52d, 0x000abaab, 0x000abc2d, 0x000ac6ab, 0x000ac72d,
+    0x000acaab, 0x000acb2d, 0x000aceab, 0x000ad02d, 0x000ad1ab, 0x000ae02d, 0x000ae4ab, 0x000ae62d,
+    0x000af2ab, 0x000af92d, 0x000b00ab, 0x000b016c, 0x000b04ab, 0x000b056c, 0x000b0dab, 0x000b0f6c,
+    0x000b11ab, 0x000b136c, 0x000b29ab, 0x000b2a6c, 0x000b31ab, 0x000b326c, 0x000b34ab, 0x000b356c,
+    0x000b3aab, 0x000b3c6c, 0x000b45ab, 0x000b476c, 0x000b49ab, 0x000b4b6c, 0x000b4eab, 0x000b556c,
+    0x000b58ab, 0x000b5c6c, 0x000b5eab, 0x000b5f6c, 0x000b64ab, 0x000b666c, 0x000b78ab, 0x000b8291,
+    0x000b84ab, 0x000b8591, 0x000b8bab, 0x000b8e91, 0x000b91ab, 0x000b9291, 0x000b96ab, 0x000b9991,
+    0x000b9bab, 0x000b9c91, 0x000b9dab, 0x000b9e91, 0x000ba0ab, 0x000ba391, 0x000ba5ab, 0x000ba891,
+    0x000babab, 0x000bae91, 0x000bbaab, 0x000bbe91, 0x000bc3ab, 0x000bc691, 0x000bc9ab, 0x000bca91,
+    0x000bceab, 0x000bd091, 0x000bd1ab, 0x000bd791, 0x000bd8ab, 0x000be691, 0x000bfbab, 0x000c0094,
+    0x000c0dab, 0x000c0e94, 0x000c11ab, 0x000c1294, 0x000c29ab, 0x000c2a94, 0x000c3aab, 0x000c3c94,
+    0x000c45ab, 0x000c4694, 0x000c49ab, 0x000c4a94, 0x000c4eab, 0x000c5594, 0x000c57ab, 0x000c5894,
+    0x000c5bab, 0x000c5d94, 0x000c5eab, 0x000c6094, 0x000c64ab, 0x000c6694, 0x000c70ab, 0x000c7794,
+    0x000c8044, 0x000c8dab, 0x000c8e44, 0x000c91ab, 0x000c9244, 0x000ca9ab, 0x000caa44, 0x000cb4ab,
+    0x000cb544, 0x000cbaab, 0x000cbc44, 0x000cc5ab, 0x000cc644, 0x000cc9ab, 0x000cca44, 0x000cceab,
+    0x000cd544, 0x000cd7ab, 0x000cdd44, 0x000cdfab, 0x000ce044, 0x000ce4ab, 0x000ce644, 0x000cf0ab,
+    0x000cf144, 0x000cf4ab, 0x000d005a, 0x000d0dab, 0x000d0e5a, 0x000d11ab, 0x000d125a, 0x000d45ab,
+    0x000d465a, 0x000d49ab, 0x000d4a5a, 0x000d50ab, 0x000d545a, 0x000d64ab, 0x000d665a, 0x000d80ab,
+    0x000d8184, 0x000d84ab, 0x000d8584, 0x000d97ab, 0x000d9a84, 0x000db2ab, 0x000db384, 0x000dbcab,
+    0x000dbd84, 0x000dbeab, 0x000dc084, 0x000dc7ab, 0x000dca84, 0x000dcbab, 0x000dcf84, 0x000dd5ab,
+    0x000dd684, 0x000dd7ab, 0x000dd884, 0x000de0ab, 0x000de684, 0x000df0ab, 0x000df284, 0x000df5ab,
+    0x000e0198, 0x000e3bab, 0x000e3faa, 0x000e4098, 0x000e5cab, 0x000e8148, 0x000e83ab, 0x000e8448,
+    0x000e85ab, 0x000e8648, 0x000e8bab, 0x000e8c48, 0x000ea4ab, 0x000ea548, 0x000ea6ab, 0x000ea748,
+    0x000ebeab, 0x000ec048, 0x000ec5ab, 0x000ec648, 0x000ec7ab, 0x000ec848, 0x000ecfab, 0x000ed048,
+    0x000edaab, 0x000edc48, 0x000ee0ab, 0x000f0099, 0x000f48ab, 0x000f4999, 0x000f6dab, 0x000f7199,
+    0x000f98ab, 0x000f9999, 0x000fbdab, 0x000fbe99, 0x000fcdab, 0x000fce99, 0x000fd5aa, 0x000fd999,
+    0x000fdbab, 0x00100060, 0x0010a026, 0x0010c6ab, 0x0010c726, 0x0010c8ab, 0x0010cd26, 0x0010ceab,
+    0x0010d026, 0x0010fbaa, 0x0010fc26, 0x00110030, 0x00120024, 0x001249ab, 0x00124a24, 0x00124eab,
+    0x00125024, 0x001257ab, 0x00125824, 0x001259ab, 0x00125a24, 0x00125eab, 0x00126024, 0x001289ab,
+    0x00128a24, 0x00128eab, 0x00129024, 0x0012b1ab, 0x0012b224, 0x0012b6ab, 0x0012b824, 0x0012bfab,
+    0x0012c024, 0x0012c1ab, 0x0012c224, 0x0012c6ab, 0x0012c824, 0x0012d7ab, 0x0012d824, 0x001311ab,
+    0x00131224, 0x001316ab, 0x00131824, 0x00135bab, 0x00135d24, 0x00137dab, 0x00138024, 0x00139aab,
+    0x0013a016, 0x0013f6ab, 0x0013f816, 0x0013feab, 0x00140013, 0x00168068, 0x00169dab, 0x0016a07b,
+    0x0016ebaa, 0x0016ee7b, 0x0016f9ab, 0x00170096, 0x001716ab, 0x00171f96, 0x00172032, 0x001735aa,
+    0x001737ab, 0x00174011, 0x001754ab, 0x0017608d, 0x00176dab, 0x00176e8d, 0x001771ab, 0x0017728d,
+    0x001774ab, 0x00178041, 0x0017deab, 0x0017e041, 0x0017eaab, 0x0017f041, 0x0017faab, 0x0018005c,
+    0x001802aa, 0x0018045c, 0x001805aa, 0x0018065c, 0x00181aab, 0x0018205c, 0x001879ab, 0x0018805c,
+    0x0018abab, 0x0018b013, 0x0018f6ab, 0x0019004b, 0x00191fab, 0x0019204b, 0x00192cab, 0x0019304b,
+    0x00193cab, 0x0019404b, 0x001941ab, 0x0019444b, 0x0019508f, 0x00196eab, 0x0019708f, 0x001975ab,
+    0x00198090, 0x0019acab, 0x0019b090, 0x0019caab, 0x0019d090, 0x0019dbab, 0x0019de90, 0x0019e041,
+    0x001a0010, 0x001a1cab, 0x001a1e10, 0x001a2047, 0x001a5fab, 0x001a6047, 0x001a7dab, 0x001a7f47,
+    0x001a8aab, 0x001a9047, 0x001a9aab, 0x001aa047, 0x001aaeab, 0x001ab0a9, 0x001acfab, 0x001b0007,
+    0x001b4dab, 0x001b4e07, 0x001b8089, 0x001bc00a, 0x001bf4ab, 0x001bfc0a, 0x001c004a, 0x001c38ab,
+    0x001c3b4a, 0x001c4aab, 0x001c4d4a, 0x001c5069, 0x001c801b, 0x001c8bab, 0x001c9026, 0x001cbbab,
+    0x001cbd26, 0x001cc089, 0x001cc8ab, 0x001cd0a9, 0x001cd3aa, 0x001cd4a9, 0x001ce1aa, 0x001ce2a9,
+    0x001ce9aa, 0x001ceda9, 0x001ceeaa, 0x001cf4a9, 0x001cf5aa, 0x001cf8a9, 0x001cfaaa, 0x001cfbab,
+    0x001d0049, 0x001d262c, 0x001d2b1b, 0x001d2c49, 0x001d5d2c, 0x001d6249, 0x001d662c, 0x001d6b49,
+    0x001d781b, 0x001d7949, 0x001dbf2c, 0x001dc0a9, 0x001e0049, 0x001f002c, 0x001f16ab, 0x001f182c,
+    0x001f1eab, 0x001f202c, 0x001f46ab, 0x001f482c, 0x001f4eab, 0x001f502c, 0x001f58ab, 0x001f592c,
+    0x001f5aab, 0x001f5b2c, 0x001f5cab, 0x001f5d2c, 0x001f5eab, 0x001f5f2c, 0x001f7eab, 0x001f802c,
+    0x001fb5ab, 0x001fb62c, 0x001fc5ab, 0x001fc62c, 0x001fd4ab, 0x001fd62c, 0x001fdcab, 0x001fdd2c,
+    0x001ff0ab, 0x001ff22c, 0x001ff5ab, 0x001ff62c, 0x001fffab, 0x002000aa, 0x00200ca9, 0x00200eaa,
+    0x002065ab, 0x002066aa, 0x00207149, 0x002072ab, 0x002074aa, 0x00207f49, 0x002080aa, 0x00208fab,
+    0x00209049, 0x00209dab, 0x0020a0aa, 0x0020c1ab, 0x0020d0a9, 0x0020f1ab, 0x002100aa, 0x0021262c,
+    0x002127aa, 0x00212a49, 0x00212caa, 0x00213249, 0x002133aa, 0x00214e49, 0x00214faa, 0x00216049,
+    0x002189aa, 0x00218cab, 0x002190aa, 0x00242aab, 0x002440aa, 0x00244bab, 0x002460aa, 0x0028000f,
+    0x002900aa, 0x002b74ab, 0x002b76aa, 0x002b96ab, 0x002b97aa, 0x002c0027, 0x002c6049, 0x002c8018,
+    0x002cf4ab, 0x002cf918, 0x002d0026, 0x002d26ab, 0x002d2726, 0x002d28ab, 0x002d2d26, 0x002d2eab,
+    0x002d3095, 0x002d68ab, 0x002d6f95, 0x002d71ab, 0x002d7f95, 0x002d8024, 0x002d97ab, 0x002da024,
+    0x002da7ab, 0x002da824, 0x002dafab, 0x002db024, 0x002db7ab, 0x002db824, 0x002dbfab, 0x002dc024,
+    0x002dc7ab, 0x002dc824, 0x002dcfab, 0x002dd024, 0x002dd7ab, 0x002dd824, 0x002ddfab, 0x002de01b,
+    0x002e00aa, 0x002e5eab, 0x002e8031, 0x002e9aab, 0x002e9b31, 0x002ef4ab, 0x002f0031, 0x002fd6ab,
+    0x002ff0aa, 0x00300531, 0x003006aa, 0x00300731, 0x003008aa, 0x00302131, 0x00302aa9, 0x00302e30,
+    0x003030aa, 0x00303831, 0x00303caa, 0x003040ab, 0x00304135, 0x003097ab, 0x003099a9, 0x00309baa,
+    0x00309d35, 0x0030a0aa, 0x0030a13e, 0x0030fbaa, 0x0030fd3e, 0x003100ab, 0x0031050d, 0x003130ab,
+    0x00313130, 0x00318fab, 0x003190aa, 0x0031a00d, 0x0031c0aa, 0x0031e6ab, 0x0031efaa, 0x0031f03e,
+    0x00320030, 0x00321fab, 0x003220aa, 0x00326030, 0x00327faa, 0x0032d03e, 0x0032ffaa, 0x0033003e,
+    0x003358aa, 0x00340031, 0x004dc0aa, 0x004e0031, 0x00a000a7, 0x00a48dab, 0x00a490a7, 0x00a4c7ab,
+    0x00a4d04e, 0x00a500a0, 0x00a62cab, 0x00a6401b, 0x00a6a008, 0x00a6f8ab, 0x00a700aa, 0x00a72249,
+    0x00a788aa, 0x00a78b49, 0x00a7ceab, 0x00a7d049, 0x00a7d2ab, 0x00a7d349, 0x00a7d4ab, 0x00a7d549,
+    0x00a7ddab, 0x00a7f249, 0x00a8008b, 0x00a82dab, 0x00a830aa, 0x00a83aab, 0x00a84073, 0x00a878ab,
+    0x00a8807e, 0x00a8c6ab, 0x00a8ce7e, 0x00a8daab, 0x00a8e01c, 0x00a9003d, 0x00a92eaa, 0x00a92f3d,
+    0x00a93079, 0x00a954ab, 0x00a95f79, 0x00a96030, 0x00a97dab, 0x00a9803c, 0x00a9ceab, 0x00a9cfaa,
+    0x00a9d03c, 0x00a9daab, 0x00a9de3c, 0x00a9e060, 0x00a9ffab, 0x00aa0015, 0x00aa37ab, 0x00aa4015,
+    0x00aa4eab, 0x00aa5015, 0x00aa5aab, 0x00aa5c15, 0x00aa6060, 0x00aa8093, 0x00aac3ab, 0x00aadb93,
+    0x00aae05e, 0x00aaf7ab, 0x00ab0124, 0x00ab07ab, 0x00ab0924, 0x00ab0fab, 0x00ab1124, 0x00ab17ab,
+    0x00ab2024, 0x00ab27ab, 0x00ab2824, 0x00ab2fab, 0x00ab3049, 0x00ab5baa, 0x00ab5c49, 0x00ab652c,
+    0x00ab6649, 0x00ab6aaa, 0x00ab6cab, 0x00ab7016, 0x00abc05e, 0x00abeeab, 0x00abf05e, 0x00abfaab,
+    0x00ac0030, 0x00d7a4ab, 0x00d7b030, 0x00d7c7ab, 0x00d7cb30, 0x00d7fcab, 0x00f90031, 0x00fa6eab,
+    0x00fa7031, 0x00fadaab, 0x00fb0049, 0x00fb07ab, 0x00fb1305, 0x00fb18ab, 0x00fb1d34, 0x00fb37ab,
+    0x00fb3834, 0x00fb3dab, 0x00fb3e34, 0x00fb3fab, 0x00fb4034, 0x00fb42ab, 0x00fb4334, 0x00fb45ab,
+    0x00fb4634, 0x00fb5003, 0x00fbc3ab, 0x00fbd303, 0x00fd3eaa, 0x00fd4003, 0x00fd90ab, 0x00fd9203,
+    0x00fdc8ab, 0x00fdcf03, 0x00fdd0ab, 0x00fdf003, 0x00fe00a9, 0x00fe10aa, 0x00fe1aab, 0x00fe20a9,
+    0x00fe2e1b, 0x00fe30aa, 0x00fe53ab, 0x00fe54aa, 0x00fe67ab, 0x00fe68aa, 0x00fe6cab, 0x00fe7003,
+    0x00fe75ab, 0x00fe7603, 0x00fefdab, 0x00feffaa, 0x00ff00ab, 0x00ff01aa, 0x00ff2149, 0x00ff3baa,
+    0x00ff4149, 0x00ff5baa, 0x00ff663e, 0x00ff70aa, 0x00ff713e, 0x00ff9eaa, 0x00ffa030, 0x00ffbfab,
+    0x00ffc230, 0x00ffc8ab, 0x00ffca30, 0x00ffd0ab, 0x00ffd230, 0x00ffd8ab, 0x00ffda30, 0x00ffddab,
+    0x00ffe0aa, 0x00ffe7ab, 0x00ffe8aa, 0x00ffefab, 0x00fff9aa, 0x00fffeab, 0x0100004d, 0x01000cab,
+    0x01000d4d, 0x010027ab, 0x0100284d, 0x01003bab, 0x01003c4d, 0x01003eab, 0x01003f4d, 0x01004eab,
+    0x0100504d, 0x01005eab, 0x0100804d, 0x0100fbab, 0x010100aa, 0x010103ab, 0x010107aa, 0x010134ab,
+    0x010137aa, 0x0101402c, 0x01018fab, 0x010190aa, 0x01019dab, 0x0101a02c, 0x0101a1ab, 0x0101d0aa,
+    0x0101fda9, 0x0101feab, 0x0102804f, 0x01029dab, 0x0102a014, 0x0102d1ab, 0x0102e0a9, 0x0102e1aa,
+    0x0102fcab, 0x0103003b, 0x010324ab, 0x01032d3b, 0x0103302a, 0x01034bab, 0x01035072, 0x01037bab,
+    0x0103809f, 0x01039eab, 0x01039f9f, 0x0103a0a4, 0x0103c4ab, 0x0103c8a4, 0x0103d6ab, 0x0104001f,
+    0x01045080, 0x0104806e, 0x01049eab, 0x0104a06e, 0x0104aaab, 0x0104b06d, 0x0104d4ab, 0x0104d86d,
+    0x0104fcab, 0x01050022, 0x010528ab, 0x01053001, 0x010564ab, 0x01056f01, 0x010570a1, 0x01057bab,
+    0x01057ca1, 0x01058bab, 0x01058ca1, 0x010593ab, 0x010594a1, 0x010596ab, 0x010597a1, 0x0105a2ab,
+    0x0105a3a1, 0x0105b2ab, 0x0105b3a1, 0x0105baab, 0x0105bba1, 0x0105bdab, 0x0105c09c, 0x0105f4ab,
+    0x0106004c, 0x010737ab, 0x0107404c, 0x010756ab, 0x0107604c, 0x010768ab, 0x01078049, 0x010786ab,
+    0x01078749, 0x0107b1ab, 0x0107b249, 0x0107bbab, 0x0108001a, 0x010806ab, 0x0108081a, 0x010809ab,
+    0x01080a1a, 0x010836ab, 0x0108371a, 0x010839ab, 0x01083c1a, 0x01083dab, 0x01083f1a, 0x01084004,
+    0x010856ab, 0x01085704, 0x01086070, 0x01088064, 0x01089fab, 0x0108a764, 0x0108b0ab, 0x0108e033,
+    0x0108f3ab, 0x0108f433, 0x0108f6ab, 0x0108fb33, 0x01090076, 0x01091cab, 0x01091f76, 0x01092050,
+    0x01093aab, 0x01093f50, 0x010940ab, 0x01098059, 0x0109a058, 0x0109b8ab, 0x0109bc58, 0x0109d0ab,
+    0x0109d258, 0x010a0040, 0x010a04ab, 0x010a0540, 0x010a07ab, 0x010a0c40, 0x010a14ab, 0x010a1540,
+    0x010a18ab, 0x010a1940, 0x010a36ab, 0x010a3840, 0x010a3bab, 0x010a3f40, 0x010a49ab, 0x010a5040,
+    0x010a59ab, 0x010a607d, 0x010a8063, 0x010aa0ab, 0x010ac054, 0x010ae7ab, 0x010aeb54, 0x010af7ab,
+    0x010b0006, 0x010b36ab, 0x010b3906, 0x010b4078, 0x010b56ab, 0x010b5878, 0x010b6074, 0x010b73ab,
+    0x010b7874, 0x010b8075, 0x010b92ab, 0x010b9975, 0x010b9dab, 0x010ba975, 0x010bb0ab, 0x010c006b,
+    0x010c49ab, 0x010c803a, 0x010cb3ab, 0x010cc03a, 0x010cf3ab, 0x010cfa3a, 0x010d007a, 0x010d28ab,
+    0x010d307a, 0x010d3aab, 0x010d4025, 0x010d66ab, 0x010d6925, 0x010d86ab, 0x010d8e25, 0x010d90ab,
+    0x010e6003, 0x010e7fab, 0x010e80a6, 0x010eaaab, 0x010eaba6, 0x010eaeab, 0x010eb0a6, 0x010eb2ab,
+    0x010ec203, 0x010ec5ab, 0x010efc03, 0x010f0086, 0x010f28ab, 0x010f3085, 0x010f5aab, 0x010f706f,
+    0x010f8aab, 0x010fb017, 0x010fccab, 0x010fe023, 0x010ff7ab, 0x0110000e, 0x01104eab, 0x0110520e,
+    0x011076ab, 0x01107f0e, 0x01108046, 0x0110c3ab, 0x0110cd46, 0x0110ceab, 0x0110d087, 0x0110e9ab,
+    0x0110f087, 0x0110faab, 0x01110012, 0x011135ab, 0x01113612, 0x011148ab, 0x01115051, 0x011177ab,
+    0x01118081, 0x0111e0ab, 0x0111e184, 0x0111f5ab, 0x01120042, 0x011212ab, 0x01121342, 0x011242ab,
+    0x0112805f, 0x011287ab, 0x0112885f, 0x011289ab, 0x01128a5f, 0x01128eab, 0x01128f5f, 0x01129eab,
+    0x01129f5f, 0x0112aaab, 0x0112b083, 0x0112ebab, 0x0112f083, 0x0112faab, 0x0113002b, 0x011304ab,
+    0x0113052b, 0x01130dab, 0x01130f2b, 0x011311ab, 0x0113132b, 0x011329ab, 0x01132a2b, 0x011331ab,
+    0x0113322b, 0x011334ab, 0x0113352b, 0x01133aab, 0x01133ba9, 0x01133c2b, 0x011345ab, 0x0113472b,
+    0x011349ab, 0x01134b2b, 0x01134eab, 0x0113502b, 0x011351ab, 0x0113572b, 0x011358ab, 0x01135d2b,
+    0x011364ab, 0x0113662b, 0x01136dab, 0x0113702b, 0x011375ab, 0x0113809e, 0x01138aab, 0x01138b9e,
+    0x01138cab, 0x01138e9e, 0x01138fab, 0x0113909e, 0x0113b6ab, 0x0113b79e, 0x0113c1ab, 0x0113c29e,
+    0x0113c3ab, 0x0113c59e, 0x0113c6ab, 0x0113c79e, 0x0113cbab, 0x0113cc9e, 0x0113d6ab, 0x0113d79e,
+    0x0113d9ab, 0x0113e19e, 0x0113e3ab, 0x01140065, 0x01145cab, 0x01145d65, 0x011462ab, 0x0114809a,
+    0x0114c8ab, 0x0114d09a, 0x0114daab, 0x01158082, 0x0115b6ab, 0x0115b882, 0x0115deab, 0x0116005b,
+    0x011645ab, 0x0116505b, 0x01165aab, 0x0116605c, 0x01166dab, 0x0116808e, 0x0116baab, 0x0116c08e,
+    0x0116caab, 0x0116d060, 0x0116e4ab, 0x01170002, 0x01171bab, 0x01171d02, 0x01172cab, 0x01173002,
+    0x011747ab, 0x0118001e, 0x01183cab, 0x0118a0a2, 0x0118f3ab, 0x0118ffa2, 0x0119001d, 0x011907ab,
+    0x0119091d, 0x01190aab, 0x01190c1d, 0x011914ab, 0x0119151d, 0x011917ab, 0x0119181d, 0x011936ab,
+    0x0119371d, 0x011939ab, 0x01193b1d, 0x011947ab, 0x0119501d, 0x01195aab, 0x0119a062, 0x0119a8ab,
+    0x0119aa62, 0x0119d8ab, 0x0119da62, 0x0119e5ab, 0x011a00a8, 0x011a48ab, 0x011a5088, 0x011aa3ab,
+    0x011ab013, 0x011ac071, 0x011af9ab, 0x011b001c, 0x011b0aab, 0x011bc08a, 0x011be2ab, 0x011bf08a,
+    0x011bfaab, 0x011c000c, 0x011c09ab, 0x011c0a0c, 0x011c37ab, 0x011c380c, 0x011c46ab, 0x011c500c,
+    0x011c6dab, 0x011c7055, 0x011c90ab, 0x011c9255, 0x011ca8ab, 0x011ca955, 0x011cb7ab, 0x011d0029,
+    0x011d07ab, 0x011d0829, 0x011d0aab, 0x011d0b29, 0x011d37ab, 0x011d3a29, 0x011d3bab, 0x011d3c29,
+    0x011d3eab, 0x011d3f29, 0x011d48ab, 0x011d5029, 0x011d5aab, 0x011d6028, 0x011d66ab, 0x011d6728,
+    0x011d69ab, 0x011d6a28, 0x011d8fab, 0x011d9028, 0x011d92ab, 0x011d9328, 0x011d99ab, 0x011da028,
+    0x011daaab, 0x011ee052, 0x011ef9ab, 0x011f003f, 0x011f11ab, 0x011f123f, 0x011f3bab, 0x011f3e3f,
+    0x011f5bab, 0x011fb04e, 0x011fb1ab, 0x011fc091, 0x011ff2ab, 0x011fff91, 0x012000a5, 0x01239aab,
+    0x012400a5, 0x01246fab, 0x012470a5, 0x012475ab, 0x012480a5, 0x012544ab, 0x012f9019, 0x012ff3ab,
+    0x01300021, 0x013456ab, 0x01346021, 0x0143fbab, 0x01440036, 0x014647ab, 0x0161002e, 0x01613aab,
+    0x01680008, 0x016a39ab, 0x016a405d, 0x016a5fab, 0x016a605d, 0x016a6aab, 0x016a6e5d, 0x016a709b,
+    0x016abfab, 0x016ac09b, 0x016acaab, 0x016ad009, 0x016aeeab, 0x016af009, 0x016af6ab, 0x016b0037,
+    0x016b46ab, 0x016b5037, 0x016b5aab, 0x016b5b37, 0x016b62ab, 0x016b6337, 0x016b78ab, 0x016b7d37,
+    0x016b90ab, 0x016d4045, 0x016d7aab, 0x016e4056, 0x016e9bab, 0x016f0077, 0x016f4bab, 0x016f4f77,
+    0x016f88ab, 0x016f8f77, 0x016fa0ab, 0x016fe092, 0x016fe167, 0x016fe231, 0x016fe443, 0x016fe5ab,
+    0x016ff031, 0x016ff2ab, 0x01700092, 0x0187f8ab, 0x01880092, 0x018b0043, 0x018cd6ab, 0x018cff43,
+    0x018d0092, 0x018d09ab, 0x01aff03e, 0x01aff4ab, 0x01aff53e, 0x01affcab, 0x01affd3e, 0x01afffab,
+    0x01b0003e, 0x01b00135, 0x01b1203e, 0x01b123ab, 0x01b13235, 0x01b133ab, 0x01b15035, 0x01b153ab,
+    0x01b1553e, 0x01b156ab, 0x01b1643e, 0x01b168ab, 0x01b17067, 0x01b2fcab, 0x01bc0020, 0x01bc6bab,
+    0x01bc7020, 0x01bc7dab, 0x01bc8020, 0x01bc89ab, 0x01bc9020, 0x01bc9aab, 0x01bc9c20, 0x01bca0aa,
+    0x01bca4ab, 0x01cc00aa, 0x01ccfaab, 0x01cd00aa, 0x01ceb4ab, 0x01cf00a9, 0x01cf2eab, 0x01cf30a9,
+    0x01cf47ab, 0x01cf50aa, 0x01cfc4ab, 0x01d000aa, 0x01d0f6ab, 0x01d100aa, 0x01d127ab, 0x01d129aa,
+    0x01d167a9, 0x01d16aaa, 0x01d17ba9, 0x01d183aa, 0x01d185a9, 0x01d18caa, 0x01d1aaa9, 0x01d1aeaa,
+    0x01d1ebab, 0x01d2002c, 0x01d246ab, 0x01d2c0aa, 0x01d2d4ab, 0x01d2e0aa, 0x01d2f4ab, 0x01d300aa,
+    0x01d357ab, 0x01d360aa, 0x01d379ab, 0x01d400aa, 0x01d455ab, 0x01d456aa, 0x01d49dab, 0x01d49eaa,
+    0x01d4a0ab, 0x01d4a2aa, 0x01d4a3ab, 0x01d4a5aa, 0x01d4a7ab, 0x01d4a9aa, 0x01d4adab, 0x01d4aeaa,
+    0x01d4baab, 0x01d4bbaa, 0x01d4bcab, 0x01d4bdaa, 0x01d4c4ab, 0x01d4c5aa, 0x01d506ab, 0x01d507aa,
+    0x01d50bab, 0x01d50daa, 0x01d515ab, 0x01d516aa, 0x01d51dab, 0x01d51eaa, 0x01d53aab, 0x01d53baa,
+    0x01d53fab, 0x01d540aa, 0x01d545ab, 0x01d546aa, 0x01d547ab, 0x01d54aaa, 0x01d551ab, 0x01d552aa,
+    0x01d6a6ab, 0x01d6a8aa, 0x01d7ccab, 0x01d7ceaa, 0x01d8007f, 0x01da8cab, 0x01da9b7f, 0x01daa0ab,
+    0x01daa17f, 0x01dab0ab, 0x01df0049, 0x01df1fab, 0x01df2549, 0x01df2bab, 0x01e00027, 0x01e007ab,
+    0x01e00827, 0x01e019ab, 0x01e01b27, 0x01e022ab, 0x01e02327, 0x01e025ab, 0x01e02627, 0x01e02bab,
+    0x01e0301b, 0x01e06eab, 0x01e08f1b, 0x01e090ab, 0x01e10038, 0x01e12dab, 0x01e13038, 0x01e13eab,
+    0x01e14038, 0x01e14aab, 0x01e14e38, 0x01e150ab, 0x01e2909d, 0x01e2afab, 0x01e2c0a3, 0x01e2faab,
+    0x01e2ffa3, 0x01e300ab, 0x01e4d061, 0x01e4faab, 0x01e5d06a, 0x01e5fbab, 0x01e5ff6a, 0x01e600ab,
+    0x01e7e024, 0x01e7e7ab, 0x01e7e824, 0x01e7ecab, 0x01e7ed24, 0x01e7efab, 0x01e7f024, 0x01e7ffab,
+    0x01e80057, 0x01e8c5ab, 0x01e8c757, 0x01e8d7ab, 0x01e90000, 0x01e94cab, 0x01e95000, 0x01e95aab,
+    0x01e95e00, 0x01e960ab, 0x01ec71aa, 0x01ecb5ab, 0x01ed01aa, 0x01ed3eab, 0x01ee0003, 0x01ee04ab,
+    0x01ee0503, 0x01ee20ab, 0x01ee2103, 0x01ee23ab, 0x01ee2403, 0x01ee25ab, 0x01ee2703, 0x01ee28ab,
+    0x01ee2903, 0x01ee33ab, 0x01ee3403, 0x01ee38ab, 0x01ee3903, 0x01ee3aab, 0x01ee3b03, 0x01ee3cab,
+    0x01ee4203, 0x01ee43ab, 0x01ee4703, 0x01ee48ab, 0x01ee4903, 0x01ee4aab, 0x01ee4b03, 0x01ee4cab,
+    0x01ee4d03, 0x01ee50ab, 0x01ee5103, 0x01ee53ab, 0x01ee5403, 0x01ee55ab, 0x01ee5703, 0x01ee58ab,
+    0x01ee5903, 0x01ee5aab, 0x01ee5b03, 0x01ee5cab, 0x01ee5d03, 0x01ee5eab, 0x01ee5f03, 0x01ee60ab,
+    0x01ee6103, 0x01ee63ab, 0x01ee6403, 0x01ee65ab, 0x01ee6703, 0x01ee6bab, 0x01ee6c03, 0x01ee73ab,
+    0x01ee7403, 0x01ee78ab, 0x01ee7903, 0x01ee7dab, 0x01ee7e03, 0x01ee7fab, 0x01ee8003, 0x01ee8aab,
+    0x01ee8b03, 0x01ee9cab, 0x01eea103, 0x01eea4ab, 0x01eea503, 0x01eeaaab, 0x01eeab03, 0x01eebcab,
+    0x01eef003, 0x01eef2ab, 0x01f000aa, 0x01f02cab, 0x01f030aa, 0x01f094ab, 0x01f0a0aa, 0x01f0afab,
+    0x01f0b1aa, 0x01f0c0ab, 0x01f0c1aa, 0x01f0d0ab, 0x01f0d1aa, 0x01f0f6ab, 0x01f100aa, 0x01f1aeab,
+    0x01f1e6aa, 0x01f20035, 0x01f201aa, 0x01f203ab, 0x01f210aa, 0x01f23cab, 0x01f240aa, 0x01f249ab,
+    0x01f250aa, 0x01f252ab, 0x01f260aa, 0x01f266ab, 0x01f300aa, 0x01f6d8ab, 0x01f6dcaa, 0x01f6edab,
+    0x01f6f0aa, 0x01f6fdab, 0x01f700aa, 0x01f777ab, 0x01f77baa, 0x01f7daab, 0x01f7e0aa, 0x01f7ecab,
+    0x01f7f0aa, 0x01f7f1ab, 0x01f800aa, 0x01f80cab, 0x01f810aa, 0x01f848ab, 0x01f850aa, 0x01f85aab,
+    0x01f860aa, 0x01f888ab, 0x01f890aa, 0x01f8aeab, 0x01f8b0aa, 0x01f8bcab, 0x01f8c0aa, 0x01f8c2ab,
+    0x01f900aa, 0x01fa54ab, 0x01fa60aa, 0x01fa6eab, 0x01fa70aa, 0x01fa7dab, 0x01fa80aa, 0x01fa8aab,
+    0x01fa8faa, 0x01fac7ab, 0x01faceaa, 0x01faddab, 0x01fadfaa, 0x01faeaab, 0x01faf0aa, 0x01faf9ab,
+    0x01fb00aa, 0x01fb93ab, 0x01fb94aa, 0x01fbfaab, 0x02000031, 0x02a6e0ab, 0x02a70031, 0x02b73aab,
+    0x02b74031, 0x02b81eab, 0x02b82031, 0x02cea2ab, 0x02ceb031, 0x02ebe1ab, 0x02ebf031, 0x02ee5eab,
+    0x02f80031, 0x02fa1eab, 0x03000031, 0x03134bab, 0x03135031, 0x0323b0ab, 0x0e0001aa, 0x0e0002ab,
+    0x0e0020aa, 0x0e0080ab, 0x0e0100a9, 0x0e01f0ab,
+];
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static SCRIPT_EXTENSIONS_RUNS: [u32; 225] = [
+    0x00000000, 0x00030001, 0x00030102, 0x00030200, 0x00030301, 0x00030403, 0x00030500, 0x00030703,
+    0x00030800, 0x00030d01, 0x00030e00, 0x00031001, 0x00031103, 0x00031200, 0x00031303, 0x00031400,
+    0x00032d01, 0x00032e00, 0x00033101, 0x00033200, 0x00034204, 0x00034300, 0x00034504, 0x00034600,
+    0x00035e03, 0x00035f00, 0x00036305, 0x00037000, 0x00048306, 0x00048407, 0x00048508, 0x00048707,
+    0x00048800, 0x00060c09, 0x00060d00, 0x00061b09, 0x00061c0a, 0x00061d00, 0x00061f0b, 0x00062000,
+    0x0006400c, 0x00064100, 0x00064b0d, 0x00065600, 0x0006600e, 0x00066a00, 0x0006700d, 0x00067100,
+    0x0006d40f, 0x0006d500, 0x00095110, 0x00095211, 0x00095300, 0x00096412, 0x00096513, 0x00096614,
+    0x00097000, 0x0009e615, 0x0009f000, 0x000a6616, 0x000a7000, 0x000ae617, 0x000af000, 0x000be618,
+    0x000bf400, 0x000ce619, 0x000cf000, 0x0010401a, 0x00104a00, 0x0010fb1b, 0x0010fc00, 0x0017351c,
+    0x00173700, 0x0018021d, 0x00180400, 0x0018051d, 0x00180600, 0x001cd01e, 0x001cd11f, 0x001cd21e,
+    0x001cd320, 0x001cd41f, 0x001cd521, 0x001cd722, 0x001cd821, 0x001cd922, 0x001cda23, 0x001cdb1f,
+    0x001cdc22, 0x001cde1f, 0x001ce022, 0x001ce121, 0x001ce21f, 0x001ce924, 0x001cea21, 0x001ceb1f,
+    0x001ced21, 0x001cee1f, 0x001cf225, 0x001cf320, 0x001cf426, 0x001cf521, 0x001cf727, 0x001cf820,
+    0x001cfa28, 0x001cfb00, 0x001dc004, 0x001dc200, 0x001df829, 0x001df900, 0x001dfa2a, 0x001dfb00,
+    0x00202f2b, 0x00203000, 0x0020f02c, 0x0020f100, 0x002e4307, 0x002e4400, 0x002ffc2d, 0x00300000,
+    0x0030012e, 0x0030032f, 0x00300400, 0x00300630, 0x00300700, 0x0030082e, 0x00301200, 0x0030132f,
+    0x0030142e, 0x00301c2f, 0x00302000, 0x00302a31, 0x00302e00, 0x0030302f, 0x00303132, 0x00303600,
+    0x0030372f, 0x00303800, 0x00303c33, 0x00303e30, 0x00304000, 0x00309932, 0x00309d00, 0x0030a032,
+    0x0030a100, 0x0030fb2e, 0x0030fc32, 0x0030fd00, 0x00319030, 0x0031a000, 0x0031c030, 0x0031e600,
+    0x0031ef2d, 0x0031f000, 0x00322030, 0x00324800, 0x00328030, 0x0032b100, 0x0032c030, 0x0032cc00,
+    0x0032ff30, 0x00330000, 0x00335830, 0x00337100, 0x00337b30, 0x00338000, 0x0033e030, 0x0033ff00,
+    0x00a66f07, 0x00a67000, 0x00a70034, 0x00a70800, 0x00a83035, 0x00a83336, 0x00a83637, 0x00a83a00,
+    0x00a8f138, 0x00a8f200, 0x00a8f339, 0x00a8f400, 0x00a92e3a, 0x00a92f00, 0x00a9cf3b, 0x00a9d000,
+    0x00fd3e3c, 0x00fd4000, 0x00fdf23d, 0x00fdf300, 0x00fdfd3d, 0x00fdfe00, 0x00fe452f, 0x00fe4700,
+    0x00ff612e, 0x00ff6600, 0x00ff7032, 0x00ff7100, 0x00ff9e32, 0x00ffa000, 0x0101003e, 0x0101023f,
+    0x01010300, 0x01010740, 0x01013400, 0x0101373f, 0x01014000, 0x0102e041, 0x0102fc00, 0x010af242,
+    0x010af300, 0x01130118, 0x01130200, 0x01130318, 0x01130400, 0x01133b18, 0x01133d00, 0x011fd018,
+    0x011fd200, 0x011fd318, 0x011fd400, 0x01bca043, 0x01bca400, 0x01d36030, 0x01d37200, 0x01f25030,
+    0x01f25200,
+];
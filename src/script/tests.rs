@@ -0,0 +1,103 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn scripts() {
+    assert_eq!(Script::of_char('a'), Script::Latin);
+    assert_eq!(Script::of_char('1'), Script::Common);
+    assert_eq!(Script::of_char('\u{0301}'), Script::Inherited);
+    assert_eq!(Script::of_char('\u{05D0}'), Script::Hebrew);
+    assert_eq!(Script::of_char('\u{0915}'), Script::Devanagari);
+    assert_eq!(Script::of_char('\u{4E00}'), Script::Han);
+    assert_eq!(Script::of_char('\u{1F600}'), Script::Common);
+    assert_eq!(Script::of_char('\u{11F00}'), Script::Kawi); // new in 15.0
+    assert_eq!(Script::of_char('\u{105C0}'), Script::Todhri); // new in 16.0
+    assert_eq!(Script::of_char('\u{0378}'), Script::Unknown);
+    assert_eq!(Script::of_char('\u{E000}'), Script::Unknown);
+    assert_eq!(Script::of_char(char::MAX), Script::Unknown);
+}
+
+#[test]
+fn extensions() {
+    assert_eq!(Script::extensions_of_char('a'), &[Script::Latin]);
+    assert_eq!(Script::extensions_of_char('!'), &[Script::Common]);
+    assert_eq!(Script::extensions_of_char('\u{0378}'), &[Script::Unknown]);
+    assert_eq!(
+        Script::extensions_of_char('\u{0660}'), // ARABIC-INDIC DIGIT ZERO
+        &[Script::Arabic, Script::Thaana, Script::Yezidi]
+    );
+    assert_eq!(
+        Script::extensions_of_char('\u{3001}'), // IDEOGRAPHIC COMMA
+        &[
+            Script::Bopomofo,
+            Script::Hangul,
+            Script::Han,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi
+        ]
+    );
+    assert_eq!(
+        Script::extensions_of_char('\u{064B}'), // ARABIC FATHATAN
+        &[Script::Arabic, Script::Syriac]
+    );
+    assert_eq!(Script::of_char('\u{064B}'), Script::Inherited);
+}
+
+#[test]
+fn names() {
+    assert_eq!(Script::Latin.short_name(), "Latn");
+    assert_eq!(Script::Latin.name(), "Latin");
+    assert_eq!(Script::OldItalic.name(), "Old_Italic");
+    assert_eq!(Script::Unknown.short_name(), "Zzzz");
+    assert_eq!(Script::TuluTigalari.short_name(), "Tutg");
+}
+
+#[test]
+fn clusters() {
+    assert_eq!(cluster_script("a"), Script::Latin);
+    assert_eq!(cluster_script("e\u{0301}"), Script::Latin);
+    assert_eq!(
+        cluster_script("\u{0915}\u{094D}\u{0937}"),
+        Script::Devanagari
+    );
+    assert_eq!(cluster_script("\u{0301}"), Script::Inherited);
+    assert_eq!(cluster_script("\u{1F469}\u{200D}\u{1F469}"), Script::Common);
+    // U+0600 ARABIC NUMBER SIGN is Prepend, so it begins a cluster with
+    // the digits after it.
+    assert_eq!(cluster_script("\u{0600}1"), Script::Arabic);
+    assert_eq!(cluster_script(""), Script::Unknown);
+}
+
+#[test]
+fn of_u8char() {
+    for c in ['a', '\u{0915}', '\u{4E00}', '\u{105C0}', char::MAX] {
+        assert_eq!(Script::of_u8char(u8char::from_char(c)), Script::of_char(c));
+    }
+}
+
+#[cfg(all(feature = "machine", feature = "tables"))]
+#[test]
+fn runs() {
+    let runs: Vec<_> = script_runs("").collect();
+    assert_eq!(runs, &[]);
+
+    let runs: Vec<_> = script_runs("“Hello, мир!” 中文\u{0301} text").collect();
+    assert_eq!(
+        runs,
+        &[
+            (Script::Latin, "“Hello, "),
+            (Script::Cyrillic, "мир!” "),
+            (Script::Han, "中文\u{0301} "),
+            (Script::Latin, "text"),
+        ]
+    );
+
+    let runs: Vec<_> = script_runs("123 \u{1F600}").collect();
+    assert_eq!(runs, &[(Script::Common, "123 \u{1F600}")]);
+
+    // A combining mark applied to a space takes the space's script.
+    let runs: Vec<_> = script_runs(" \u{0301}\u{05D0}").collect();
+    assert_eq!(runs, &[(Script::Hebrew, " \u{0301}\u{05D0}")]);
+}
@@ -0,0 +1,197 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ::u8char::AsU8Chars;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// Finds occurrences of a sequence of grapheme clusters in streaming input,
+/// reporting the offset range of each match.
+///
+/// Unlike a search of the raw bytes or characters, this respects cluster
+/// boundaries: searching for "👩" doesn't match the first part of the
+/// family emoji "👩‍👩‍👦", and searching for "e" doesn't match the "e" in
+/// "e\u{0301}".
+///
+/// The searcher uses the Knuth-Morris-Pratt algorithm over whole clusters,
+/// and keeps only the cluster in progress in memory. Once that cluster's
+/// length exceeds the longest cluster in the pattern it can't match, and so
+/// the searcher stops keeping its characters. Memory use is therefore
+/// bounded by the length of the pattern regardless of the input.
+///
+/// A match can only be reported once the cluster after it begins, because
+/// until then more characters might extend the final cluster of the match.
+/// Overlapping matches are all reported.
+///
+/// The searcher uses a [`GraphemeMachine`] unless constructed using
+/// [`Self::with_machine`], which accepts any other [`Segmenter`] and then
+/// matches whole segments from that machine instead.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct ClusterSearcher<'p, S = GraphemeMachine> {
+    pattern: &'p str,
+    clusters: Vec<&'p str>,
+    // The standard KMP failure function: the length of the longest proper
+    // prefix of clusters[..=i] that is also a suffix of it.
+    fail: Vec<usize>,
+    max_cluster_len: usize,
+    machine: S,
+    offset: u64,
+    current: String,
+    current_overlong: bool,
+    matched: usize,
+}
+
+impl<'p> ClusterSearcher<'p> {
+    /// Constructs a searcher for the given pattern, which is segmented into
+    /// grapheme clusters in the same way as the input will be.
+    ///
+    /// An empty pattern never matches anything.
+    pub fn new(pattern: &'p str) -> Self {
+        Self::with_machine(pattern, GraphemeMachine::new())
+    }
+}
+
+impl<'p, S: Segmenter> ClusterSearcher<'p, S> {
+    /// Constructs a searcher for the given pattern using an existing
+    /// machine, which should be in its "start of input" state. The machine
+    /// first segments the pattern, and then [`Self::end_of_input`] returns
+    /// it to that state to segment the input.
+    pub fn with_machine(pattern: &'p str, mut machine: S) -> Self {
+        let mut clusters: Vec<&'p str> = Vec::new();
+        let mut start = 0;
+        let mut offset = 0;
+        for c in pattern.u8chars() {
+            if machine.next_u8char(c).is_boundary() && offset > start {
+                clusters.push(&pattern[start..offset]);
+                start = offset;
+            }
+            offset += c.len();
+        }
+        if offset > start {
+            clusters.push(&pattern[start..]);
+        }
+        machine.end_of_input();
+        let mut fail = alloc::vec![0; clusters.len()];
+        let mut k = 0;
+        for i in 1..clusters.len() {
+            while k > 0 && clusters[i] != clusters[k] {
+                k = fail[k - 1];
+            }
+            if clusters[i] == clusters[k] {
+                k += 1;
+            }
+            fail[i] = k;
+        }
+        let max_cluster_len = clusters.iter().map(|c| c.len()).max().unwrap_or(0);
+        Self {
+            pattern,
+            clusters,
+            fail,
+            max_cluster_len,
+            machine,
+            offset: 0,
+            current: String::with_capacity(max_cluster_len),
+            current_overlong: false,
+            matched: 0,
+        }
+    }
+
+    /// Accepts the next character of the input, returning the range of a
+    /// match that ended immediately before it, if any.
+    pub fn next_u8char(&mut self, c: u8char) -> Option<Range<u64>> {
+        let mut ret = None;
+        if self.machine.next_u8char(c).is_boundary() {
+            ret = self.finish_cluster();
+        }
+        self.offset = self.offset.saturating_add(c.len() as u64);
+        if !self.current_overlong {
+            if self.current.len() + c.len() > self.max_cluster_len {
+                self.current.clear();
+                self.current_overlong = true;
+            } else {
+                self.current.push_str(c.as_str());
+            }
+        }
+        ret
+    }
+
+    /// Accepts the next character of the input, returning the range of a
+    /// match that ended immediately before it, if any.
+    pub fn next_char(&mut self, c: char) -> Option<Range<u64>> {
+        self.next_u8char(u8char::from_char(c))
+    }
+
+    /// Returns an iterator that feeds each character in `s` into the
+    /// searcher in turn, yielding the range of each match that ends.
+    ///
+    /// As with [`GraphemeMachine::next_u8chars_from_str`], there is no
+    /// automatic call to [`Self::end_of_input`] at the end, so the input
+    /// can be provided in a series of chunks.
+    pub fn matches_in_str<'a>(&'a mut self, s: &'a str) -> impl Iterator<Item = Range<u64>> + 'a {
+        let mut remain = s;
+        core::iter::from_fn(move || {
+            while let (Some(c), rest) = u8char::from_string_prefix(remain) {
+                remain = rest;
+                if let Some(found) = self.next_u8char(c) {
+                    return Some(found);
+                }
+            }
+            None
+        })
+    }
+
+    /// Signals the end of the input, returning the range of a match that
+    /// ended at the end of the input, if any.
+    ///
+    /// The searcher is then ready to search a new stream, with offsets
+    /// continuing from the end of this one.
+    pub fn end_of_input(&mut self) -> Option<Range<u64>> {
+        self.machine.end_of_input();
+        let ret = self.finish_cluster();
+        self.matched = 0;
+        ret
+    }
+
+    /// Returns the offset where the next character will be treated as
+    /// beginning.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Advances the pattern match for the cluster that just ended.
+    fn finish_cluster(&mut self) -> Option<Range<u64>> {
+        if self.clusters.is_empty() || (self.current.is_empty() && !self.current_overlong) {
+            return None;
+        }
+        let cluster = match self.current_overlong {
+            true => None,
+            false => Some(self.current.as_str()),
+        };
+        let mut ret = None;
+        loop {
+            if cluster == Some(self.clusters[self.matched]) {
+                self.matched += 1;
+                break;
+            }
+            if self.matched == 0 {
+                break;
+            }
+            self.matched = self.fail[self.matched - 1];
+        }
+        if self.matched == self.clusters.len() {
+            let len = self.pattern.len() as u64;
+            ret = Some(self.offset - len..self.offset);
+            self.matched = self.fail[self.matched - 1];
+        }
+        self.current.clear();
+        self.current_overlong = false;
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn matches(pattern: &str, input: &str) -> Vec<(u64, u64)> {
+    let mut searcher = ClusterSearcher::new(pattern);
+    let mut got: Vec<_> = searcher.matches_in_str(input).collect();
+    got.extend(searcher.end_of_input());
+    got.into_iter().map(|r| (r.start, r.end)).collect()
+}
+
+#[test]
+fn whole_clusters_only() {
+    // The woman emoji alone doesn't match the start of the family.
+    assert_eq!(matches("👩", "👩‍👩‍👦 👩"), &[(19, 23)]);
+    assert_eq!(matches("e", "e\u{0301}ee"), &[(3, 4), (4, 5)]);
+    assert_eq!(matches("e\u{0301}", "ee\u{0301}"), &[(1, 4)]);
+}
+
+#[test]
+fn multi_cluster_pattern() {
+    assert_eq!(matches("🇦🇧🇨🇩", "🇦🇧🇦🇧🇨🇩"), &[(8, 24)]);
+    assert_eq!(matches("ab", "aab"), &[(1, 3)]);
+    assert_eq!(matches("ab", "abc"), &[(0, 2)]);
+    assert_eq!(matches("ab", "a"), &[]);
+}
+
+#[test]
+fn overlapping() {
+    assert_eq!(matches("aa", "aaaa"), &[(0, 2), (1, 3), (2, 4)]);
+    assert_eq!(matches("aba", "ababa"), &[(0, 3), (2, 5)]);
+}
+
+#[test]
+fn match_waits_for_next_cluster() {
+    let mut searcher = ClusterSearcher::new("x");
+    assert_eq!(searcher.next_char('x'), None);
+    // A combining mark extends the "x", so it would no longer match.
+    assert_eq!(searcher.next_char('\u{0301}'), None);
+    assert_eq!(searcher.next_char('x'), None);
+    assert_eq!(searcher.next_char('y'), Some(3..4));
+    assert_eq!(searcher.end_of_input(), None);
+    assert_eq!(searcher.offset(), 5);
+}
+
+#[test]
+fn chunked_input() {
+    let mut searcher = ClusterSearcher::new("\r\n");
+    let mut got: Vec<_> = searcher.matches_in_str("a\r").collect();
+    got.extend(searcher.matches_in_str("\nb\r"));
+    got.extend(searcher.matches_in_str("\n"));
+    got.extend(searcher.end_of_input());
+    assert_eq!(got, vec![1..3, 4..6]);
+}
+
+#[test]
+fn overlong_clusters() {
+    // A cluster longer than any in the pattern is never kept in memory.
+    let mut searcher = ClusterSearcher::new("a");
+    for _ in 0..100 {
+        searcher.next_char('\u{0301}');
+    }
+    assert_eq!(searcher.current.capacity(), 1);
+    assert_eq!(searcher.next_char('a'), None);
+    assert_eq!(searcher.end_of_input(), Some(200..201));
+}
+
+#[test]
+fn empty_pattern() {
+    assert_eq!(matches("", "abc"), &[]);
+}
@@ -0,0 +1,90 @@
+use u8char::u8char;
+
+use crate::{ClusterAction, GraphemeMachine};
+
+/// A streaming segmentation state machine that is fed one character at a
+/// time and decides for each new character whether it begins a new segment.
+///
+/// [`GraphemeMachine`] is the implementation of this trait provided by this
+/// library, but the iterator adapters and other helpers in this library are
+/// generic over this trait so that they can also drive a third-party
+/// machine, such as one implementing a tailored variant of the grapheme
+/// cluster rules.
+///
+/// Some helpers rely on specific properties of the grapheme cluster rules,
+/// such as knowing where segmentation can safely begin partway through a
+/// string, and so they work only with [`GraphemeMachine`]. Those include
+/// [`cluster_at`](crate::cluster_at), [`snap_to_boundary`](crate::snap_to_boundary),
+/// [`floor_boundary`](crate::floor_boundary),
+/// [`next_safe_restart`](crate::next_safe_restart),
+/// [`prev_safe_restart`](crate::prev_safe_restart), and
+/// [`DiagnosticSpan::new`](crate::DiagnosticSpan::new). The
+/// `tail_offset_with_machine` family of functions accept any segmenter,
+/// but begin segmenting at the same safe restart points.
+///
+/// The IO and stream adapters, such as `GraphemeReader`, `ClusterStream`
+/// and `SanitizingWriter`, default to [`GraphemeMachine`] and have a
+/// `with_machine` constructor for using some other segmenter. The
+/// `WordMachine` and `LineBreakMachine` don't implement this trait,
+/// because some of their boundaries are decided only after a later
+/// character, which [`SegmentAction`] can't express.
+///
+/// Implementations should follow the same conventions as
+/// [`GraphemeMachine`]: the first character after construction or after a
+/// call to [`Self::end_of_input`] always begins a new segment.
+pub trait Segmenter {
+    /// The type used to describe what to do with each new character.
+    type Action: SegmentAction;
+
+    /// Advances the state machine for the given character, returning the
+    /// action to take at the boundary between it and the previous character
+    /// (if any).
+    fn next_u8char(&mut self, c: u8char) -> Self::Action;
+
+    /// Advances the state machine for the given character, returning the
+    /// action to take at the boundary between it and the previous character
+    /// (if any).
+    ///
+    /// The default implementation converts the character to [`u8char`] and
+    /// passes it to [`Self::next_u8char`].
+    fn next_char(&mut self, c: char) -> Self::Action {
+        self.next_u8char(u8char::from_char(c))
+    }
+
+    /// Tells the state machine that the input stream has ended, returning
+    /// the action that marks the end of the final segment.
+    fn end_of_input(&mut self) -> Self::Action;
+}
+
+/// Implemented by the action types returned by a [`Segmenter`], so that
+/// generic code can tell whether a character begins a new segment.
+pub trait SegmentAction: Copy {
+    /// Returns `true` if the action represents a boundary between the new
+    /// character and whatever preceded it.
+    fn is_boundary(self) -> bool;
+}
+
+impl Segmenter for GraphemeMachine {
+    type Action = ClusterAction;
+
+    fn next_u8char(&mut self, c: u8char) -> ClusterAction {
+        GraphemeMachine::next_u8char(self, c)
+    }
+
+    fn next_char(&mut self, c: char) -> ClusterAction {
+        GraphemeMachine::next_char(self, c)
+    }
+
+    fn end_of_input(&mut self) -> ClusterAction {
+        GraphemeMachine::end_of_input(self)
+    }
+}
+
+impl SegmentAction for ClusterAction {
+    fn is_boundary(self) -> bool {
+        matches!(self, ClusterAction::Split)
+    }
+}
+
+#[cfg(test)]
+mod tests;
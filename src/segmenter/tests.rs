@@ -0,0 +1,159 @@
+use super::*;
+use crate::CharActions;
+
+use pretty_assertions::assert_eq;
+
+/// A deliberately-simple tailored segmenter that treats each line of text
+/// as a single segment, used to show that the crate's generic helpers can
+/// drive a third-party state machine.
+#[derive(Debug, Default)]
+struct LineSegmenter {
+    after_lf: bool,
+    started: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineAction {
+    SameLine,
+    NewLine,
+}
+
+impl Segmenter for LineSegmenter {
+    type Action = LineAction;
+
+    fn next_u8char(&mut self, c: u8char) -> LineAction {
+        let new_line = !self.started || self.after_lf;
+        self.started = true;
+        self.after_lf = c.to_char() == '\n';
+        if new_line {
+            LineAction::NewLine
+        } else {
+            LineAction::SameLine
+        }
+    }
+
+    fn end_of_input(&mut self) -> LineAction {
+        *self = Self::default();
+        LineAction::NewLine
+    }
+}
+
+impl SegmentAction for LineAction {
+    fn is_boundary(self) -> bool {
+        matches!(self, LineAction::NewLine)
+    }
+}
+
+#[test]
+fn grapheme_machine_as_segmenter() {
+    fn drive<S: Segmenter>(machine: &mut S, s: &str) -> Vec<bool> {
+        s.chars()
+            .map(|c| machine.next_char(c).is_boundary())
+            .collect()
+    }
+
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(drive(&mut machine, "a\r\nb"), &[true, true, false, true]);
+    assert!(Segmenter::end_of_input(&mut machine).is_boundary());
+    assert_eq!(drive(&mut machine, "\n"), &[true]);
+}
+
+#[test]
+fn tailored_segmenter() {
+    use LineAction::*;
+    let got: Vec<_> = CharActions::with_machine(LineSegmenter::default(), "a\nb\n\n".chars())
+        .map(|(a, _)| a)
+        .collect();
+    assert_eq!(got, &[NewLine, SameLine, NewLine, SameLine, NewLine]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tailored_segmenter_clusters() {
+    let got: Vec<_> = CharActions::with_machine(LineSegmenter::default(), "ab\ncd\n\ne".chars())
+        .clusters()
+        .collect();
+    assert_eq!(got, &["ab\n", "cd\n", "\n", "e"]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boundary_index() {
+    let index = crate::BoundaryIndex::with_segmenter("ab\ncd\ne", LineSegmenter::default());
+    assert_eq!(index.cluster_count(), 3);
+    assert_eq!(index.cluster_range(0), Some(0..3));
+    assert_eq!(index.cluster_range(1), Some(3..6));
+    assert_eq!(index.cluster_range(2), Some(6..7));
+    assert_eq!(index.cluster_index(4), Some(1));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn reader() {
+    let input = "ab\ncd\n\ne".as_bytes();
+    let reader =
+        crate::GraphemeReader::with_capacity_and_machine(2, LineSegmenter::default(), input);
+    let got: Vec<_> = reader.collect::<std::io::Result<_>>().unwrap();
+    assert_eq!(got, &["ab\n", "cd\n", "\n", "e"]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn chunked() {
+    let chunks = ["ab", "\ncd", "\n", "\ne"];
+    let got: Vec<_> = crate::StrChunkClusters::with_machine(LineSegmenter::default(), chunks)
+        .with_ranges()
+        .map(|cluster| (cluster.text.into_owned(), cluster.bytes))
+        .collect();
+    assert_eq!(
+        got,
+        [
+            ("ab\n".to_string(), 0..3),
+            ("cd\n".to_string(), 3..6),
+            ("\n".to_string(), 6..7),
+            ("e".to_string(), 7..8),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn searcher() {
+    // The pattern is segmented by the same machine, so it matches only
+    // whole lines.
+    let mut searcher = crate::ClusterSearcher::with_machine("b\n", LineSegmenter::default());
+    let mut got: Vec<_> = searcher.matches_in_str("ab\nb\nb").collect();
+    got.extend(searcher.end_of_input());
+    let got: Vec<_> = got.into_iter().map(|r| (r.start, r.end)).collect();
+    assert_eq!(got, &[(3, 5)]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sanitizing_writer() {
+    use core::fmt::Write;
+
+    // Each whole line is one segment, so an overlong line is replaced and
+    // the limit counts lines, since none of the segments is a line break
+    // by itself.
+    let mut w = crate::SanitizingWriter::<_, 4, _>::with_machine(
+        alloc::string::String::new(),
+        2,
+        LineSegmenter::default(),
+    );
+    write!(w, "ab\nlong line\ncd").unwrap();
+    assert_eq!(w.finish().unwrap(), "ab\n\u{FFFD}");
+}
+
+#[test]
+fn tail_offset() {
+    let s = "ab\ncd\n\ne";
+    assert_eq!(
+        crate::tail_offset_with_machine(s, 2, LineSegmenter::default()),
+        6
+    );
+    assert_eq!(
+        crate::tail_offset_with_machine(s, 9, LineSegmenter::default()),
+        0
+    );
+}
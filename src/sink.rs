@@ -0,0 +1,138 @@
+use alloc::string::String;
+use core::fmt;
+
+use ::u8char::AsU8Chars;
+use ::u8char::stream::U8CharStream;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, SegmentAction, Segmenter};
+
+/// Accepts text in chunks of any size and calls a closure with the text of
+/// each grapheme cluster once it's complete.
+///
+/// This manages everything between a source of text and code that handles
+/// one cluster at a time: the buffer for the cluster in progress, clusters
+/// and characters split across chunks, and passing on the final cluster
+/// when [`Self::finish`] signals the end of the text.
+///
+/// Chunks can be either [`str`] or UTF-8 bytes. Invalid byte sequences are
+/// replaced with U+FFFD REPLACEMENT CHARACTER, along with any incomplete
+/// sequence at the end of a byte chunk that the next chunk doesn't
+/// complete.
+///
+/// The buffer grows to fit the longest cluster, so use
+/// [`BufferedGraphemeMachine`](crate::BufferedGraphemeMachine) instead to
+/// limit memory use for untrusted input.
+///
+/// This is available only when the `alloc` feature is enabled.
+pub struct ClusterSink<F, S = GraphemeMachine> {
+    bytes: U8CharStream,
+    clusters: Clusters<F, S>,
+}
+
+impl<F: FnMut(&str)> ClusterSink<F> {
+    /// Constructs a sink that calls `f` with each complete cluster, using a
+    /// new [`GraphemeMachine`] in its initial "start of input" state.
+    pub fn new(f: F) -> Self {
+        Self::with_machine(GraphemeMachine::new(), f)
+    }
+}
+
+impl<F: FnMut(&str), S: Segmenter> ClusterSink<F, S> {
+    /// Constructs a sink that calls `f` with each complete cluster, using
+    /// the given machine to find the cluster boundaries.
+    ///
+    /// The given machine should be in its "start of input" state.
+    pub fn with_machine(machine: S, f: F) -> Self {
+        Self {
+            bytes: U8CharStream::new(),
+            clusters: Clusters {
+                machine,
+                cluster: String::new(),
+                f,
+            },
+        }
+    }
+
+    /// Accepts the next chunk of text, calling the closure for each cluster
+    /// that the chunk completes.
+    ///
+    /// If the previous chunk was bytes that ended partway through a UTF-8
+    /// sequence, then that sequence is replaced with U+FFFD REPLACEMENT
+    /// CHARACTER first.
+    pub fn push_str(&mut self, s: &str) {
+        self.flush_bytes();
+        for c in s.u8chars() {
+            self.clusters.push(c);
+        }
+    }
+
+    /// Accepts the next chunk of UTF-8 bytes, calling the closure for each
+    /// cluster that the chunk completes.
+    ///
+    /// A UTF-8 sequence that's incomplete at the end of the chunk is held
+    /// until the next chunk.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        for c in self.bytes.more(bytes) {
+            self.clusters.push(c);
+        }
+    }
+
+    /// Signals the end of the text, calling the closure for the final
+    /// cluster if any text was pushed since the previous cluster.
+    ///
+    /// The sink is then back in its "start of input" state, and so any text
+    /// pushed afterwards begins a new cluster.
+    pub fn finish(&mut self) {
+        self.flush_bytes();
+        let clusters = &mut self.clusters;
+        clusters.machine.end_of_input();
+        if !clusters.cluster.is_empty() {
+            (clusters.f)(&clusters.cluster);
+            clusters.cluster.clear();
+        }
+    }
+
+    /// Consumes the sink and returns the closure it was using, discarding
+    /// any cluster still in progress.
+    pub fn into_inner(self) -> F {
+        self.clusters.f
+    }
+
+    fn flush_bytes(&mut self) {
+        for c in self.bytes.end() {
+            self.clusters.push(c);
+        }
+    }
+}
+
+impl<F, S: fmt::Debug> fmt::Debug for ClusterSink<F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClusterSink")
+            .field("machine", &self.clusters.machine)
+            .field("cluster", &self.clusters.cluster)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The part of [`ClusterSink`] other than its byte decoder, separated so
+/// that it can be updated while the decoder is borrowed.
+struct Clusters<F, S> {
+    machine: S,
+    // The cluster in progress.
+    cluster: String,
+    f: F,
+}
+
+impl<F: FnMut(&str), S: Segmenter> Clusters<F, S> {
+    fn push(&mut self, c: u8char) {
+        if self.machine.next_u8char(c).is_boundary() && !self.cluster.is_empty() {
+            (self.f)(&self.cluster);
+            self.cluster.clear();
+        }
+        self.cluster.push_str(c.as_str());
+    }
+}
+
+#[cfg(test)]
+mod tests;
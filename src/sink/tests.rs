@@ -0,0 +1,53 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn clusters(chunks: &[&[u8]]) -> Vec<String> {
+    let mut got = Vec::new();
+    let mut sink = ClusterSink::new(|cluster: &str| got.push(cluster.to_string()));
+    for chunk in chunks {
+        sink.push_bytes(chunk);
+    }
+    sink.finish();
+    got
+}
+
+#[test]
+fn push_str() {
+    let mut got = Vec::new();
+    let mut sink = ClusterSink::new(|cluster: &str| got.push(cluster.to_string()));
+    sink.push_str("a\r");
+    sink.push_str("");
+    sink.push_str("\ne");
+    sink.push_str("\u{0301}🇦🇧");
+    sink.finish();
+    // Text pushed after finishing begins a new cluster.
+    sink.push_str("\u{0301}");
+    sink.finish();
+    assert_eq!(got, &["a", "\r\n", "e\u{0301}", "🇦🇧", "\u{0301}"]);
+}
+
+#[test]
+fn push_bytes() {
+    let input = "a\r\ne\u{0301}🇦🇧🧑‍🌾!".as_bytes();
+    let want = &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"];
+    for i in 0..=input.len() {
+        let (a, b) = input.split_at(i);
+        assert_eq!(clusters(&[a, b]), want, "split at {i}");
+    }
+    assert_eq!(clusters(&[]), &[] as &[&str]);
+}
+
+#[test]
+fn invalid_bytes() {
+    assert_eq!(clusters(&[b"a\xffb"]), &["a", "\u{FFFD}", "b"]);
+    assert_eq!(clusters(&[b"a\xe2\x82"]), &["a", "\u{FFFD}"]);
+
+    // An incomplete sequence interrupted by a str chunk is replaced.
+    let mut got = Vec::new();
+    let mut sink = ClusterSink::new(|cluster: &str| got.push(cluster.to_string()));
+    sink.push_bytes(b"a\xe2\x82");
+    sink.push_str("b");
+    sink.finish();
+    assert_eq!(got, &["a", "\u{FFFD}", "b"]);
+}
@@ -0,0 +1,177 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use u8char::u8char;
+
+use crate::ClusterSearcher;
+
+/// Divides streaming input into records separated by a delimiter that
+/// matches only at grapheme cluster boundaries, reporting the offset range
+/// of each record.
+///
+/// The delimiter can be any sequence of clusters, including a single
+/// multi-character cluster like an emoji ZWJ sequence. Because matching is
+/// done on whole clusters the delimiter doesn't split the input where it
+/// appears only as part of a larger cluster. For example, a delimiter "👩"
+/// doesn't match inside the family emoji "👩‍👩‍👦".
+///
+/// As with [`str::split`], a delimiter at the start or end of the input
+/// produces an empty record there, and the input always contains at least
+/// one record. Where occurrences of a multi-cluster delimiter overlap, only
+/// the first is treated as a delimiter.
+///
+/// Only the record ranges are reported, so the splitter doesn't need to
+/// keep the records in memory. See [`split_on_cluster`] to split a string
+/// that's already in memory.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug)]
+pub struct RecordSplitter<'d> {
+    searcher: ClusterSearcher<'d>,
+    record_start: u64,
+}
+
+impl<'d> RecordSplitter<'d> {
+    /// Constructs a splitter for the given delimiter, which is itself
+    /// segmented into grapheme clusters in the same way as the input.
+    ///
+    /// An empty delimiter never matches, and so the entire input is a
+    /// single record.
+    pub fn new(delimiter: &'d str) -> Self {
+        Self {
+            searcher: ClusterSearcher::new(delimiter),
+            record_start: 0,
+        }
+    }
+
+    /// Accepts the next character of the input, returning the range of the
+    /// record that just ended if it completed a delimiter.
+    ///
+    /// As with [`ClusterSearcher`], a delimiter is only known to be complete
+    /// once the cluster after it begins.
+    pub fn next_u8char(&mut self, c: u8char) -> Option<Range<u64>> {
+        let found = self.searcher.next_u8char(c)?;
+        self.delimiter_found(found)
+    }
+
+    /// Accepts the next character of the input, returning the range of the
+    /// record that just ended if it completed a delimiter.
+    pub fn next_char(&mut self, c: char) -> Option<Range<u64>> {
+        self.next_u8char(u8char::from_char(c))
+    }
+
+    /// Returns an iterator that feeds each character in `s` into the
+    /// splitter in turn, yielding the range of each record that ends.
+    ///
+    /// There is no automatic call to [`Self::end_of_input`] at the end, so
+    /// the input can be provided in a series of chunks.
+    pub fn records_in_str<'a>(&'a mut self, s: &'a str) -> impl Iterator<Item = Range<u64>> + 'a {
+        let mut remain = s;
+        core::iter::from_fn(move || {
+            while let (Some(c), rest) = u8char::from_string_prefix(remain) {
+                remain = rest;
+                if let Some(record) = self.next_u8char(c) {
+                    return Some(record);
+                }
+            }
+            None
+        })
+    }
+
+    /// Signals the end of the input, returning the range of the final
+    /// record.
+    ///
+    /// If a delimiter ended at the end of the input then the record that it
+    /// ended is returned as the first element, followed by the empty final
+    /// record. Otherwise there is only one.
+    ///
+    /// The splitter is then ready to split a new stream, with offsets
+    /// continuing from the end of this one.
+    pub fn end_of_input(&mut self) -> (Option<Range<u64>>, Range<u64>) {
+        let ended = self
+            .searcher
+            .end_of_input()
+            .and_then(|found| self.delimiter_found(found));
+        let end = self.searcher.offset();
+        let last = self.record_start..end;
+        self.record_start = end;
+        (ended, last)
+    }
+
+    fn delimiter_found(&mut self, found: Range<u64>) -> Option<Range<u64>> {
+        if found.start < self.record_start {
+            // This overlaps the previous delimiter.
+            return None;
+        }
+        let record = self.record_start..found.start;
+        self.record_start = found.end;
+        Some(record)
+    }
+}
+
+/// Returns an iterator over the records in `s` that are separated by
+/// `delimiter`, matching the delimiter only at grapheme cluster boundaries.
+///
+/// See [`RecordSplitter`] for the details of how the delimiter is matched.
+///
+/// This is available only when the `alloc` feature is enabled.
+pub fn split_on_cluster<'a, 'd>(s: &'a str, delimiter: &'d str) -> SplitOnCluster<'a, 'd> {
+    SplitOnCluster {
+        s,
+        remain: s,
+        splitter: RecordSplitter::new(delimiter),
+        last: None,
+        done: false,
+    }
+}
+
+/// The iterator returned by [`split_on_cluster`].
+#[derive(Debug)]
+pub struct SplitOnCluster<'a, 'd> {
+    s: &'a str,
+    remain: &'a str,
+    splitter: RecordSplitter<'d>,
+    // The final record, once the end of input has been found while there
+    // was still another record to return first.
+    last: Option<Range<u64>>,
+    done: bool,
+}
+
+impl<'a, 'd> SplitOnCluster<'a, 'd> {
+    fn record(&self, range: Range<u64>) -> &'a str {
+        // The offsets are all within s, which is no longer than usize::MAX.
+        &self.s[range.start as usize..range.end as usize]
+    }
+}
+
+impl<'a, 'd> Iterator for SplitOnCluster<'a, 'd> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(last) = self.last.take() {
+            return Some(self.record(last));
+        }
+        if self.done {
+            return None;
+        }
+        while let (Some(c), rest) = u8char::from_string_prefix(self.remain) {
+            self.remain = rest;
+            if let Some(record) = self.splitter.next_u8char(c) {
+                return Some(self.record(record));
+            }
+        }
+        self.done = true;
+        match self.splitter.end_of_input() {
+            (Some(record), last) => {
+                self.last = Some(last);
+                Some(self.record(record))
+            }
+            (None, last) => Some(self.record(last)),
+        }
+    }
+}
+
+impl<'a, 'd> FusedIterator for SplitOnCluster<'a, 'd> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn split(s: &str, delimiter: &str) -> Vec<String> {
+    split_on_cluster(s, delimiter).map(String::from).collect()
+}
+
+#[test]
+fn emoji_delimiter() {
+    assert_eq!(split("a👩b👩c", "👩"), &["a", "b", "c"]);
+    // The woman emoji inside a family isn't a delimiter.
+    assert_eq!(split("a👩‍👩‍👦b👩c", "👩"), &["a👩‍👩‍👦b", "c"]);
+    assert_eq!(split("x👩‍👩‍👦y👩‍👩‍👦", "👩‍👩‍👦"), &["x", "y", ""]);
+}
+
+#[test]
+fn combining_marks() {
+    // A comma followed by a combining mark is not a comma.
+    assert_eq!(split("a,b,\u{0301}c", ","), &["a", "b,\u{0301}c"]);
+    assert_eq!(split("a\r\nb\rc", "\r\n"), &["a", "b\rc"]);
+}
+
+#[test]
+fn empty_records() {
+    assert_eq!(split("", ","), &[""]);
+    assert_eq!(split(",", ","), &["", ""]);
+    assert_eq!(split(",a,,", ","), &["", "a", "", ""]);
+    assert_eq!(split("abc", ""), &["abc"]);
+}
+
+#[test]
+fn overlapping_delimiters() {
+    assert_eq!(split("aaab", "aa"), &["", "ab"]);
+    assert_eq!(split("aaaab", "aa"), &["", "", "b"]);
+}
+
+#[test]
+fn streaming() {
+    let mut splitter = RecordSplitter::new("🇦🇧");
+    let mut got: Vec<_> = splitter.records_in_str("one\u{1F1E6}").collect();
+    got.extend(splitter.records_in_str("\u{1F1E7}two🇦"));
+    got.extend(splitter.records_in_str("🇧"));
+    let (ended, last) = splitter.end_of_input();
+    assert_eq!(got, vec![0..3]);
+    assert_eq!(ended, Some(11..14));
+    assert_eq!(last, 22..22);
+
+    // Offsets continue into the next stream.
+    assert_eq!(splitter.next_char('x'), None);
+    assert_eq!(splitter.end_of_input(), (None, 22..23));
+}
@@ -0,0 +1,177 @@
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+use crate::InvalidUtf8Error;
+
+/// UTF-8 text stored non-contiguously across two byte slices, such as the
+/// two halves of a ring buffer like `VecDeque<u8>` as returned by its
+/// `as_slices` method.
+///
+/// Constructing a `SplitStr` validates both slices together, so a character
+/// whose encoding straddles the two slices is accepted without the caller
+/// first making the storage contiguous. The machines in this library can
+/// then segment it directly using methods like
+/// [`GraphemeMachine::next_u8chars_from_split_str`](crate::GraphemeMachine::next_u8chars_from_split_str).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SplitStr<'a> {
+    front: &'a str,
+    // The character whose encoding began at the end of the first slice
+    // and continued at the start of the second, if any.
+    mid: Option<u8char>,
+    back: &'a str,
+}
+
+impl<'a> SplitStr<'a> {
+    /// Validates that the concatenation of `front` and `back` is valid
+    /// UTF-8, returning an error describing the first problem if not.
+    ///
+    /// The offsets in the error are relative to the start of `front`,
+    /// continuing into `back` as if the two were contiguous.
+    pub fn from_utf8(front: &'a [u8], back: &'a [u8]) -> Result<Self, InvalidUtf8Error> {
+        Self::parse(front, back, false)
+    }
+
+    /// Behaves the same as [`Self::from_utf8`] except that an incomplete
+    /// UTF-8 sequence at the very end of the input is not an error, and
+    /// is instead excluded from the result.
+    ///
+    /// This suits ring buffers used for streaming input, where the bytes of
+    /// the final character might not all have arrived yet. Those bytes are
+    /// the ones beyond [`Self::len`], and should be left in the buffer to be
+    /// completed by the next read.
+    pub fn from_utf8_partial(front: &'a [u8], back: &'a [u8]) -> Result<Self, InvalidUtf8Error> {
+        Self::parse(front, back, true)
+    }
+
+    fn parse(front: &'a [u8], back: &'a [u8], partial: bool) -> Result<Self, InvalidUtf8Error> {
+        let (front_str, tail) = match core::str::from_utf8(front) {
+            Ok(s) => (s, &[][..]),
+            Err(err) if err.error_len().is_some() => return Err(err.into()),
+            Err(err) => {
+                let (valid, tail) = front.split_at(err.valid_up_to());
+                // Safety: from_utf8 reported that these bytes are valid.
+                (unsafe { core::str::from_utf8_unchecked(valid) }, tail)
+            }
+        };
+
+        let mut mid = None;
+        let mut back_start = 0;
+        if !tail.is_empty() {
+            let want = match tail[0] {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                _ => 4,
+            };
+            let used = back.len().min(want - tail.len());
+            let mut buf = [0; 4];
+            buf[..tail.len()].copy_from_slice(tail);
+            buf[tail.len()..tail.len() + used].copy_from_slice(&back[..used]);
+            match core::str::from_utf8(&buf[..tail.len() + used]) {
+                Ok(s) => {
+                    // from_utf8 succeeded, so there's exactly one character.
+                    mid = u8char::from_string_prefix(s).0;
+                    back_start = used;
+                }
+                Err(err) if err.error_len().is_none() && partial => {
+                    // The second slice is too short to complete the
+                    // sequence, so it's the end of the input.
+                    return Ok(Self {
+                        front: front_str,
+                        mid: None,
+                        back: "",
+                    });
+                }
+                Err(err) => {
+                    return Err(InvalidUtf8Error {
+                        valid_up_to: front_str.len(),
+                        error_len: err.error_len(),
+                    });
+                }
+            }
+        }
+
+        let rest = &back[back_start..];
+        let back_str = match core::str::from_utf8(rest) {
+            Ok(s) => s,
+            Err(err) if err.error_len().is_none() && partial => {
+                // Safety: from_utf8 reported that these bytes are valid.
+                unsafe { core::str::from_utf8_unchecked(&rest[..err.valid_up_to()]) }
+            }
+            Err(err) => {
+                return Err(InvalidUtf8Error {
+                    valid_up_to: front.len() + back_start + err.valid_up_to(),
+                    error_len: err.error_len(),
+                });
+            }
+        };
+        Ok(Self {
+            front: front_str,
+            mid,
+            back: back_str,
+        })
+    }
+
+    /// Returns the total length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.mid.map_or(0, u8char::len) + self.back.len()
+    }
+
+    /// Returns `true` if there is no text at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the characters of the text, in order.
+    pub fn u8chars(&self) -> SplitStrChars<'a> {
+        SplitStrChars { remain: *self }
+    }
+}
+
+impl<'a> From<&'a str> for SplitStr<'a> {
+    fn from(s: &'a str) -> Self {
+        Self {
+            front: s,
+            mid: None,
+            back: "",
+        }
+    }
+}
+
+/// An iterator over the characters of a [`SplitStr`].
+#[derive(Debug, Clone)]
+pub struct SplitStrChars<'a> {
+    remain: SplitStr<'a>,
+}
+
+impl<'a> Iterator for SplitStrChars<'a> {
+    type Item = u8char;
+
+    fn next(&mut self) -> Option<u8char> {
+        let remain = &mut self.remain;
+        if let (Some(c), rest) = u8char::from_string_prefix(remain.front) {
+            remain.front = rest;
+            return Some(c);
+        }
+        if let Some(c) = remain.mid.take() {
+            return Some(c);
+        }
+        let (c, rest) = u8char::from_string_prefix(remain.back);
+        remain.back = rest;
+        c
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = crate::scan::lead_byte_count(self.remain.front.as_bytes())
+            + usize::from(self.remain.mid.is_some())
+            + crate::scan::lead_byte_count(self.remain.back.as_bytes());
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for SplitStrChars<'a> {}
+
+impl<'a> FusedIterator for SplitStrChars<'a> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::{ClusterAction, GraphemeMachine, OffsetGraphemeMachine};
+
+use pretty_assertions::assert_eq;
+
+use std::collections::VecDeque;
+
+#[test]
+fn ring_buffer() {
+    use ClusterAction::*;
+
+    // Every possible split position must give the same result, including
+    // the ones partway through a character.
+    let text = "a\u{0301}🇦🇧\r\n";
+    for split in 0..=text.len() {
+        let (front, back) = text.as_bytes().split_at(split);
+        let s = SplitStr::from_utf8(front, back).unwrap();
+        assert_eq!(s.len(), text.len());
+
+        let mut machine = GraphemeMachine::new();
+        let got: Vec<_> = machine
+            .next_u8chars_from_split_str(s)
+            .map(|(a, c)| (a, c.to_char()))
+            .collect();
+        assert_eq!(
+            got,
+            &[
+                (Split, 'a'),
+                (Continue, '\u{0301}'),
+                (Split, '\u{1F1E6}'),
+                (Continue, '\u{1F1E7}'),
+                (Split, '\r'),
+                (Continue, '\n'),
+            ],
+            "split at {split}"
+        );
+    }
+}
+
+#[test]
+fn vec_deque() {
+    let mut buf: VecDeque<u8> = VecDeque::with_capacity(8);
+    let cap = buf.capacity();
+    // Filling the buffer from partway through its storage makes the
+    // contents wrap around, so that the second slice isn't empty.
+    buf.extend(core::iter::repeat_n(b' ', cap - 2));
+    for _ in 0..cap - 2 {
+        buf.pop_front();
+    }
+    buf.extend("é!x".as_bytes());
+    let (front, back) = buf.as_slices();
+    assert_eq!((front.len(), back.len()), (2, 2));
+
+    let s = SplitStr::from_utf8(front, back).unwrap();
+    let mut machine = OffsetGraphemeMachine::new();
+    let got: Vec<_> = machine.boundaries_in_split_str(s).collect();
+    assert_eq!(got, &[0, 2, 3]);
+}
+
+#[test]
+fn straddling_char() {
+    let bytes = "x🧑y".as_bytes();
+    let s = SplitStr::from_utf8(&bytes[..3], &bytes[3..]).unwrap();
+    let chars: Vec<_> = s.u8chars().map(u8char::to_char).collect();
+    assert_eq!(chars, &['x', '🧑', 'y']);
+    assert_eq!(s.u8chars().size_hint(), (3, Some(3)));
+
+    let mut machine = OffsetGraphemeMachine::with_offset(10);
+    let got: Vec<_> = machine.boundaries_in_split_str(s).collect();
+    assert_eq!(got, &[10, 11, 15]);
+    assert_eq!(machine.offset(), 16);
+}
+
+#[test]
+fn invalid() {
+    let err = SplitStr::from_utf8(b"ab\xff", b"cd").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+    let err = SplitStr::from_utf8(b"ab\xe2", b"\x82x").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(2)));
+    let err = SplitStr::from_utf8(b"ab", b"c\xff").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (3, Some(1)));
+    let err = SplitStr::from_utf8(b"ab\xe2", b"\x82").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, None));
+    let err = SplitStr::from_utf8(b"ab", b"c\xe2\x82").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (3, None));
+}
+
+#[test]
+fn partial() {
+    let s = SplitStr::from_utf8_partial(b"ab\xe2", b"\x82").unwrap();
+    assert_eq!(s.len(), 2);
+    let s = SplitStr::from_utf8_partial(b"ab\xe2", b"\x82\xaccd\xf0").unwrap();
+    assert_eq!(s.len(), 7);
+    assert_eq!(
+        s.u8chars().map(u8char::to_char).collect::<String>(),
+        "ab€cd"
+    );
+    let s = SplitStr::from_utf8_partial(b"ab\xf0", b"").unwrap();
+    assert_eq!(s.len(), 2);
+
+    // Invalid sequences are still errors.
+    let err = SplitStr::from_utf8_partial(b"ab", b"\xff").unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (2, Some(1)));
+
+    assert!(SplitStr::from("").is_empty());
+}
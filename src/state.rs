@@ -15,6 +15,7 @@ use crate::InCBProperty;
 /// of advanced text shaping anyway, so clusters over a certain length cannot be
 /// rendered anyway and so in that case we just want to find the beginning of
 /// the next cluster so we can know when to stop discarding overlong input.
+#[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State {
     /// The initial state at the beginning of the text or when the following
@@ -50,20 +51,22 @@ impl State {
     /// categories in the current state, and the state that should be used for
     /// the next transition.
     ///
-    /// Use [`GcProperties::Other`] to represent the absense of a character at
-    /// the start or end of input.
+    /// Use [`CharProperties::NO_CHAR`] as `prev` to represent the absence of
+    /// a character at the start of input, in which case there's always a
+    /// boundary. This sentinel allows the caller to store the previous
+    /// character's properties without wrapping them in an [`Option`].
     ///
     /// Correct use requires that the `prev` of one call equals the `next`
     /// of the previous call that generated the new state. If that is not
     /// upheld then the results are unspecified.
-    pub fn transition(self, prev: Option<CharProperties>, next: CharProperties) -> (bool, State) {
+    pub const fn transition(self, prev: CharProperties, next: CharProperties) -> (bool, State) {
         use GCBProperty::*;
 
         let next_state = self.next_state(next);
-        let Some(prev) = prev else {
+        if prev.is_no_char() {
             // At start of input there's always a boundary.
             return (true, next_state);
-        };
+        }
 
         // GB1 and GB2 aren't covered here because we use "Other" to represent
         // the beginning and end of the data, which will therefore always
@@ -113,31 +116,27 @@ impl State {
             return (false, next_state);
         }
         // GB9c: Do not break within certain combinations with Indic_Conjunct_Break (InCB)=Linker
-        if self.gb9c_active() {
-            if matches!(
+        if self.gb9c_active()
+            && matches!(
                 prev.incb_property(),
                 InCBProperty::Linker | InCBProperty::Extend
-            ) && matches!(next.incb_property(), InCBProperty::Consonant)
-            {
-                return (false, next_state);
-            }
+            )
+            && matches!(next.incb_property(), InCBProperty::Consonant)
+        {
+            return (false, next_state);
         }
         // (GB10 was from an earlier version of the specification but is no longer used)
         // GB11: Do not break within emoji modifier sequences or emoji zwj sequences.
-        if self.gb11_active() {
-            if pair_matches!(ZWJ, ExtendedPictographic) {
-                return (false, next_state);
-            }
+        if self.gb11_active() && pair_matches!(ZWJ, ExtendedPictographic) {
+            return (false, next_state);
         }
         // GB12 and GB13: Do not break within emoji flag sequences.
-        if self.gb13_active() {
-            if pair_matches!(RegionalIndicator, RegionalIndicator) {
-                return (false, next_state);
-            }
+        if self.gb13_active() && pair_matches!(RegionalIndicator, RegionalIndicator) {
+            return (false, next_state);
         }
 
         // GB999: Otherwise, break everywhere.
-        return (true, next_state);
+        (true, next_state)
     }
 
     /// Returns the next state that the state machine transitions to when
@@ -147,20 +146,26 @@ impl State {
         use State::*;
         // Two of the multi-character prefixes can begin regardless of
         // what preceeds them. These don't need to be covered by the
-        // state-specific arms that fllow.
+        // state-specific arms that follow.
         if matches!(next.gcb_property(), ExtendedPictographic) {
             return GB11BeforeZWJ;
         }
         if matches!(next.incb_property(), InCBProperty::Consonant) {
             return GB9cConsonant;
         }
+        // A regional indicator can begin an emoji flag after anything other
+        // than the first half of another emoji flag, regardless of which
+        // other rule might've been in progress before it.
+        if matches!(next.gcb_property(), RegionalIndicator) {
+            return match self {
+                AwaitEmojiFlag => Base,
+                _ => AwaitEmojiFlag,
+            };
+        }
         let gc_prop = next.gcb_property();
         let incb_prop = next.incb_property();
         match self {
-            Base => match gc_prop {
-                RegionalIndicator => AwaitEmojiFlag,
-                _ => Base,
-            },
+            Base => Base,
             AwaitEmojiFlag => Base,
             GB11BeforeZWJ => match gc_prop {
                 ZWJ => GB11AfterZWJ,
@@ -180,6 +185,45 @@ impl State {
         }
     }
 
+    /// Returns `true` if this state could have been produced by
+    /// [`Self::transition`] for a character with the given properties, or
+    /// at the start of input if `prev` is [`CharProperties::NO_CHAR`].
+    ///
+    /// This is used to detect violations of the contract described for
+    /// [`Self::transition`], such as a state being paired with the previous
+    /// character from a different stream.
+    pub const fn accepts_prev(self, prev: CharProperties) -> bool {
+        use GCBProperty::*;
+        use State::*;
+        if prev.is_no_char() {
+            return matches!(self, Base);
+        }
+        let gcb = prev.gcb_property();
+        let incb = prev.incb_property();
+        match self {
+            Base => true,
+            AwaitEmojiFlag => matches!(gcb, RegionalIndicator),
+            GB11BeforeZWJ => matches!(gcb, ExtendedPictographic | Extend),
+            GB11AfterZWJ => matches!(gcb, ZWJ),
+            GB9cConsonant => matches!(incb, InCBProperty::Consonant | InCBProperty::Extend),
+            GB9cLinker => matches!(incb, InCBProperty::Linker | InCBProperty::Extend),
+        }
+    }
+
+    /// Returns the state whose `repr(u8)` value is `raw`, if any.
+    pub const fn from_raw(raw: u8) -> Option<Self> {
+        use State::*;
+        Some(match raw {
+            0 => Base,
+            1 => AwaitEmojiFlag,
+            2 => GB11BeforeZWJ,
+            3 => GB11AfterZWJ,
+            4 => GB9cConsonant,
+            5 => GB9cLinker,
+            _ => return None,
+        })
+    }
+
     const fn gb9c_active(self) -> bool {
         // GB9c only active in GB9cConsonantExtendLinkerLinker state
         matches!(self, Self::GB9cLinker)
@@ -196,5 +240,44 @@ impl State {
     }
 }
 
-#[cfg(test)]
+/// The state carried from one call to [`boundary_between`] to the next.
+///
+/// This summarizes whatever came before the previous character that could
+/// affect the boundaries that follow, in the same way as the internal
+/// state of a [`GraphemeMachine`](crate::GraphemeMachine). The
+/// representation is opaque, but the value is small and can be copied and
+/// compared freely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundaryState(State);
+
+impl BoundaryState {
+    /// Returns the state to use with the first character of input, which
+    /// always begins a grapheme cluster, as `prev` in the first call to
+    /// [`boundary_between`].
+    pub const fn start(first: CharProperties) -> Self {
+        Self(State::Base.next_state(first))
+    }
+}
+
+/// Returns whether there's a grapheme cluster boundary between two
+/// characters with the given properties, along with the state to pass to
+/// the next call.
+///
+/// This is the same algorithm that [`GraphemeMachine`](crate::GraphemeMachine)
+/// uses, as a pure function so that callers can drive it without owning a
+/// mutable machine, including in `const` contexts. Begin with
+/// [`BoundaryState::start`] for the first character, and then in each
+/// call pass the state returned from the previous call and the `next` of
+/// that call as `prev`. If that isn't upheld then the results are
+/// unspecified.
+pub const fn boundary_between(
+    state: BoundaryState,
+    prev: CharProperties,
+    next: CharProperties,
+) -> (bool, BoundaryState) {
+    let (boundary, next_state) = state.0.transition(prev, next);
+    (boundary, BoundaryState(next_state))
+}
+
+#[cfg(all(test, feature = "tables"))]
 mod tests;
@@ -93,6 +93,79 @@ fn emoji_flags() {
     );
 }
 
+#[test]
+fn emoji_flags_after_other_rules() {
+    // GB12 and GB13 must apply no matter which other multi-character rule
+    // was in progress just before the first regional indicator.
+    use State::*;
+    let consonant = CharProperties::new(GCBProperty::None, InCBProperty::Consonant);
+    let got: Vec<_> = transitions(&[
+        CharProperties::ExtendedPictographic,
+        CharProperties::RegionalIndicator,
+        CharProperties::RegionalIndicator,
+        consonant,
+        CharProperties::RegionalIndicator,
+        CharProperties::RegionalIndicator,
+        CharProperties::ExtendedPictographic,
+        CharProperties::ZWJ,
+        CharProperties::RegionalIndicator,
+        CharProperties::RegionalIndicator,
+    ])
+    .collect();
+    assert_eq!(
+        got,
+        &[
+            (true, CharProperties::ExtendedPictographic, GB11BeforeZWJ),
+            (true, CharProperties::RegionalIndicator, AwaitEmojiFlag),
+            (false, CharProperties::RegionalIndicator, Base),
+            (true, consonant, GB9cConsonant),
+            (true, CharProperties::RegionalIndicator, AwaitEmojiFlag),
+            (false, CharProperties::RegionalIndicator, Base),
+            (true, CharProperties::ExtendedPictographic, GB11BeforeZWJ),
+            (false, CharProperties::ZWJ, GB11AfterZWJ),
+            (true, CharProperties::RegionalIndicator, AwaitEmojiFlag),
+            (false, CharProperties::RegionalIndicator, Base),
+        ]
+    );
+}
+
+#[test]
+fn regional_indicator_after_emoji_or_conjunct() {
+    // Regression test: a regional indicator immediately after an extended
+    // pictographic character or an Indic consonant must still begin an
+    // emoji flag, rather than leaving the machine in its base state.
+    fn clusters(s: &str) -> Vec<&str> {
+        let mut machine = crate::GraphemeMachine::new();
+        let mut got = Vec::new();
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            if machine.next_char(c) == crate::ClusterAction::Split && i != 0 {
+                got.push(&s[start..i]);
+                start = i;
+            }
+        }
+        got.push(&s[start..]);
+        got
+    }
+
+    assert_eq!(
+        clusters("\u{1F600}\u{1F1E6}\u{1F1E7}\u{1F1E8}"),
+        &["\u{1F600}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}"]
+    );
+    assert_eq!(
+        clusters("\u{1F469}\u{200D}\u{1F1E6}\u{1F1E7}"),
+        &["\u{1F469}\u{200D}", "\u{1F1E6}\u{1F1E7}"]
+    );
+    assert_eq!(
+        clusters("\u{0915}\u{1F1E6}\u{1F1E7}\u{1F1E8}\u{1F1E9}"),
+        &["\u{0915}", "\u{1F1E6}\u{1F1E7}", "\u{1F1E8}\u{1F1E9}"]
+    );
+    assert_eq!(
+        clusters("\u{0915}\u{094D}\u{1F1E6}\u{1F1E7}"),
+        &["\u{0915}\u{094D}", "\u{1F1E6}\u{1F1E7}"]
+    );
+}
+
 #[test]
 fn unicode_test_table() {
     let mut failures = 0;
@@ -100,25 +173,20 @@ fn unicode_test_table() {
         let input = str::from_utf8(test.input).expect("invalid UTF-8 in test input");
         let mut remain = input;
         let mut state = State::Base;
-        let mut prev: Option<CharProperties> = None;
+        let mut prev = CharProperties::NO_CHAR;
         let mut got: Vec<Box<[u8]>> = Vec::new();
         let mut current: Vec<u8> = Vec::new();
-        loop {
-            let (Some(next), rest) = u8char::from_string_prefix(remain) else {
-                break;
-            };
+        while let (Some(next), rest) = u8char::from_string_prefix(remain) {
             let next_props = crate::CharProperties::for_u8char(next);
             let (boundary, next_state) = state.transition(prev, next_props);
-            if boundary {
-                if !current.is_empty() {
-                    let boxed = current.clone().into_boxed_slice();
-                    got.push(boxed);
-                    current.clear();
-                }
+            if boundary && !current.is_empty() {
+                let boxed = current.clone().into_boxed_slice();
+                got.push(boxed);
+                current.clear();
             }
             current.extend_from_slice(next.as_bytes());
             remain = rest;
-            prev = Some(next_props);
+            prev = next_props;
             state = next_state;
         }
         if !current.is_empty() {
@@ -138,7 +206,7 @@ fn unicode_test_table() {
         panic!("{failures} tests failed");
     }
 
-    fn result_matches(got: &Vec<Box<[u8]>>, want: &[&[u8]]) -> bool {
+    fn result_matches(got: &[Box<[u8]>], want: &[&[u8]]) -> bool {
         if got.len() != want.len() {
             return false;
         }
@@ -247,27 +315,75 @@ fn emoji_extend() {
     );
 }
 
+#[test]
+fn transitions_accept_their_character() {
+    use State::*;
+    let states = [
+        Base,
+        AwaitEmojiFlag,
+        GB11BeforeZWJ,
+        GB11AfterZWJ,
+        GB9cConsonant,
+        GB9cLinker,
+    ];
+    let all_props = (0..0x40).filter_map(|raw| CharProperties::from_raw(raw).ok());
+    for next in all_props {
+        for state in states {
+            let (_, next_state) = state.transition(CharProperties::NO_CHAR, next);
+            assert!(
+                next_state.accepts_prev(next),
+                "{state:?} transitioned to {next_state:?} for {next:?}, which then rejects it"
+            );
+        }
+    }
+    assert!(Base.accepts_prev(CharProperties::NO_CHAR));
+    assert!(!GB11AfterZWJ.accepts_prev(CharProperties::NO_CHAR));
+    assert!(!GB11AfterZWJ.accepts_prev(CharProperties::None));
+}
+
+#[test]
+fn boundary_between_matches_machine() {
+    const RI: CharProperties =
+        CharProperties::new(GCBProperty::RegionalIndicator, InCBProperty::None);
+    const FLAG_BOUNDARY: bool = boundary_between(BoundaryState::start(RI), RI, RI).0;
+    const { assert!(!FLAG_BOUNDARY) };
+
+    let s = "a\u{1F1E6}\u{1F1E8}\u{1F1E6}\u{1F468}\u{200D}\u{1F469}\u{0915}\u{094D}\u{0924}\r\nb";
+    let props: Vec<_> = s.chars().map(CharProperties::for_char).collect();
+    let mut machine = crate::GraphemeMachine::new();
+    let want: Vec<_> = s
+        .chars()
+        .map(|c| machine.next_char(c) == crate::ClusterAction::Split)
+        .collect();
+    let mut state = BoundaryState::start(props[0]);
+    let mut got = vec![true];
+    for pair in props.windows(2) {
+        let (boundary, next_state) = boundary_between(state, pair[0], pair[1]);
+        got.push(boundary);
+        state = next_state;
+    }
+    assert_eq!(got, want);
+}
+
 fn transitions(
     cats: &[CharProperties],
 ) -> impl Iterator<Item = (bool, CharProperties, State)> + use<'_> {
     struct Iter<'a> {
         remain: &'a [CharProperties],
         state: State,
-        prev: Option<CharProperties>,
+        prev: CharProperties,
     }
     impl<'a> Iterator for Iter<'a> {
         type Item = (bool, CharProperties, State);
 
         fn next(&mut self) -> Option<Self::Item> {
-            let Some((next, remain)) = self.remain.split_first() else {
-                return None;
-            };
+            let (next, remain) = self.remain.split_first()?;
             let prev = self.prev;
             let next = *next;
             let (split, next_state) = self.state.transition(prev, next);
             self.remain = remain;
             self.state = next_state;
-            self.prev = Some(next);
+            self.prev = next;
             Some((split, next, next_state))
         }
     }
@@ -275,6 +391,6 @@ fn transitions(
     Iter {
         remain: cats,
         state: State::Base,
-        prev: None,
+        prev: CharProperties::NO_CHAR,
     }
 }
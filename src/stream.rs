@@ -0,0 +1,394 @@
+//! Adapters for segmenting asynchronous streams of byte chunks.
+
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+
+use ::u8char::AsU8Chars;
+use bytes::{Bytes, BytesMut};
+use futures_core::{FusedStream, Stream};
+use u8char::u8char;
+
+use crate::{GraphemeMachine, InvalidUtf8Error, SegmentAction, Segmenter};
+
+/// An adapter that turns a [`Stream`] of [`Bytes`] chunks containing UTF-8
+/// text into a stream of grapheme clusters, each also represented as
+/// [`Bytes`].
+///
+/// The chunks can be split at arbitrary points, including partway through
+/// a UTF-8 sequence or a grapheme cluster, as is typical for HTTP bodies
+/// and websocket messages. The adapter carries any incomplete character and
+/// the state of its [`GraphemeMachine`] over to the next chunk.
+///
+/// Each cluster that lies entirely within one chunk is returned as a slice
+/// of that chunk without copying. The bytes of a cluster that straddles
+/// chunks are copied into a new buffer. Every returned cluster is valid
+/// UTF-8.
+///
+/// The inner stream's items are results so that fallible sources like
+/// request bodies can be used directly. Use `.map(Ok)` to adapt an
+/// infallible stream. The inner stream must be [`Unpin`]; use
+/// [`Box::pin`](alloc::boxed::Box::pin) for one that isn't.
+///
+/// The stream ends after returning the first error, whether from the inner
+/// stream or because the input wasn't valid UTF-8. Any clusters that were
+/// known to be complete before the error are returned first, but the
+/// cluster that was still in progress is discarded.
+///
+/// The adapter uses a [`GraphemeMachine`] by default, but
+/// [`Self::with_machine`] accepts any other [`Segmenter`], in which case
+/// each "cluster" is one of that machine's segments.
+///
+/// This is available only when the `stream` feature is enabled.
+#[derive(Debug)]
+pub struct ClusterStream<S, E, M = GraphemeMachine> {
+    inner: S,
+    machine: M,
+    ready: VecDeque<Bytes>,
+    error: Option<StreamError<E>>,
+    done: bool,
+    // The bytes of the cluster in progress that arrived in earlier chunks.
+    carry: BytesMut,
+    // An incomplete UTF-8 sequence from the end of the previous chunk.
+    partial: [u8; 4],
+    partial_len: usize,
+    // The number of bytes in all of the chunks processed so far, for
+    // reporting the positions of errors.
+    offset: usize,
+}
+
+impl<S, E> ClusterStream<S, E>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+{
+    /// Wraps the given stream of chunks, using a new [`GraphemeMachine`] in
+    /// its initial "start of input" state.
+    pub fn new(inner: S) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+}
+
+impl<S, E, M> ClusterStream<S, E, M>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    M: Segmenter,
+{
+    /// Wraps the given stream of chunks using an existing machine, which
+    /// should be in its "start of input" state, such as a [`Segmenter`]
+    /// other than [`GraphemeMachine`].
+    pub fn with_machine(machine: M, inner: S) -> Self {
+        Self {
+            inner,
+            machine,
+            ready: VecDeque::new(),
+            error: None,
+            done: false,
+            carry: BytesMut::new(),
+            partial: [0; 4],
+            partial_len: 0,
+            offset: 0,
+        }
+    }
+
+    /// Consumes the adapter and returns the inner stream, discarding any
+    /// clusters that were not yet returned.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn push_chunk(&mut self, chunk: Bytes) {
+        let mut pos = 0;
+        if self.partial_len > 0 {
+            pos = self.complete_partial(&chunk);
+            if self.partial_len > 0 || self.error.is_some() {
+                // The chunk was too short to complete the sequence.
+                self.offset += chunk.len();
+                return;
+            }
+        }
+
+        let rest = &chunk[pos..];
+        let (valid, tail) = match core::str::from_utf8(rest) {
+            Ok(s) => (s, &[][..]),
+            Err(err) => {
+                let (valid, tail) = rest.split_at(err.valid_up_to());
+                if err.error_len().is_some() {
+                    self.error = Some(StreamError::InvalidUtf8(InvalidUtf8Error {
+                        valid_up_to: self.offset + pos + err.valid_up_to(),
+                        error_len: err.error_len(),
+                    }));
+                }
+                // Safety: from_utf8 reported that these bytes are valid.
+                (unsafe { core::str::from_utf8_unchecked(valid) }, tail)
+            }
+        };
+
+        let mut start = pos;
+        let mut end = pos;
+        for c in valid.u8chars() {
+            let split = self.machine.next_u8char(c).is_boundary();
+            if split && (end > start || !self.carry.is_empty()) {
+                let cluster = if self.carry.is_empty() {
+                    chunk.slice(start..end)
+                } else {
+                    self.carry.extend_from_slice(&chunk[start..end]);
+                    self.carry.split().freeze()
+                };
+                self.ready.push_back(cluster);
+                start = end;
+            }
+            end += c.len();
+        }
+        self.carry.extend_from_slice(&chunk[start..end]);
+
+        if self.error.is_none() {
+            self.partial[..tail.len()].copy_from_slice(tail);
+            self.partial_len = tail.len();
+        }
+        self.offset += chunk.len();
+    }
+
+    /// Adds bytes from the start of the chunk to the incomplete sequence
+    /// left over from the previous chunk, processing the resulting
+    /// character if it's now complete. Returns the number of bytes used.
+    fn complete_partial(&mut self, chunk: &[u8]) -> usize {
+        let want = match self.partial[0] {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        let used = chunk.len().min(want - self.partial_len);
+        self.partial[self.partial_len..self.partial_len + used].copy_from_slice(&chunk[..used]);
+        let len = self.partial_len + used;
+        let start_offset = self.offset - self.partial_len;
+        match core::str::from_utf8(&self.partial[..len]) {
+            Ok(s) => {
+                self.partial_len = 0;
+                let (c, _) = u8char::from_string_prefix(s);
+                // from_utf8 succeeded, so there's exactly one character.
+                let c = c.unwrap();
+                if self.machine.next_u8char(c).is_boundary() && !self.carry.is_empty() {
+                    self.ready.push_back(self.carry.split().freeze());
+                }
+                self.carry.extend_from_slice(c.as_bytes());
+            }
+            Err(err) if err.error_len().is_some() => {
+                self.error = Some(StreamError::InvalidUtf8(InvalidUtf8Error {
+                    valid_up_to: start_offset + err.valid_up_to(),
+                    error_len: err.error_len(),
+                }));
+            }
+            Err(_) => self.partial_len = len,
+        }
+        used
+    }
+
+    fn finish(&mut self) {
+        if self.partial_len > 0 {
+            self.error = Some(StreamError::InvalidUtf8(InvalidUtf8Error {
+                valid_up_to: self.offset - self.partial_len,
+                error_len: None,
+            }));
+            return;
+        }
+        self.machine.end_of_input();
+        if !self.carry.is_empty() {
+            self.ready.push_back(self.carry.split().freeze());
+        }
+        self.done = true;
+    }
+}
+
+// The adapter never pins its error value or its machine, so it's Unpin
+// whenever the inner stream is, regardless of their types.
+impl<S: Unpin, E, M> Unpin for ClusterStream<S, E, M> {}
+
+impl<S, E, M> Stream for ClusterStream<S, E, M>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    M: Segmenter,
+{
+    type Item = Result<Bytes, StreamError<E>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(cluster) = this.ready.pop_front() {
+                return Poll::Ready(Some(Ok(cluster)));
+            }
+            if let Some(err) = this.error.take() {
+                this.done = true;
+                return Poll::Ready(Some(Err(err)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+                Some(Ok(chunk)) => this.push_chunk(chunk),
+                Some(Err(err)) => this.error = Some(StreamError::Source(err)),
+                None => this.finish(),
+            }
+        }
+    }
+}
+
+impl<S, E, M> FusedStream for ClusterStream<S, E, M>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    M: Segmenter,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.ready.is_empty() && self.error.is_none()
+    }
+}
+
+/// An adapter that turns a [`Stream`] of text chunks, such as `&str` or
+/// `String` values, into a stream of grapheme clusters, each as a `String`.
+///
+/// This is like [`ClusterStream`] for sources that have already decoded
+/// their text, such as the text messages of a websocket. The chunks can be
+/// split at arbitrary character boundaries, including partway through a
+/// grapheme cluster, and the adapter carries the cluster in progress and
+/// the state of its [`GraphemeMachine`] over to the next chunk.
+///
+/// The inner stream must be [`Unpin`]; use
+/// [`Box::pin`](alloc::boxed::Box::pin) for one that isn't. As with
+/// [`ClusterStream`], [`Self::with_machine`] accepts a [`Segmenter`] other
+/// than [`GraphemeMachine`].
+///
+/// This is available only when the `stream` feature is enabled.
+#[derive(Debug)]
+pub struct StrClusterStream<S, M = GraphemeMachine> {
+    inner: S,
+    machine: M,
+    ready: VecDeque<String>,
+    done: bool,
+    // The cluster in progress, which began in an earlier chunk.
+    carry: String,
+}
+
+impl<S, T> StrClusterStream<S>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<str>,
+{
+    /// Wraps the given stream of chunks, using a new [`GraphemeMachine`] in
+    /// its initial "start of input" state.
+    pub fn new(inner: S) -> Self {
+        Self::with_machine(GraphemeMachine::new(), inner)
+    }
+}
+
+impl<S, T, M> StrClusterStream<S, M>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<str>,
+    M: Segmenter,
+{
+    /// Wraps the given stream of chunks using an existing machine, which
+    /// should be in its "start of input" state.
+    pub fn with_machine(machine: M, inner: S) -> Self {
+        Self {
+            inner,
+            machine,
+            ready: VecDeque::new(),
+            done: false,
+            carry: String::new(),
+        }
+    }
+
+    /// Consumes the adapter and returns the inner stream, discarding any
+    /// clusters that were not yet returned.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn push_chunk(&mut self, chunk: &str) {
+        for c in chunk.u8chars() {
+            if self.machine.next_u8char(c).is_boundary() && !self.carry.is_empty() {
+                self.ready.push_back(core::mem::take(&mut self.carry));
+            }
+            self.carry.push_str(c.as_str());
+        }
+    }
+
+    fn finish(&mut self) {
+        self.machine.end_of_input();
+        if !self.carry.is_empty() {
+            self.ready.push_back(core::mem::take(&mut self.carry));
+        }
+        self.done = true;
+    }
+}
+
+// As for ClusterStream, the machine is never pinned.
+impl<S: Unpin, M> Unpin for StrClusterStream<S, M> {}
+
+impl<S, T, M> Stream for StrClusterStream<S, M>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<str>,
+    M: Segmenter,
+{
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(cluster) = this.ready.pop_front() {
+                return Poll::Ready(Some(cluster));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+                Some(chunk) => this.push_chunk(chunk.as_ref()),
+                None => this.finish(),
+            }
+        }
+    }
+}
+
+impl<S, T, M> FusedStream for StrClusterStream<S, M>
+where
+    S: Stream<Item = T> + Unpin,
+    T: AsRef<str>,
+    M: Segmenter,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.ready.is_empty()
+    }
+}
+
+/// The error type for [`ClusterStream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamError<E> {
+    /// The inner stream returned an error.
+    Source(E),
+    /// The stream's content was not valid UTF-8. The error's offsets are
+    /// relative to the start of the whole stream.
+    InvalidUtf8(InvalidUtf8Error),
+}
+
+impl<E: fmt::Display> fmt::Display for StreamError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(err) => err.fmt(f),
+            Self::InvalidUtf8(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for StreamError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Source(err) => Some(err),
+            Self::InvalidUtf8(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,161 @@
+use super::*;
+
+use core::task::Waker;
+use pretty_assertions::assert_eq;
+
+/// A stream that returns each of the given items immediately.
+struct Items<T>(VecDeque<T>);
+
+impl<T: Unpin> Stream for Items<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.get_mut().0.pop_front())
+    }
+}
+
+fn collect<E>(chunks: &[&[u8]], err: Option<E>) -> Vec<Result<Bytes, StreamError<E>>>
+where
+    E: Unpin,
+{
+    let mut items: VecDeque<_> = chunks
+        .iter()
+        .map(|c| Ok(Bytes::copy_from_slice(c)))
+        .collect();
+    if let Some(err) = err {
+        items.push_back(Err(err));
+    }
+    let mut stream = ClusterStream::new(Items(items));
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut ret = Vec::new();
+    while let Poll::Ready(Some(item)) = Pin::new(&mut stream).poll_next(&mut cx) {
+        ret.push(item);
+    }
+    assert!(stream.is_terminated());
+    ret
+}
+
+fn clusters(chunks: &[&[u8]]) -> Vec<String> {
+    collect::<()>(chunks, None)
+        .into_iter()
+        .map(|r| String::from_utf8(r.unwrap().to_vec()).unwrap())
+        .collect()
+}
+
+#[test]
+fn chunked_clusters() {
+    let input = "a\r\ne\u{0301}🇦🇧🧑‍🌾!".as_bytes();
+    let want = &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"];
+    assert_eq!(clusters(&[input]), want);
+    // Every possible split into two chunks, including partway through
+    // characters.
+    for i in 0..=input.len() {
+        let (a, b) = input.split_at(i);
+        assert_eq!(clusters(&[a, b]), want, "split at {i}");
+    }
+    // One byte at a time, with some empty chunks too.
+    let bytes: Vec<&[u8]> = input.chunks(1).flat_map(|c| [c, &[][..]]).collect();
+    assert_eq!(clusters(&bytes), want);
+    assert_eq!(clusters(&[]), &[] as &[&str]);
+}
+
+#[test]
+fn zero_copy() {
+    let chunk = Bytes::from_static("ab\u{0301}c".as_bytes());
+    let mut stream = ClusterStream::new(Items(VecDeque::from([Ok::<_, ()>(chunk.clone())])));
+    let mut cx = Context::from_waker(Waker::noop());
+    let Poll::Ready(Some(Ok(first))) = Pin::new(&mut stream).poll_next(&mut cx) else {
+        panic!("no first cluster");
+    };
+    let Poll::Ready(Some(Ok(second))) = Pin::new(&mut stream).poll_next(&mut cx) else {
+        panic!("no second cluster");
+    };
+    assert_eq!(first, "a");
+    assert_eq!(second, "b\u{0301}");
+    assert_eq!(first.as_ptr(), chunk.as_ptr());
+    assert_eq!(second.as_ptr(), chunk[1..].as_ptr());
+}
+
+#[test]
+fn errors() {
+    fn ok<E>(s: &'static str) -> Result<Bytes, StreamError<E>> {
+        Ok(Bytes::from_static(s.as_bytes()))
+    }
+    fn invalid(valid_up_to: usize, error_len: Option<usize>) -> Result<Bytes, StreamError<()>> {
+        Err(StreamError::InvalidUtf8(InvalidUtf8Error {
+            valid_up_to,
+            error_len,
+        }))
+    }
+
+    assert_eq!(
+        collect::<()>(&[b"ab", b"c\xffd"], None),
+        &[ok("a"), ok("b"), invalid(3, Some(1))],
+    );
+    // An invalid continuation of a sequence started in an earlier chunk.
+    // The "a" cluster is discarded because it's still in progress.
+    assert_eq!(
+        collect::<()>(&[b"a\xe2\x82", b"x"], None),
+        &[invalid(1, Some(2))],
+    );
+    // The stream ends partway through a sequence.
+    assert_eq!(
+        collect::<()>(&[b"ab\xe2", b"\x82"], None),
+        &[ok("a"), invalid(2, None)],
+    );
+    assert_eq!(
+        collect(&[b"ab"], Some("oops")),
+        &[ok("a"), Err(StreamError::Source("oops"))],
+    );
+}
+
+#[test]
+fn str_clusters() {
+    let collect = |chunks: &[&'static str]| {
+        let mut stream = StrClusterStream::new(Items(chunks.iter().copied().collect()));
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut ret = Vec::new();
+        while let Poll::Ready(Some(cluster)) = Pin::new(&mut stream).poll_next(&mut cx) {
+            ret.push(cluster);
+        }
+        assert!(stream.is_terminated());
+        ret
+    };
+    let input = "a\r\ne\u{0301}🇦🇧🧑‍🌾!";
+    let want = &["a", "\r\n", "e\u{0301}", "🇦🇧", "🧑‍🌾", "!"];
+    assert_eq!(collect(&[input]), want);
+    for (i, _) in input.char_indices() {
+        let (a, b) = input.split_at(i);
+        assert_eq!(collect(&[a, "", b]), want, "split at {i}");
+    }
+    assert_eq!(collect(&[]), &[] as &[&str]);
+}
+
+#[test]
+fn with_machine() {
+    use crate::{CharProperties, GCBProperty, InCBProperty, OverlayProperties, TailoredMachine};
+
+    // A tailoring in which a hyphen extends the cluster before it.
+    static OVERRIDES: &[(core::ops::RangeInclusive<char>, CharProperties)] = &[(
+        '-'..='-',
+        CharProperties::new(GCBProperty::Extend, InCBProperty::None),
+    )];
+    let machine = || TailoredMachine::new(OverlayProperties::new(OVERRIDES));
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let chunks = ["a-", "-b", "-\u{0301}c"].map(|s| Ok::<_, ()>(Bytes::from_static(s.as_bytes())));
+    let mut stream = ClusterStream::with_machine(machine(), Items(VecDeque::from(chunks)));
+    let mut got = Vec::new();
+    while let Poll::Ready(Some(cluster)) = Pin::new(&mut stream).poll_next(&mut cx) {
+        got.push(cluster.unwrap());
+    }
+    assert_eq!(got, ["a--", "b-\u{0301}", "c"]);
+
+    let chunks = VecDeque::from(["a-", "-b", "-\u{0301}c"]);
+    let mut stream = StrClusterStream::with_machine(machine(), Items(chunks));
+    let mut got = Vec::new();
+    while let Poll::Ready(Some(cluster)) = Pin::new(&mut stream).poll_next(&mut cx) {
+        got.push(cluster);
+    }
+    assert_eq!(got, ["a--", "b-\u{0301}", "c"]);
+}
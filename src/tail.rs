@@ -0,0 +1,212 @@
+use ::u8char::AsU8Chars;
+
+use crate::{GraphemeMachine, InvalidUtf8Error, SegmentAction, Segmenter, next_safe_restart};
+
+/// Returns the byte offset in `s` at which its last `n` grapheme clusters
+/// begin, or zero if it has fewer than `n` clusters.
+///
+/// Unlike counting clusters from the start of the string, this examines
+/// only a suffix of the string a little longer than those clusters: it
+/// starts segmenting from a safe restart point (as found by
+/// [`next_safe_restart`]) a bounded distance before the end, moving that
+/// distance further back only if it doesn't yet cover `n` clusters. That
+/// makes it suitable for showing the end of a large log buffer.
+pub fn tail_offset(s: &str, n: usize) -> usize {
+    tail_offset_with_machine(s, n, GraphemeMachine::new())
+}
+
+/// Like [`tail_offset`], but counting the segments found by the given
+/// [`Segmenter`] instead of grapheme clusters.
+///
+/// Segmentation still begins at a grapheme cluster safe restart point, and
+/// so this is correct only for a segmenter whose boundaries after such a
+/// point don't depend on the text before it, as is typical for tailorings
+/// of the grapheme cluster rules. The segmenter should be in its "start of
+/// input" state.
+pub fn tail_offset_with_machine(s: &str, n: usize, machine: impl Segmenter) -> usize {
+    match tail_offset_utf8_with_machine(s.as_bytes(), n, machine) {
+        Ok(offset) => offset,
+        // A `str` is always valid UTF-8.
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Like [`tail_offset`], but for a buffer of bytes that are expected to be
+/// UTF-8.
+///
+/// Only the suffix of the buffer that is examined must be valid UTF-8, so
+/// this returns [`InvalidUtf8Error`] only if there's an invalid sequence
+/// within roughly the last `n` clusters. Its offsets are relative to the
+/// start of `bytes`.
+pub fn tail_offset_utf8(bytes: &[u8], n: usize) -> Result<usize, InvalidUtf8Error> {
+    tail_offset_utf8_with_machine(bytes, n, GraphemeMachine::new())
+}
+
+/// Like [`tail_offset_utf8`], but counting the segments found by the given
+/// [`Segmenter`], with the same caveats as for
+/// [`tail_offset_with_machine`].
+pub fn tail_offset_utf8_with_machine(
+    bytes: &[u8],
+    n: usize,
+    mut machine: impl Segmenter,
+) -> Result<usize, InvalidUtf8Error> {
+    if n == 0 {
+        return Ok(bytes.len());
+    }
+    let mut window = initial_window(n);
+    loop {
+        let start = bytes.len().saturating_sub(window);
+        match tail_of_window(&bytes[start..], start == 0, n, &mut machine) {
+            Ok(Some(offset)) => return Ok(start + offset),
+            Ok(None) => window = window.saturating_mul(2),
+            Err(err) => {
+                return Err(InvalidUtf8Error {
+                    valid_up_to: start + err.valid_up_to,
+                    error_len: err.error_len,
+                });
+            }
+        }
+    }
+}
+
+/// Reads the last `n` grapheme clusters from the given seekable source,
+/// such as a file, returning them as a string.
+///
+/// Like [`tail_offset_utf8`], this seeks back from the end of the source
+/// only far enough to find `n` complete clusters, and so it reads only a
+/// little more than the returned text. The source is left positioned at
+/// its end, so that a `tail -f`-style viewer can go on to read whatever is
+/// appended to it afterwards.
+///
+/// Returns an error of kind [`std::io::ErrorKind::InvalidData`] wrapping an
+/// [`InvalidUtf8Error`] if the part of the source that was read isn't valid
+/// UTF-8, with offsets relative to the start of the source.
+///
+/// This is available only when the `std` feature is enabled.
+#[cfg(feature = "std")]
+pub fn read_tail<R>(reader: &mut R, n: usize) -> std::io::Result<alloc::string::String>
+where
+    R: std::io::Read + std::io::Seek + ?Sized,
+{
+    read_tail_with_machine(reader, n, GraphemeMachine::new())
+}
+
+/// Like [`read_tail`], but reading the last `n` segments found by the given
+/// [`Segmenter`], with the same caveats as for
+/// [`tail_offset_with_machine`].
+///
+/// This is available only when the `std` feature is enabled.
+#[cfg(feature = "std")]
+pub fn read_tail_with_machine<R>(
+    reader: &mut R,
+    n: usize,
+    mut machine: impl Segmenter,
+) -> std::io::Result<alloc::string::String>
+where
+    R: std::io::Read + std::io::Seek + ?Sized,
+{
+    use std::io::SeekFrom;
+
+    let len = reader.seek(SeekFrom::End(0))?;
+    if n == 0 {
+        return Ok(alloc::string::String::new());
+    }
+    let mut window = initial_window(n) as u64;
+    let mut buf = alloc::vec::Vec::new();
+    loop {
+        let start = len.saturating_sub(window);
+        reader.seek(SeekFrom::Start(start))?;
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        match tail_of_window(&buf, start == 0, n, &mut machine) {
+            Ok(Some(offset)) => {
+                buf.drain(..offset);
+                // tail_of_window validated everything from the offset on.
+                return Ok(alloc::string::String::from_utf8(buf).unwrap());
+            }
+            Ok(None) => window = window.saturating_mul(2),
+            Err(err) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    InvalidUtf8Error {
+                        valid_up_to: start as usize + err.valid_up_to,
+                        error_len: err.error_len,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// The number of bytes to examine first when looking for the last `n`
+/// clusters, which is enough for `n` clusters of typical length.
+fn initial_window(n: usize) -> usize {
+    n.saturating_mul(8).max(256)
+}
+
+/// Finds the offset in `window`, which is a suffix of some larger text, at
+/// which the last `n` clusters of that text begin.
+///
+/// If `at_start` is `false` then `window` may begin partway through a
+/// character or a cluster, and so only the clusters after the first safe
+/// restart point in `window` are counted. Returns `None` if there are fewer
+/// than `n` of those, in which case the caller should try a larger window.
+///
+/// The machine must be in its "start of input" state, and is left in that
+/// state again afterwards.
+fn tail_of_window(
+    window: &[u8],
+    at_start: bool,
+    n: usize,
+    machine: &mut impl Segmenter,
+) -> Result<Option<usize>, InvalidUtf8Error> {
+    let skip = if at_start {
+        0
+    } else {
+        // Skip the remainder of a character that began before the window.
+        window
+            .iter()
+            .take(3)
+            .take_while(|&&b| b & 0xc0 == 0x80)
+            .count()
+    };
+    let s = core::str::from_utf8(&window[skip..]).map_err(|err| {
+        let err = InvalidUtf8Error::from(err);
+        InvalidUtf8Error {
+            valid_up_to: skip + err.valid_up_to,
+            error_len: err.error_len,
+        }
+    })?;
+    let restart = if at_start {
+        0
+    } else {
+        // The start of the window isn't necessarily the start of a cluster,
+        // so skip at least its first character.
+        next_safe_restart(s, 1)
+    };
+    let s = &s[restart..];
+    let count = s
+        .u8chars()
+        .filter(|&c| machine.next_u8char(c).is_boundary())
+        .count();
+    machine.end_of_input();
+    if count < n {
+        return Ok(if at_start { Some(0) } else { None });
+    }
+    let mut splits = 0;
+    let mut offset = 0;
+    for c in s.u8chars() {
+        if machine.next_u8char(c).is_boundary() {
+            if splits == count - n {
+                break;
+            }
+            splits += 1;
+        }
+        offset += c.len();
+    }
+    machine.end_of_input();
+    Ok(Some(skip + restart + offset))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::ClusterAction;
+
+use pretty_assertions::assert_eq;
+
+/// Finds the start of the last `n` clusters by segmenting all of `s`.
+fn tail_offset_slow(s: &str, n: usize) -> usize {
+    let mut machine = GraphemeMachine::new();
+    let mut splits = Vec::new();
+    let mut offset = 0;
+    for (action, c) in machine.next_u8chars_from_str(s) {
+        if action == ClusterAction::Split {
+            splits.push(offset);
+        }
+        offset += c.len();
+    }
+    match splits.len().checked_sub(n) {
+        Some(i) if n > 0 => splits[i],
+        Some(_) => s.len(),
+        None => 0,
+    }
+}
+
+fn samples() -> Vec<String> {
+    let mut flags = "x".to_string();
+    // A long run of regional indicators has no safe restart points, so the
+    // window must grow until it reaches the start of the string.
+    flags.push_str(&"\u{1F1EF}\u{1F1F5}".repeat(300));
+    flags.push('\u{1F1EF}');
+    vec![
+        String::new(),
+        "a".to_string(),
+        "hello\r\nworld\n".repeat(100),
+        "e\u{0301}\u{0323}\u{1F469}\u{1F3FD}\u{200D}\u{1F680}".repeat(80),
+        "\u{0915}\u{094D}\u{0937}\u{093F} \u{1112}\u{1161}\u{11AB}".repeat(90),
+        format!("{}{}", "a".repeat(2000), "\u{0301}".repeat(500)),
+        flags,
+    ]
+}
+
+#[test]
+fn matches_full_segmentation() {
+    for s in samples() {
+        for n in [0, 1, 2, 3, 10, 33, 100, 257, 1000, 5000] {
+            assert_eq!(tail_offset(&s, n), tail_offset_slow(&s, n), "{n} of {s:?}");
+        }
+    }
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut bytes = b"\xff\xfe".to_vec();
+    bytes.extend_from_slice("abc\u{00e9}".repeat(200).as_bytes());
+    let len = bytes.len();
+    // The invalid bytes are far enough back that they're never examined.
+    assert_eq!(tail_offset_utf8(&bytes, 3), Ok(len - 4));
+    let err = tail_offset_utf8(&bytes, 1000).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (0, Some(1)));
+
+    let mut bytes = "abc".repeat(200).into_bytes();
+    bytes.push(0xe2);
+    let err = tail_offset_utf8(&bytes, 1).unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (600, None));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn reading_tail() {
+    for s in samples() {
+        for n in [0, 1, 10, 1000] {
+            let mut reader = std::io::Cursor::new(s.as_bytes());
+            let got = read_tail(&mut reader, n).unwrap();
+            assert_eq!(got, &s[tail_offset_slow(&s, n)..], "{n} of {s:?}");
+            assert_eq!(reader.position(), s.len() as u64);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn reading_tail_invalid_utf8() {
+    let mut bytes = "abc".repeat(200).into_bytes();
+    bytes[550] = 0xff;
+    let err = read_tail(&mut std::io::Cursor::new(bytes), 100).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let err = err
+        .into_inner()
+        .unwrap()
+        .downcast::<InvalidUtf8Error>()
+        .unwrap();
+    assert_eq!((err.valid_up_to(), err.error_len()), (550, Some(1)));
+}
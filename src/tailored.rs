@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use u8char::u8char;
+
+use crate::{CharProperties, GCBProperty, InCBProperty, PropertyLookup};
+
+/// A character property lookup table constructed at runtime, such as from
+/// newer or tailored Unicode character database files loaded at startup.
+///
+/// Construct this using a [`TailoredTableBuilder`]. It implements
+/// [`PropertyLookup`], so a `TailoredMachine` can use it in place of this
+/// library's own tables, or its results can be passed directly to
+/// [`GraphemeMachine::next_char_properties`](crate::GraphemeMachine::next_char_properties).
+///
+/// The table is a sorted list of runs of characters that share the same
+/// properties, and so each lookup is a binary search. That's slower than
+/// the tries embedded in this library, but the table for a whole Unicode
+/// release is still only a few thousand runs.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TailoredTable {
+    /// The first code point of each run along with the properties of all of
+    /// the characters up to the start of the next run. The first run always
+    /// starts at zero, and no two adjacent runs have the same properties.
+    runs: Vec<(u32, CharProperties)>,
+}
+
+impl TailoredTable {
+    /// Returns the properties of the given character, represented as a
+    /// [`u8char`] value.
+    pub fn for_u8char(&self, c: u8char) -> CharProperties {
+        self.for_char(c.to_char())
+    }
+
+    /// Returns the properties of the given character, represented as a
+    /// [`char`] value.
+    pub fn for_char(&self, c: char) -> CharProperties {
+        run_at(&self.runs, c as u32)
+    }
+
+    /// Returns the size in bytes of the table's heap allocation, in the same
+    /// sense as [`CharProperties::TABLE_SIZE`].
+    pub fn size(&self) -> usize {
+        self.runs.len() * size_of::<(u32, CharProperties)>()
+    }
+}
+
+impl PropertyLookup for TailoredTable {
+    fn for_u8char(&self, c: u8char) -> CharProperties {
+        TailoredTable::for_u8char(self, c)
+    }
+
+    fn for_char(&self, c: char) -> CharProperties {
+        TailoredTable::for_char(self, c)
+    }
+}
+
+/// Constructs a [`TailoredTable`] from a series of ranges of characters and
+/// their properties.
+///
+/// Characters start with [`GCBProperty::None`] and [`InCBProperty::None`],
+/// unless the builder was created with [`Self::from_lookup`], and each call
+/// to [`Self::insert`] replaces the properties of a range of characters,
+/// so later entries win where ranges overlap. For example, loading the
+/// entries from `GraphemeBreakProperty.txt` followed by the
+/// **Indic_Conjunct_Break** entries from `DerivedCoreProperties.txt` would
+/// lose the **Grapheme_Cluster_Break** values of the characters listed in
+/// both, and so callers should combine the properties of each character
+/// before inserting them.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct TailoredTableBuilder {
+    /// Runs in the same form as [`TailoredTable::runs`], except that
+    /// adjacent runs may have the same properties until [`Self::build`].
+    runs: Vec<(u32, CharProperties)>,
+}
+
+impl TailoredTableBuilder {
+    /// Constructs a builder in which every character has no properties.
+    pub fn new() -> Self {
+        let none = CharProperties::new(GCBProperty::None, InCBProperty::None);
+        Self {
+            runs: alloc::vec![(0, none)],
+        }
+    }
+
+    /// Constructs a builder that starts with the properties from some other
+    /// lookup, such as [`PropertyTable::DEFAULT`](crate::PropertyTable::DEFAULT),
+    /// so that the caller can tailor just a few characters.
+    ///
+    /// This looks up every character, and so is considerably slower than
+    /// inserting the ranges from a file of the Unicode character database.
+    pub fn from_lookup(lookup: &impl PropertyLookup) -> Self {
+        let mut runs: Vec<(u32, CharProperties)> = Vec::new();
+        for c in '\0'..=char::MAX {
+            let props = lookup.for_char(c);
+            if runs.last().is_none_or(|&(_, prev)| prev != props) {
+                runs.push((c as u32, props));
+            }
+        }
+        Self { runs }
+    }
+
+    /// Sets the given properties for all of the characters in `range`, in
+    /// place of whatever they had before.
+    pub fn insert(
+        &mut self,
+        range: RangeInclusive<char>,
+        gcb: GCBProperty,
+        incb: InCBProperty,
+    ) -> &mut Self {
+        self.insert_properties(range, CharProperties::new(gcb, incb))
+    }
+
+    /// Sets the given properties for all of the characters in `range`, in
+    /// place of whatever they had before.
+    ///
+    /// Unlike [`Self::insert`] this can also set the other properties of
+    /// [`CharProperties`], such as [`CharProperties::is_default_ignorable`]
+    /// or the user bits.
+    pub fn insert_properties(
+        &mut self,
+        range: RangeInclusive<char>,
+        props: CharProperties,
+    ) -> &mut Self {
+        let (start, end) = (*range.start() as u32, *range.end() as u32);
+        if start > end {
+            return self;
+        }
+        // The run that follows the range keeps whatever properties its
+        // first character had before.
+        let after = (end < char::MAX as u32).then(|| (end + 1, run_at(&self.runs, end + 1)));
+        let first = self.runs.partition_point(|&(s, _)| s < start);
+        let last = self.runs.partition_point(|&(s, _)| s <= end + 1);
+        let replacement = core::iter::once((start, props)).chain(after);
+        self.runs.splice(first..last, replacement);
+        self
+    }
+
+    /// Returns the finished table.
+    pub fn build(&self) -> TailoredTable {
+        let mut runs: Vec<(u32, CharProperties)> = Vec::with_capacity(self.runs.len());
+        for &(start, props) in &self.runs {
+            if runs.last().is_none_or(|&(_, prev)| prev != props) {
+                runs.push((start, props));
+            }
+        }
+        TailoredTable { runs }
+    }
+}
+
+impl Default for TailoredTableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<(RangeInclusive<char>, GCBProperty, InCBProperty)> for TailoredTableBuilder {
+    fn extend<T: IntoIterator<Item = (RangeInclusive<char>, GCBProperty, InCBProperty)>>(
+        &mut self,
+        iter: T,
+    ) {
+        for (range, gcb, incb) in iter {
+            self.insert(range, gcb, incb);
+        }
+    }
+}
+
+/// Returns the properties of the run containing code point `c`.
+fn run_at(runs: &[(u32, CharProperties)], c: u32) -> CharProperties {
+    let i = runs.partition_point(|&(start, _)| start <= c);
+    runs[i - 1].1
+}
+
+#[cfg(test)]
+mod tests;
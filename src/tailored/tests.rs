@@ -0,0 +1,98 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn insert() {
+    let mut builder = TailoredTableBuilder::new();
+    builder
+        .insert('a'..='z', GCBProperty::L, InCBProperty::None)
+        .insert('m'..='p', GCBProperty::V, InCBProperty::Consonant)
+        .insert('z'..='z', GCBProperty::V, InCBProperty::Consonant)
+        .insert(
+            '\u{10FFFF}'..='\u{10FFFF}',
+            GCBProperty::Control,
+            InCBProperty::None,
+        );
+    #[allow(clippy::reversed_empty_ranges)]
+    builder.insert('z'..='a', GCBProperty::CR, InCBProperty::None);
+    let table = builder.build();
+
+    let gcb = |c| table.for_char(c).gcb_property();
+    assert_eq!(gcb('`'), GCBProperty::None);
+    assert_eq!(gcb('a'), GCBProperty::L);
+    assert_eq!(gcb('l'), GCBProperty::L);
+    assert_eq!(gcb('m'), GCBProperty::V);
+    assert_eq!(gcb('p'), GCBProperty::V);
+    assert_eq!(gcb('q'), GCBProperty::L);
+    assert_eq!(gcb('z'), GCBProperty::V);
+    assert_eq!(gcb('{'), GCBProperty::None);
+    assert_eq!(gcb('\u{10FFFE}'), GCBProperty::None);
+    assert_eq!(gcb('\u{10FFFF}'), GCBProperty::Control);
+    assert_eq!(table.for_char('n').incb_property(), InCBProperty::Consonant);
+    assert_eq!(
+        table.for_u8char(u8char::from_char('n')),
+        table.for_char('n')
+    );
+    // Adjacent runs with the same properties are merged.
+    assert_eq!(table.runs.len(), 7);
+    assert_eq!(table.size(), 7 * size_of::<(u32, CharProperties)>());
+}
+
+#[test]
+fn extend() {
+    let mut builder = TailoredTableBuilder::default();
+    builder.extend([
+        ('\r'..='\r', GCBProperty::CR, InCBProperty::None),
+        ('\n'..='\n', GCBProperty::LF, InCBProperty::None),
+        (
+            '\u{300}'..='\u{36F}',
+            GCBProperty::Extend,
+            InCBProperty::Extend,
+        ),
+    ]);
+    let table = builder.build();
+    assert_eq!(table.for_char('\n').gcb_property(), GCBProperty::LF);
+    assert_eq!(
+        table.for_char('\u{301}'),
+        CharProperties::new(GCBProperty::Extend, InCBProperty::Extend)
+    );
+    assert_eq!(
+        PropertyLookup::for_char(&&table, '\r').gcb_property(),
+        GCBProperty::CR
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn from_lookup() {
+    let mut builder = TailoredTableBuilder::from_lookup(&crate::PropertyTable::DEFAULT);
+    assert_eq!(builder.build(), builder.build());
+    for c in [
+        'a',
+        '\r',
+        '\u{301}',
+        '\u{94D}',
+        '\u{FE0F}',
+        '👍',
+        '\u{E0100}',
+    ] {
+        assert_eq!(
+            builder.build().for_char(c),
+            CharProperties::for_char(c),
+            "{c:?}"
+        );
+    }
+
+    builder.insert(
+        '\u{A0}'..='\u{A0}',
+        GCBProperty::Control,
+        InCBProperty::None,
+    );
+    let table = builder.build();
+    assert_eq!(
+        table.for_char('\u{A0}').gcb_property(),
+        GCBProperty::Control
+    );
+    assert_eq!(table.for_char('\u{A1}'), CharProperties::for_char('\u{A1}'));
+}
@@ -0,0 +1,89 @@
+use u8char::u8char;
+
+use crate::{CharProperties, ClusterAction, GraphemeMachine, PropertyLookup};
+
+/// A [`GraphemeMachine`] that looks up the properties of each character
+/// using some [`PropertyLookup`] instead of this library's own table.
+///
+/// The lookup can be a [`PropertyTable`](crate::PropertyTable) chosen at
+/// runtime, a `TailoredTable` built from data loaded at startup, or a
+/// caller's own implementation, including a reference to any of those.
+/// When the `tables` feature is enabled this implements
+/// [`Segmenter`](crate::Segmenter), and so it can drive any of the helpers
+/// that accept a third-party machine, like
+/// [`CharActions::with_machine`](crate::CharActions::with_machine).
+#[derive(Debug)]
+pub struct TailoredMachine<P> {
+    machine: GraphemeMachine,
+    lookup: P,
+}
+
+impl<P: PropertyLookup> TailoredMachine<P> {
+    /// Constructs a new machine in an initial "start of input" state, which
+    /// uses the given lookup.
+    pub fn new(lookup: P) -> Self {
+        Self {
+            machine: GraphemeMachine::new(),
+            lookup,
+        }
+    }
+
+    /// Advances the state machine for a character with the given properties,
+    /// in the same way as [`GraphemeMachine::next_char_properties`].
+    pub fn next_char_properties(&mut self, next: CharProperties) -> ClusterAction {
+        self.machine.next_char_properties(next)
+    }
+
+    /// Looks up the [`CharProperties`] for the given character using this
+    /// machine's lookup, and then advances the state machine by passing it
+    /// to [`Self::next_char_properties`].
+    pub fn next_u8char(&mut self, c: u8char) -> ClusterAction {
+        let props = self.lookup.for_u8char(c);
+        self.next_char_properties(props)
+    }
+
+    /// Looks up the [`CharProperties`] for the given character using this
+    /// machine's lookup, and then advances the state machine by passing it
+    /// to [`Self::next_char_properties`].
+    pub fn next_char(&mut self, c: char) -> ClusterAction {
+        let props = self.lookup.for_char(c);
+        self.next_char_properties(props)
+    }
+
+    /// Tells the state machine that the input stream has ended, in the same
+    /// way as [`GraphemeMachine::end_of_input`].
+    pub fn end_of_input(&mut self) -> ClusterAction {
+        self.machine.end_of_input()
+    }
+
+    /// Returns the lookup that this machine uses.
+    pub fn lookup(&self) -> &P {
+        &self.lookup
+    }
+
+    /// Consumes the machine and returns the inner [`GraphemeMachine`] and
+    /// the lookup, with the machine in whatever state it was left in.
+    pub fn into_parts(self) -> (GraphemeMachine, P) {
+        (self.machine, self.lookup)
+    }
+}
+
+#[cfg(feature = "tables")]
+impl<P: PropertyLookup> crate::Segmenter for TailoredMachine<P> {
+    type Action = ClusterAction;
+
+    fn next_u8char(&mut self, c: u8char) -> ClusterAction {
+        TailoredMachine::next_u8char(self, c)
+    }
+
+    fn next_char(&mut self, c: char) -> ClusterAction {
+        TailoredMachine::next_char(self, c)
+    }
+
+    fn end_of_input(&mut self) -> ClusterAction {
+        TailoredMachine::end_of_input(self)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::{GCBProperty, InCBProperty};
+
+/// Treats every character as Regional_Indicator, so that each pair of
+/// characters forms a cluster.
+struct AllRegional;
+
+impl PropertyLookup for AllRegional {
+    fn for_u8char(&self, _: u8char) -> CharProperties {
+        CharProperties::new(GCBProperty::RegionalIndicator, InCBProperty::None)
+    }
+}
+
+#[test]
+fn custom_lookup() {
+    let mut machine = TailoredMachine::new(AllRegional);
+    let actions: Vec<_> = "abcde".chars().map(|c| machine.next_char(c)).collect();
+    assert_eq!(
+        actions,
+        [
+            ClusterAction::Split,
+            ClusterAction::Continue,
+            ClusterAction::Split,
+            ClusterAction::Continue,
+            ClusterAction::Split,
+        ]
+    );
+    assert_eq!(machine.end_of_input(), ClusterAction::Split);
+    assert_eq!(machine.next_char('a'), ClusterAction::Split);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn same_as_built_in() {
+    let mut tailored = TailoredMachine::new(crate::PropertyTable::DEFAULT);
+    for test in crate::properties::test_table::UNICODE_GRAPHEME_CLUSTER_TESTS {
+        let input = core::str::from_utf8(test.input).unwrap();
+        let mut machine = GraphemeMachine::new();
+        for c in input.chars() {
+            assert_eq!(tailored.next_char(c), machine.next_char(c), "{}", test.desc);
+        }
+        tailored.end_of_input();
+    }
+
+    let actions: Vec<_> = crate::CharActions::with_machine(tailored, "e\u{301}x".chars())
+        .map(|(action, _)| action)
+        .collect();
+    assert_eq!(
+        actions,
+        [
+            ClusterAction::Split,
+            ClusterAction::Continue,
+            ClusterAction::Split,
+        ]
+    );
+}
@@ -0,0 +1,308 @@
+//! A generator of realistic test strings made of the kinds of grapheme
+//! clusters that text handling most often gets wrong.
+
+use alloc::string::String;
+use core::iter::FusedIterator;
+
+/// A kind of grapheme cluster that [`TestStrings`] can generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClusterFamily {
+    /// A Latin letter, sometimes followed by one or two combining
+    /// diacritical marks, like `e\u{0301}`.
+    Latin,
+    /// An emoji modifier base with a skin tone modifier, like
+    /// `\u{1F44B}\u{1F3FD}`, sometimes also joined with a ZWJ to an object
+    /// to make a profession emoji, like `\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}`.
+    SkinToneEmoji,
+    /// A family emoji of one or two adults and one or two children joined
+    /// with ZWJs, like `\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}`.
+    FamilyEmoji,
+    /// A flag made of a pair of regional indicators, like
+    /// `\u{1F1EF}\u{1F1F5}`.
+    Flag,
+    /// A Devanagari conjunct of two or three consonants joined by viramas,
+    /// sometimes followed by a vowel sign and an anusvara, like
+    /// `\u{0915}\u{094D}\u{0937}\u{093F}`.
+    DevanagariConjunct,
+    /// A Hangul syllable, either precomposed or made of conjoining jamo,
+    /// like `\u{1112}\u{1161}\u{11AB}`.
+    Hangul,
+    /// A Thai consonant, sometimes followed by a vowel sign and a tone
+    /// mark, like `\u{0E19}\u{0E49}\u{0E33}`.
+    Thai,
+}
+
+impl ClusterFamily {
+    /// All of the cluster families, in the order they're declared.
+    pub const ALL: [Self; 7] = [
+        Self::Latin,
+        Self::SkinToneEmoji,
+        Self::FamilyEmoji,
+        Self::Flag,
+        Self::DevanagariConjunct,
+        Self::Hangul,
+        Self::Thai,
+    ];
+}
+
+/// Generates well-formed test strings from a mix of [`ClusterFamily`]
+/// grapheme clusters, such as for building corpora to test text editors,
+/// renderers and other software that handles international text.
+///
+/// The generator is a deterministic pseudorandom sequence, so the same seed
+/// and mix always produce the same strings. It isn't suitable for anything
+/// that needs unpredictable randomness.
+///
+/// Every generated cluster is exactly one extended grapheme cluster, even
+/// when placed directly after any other generated cluster, so the clusters
+/// returned by iterating over the generator can be used as the expected
+/// result of segmenting the string made by concatenating them.
+///
+/// This is available only when the `testgen` feature is enabled.
+#[derive(Debug, Clone)]
+pub struct TestStrings {
+    state: u64,
+    weights: [u32; ClusterFamily::ALL.len()],
+}
+
+impl TestStrings {
+    /// Constructs a generator with the given seed that chooses each of
+    /// the cluster families equally often.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            weights: [1; ClusterFamily::ALL.len()],
+        }
+    }
+
+    /// Sets how often the given family is chosen, relative to the weights
+    /// of the other families, which all start at 1.
+    ///
+    /// A weight of zero means that the family is never chosen. Setting
+    /// every weight to zero makes the generator panic when asked for a
+    /// cluster.
+    pub fn with_weight(mut self, family: ClusterFamily, weight: u32) -> Self {
+        self.weights[family as usize] = weight;
+        self
+    }
+
+    /// Appends one generated grapheme cluster to the given buffer, and
+    /// returns the family it was chosen from.
+    pub fn push_cluster(&mut self, buf: &mut String) -> ClusterFamily {
+        let family = self.choose_family();
+        match family {
+            ClusterFamily::Latin => self.push_latin(buf),
+            ClusterFamily::SkinToneEmoji => self.push_skin_tone_emoji(buf),
+            ClusterFamily::FamilyEmoji => self.push_family_emoji(buf),
+            ClusterFamily::Flag => buf.push_str(self.choose(FLAGS)),
+            ClusterFamily::DevanagariConjunct => self.push_devanagari_conjunct(buf),
+            ClusterFamily::Hangul => self.push_hangul(buf),
+            ClusterFamily::Thai => self.push_thai(buf),
+        }
+        family
+    }
+
+    /// Returns a string made of the given number of generated grapheme
+    /// clusters.
+    pub fn generate(&mut self, clusters: usize) -> String {
+        let mut buf = String::new();
+        for _ in 0..clusters {
+            self.push_cluster(&mut buf);
+        }
+        buf
+    }
+
+    fn choose_family(&mut self) -> ClusterFamily {
+        let total: u64 = self.weights.iter().map(|&w| u64::from(w)).sum();
+        assert!(total > 0, "all cluster family weights are zero");
+        let mut n = self.below_u64(total);
+        for (family, &weight) in ClusterFamily::ALL.iter().zip(&self.weights) {
+            if n < u64::from(weight) {
+                return *family;
+            }
+            n -= u64::from(weight);
+        }
+        unreachable!()
+    }
+
+    fn push_latin(&mut self, buf: &mut String) {
+        buf.push(self.choose_char(LATIN_LETTERS));
+        for _ in 0..self.below(4).saturating_sub(1) {
+            buf.push(self.choose_char(COMBINING_MARKS));
+        }
+    }
+
+    fn push_skin_tone_emoji(&mut self, buf: &mut String) {
+        let base = self.choose_char(MODIFIER_BASES);
+        buf.push(base);
+        buf.push(self.char_in(0x1F3FB, 5));
+        if PERSON_BASES.contains(base) && self.below(3) == 0 {
+            buf.push('\u{200D}');
+            buf.push(self.choose_char(PROFESSION_OBJECTS));
+        }
+    }
+
+    fn push_family_emoji(&mut self, buf: &mut String) {
+        let adults = self.choose(FAMILY_ADULTS);
+        let children = self.choose(FAMILY_CHILDREN);
+        for (i, c) in adults.chars().chain(children.chars()).enumerate() {
+            if i > 0 {
+                buf.push('\u{200D}');
+            }
+            buf.push(c);
+        }
+    }
+
+    fn push_devanagari_conjunct(&mut self, buf: &mut String) {
+        buf.push(self.char_in(0x0915, 37));
+        for _ in 0..self.below(2) + 1 {
+            buf.push('\u{094D}');
+            buf.push(self.char_in(0x0915, 37));
+        }
+        if self.below(2) == 0 {
+            buf.push(self.choose_char(DEVANAGARI_VOWEL_SIGNS));
+        }
+        if self.below(4) == 0 {
+            buf.push('\u{0902}');
+        }
+    }
+
+    fn push_hangul(&mut self, buf: &mut String) {
+        match self.below(3) {
+            0 => buf.push(self.char_in(0xAC00, 19 * 21 * 28)),
+            1 => {
+                // An LV syllable followed by a trailing consonant jamo.
+                buf.push(char::from_u32(0xAC00 + self.below(19 * 21) * 28).unwrap());
+                buf.push(self.char_in(0x11A8, 27));
+            }
+            _ => {
+                buf.push(self.char_in(0x1100, 19));
+                buf.push(self.char_in(0x1161, 21));
+                if self.below(2) == 0 {
+                    buf.push(self.char_in(0x11A8, 27));
+                }
+            }
+        }
+    }
+
+    fn push_thai(&mut self, buf: &mut String) {
+        buf.push(self.char_in(0x0E01, 46));
+        let vowel = self.below(2) == 0;
+        if vowel {
+            buf.push(self.choose_char(THAI_VOWEL_SIGNS));
+        }
+        if self.below(2) == 0 {
+            buf.push(self.char_in(0x0E48, 4));
+        }
+        if !vowel && self.below(4) == 0 {
+            // SARA AM is a spacing mark, so it extends the cluster too.
+            buf.push('\u{0E33}');
+        }
+    }
+
+    fn choose<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u32) as usize]
+    }
+
+    fn choose_char(&mut self, chars: &str) -> char {
+        // All of the character lists are short, so counting them each time
+        // is cheaper than it might seem.
+        let n = chars.chars().count() as u32;
+        chars.chars().nth(self.below(n) as usize).unwrap()
+    }
+
+    fn char_in(&mut self, first: u32, count: u32) -> char {
+        char::from_u32(first + self.below(count)).unwrap()
+    }
+
+    fn below(&mut self, n: u32) -> u32 {
+        self.below_u64(u64::from(n)) as u32
+    }
+
+    fn below_u64(&mut self, n: u64) -> u64 {
+        // This has a slight bias toward smaller values, which doesn't matter
+        // for generating test data.
+        self.next_u64() % n
+    }
+
+    /// The SplitMix64 generator, which is small and fast and has no
+    /// problematic seeds.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Generates grapheme clusters forever, each as a separate string.
+impl Iterator for TestStrings {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let mut buf = String::new();
+        self.push_cluster(&mut buf);
+        Some(buf)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for TestStrings {}
+
+const LATIN_LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const COMBINING_MARKS: &str = "\u{0300}\u{0301}\u{0302}\u{0303}\u{0308}\u{030A}\u{0327}";
+
+const MODIFIER_BASES: &str =
+    "\u{1F44B}\u{1F44D}\u{1F44F}\u{1F64C}\u{1F4AA}\u{1F9D1}\u{1F469}\u{1F468}";
+const PERSON_BASES: &str = "\u{1F9D1}\u{1F469}\u{1F468}";
+// Laptop, microscope, rocket and fire engine, which all make professions
+// with any of the person bases.
+const PROFESSION_OBJECTS: &str = "\u{1F4BB}\u{1F52C}\u{1F680}\u{1F692}";
+
+// Every combination of these is a recommended family emoji.
+const FAMILY_ADULTS: &[&str] = &[
+    "\u{1F468}\u{1F469}",
+    "\u{1F468}\u{1F468}",
+    "\u{1F469}\u{1F469}",
+    "\u{1F468}",
+    "\u{1F469}",
+];
+const FAMILY_CHILDREN: &[&str] = &[
+    "\u{1F466}",
+    "\u{1F467}",
+    "\u{1F467}\u{1F466}",
+    "\u{1F466}\u{1F466}",
+    "\u{1F467}\u{1F467}",
+];
+
+// Brazil, Canada, Germany, Egypt, France, United Kingdom, India, Japan,
+// South Korea, Mexico, Nigeria, Thailand, Ukraine, United States and
+// South Africa.
+const FLAGS: &[&str] = &[
+    "\u{1F1E7}\u{1F1F7}",
+    "\u{1F1E8}\u{1F1E6}",
+    "\u{1F1E9}\u{1F1EA}",
+    "\u{1F1EA}\u{1F1EC}",
+    "\u{1F1EB}\u{1F1F7}",
+    "\u{1F1EC}\u{1F1E7}",
+    "\u{1F1EE}\u{1F1F3}",
+    "\u{1F1EF}\u{1F1F5}",
+    "\u{1F1F0}\u{1F1F7}",
+    "\u{1F1F2}\u{1F1FD}",
+    "\u{1F1F3}\u{1F1EC}",
+    "\u{1F1F9}\u{1F1ED}",
+    "\u{1F1FA}\u{1F1E6}",
+    "\u{1F1FA}\u{1F1F8}",
+    "\u{1F1FF}\u{1F1E6}",
+];
+
+const DEVANAGARI_VOWEL_SIGNS: &str =
+    "\u{093E}\u{093F}\u{0940}\u{0941}\u{0942}\u{0947}\u{0948}\u{094B}\u{094C}";
+const THAI_VOWEL_SIGNS: &str = "\u{0E31}\u{0E34}\u{0E35}\u{0E36}\u{0E37}\u{0E38}\u{0E39}";
+
+#[cfg(all(test, feature = "machine", feature = "tables"))]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::CharActions;
+
+fn segment(s: &str) -> Vec<String> {
+    CharActions::new(s.chars()).clusters().collect()
+}
+
+#[test]
+fn clusters_segment_as_generated() {
+    let want: Vec<String> = TestStrings::new(1).take(2000).collect();
+    let got = segment(&want.concat());
+    assert_eq!(got, want);
+}
+
+#[test]
+fn each_family_alone() {
+    for family in ClusterFamily::ALL {
+        let mut generator = ClusterFamily::ALL
+            .iter()
+            .fold(TestStrings::new(2), |g, &f| g.with_weight(f, 0))
+            .with_weight(family, 1);
+        let mut buf = String::new();
+        for _ in 0..200 {
+            assert_eq!(generator.push_cluster(&mut buf), family);
+        }
+        assert_eq!(segment(&buf).len(), 200, "{family:?}");
+    }
+}
+
+#[test]
+fn families_are_well_formed() {
+    let mut generator = TestStrings::new(3);
+    for _ in 0..500 {
+        let mut buf = String::new();
+        let family = generator.push_cluster(&mut buf);
+        let first = buf.chars().next().unwrap();
+        let ok = match family {
+            ClusterFamily::Latin => first.is_ascii_alphabetic(),
+            ClusterFamily::SkinToneEmoji => buf
+                .chars()
+                .nth(1)
+                .is_some_and(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)),
+            ClusterFamily::FamilyEmoji => buf.contains('\u{200D}'),
+            ClusterFamily::Flag => buf.chars().count() == 2,
+            ClusterFamily::DevanagariConjunct => buf.contains('\u{094D}'),
+            ClusterFamily::Hangul => {
+                matches!(first, '\u{1100}'..='\u{1112}' | '\u{AC00}'..='\u{D7A3}')
+            }
+            ClusterFamily::Thai => ('\u{0E01}'..='\u{0E2E}').contains(&first),
+        };
+        assert!(ok, "{family:?} cluster {buf:?}");
+    }
+}
+
+#[test]
+fn deterministic() {
+    let a = TestStrings::new(42).generate(100);
+    let b = TestStrings::new(42).generate(100);
+    let c = TestStrings::new(43).generate(100);
+    assert_eq!(a, b);
+    assert!(a != c);
+    assert_eq!(segment(&a).len(), 100);
+}
+
+#[test]
+fn weights() {
+    let mut generator = TestStrings::new(4)
+        .with_weight(ClusterFamily::Flag, 0)
+        .with_weight(ClusterFamily::Hangul, 10);
+    let mut counts = [0; ClusterFamily::ALL.len()];
+    let mut buf = String::new();
+    for _ in 0..1600 {
+        counts[generator.push_cluster(&mut buf) as usize] += 1;
+    }
+    assert_eq!(counts[ClusterFamily::Flag as usize], 0);
+    // Hangul should make up about two thirds of the clusters.
+    assert!(counts[ClusterFamily::Hangul as usize] > 900, "{counts:?}");
+}
+
+#[test]
+#[should_panic(expected = "all cluster family weights are zero")]
+fn all_weights_zero() {
+    let mut generator = ClusterFamily::ALL
+        .iter()
+        .fold(TestStrings::new(5), |g, &f| g.with_weight(f, 0));
+    generator.generate(1);
+}
@@ -16,11 +16,9 @@ fn basics() {
     let input = "Hello!\r\nBeep 🧑‍🌾";
 
     for c in input.u8chars() {
-        if machine.next_u8char(c) == ClusterAction::Split {
-            if !current_cluster.is_empty() {
-                clusters.push(current_cluster.clone());
-                current_cluster.clear();
-            }
+        if machine.next_u8char(c) == ClusterAction::Split && !current_cluster.is_empty() {
+            clusters.push(current_cluster.clone());
+            current_cluster.clear();
         }
         current_cluster.push_str(c.as_str());
     }
@@ -91,3 +89,356 @@ fn next_chars_from_str() {
         ]
     );
 }
+
+#[test]
+fn next_u8chars() {
+    use ::u8char::AsU8Chars;
+    use ClusterAction::*;
+    let mut machine = GraphemeMachine::new();
+    let chars: Vec<_> = "a\r\n🇦🇧".u8chars().collect();
+
+    let mut actions = [Continue; 3];
+    let n = machine.next_u8chars(&chars, &mut actions);
+    assert_eq!(n, 3);
+    assert_eq!(actions, [Split, Split, Continue]);
+
+    let n = machine.next_u8chars(&chars[n..], &mut actions);
+    assert_eq!(n, 2);
+    assert_eq!(actions[..n], [Split, Continue]);
+
+    let n = machine.next_u8chars(&[], &mut actions);
+    assert_eq!(n, 0);
+}
+
+#[test]
+fn next_u8chars_from_utf8_unchecked() {
+    use ClusterAction::*;
+    let mut machine = GraphemeMachine::new();
+    let input = "e\u{0301}🇦🇧".as_bytes();
+    // Safety: input was taken from a str, so it's valid UTF-8.
+    let got: Vec<_> = unsafe { machine.next_u8chars_from_utf8_unchecked(input) }
+        .map(|(a, c)| (a, c.to_char()))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            (Split, 'e'),
+            (Continue, '\u{0301}'),
+            (Split, '\u{1F1E6}'),
+            (Continue, '\u{1F1E7}'),
+        ]
+    );
+}
+
+#[test]
+fn count_splits_in_str() {
+    let inputs = [
+        "",
+        "Hello!\r\nBeep 🧑‍🌾",
+        "plain ASCII text that is long enough to cover a few SIMD blocks",
+        "\u{0600}abc", // U+0600 is Prepend, so combines with the "a"
+        "déjà vu?\n🇦🇧🇨 and more text after the flags",
+        "ab\u{0308}cd",
+    ];
+    for input in inputs {
+        let mut machine = GraphemeMachine::new();
+        let want = machine
+            .next_u8chars_from_str(input)
+            .filter(|(a, _)| *a == ClusterAction::Split)
+            .count();
+        let mut machine = GraphemeMachine::new();
+        let got = machine.count_splits_in_str(input);
+        assert_eq!(got, want, "wrong count for {input:?}");
+    }
+
+    // The machine state must carry over between chunks in the same way
+    // as for the per-character methods.
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(machine.count_splits_in_str("abc\r"), 4);
+    assert_eq!(machine.count_splits_in_str("\ndef\u{0600}"), 4);
+    assert_eq!(machine.count_splits_in_str("ghi"), 2);
+}
+
+#[test]
+fn boundaries_in_chars() {
+    let chars: Vec<char> = "ae\u{0301}🇦🇧🇨\r\n🧑\u{200D}🌾".chars().collect();
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine.boundaries_in_chars(&chars).collect();
+    assert_eq!(got, &[0, 1, 3, 5, 6, 8]);
+
+    // The machine state carries over between slices, so a cluster split
+    // across them has no boundary at the start of the second.
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine.boundaries_in_chars(&chars[..4]).collect();
+    assert_eq!(got, &[0, 1, 3]);
+    let got: Vec<_> = machine.boundaries_in_chars(&chars[4..]).collect();
+    assert_eq!(got, &[1, 2, 4]);
+    assert_eq!(machine.boundaries_in_chars(&[]).count(), 0);
+}
+
+#[test]
+fn next_u8chars_from_str_ascii_runs() {
+    use ::u8char::AsU8Chars;
+    let inputs = [
+        "",
+        "plain ASCII text that is long enough to cover a few SIMD blocks",
+        "ab\u{0308}cd efg\u{0600}hij",
+        "déjà vu?\r\n🇦🇧🇨 and more text after the flags",
+    ];
+    for input in inputs {
+        let mut machine = GraphemeMachine::new();
+        let want: Vec<_> = input.u8chars().map(|c| machine.next_u8char(c)).collect();
+        let mut machine = GraphemeMachine::new();
+        let iter = machine.next_u8chars_from_str(input);
+        let len = input.chars().count();
+        assert_eq!(
+            iter.size_hint(),
+            (len, Some(len)),
+            "size_hint for {input:?}"
+        );
+        let got: Vec<_> = iter.map(|(a, _)| a).collect();
+        assert_eq!(got, want, "wrong actions for {input:?}");
+    }
+
+    // Stopping partway through an ASCII run must leave the machine in the
+    // same state as if it had seen the characters one at a time.
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(machine.next_u8chars_from_str("abc").take(2).count(), 2);
+    assert_eq!(machine.next_char('\u{0308}'), ClusterAction::Continue);
+}
+
+#[test]
+fn next_u8chars_from_utf8() {
+    use ClusterAction::*;
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine
+        .next_u8chars_from_utf8("e\u{0301}x".as_bytes())
+        .unwrap()
+        .map(|(a, c)| (a, c.to_char()))
+        .collect();
+    assert_eq!(got, &[(Split, 'e'), (Continue, '\u{0301}'), (Split, 'x')]);
+
+    // An error leaves the machine untouched, so the combining character
+    // still continues the "x" cluster.
+    let err = machine.next_u8chars_from_utf8(b"a\xc3").err().unwrap();
+    assert_eq!(err.valid_up_to(), 1);
+    assert_eq!(machine.next_char('\u{0301}'), Continue);
+}
+
+#[test]
+fn next_u8chars_from_utf8_lossy() {
+    use ClusterAction::*;
+    let mut machine = GraphemeMachine::new();
+    // "\xf0\x9f\x98" is one maximal subpart, and "\xc0" and "\xaf" are one
+    // each because neither can begin a valid sequence.
+    let got: Vec<_> = machine
+        .next_u8chars_from_utf8_lossy(b"e\xf0\x9f\x98\xcc\x81\xc0\xafx\xe2\x82")
+        .map(|(a, c)| (a, c.to_char()))
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            (Split, 'e'),
+            (Split, '\u{FFFD}'),
+            (Continue, '\u{0301}'),
+            (Split, '\u{FFFD}'),
+            (Split, '\u{FFFD}'),
+            (Split, 'x'),
+            (Split, '\u{FFFD}'),
+        ]
+    );
+
+    let got: Vec<_> = machine
+        .next_u8chars_from_utf8_lossy("e\u{0301}".as_bytes())
+        .map(|(a, _)| a)
+        .collect();
+    assert_eq!(got, &[Split, Continue]);
+}
+
+#[test]
+fn ffi_layouts() {
+    use core::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<ClusterAction>(), 1);
+    assert_eq!(ClusterAction::Continue as u8, 0);
+    assert_eq!(ClusterAction::Split as u8, 1);
+    assert_eq!(
+        (size_of::<CharProperties>(), align_of::<CharProperties>()),
+        (2, 2)
+    );
+    assert_eq!(
+        (size_of::<GraphemeMachine>(), align_of::<GraphemeMachine>()),
+        (4, 2)
+    );
+
+    // Safety: GraphemeMachine is repr(C) with no padding, so all four bytes
+    // are initialized.
+    let machine = GraphemeMachine::new();
+    let bytes: [u8; 4] = unsafe { core::mem::transmute(machine) };
+    assert_eq!(bytes, [0x0f, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn bytes_round_trip() {
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(machine.to_bytes(), [0x0f, 0x00, 0x00, 0x00]);
+    // Save the machine partway through a flag, an emoji ZWJ sequence and
+    // an Indic conjunct, and check that the restored machine continues each
+    // of them just as the original does.
+    for (before, after) in [
+        ("x\u{1F1EF}", "\u{1F1F5}\u{1F1EF}"),
+        ("\u{1F469}\u{1F3FD}\u{200D}", "\u{1F680}a"),
+        ("\u{0915}\u{094D}", "\u{0937}\u{093F}"),
+    ] {
+        machine.end_of_input();
+        before.chars().for_each(|c| {
+            machine.next_char(c);
+        });
+        let bytes = machine.to_bytes();
+        let mut restored = GraphemeMachine::from_bytes(bytes).unwrap();
+        for c in after.chars() {
+            assert_eq!(restored.next_char(c), machine.next_char(c), "{c:?}");
+        }
+    }
+}
+
+#[test]
+fn stable_bytes() {
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(machine.to_stable_bytes(), [0x01, 0x00, 0x0f, 0x00]);
+    // These must never change, because they might have been persisted.
+    machine.next_char('\u{1F1EF}');
+    let bytes = machine.to_stable_bytes();
+    assert_eq!(bytes, [0x01, 0x01, 0x0a, 0x00]);
+
+    let mut restored = GraphemeMachine::from_stable_bytes(bytes).unwrap();
+    assert_eq!(restored.next_char('\u{1F1F5}'), ClusterAction::Continue);
+    assert_eq!(restored.next_char('\u{1F1EF}'), ClusterAction::Split);
+
+    for bytes in [
+        // An unknown version.
+        [0x02, 0x00, 0x0f, 0x00],
+        // An unknown state.
+        [0x01, 6, 0x0f, 0x00],
+        // Properties that don't exist.
+        [0x01, 0x00, 0xff, 0xff],
+    ] {
+        let err = GraphemeMachine::from_stable_bytes(bytes).unwrap_err();
+        assert_eq!(err.bytes(), bytes);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde::Deserialize;
+    use serde::de::value::SeqDeserializer;
+    use serde_test::{Token, assert_de_tokens_error, assert_ser_tokens};
+
+    let mut machine = GraphemeMachine::new();
+    machine.next_char('\u{1F1EF}');
+    let tokens = [
+        Token::Tuple { len: 4 },
+        Token::U8(0x01),
+        Token::U8(0x01),
+        Token::U8(0x0a),
+        Token::U8(0x00),
+        Token::TupleEnd,
+    ];
+    assert_ser_tokens(&machine, &tokens);
+    let bytes =
+        SeqDeserializer::<_, serde::de::value::Error>::new(machine.to_stable_bytes().into_iter());
+    let mut restored = GraphemeMachine::deserialize(bytes).unwrap();
+    assert_eq!(restored.next_char('\u{1F1F5}'), ClusterAction::Continue);
+
+    let invalid = [0x02, 0x00, 0x0f, 0x00];
+    let mut tokens = vec![Token::Tuple { len: 4 }];
+    tokens.extend(invalid.map(Token::U8));
+    tokens.push(Token::TupleEnd);
+    assert_de_tokens_error::<GraphemeMachine>(
+        &tokens,
+        &InvalidMachineError { bytes: invalid }.to_string(),
+    );
+
+    serde_test::assert_tokens(
+        &ClusterAction::Split,
+        &[Token::UnitVariant {
+            name: "ClusterAction",
+            variant: "Split",
+        }],
+    );
+}
+
+#[test]
+fn snapshot_restore() {
+    let mut machine = GraphemeMachine::new();
+    let start = machine.snapshot();
+    assert_eq!(start, GraphemeMachine::new().snapshot());
+    machine.next_char('\u{1F1EF}');
+    let partway = machine.snapshot();
+    assert_ne!(partway, start);
+
+    // The snapshot can be restored any number of times, into any machine.
+    for _ in 0..2 {
+        machine.restore(partway);
+        assert_eq!(machine.next_char('\u{1F1F5}'), ClusterAction::Continue);
+        assert_eq!(machine.next_char('\u{1F1EF}'), ClusterAction::Split);
+    }
+    let mut other = GraphemeMachine::new();
+    other.restore(partway);
+    assert_eq!(other.next_char('\u{1F1F5}'), ClusterAction::Continue);
+    other.restore(start);
+    assert_eq!(other.next_char('\u{0301}'), ClusterAction::Split);
+}
+
+#[test]
+fn peek() {
+    use ClusterAction::*;
+    let mut machine = GraphemeMachine::new();
+    assert_eq!(machine.peek_char('a'), Split);
+    machine.next_char('\u{1F1EF}');
+    // Peeking doesn't advance the machine, so the same character can be
+    // peeked any number of times before it's given.
+    for _ in 0..2 {
+        assert_eq!(machine.peek_char('\u{1F1F5}'), Continue);
+        assert_eq!(machine.peek_u8char(u8char::from_char('a')), Split);
+        assert_eq!(
+            machine.peek_char_properties(CharProperties::for_char('\u{0301}')),
+            Continue
+        );
+    }
+    assert_eq!(machine.next_char('\u{1F1F5}'), Continue);
+    assert_eq!(machine.peek_char('\u{1F1EF}'), Split);
+}
+
+#[test]
+fn invalid_bytes() {
+    let machine = GraphemeMachine::new();
+    let [a, b, _, _] = machine.to_bytes();
+    for bytes in [
+        // An unknown state.
+        [a, b, 6, 0],
+        // A nonzero reserved byte.
+        [a, b, 0, 1],
+        // A state that can't follow the start of input.
+        [a, b, state::State::GB11AfterZWJ as u8, 0],
+        // Properties that don't exist.
+        [0xff, 0xff, 0, 0],
+    ] {
+        let err = GraphemeMachine::from_bytes(bytes).unwrap_err();
+        assert_eq!(err.bytes(), bytes);
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "strict"))]
+#[test]
+#[should_panic(expected = "GraphemeMachine is corrupt")]
+fn corrupt_machine() {
+    // A machine can only get into this situation if its memory is modified
+    // from outside of this library, which we simulate here by writing the
+    // private fields directly.
+    let mut machine = GraphemeMachine::new();
+    machine.next_char('a');
+    machine.state = state::State::GB11AfterZWJ;
+    machine.next_char('\u{1F33E}');
+}
@@ -0,0 +1,396 @@
+//! Helper functions for working with grapheme clusters in strings that are
+//! entirely in memory, as opposed to the streaming use-cases that the state
+//! machine is primarily designed for.
+
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{
+    CharProperties, ClusterAction, ClusterChunks, ClusterWindows, GraphemeMachine, RenderFallback,
+    prev_safe_restart,
+};
+
+/// Returns an iterator over the grapheme clusters in `s`, each as a slice
+/// of `s`.
+///
+/// Unlike the iterator adapters that produce each cluster as a `String`,
+/// this doesn't need to allocate and so is available without the `alloc`
+/// feature.
+///
+/// The iterator is double-ended. Iterating from the back segments only a
+/// little of the string before each cluster it returns, using the same
+/// search as [`cluster_at`], and so it's suitable for walking backwards from
+/// the end of a large buffer, such as when handling backspace in an editor.
+pub fn clusters_in_str(s: &str) -> StrClusters<'_> {
+    StrClusters {
+        machine: GraphemeMachine::new(),
+        s,
+        start: 0,
+        scanned: 0,
+        end: s.len(),
+    }
+}
+
+/// An iterator over the grapheme clusters in a string, each as a slice of
+/// that string.
+///
+/// Construct this using [`clusters_in_str`].
+#[derive(Debug)]
+pub struct StrClusters<'a> {
+    machine: GraphemeMachine,
+    s: &'a str,
+    // The start of the next cluster to return.
+    start: usize,
+    // The end of the characters already given to the machine, which is
+    // after the first character of the next cluster when that character
+    // was what ended the previous one.
+    scanned: usize,
+    // The end of the last cluster not yet returned from the back.
+    end: usize,
+}
+
+impl<'a> StrClusters<'a> {
+    /// Converts this iterator into one that groups the clusters into
+    /// chunks of up to `N` at a time.
+    pub fn chunks<const N: usize>(self) -> ClusterChunks<Self, N> {
+        ClusterChunks::new(self)
+    }
+
+    /// Converts this iterator into one that replaces each cluster for which
+    /// `can_render` returns `false` with the result of `fallback`.
+    ///
+    /// Refer to [`RenderFallback`] for more information.
+    pub fn render_fallback<P, F>(self, can_render: P, fallback: F) -> RenderFallback<Self, P, F>
+    where
+        P: FnMut(&str) -> bool,
+        F: FnMut(&'a str) -> &'a str,
+    {
+        RenderFallback::new(self, can_render, fallback)
+    }
+
+    /// Converts this iterator into one that produces overlapping windows of
+    /// `N` consecutive clusters.
+    ///
+    /// Refer to [`ClusterWindows`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero, because a window must hold at least one
+    /// cluster.
+    pub fn windows<const N: usize>(self) -> ClusterWindows<'a, N> {
+        ClusterWindows::new(self.s, self.start, self)
+    }
+}
+
+impl<'a> Iterator for StrClusters<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.s;
+        if self.start >= self.end {
+            return None;
+        }
+        let mut end = self.scanned;
+        for (action, c) in self
+            .machine
+            .next_u8chars_from_str(&s[self.scanned..self.end])
+        {
+            if action == ClusterAction::Split && end > self.start {
+                let cluster = &s[self.start..end];
+                self.start = end;
+                self.scanned = end + c.len();
+                return Some(cluster);
+            }
+            end += c.len();
+        }
+        let cluster = &s[self.start..self.end];
+        self.start = self.end;
+        self.scanned = self.end;
+        Some(cluster)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each remaining character could begin a new cluster, but all of
+        // them could also belong to just one.
+        let remain = &self.s[self.start.min(self.end)..self.end];
+        (usize::from(!remain.is_empty()), Some(remain.len()))
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrClusters<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.start >= self.end {
+            return None;
+        }
+        // The clusters that have already been returned from the front end
+        // at a boundary, so the cluster containing the last remaining byte
+        // can't begin before self.start.
+        let (range, cluster) = cluster_at(self.s, self.end - 1)?;
+        self.end = range.start;
+        Some(cluster)
+    }
+}
+
+impl<'a> FusedIterator for StrClusters<'a> {}
+
+/// Returns an iterator over the grapheme clusters in `s` along with the
+/// byte offset at which each one begins, like [`clusters_in_str`] but for
+/// callers that need to map clusters back to positions in the string.
+pub fn cluster_indices_in_str(s: &str) -> StrClusterIndices<'_> {
+    StrClusterIndices {
+        clusters: clusters_in_str(s),
+    }
+}
+
+/// An iterator over the grapheme clusters in a string, each as its byte
+/// offset and a slice of that string.
+///
+/// Construct this using [`cluster_indices_in_str`].
+#[derive(Debug)]
+pub struct StrClusterIndices<'a> {
+    clusters: StrClusters<'a>,
+}
+
+impl<'a> StrClusterIndices<'a> {
+    /// Returns the byte offset of the next cluster this iterator will
+    /// return from the front, which is the length of the string once all
+    /// of the clusters have been returned from the front.
+    pub fn offset(&self) -> usize {
+        self.clusters.start
+    }
+}
+
+impl<'a> Iterator for StrClusterIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let offset = self.clusters.start;
+        self.clusters.next().map(|cluster| (offset, cluster))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.clusters.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for StrClusterIndices<'a> {
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        let cluster = self.clusters.next_back()?;
+        Some((self.clusters.end, cluster))
+    }
+}
+
+impl<'a> FusedIterator for StrClusterIndices<'a> {}
+
+/// Returns the byte range and content of the grapheme cluster in `s` that
+/// contains the byte at `offset`, or `None` if `offset` is beyond the last
+/// byte of the string.
+///
+/// `offset` does not need to be on a character boundary. This is useful for
+/// hit-testing, such as when translating the position of a mouse click into
+/// a cursor position, because it finds the whole user-visible character
+/// under the pointer.
+///
+/// This segments only a small part of the string around the given offset,
+/// searching backwards for the nearest position where segmentation can
+/// safely begin.
+pub fn cluster_at(s: &str, offset: usize) -> Option<(Range<usize>, &str)> {
+    if offset >= s.len() {
+        return None;
+    }
+    let mut start = prev_safe_restart(s, offset);
+    let mut machine = GraphemeMachine::new();
+    let mut end = start;
+    for (action, c) in machine.next_u8chars_from_str(&s[start..]) {
+        if action == ClusterAction::Split && end != start {
+            if end > offset {
+                break;
+            }
+            start = end;
+        }
+        end += c.len();
+    }
+    Some((start..end, &s[start..end]))
+}
+
+/// How [`snap_to_boundary`] chooses a boundary for an offset that falls
+/// inside a grapheme cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapPolicy {
+    /// Move to the start of the cluster containing the offset.
+    Left,
+    /// Move to the end of the cluster containing the offset.
+    Right,
+    /// Move to whichever end of the cluster containing the offset is
+    /// fewer bytes away, preferring the start if both are equally far.
+    Nearest,
+}
+
+/// Returns the grapheme cluster boundary in `s` that the given byte offset
+/// should snap to under the given policy.
+///
+/// If `offset` is already at a boundary then it is returned unchanged, and
+/// any offset at or beyond the end of the string snaps to `s.len()`.
+/// Otherwise the result is the start or end of the cluster containing
+/// `offset`, as decided by `policy`. `offset` does not need to be on a
+/// character boundary.
+///
+/// This is useful for turning positions from mouse clicks or from other
+/// programs into valid cursor positions.
+pub fn snap_to_boundary(s: &str, offset: usize, policy: SnapPolicy) -> usize {
+    let Some((range, _)) = cluster_at(s, offset) else {
+        return s.len();
+    };
+    if offset == range.start {
+        return offset;
+    }
+    match policy {
+        SnapPolicy::Left => range.start,
+        SnapPolicy::Right => range.end,
+        SnapPolicy::Nearest if offset - range.start <= range.end - offset => range.start,
+        SnapPolicy::Nearest => range.end,
+    }
+}
+
+/// Returns the largest grapheme cluster boundary in `s` that is no greater
+/// than `max_bytes`, which is `s.len()` if the whole string fits.
+///
+/// `&s[..floor_boundary(s, max_bytes)]` is therefore the longest prefix
+/// of `s` that fits in `max_bytes` bytes without splitting a cluster, for
+/// packing text into a fixed-size database column or a frame with a
+/// limited size. That prefix is empty if the first cluster doesn't fit.
+///
+/// This is the same as [`snap_to_boundary`] with [`SnapPolicy::Left`], and
+/// so it segments only a small part of the string before `max_bytes`.
+pub fn floor_boundary(s: &str, max_bytes: usize) -> usize {
+    snap_to_boundary(s, max_bytes, SnapPolicy::Left)
+}
+
+/// The ways that [`clusters_equal`] can compare two grapheme clusters.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareMode {
+    /// The clusters are equal only if they have exactly the same UTF-8
+    /// bytes.
+    Exact,
+
+    /// The clusters are equal if they are canonically equivalent, which is
+    /// tested by comparing their
+    /// [Normalization Form C](https://www.unicode.org/reports/tr15/#Norm_Forms)
+    /// representations. For example, "é" written as the single character
+    /// U+00E9 is equal to "e" followed by U+0301 COMBINING ACUTE ACCENT.
+    ///
+    /// This is available only when the `normalization` feature is enabled.
+    #[cfg(feature = "normalization")]
+    Nfc,
+}
+
+/// Returns `true` if the two given grapheme clusters are equal under the
+/// given comparison mode.
+///
+/// This is intended for comparing individual clusters, such as when checking
+/// whether a character a user typed matches an expected character, but it
+/// doesn't actually check that each string is a single cluster and so will
+/// also compare longer strings in the same way.
+pub fn clusters_equal(a: &str, b: &str, mode: CompareMode) -> bool {
+    match mode {
+        CompareMode::Exact => a == b,
+        #[cfg(feature = "normalization")]
+        CompareMode::Nfc => {
+            use unicode_normalization::UnicodeNormalization;
+            // Most text is already in NFC, in which case we can avoid
+            // normalizing it.
+            a == b || a.nfc().eq(b.nfc())
+        }
+    }
+}
+
+/// Returns `true` if every character in the given grapheme cluster has the
+/// **Default_Ignorable_Code_Point** property, meaning that the cluster
+/// would normally be displayed as nothing at all.
+///
+/// This is intended for detecting invisible clusters that could be used to
+/// make two different strings look the same, such as a zero width space in
+/// the middle of a user name. Whitespace characters are _not_ default
+/// ignorable, and so a cluster made of a normal space is not reported.
+///
+/// Returns `false` for the empty string, which isn't a cluster at all.
+pub fn is_default_ignorable_cluster(cluster: &str) -> bool {
+    !cluster.is_empty()
+        && cluster
+            .chars()
+            .all(|c| CharProperties::for_char(c).is_default_ignorable())
+}
+
+/// Returns a copy of `s` without the variation selectors that follow other
+/// characters in the same grapheme cluster, or `s` itself if there are
+/// none.
+///
+/// This is intended for search indexing and similar comparisons where the
+/// text and emoji presentations of a symbol, such as "🖤" and "🖤\u{FE0E}",
+/// should match. Each variation selector is removed from the cluster that
+/// it modifies, so the boundaries between the clusters don't change. A
+/// variation selector that begins a cluster of its own isn't modifying
+/// anything, and so it is kept.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn strip_variation_selectors(s: &str) -> alloc::borrow::Cow<'_, str> {
+    use alloc::borrow::Cow;
+
+    let mut ret: Option<alloc::string::String> = None;
+    let mut start = 0;
+    let mut machine = GraphemeMachine::new();
+    for (action, c) in machine.next_u8chars_from_str(s) {
+        let end = start + c.len();
+        let strip = action == ClusterAction::Continue
+            && CharProperties::for_u8char(c).is_variation_selector();
+        match (&mut ret, strip) {
+            (None, true) => ret = Some(s[..start].into()),
+            (Some(ret), false) => ret.push_str(c.as_str()),
+            _ => {}
+        }
+        start = end;
+    }
+    match ret {
+        Some(ret) => Cow::Owned(ret),
+        None => Cow::Borrowed(s),
+    }
+}
+
+/// Returns a copy of `s` with its grapheme clusters in reverse order.
+///
+/// Reversing the characters instead would separate combining marks from
+/// the letters they follow, and would turn a flag into a different flag
+/// or an emoji ZWJ sequence into a jumble of its parts, but this keeps the
+/// characters of each cluster in their original order.
+///
+/// This is available only when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn reverse_graphemes(s: &str) -> alloc::string::String {
+    clusters_in_str(s).rev().collect()
+}
+
+/// Reverses the order of the grapheme clusters in `s` without allocating,
+/// in the same way as `reverse_graphemes`.
+pub fn reverse_graphemes_in_place(s: &mut str) {
+    // Safety: this reverses the bytes of each cluster and then the bytes
+    // of the whole string, which puts the characters of each cluster back
+    // in their original order, so the string is valid UTF-8 again by the
+    // time the borrow ends. Nothing in between can panic.
+    let bytes = unsafe { s.as_bytes_mut() };
+    let mut start = 0;
+    while start < bytes.len() {
+        // Safety: only the clusters before `start` have been reversed so
+        // far, and `start` is at a boundary between clusters.
+        let rest = unsafe { core::str::from_utf8_unchecked(&bytes[start..]) };
+        // Segmentation can always begin again at a boundary.
+        let len = clusters_in_str(rest).next().map_or(rest.len(), str::len);
+        bytes[start..start + len].reverse();
+        start += len;
+    }
+    bytes.reverse();
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,219 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn cluster_at_each_offset() {
+    let s = "a\r\ne\u{0301}🇦🇧🇨";
+    let got: Vec<_> = (0..=s.len()).map(|i| cluster_at(s, i)).collect();
+    assert_eq!(
+        got,
+        &[
+            Some((0..1, "a")),
+            Some((1..3, "\r\n")),
+            Some((1..3, "\r\n")),
+            Some((3..6, "e\u{0301}")),
+            Some((3..6, "e\u{0301}")),
+            Some((3..6, "e\u{0301}")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((6..14, "🇦🇧")),
+            Some((14..18, "🇨")),
+            Some((14..18, "🇨")),
+            Some((14..18, "🇨")),
+            Some((14..18, "🇨")),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn cluster_indices() {
+    let s = "a\r\ne\u{0301}🇦🇧🇨";
+    let mut iter = cluster_indices_in_str(s);
+    assert_eq!(iter.offset(), 0);
+    let got: Vec<_> = iter.by_ref().collect();
+    assert_eq!(
+        got,
+        &[
+            (0, "a"),
+            (1, "\r\n"),
+            (3, "e\u{0301}"),
+            (6, "🇦🇧"),
+            (14, "🇨"),
+        ]
+    );
+    assert_eq!(iter.offset(), s.len());
+    assert_eq!(cluster_indices_in_str("").next(), None);
+}
+
+#[test]
+fn clusters_from_both_ends() {
+    let tests = crate::properties::test_table::UNICODE_GRAPHEME_CLUSTER_TESTS;
+    for test in tests {
+        let s = str::from_utf8(test.input).unwrap();
+        let forward: Vec<_> = clusters_in_str(s).collect();
+        let mut backward: Vec<_> = clusters_in_str(s).rev().collect();
+        backward.reverse();
+        assert_eq!(backward, forward, "{}", test.desc);
+
+        // Alternating between the ends meets in the middle without
+        // skipping or repeating any clusters.
+        let mut iter = clusters_in_str(s);
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        while let Some(cluster) = iter.next() {
+            front.push(cluster);
+            let Some(cluster) = iter.next_back() else {
+                break;
+            };
+            back.push(cluster);
+        }
+        front.extend(back.into_iter().rev());
+        assert_eq!(front, forward, "{}", test.desc);
+    }
+
+    let s = "a\r\ne\u{0301}🇦🇧🇨";
+    let got: Vec<_> = cluster_indices_in_str(s).rev().collect();
+    assert_eq!(
+        got,
+        &[
+            (14, "🇨"),
+            (6, "🇦🇧"),
+            (3, "e\u{0301}"),
+            (1, "\r\n"),
+            (0, "a"),
+        ]
+    );
+}
+
+#[test]
+fn clusters_equal_exact() {
+    assert!(clusters_equal("e\u{0301}", "e\u{0301}", CompareMode::Exact));
+    assert!(!clusters_equal("\u{00E9}", "e\u{0301}", CompareMode::Exact));
+    assert!(!clusters_equal("e", "e\u{0301}", CompareMode::Exact));
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn clusters_equal_nfc() {
+    assert!(clusters_equal("\u{00E9}", "e\u{0301}", CompareMode::Nfc));
+    assert!(clusters_equal("e\u{0301}", "\u{00E9}", CompareMode::Nfc));
+    assert!(!clusters_equal("e", "e\u{0301}", CompareMode::Nfc));
+    // Canonical ordering of combining marks: dot below (ccc 220) and
+    // acute (ccc 230) in either order.
+    assert!(clusters_equal(
+        "e\u{0301}\u{0323}",
+        "e\u{0323}\u{0301}",
+        CompareMode::Nfc
+    ));
+    // Compatibility equivalents are not canonically equivalent.
+    assert!(!clusters_equal("\u{FB01}", "fi", CompareMode::Nfc));
+}
+
+#[test]
+fn snap_to_boundary_policies() {
+    use SnapPolicy::*;
+
+    // "e" + U+0301 is three bytes, and the flag is eight.
+    let s = "ae\u{0301}🇦🇧";
+    let got: Vec<_> = (0..=s.len() + 1)
+        .map(|i| {
+            (
+                snap_to_boundary(s, i, Left),
+                snap_to_boundary(s, i, Right),
+                snap_to_boundary(s, i, Nearest),
+            )
+        })
+        .collect();
+    assert_eq!(
+        got,
+        &[
+            (0, 0, 0),
+            (1, 1, 1),
+            (1, 4, 1),
+            (1, 4, 4),
+            (4, 4, 4),
+            (4, 12, 4),
+            (4, 12, 4),
+            (4, 12, 4),
+            (4, 12, 4),
+            (4, 12, 12),
+            (4, 12, 12),
+            (4, 12, 12),
+            (12, 12, 12),
+            (12, 12, 12),
+        ]
+    );
+}
+
+#[test]
+fn floor_boundary_budgets() {
+    let s = "ae\u{0301}🇦🇧";
+    let got: Vec<_> = (0..=s.len() + 1).map(|i| floor_boundary(s, i)).collect();
+    assert_eq!(got, &[0, 1, 1, 1, 4, 4, 4, 4, 4, 4, 4, 4, 12, 12]);
+    assert_eq!(floor_boundary("", 10), 0);
+}
+
+#[test]
+fn clusters_in_str_slices() {
+    let s = "a\r\ne\u{0301}🇦🇧🇨 ";
+    let got: Vec<_> = clusters_in_str(s).collect();
+    assert_eq!(got, &["a", "\r\n", "e\u{0301}", "🇦🇧", "🇨", " "]);
+    assert_eq!(clusters_in_str("").next(), None);
+    assert_eq!(clusters_in_str("x").size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn default_ignorable_clusters() {
+    let s = "a\u{200B}b\u{200C}\u{3164} \u{E0001}\u{E0041}";
+    let got: Vec<_> = clusters_in_str(s)
+        .filter(|c| is_default_ignorable_cluster(c))
+        .collect();
+    assert_eq!(got, &["\u{200B}", "\u{3164}", "\u{E0001}", "\u{E0041}"]);
+    assert!(!is_default_ignorable_cluster(""));
+    assert!(!is_default_ignorable_cluster("b\u{200C}"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn strip_variation_selectors_per_cluster() {
+    use alloc::borrow::Cow;
+
+    assert!(matches!(
+        strip_variation_selectors("🖤 ok"),
+        Cow::Borrowed("🖤 ok")
+    ));
+    assert_eq!(strip_variation_selectors("🖤\u{FE0E} ❤\u{FE0F}!"), "🖤 ❤!");
+    // The joiner sequence keeps its other characters.
+    assert_eq!(
+        strip_variation_selectors("❤\u{FE0F}\u{200D}🔥"),
+        "❤\u{200D}🔥"
+    );
+    // A variation selector at the start doesn't modify anything.
+    assert_eq!(strip_variation_selectors("\u{FE0F}a\u{FE0F}"), "\u{FE0F}a");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn reverse() {
+    let s = "ae\u{0301}🇦🇧\u{1F468}\u{200D}\u{1F469}\r\n";
+    let want = "\r\n\u{1F468}\u{200D}\u{1F469}🇦🇧e\u{0301}a";
+    assert_eq!(reverse_graphemes(s), want);
+    assert_eq!(reverse_graphemes(""), "");
+}
+
+#[test]
+fn reverse_in_place() {
+    let s = "ae\u{0301}🇦🇧\u{1F468}\u{200D}\u{1F469}\r\n";
+    let mut buf = s.to_string();
+    reverse_graphemes_in_place(&mut buf);
+    assert_eq!(buf, "\r\n\u{1F468}\u{200D}\u{1F469}🇦🇧e\u{0301}a");
+    let mut empty = String::new();
+    reverse_graphemes_in_place(&mut empty);
+    assert_eq!(empty, "");
+}
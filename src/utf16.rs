@@ -0,0 +1,159 @@
+use core::iter::{Flatten, FusedIterator};
+
+use crate::{GraphemeMachine, Segmenter};
+
+/// A wrapper around a [`GraphemeMachine`] (or any other [`Segmenter`]) that
+/// accepts UTF-16 input one code unit at a time, assembling surrogate pairs
+/// into characters.
+///
+/// This is for text that comes from Windows APIs, JavaScript engines, and
+/// other sources whose strings are sequences of UTF-16 code units, without
+/// a separate pass to decode them first.
+///
+/// Such strings can contain unpaired surrogates, which don't represent any
+/// character. This replaces each of them with U+FFFD REPLACEMENT CHARACTER,
+/// just as [`char::decode_utf16`] followed by
+/// [`char::REPLACEMENT_CHARACTER`] would, and the machine then segments the
+/// replacement like any other character. [`Self::unpaired_surrogates`]
+/// counts the replacements, for callers that want to reject such input
+/// instead.
+#[derive(Debug)]
+pub struct Utf16GraphemeMachine<S = GraphemeMachine> {
+    machine: S,
+    // A high surrogate waiting for the low surrogate that should follow it.
+    high: Option<u16>,
+    unpaired: u64,
+}
+
+/// The characters completed by one code unit given to
+/// [`Utf16GraphemeMachine::next_utf16_unit`], along with the machine's
+/// action for each.
+///
+/// This is usually either nothing or one character, but a code unit that
+/// follows an unpaired high surrogate completes both the replacement for
+/// that surrogate and, unless it's a high surrogate itself, its own
+/// character.
+#[derive(Debug)]
+pub struct Utf16Chars<A> {
+    inner: Flatten<core::array::IntoIter<Option<(A, char)>, 2>>,
+}
+
+impl<A> Iterator for Utf16Chars<A> {
+    type Item = (A, char);
+
+    fn next(&mut self) -> Option<(A, char)> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<A> FusedIterator for Utf16Chars<A> {}
+
+impl Utf16GraphemeMachine {
+    /// Constructs a new machine in an initial "start of input" state.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl Default for Utf16GraphemeMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Segmenter> Utf16GraphemeMachine<S> {
+    /// Wraps the given machine, which should be in its "start of input"
+    /// state.
+    pub fn with_machine(machine: S) -> Self {
+        Self {
+            machine,
+            high: None,
+            unpaired: 0,
+        }
+    }
+
+    /// Accepts the next UTF-16 code unit, returning the characters it
+    /// completes along with the machine's action for each.
+    ///
+    /// A high surrogate completes nothing until the next code unit arrives,
+    /// since that unit decides whether the surrogate is paired.
+    pub fn next_utf16_unit(&mut self, unit: u16) -> Utf16Chars<S::Action> {
+        let mut out = [None, None];
+        match (self.high.take(), unit) {
+            (Some(high), 0xdc00..=0xdfff) => {
+                let c = 0x10000 + ((u32::from(high) - 0xd800) << 10) + (u32::from(unit) - 0xdc00);
+                // A surrogate pair always represents a supplementary character.
+                out[0] = Some(self.emit(char::from_u32(c).unwrap()));
+            }
+            (high, _) => {
+                if high.is_some() {
+                    out[0] = Some(self.replace());
+                }
+                match unit {
+                    0xd800..=0xdbff => self.high = Some(unit),
+                    0xdc00..=0xdfff => out[1] = Some(self.replace()),
+                    // Every other code unit is a character by itself.
+                    _ => out[1] = Some(self.emit(char::from_u32(u32::from(unit)).unwrap())),
+                }
+            }
+        }
+        Utf16Chars {
+            inner: out.into_iter().flatten(),
+        }
+    }
+
+    /// Returns an iterator which feeds each code unit of `units` into the
+    /// machine in turn and yields each character it completes, along with
+    /// the machine's action for that character.
+    ///
+    /// As with [`GraphemeMachine::next_u8chars_from_str`], there is no
+    /// automatic call to [`Self::end_of_input`] at the end, so the input can
+    /// be provided in a series of chunks, even with a surrogate pair split
+    /// between two of them.
+    pub fn next_utf16_units<'a>(
+        &'a mut self,
+        units: &'a [u16],
+    ) -> impl FusedIterator<Item = (S::Action, char)> + 'a {
+        units
+            .iter()
+            .flat_map(|&unit| self.next_utf16_unit(unit))
+            .fuse()
+    }
+
+    /// Tells the machine that the input has ended, returning the replacement
+    /// character for a high surrogate left unpaired at the end of the input,
+    /// if any, and then the action to mark the end of the final grapheme
+    /// cluster.
+    pub fn end_of_input(&mut self) -> (Option<(S::Action, char)>, S::Action) {
+        let last = self.high.take().map(|_| self.replace());
+        (last, self.machine.end_of_input())
+    }
+
+    /// Returns the number of unpaired surrogates that have been replaced
+    /// with U+FFFD REPLACEMENT CHARACTER so far.
+    pub fn unpaired_surrogates(&self) -> u64 {
+        self.unpaired
+    }
+
+    /// Consumes the wrapper and returns the machine it was using, discarding
+    /// any high surrogate that was waiting to be paired.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+
+    fn emit(&mut self, c: char) -> (S::Action, char) {
+        (self.machine.next_char(c), c)
+    }
+
+    fn replace(&mut self) -> (S::Action, char) {
+        self.unpaired = self.unpaired.saturating_add(1);
+        self.emit(char::REPLACEMENT_CHARACTER)
+    }
+}
+
+#[cfg(test)]
+mod tests;
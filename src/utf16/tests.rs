@@ -0,0 +1,54 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::ClusterAction::*;
+
+#[test]
+fn surrogate_pairs() {
+    let s = "ae\u{0301}🇦🇧\r\n🧑\u{200D}🌾";
+    let units: Vec<u16> = s.encode_utf16().collect();
+    let mut machine = GraphemeMachine::new();
+    let want: Vec<_> = s.chars().map(|c| (machine.next_char(c), c)).collect();
+    for split in 0..=units.len() {
+        let mut machine = Utf16GraphemeMachine::new();
+        let mut got: Vec<_> = machine.next_utf16_units(&units[..split]).collect();
+        got.extend(machine.next_utf16_units(&units[split..]));
+        assert_eq!(got, want, "split at {split}");
+        assert_eq!(machine.end_of_input(), (None, Split));
+        assert_eq!(machine.unpaired_surrogates(), 0);
+    }
+}
+
+#[test]
+fn unpaired_surrogates() {
+    let mut machine = Utf16GraphemeMachine::new();
+    let units = [
+        0x0061, // a
+        0xdc00, // a lone low surrogate
+        0xd83c, // a high surrogate followed by another
+        0xd83c, 0xdde6, // a regional indicator
+        0xd800, // a high surrogate followed by a combining mark
+        0x0301, 0xd83d, // a high surrogate at the end of the input
+    ];
+    let got: Vec<_> = machine.next_utf16_units(&units).collect();
+    assert_eq!(
+        got,
+        &[
+            (Split, 'a'),
+            (Split, '\u{FFFD}'),
+            (Split, '\u{FFFD}'),
+            (Split, '\u{1F1E6}'),
+            (Split, '\u{FFFD}'),
+            (Continue, '\u{0301}'),
+        ]
+    );
+    assert_eq!(machine.end_of_input(), (Some((Split, '\u{FFFD}')), Split));
+    assert_eq!(machine.unpaired_surrogates(), 4);
+
+    // A code unit after an unpaired high surrogate completes two characters.
+    let mut machine = Utf16GraphemeMachine::new();
+    assert_eq!(machine.next_utf16_unit(0xd800).count(), 0);
+    let got: Vec<_> = machine.next_utf16_unit(0x0062).collect();
+    assert_eq!(got, &[(Split, '\u{FFFD}'), (Split, 'b')]);
+}
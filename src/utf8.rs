@@ -0,0 +1,147 @@
+use core::iter::FusedIterator;
+use u8char::u8char;
+
+use crate::{GraphemeMachine, InvalidUtf8Error, Segmenter};
+
+/// A wrapper around a [`GraphemeMachine`] (or any other [`Segmenter`]) that
+/// accepts UTF-8 input one byte at a time, buffering each partial sequence
+/// until its character is complete.
+///
+/// This is for input that arrives in chunks of arbitrary size, such as
+/// from a network socket, where a chunk can end partway through a
+/// character. The machine carries the partial sequence over to the next
+/// chunk, so that the caller doesn't need to.
+#[derive(Debug)]
+pub struct Utf8GraphemeMachine<S = GraphemeMachine> {
+    machine: S,
+    // The partial UTF-8 sequence so far, whose first byte is at offset
+    // `seq_start`.
+    buf: [u8; 4],
+    len: u8,
+    seq_start: usize,
+    // The offset of the next byte.
+    offset: usize,
+}
+
+impl Utf8GraphemeMachine {
+    /// Constructs a new machine in an initial "start of input" state, at
+    /// offset zero.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl Default for Utf8GraphemeMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Segmenter> Utf8GraphemeMachine<S> {
+    /// Wraps the given machine, which should be in its "start of input"
+    /// state.
+    pub fn with_machine(machine: S) -> Self {
+        Self {
+            machine,
+            buf: [0; 4],
+            len: 0,
+            seq_start: 0,
+            offset: 0,
+        }
+    }
+
+    /// Returns the number of bytes the machine has accepted so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Accepts the next byte of input, returning the character it completes
+    /// and the machine's action for that character, or `Ok(None)` if the
+    /// byte begins or continues a sequence that isn't yet complete.
+    ///
+    /// Returns [`InvalidUtf8Error`] if the byte makes the input invalid,
+    /// with [`InvalidUtf8Error::valid_up_to`] giving the offset where the
+    /// invalid sequence begins. The invalid sequence is then discarded,
+    /// along with the byte itself, and the machine resumes decoding with the
+    /// next byte.
+    pub fn next_byte(&mut self, b: u8) -> Result<Option<(S::Action, u8char)>, InvalidUtf8Error> {
+        if self.len == 0 {
+            self.seq_start = self.offset;
+        }
+        self.offset = self.offset.saturating_add(1);
+        self.buf[self.len as usize] = b;
+        self.len += 1;
+        match core::str::from_utf8(&self.buf[..self.len as usize]) {
+            Ok(s) => {
+                self.len = 0;
+                // from_utf8 succeeded, so there's exactly one character.
+                let c = u8char::from_string_prefix(s).0.unwrap();
+                Ok(Some((self.machine.next_u8char(c), c)))
+            }
+            Err(err) if err.error_len().is_none() => Ok(None),
+            Err(err) => {
+                self.len = 0;
+                Err(InvalidUtf8Error {
+                    valid_up_to: self.seq_start,
+                    error_len: err.error_len(),
+                })
+            }
+        }
+    }
+
+    /// Returns an iterator which feeds each byte of `bytes` into the machine
+    /// in turn and yields each character it completes, along with the
+    /// machine's action for that character.
+    ///
+    /// The iterator yields errors as described for [`Self::next_byte`] and
+    /// then continues decoding after them, so callers who want to stop at
+    /// the first error must do so themselves. As with
+    /// [`GraphemeMachine::next_u8chars_from_str`], there is no automatic
+    /// call to [`Self::end_of_input`] at the end, so the input can be
+    /// provided in a series of chunks.
+    pub fn next_bytes<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> impl FusedIterator<Item = Result<(S::Action, u8char), InvalidUtf8Error>> + 'a {
+        let mut bytes = bytes.iter();
+        core::iter::from_fn(move || {
+            for &b in bytes.by_ref() {
+                match self.next_byte(b) {
+                    Ok(Some(item)) => return Some(Ok(item)),
+                    Ok(None) => {}
+                    Err(err) => return Some(Err(err)),
+                }
+            }
+            None
+        })
+        .fuse()
+    }
+
+    /// Tells the machine that the input has ended, returning the action to
+    /// mark the end of the final grapheme cluster.
+    ///
+    /// Returns an error if the input ended partway through a UTF-8 sequence,
+    /// in which case that incomplete sequence is discarded. Either way the
+    /// machine can then be used for more input, with offsets continuing
+    /// from the end of this input.
+    pub fn end_of_input(&mut self) -> Result<S::Action, InvalidUtf8Error> {
+        let action = self.machine.end_of_input();
+        if self.len == 0 {
+            return Ok(action);
+        }
+        self.len = 0;
+        Err(InvalidUtf8Error {
+            valid_up_to: self.seq_start,
+            error_len: None,
+        })
+    }
+
+    /// Consumes the wrapper and returns the machine it was using, discarding
+    /// any partial UTF-8 sequence.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::ClusterAction::{self, *};
+
+/// Each character and its action, or the offset and length of each error.
+type Fed = Vec<Result<(ClusterAction, String), (usize, Option<usize>)>>;
+
+fn feed(machine: &mut Utf8GraphemeMachine, bytes: &[u8]) -> Fed {
+    machine
+        .next_bytes(bytes)
+        .map(|r| {
+            r.map(|(action, c)| (action, c.as_str().to_string()))
+                .map_err(|err| (err.valid_up_to(), err.error_len()))
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_chunks() {
+    let s = "ae\u{0301}🇦🇧\r\n🧑\u{200D}🌾";
+    let mut want = Vec::new();
+    let mut machine = GraphemeMachine::new();
+    for c in s.chars() {
+        want.push(Ok((machine.next_char(c), c.to_string())));
+    }
+    // Every way of splitting the input into two chunks gives the same
+    // results, as does feeding it one byte at a time.
+    for split in 0..=s.len() {
+        let mut machine = Utf8GraphemeMachine::new();
+        let mut got = feed(&mut machine, &s.as_bytes()[..split]);
+        got.extend(feed(&mut machine, &s.as_bytes()[split..]));
+        assert_eq!(got, want, "split at {split}");
+        assert_eq!(machine.end_of_input(), Ok(Split));
+        assert_eq!(machine.offset(), s.len());
+    }
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut machine = Utf8GraphemeMachine::new();
+    assert_eq!(
+        feed(&mut machine, b"a\xff\xe2\x82b\xe2\x82\xacc\xed\xa0\x80"),
+        &[
+            Ok((Split, "a".to_string())),
+            Err((1, Some(1))),
+            // The "b" that interrupted the sequence is discarded with it.
+            Err((2, Some(2))),
+            Ok((Split, "€".to_string())),
+            Ok((Split, "c".to_string())),
+            // A surrogate is rejected at its second byte.
+            Err((9, Some(1))),
+            Err((11, Some(1))),
+        ]
+    );
+    assert_eq!(feed(&mut machine, b"\xf0\x9f"), &[]);
+    let err = machine.end_of_input().unwrap_err();
+    assert_eq!((err.valid_up_to(), err.error_len()), (12, None));
+    assert_eq!(feed(&mut machine, b"x"), &[Ok((Split, "x".to_string()))]);
+    assert_eq!(machine.end_of_input(), Ok(Split));
+}
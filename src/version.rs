@@ -0,0 +1,45 @@
+use core::fmt;
+
+/// A version of the Unicode Standard, as a major and minor version number.
+///
+/// The **Age** property only ever uses these two parts of the version
+/// number, because update versions don't assign new characters, and the
+/// same is true of the other character properties that this library's
+/// tables hold.
+///
+/// Versions are ordered chronologically, so that a character that needs a
+/// version greater than the one a recipient's platform supports might
+/// not be displayed correctly there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl UnicodeVersion {
+    /// The newest version of the Unicode Standard that this library's
+    /// property tables are based on. No character has an **Age** newer than
+    /// this.
+    pub const CURRENT: Self = Self::new(16, 0);
+
+    /// Constructs a version from its major and minor version numbers.
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Returns the major version number.
+    pub const fn major(self) -> u8 {
+        self.major
+    }
+
+    /// Returns the minor version number.
+    pub const fn minor(self) -> u8 {
+        self.minor
+    }
+}
+
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
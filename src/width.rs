@@ -0,0 +1,244 @@
+//! The display widths of grapheme clusters, for laying out text in a
+//! terminal or any other grid of fixed-width cells.
+
+use ::u8char::AsU8Chars;
+use u8char::u8char;
+
+use crate::{
+    CharProperties, EastAsianWidth, GCBProperty, GraphemeMachine, SegmentAction, Segmenter,
+};
+
+mod table;
+
+impl EastAsianWidth {
+    /// The size in bytes of the lookup table that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`], this table honors the
+    /// `GRAPHEME_MACHINE_LINK_SECTION` environment variable.
+    pub const TABLE_SIZE: usize = table::EAW_RUNS_SIZE;
+
+    /// Returns the **East_Asian_Width** of the given character.
+    ///
+    /// This is a separate table from the one behind [`CharProperties`],
+    /// whose lookup functions don't include this property.
+    ///
+    /// Unassigned characters in the blocks reserved for CJK ideographs are
+    /// [`Self::Wide`], and other unassigned characters are
+    /// [`Self::Neutral`].
+    pub const fn of_char(c: char) -> Self {
+        match table::eaw_lookup(c) {
+            1 => Self::Ambiguous,
+            2 => Self::Halfwidth,
+            3 => Self::Narrow,
+            4 => Self::Wide,
+            5 => Self::Fullwidth,
+            _ => Self::Neutral,
+        }
+    }
+}
+
+/// Returns the number of terminal columns that the given grapheme cluster
+/// occupies, which is zero, one or two.
+///
+/// This combines the **East_Asian_Width** property of the characters with
+/// the conventions that terminal emulators use for emoji:
+///
+/// - A cluster that begins with a control character, such as a line break,
+///   has no width.
+/// - A pair of regional indicators, which is displayed as a flag, is two
+///   columns wide.
+/// - An emoji followed by U+FE0F VARIATION SELECTOR-16, which requests the
+///   emoji presentation, is two columns wide, and one followed by U+FE0E
+///   VARIATION SELECTOR-15, which requests the text presentation, is one.
+/// - Otherwise the cluster is as wide as its widest character, where wide
+///   and fullwidth characters take two columns, combining marks, joiners
+///   and other default-ignorable characters take none, and all others take
+///   one. Ambiguous-width characters are treated as narrow.
+///
+/// That makes, for example, an emoji ZWJ sequence as wide as the emoji it
+/// begins with, and a letter with combining accents as wide as the letter.
+///
+/// This doesn't check that `cluster` is a single grapheme cluster, and so
+/// a longer string is measured as if it were one. Use [`str_width`] to
+/// measure a whole string.
+///
+/// This is available only when the `width` feature is enabled.
+pub fn cluster_width(cluster: &str) -> usize {
+    let mut acc = ClusterWidth::default();
+    for c in cluster.u8chars() {
+        acc.push(c);
+    }
+    acc.width()
+}
+
+/// Returns the total number of terminal columns that the grapheme clusters
+/// in `s` occupy, measuring each one as described for [`cluster_width`].
+///
+/// This is available only when the `width` feature is enabled.
+pub fn str_width(s: &str) -> usize {
+    let mut counter = WidthCounter::new();
+    counter.push_str(s);
+    counter.width() as usize
+}
+
+/// Measures the display width of text that arrives incrementally, without
+/// keeping the text itself.
+///
+/// The width includes the cluster in progress, measured as though it ended
+/// with the text received so far. A later character can still change the
+/// width of that cluster, such as a second regional indicator making a flag
+/// or a variation selector choosing a narrower presentation, and so the
+/// width can decrease as well as increase.
+///
+/// This is available only when the `width` feature is enabled.
+#[derive(Debug)]
+pub struct WidthCounter<S = GraphemeMachine> {
+    machine: S,
+    // The total width of the clusters before the one in progress.
+    complete: u64,
+    current: ClusterWidth,
+}
+
+impl WidthCounter {
+    /// Constructs a new counter in an initial "start of input" state, with
+    /// a width of zero.
+    pub fn new() -> Self {
+        Self::with_machine(GraphemeMachine::new())
+    }
+}
+
+impl Default for WidthCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Segmenter> WidthCounter<S> {
+    /// Wraps the given machine, which should be in its "start of input"
+    /// state.
+    pub fn with_machine(machine: S) -> Self {
+        Self {
+            machine,
+            complete: 0,
+            current: ClusterWidth::default(),
+        }
+    }
+
+    /// Returns the total width of the text seen so far, including the
+    /// cluster in progress.
+    pub fn width(&self) -> u64 {
+        self.complete.saturating_add(self.current.width() as u64)
+    }
+
+    /// Measures the next character of input, returning `true` if it began a
+    /// new grapheme cluster.
+    pub fn push_u8char(&mut self, c: u8char) -> bool {
+        let boundary = self.machine.next_u8char(c).is_boundary();
+        if boundary {
+            self.finish_cluster();
+        }
+        self.current.push(c);
+        boundary
+    }
+
+    /// Measures the next character of input, returning `true` if it began a
+    /// new grapheme cluster.
+    pub fn push_char(&mut self, c: char) -> bool {
+        self.push_u8char(u8char::from_char(c))
+    }
+
+    /// Measures the characters in the next chunk of input, returning the
+    /// updated width.
+    ///
+    /// A grapheme cluster can straddle chunks, in which case it's measured
+    /// as a whole.
+    pub fn push_str(&mut self, s: &str) -> u64 {
+        for c in s.u8chars() {
+            self.push_u8char(c);
+        }
+        self.width()
+    }
+
+    /// Tells the counter that the input has ended, so that the next
+    /// character always begins a new cluster. The width is not reset.
+    pub fn end_of_input(&mut self) {
+        self.machine.end_of_input();
+        self.finish_cluster();
+    }
+
+    /// Consumes the counter and returns the machine it was using.
+    pub fn into_machine(self) -> S {
+        self.machine
+    }
+
+    fn finish_cluster(&mut self) {
+        let width = core::mem::take(&mut self.current).width();
+        self.complete = self.complete.saturating_add(width as u64);
+    }
+}
+
+/// Accumulates what [`cluster_width`] needs to know about the characters of
+/// a cluster, one character at a time.
+#[derive(Debug, Clone, Copy, Default)]
+struct ClusterWidth {
+    started: bool,
+    control: bool,
+    // Whether the first character can take a variation selector choosing an
+    // emoji or text presentation.
+    emoji_base: bool,
+    regional_indicators: u8,
+    presentation: Option<u8>,
+    widest: u8,
+}
+
+impl ClusterWidth {
+    fn push(&mut self, c: u8char) {
+        let props = CharProperties::for_u8char(c);
+        let gcb = props.gcb_property();
+        if !self.started {
+            self.started = true;
+            self.control = props.is_any_control();
+            self.emoji_base = matches!(gcb, GCBProperty::ExtendedPictographic)
+                || matches!(c.to_char(), '#' | '*' | '0'..='9');
+        }
+        match c.to_char() {
+            '\u{FE0F}' if self.emoji_base => self.presentation = Some(2),
+            '\u{FE0E}' if self.emoji_base => self.presentation = Some(1),
+            _ => {}
+        }
+        if matches!(gcb, GCBProperty::RegionalIndicator) {
+            self.regional_indicators = self.regional_indicators.saturating_add(1);
+        }
+        self.widest = self.widest.max(char_width(c, props));
+    }
+
+    fn width(&self) -> usize {
+        let width = if self.control {
+            0
+        } else if self.regional_indicators >= 2 {
+            2
+        } else {
+            self.presentation.unwrap_or(self.widest)
+        };
+        width as usize
+    }
+}
+
+/// Returns the width of a single character in isolation, ignoring the
+/// emoji conventions that depend on the rest of its cluster.
+fn char_width(c: u8char, props: CharProperties) -> u8 {
+    use GCBProperty::*;
+    if matches!(props.gcb_property(), Extend | ZWJ | Control | CR | LF)
+        || props.is_default_ignorable()
+    {
+        return 0;
+    }
+    if EastAsianWidth::of_char(c.to_char()).is_wide() {
+        2
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+/// Returns the encoded **East_Asian_Width** of the given character, as a
+/// discriminant of [`EastAsianWidth`](super::EastAsianWidth).
+///
+/// [`EAW_RUNS`] is sorted by code point, so this is a binary search for the
+/// last run beginning at or before `c`.
+pub const fn eaw_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, EAW_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if EAW_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    EAW_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`EAW_RUNS`].
+pub const EAW_RUNS_SIZE: usize = EAW_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static EAW_RUNS: [u32; 600] = [
+    0x00000000, 0x00002003, 0x00007f00, 0x0000a101, 0x0000a203, 0x0000a401, 0x0000a503, 0x0000a701,
+    0x0000a900, 0x0000aa01, 0x0000ab00, 0x0000ac03, 0x0000ad01, 0x0000af03, 0x0000b001, 0x0000b500,
+    0x0000b601, 0x0000bb00, 0x0000bc01, 0x0000c000, 0x0000c601, 0x0000c700, 0x0000d001, 0x0000d100,
+    0x0000d701, 0x0000d900, 0x0000de01, 0x0000e200, 0x0000e601, 0x0000e700, 0x0000e801, 0x0000eb00,
+    0x0000ec01, 0x0000ee00, 0x0000f001, 0x0000f100, 0x0000f201, 0x0000f400, 0x0000f701, 0x0000fb00,
+    0x0000fc01, 0x0000fd00, 0x0000fe01, 0x0000ff00, 0x00010101, 0x00010200, 0x00011101, 0x00011200,
+    0x00011301, 0x00011400, 0x00011b01, 0x00011c00, 0x00012601, 0x00012800, 0x00012b01, 0x00012c00,
+    0x00013101, 0x00013400, 0x00013801, 0x00013900, 0x00013f01, 0x00014300, 0x00014401, 0x00014500,
+    0x00014801, 0x00014c00, 0x00014d01, 0x00014e00, 0x00015201, 0x00015400, 0x00016601, 0x00016800,
+    0x00016b01, 0x00016c00, 0x0001ce01, 0x0001cf00, 0x0001d001, 0x0001d100, 0x0001d201, 0x0001d300,
+    0x0001d401, 0x0001d500, 0x0001d601, 0x0001d700, 0x0001d801, 0x0001d900, 0x0001da01, 0x0001db00,
+    0x0001dc01, 0x0001dd00, 0x00025101, 0x00025200, 0x00026101, 0x00026200, 0x0002c401, 0x0002c500,
+    0x0002c701, 0x0002c800, 0x0002c901, 0x0002cc00, 0x0002cd01, 0x0002ce00, 0x0002d001, 0x0002d100,
+    0x0002d801, 0x0002dc00, 0x0002dd01, 0x0002de00, 0x0002df01, 0x0002e000, 0x00030001, 0x00037000,
+    0x00039101, 0x0003a200, 0x0003a301, 0x0003aa00, 0x0003b101, 0x0003c200, 0x0003c301, 0x0003ca00,
+    0x00040101, 0x00040200, 0x00041001, 0x00045000, 0x00045101, 0x00045200, 0x00110004, 0x00116000,
+    0x00201001, 0x00201100, 0x00201301, 0x00201700, 0x00201801, 0x00201a00, 0x00201c01, 0x00201e00,
+    0x00202001, 0x00202300, 0x00202401, 0x00202800, 0x00203001, 0x00203100, 0x00203201, 0x00203400,
+    0x00203501, 0x00203600, 0x00203b01, 0x00203c00, 0x00203e01, 0x00203f00, 0x00207401, 0x00207500,
+    0x00207f01, 0x00208000, 0x00208101, 0x00208500, 0x0020a902, 0x0020aa00, 0x0020ac01, 0x0020ad00,
+    0x00210301, 0x00210400, 0x00210501, 0x00210600, 0x00210901, 0x00210a00, 0x00211301, 0x00211400,
+    0x00211601, 0x00211700, 0x00212101, 0x00212300, 0x00212601, 0x00212700, 0x00212b01, 0x00212c00,
+    0x00215301, 0x00215500, 0x00215b01, 0x00215f00, 0x00216001, 0x00216c00, 0x00217001, 0x00217a00,
+    0x00218901, 0x00218a00, 0x00219001, 0x00219a00, 0x0021b801, 0x0021ba00, 0x0021d201, 0x0021d300,
+    0x0021d401, 0x0021d500, 0x0021e701, 0x0021e800, 0x00220001, 0x00220100, 0x00220201, 0x00220400,
+    0x00220701, 0x00220900, 0x00220b01, 0x00220c00, 0x00220f01, 0x00221000, 0x00221101, 0x00221200,
+    0x00221501, 0x00221600, 0x00221a01, 0x00221b00, 0x00221d01, 0x00222100, 0x00222301, 0x00222400,
+    0x00222501, 0x00222600, 0x00222701, 0x00222d00, 0x00222e01, 0x00222f00, 0x00223401, 0x00223800,
+    0x00223c01, 0x00223e00, 0x00224801, 0x00224900, 0x00224c01, 0x00224d00, 0x00225201, 0x00225300,
+    0x00226001, 0x00226200, 0x00226401, 0x00226800, 0x00226a01, 0x00226c00, 0x00226e01, 0x00227000,
+    0x00228201, 0x00228400, 0x00228601, 0x00228800, 0x00229501, 0x00229600, 0x00229901, 0x00229a00,
+    0x0022a501, 0x0022a600, 0x0022bf01, 0x0022c000, 0x00231201, 0x00231300, 0x00231a04, 0x00231c00,
+    0x00232904, 0x00232b00, 0x0023e904, 0x0023ed00, 0x0023f004, 0x0023f100, 0x0023f304, 0x0023f400,
+    0x00246001, 0x0024ea00, 0x0024eb01, 0x00254c00, 0x00255001, 0x00257400, 0x00258001, 0x00259000,
+    0x00259201, 0x00259600, 0x0025a001, 0x0025a200, 0x0025a301, 0x0025aa00, 0x0025b201, 0x0025b400,
+    0x0025b601, 0x0025b800, 0x0025bc01, 0x0025be00, 0x0025c001, 0x0025c200, 0x0025c601, 0x0025c900,
+    0x0025cb01, 0x0025cc00, 0x0025ce01, 0x0025d200, 0x0025e201, 0x0025e600, 0x0025ef01, 0x0025f000,
+    0x0025fd04, 0x0025ff00, 0x00260501, 0x00260700, 0x00260901, 0x00260a00, 0x00260e01, 0x00261000,
+    0x00261404, 0x00261600, 0x00261c01, 0x00261d00, 0x00261e01, 0x00261f00, 0x00263004, 0x00263800,
+    0x00264001, 0x00264100, 0x00264201, 0x00264300, 0x00264804, 0x00265400, 0x00266001, 0x00266200,
+    0x00266301, 0x00266600, 0x00266701, 0x00266b00, 0x00266c01, 0x00266e00, 0x00266f01, 0x00267000,
+    0x00267f04, 0x00268000, 0x00268a04, 0x00269000, 0x00269304, 0x00269400, 0x00269e01, 0x0026a000,
+    0x0026a104, 0x0026a200, 0x0026aa04, 0x0026ac00, 0x0026bd04, 0x0026bf01, 0x0026c000, 0x0026c404,
+    0x0026c601, 0x0026ce04, 0x0026cf01, 0x0026d404, 0x0026d501, 0x0026e200, 0x0026e301, 0x0026e400,
+    0x0026e801, 0x0026ea04, 0x0026eb01, 0x0026f204, 0x0026f401, 0x0026f504, 0x0026f601, 0x0026fa04,
+    0x0026fb01, 0x0026fd04, 0x0026fe01, 0x00270000, 0x00270504, 0x00270600, 0x00270a04, 0x00270c00,
+    0x00272804, 0x00272900, 0x00273d01, 0x00273e00, 0x00274c04, 0x00274d00, 0x00274e04, 0x00274f00,
+    0x00275304, 0x00275600, 0x00275704, 0x00275800, 0x00277601, 0x00278000, 0x00279504, 0x00279800,
+    0x0027b004, 0x0027b100, 0x0027bf04, 0x0027c000, 0x0027e603, 0x0027ee00, 0x00298503, 0x00298700,
+    0x002b1b04, 0x002b1d00, 0x002b5004, 0x002b5100, 0x002b5504, 0x002b5601, 0x002b5a00, 0x002e8004,
+    0x002e9a00, 0x002e9b04, 0x002ef400, 0x002f0004, 0x002fd600, 0x002ff004, 0x00300005, 0x00300104,
+    0x00303f00, 0x00304104, 0x00309700, 0x00309904, 0x00310000, 0x00310504, 0x00313000, 0x00313104,
+    0x00318f00, 0x00319004, 0x0031e600, 0x0031ef04, 0x00321f00, 0x00322004, 0x00324801, 0x00325004,
+    0x00a48d00, 0x00a49004, 0x00a4c700, 0x00a96004, 0x00a97d00, 0x00ac0004, 0x00d7a400, 0x00e00001,
+    0x00f90004, 0x00fb0000, 0x00fe0001, 0x00fe1004, 0x00fe1a00, 0x00fe3004, 0x00fe5300, 0x00fe5404,
+    0x00fe6700, 0x00fe6804, 0x00fe6c00, 0x00ff0105, 0x00ff6102, 0x00ffbf00, 0x00ffc202, 0x00ffc800,
+    0x00ffca02, 0x00ffd000, 0x00ffd202, 0x00ffd800, 0x00ffda02, 0x00ffdd00, 0x00ffe005, 0x00ffe700,
+    0x00ffe802, 0x00ffef00, 0x00fffd01, 0x00fffe00, 0x016fe004, 0x016fe500, 0x016ff004, 0x016ff200,
+    0x01700004, 0x0187f800, 0x01880004, 0x018cd600, 0x018cff04, 0x018d0900, 0x01aff004, 0x01aff400,
+    0x01aff504, 0x01affc00, 0x01affd04, 0x01afff00, 0x01b00004, 0x01b12300, 0x01b13204, 0x01b13300,
+    0x01b15004, 0x01b15300, 0x01b15504, 0x01b15600, 0x01b16404, 0x01b16800, 0x01b17004, 0x01b2fc00,
+    0x01d30004, 0x01d35700, 0x01d36004, 0x01d37700, 0x01f00404, 0x01f00500, 0x01f0cf04, 0x01f0d000,
+    0x01f10001, 0x01f10b00, 0x01f11001, 0x01f12e00, 0x01f13001, 0x01f16a00, 0x01f17001, 0x01f18e04,
+    0x01f18f01, 0x01f19104, 0x01f19b01, 0x01f1ad00, 0x01f20004, 0x01f20300, 0x01f21004, 0x01f23c00,
+    0x01f24004, 0x01f24900, 0x01f25004, 0x01f25200, 0x01f26004, 0x01f26600, 0x01f30004, 0x01f32100,
+    0x01f32d04, 0x01f33600, 0x01f33704, 0x01f37d00, 0x01f37e04, 0x01f39400, 0x01f3a004, 0x01f3cb00,
+    0x01f3cf04, 0x01f3d400, 0x01f3e004, 0x01f3f100, 0x01f3f404, 0x01f3f500, 0x01f3f804, 0x01f43f00,
+    0x01f44004, 0x01f44100, 0x01f44204, 0x01f4fd00, 0x01f4ff04, 0x01f53e00, 0x01f54b04, 0x01f54f00,
+    0x01f55004, 0x01f56800, 0x01f57a04, 0x01f57b00, 0x01f59504, 0x01f59700, 0x01f5a404, 0x01f5a500,
+    0x01f5fb04, 0x01f65000, 0x01f68004, 0x01f6c600, 0x01f6cc04, 0x01f6cd00, 0x01f6d004, 0x01f6d300,
+    0x01f6d504, 0x01f6d800, 0x01f6dc04, 0x01f6e000, 0x01f6eb04, 0x01f6ed00, 0x01f6f404, 0x01f6fd00,
+    0x01f7e004, 0x01f7ec00, 0x01f7f004, 0x01f7f100, 0x01f90c04, 0x01f93b00, 0x01f93c04, 0x01f94600,
+    0x01f94704, 0x01fa0000, 0x01fa7004, 0x01fa7d00, 0x01fa8004, 0x01fa8a00, 0x01fa8f04, 0x01fac700,
+    0x01face04, 0x01fadd00, 0x01fadf04, 0x01faea00, 0x01faf004, 0x01faf900, 0x02000004, 0x02fffe00,
+    0x03000004, 0x03fffe00, 0x0e010001, 0x0e01f000, 0x0f000001, 0x0ffffe00, 0x10000001, 0x10fffe00,
+];
@@ -0,0 +1,74 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn east_asian_width() {
+    let cases = [
+        ('a', EastAsianWidth::Narrow),
+        ('\u{00A1}', EastAsianWidth::Ambiguous),
+        ('\u{0301}', EastAsianWidth::Ambiguous),
+        ('\u{05D0}', EastAsianWidth::Neutral),
+        ('\u{FF71}', EastAsianWidth::Halfwidth),
+        ('中', EastAsianWidth::Wide),
+        ('\u{3FFFD}', EastAsianWidth::Wide),
+        ('\u{FF21}', EastAsianWidth::Fullwidth),
+        ('\u{1F600}', EastAsianWidth::Wide),
+        ('\u{E0000}', EastAsianWidth::Neutral),
+    ];
+    for (c, want) in cases {
+        assert_eq!(EastAsianWidth::of_char(c), want, "width of {c:?}");
+    }
+}
+
+#[test]
+fn cluster_widths() {
+    let cases = [
+        ("", 0),
+        ("a", 1),
+        ("e\u{0301}", 1),
+        ("\u{0301}", 0),
+        ("\r\n", 0),
+        ("\t", 0),
+        ("中", 2),
+        ("\u{FF21}", 2),
+        ("\u{FF71}", 1),
+        ("\u{00A1}", 1),
+        ("\u{1100}\u{1161}\u{11A8}", 2),
+        ("\u{1F1E6}\u{1F1E8}", 2),
+        ("\u{1F1E6}", 1),
+        ("\u{1F600}", 2),
+        ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 2),
+        ("\u{1F44D}\u{1F3FD}", 2),
+        ("\u{2764}", 1),
+        ("\u{2764}\u{FE0F}", 2),
+        ("\u{231A}\u{FE0E}", 1),
+        ("1\u{FE0F}\u{20E3}", 2),
+        ("a\u{FE0F}", 1),
+        ("\u{200B}", 0),
+    ];
+    for (cluster, want) in cases {
+        assert_eq!(cluster_width(cluster), want, "width of {cluster:?}");
+    }
+}
+
+#[test]
+fn str_widths() {
+    assert_eq!(str_width(""), 0);
+    assert_eq!(str_width("ab中\r\n\u{1F1E6}\u{1F1E8}e\u{0301}"), 7);
+}
+
+#[test]
+fn counter_across_chunks() {
+    let mut counter = WidthCounter::new();
+    assert_eq!(counter.push_str("a\u{1F1E6}"), 2);
+    // The second regional indicator makes a flag with the first.
+    assert_eq!(counter.push_str("\u{1F1E8}"), 3);
+    assert_eq!(counter.push_str("\u{231A}"), 5);
+    // Text presentation makes the watch narrower.
+    assert!(!counter.push_char('\u{FE0E}'));
+    assert_eq!(counter.width(), 4);
+    counter.end_of_input();
+    assert!(counter.push_char('\u{0301}'));
+    assert_eq!(counter.width(), 4);
+}
@@ -0,0 +1,179 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{StrClusters, clusters_in_str};
+
+/// An iterator over overlapping windows of `N` consecutive grapheme
+/// clusters in a string, for n-gram analysis and fuzzy matching.
+///
+/// Each window is a [`ClusterWindow`], which can be used either as a single
+/// slice covering all `N` clusters or as the ranges of the individual
+/// clusters. The iterator tracks only the offsets of the most recent `N`
+/// clusters, and so doesn't allocate.
+///
+/// As with [`slice::windows`], a string with fewer than `N` clusters has
+/// no windows at all.
+///
+/// Construct this using [`StrClusters::windows`].
+#[derive(Debug)]
+pub struct ClusterWindows<'a, const N: usize> {
+    s: &'a str,
+    inner: StrClusters<'a>,
+    starts: [usize; N],
+    filled: usize,
+    end: usize,
+}
+
+impl<'a, const N: usize> ClusterWindows<'a, N> {
+    /// Wraps an iterator over the clusters of `s` whose next cluster begins
+    /// at offset `start`.
+    pub(crate) fn new(s: &'a str, start: usize, inner: StrClusters<'a>) -> Self {
+        assert!(N > 0, "window size must be at least one");
+        Self {
+            s,
+            inner,
+            starts: [start; N],
+            filled: 0,
+            end: start,
+        }
+    }
+}
+
+impl<'a, const N: usize> Iterator for ClusterWindows<'a, N> {
+    type Item = ClusterWindow<'a, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let cluster = self.inner.next()?;
+            if self.filled == N {
+                self.starts.copy_within(1.., 0);
+                self.starts[N - 1] = self.end;
+            } else {
+                self.starts[self.filled] = self.end;
+                self.filled += 1;
+            }
+            self.end += cluster.len();
+            if self.filled == N {
+                return Some(ClusterWindow {
+                    s: self.s,
+                    starts: self.starts,
+                    end: self.end,
+                });
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The first window needs enough clusters to fill it, and then every
+        // cluster after that produces one more.
+        let skip = (N - self.filled).saturating_sub(1);
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_sub(skip),
+            upper.map(|n| n.saturating_sub(skip)),
+        )
+    }
+}
+
+impl<'a, const N: usize> FusedIterator for ClusterWindows<'a, N> {}
+
+/// A window of `N` consecutive grapheme clusters produced by
+/// [`ClusterWindows`].
+///
+/// All of the offsets are relative to the start of the string that the
+/// clusters came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClusterWindow<'a, const N: usize> {
+    s: &'a str,
+    starts: [usize; N],
+    end: usize,
+}
+
+impl<'a, const N: usize> ClusterWindow<'a, N> {
+    /// Returns the byte range covering all of the clusters in the window.
+    pub fn range(&self) -> Range<usize> {
+        self.starts[0]..self.end
+    }
+
+    /// Returns a slice covering all of the clusters in the window.
+    pub fn as_str(&self) -> &'a str {
+        &self.s[self.range()]
+    }
+
+    /// Returns the byte ranges of each of the clusters in the window.
+    pub fn cluster_ranges(&self) -> [Range<usize>; N] {
+        core::array::from_fn(|i| {
+            self.starts[i]..self.starts.get(i + 1).copied().unwrap_or(self.end)
+        })
+    }
+
+    /// Returns each of the clusters in the window.
+    pub fn clusters(&self) -> [&'a str; N] {
+        let s = self.s;
+        self.cluster_ranges().map(|range| &s[range])
+    }
+}
+
+/// Returns an iterator over overlapping windows of `n` consecutive grapheme
+/// clusters in `s`, each as a slice of `s`.
+///
+/// This is like [`StrClusters::windows`] for callers that choose the window
+/// size at runtime, such as when building n-grams of a configurable size.
+/// It doesn't allocate either, but it finds each boundary twice: once for
+/// the end of a window and then again for the start of a later one.
+///
+/// As with [`slice::windows`], a string with fewer than `n` clusters has
+/// no windows at all.
+///
+/// # Panics
+///
+/// Panics if `n` is zero, because a window must hold at least one cluster.
+pub fn cluster_windows(s: &str, n: usize) -> StrClusterWindows<'_> {
+    assert!(n > 0, "window size must be at least one");
+    let mut lead = clusters_in_str(s);
+    let end = lead.by_ref().take(n - 1).map(str::len).sum();
+    StrClusterWindows {
+        s,
+        lead,
+        trail: clusters_in_str(s),
+        start: 0,
+        end,
+    }
+}
+
+/// An iterator over overlapping windows of some number of consecutive
+/// grapheme clusters in a string, each as a slice of that string.
+///
+/// Construct this using [`cluster_windows`].
+#[derive(Debug)]
+pub struct StrClusterWindows<'a> {
+    s: &'a str,
+    // The clusters after the end of the next window.
+    lead: StrClusters<'a>,
+    // The clusters from the start of the next window.
+    trail: StrClusters<'a>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for StrClusterWindows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.end += self.lead.next()?.len();
+        let window = &self.s[self.start..self.end];
+        self.start += self.trail.next().map_or(0, str::len);
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each remaining cluster after the first window is filled completes
+        // one more window.
+        self.lead.size_hint()
+    }
+}
+
+impl<'a> FusedIterator for StrClusterWindows<'a> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,75 @@
+use super::*;
+use crate::clusters_in_str;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn bigrams() {
+    let s = "ae\u{0301}🇦🇧b";
+    let got: Vec<_> = clusters_in_str(s)
+        .windows::<2>()
+        .map(|w| w.as_str())
+        .collect();
+    assert_eq!(got, &["ae\u{0301}", "e\u{0301}🇦🇧", "🇦🇧b"]);
+
+    let windows: Vec<ClusterWindow<'_, 2>> = clusters_in_str(s).windows().collect();
+    assert_eq!(windows[1].range(), 1..12);
+    assert_eq!(windows[1].cluster_ranges(), [1..4, 4..12]);
+    assert_eq!(windows[1].clusters(), ["e\u{0301}", "🇦🇧"]);
+}
+
+#[test]
+fn too_few_clusters() {
+    assert_eq!(clusters_in_str("ab").windows::<3>().count(), 0);
+    assert_eq!(clusters_in_str("").windows::<1>().count(), 0);
+    assert_eq!(clusters_in_str("abc").windows::<3>().count(), 1);
+}
+
+#[test]
+fn single_cluster_windows() {
+    let got: Vec<_> = clusters_in_str("a\r\nb")
+        .windows::<1>()
+        .map(|w| w.as_str())
+        .collect();
+    assert_eq!(got, &["a", "\r\n", "b"]);
+}
+
+#[test]
+fn partially_consumed() {
+    // Offsets remain relative to the whole string.
+    let mut clusters = clusters_in_str("xyz");
+    clusters.next();
+    let got: Vec<_> = clusters.windows::<2>().map(|w| w.range()).collect();
+    assert_eq!(got, vec![1..3]);
+}
+
+#[test]
+fn size_hint() {
+    let windows = clusters_in_str("abcd").windows::<3>();
+    assert_eq!(windows.size_hint(), (0, Some(2)));
+}
+
+#[test]
+#[should_panic(expected = "window size must be at least one")]
+fn zero_size() {
+    clusters_in_str("abc").windows::<0>();
+}
+
+#[test]
+fn runtime_size() {
+    let s = "ae\u{0301}🇦🇧b";
+    let got: Vec<_> = cluster_windows(s, 2).collect();
+    assert_eq!(got, &["ae\u{0301}", "e\u{0301}🇦🇧", "🇦🇧b"]);
+    let got: Vec<_> = cluster_windows(s, 4).collect();
+    assert_eq!(got, &[s]);
+    assert_eq!(cluster_windows(s, 5).count(), 0);
+    assert_eq!(cluster_windows("", 1).count(), 0);
+    let got: Vec<_> = cluster_windows("a\r\nb", 1).collect();
+    assert_eq!(got, &["a", "\r\n", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "window size must be at least one")]
+fn runtime_zero_size() {
+    cluster_windows("abc", 0);
+}
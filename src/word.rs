@@ -0,0 +1,466 @@
+//! Word boundaries from
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries), found by
+//! a state machine that accepts one character at a time in the same way as
+//! `GraphemeMachine`.
+
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+use crate::{CharProperties, GCBProperty};
+
+mod table;
+
+/// Enumeration of **Word_Break** property values, from
+/// [UAX #29 Section 4.1](https://www.unicode.org/reports/tr29/#Word_Boundary_Rules).
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WBProperty {
+    /// Represents that none of the other word break property values apply
+    /// to a particular character, which UAX #29 calls **Other**.
+    None = 0,
+    CR = 1,
+    LF = 2,
+    Newline = 3,
+    Extend = 4,
+    ZWJ = 5,
+    RegionalIndicator = 6,
+    Format = 7,
+    Katakana = 8,
+    HebrewLetter = 9,
+    ALetter = 10,
+    SingleQuote = 11,
+    DoubleQuote = 12,
+    MidNumLet = 13,
+    MidLetter = 14,
+    MidNum = 15,
+    Numeric = 16,
+    ExtendNumLet = 17,
+    WSegSpace = 18,
+}
+
+impl WBProperty {
+    const ALL: [Self; 19] = [
+        Self::None,
+        Self::CR,
+        Self::LF,
+        Self::Newline,
+        Self::Extend,
+        Self::ZWJ,
+        Self::RegionalIndicator,
+        Self::Format,
+        Self::Katakana,
+        Self::HebrewLetter,
+        Self::ALetter,
+        Self::SingleQuote,
+        Self::DoubleQuote,
+        Self::MidNumLet,
+        Self::MidLetter,
+        Self::MidNum,
+        Self::Numeric,
+        Self::ExtendNumLet,
+        Self::WSegSpace,
+    ];
+
+    /// The size in bytes of the lookup table that [`Self::of_char`] uses.
+    ///
+    /// Like the tables behind [`CharProperties`], this table honors the
+    /// `GRAPHEME_MACHINE_LINK_SECTION` environment variable.
+    pub const TABLE_SIZE: usize = table::WORD_BREAK_RUNS_SIZE;
+
+    /// Returns the **Word_Break** property of the given character.
+    pub const fn of_char(c: char) -> Self {
+        Self::ALL[table::word_break_lookup(c) as usize]
+    }
+
+    const fn is_ah_letter(self) -> bool {
+        matches!(self, Self::ALetter | Self::HebrewLetter)
+    }
+
+    const fn is_newline(self) -> bool {
+        matches!(self, Self::CR | Self::LF | Self::Newline)
+    }
+
+    const fn is_ignored(self) -> bool {
+        matches!(self, Self::Extend | Self::Format | Self::ZWJ)
+    }
+
+    // MidLetter or MidNumLetQ, which can join letters.
+    const fn is_mid_letter(self) -> bool {
+        matches!(self, Self::MidLetter | Self::MidNumLet | Self::SingleQuote)
+    }
+
+    // MidNum or MidNumLetQ, which can join digits.
+    const fn is_mid_num(self) -> bool {
+        matches!(self, Self::MidNum | Self::MidNumLet | Self::SingleQuote)
+    }
+}
+
+/// What a [`WordMachine`] found before a new character.
+///
+/// Unlike grapheme cluster boundaries, some word boundaries depend on the
+/// character after the one they precede: the full stop in `"e.g"` doesn't
+/// begin a new word because a letter follows it, but the one at the end of
+/// a sentence does. The machine reports those as [`WordAction::Undecided`]
+/// and then settles them with a later [`WordAction::Resolved`], so a caller
+/// that buffers the text of each word must be prepared to buffer the
+/// undecided character separately until then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordAction {
+    /// There's no word boundary before the new character.
+    Continue,
+    /// There's a word boundary before the new character.
+    Split,
+    /// Whether there's a word boundary before the new character depends on
+    /// the next character other than the ones that are ignored by rule
+    /// [WB4](https://www.unicode.org/reports/tr29/#WB4), such as combining
+    /// marks, which get [`WordAction::Continue`] in the meantime.
+    Undecided,
+    /// The new character settled the most recent [`WordAction::Undecided`].
+    Resolved {
+        /// Whether there's a word boundary before the character whose action
+        /// was [`WordAction::Undecided`].
+        undecided_split: bool,
+        /// Whether there's a word boundary before the new character.
+        split: bool,
+    },
+}
+
+impl WordAction {
+    /// Returns `true` if there's a word boundary before the new character,
+    /// regardless of any undecided boundary before an earlier one.
+    pub const fn is_boundary(self) -> bool {
+        matches!(self, Self::Split | Self::Resolved { split: true, .. })
+    }
+}
+
+/// Which property must appear next to join the character whose action was
+/// [`WordAction::Undecided`] to the word before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    // WB6 and WB7.
+    Letter,
+    // WB7b and WB7c.
+    HebrewLetter,
+    // WB11 and WB12.
+    Numeric,
+}
+
+impl Pending {
+    const fn is_completed_by(self, next: WBProperty) -> bool {
+        match self {
+            Self::Letter => next.is_ah_letter(),
+            Self::HebrewLetter => matches!(next, WBProperty::HebrewLetter),
+            Self::Numeric => matches!(next, WBProperty::Numeric),
+        }
+    }
+}
+
+/// A state machine for detecting word boundaries, using the default rules
+/// from [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundary_Rules).
+///
+/// This is a sibling of `GraphemeMachine` for
+/// streaming input: feed it each character in turn using
+/// [`Self::next_u8char`] or [`Self::next_char`], and it returns a
+/// [`WordAction`] for the position before that character. As with grapheme
+/// clusters, the segments between boundaries include the spaces and
+/// punctuation between words, so a search indexer would typically keep
+/// only the segments that contain a letter or digit.
+///
+/// For a whole string that's already in memory, [`words_in_str`] drives a
+/// machine internally and returns each segment as a slice of the string.
+///
+/// This is available only when the `word` feature is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct WordMachine {
+    // The property of the previous character, or None at the start of input.
+    prev_raw: Option<WBProperty>,
+    // The properties of the previous two characters that rule WB4 doesn't
+    // ignore, which are what most of the rules are written in terms of.
+    prev: Option<WBProperty>,
+    prev_prev: Option<WBProperty>,
+    // Whether `prev` ends an odd number of consecutive regional indicators.
+    odd_regional_indicators: bool,
+    pending: Option<Pending>,
+}
+
+/// The decision for a boundary according to the rules after WB4.
+enum Decision {
+    Continue,
+    Split,
+    Undecided(Pending),
+}
+
+impl WordMachine {
+    /// Constructs a new machine in an initial "start of input" state.
+    pub const fn new() -> Self {
+        Self {
+            prev_raw: None,
+            prev: None,
+            prev_prev: None,
+            odd_regional_indicators: false,
+            pending: None,
+        }
+    }
+
+    /// Looks up the properties of the given character and then advances
+    /// the machine by passing them to [`Self::next_properties`].
+    pub fn next_u8char(&mut self, c: u8char) -> WordAction {
+        self.next_char(c.to_char())
+    }
+
+    /// Looks up the properties of the given character and then advances
+    /// the machine by passing them to [`Self::next_properties`].
+    pub fn next_char(&mut self, c: char) -> WordAction {
+        let pictographic =
+            CharProperties::for_char(c).gcb_property() == GCBProperty::ExtendedPictographic;
+        self.next_properties(WBProperty::of_char(c), pictographic)
+    }
+
+    /// Advances the machine for a character with the given **Word_Break**
+    /// property, and with the **Extended_Pictographic** property if
+    /// `pictographic` is set, returning what the machine found before that
+    /// character.
+    ///
+    /// This is for callers with tailored property tables, in the same way
+    /// as `GraphemeMachine::next_char_properties`.
+    pub fn next_properties(&mut self, next: WBProperty, pictographic: bool) -> WordAction {
+        use WBProperty::{CR, LF, RegionalIndicator, WSegSpace, ZWJ};
+
+        // WB1: Break at the start of input.
+        let Some(prev_raw) = self.prev_raw.replace(next) else {
+            self.prev = Some(next);
+            self.odd_regional_indicators = next == RegionalIndicator;
+            return WordAction::Split;
+        };
+
+        // WB3 through WB3d are in terms of the immediately-preceding
+        // character, before WB4 ignores any.
+        let decision = if prev_raw == CR && next == LF {
+            Decision::Continue
+        } else if prev_raw.is_newline() || next.is_newline() {
+            Decision::Split
+        } else if (prev_raw == ZWJ && pictographic) || (prev_raw == WSegSpace && next == WSegSpace)
+        {
+            Decision::Continue
+        } else if next.is_ignored() {
+            // WB4: Ignore extending characters, so that they don't change
+            // the state and leave any undecided boundary undecided.
+            return WordAction::Continue;
+        } else {
+            self.decide(next)
+        };
+
+        let undecided_split = self
+            .pending
+            .take()
+            .map(|pending| !pending.is_completed_by(next));
+        let odd = self.prev == Some(RegionalIndicator) && self.odd_regional_indicators;
+        self.odd_regional_indicators = next == RegionalIndicator && !odd;
+        self.prev_prev = self.prev;
+        self.prev = Some(next);
+
+        let split = match decision {
+            Decision::Continue => false,
+            Decision::Split => true,
+            Decision::Undecided(pending) => {
+                // The character completing a pattern can't also begin one,
+                // because it's never of the kind that begins one.
+                debug_assert!(undecided_split.is_none());
+                self.pending = Some(pending);
+                return WordAction::Undecided;
+            }
+        };
+        match undecided_split {
+            Some(undecided_split) => WordAction::Resolved {
+                undecided_split,
+                split,
+            },
+            None if split => WordAction::Split,
+            None => WordAction::Continue,
+        }
+    }
+
+    /// Applies rules WB5 through WB999 to the boundary before `next`.
+    fn decide(&self, next: WBProperty) -> Decision {
+        use WBProperty::*;
+
+        // There's always a previous character after the start of input,
+        // and WB4 never ignores the first one.
+        let prev = self.prev.unwrap_or(WBProperty::None);
+        let prev_prev = self.prev_prev.unwrap_or(WBProperty::None);
+        if prev.is_ah_letter() && next.is_ah_letter() {
+            // WB5
+            Decision::Continue
+        } else if prev == HebrewLetter && next == SingleQuote {
+            // WB7a, which also covers WB6 for this pair.
+            Decision::Continue
+        } else if prev.is_ah_letter() && next.is_mid_letter() {
+            // WB6
+            Decision::Undecided(Pending::Letter)
+        } else if prev_prev.is_ah_letter() && prev.is_mid_letter() && next.is_ah_letter() {
+            // WB7
+            Decision::Continue
+        } else if prev == HebrewLetter && next == DoubleQuote {
+            // WB7b
+            Decision::Undecided(Pending::HebrewLetter)
+        } else if prev_prev == HebrewLetter && prev == DoubleQuote && next == HebrewLetter {
+            // WB7c
+            Decision::Continue
+        } else if (prev == Numeric || prev.is_ah_letter()) && next == Numeric {
+            // WB8 and WB9
+            Decision::Continue
+        } else if prev == Numeric && next.is_ah_letter() {
+            // WB10
+            Decision::Continue
+        } else if prev_prev == Numeric && prev.is_mid_num() && next == Numeric {
+            // WB11
+            Decision::Continue
+        } else if prev == Numeric && next.is_mid_num() {
+            // WB12
+            Decision::Undecided(Pending::Numeric)
+        } else if prev == Katakana && next == Katakana {
+            // WB13
+            Decision::Continue
+        } else if next == ExtendNumLet
+            && (prev.is_ah_letter() || matches!(prev, Numeric | Katakana | ExtendNumLet))
+        {
+            // WB13a
+            Decision::Continue
+        } else if prev == ExtendNumLet
+            && (next.is_ah_letter() || matches!(next, Numeric | Katakana))
+        {
+            // WB13b
+            Decision::Continue
+        } else if prev == RegionalIndicator
+            && next == RegionalIndicator
+            && self.odd_regional_indicators
+        {
+            // WB15 and WB16
+            Decision::Continue
+        } else {
+            // WB999
+            Decision::Split
+        }
+    }
+
+    /// Tells the machine that the input has ended, returning the action to
+    /// mark the end of the final segment.
+    ///
+    /// This is [`WordAction::Split`] unless there was an undecided boundary,
+    /// in which case it's [`WordAction::Resolved`] with a boundary before
+    /// the undecided character, because nothing completed its pattern.
+    ///
+    /// As with `GraphemeMachine::end_of_input`,
+    /// the machine then returns to its initial state, so that it can be
+    /// used for new input.
+    pub fn end_of_input(&mut self) -> WordAction {
+        let pending = self.pending.is_some();
+        *self = Self::new();
+        match pending {
+            true => WordAction::Resolved {
+                undecided_split: true,
+                split: true,
+            },
+            false => WordAction::Split,
+        }
+    }
+}
+
+/// Returns an iterator over the segments between the word boundaries in
+/// `s`, each as a slice of `s`.
+///
+/// The segments include the spaces and punctuation between words, each as
+/// separate segments except where the rules join them, so concatenating
+/// all of the segments reproduces `s`.
+///
+/// This is available only when the `word` feature is enabled.
+pub fn words_in_str(s: &str) -> StrWords<'_> {
+    StrWords {
+        machine: WordMachine::new(),
+        s,
+        start: 0,
+        scanned: 0,
+        undecided: 0,
+        queued: None,
+    }
+}
+
+/// An iterator over the segments between the word boundaries in a string,
+/// each as a slice of that string.
+///
+/// Construct this using [`words_in_str`].
+#[derive(Debug, Clone)]
+pub struct StrWords<'a> {
+    machine: WordMachine,
+    s: &'a str,
+    // The start of the next segment to return.
+    start: usize,
+    // The end of the characters already given to the machine.
+    scanned: usize,
+    // The offset of the character whose action was most recently
+    // WordAction::Undecided.
+    undecided: usize,
+    // The end of the segment after the next one, when a single action
+    // found two boundaries.
+    queued: Option<usize>,
+}
+
+impl<'a> StrWords<'a> {
+    fn take_until(&mut self, end: usize) -> &'a str {
+        let segment = &self.s[self.start..end];
+        self.start = end;
+        segment
+    }
+}
+
+impl<'a> Iterator for StrWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if let Some(end) = self.queued.take() {
+            return Some(self.take_until(end));
+        }
+        if self.start >= self.s.len() {
+            return None;
+        }
+        while let (Some(c), _) = u8char::from_string_prefix(&self.s[self.scanned..]) {
+            let offset = self.scanned;
+            self.scanned += c.len();
+            match self.machine.next_u8char(c) {
+                WordAction::Split if offset > self.start => {
+                    return Some(self.take_until(offset));
+                }
+                WordAction::Undecided => self.undecided = offset,
+                WordAction::Resolved {
+                    undecided_split,
+                    split,
+                } => {
+                    if undecided_split {
+                        if split {
+                            self.queued = Some(offset);
+                        }
+                        return Some(self.take_until(self.undecided));
+                    }
+                    if split {
+                        return Some(self.take_until(offset));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let WordAction::Resolved { .. } = self.machine.end_of_input() {
+            self.queued = Some(self.s.len());
+            return Some(self.take_until(self.undecided));
+        }
+        Some(self.take_until(self.s.len()))
+    }
+}
+
+impl<'a> FusedIterator for StrWords<'a> {}
+
+#[cfg(test)]
+mod test_table;
+
+#[cfg(test)]
+mod tests;
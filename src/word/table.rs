@@ -0,0 +1,266 @@
+/// Returns the encoded **Word_Break** property of the given character, as a
+/// discriminant of [`WBProperty`](super::WBProperty).
+///
+/// [`WORD_BREAK_RUNS`] is sorted by code point, so this is a binary search
+/// for the last run beginning at or before `c`.
+pub const fn word_break_lookup(c: char) -> u8 {
+    let c = c as u32;
+    // The first run always begins at U+0000.
+    let (mut lo, mut hi) = (0, WORD_BREAK_RUNS.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if WORD_BREAK_RUNS[mid] >> 8 <= c {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    WORD_BREAK_RUNS[lo] as u8
+}
+
+/// The size in bytes of [`WORD_BREAK_RUNS`].
+pub const WORD_BREAK_RUNS_SIZE: usize = WORD_BREAK_RUNS.len() * 4;
+
+#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!("GRAPHEME_MACHINE_LINK_SECTION"))
+)]
+static WORD_BREAK_RUNS: [u32; 1889] = [
+    0x00000000, 0x00000a02, 0x00000b03, 0x00000d01, 0x00000e00, 0x00002012, 0x00002100, 0x0000220c,
+    0x00002300, 0x0000270b, 0x00002800, 0x00002c0f, 0x00002d00, 0x00002e0d, 0x00002f00, 0x00003010,
+    0x00003a0e, 0x00003b0f, 0x00003c00, 0x0000410a, 0x00005b00, 0x00005f11, 0x00006000, 0x0000610a,
+    0x00007b00, 0x00008503, 0x00008600, 0x0000aa0a, 0x0000ab00, 0x0000ad07, 0x0000ae00, 0x0000b50a,
+    0x0000b600, 0x0000b70e, 0x0000b80a, 0x0000b900, 0x0000ba0a, 0x0000bb00, 0x0000c00a, 0x0000d700,
+    0x0000d80a, 0x0000f700, 0x0000f80a, 0x0002d800, 0x0002de0a, 0x00030004, 0x0003700a, 0x00037500,
+    0x0003760a, 0x00037800, 0x00037a0a, 0x00037e0f, 0x00037f0a, 0x00038000, 0x0003860a, 0x0003870e,
+    0x0003880a, 0x00038b00, 0x00038c0a, 0x00038d00, 0x00038e0a, 0x0003a200, 0x0003a30a, 0x0003f600,
+    0x0003f70a, 0x00048200, 0x00048304, 0x00048a0a, 0x00053000, 0x0005310a, 0x00055700, 0x0005590a,
+    0x00055d00, 0x00055e0a, 0x00055f0e, 0x0005600a, 0x0005890f, 0x00058a0a, 0x00058b00, 0x00059104,
+    0x0005be00, 0x0005bf04, 0x0005c000, 0x0005c104, 0x0005c300, 0x0005c404, 0x0005c600, 0x0005c704,
+    0x0005c800, 0x0005d009, 0x0005eb00, 0x0005ef09, 0x0005f30a, 0x0005f40e, 0x0005f500, 0x00060010,
+    0x00060600, 0x00060c0f, 0x00060e00, 0x00061004, 0x00061b00, 0x00061c07, 0x00061d00, 0x0006200a,
+    0x00064b04, 0x00066010, 0x00066a00, 0x00066b10, 0x00066c0f, 0x00066d00, 0x00066e0a, 0x00067004,
+    0x0006710a, 0x0006d400, 0x0006d50a, 0x0006d604, 0x0006dd10, 0x0006de00, 0x0006df04, 0x0006e50a,
+    0x0006e704, 0x0006e900, 0x0006ea04, 0x0006ee0a, 0x0006f010, 0x0006fa0a, 0x0006fd00, 0x0006ff0a,
+    0x00070000, 0x00070f0a, 0x00071104, 0x0007120a, 0x00073004, 0x00074b00, 0x00074d0a, 0x0007a604,
+    0x0007b10a, 0x0007b200, 0x0007c010, 0x0007ca0a, 0x0007eb04, 0x0007f40a, 0x0007f600, 0x0007f80f,
+    0x0007f900, 0x0007fa0a, 0x0007fb00, 0x0007fd04, 0x0007fe00, 0x0008000a, 0x00081604, 0x00081a0a,
+    0x00081b04, 0x0008240a, 0x00082504, 0x0008280a, 0x00082904, 0x00082e00, 0x0008400a, 0x00085904,
+    0x00085c00, 0x0008600a, 0x00086b00, 0x0008700a, 0x00088800, 0x0008890a, 0x00088f00, 0x00089010,
+    0x00089200, 0x00089704, 0x0008a00a, 0x0008ca04, 0x0008e210, 0x0008e304, 0x0009040a, 0x00093a04,
+    0x00093d0a, 0x00093e04, 0x0009500a, 0x00095104, 0x0009580a, 0x00096204, 0x00096400, 0x00096610,
+    0x00097000, 0x0009710a, 0x00098104, 0x00098400, 0x0009850a, 0x00098d00, 0x00098f0a, 0x00099100,
+    0x0009930a, 0x0009a900, 0x0009aa0a, 0x0009b100, 0x0009b20a, 0x0009b300, 0x0009b60a, 0x0009ba00,
+    0x0009bc04, 0x0009bd0a, 0x0009be04, 0x0009c500, 0x0009c704, 0x0009c900, 0x0009cb04, 0x0009ce0a,
+    0x0009cf00, 0x0009d704, 0x0009d800, 0x0009dc0a, 0x0009de00, 0x0009df0a, 0x0009e204, 0x0009e400,
+    0x0009e610, 0x0009f00a, 0x0009f200, 0x0009fc0a, 0x0009fd00, 0x0009fe04, 0x0009ff00, 0x000a0104,
+    0x000a0400, 0x000a050a, 0x000a0b00, 0x000a0f0a, 0x000a1100, 0x000a130a, 0x000a2900, 0x000a2a0a,
+    0x000a3100, 0x000a320a, 0x000a3400, 0x000a350a, 0x000a3700, 0x000a380a, 0x000a3a00, 0x000a3c04,
+    0x000a3d00, 0x000a3e04, 0x000a4300, 0x000a4704, 0x000a4900, 0x000a4b04, 0x000a4e00, 0x000a5104,
+    0x000a5200, 0x000a590a, 0x000a5d00, 0x000a5e0a, 0x000a5f00, 0x000a6610, 0x000a7004, 0x000a720a,
+    0x000a7504, 0x000a7600, 0x000a8104, 0x000a8400, 0x000a850a, 0x000a8e00, 0x000a8f0a, 0x000a9200,
+    0x000a930a, 0x000aa900, 0x000aaa0a, 0x000ab100, 0x000ab20a, 0x000ab400, 0x000ab50a, 0x000aba00,
+    0x000abc04, 0x000abd0a, 0x000abe04, 0x000ac600, 0x000ac704, 0x000aca00, 0x000acb04, 0x000ace00,
+    0x000ad00a, 0x000ad100, 0x000ae00a, 0x000ae204, 0x000ae400, 0x000ae610, 0x000af000, 0x000af90a,
+    0x000afa04, 0x000b0000, 0x000b0104, 0x000b0400, 0x000b050a, 0x000b0d00, 0x000b0f0a, 0x000b1100,
+    0x000b130a, 0x000b2900, 0x000b2a0a, 0x000b3100, 0x000b320a, 0x000b3400, 0x000b350a, 0x000b3a00,
+    0x000b3c04, 0x000b3d0a, 0x000b3e04, 0x000b4500, 0x000b4704, 0x000b4900, 0x000b4b04, 0x000b4e00,
+    0x000b5504, 0x000b5800, 0x000b5c0a, 0x000b5e00, 0x000b5f0a, 0x000b6204, 0x000b6400, 0x000b6610,
+    0x000b7000, 0x000b710a, 0x000b7200, 0x000b8204, 0x000b830a, 0x000b8400, 0x000b850a, 0x000b8b00,
+    0x000b8e0a, 0x000b9100, 0x000b920a, 0x000b9600, 0x000b990a, 0x000b9b00, 0x000b9c0a, 0x000b9d00,
+    0x000b9e0a, 0x000ba000, 0x000ba30a, 0x000ba500, 0x000ba80a, 0x000bab00, 0x000bae0a, 0x000bba00,
+    0x000bbe04, 0x000bc300, 0x000bc604, 0x000bc900, 0x000bca04, 0x000bce00, 0x000bd00a, 0x000bd100,
+    0x000bd704, 0x000bd800, 0x000be610, 0x000bf000, 0x000c0004, 0x000c050a, 0x000c0d00, 0x000c0e0a,
+    0x000c1100, 0x000c120a, 0x000c2900, 0x000c2a0a, 0x000c3a00, 0x000c3c04, 0x000c3d0a, 0x000c3e04,
+    0x000c4500, 0x000c4604, 0x000c4900, 0x000c4a04, 0x000c4e00, 0x000c5504, 0x000c5700, 0x000c580a,
+    0x000c5b00, 0x000c5d0a, 0x000c5e00, 0x000c600a, 0x000c6204, 0x000c6400, 0x000c6610, 0x000c7000,
+    0x000c800a, 0x000c8104, 0x000c8400, 0x000c850a, 0x000c8d00, 0x000c8e0a, 0x000c9100, 0x000c920a,
+    0x000ca900, 0x000caa0a, 0x000cb400, 0x000cb50a, 0x000cba00, 0x000cbc04, 0x000cbd0a, 0x000cbe04,
+    0x000cc500, 0x000cc604, 0x000cc900, 0x000cca04, 0x000cce00, 0x000cd504, 0x000cd700, 0x000cdd0a,
+    0x000cdf00, 0x000ce00a, 0x000ce204, 0x000ce400, 0x000ce610, 0x000cf000, 0x000cf10a, 0x000cf304,
+    0x000cf400, 0x000d0004, 0x000d040a, 0x000d0d00, 0x000d0e0a, 0x000d1100, 0x000d120a, 0x000d3b04,
+    0x000d3d0a, 0x000d3e04, 0x000d4500, 0x000d4604, 0x000d4900, 0x000d4a04, 0x000d4e0a, 0x000d4f00,
+    0x000d540a, 0x000d5704, 0x000d5800, 0x000d5f0a, 0x000d6204, 0x000d6400, 0x000d6610, 0x000d7000,
+    0x000d7a0a, 0x000d8000, 0x000d8104, 0x000d8400, 0x000d850a, 0x000d9700, 0x000d9a0a, 0x000db200,
+    0x000db30a, 0x000dbc00, 0x000dbd0a, 0x000dbe00, 0x000dc00a, 0x000dc700, 0x000dca04, 0x000dcb00,
+    0x000dcf04, 0x000dd500, 0x000dd604, 0x000dd700, 0x000dd804, 0x000de000, 0x000de610, 0x000df000,
+    0x000df204, 0x000df400, 0x000e3104, 0x000e3200, 0x000e3404, 0x000e3b00, 0x000e4704, 0x000e4f00,
+    0x000e5010, 0x000e5a00, 0x000eb104, 0x000eb200, 0x000eb404, 0x000ebd00, 0x000ec804, 0x000ecf00,
+    0x000ed010, 0x000eda00, 0x000f000a, 0x000f0100, 0x000f1804, 0x000f1a00, 0x000f2010, 0x000f2a00,
+    0x000f3504, 0x000f3600, 0x000f3704, 0x000f3800, 0x000f3904, 0x000f3a00, 0x000f3e04, 0x000f400a,
+    0x000f4800, 0x000f490a, 0x000f6d00, 0x000f7104, 0x000f8500, 0x000f8604, 0x000f880a, 0x000f8d04,
+    0x000f9800, 0x000f9904, 0x000fbd00, 0x000fc604, 0x000fc700, 0x00102b04, 0x00103f00, 0x00104010,
+    0x00104a00, 0x00105604, 0x00105a00, 0x00105e04, 0x00106100, 0x00106204, 0x00106500, 0x00106704,
+    0x00106e00, 0x00107104, 0x00107500, 0x00108204, 0x00108e00, 0x00108f04, 0x00109010, 0x00109a04,
+    0x00109e00, 0x0010a00a, 0x0010c600, 0x0010c70a, 0x0010c800, 0x0010cd0a, 0x0010ce00, 0x0010d00a,
+    0x0010fb00, 0x0010fc0a, 0x00124900, 0x00124a0a, 0x00124e00, 0x0012500a, 0x00125700, 0x0012580a,
+    0x00125900, 0x00125a0a, 0x00125e00, 0x0012600a, 0x00128900, 0x00128a0a, 0x00128e00, 0x0012900a,
+    0x0012b100, 0x0012b20a, 0x0012b600, 0x0012b80a, 0x0012bf00, 0x0012c00a, 0x0012c100, 0x0012c20a,
+    0x0012c600, 0x0012c80a, 0x0012d700, 0x0012d80a, 0x00131100, 0x0013120a, 0x00131600, 0x0013180a,
+    0x00135b00, 0x00135d04, 0x00136000, 0x0013800a, 0x00139000, 0x0013a00a, 0x0013f600, 0x0013f80a,
+    0x0013fe00, 0x0014010a, 0x00166d00, 0x00166f0a, 0x00168012, 0x0016810a, 0x00169b00, 0x0016a00a,
+    0x0016eb00, 0x0016ee0a, 0x0016f900, 0x0017000a, 0x00171204, 0x00171600, 0x00171f0a, 0x00173204,
+    0x00173500, 0x0017400a, 0x00175204, 0x00175400, 0x0017600a, 0x00176d00, 0x00176e0a, 0x00177100,
+    0x00177204, 0x00177400, 0x0017b404, 0x0017d400, 0x0017dd04, 0x0017de00, 0x0017e010, 0x0017ea00,
+    0x00180b04, 0x00180e07, 0x00180f04, 0x00181010, 0x00181a00, 0x0018200a, 0x00187900, 0x0018800a,
+    0x00188504, 0x0018870a, 0x0018a904, 0x0018aa0a, 0x0018ab00, 0x0018b00a, 0x0018f600, 0x0019000a,
+    0x00191f00, 0x00192004, 0x00192c00, 0x00193004, 0x00193c00, 0x00194610, 0x00195000, 0x0019d010,
+    0x0019db00, 0x001a000a, 0x001a1704, 0x001a1c00, 0x001a5504, 0x001a5f00, 0x001a6004, 0x001a7d00,
+    0x001a7f04, 0x001a8010, 0x001a8a00, 0x001a9010, 0x001a9a00, 0x001ab004, 0x001acf00, 0x001b0004,
+    0x001b050a, 0x001b3404, 0x001b450a, 0x001b4d00, 0x001b5010, 0x001b5a00, 0x001b6b04, 0x001b7400,
+    0x001b8004, 0x001b830a, 0x001ba104, 0x001bae0a, 0x001bb010, 0x001bba0a, 0x001be604, 0x001bf400,
+    0x001c000a, 0x001c2404, 0x001c3800, 0x001c4010, 0x001c4a00, 0x001c4d0a, 0x001c5010, 0x001c5a0a,
+    0x001c7e00, 0x001c800a, 0x001c8b00, 0x001c900a, 0x001cbb00, 0x001cbd0a, 0x001cc000, 0x001cd004,
+    0x001cd300, 0x001cd404, 0x001ce90a, 0x001ced04, 0x001cee0a, 0x001cf404, 0x001cf50a, 0x001cf704,
+    0x001cfa0a, 0x001cfb00, 0x001d000a, 0x001dc004, 0x001e000a, 0x001f1600, 0x001f180a, 0x001f1e00,
+    0x001f200a, 0x001f4600, 0x001f480a, 0x001f4e00, 0x001f500a, 0x001f5800, 0x001f590a, 0x001f5a00,
+    0x001f5b0a, 0x001f5c00, 0x001f5d0a, 0x001f5e00, 0x001f5f0a, 0x001f7e00, 0x001f800a, 0x001fb500,
+    0x001fb60a, 0x001fbd00, 0x001fbe0a, 0x001fbf00, 0x001fc20a, 0x001fc500, 0x001fc60a, 0x001fcd00,
+    0x001fd00a, 0x001fd400, 0x001fd60a, 0x001fdc00, 0x001fe00a, 0x001fed00, 0x001ff20a, 0x001ff500,
+    0x001ff60a, 0x001ffd00, 0x00200012, 0x00200700, 0x00200812, 0x00200b00, 0x00200c04, 0x00200d05,
+    0x00200e07, 0x00201000, 0x0020180d, 0x00201a00, 0x0020240d, 0x00202500, 0x0020270e, 0x00202803,
+    0x00202a07, 0x00202f11, 0x00203000, 0x00203f11, 0x00204100, 0x0020440f, 0x00204500, 0x00205411,
+    0x00205500, 0x00205f12, 0x00206007, 0x00206500, 0x00206607, 0x00207000, 0x0020710a, 0x00207200,
+    0x00207f0a, 0x00208000, 0x0020900a, 0x00209d00, 0x0020d004, 0x0020f100, 0x0021020a, 0x00210300,
+    0x0021070a, 0x00210800, 0x00210a0a, 0x00211400, 0x0021150a, 0x00211600, 0x0021190a, 0x00211e00,
+    0x0021240a, 0x00212500, 0x0021260a, 0x00212700, 0x0021280a, 0x00212900, 0x00212a0a, 0x00212e00,
+    0x00212f0a, 0x00213a00, 0x00213c0a, 0x00214000, 0x0021450a, 0x00214a00, 0x00214e0a, 0x00214f00,
+    0x0021600a, 0x00218900, 0x0024b60a, 0x0024ea00, 0x002c000a, 0x002ce500, 0x002ceb0a, 0x002cef04,
+    0x002cf20a, 0x002cf400, 0x002d000a, 0x002d2600, 0x002d270a, 0x002d2800, 0x002d2d0a, 0x002d2e00,
+    0x002d300a, 0x002d6800, 0x002d6f0a, 0x002d7000, 0x002d7f04, 0x002d800a, 0x002d9700, 0x002da00a,
+    0x002da700, 0x002da80a, 0x002daf00, 0x002db00a, 0x002db700, 0x002db80a, 0x002dbf00, 0x002dc00a,
+    0x002dc700, 0x002dc80a, 0x002dcf00, 0x002dd00a, 0x002dd700, 0x002dd80a, 0x002ddf00, 0x002de004,
+    0x002e0000, 0x002e2f0a, 0x002e3000, 0x00300012, 0x00300100, 0x0030050a, 0x00300600, 0x00302a04,
+    0x00303000, 0x00303108, 0x00303600, 0x00303b0a, 0x00303d00, 0x00309904, 0x00309b08, 0x00309d00,
+    0x0030a008, 0x0030fb00, 0x0030fc08, 0x00310000, 0x0031050a, 0x00313000, 0x0031310a, 0x00318f00,
+    0x0031a00a, 0x0031c000, 0x0031f008, 0x00320000, 0x0032d008, 0x0032ff00, 0x00330008, 0x00335800,
+    0x00a0000a, 0x00a48d00, 0x00a4d00a, 0x00a4fe00, 0x00a5000a, 0x00a60d00, 0x00a6100a, 0x00a62010,
+    0x00a62a0a, 0x00a62c00, 0x00a6400a, 0x00a66f04, 0x00a67300, 0x00a67404, 0x00a67e00, 0x00a67f0a,
+    0x00a69e04, 0x00a6a00a, 0x00a6f004, 0x00a6f200, 0x00a7080a, 0x00a7ce00, 0x00a7d00a, 0x00a7d200,
+    0x00a7d30a, 0x00a7d400, 0x00a7d50a, 0x00a7dd00, 0x00a7f20a, 0x00a80204, 0x00a8030a, 0x00a80604,
+    0x00a8070a, 0x00a80b04, 0x00a80c0a, 0x00a82304, 0x00a82800, 0x00a82c04, 0x00a82d00, 0x00a8400a,
+    0x00a87400, 0x00a88004, 0x00a8820a, 0x00a8b404, 0x00a8c600, 0x00a8d010, 0x00a8da00, 0x00a8e004,
+    0x00a8f20a, 0x00a8f800, 0x00a8fb0a, 0x00a8fc00, 0x00a8fd0a, 0x00a8ff04, 0x00a90010, 0x00a90a0a,
+    0x00a92604, 0x00a92e00, 0x00a9300a, 0x00a94704, 0x00a95400, 0x00a9600a, 0x00a97d00, 0x00a98004,
+    0x00a9840a, 0x00a9b304, 0x00a9c100, 0x00a9cf0a, 0x00a9d010, 0x00a9da00, 0x00a9e504, 0x00a9e600,
+    0x00a9f010, 0x00a9fa00, 0x00aa000a, 0x00aa2904, 0x00aa3700, 0x00aa400a, 0x00aa4304, 0x00aa440a,
+    0x00aa4c04, 0x00aa4e00, 0x00aa5010, 0x00aa5a00, 0x00aa7b04, 0x00aa7e00, 0x00aab004, 0x00aab100,
+    0x00aab204, 0x00aab500, 0x00aab704, 0x00aab900, 0x00aabe04, 0x00aac000, 0x00aac104, 0x00aac200,
+    0x00aae00a, 0x00aaeb04, 0x00aaf000, 0x00aaf20a, 0x00aaf504, 0x00aaf700, 0x00ab010a, 0x00ab0700,
+    0x00ab090a, 0x00ab0f00, 0x00ab110a, 0x00ab1700, 0x00ab200a, 0x00ab2700, 0x00ab280a, 0x00ab2f00,
+    0x00ab300a, 0x00ab6a00, 0x00ab700a, 0x00abe304, 0x00abeb00, 0x00abec04, 0x00abee00, 0x00abf010,
+    0x00abfa00, 0x00ac000a, 0x00d7a400, 0x00d7b00a, 0x00d7c700, 0x00d7cb0a, 0x00d7fc00, 0x00fb000a,
+    0x00fb0700, 0x00fb130a, 0x00fb1800, 0x00fb1d09, 0x00fb1e04, 0x00fb1f09, 0x00fb2900, 0x00fb2a09,
+    0x00fb3700, 0x00fb3809, 0x00fb3d00, 0x00fb3e09, 0x00fb3f00, 0x00fb4009, 0x00fb4200, 0x00fb4309,
+    0x00fb4500, 0x00fb4609, 0x00fb500a, 0x00fbb200, 0x00fbd30a, 0x00fd3e00, 0x00fd500a, 0x00fd9000,
+    0x00fd920a, 0x00fdc800, 0x00fdf00a, 0x00fdfc00, 0x00fe0004, 0x00fe1000, 0x00fe130e, 0x00fe1400,
+    0x00fe2004, 0x00fe3000, 0x00fe3311, 0x00fe3500, 0x00fe4d11, 0x00fe500f, 0x00fe5100, 0x00fe520d,
+    0x00fe5300, 0x00fe540f, 0x00fe550e, 0x00fe5600, 0x00fe700a, 0x00fe7500, 0x00fe760a, 0x00fefd00,
+    0x00feff07, 0x00ff0000, 0x00ff070d, 0x00ff0800, 0x00ff0c0f, 0x00ff0d00, 0x00ff0e0d, 0x00ff0f00,
+    0x00ff1010, 0x00ff1a0e, 0x00ff1b0f, 0x00ff1c00, 0x00ff210a, 0x00ff3b00, 0x00ff3f11, 0x00ff4000,
+    0x00ff410a, 0x00ff5b00, 0x00ff6608, 0x00ff9e04, 0x00ffa00a, 0x00ffbf00, 0x00ffc20a, 0x00ffc800,
+    0x00ffca0a, 0x00ffd000, 0x00ffd20a, 0x00ffd800, 0x00ffda0a, 0x00ffdd00, 0x00fff907, 0x00fffc00,
+    0x0100000a, 0x01000c00, 0x01000d0a, 0x01002700, 0x0100280a, 0x01003b00, 0x01003c0a, 0x01003e00,
+    0x01003f0a, 0x01004e00, 0x0100500a, 0x01005e00, 0x0100800a, 0x0100fb00, 0x0101400a, 0x01017500,
+    0x0101fd04, 0x0101fe00, 0x0102800a, 0x01029d00, 0x0102a00a, 0x0102d100, 0x0102e004, 0x0102e100,
+    0x0103000a, 0x01032000, 0x01032d0a, 0x01034b00, 0x0103500a, 0x01037604, 0x01037b00, 0x0103800a,
+    0x01039e00, 0x0103a00a, 0x0103c400, 0x0103c80a, 0x0103d000, 0x0103d10a, 0x0103d600, 0x0104000a,
+    0x01049e00, 0x0104a010, 0x0104aa00, 0x0104b00a, 0x0104d400, 0x0104d80a, 0x0104fc00, 0x0105000a,
+    0x01052800, 0x0105300a, 0x01056400, 0x0105700a, 0x01057b00, 0x01057c0a, 0x01058b00, 0x01058c0a,
+    0x01059300, 0x0105940a, 0x01059600, 0x0105970a, 0x0105a200, 0x0105a30a, 0x0105b200, 0x0105b30a,
+    0x0105ba00, 0x0105bb0a, 0x0105bd00, 0x0105c00a, 0x0105f400, 0x0106000a, 0x01073700, 0x0107400a,
+    0x01075600, 0x0107600a, 0x01076800, 0x0107800a, 0x01078600, 0x0107870a, 0x0107b100, 0x0107b20a,
+    0x0107bb00, 0x0108000a, 0x01080600, 0x0108080a, 0x01080900, 0x01080a0a, 0x01083600, 0x0108370a,
+    0x01083900, 0x01083c0a, 0x01083d00, 0x01083f0a, 0x01085600, 0x0108600a, 0x01087700, 0x0108800a,
+    0x01089f00, 0x0108e00a, 0x0108f300, 0x0108f40a, 0x0108f600, 0x0109000a, 0x01091600, 0x0109200a,
+    0x01093a00, 0x0109800a, 0x0109b800, 0x0109be0a, 0x0109c000, 0x010a000a, 0x010a0104, 0x010a0400,
+    0x010a0504, 0x010a0700, 0x010a0c04, 0x010a100a, 0x010a1400, 0x010a150a, 0x010a1800, 0x010a190a,
+    0x010a3600, 0x010a3804, 0x010a3b00, 0x010a3f04, 0x010a4000, 0x010a600a, 0x010a7d00, 0x010a800a,
+    0x010a9d00, 0x010ac00a, 0x010ac800, 0x010ac90a, 0x010ae504, 0x010ae700, 0x010b000a, 0x010b3600,
+    0x010b400a, 0x010b5600, 0x010b600a, 0x010b7300, 0x010b800a, 0x010b9200, 0x010c000a, 0x010c4900,
+    0x010c800a, 0x010cb300, 0x010cc00a, 0x010cf300, 0x010d000a, 0x010d2404, 0x010d2800, 0x010d3010,
+    0x010d3a00, 0x010d4010, 0x010d4a0a, 0x010d6600, 0x010d6904, 0x010d6e00, 0x010d6f0a, 0x010d8600,
+    0x010e800a, 0x010eaa00, 0x010eab04, 0x010ead00, 0x010eb00a, 0x010eb200, 0x010ec20a, 0x010ec500,
+    0x010efc04, 0x010f000a, 0x010f1d00, 0x010f270a, 0x010f2800, 0x010f300a, 0x010f4604, 0x010f5100,
+    0x010f700a, 0x010f8204, 0x010f8600, 0x010fb00a, 0x010fc500, 0x010fe00a, 0x010ff700, 0x01100004,
+    0x0110030a, 0x01103804, 0x01104700, 0x01106610, 0x01107004, 0x0110710a, 0x01107304, 0x0110750a,
+    0x01107600, 0x01107f04, 0x0110830a, 0x0110b004, 0x0110bb00, 0x0110bd10, 0x0110be00, 0x0110c204,
+    0x0110c300, 0x0110cd10, 0x0110ce00, 0x0110d00a, 0x0110e900, 0x0110f010, 0x0110fa00, 0x01110004,
+    0x0111030a, 0x01112704, 0x01113500, 0x01113610, 0x01114000, 0x0111440a, 0x01114504, 0x0111470a,
+    0x01114800, 0x0111500a, 0x01117304, 0x01117400, 0x0111760a, 0x01117700, 0x01118004, 0x0111830a,
+    0x0111b304, 0x0111c10a, 0x0111c500, 0x0111c904, 0x0111cd00, 0x0111ce04, 0x0111d010, 0x0111da0a,
+    0x0111db00, 0x0111dc0a, 0x0111dd00, 0x0112000a, 0x01121200, 0x0112130a, 0x01122c04, 0x01123800,
+    0x01123e04, 0x01123f0a, 0x01124104, 0x01124200, 0x0112800a, 0x01128700, 0x0112880a, 0x01128900,
+    0x01128a0a, 0x01128e00, 0x01128f0a, 0x01129e00, 0x01129f0a, 0x0112a900, 0x0112b00a, 0x0112df04,
+    0x0112eb00, 0x0112f010, 0x0112fa00, 0x01130004, 0x01130400, 0x0113050a, 0x01130d00, 0x01130f0a,
+    0x01131100, 0x0113130a, 0x01132900, 0x01132a0a, 0x01133100, 0x0113320a, 0x01133400, 0x0113350a,
+    0x01133a00, 0x01133b04, 0x01133d0a, 0x01133e04, 0x01134500, 0x01134704, 0x01134900, 0x01134b04,
+    0x01134e00, 0x0113500a, 0x01135100, 0x01135704, 0x01135800, 0x01135d0a, 0x01136204, 0x01136400,
+    0x01136604, 0x01136d00, 0x01137004, 0x01137500, 0x0113800a, 0x01138a00, 0x01138b0a, 0x01138c00,
+    0x01138e0a, 0x01138f00, 0x0113900a, 0x0113b600, 0x0113b70a, 0x0113b804, 0x0113c100, 0x0113c204,
+    0x0113c300, 0x0113c504, 0x0113c600, 0x0113c704, 0x0113cb00, 0x0113cc04, 0x0113d10a, 0x0113d204,
+    0x0113d30a, 0x0113d400, 0x0113e104, 0x0113e300, 0x0114000a, 0x01143504, 0x0114470a, 0x01144b00,
+    0x01145010, 0x01145a00, 0x01145e04, 0x01145f0a, 0x01146200, 0x0114800a, 0x0114b004, 0x0114c40a,
+    0x0114c600, 0x0114c70a, 0x0114c800, 0x0114d010, 0x0114da00, 0x0115800a, 0x0115af04, 0x0115b600,
+    0x0115b804, 0x0115c100, 0x0115d80a, 0x0115dc04, 0x0115de00, 0x0116000a, 0x01163004, 0x01164100,
+    0x0116440a, 0x01164500, 0x01165010, 0x01165a00, 0x0116800a, 0x0116ab04, 0x0116b80a, 0x0116b900,
+    0x0116c010, 0x0116ca00, 0x0116d010, 0x0116e400, 0x01171d04, 0x01172c00, 0x01173010, 0x01173a00,
+    0x0118000a, 0x01182c04, 0x01183b00, 0x0118a00a, 0x0118e010, 0x0118ea00, 0x0118ff0a, 0x01190700,
+    0x0119090a, 0x01190a00, 0x01190c0a, 0x01191400, 0x0119150a, 0x01191700, 0x0119180a, 0x01193004,
+    0x01193600, 0x01193704, 0x01193900, 0x01193b04, 0x01193f0a, 0x01194004, 0x0119410a, 0x01194204,
+    0x01194400, 0x01195010, 0x01195a00, 0x0119a00a, 0x0119a800, 0x0119aa0a, 0x0119d104, 0x0119d800,
+    0x0119da04, 0x0119e10a, 0x0119e200, 0x0119e30a, 0x0119e404, 0x0119e500, 0x011a000a, 0x011a0104,
+    0x011a0b0a, 0x011a3304, 0x011a3a0a, 0x011a3b04, 0x011a3f00, 0x011a4704, 0x011a4800, 0x011a500a,
+    0x011a5104, 0x011a5c0a, 0x011a8a04, 0x011a9a00, 0x011a9d0a, 0x011a9e00, 0x011ab00a, 0x011af900,
+    0x011bc00a, 0x011be100, 0x011bf010, 0x011bfa00, 0x011c000a, 0x011c0900, 0x011c0a0a, 0x011c2f04,
+    0x011c3700, 0x011c3804, 0x011c400a, 0x011c4100, 0x011c5010, 0x011c5a00, 0x011c720a, 0x011c9000,
+    0x011c9204, 0x011ca800, 0x011ca904, 0x011cb700, 0x011d000a, 0x011d0700, 0x011d080a, 0x011d0a00,
+    0x011d0b0a, 0x011d3104, 0x011d3700, 0x011d3a04, 0x011d3b00, 0x011d3c04, 0x011d3e00, 0x011d3f04,
+    0x011d460a, 0x011d4704, 0x011d4800, 0x011d5010, 0x011d5a00, 0x011d600a, 0x011d6600, 0x011d670a,
+    0x011d6900, 0x011d6a0a, 0x011d8a04, 0x011d8f00, 0x011d9004, 0x011d9200, 0x011d9304, 0x011d980a,
+    0x011d9900, 0x011da010, 0x011daa00, 0x011ee00a, 0x011ef304, 0x011ef700, 0x011f0004, 0x011f020a,
+    0x011f0304, 0x011f040a, 0x011f1100, 0x011f120a, 0x011f3404, 0x011f3b00, 0x011f3e04, 0x011f4300,
+    0x011f5010, 0x011f5a04, 0x011f5b00, 0x011fb00a, 0x011fb100, 0x0120000a, 0x01239a00, 0x0124000a,
+    0x01246f00, 0x0124800a, 0x01254400, 0x012f900a, 0x012ff100, 0x0130000a, 0x01343007, 0x01344004,
+    0x0134410a, 0x01344704, 0x01345600, 0x0134600a, 0x0143fb00, 0x0144000a, 0x01464700, 0x0161000a,
+    0x01611e04, 0x01613010, 0x01613a00, 0x0168000a, 0x016a3900, 0x016a400a, 0x016a5f00, 0x016a6010,
+    0x016a6a00, 0x016a700a, 0x016abf00, 0x016ac010, 0x016aca00, 0x016ad00a, 0x016aee00, 0x016af004,
+    0x016af500, 0x016b000a, 0x016b3004, 0x016b3700, 0x016b400a, 0x016b4400, 0x016b5010, 0x016b5a00,
+    0x016b630a, 0x016b7800, 0x016b7d0a, 0x016b9000, 0x016d400a, 0x016d6d00, 0x016d7010, 0x016d7a00,
+    0x016e400a, 0x016e8000, 0x016f000a, 0x016f4b00, 0x016f4f04, 0x016f500a, 0x016f5104, 0x016f8800,
+    0x016f8f04, 0x016f930a, 0x016fa000, 0x016fe00a, 0x016fe200, 0x016fe30a, 0x016fe404, 0x016fe500,
+    0x016ff004, 0x016ff200, 0x01aff008, 0x01aff400, 0x01aff508, 0x01affc00, 0x01affd08, 0x01afff00,
+    0x01b00008, 0x01b00100, 0x01b12008, 0x01b12300, 0x01b15508, 0x01b15600, 0x01b16408, 0x01b16800,
+    0x01bc000a, 0x01bc6b00, 0x01bc700a, 0x01bc7d00, 0x01bc800a, 0x01bc8900, 0x01bc900a, 0x01bc9a00,
+    0x01bc9d04, 0x01bc9f00, 0x01bca007, 0x01bca400, 0x01ccf010, 0x01ccfa00, 0x01cf0004, 0x01cf2e00,
+    0x01cf3004, 0x01cf4700, 0x01d16504, 0x01d16a00, 0x01d16d04, 0x01d17307, 0x01d17b04, 0x01d18300,
+    0x01d18504, 0x01d18c00, 0x01d1aa04, 0x01d1ae00, 0x01d24204, 0x01d24500, 0x01d4000a, 0x01d45500,
+    0x01d4560a, 0x01d49d00, 0x01d49e0a, 0x01d4a000, 0x01d4a20a, 0x01d4a300, 0x01d4a50a, 0x01d4a700,
+    0x01d4a90a, 0x01d4ad00, 0x01d4ae0a, 0x01d4ba00, 0x01d4bb0a, 0x01d4bc00, 0x01d4bd0a, 0x01d4c400,
+    0x01d4c50a, 0x01d50600, 0x01d5070a, 0x01d50b00, 0x01d50d0a, 0x01d51500, 0x01d5160a, 0x01d51d00,
+    0x01d51e0a, 0x01d53a00, 0x01d53b0a, 0x01d53f00, 0x01d5400a, 0x01d54500, 0x01d5460a, 0x01d54700,
+    0x01d54a0a, 0x01d55100, 0x01d5520a, 0x01d6a600, 0x01d6a80a, 0x01d6c100, 0x01d6c20a, 0x01d6db00,
+    0x01d6dc0a, 0x01d6fb00, 0x01d6fc0a, 0x01d71500, 0x01d7160a, 0x01d73500, 0x01d7360a, 0x01d74f00,
+    0x01d7500a, 0x01d76f00, 0x01d7700a, 0x01d78900, 0x01d78a0a, 0x01d7a900, 0x01d7aa0a, 0x01d7c300,
+    0x01d7c40a, 0x01d7cc00, 0x01d7ce10, 0x01d80000, 0x01da0004, 0x01da3700, 0x01da3b04, 0x01da6d00,
+    0x01da7504, 0x01da7600, 0x01da8404, 0x01da8500, 0x01da9b04, 0x01daa000, 0x01daa104, 0x01dab000,
+    0x01df000a, 0x01df1f00, 0x01df250a, 0x01df2b00, 0x01e00004, 0x01e00700, 0x01e00804, 0x01e01900,
+    0x01e01b04, 0x01e02200, 0x01e02304, 0x01e02500, 0x01e02604, 0x01e02b00, 0x01e0300a, 0x01e06e00,
+    0x01e08f04, 0x01e09000, 0x01e1000a, 0x01e12d00, 0x01e13004, 0x01e1370a, 0x01e13e00, 0x01e14010,
+    0x01e14a00, 0x01e14e0a, 0x01e14f00, 0x01e2900a, 0x01e2ae04, 0x01e2af00, 0x01e2c00a, 0x01e2ec04,
+    0x01e2f010, 0x01e2fa00, 0x01e4d00a, 0x01e4ec04, 0x01e4f010, 0x01e4fa00, 0x01e5d00a, 0x01e5ee04,
+    0x01e5f00a, 0x01e5f110, 0x01e5fb00, 0x01e7e00a, 0x01e7e700, 0x01e7e80a, 0x01e7ec00, 0x01e7ed0a,
+    0x01e7ef00, 0x01e7f00a, 0x01e7ff00, 0x01e8000a, 0x01e8c500, 0x01e8d004, 0x01e8d700, 0x01e9000a,
+    0x01e94404, 0x01e94b0a, 0x01e94c00, 0x01e95010, 0x01e95a00, 0x01ee000a, 0x01ee0400, 0x01ee050a,
+    0x01ee2000, 0x01ee210a, 0x01ee2300, 0x01ee240a, 0x01ee2500, 0x01ee270a, 0x01ee2800, 0x01ee290a,
+    0x01ee3300, 0x01ee340a, 0x01ee3800, 0x01ee390a, 0x01ee3a00, 0x01ee3b0a, 0x01ee3c00, 0x01ee420a,
+    0x01ee4300, 0x01ee470a, 0x01ee4800, 0x01ee490a, 0x01ee4a00, 0x01ee4b0a, 0x01ee4c00, 0x01ee4d0a,
+    0x01ee5000, 0x01ee510a, 0x01ee5300, 0x01ee540a, 0x01ee5500, 0x01ee570a, 0x01ee5800, 0x01ee590a,
+    0x01ee5a00, 0x01ee5b0a, 0x01ee5c00, 0x01ee5d0a, 0x01ee5e00, 0x01ee5f0a, 0x01ee6000, 0x01ee610a,
+    0x01ee6300, 0x01ee640a, 0x01ee6500, 0x01ee670a, 0x01ee6b00, 0x01ee6c0a, 0x01ee7300, 0x01ee740a,
+    0x01ee7800, 0x01ee790a, 0x01ee7d00, 0x01ee7e0a, 0x01ee7f00, 0x01ee800a, 0x01ee8a00, 0x01ee8b0a,
+    0x01ee9c00, 0x01eea10a, 0x01eea400, 0x01eea50a, 0x01eeaa00, 0x01eeab0a, 0x01eebc00, 0x01f1300a,
+    0x01f14a00, 0x01f1500a, 0x01f16a00, 0x01f1700a, 0x01f18a00, 0x01f1e606, 0x01f20000, 0x01f3fb04,
+    0x01f40000, 0x01fbf010, 0x01fbfa00, 0x0e000107, 0x0e000200, 0x0e002004, 0x0e008000, 0x0e010004,
+    0x0e01f000,
+];
@@ -0,0 +1,7 @@
+pub(crate) struct SegmentationTest {
+    pub desc: &'static str,
+    pub input: &'static [u8],
+    pub expected: &'static [&'static [u8]],
+}
+
+pub(crate) static UNICODE_WORD_BREAK_TESTS: &[SegmentationTest] = &[];
@@ -0,0 +1,142 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn words(s: &str) -> Vec<&str> {
+    words_in_str(s).collect()
+}
+
+#[test]
+fn properties() {
+    assert_eq!(WBProperty::of_char('a'), WBProperty::ALetter);
+    assert_eq!(WBProperty::of_char('א'), WBProperty::HebrewLetter);
+    assert_eq!(WBProperty::of_char('カ'), WBProperty::Katakana);
+    assert_eq!(WBProperty::of_char('5'), WBProperty::Numeric);
+    assert_eq!(WBProperty::of_char('.'), WBProperty::MidNumLet);
+    assert_eq!(WBProperty::of_char(':'), WBProperty::MidLetter);
+    assert_eq!(WBProperty::of_char(','), WBProperty::MidNum);
+    assert_eq!(WBProperty::of_char('\''), WBProperty::SingleQuote);
+    assert_eq!(WBProperty::of_char('"'), WBProperty::DoubleQuote);
+    assert_eq!(WBProperty::of_char('_'), WBProperty::ExtendNumLet);
+    assert_eq!(WBProperty::of_char(' '), WBProperty::WSegSpace);
+    assert_eq!(WBProperty::of_char('\r'), WBProperty::CR);
+    assert_eq!(WBProperty::of_char('\n'), WBProperty::LF);
+    assert_eq!(WBProperty::of_char('\u{85}'), WBProperty::Newline);
+    assert_eq!(WBProperty::of_char('\u{301}'), WBProperty::Extend);
+    assert_eq!(WBProperty::of_char('\u{200D}'), WBProperty::ZWJ);
+    assert_eq!(WBProperty::of_char('\u{AD}'), WBProperty::Format);
+    assert_eq!(WBProperty::of_char('🇦'), WBProperty::RegionalIndicator);
+    assert_eq!(WBProperty::of_char('!'), WBProperty::None);
+    assert_eq!(WBProperty::of_char('\u{10FFFF}'), WBProperty::None);
+}
+
+#[test]
+fn sentence() {
+    // The example from UAX #29 Section 4.
+    assert_eq!(
+        words("The quick (\"brown\") fox can't jump 32.3 feet, right?"),
+        [
+            "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't", " ",
+            "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?",
+        ]
+    );
+}
+
+#[test]
+fn deferred() {
+    assert_eq!(words("e.g."), ["e.g", "."]);
+    assert_eq!(words("3,000.5"), ["3,000.5"]);
+    assert_eq!(words("3,"), ["3", ","]);
+    assert_eq!(words("a.."), ["a", ".", "."]);
+    assert_eq!(words("a.\u{301}b"), ["a.\u{301}b"]);
+    assert_eq!(words("a.\u{301}"), ["a", ".\u{301}"]);
+    assert_eq!(words("a. b"), ["a", ".", " ", "b"]);
+    assert_eq!(words("א\"ב"), ["א\"ב"]);
+    assert_eq!(words("א\"a"), ["א", "\"", "a"]);
+    assert_eq!(words("א'"), ["א'"]);
+    assert_eq!(words("a'"), ["a", "'"]);
+}
+
+#[test]
+fn other_rules() {
+    assert_eq!(words("  a"), ["  ", "a"]);
+    assert_eq!(words("a\r\nb"), ["a", "\r\n", "b"]);
+    assert_eq!(words("\n\n"), ["\n", "\n"]);
+    assert_eq!(words("👩\u{200D}💻 x"), ["👩\u{200D}💻", " ", "x"]);
+    assert_eq!(words("🇦🇨🇦"), ["🇦🇨", "🇦"]);
+    assert_eq!(words("🇦\u{301}🇨🇦🇨"), ["🇦\u{301}🇨", "🇦🇨"]);
+    assert_eq!(words("カタカナ漢字"), ["カタカナ", "漢", "字"]);
+    assert_eq!(words("foo_bar1 x1.5"), ["foo_bar1", " ", "x1.5"]);
+    assert_eq!(words(""), [] as [&str; 0]);
+}
+
+#[test]
+fn actions() {
+    let mut machine = WordMachine::new();
+    assert_eq!(machine.next_char('a'), WordAction::Split);
+    assert_eq!(machine.next_char('.'), WordAction::Undecided);
+    assert_eq!(machine.next_char('\u{301}'), WordAction::Continue);
+    assert_eq!(
+        machine.next_char('b'),
+        WordAction::Resolved {
+            undecided_split: false,
+            split: false,
+        }
+    );
+    assert_eq!(machine.next_char('.'), WordAction::Undecided);
+    assert_eq!(
+        machine.next_char(','),
+        WordAction::Resolved {
+            undecided_split: true,
+            split: true,
+        }
+    );
+    assert_eq!(machine.end_of_input(), WordAction::Split);
+
+    assert_eq!(machine.next_char('1'), WordAction::Split);
+    assert_eq!(machine.next_char(','), WordAction::Undecided);
+    assert_eq!(
+        machine.end_of_input(),
+        WordAction::Resolved {
+            undecided_split: true,
+            split: true,
+        }
+    );
+    assert_eq!(machine.next_char('1'), WordAction::Split);
+
+    assert!(WordAction::Split.is_boundary());
+    assert!(!WordAction::Undecided.is_boundary());
+    assert!(
+        !WordAction::Resolved {
+            undecided_split: true,
+            split: false,
+        }
+        .is_boundary()
+    );
+}
+
+#[test]
+fn unicode_test_table() {
+    // `test_table.rs` is generated from `WordBreakTest.txt` by
+    // `cargo xtask generate-word-break-tests`.
+    let mut failures = 0;
+    for test in test_table::UNICODE_WORD_BREAK_TESTS {
+        let input = str::from_utf8(test.input).expect("invalid UTF-8 in test input");
+        let got = words(input);
+        let want: Vec<&str> = test
+            .expected
+            .iter()
+            .map(|s| str::from_utf8(s).expect("invalid UTF-8 in expected result"))
+            .collect();
+        if got != want {
+            println!("- test failed: {}", test.desc);
+            println!("  input: {:x?}", test.input);
+            println!("  got:   {:?}", got);
+            println!("  want:  {:?}", want);
+            failures += 1;
+        }
+    }
+    if failures != 0 {
+        panic!("{failures} tests failed");
+    }
+}
@@ -0,0 +1,83 @@
+use core::fmt;
+
+use ::u8char::AsU8Chars;
+
+use crate::{BufferedGraphemeMachine, GraphemeMachine, Segmenter};
+
+/// An implementation of [`fmt::Write`] that segments everything written
+/// through it into grapheme clusters, passing each one to a callback once
+/// it's complete.
+///
+/// This puts a [`BufferedGraphemeMachine`] behind [`write!`], so that code
+/// which already formats its output, such as the rendering code of a
+/// terminal emulator, can receive it one cluster at a time without being
+/// restructured. To forward the clusters to another [`fmt::Write`], use a
+/// callback that calls its [`fmt::Write::write_str`].
+///
+/// An error returned by the callback is returned from the write that
+/// completed the cluster. As with [`BufferedGraphemeMachine`], a cluster
+/// longer than `N` bytes is passed to the callback as a single U+FFFD
+/// REPLACEMENT CHARACTER.
+///
+/// A cluster is only known to be complete once the next one begins, so
+/// call [`Self::finish`] after the last write to pass on the final cluster.
+#[derive(Debug)]
+pub struct ClusterWriter<F, const N: usize, S = GraphemeMachine> {
+    machine: BufferedGraphemeMachine<N, S>,
+    f: F,
+}
+
+impl<F: FnMut(&str) -> fmt::Result, const N: usize> ClusterWriter<F, N> {
+    /// Constructs a writer that passes each complete cluster to `f`, using
+    /// a new [`GraphemeMachine`] in its initial "start of input" state.
+    pub fn new(f: F) -> Self {
+        Self::with_machine(GraphemeMachine::new(), f)
+    }
+}
+
+impl<F: FnMut(&str) -> fmt::Result, const N: usize, S: Segmenter> ClusterWriter<F, N, S> {
+    /// Constructs a writer that passes each complete cluster to `f`, using
+    /// the given machine to find the cluster boundaries.
+    ///
+    /// The given machine should be in its "start of input" state.
+    pub fn with_machine(machine: S, f: F) -> Self {
+        Self {
+            machine: BufferedGraphemeMachine::with_machine(machine),
+            f,
+        }
+    }
+
+    /// Signals the end of the text, passing the final cluster to the
+    /// callback if any text was written since the previous cluster.
+    ///
+    /// The writer is then back in its "start of input" state, and so
+    /// anything written afterwards begins a new cluster.
+    pub fn finish(&mut self) -> fmt::Result {
+        match self.machine.end_of_input() {
+            Some(cluster) => (self.f)(cluster),
+            None => Ok(()),
+        }
+    }
+
+    /// Consumes the writer and returns the callback it was using,
+    /// discarding any cluster still in progress.
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+impl<F: FnMut(&str) -> fmt::Result, const N: usize, S: Segmenter> fmt::Write
+    for ClusterWriter<F, N, S>
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.u8chars() {
+            if let Some(cluster) = self.machine.next_u8char(c) {
+                (self.f)(cluster)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
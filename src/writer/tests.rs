@@ -0,0 +1,50 @@
+use super::*;
+
+use core::fmt::Write;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn write_clusters() {
+    let mut got = Vec::new();
+    let mut writer = ClusterWriter::<_, 16>::new(|cluster: &str| {
+        got.push(cluster.to_string());
+        Ok(())
+    });
+    // A cluster can be split across writes, and across the pieces of a
+    // single formatted write.
+    let (cr, e) = ('\r', 'e');
+    write!(writer, "a{cr}\n{e}").unwrap();
+    writer.write_str("\u{0301}🇦").unwrap();
+    writer.write_char('🇧').unwrap();
+    writer.finish().unwrap();
+    assert_eq!(got, &["a", "\r\n", "e\u{0301}", "🇦🇧"]);
+}
+
+#[test]
+fn forward_to_writer() {
+    let mut out = String::new();
+    let mut writer = ClusterWriter::<_, 4>::new(|cluster: &str| {
+        out.write_char('[')?;
+        out.write_str(cluster)?;
+        out.write_char(']')
+    });
+    // The farmer emoji doesn't fit in four bytes, and so is replaced.
+    write!(writer, "x🧑\u{200D}🌾y").unwrap();
+    writer.finish().unwrap();
+    assert_eq!(out, "[x][\u{FFFD}][y]");
+}
+
+#[test]
+fn callback_errors() {
+    let mut count = 0;
+    {
+        let mut writer = ClusterWriter::<_, 16>::new(|_: &str| {
+            count += 1;
+            if count == 2 { Err(fmt::Error) } else { Ok(()) }
+        });
+        assert_eq!(writer.write_str("ab"), Ok(()));
+        assert_eq!(writer.write_str("c"), Err(fmt::Error));
+        assert_eq!(writer.finish(), Ok(()));
+    }
+    assert_eq!(count, 3);
+}
@@ -0,0 +1,132 @@
+use core::iter::FusedIterator;
+
+use u8char::u8char;
+
+use crate::{
+    CharProperties, ClusterAction, GCBProperty, GraphemeMachine, InCBProperty, InvalidUtf8Error,
+};
+
+/// A code point decoded from [WTF-8](https://simonsapin.github.io/wtf-8/),
+/// which is either a character or a surrogate code point that isn't part
+/// of a pair.
+///
+/// WTF-8 is how Rust represents `OsStr` on Windows, and is a common way to
+/// store potentially ill-formed UTF-16 from JavaScript engines as bytes.
+/// It's the same as UTF-8 except that it can also encode surrogates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wtf8CodePoint {
+    /// A character, just as it would appear in UTF-8.
+    Char(u8char),
+    /// An unpaired surrogate code point, between U+D800 and U+DFFF.
+    Surrogate(u16),
+}
+
+impl Wtf8CodePoint {
+    /// Returns the code point as an integer.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            Self::Char(c) => c.to_char() as u32,
+            Self::Surrogate(cp) => cp as u32,
+        }
+    }
+
+    /// Returns the number of bytes the code point occupies in WTF-8.
+    pub const fn len_wtf8(self) -> usize {
+        match self {
+            Self::Char(c) => c.len(),
+            Self::Surrogate(_) => 3,
+        }
+    }
+
+    /// Returns the properties that [`GraphemeMachine`] uses to segment the
+    /// code point.
+    ///
+    /// UAX #29 gives surrogates the **Grapheme_Cluster_Break** property
+    /// value **Control**, and so an unpaired surrogate is always a grapheme
+    /// cluster by itself, just like a control character.
+    pub const fn properties(self) -> CharProperties {
+        match self {
+            Self::Char(c) => CharProperties::for_u8char(c),
+            Self::Surrogate(_) => CharProperties::new(GCBProperty::Control, InCBProperty::None),
+        }
+    }
+}
+
+impl GraphemeMachine {
+    /// Behaves the same as [`Self::next_u8chars_from_utf8`] except that it
+    /// accepts WTF-8, which can also contain unpaired surrogates, yielding
+    /// each [`Wtf8CodePoint`] along with the action it caused.
+    ///
+    /// Each unpaired surrogate is segmented as described for
+    /// [`Wtf8CodePoint::properties`]. A high surrogate immediately followed
+    /// by a low surrogate isn't valid WTF-8, because the pair should have
+    /// been encoded as the supplementary character it represents, and so
+    /// the low surrogate is reported as an invalid sequence.
+    ///
+    /// If any part of `bytes` is invalid then this returns an error without
+    /// advancing the state machine at all, even for the valid prefix.
+    pub fn next_code_points_from_wtf8<'a>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> Result<impl FusedIterator<Item = (ClusterAction, Wtf8CodePoint)> + 'a, InvalidUtf8Error>
+    {
+        validate_wtf8(bytes)?;
+        let mut remain = bytes;
+        Ok(core::iter::from_fn(move || {
+            let next = match u8char::from_bytes_prefix(remain) {
+                (Ok(next), rest) => {
+                    remain = rest;
+                    Wtf8CodePoint::Char(next?)
+                }
+                // The input was already validated, so this can only be a
+                // surrogate.
+                (Err(_), _) => {
+                    let (seq, rest) = remain.split_at(3);
+                    remain = rest;
+                    Wtf8CodePoint::Surrogate(decode_surrogate(seq))
+                }
+            };
+            Some((self.next_char_properties(next.properties()), next))
+        })
+        .fuse())
+    }
+}
+
+fn validate_wtf8(bytes: &[u8]) -> Result<(), InvalidUtf8Error> {
+    let mut start = 0;
+    // Whether the code point just before `start` was a high surrogate.
+    let mut after_high = false;
+    loop {
+        let err = match core::str::from_utf8(&bytes[start..]) {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+        let at = start + err.valid_up_to();
+        let invalid = |error_len| InvalidUtf8Error {
+            valid_up_to: at,
+            error_len,
+        };
+        if bytes[at] != 0xed || !matches!(bytes.get(at + 1), Some(0xa0..=0xbf)) {
+            return Err(invalid(err.error_len()));
+        }
+        let Some(&third) = bytes.get(at + 2) else {
+            return Err(invalid(None));
+        };
+        if third & 0xc0 != 0x80 {
+            return Err(invalid(Some(2)));
+        }
+        let low = bytes[at + 1] >= 0xb0;
+        if low && after_high && err.valid_up_to() == 0 {
+            return Err(invalid(Some(3)));
+        }
+        after_high = !low;
+        start = at + 3;
+    }
+}
+
+fn decode_surrogate(seq: &[u8]) -> u16 {
+    (u16::from(seq[0] & 0x0f) << 12) | (u16::from(seq[1] & 0x3f) << 6) | u16::from(seq[2] & 0x3f)
+}
+
+#[cfg(test)]
+mod tests;
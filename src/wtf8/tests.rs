@@ -0,0 +1,73 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+use crate::ClusterAction::*;
+use crate::GCBProperty;
+
+fn code_points(bytes: &[u8]) -> Result<Vec<(ClusterAction, u32)>, InvalidUtf8Error> {
+    let mut machine = GraphemeMachine::new();
+    let got = machine.next_code_points_from_wtf8(bytes)?;
+    Ok(got.map(|(a, cp)| (a, cp.to_u32())).collect())
+}
+
+#[test]
+fn utf8() {
+    let s = "ae\u{0301}🇦🇧\r\n";
+    let mut machine = GraphemeMachine::new();
+    let want: Vec<_> = machine
+        .next_chars_from_str(s)
+        .map(|(a, c)| (a, c as u32))
+        .collect();
+    assert_eq!(code_points(s.as_bytes()), Ok(want));
+}
+
+#[test]
+fn surrogates() {
+    let bytes = [
+        b'a', // a
+        0xed, 0xa0, 0xbd, // U+D83D
+        0xcc, 0x81, // U+0301, which can't extend a surrogate
+        0xed, 0xb8, 0x80, // U+DE00
+        0xed, 0xb8, 0x80, // U+DE00 again, which isn't paired either
+        0xed, 0xa0, 0x80, // U+D800
+    ];
+    assert_eq!(
+        code_points(&bytes),
+        Ok(vec![
+            (Split, 0x61),
+            (Split, 0xd83d),
+            (Split, 0x0301),
+            (Split, 0xde00),
+            (Split, 0xde00),
+            (Split, 0xd800),
+        ])
+    );
+
+    let mut machine = GraphemeMachine::new();
+    let got: Vec<_> = machine
+        .next_code_points_from_wtf8(&bytes[..4])
+        .unwrap()
+        .map(|(_, cp)| (cp.len_wtf8(), cp.properties().gcb_property()))
+        .collect();
+    assert_eq!(got, &[(1, GCBProperty::None), (3, GCBProperty::Control)]);
+}
+
+#[test]
+fn invalid() {
+    let err = |valid_up_to, error_len| {
+        Err(InvalidUtf8Error {
+            valid_up_to,
+            error_len,
+        })
+    };
+    // An encoded surrogate pair should have been a supplementary character.
+    assert_eq!(
+        code_points(&[b'a', 0xed, 0xa0, 0xbd, 0xed, 0xb8, 0x80]),
+        err(4, Some(3))
+    );
+    assert_eq!(code_points(&[b'a', 0xed, 0xa0]), err(1, None));
+    assert_eq!(code_points(&[b'a', 0xed, 0xa0, b'b']), err(1, Some(2)));
+    assert_eq!(code_points(&[0xed, 0xa0, 0x80, 0xff]), err(3, Some(1)));
+    assert_eq!(code_points(&[0xc0, 0x80]), err(0, Some(1)));
+}
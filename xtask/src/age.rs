@@ -0,0 +1,114 @@
+//! Generates the **Age** property table in `src/age/table.rs` from the
+//! `DerivedAge.txt` file of the Unicode character database.
+//!
+//! Unlike the grapheme cluster break properties, the ages of neighbouring
+//! characters vary too much for the leaves of a UTF-8 trie to be addressed
+//! by 8-bit pointers, and so this table is instead a sorted list of runs of
+//! characters with the same age. Each run is a `u32` holding its first code
+//! point shifted left by eight bits, with the age of all of the characters
+//! up to the start of the next run in the low eight bits. That value
+//! encodes the version as `major << 2 | minor`, or is zero for unassigned
+//! characters, as decoded by `UnicodeVersion::from_table_value` in the main
+//! crate.
+
+use crate::ucd;
+
+/// A range of characters that were all assigned in the same version, along
+/// with the encoded table value for that version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AgeRange {
+    pub start: u32,
+    pub end: u32,
+    pub value: u8,
+}
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "AGE_RUNS";
+
+/// Parses the content of `DerivedAge.txt`, whose lines each give a
+/// character or range of characters and the version it was assigned in,
+/// like `0600..0604    ; 6.1 #  [5] ARABIC NUMBER SIGN..`.
+///
+/// The file lists the ranges grouped by version, but the result is sorted
+/// by code point.
+pub fn parse(src: &str) -> Result<Vec<AgeRange>, String> {
+    let mut ranges = Vec::new();
+    for line in ucd::parse_lines(src)? {
+        let value = encode(line.value).map_err(|msg| line.error(&msg))?;
+        ranges.push((line.start, line.end, value));
+    }
+    ucd::sort_ranges(&mut ranges)?;
+    Ok(ranges
+        .into_iter()
+        .map(|(start, end, value)| AgeRange { start, end, value })
+        .collect())
+}
+
+/// Returns the table value for a version written as `major.minor`.
+pub fn encode(version: &str) -> Result<u8, String> {
+    let invalid = || format!("invalid version {version:?}");
+    let (major, minor) = version.split_once('.').ok_or_else(invalid)?;
+    let major: u8 = major.parse().map_err(|_| invalid())?;
+    let minor: u8 = minor.parse().map_err(|_| invalid())?;
+    if major == 0 || major > 63 || minor > 3 {
+        return Err(format!("version {version:?} can't be encoded in the table"));
+    }
+    Ok(major << 2 | minor)
+}
+
+/// Returns the table value for the given character according to `ranges`,
+/// which must be sorted as returned by [`parse`].
+pub fn value_for(ranges: &[AgeRange], c: char) -> u8 {
+    ucd::value_for(&tuples(ranges), c, 0)
+}
+
+/// Returns the runs for the given sorted ranges, filling the gaps between
+/// them with runs of unassigned characters.
+pub fn runs(ranges: &[AgeRange]) -> Vec<u32> {
+    ucd::runs(&tuples(ranges), 0)
+}
+
+fn tuples(ranges: &[AgeRange]) -> Vec<(u32, u32, u8)> {
+    ranges.iter().map(|r| (r.start, r.end, r.value)).collect()
+}
+
+/// The generated age table, along with the handwritten source code that
+/// appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `age_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
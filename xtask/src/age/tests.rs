@@ -0,0 +1,97 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parse_derived_age() {
+    let src = "\
+# DerivedAge-16.0.0.txt
+
+0000..001F    ; 1.1 #  [32] <control-0000>..<control-001F>
+00AD          ; 3.2 #       SOFT HYPHEN
+
+1FAE9         ; 16.0 #      FACE WITH BAGS UNDER EYES
+";
+    let got = parse(src).unwrap();
+    assert_eq!(
+        got,
+        &[
+            AgeRange {
+                start: 0x0000,
+                end: 0x001F,
+                value: 1 << 2 | 1,
+            },
+            AgeRange {
+                start: 0x00AD,
+                end: 0x00AD,
+                value: 3 << 2 | 2,
+            },
+            AgeRange {
+                start: 0x1FAE9,
+                end: 0x1FAE9,
+                value: 16 << 2,
+            },
+        ]
+    );
+    assert_eq!(value_for(&got, '\u{1F}'), 5);
+    assert_eq!(value_for(&got, ' '), 0);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("0041 1.1").unwrap_err(), "line 1: missing ';'");
+    assert_eq!(
+        parse("\n0041 ; 1.4").unwrap_err(),
+        "line 2: version \"1.4\" can't be encoded in the table"
+    );
+    assert_eq!(
+        parse("0042..0041 ; 1.1").unwrap_err(),
+        "line 1: invalid range"
+    );
+    assert_eq!(
+        parse("0041..0043 ; 1.1\n0043 ; 2.0").unwrap_err(),
+        "overlapping ranges at 0043"
+    );
+}
+
+#[test]
+fn build_runs() {
+    let ranges = parse("1F600 ; 6.1\n0041..005A ; 1.1\n005B ; 1.1\n00E9 ; 1.1\n").unwrap();
+    let table = Table {
+        preamble: String::new(),
+        runs: runs(&ranges),
+    };
+    assert_eq!(
+        table.runs,
+        &[
+            run(0x0000, 0),
+            run(0x0041, 5),
+            run(0x005c, 0),
+            run(0x00e9, 5),
+            run(0x00ea, 0),
+            run(0x1f600, 6 << 2 | 1),
+            run(0x1f601, 0),
+        ]
+    );
+    assert_eq!(table.lookup('\0'), 0);
+    assert_eq!(table.lookup('A'), 5);
+    assert_eq!(table.lookup('['), 5);
+    assert_eq!(table.lookup('a'), 0);
+    assert_eq!(table.lookup('\u{E9}'), 5);
+    assert_eq!(table.lookup('\u{1F600}'), 6 << 2 | 1);
+    assert_eq!(table.lookup(char::MAX), 0);
+}
+
+#[test]
+fn render_round_trip() {
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: vec![run(0x0000, 0), run(0x0041, 5), run(0x10ffff, 0)],
+    };
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}
+
+fn run(start: u32, value: u8) -> u32 {
+    start << 8 | value as u32
+}
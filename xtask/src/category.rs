@@ -0,0 +1,115 @@
+//! Generates the **General_Category** property table in
+//! `src/category/table.rs` from the `UnicodeData.txt` file of the Unicode
+//! character database.
+//!
+//! Neighbouring characters alternate between categories too often for the
+//! leaves of a UTF-8 trie to be addressed by 8-bit pointers, such as the
+//! upper and lower case letters of Latin Extended-A, and so this uses the
+//! same sorted list of runs as the **Age** table, with the category of all
+//! of the characters up to the start of the next run in the low eight bits
+//! of each run, encoded as by [`encode`].
+
+use crate::ucd;
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "CATEGORY_RUNS";
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// Parses the content of `UnicodeData.txt`, whose lines each begin with a
+/// code point, a name and a category, like `0041;LATIN CAPITAL LETTER
+/// A;Lu;...`, returning the encoded category of every code point in order.
+///
+/// Ranges of characters are given by a pair of lines whose names are like
+/// `<CJK Ideograph Extension A, First>` and `<..., Last>`, and characters
+/// that aren't listed are unassigned.
+pub fn parse(src: &str) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    let mut range_start: Option<(u32, u8)> = None;
+    for (i, line) in src.lines().enumerate() {
+        let err = |msg: &str| format!("line {}: {msg}", i + 1);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';');
+        let cp = fields.next().unwrap();
+        let cp = u32::from_str_radix(cp, 16).map_err(|e| err(&e.to_string()))?;
+        let name = fields.next().ok_or_else(|| err("missing name"))?;
+        let category = fields.next().ok_or_else(|| err("missing category"))?;
+        if cp > char::MAX as u32 {
+            return Err(err("invalid code point"));
+        }
+        let value = encode(category).map_err(|msg| err(&msg))?;
+
+        if let Some((start, start_value)) = range_start.take() {
+            if !name.ends_with(", Last>") || value != start_value {
+                return Err(err("expected the end of the range"));
+            }
+            values[start as usize..=cp as usize].fill(value);
+        } else if name.ends_with(", First>") {
+            range_start = Some((cp, value));
+        } else {
+            values[cp as usize] = value;
+        }
+    }
+    if range_start.is_some() {
+        return Err("unterminated range at end of file".to_string());
+    }
+    Ok(values)
+}
+
+/// Returns the table value for a short **General_Category** property value
+/// alias, in the same order as the `GeneralCategory` enum in the main
+/// crate.
+pub fn encode(value: &str) -> Result<u8, String> {
+    const ALIASES: [&str; 30] = [
+        "Cn", "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps",
+        "Pe", "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co",
+    ];
+    match ALIASES.iter().position(|&alias| alias == value) {
+        Some(i) => Ok(i as u8),
+        None => Err(format!("invalid General_Category {value:?}")),
+    }
+}
+
+/// The generated category table, along with the handwritten source code that
+/// appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `category_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parse_unicode_data() {
+    let src = "\
+0030;DIGIT ZERO;Nd;0;EN;;0;0;0;N;;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+0061;LATIN SMALL LETTER A;Ll;0;L;;;;;N;;;0041;;0041
+3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;
+4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;
+";
+    let got = parse(src).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x2f], 0);
+    assert_eq!(got[0x30], 9);
+    assert_eq!(got[0x41], 1);
+    assert_eq!(got[0x61], 2);
+    assert_eq!(got[0x3400], 5);
+    assert_eq!(got[0x4000], 5);
+    assert_eq!(got[0x4dbf], 5);
+    assert_eq!(got[0x4dc0], 0);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("0041").unwrap_err(), "line 1: missing name");
+    assert_eq!(parse("0041;A").unwrap_err(), "line 1: missing category");
+    assert_eq!(
+        parse("\n0041;A;Xx").unwrap_err(),
+        "line 2: invalid General_Category \"Xx\""
+    );
+    assert_eq!(
+        parse("3400;<X, First>;Lo\n3401;B;Lo").unwrap_err(),
+        "line 2: expected the end of the range"
+    );
+    assert_eq!(
+        parse("3400;<X, First>;Lo").unwrap_err(),
+        "unterminated range at end of file"
+    );
+}
+
+#[test]
+fn render_round_trip() {
+    let values = parse("0041;A;Lu\n0042;B;Lu\n0061;a;Ll\n").unwrap();
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: ucd::value_runs(&values),
+    };
+    assert_eq!(table.runs, &[0x0000, 0x4101, 0x4300, 0x6102, 0x6200]);
+    assert_eq!(table.lookup('B'), 1);
+    assert_eq!(table.lookup('a'), 2);
+    assert_eq!(table.lookup(char::MAX), 0);
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}
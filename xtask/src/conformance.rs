@@ -0,0 +1,112 @@
+//! Generates the conformance test tables like `src/word/test_table.rs`
+//! from the `*BreakTest.txt` files of the Unicode character database, in
+//! the same format as the grapheme cluster tests in
+//! `src/properties/test_table.rs`.
+
+use std::fmt::Write;
+
+/// A test case from one line of a `*BreakTest.txt` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Test {
+    pub desc: String,
+    pub input: String,
+    pub expected: Vec<String>,
+}
+
+/// Parses lines like `÷ 0041 × 0308 ÷ 0020 ÷ # ÷ [0.2] LATIN CAPITAL..`,
+/// where `÷` marks a boundary and `×` marks a position that isn't one,
+/// skipping comments and blank lines.
+///
+/// The mark before the first character is ignored, because the tables
+/// don't distinguish the start of the text from a boundary.
+pub fn parse(src: &str) -> Result<Vec<Test>, String> {
+    let mut ret = Vec::new();
+    for (i, line) in src.lines().enumerate() {
+        let (line, desc) = line.split_once('#').unwrap_or((line, ""));
+        if line.trim().is_empty() {
+            continue;
+        }
+        let err = |msg: &str| format!("line {}: {msg}", i + 1);
+        let mut input = String::new();
+        let mut expected = Vec::new();
+        let mut start = 0;
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => {
+                    if start < input.len() {
+                        expected.push(input[start..].to_string());
+                    }
+                    start = input.len();
+                }
+                "×" => {}
+                _ => {
+                    let c = u32::from_str_radix(token, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| err(&format!("invalid code point {token:?}")))?;
+                    input.push(c);
+                }
+            }
+        }
+        if start < input.len() {
+            return Err(err("missing '÷' at the end"));
+        }
+        ret.push(Test {
+            desc: desc.trim().to_string(),
+            input,
+            expected,
+        });
+    }
+    Ok(ret)
+}
+
+/// Renders the tests as Rust source code defining an array with the given
+/// name.
+pub fn render(name: &str, tests: &[Test]) -> String {
+    let mut ret = String::new();
+    ret.push_str(
+        "pub(crate) struct SegmentationTest {
+    pub desc: &'static str,
+    pub input: &'static [u8],
+    pub expected: &'static [&'static [u8]],
+}
+
+",
+    );
+    write!(ret, "pub(crate) static {name}: &[SegmentationTest] = &[").unwrap();
+    if tests.is_empty() {
+        ret.push_str("];\n");
+        return ret;
+    }
+    ret.push('\n');
+    for test in tests {
+        ret.push_str("    SegmentationTest {\n");
+        writeln!(ret, "        desc: {:?},", test.desc).unwrap();
+        let input = byte_string(&test.input);
+        writeln!(ret, "        input: {input}, // {}", comment(&test.input)).unwrap();
+        ret.push_str("        expected: &[\n");
+        let width = test.expected.iter().map(|s| byte_string(s).len() + 1);
+        let width = width.max().unwrap_or(0);
+        for s in &test.expected {
+            let literal = format!("{},", byte_string(s));
+            writeln!(ret, "            {literal:width$} // {}", comment(s)).unwrap();
+        }
+        ret.push_str("        ],\n    },\n");
+    }
+    ret.push_str("];\n");
+    ret
+}
+
+/// Returns a byte string literal for the UTF-8 encoding of `s`.
+fn byte_string(s: &str) -> String {
+    format!("b\"{}\"", s.as_bytes().escape_ascii())
+}
+
+/// Returns `s` as a string literal for the comment after its byte string,
+/// which is easier to read than the bytes.
+fn comment(s: &str) -> String {
+    format!("\"{}\"", s.escape_debug())
+}
+
+#[cfg(test)]
+mod tests;
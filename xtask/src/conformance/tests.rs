@@ -0,0 +1,81 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const WORD_BREAK_TEST: &str = "\
+# WordBreakTest-16.0.0.txt
+
+÷ 0001 ÷ 0001 ÷	#  ÷ [0.2] <START OF HEADING> (Other) ÷ [999.0] <START OF HEADING> (Other) ÷ [0.3]
+÷ 0041 × 0308 ÷ 0020 ÷	#  ÷ [0.2] LATIN CAPITAL LETTER A (ALetter) × [4.0] COMBINING DIAERESIS (Extend_FE) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]
+";
+
+#[test]
+fn parse_tests() {
+    assert_eq!(
+        parse(WORD_BREAK_TEST).unwrap(),
+        [
+            Test {
+                desc: "÷ [0.2] <START OF HEADING> (Other) ÷ [999.0] <START OF HEADING> (Other) ÷ [0.3]"
+                    .to_string(),
+                input: "\x01\x01".to_string(),
+                expected: vec!["\x01".to_string(), "\x01".to_string()],
+            },
+            Test {
+                desc: "÷ [0.2] LATIN CAPITAL LETTER A (ALetter) × [4.0] COMBINING DIAERESIS (Extend_FE) ÷ [999.0] SPACE (WSegSpace) ÷ [0.3]"
+                    .to_string(),
+                input: "A\u{308} ".to_string(),
+                expected: vec!["A\u{308}".to_string(), " ".to_string()],
+            },
+        ]
+    );
+    assert_eq!(parse("× 0023 × 0020 ÷").unwrap()[0].expected, ["# "]);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse("÷ 0041 × ZZZZ ÷").unwrap_err(),
+        "line 1: invalid code point \"ZZZZ\""
+    );
+    assert_eq!(
+        parse("\n÷ D800 ÷").unwrap_err(),
+        "line 2: invalid code point \"D800\""
+    );
+    assert_eq!(
+        parse("÷ 0041 × 0042").unwrap_err(),
+        "line 1: missing '÷' at the end"
+    );
+}
+
+#[test]
+fn render_tests() {
+    let tests = parse("÷ 0041 × 0308 ÷ 000D ÷ # A test").unwrap();
+    assert_eq!(
+        render("TESTS", &tests),
+        r#"pub(crate) struct SegmentationTest {
+    pub desc: &'static str,
+    pub input: &'static [u8],
+    pub expected: &'static [&'static [u8]],
+}
+
+pub(crate) static TESTS: &[SegmentationTest] = &[
+    SegmentationTest {
+        desc: "A test",
+        input: b"A\xcc\x88\r", // "Ä\r"
+        expected: &[
+            b"A\xcc\x88", // "Ä"
+            b"\r",        // "\r"
+        ],
+    },
+];
+"#
+    );
+}
+
+#[test]
+fn render_empty() {
+    assert_eq!(
+        render("TESTS", &[]).lines().last(),
+        Some("pub(crate) static TESTS: &[SegmentationTest] = &[];")
+    );
+}
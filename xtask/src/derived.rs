@@ -0,0 +1,55 @@
+//! Character properties that are added to the lookup tables on top of the
+//! grapheme cluster break properties, using data from the
+//! `DerivedCoreProperties.txt` and `PropList.txt` files of the Unicode
+//! character database.
+
+use crate::ucd;
+
+/// The bit of each table value that is set for characters with the
+/// **Default_Ignorable_Code_Point** property.
+pub const DEFAULT_IGNORABLE_BIT: u8 = 0x40;
+
+/// The bit of each table value that is set for characters with the
+/// **Variation_Selector** property.
+pub const VARIATION_SELECTOR_BIT: u8 = 0x80;
+
+/// Parses the contents of `DerivedCoreProperties.txt` and `PropList.txt`,
+/// returning the bits for the derived properties of every code point in
+/// order.
+///
+/// The other properties that the files list are ignored.
+pub fn parse(derived_core: &str, prop_list: &str) -> Result<Vec<u8>, String> {
+    let mut bits = vec![0; char::MAX as usize + 1];
+    for (src, property, bit) in [
+        (
+            derived_core,
+            "Default_Ignorable_Code_Point",
+            DEFAULT_IGNORABLE_BIT,
+        ),
+        (prop_list, "Variation_Selector", VARIATION_SELECTOR_BIT),
+    ] {
+        for line in ucd::parse_lines(src)? {
+            if line.value == property {
+                for b in &mut bits[line.start as usize..=line.end as usize] {
+                    *b |= bit;
+                }
+            }
+        }
+    }
+    Ok(bits)
+}
+
+/// Returns the table value for the given byte sequence after updating the
+/// bits for the derived properties from the result of [`parse`], given its
+/// current value.
+///
+/// Sequences that aren't valid UTF-8 keep their current values.
+pub fn apply(bits: &[u8], s: &[u8], value: u8) -> u8 {
+    let Some(c) = std::str::from_utf8(s).ok().and_then(|s| s.chars().next()) else {
+        return value;
+    };
+    value & !(DEFAULT_IGNORABLE_BIT | VARIATION_SELECTOR_BIT) | bits[c as usize]
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,49 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const DERIVED_CORE: &str = "\
+# @missing: 0000..10FFFF; InCB; None
+
+00AD          ; Default_Ignorable_Code_Point # Cf       SOFT HYPHEN
+0300..036F    ; InCB; Extend # Mn [112] COMBINING GRAVE ACCENT..
+FE00..FE0F    ; Default_Ignorable_Code_Point # Mn  [16] VARIATION SELECTOR-1..
+";
+
+const PROP_LIST: &str = "\
+0020          ; White_Space # Zs       SPACE
+FE00..FE0F    ; Variation_Selector # Mn  [16] VARIATION SELECTOR-1..
+E0100..E01EF  ; Variation_Selector # Mn [240] VARIATION SELECTOR-17..
+";
+
+#[test]
+fn parse_properties() {
+    let got = parse(DERIVED_CORE, PROP_LIST).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x20], 0x00);
+    assert_eq!(got[0xad], 0x40);
+    assert_eq!(got[0x301], 0x00);
+    assert_eq!(got[0xfe0f], 0xc0);
+    assert_eq!(got[0xe0100], 0x80);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse("00AD Default_Ignorable_Code_Point", "").unwrap_err(),
+        "line 1: missing ';'"
+    );
+    assert_eq!(
+        parse("", "\nFE0F..FE00; Variation_Selector").unwrap_err(),
+        "line 2: invalid range"
+    );
+}
+
+#[test]
+fn apply_values() {
+    let bits = parse(DERIVED_CORE, PROP_LIST).unwrap();
+    assert_eq!(apply(&bits, "\u{FE0F}".as_bytes(), 0x03), 0xc3);
+    assert_eq!(apply(&bits, "\u{AD}".as_bytes(), 0x82), 0x42);
+    assert_eq!(apply(&bits, b"A", 0xc0), 0x00);
+    assert_eq!(apply(&bits, b"\xed\xa0\x80", 0xc2), 0xc2);
+}
@@ -0,0 +1,87 @@
+//! Generates the emoji property table in `src/emoji/table.rs` from the
+//! `emoji-data.txt` file of the Unicode emoji data.
+//!
+//! This uses the same sorted list of runs as the **Age** table. The file
+//! defines several binary properties, and so the low eight bits of each
+//! run hold one bit for each property that all of the characters up to the
+//! start of the next run have, as given by [`encode`].
+
+use crate::ucd;
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "EMOJI_RUNS";
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// Parses the content of `emoji-data.txt`, returning the encoded property
+/// bits of every code point in order.
+///
+/// The file lists each character or range of characters once for each
+/// property it has, like `1F600..1F64F ; Emoji_Presentation # ..`, and
+/// characters that aren't listed have none of the properties.
+pub fn parse(src: &str) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    for line in ucd::parse_lines(src)? {
+        let bit = encode(line.value).map_err(|msg| line.error(&msg))?;
+        for value in &mut values[line.start as usize..=line.end as usize] {
+            *value |= bit;
+        }
+    }
+    Ok(values)
+}
+
+/// Returns the table bit for an emoji property name, matching the
+/// constants of `EmojiProperties` in the main crate.
+pub fn encode(property: &str) -> Result<u8, String> {
+    Ok(match property {
+        "Emoji" => 0x01,
+        "Emoji_Presentation" => 0x02,
+        "Emoji_Modifier" => 0x04,
+        "Emoji_Modifier_Base" => 0x08,
+        "Emoji_Component" => 0x10,
+        "Extended_Pictographic" => 0x20,
+        _ => return Err(format!("invalid emoji property {property:?}")),
+    })
+}
+
+/// The generated emoji table, along with the handwritten source code that
+/// appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `emoji_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,51 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parse_emoji_data() {
+    let src = "\
+# emoji-data.txt
+
+0023          ; Emoji                #  1.1  [1] (#️)       hash sign
+231A..231B    ; Emoji                #  1.1  [2] (⌚..⌛)    watch..hourglass done
+1F3FB..1F3FF  ; Emoji                #  8.0  [5] (🏻..🏿)    light skin tone..dark skin tone
+231A..231B    ; Emoji_Presentation   #  1.1  [2] (⌚..⌛)    watch..hourglass done
+1F3FB..1F3FF  ; Emoji_Modifier       #  8.0  [5] (🏻..🏿)    light skin tone..dark skin tone
+0023          ; Emoji_Component      #  1.1  [1] (#️)       hash sign
+1F3FB..1F3FF  ; Emoji_Component      #  8.0  [5] (🏻..🏿)    light skin tone..dark skin tone
+261D          ; Emoji_Modifier_Base  #  0.6  [1] (☝️)       index pointing up
+231A..231B    ; Extended_Pictographic#  1.1  [2] (⌚..⌛)    watch..hourglass done
+";
+    let got = parse(src).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x22], 0);
+    assert_eq!(got[0x23], 0x11);
+    assert_eq!(got[0x231b], 0x23);
+    assert_eq!(got[0x261d], 0x08);
+    assert_eq!(got[0x1f3fd], 0x15);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("0023 Emoji").unwrap_err(), "line 1: missing ';'");
+    assert_eq!(
+        parse("\n0023 ; Emoji_Thing").unwrap_err(),
+        "line 2: invalid emoji property \"Emoji_Thing\""
+    );
+}
+
+#[test]
+fn render_round_trip() {
+    let values = parse("0023 ; Emoji\n1F600 ; Emoji\n1F600 ; Emoji_Presentation\n").unwrap();
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: ucd::value_runs(&values),
+    };
+    assert_eq!(table.runs, &[0x0000, 0x2301, 0x2400, 0x1f60003, 0x1f60100]);
+    assert_eq!(table.lookup('#'), 0x01);
+    assert_eq!(table.lookup('\u{1F600}'), 0x03);
+    assert_eq!(table.lookup(char::MAX), 0);
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}
@@ -0,0 +1,156 @@
+//! Generates the **Line_Break** property table in `src/line/table.rs` from
+//! the `LineBreak.txt` file of the Unicode character database, along with
+//! the few details of other properties that the line breaking rules need.
+//!
+//! This uses the same sorted list of runs as the **Age** table. The low six
+//! bits of each run's value are the **Line_Break** property value encoded
+//! as by [`encode`], and the two bits above them are a variant whose
+//! meaning depends on the property value:
+//!
+//! - 1 for any value means the character is East Asian, with an
+//!   **East_Asian_Width** of **Fullwidth**, **Wide** or **Halfwidth**.
+//! - 2 for `QU` means the character is an initial quotation mark, with a
+//!   **General_Category** of **Pi**, and 3 means it's a final quotation
+//!   mark, with **Pf**.
+//! - 2 for `SA` means the character is a combining mark, with a
+//!   **General_Category** of **Mn** or **Mc**.
+//! - 2 for `BA` means the character is U+2010 HYPHEN, and 2 for `AL` means
+//!   it's U+25CC DOTTED CIRCLE.
+//! - 2 for `ID` means the character is an unassigned code point with the
+//!   **Extended_Pictographic** property, and 3 means it's one that's also
+//!   East Asian.
+//!
+//! None of the other variants of a value can also be East Asian, and
+//! [`parse`] fails if the files disagree.
+
+use crate::{category, emoji, ucd, width};
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "LINE_BREAK_RUNS";
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// The short **Line_Break** property value aliases, in the same order as
+/// the `LBProperty` enum in the main crate.
+const ALIASES: [&str; 48] = [
+    "XX", "AI", "AK", "AL", "AP", "AS", "B2", "BA", "BB", "BK", "CB", "CJ", "CL", "CM", "CP", "CR",
+    "EB", "EM", "EX", "GL", "H2", "H3", "HL", "HY", "ID", "IN", "IS", "JL", "JT", "JV", "LF", "NL",
+    "NS", "NU", "OP", "PO", "PR", "QU", "RI", "SA", "SG", "SP", "SY", "VF", "VI", "WJ", "ZW",
+    "ZWJ",
+];
+
+/// The shift of the variant in each table value.
+const VARIANT_SHIFT: u8 = 6;
+
+/// Parses the contents of `LineBreak.txt`, `EastAsianWidth.txt`,
+/// `UnicodeData.txt` and `emoji-data.txt`, returning the table value of
+/// every code point in order.
+///
+/// `LineBreak.txt` lists each assigned character or range of characters
+/// with its value, like `0041..005A;AL  # Lu [26] LATIN CAPITAL LETTER
+/// A..`. Characters that aren't listed take their value from the last
+/// `@missing` line whose range includes them, or `XX` if there is none.
+pub fn parse(
+    line_break: &str,
+    east_asian_width: &str,
+    unicode_data: &str,
+    emoji_data: &str,
+) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    let missing = ucd::missing_lines(line_break);
+    for lines in [ucd::parse_lines(&missing)?, ucd::parse_lines(line_break)?] {
+        for line in lines {
+            let value = encode(line.value).map_err(|msg| line.error(&msg))?;
+            values[line.start as usize..=line.end as usize].fill(value);
+        }
+    }
+
+    let widths = width::parse(east_asian_width)?;
+    let categories = category::parse(unicode_data)?;
+    let emoji = emoji::parse(emoji_data)?;
+    let category = |c: usize, aliases: &[&str]| {
+        aliases
+            .iter()
+            .any(|&alias| category::encode(alias) == Ok(categories[c]))
+    };
+    let east_asian_widths = ["H", "W", "F"].map(|alias| width::encode(alias).unwrap());
+    let pictographic = emoji::encode("Extended_Pictographic").unwrap();
+    for (c, value) in values.iter_mut().enumerate() {
+        let east_asian = east_asian_widths.contains(&widths[c]);
+        let variant = match ALIASES[*value as usize] {
+            "QU" if category(c, &["Pi"]) => 2,
+            "QU" if category(c, &["Pf"]) => 3,
+            "SA" if category(c, &["Mn", "Mc"]) => 2,
+            "BA" if c == 0x2010 => 2,
+            "AL" if c == 0x25cc => 2,
+            "ID" if category(c, &["Cn"]) && emoji[c] & pictographic != 0 => {
+                *value |= (2 | east_asian as u8) << VARIANT_SHIFT;
+                continue;
+            }
+            _ => 0,
+        };
+        *value |= match (variant, east_asian) {
+            (0, false) => 0,
+            (0, true) => 1 << VARIANT_SHIFT,
+            (variant, false) => variant << VARIANT_SHIFT,
+            (_, true) => {
+                let class = ALIASES[*value as usize];
+                return Err(format!(
+                    "U+{c:04X} is both East Asian and a special {class}"
+                ));
+            }
+        };
+    }
+    Ok(values)
+}
+
+/// Returns the table value for a short **Line_Break** property value
+/// alias, in the same order as the `LBProperty` enum in the main crate.
+pub fn encode(value: &str) -> Result<u8, String> {
+    match ALIASES.iter().position(|&alias| alias == value) {
+        Some(i) => Ok(i as u8),
+        None => Err(format!("invalid Line_Break {value:?}")),
+    }
+}
+
+/// The generated line break table, along with the handwritten source code
+/// that appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `line_break_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
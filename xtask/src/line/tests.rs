@@ -0,0 +1,88 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const LINE_BREAK: &str = "\
+# LineBreak-16.0.0.txt
+
+# @missing: 0000..10FFFF; XX
+# @missing: 1F000..1FAFF; ID
+
+0020;SP     # Zs         SPACE
+0041..005A;AL     # Lu    [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+00AB;QU     # Pi         LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+00BB;QU     # Pf         RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+0E01;SA     # Lo         THAI CHARACTER KO KAI
+0E31;SA     # Mn         THAI CHARACTER MAI HAN-AKAT
+2010;BA     # Pd         HYPHEN
+25CC;AL     # So         DOTTED CIRCLE
+3001;CL     # Po         IDEOGRAPHIC COMMA
+";
+
+const EAST_ASIAN_WIDTH: &str = "\
+# @missing: 0000..10FFFF; N
+3001;W
+1F000..1FAFF;W
+";
+
+const UNICODE_DATA: &str = "\
+00AB;LEFT-POINTING DOUBLE ANGLE QUOTATION MARK;Pi;0;ON;;;;;Y;;;;;
+00BB;RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK;Pf;0;ON;;;;;Y;;;;;
+0E01;THAI CHARACTER KO KAI;Lo;0;L;;;;;N;;;;;
+0E31;THAI CHARACTER MAI HAN-AKAT;Mn;0;NSM;;;;;N;;;;;
+1F000;MAHJONG TILE EAST WIND;So;0;ON;;;;;N;;;;;
+";
+
+const EMOJI_DATA: &str = "\
+1F000..1FAFF ; Extended_Pictographic
+";
+
+#[test]
+fn parse_line_break() {
+    let got = parse(LINE_BREAK, EAST_ASIAN_WIDTH, UNICODE_DATA, EMOJI_DATA).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x1f], 0);
+    assert_eq!(got[0x20], 41);
+    assert_eq!(got[0x41], 3);
+    assert_eq!(got[0xab], 37 | 0x80);
+    assert_eq!(got[0xbb], 37 | 0xc0);
+    assert_eq!(got[0xe01], 39);
+    assert_eq!(got[0xe31], 39 | 0x80);
+    assert_eq!(got[0x2010], 7 | 0x80);
+    assert_eq!(got[0x25cc], 3 | 0x80);
+    assert_eq!(got[0x3001], 12 | 0x40);
+    // U+1F000 is assigned, while U+1F001 takes its value from @missing.
+    assert_eq!(got[0x1f000], 24 | 0x40);
+    assert_eq!(got[0x1f001], 24 | 0xc0);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse("0041 AL", "", "", "").unwrap_err(),
+        "line 1: missing ';'"
+    );
+    assert_eq!(
+        parse("\n0041;HH", "", "", "").unwrap_err(),
+        "line 2: invalid Line_Break \"HH\""
+    );
+    assert_eq!(
+        parse("00AB;QU", "00AB;W", "00AB;X;Pi", "").unwrap_err(),
+        "U+00AB is both East Asian and a special QU"
+    );
+}
+
+#[test]
+fn render_round_trip() {
+    let values = parse("0030..0039;NU\n0041;AL\n", "", "", "").unwrap();
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: ucd::value_runs(&values),
+    };
+    assert_eq!(table.runs, &[0x0000, 0x3021, 0x3a00, 0x4103, 0x4200]);
+    assert_eq!(table.lookup('5'), 33);
+    assert_eq!(table.lookup('A'), 3);
+    assert_eq!(table.lookup(char::MAX), 0);
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}
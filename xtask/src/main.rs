@@ -0,0 +1,691 @@
+//! Maintenance tasks for the `grapheme_machine` crate, run using
+//! `cargo xtask <command>` from anywhere in the repository.
+//!
+//! Commands:
+//!
+//! - `shrink-tables`: Rewrites each of the `src/properties/table_*.rs`
+//!   files after merging any duplicate nodes in its property lookup trie,
+//!   and reports how much smaller the tables became. Pass `--check` to only
+//!   report whether the tables could be shrunk, without changing them.
+//! - `apply-derived-properties <version> <dir>`: Rewrites the
+//!   `src/properties/table_*.rs` file for the given Unicode version after
+//!   setting the bits for the **Default_Ignorable_Code_Point** and
+//!   **Variation_Selector** properties in every value from the
+//!   `DerivedCoreProperties.txt` and `PropList.txt` files in the given
+//!   directory of that version of the Unicode character database, leaving
+//!   the grapheme cluster break properties as they were. Pass `--check`
+//!   after the path to only report whether any bits would change.
+//! - `generate-property-table <version> <dir>`: Rewrites the
+//!   `src/properties/table_*.rs` file for the given Unicode version, like
+//!   `16.0`, with the **Grapheme_Cluster_Break**, **Extended_Pictographic**,
+//!   **Indic_Conjunct_Break**, **Default_Ignorable_Code_Point** and
+//!   **Variation_Selector** property values from the
+//!   `GraphemeBreakProperty.txt`, `emoji-data.txt`,
+//!   `DerivedCoreProperties.txt` and `PropList.txt` files in the given
+//!   directory of that version of the Unicode character database. Pass
+//!   `--check` after the path to only report whether the table matches the
+//!   files.
+//! - `generate-age-table <DerivedAge.txt>`: Rewrites `src/age/table.rs`
+//!   with the **Age** property values from the given file of the Unicode
+//!   character database. Pass `--check` after the path to only report
+//!   whether the table matches the file.
+//! - `generate-script-tables <dir>`: Rewrites `src/script/table.rs` with
+//!   the **Script** and **Script_Extensions** property values from the
+//!   `PropertyValueAliases.txt`, `Scripts.txt` and `ScriptExtensions.txt`
+//!   files in the given directory of the Unicode character database. Pass
+//!   `--check` after the path to only report whether the tables match the
+//!   files.
+//! - `generate-name-tables <UnicodeData.txt>`: Rewrites
+//!   `src/names/table.rs` with the character names from the given file of
+//!   the Unicode character database. Pass `--check` after the path to only
+//!   report whether the tables match the file.
+//! - `generate-width-table <EastAsianWidth.txt>`: Rewrites
+//!   `src/width/table.rs` with the **East_Asian_Width** property values
+//!   from the given file of the Unicode character database. Pass `--check`
+//!   after the path to only report whether the table matches the file.
+//! - `generate-emoji-table <emoji-data.txt>`: Rewrites
+//!   `src/emoji/table.rs` with the **Emoji**, **Emoji_Presentation**,
+//!   **Emoji_Modifier**, **Emoji_Modifier_Base**, **Emoji_Component** and
+//!   **Extended_Pictographic** property values from the given file of the
+//!   Unicode emoji data. Pass `--check` after the path to only report
+//!   whether the table matches the file.
+//! - `generate-category-table <UnicodeData.txt>`: Rewrites
+//!   `src/category/table.rs` with the **General_Category** property values
+//!   from the given file of the Unicode character database. Pass `--check`
+//!   after the path to only report whether the table matches the file.
+//! - `generate-word-break-table <WordBreakProperty.txt>`: Rewrites
+//!   `src/word/table.rs` with the **Word_Break** property values from the
+//!   given file of the Unicode character database. Pass `--check` after the
+//!   path to only report whether the table matches the file.
+//! - `generate-word-break-tests <WordBreakTest.txt>`: Rewrites
+//!   `src/word/test_table.rs` with the test cases from the given file of
+//!   the Unicode character database. Pass `--check` after the path to only
+//!   report whether the tests match the file.
+//! - `generate-line-break-table <dir>`: Rewrites `src/line/table.rs` with
+//!   the **Line_Break** property values from the `LineBreak.txt` file in
+//!   the given directory of the Unicode character database, along with the
+//!   details from its `EastAsianWidth.txt`, `UnicodeData.txt` and
+//!   `emoji-data.txt` files that the line breaking rules need. Pass
+//!   `--check` after the path to only report whether the table matches the
+//!   files.
+//! - `generate-line-break-tests <LineBreakTest.txt>`: Rewrites
+//!   `src/line/test_table.rs` with the test cases from the given file of
+//!   the Unicode character database. Pass `--check` after the path to only
+//!   report whether the tests match the file.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod age;
+mod category;
+mod conformance;
+mod derived;
+mod emoji;
+mod line;
+mod names;
+mod properties;
+mod script;
+mod trie;
+mod ucd;
+mod width;
+mod word;
+
+use trie::Trie;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["shrink-tables"] => shrink_tables(false),
+        ["shrink-tables", "--check"] => shrink_tables(true),
+        ["apply-derived-properties", version, dir] => apply_derived_properties(version, dir, false),
+        ["apply-derived-properties", version, dir, "--check"] => {
+            apply_derived_properties(version, dir, true)
+        }
+        ["generate-property-table", version, dir] => generate_property_table(version, dir, false),
+        ["generate-property-table", version, dir, "--check"] => {
+            generate_property_table(version, dir, true)
+        }
+        ["generate-age-table", path] => generate_age_table(path, false),
+        ["generate-age-table", path, "--check"] => generate_age_table(path, true),
+        ["generate-script-tables", dir] => generate_script_tables(dir, false),
+        ["generate-script-tables", dir, "--check"] => generate_script_tables(dir, true),
+        ["generate-name-tables", path] => generate_name_tables(path, false),
+        ["generate-name-tables", path, "--check"] => generate_name_tables(path, true),
+        ["generate-width-table", path] => generate_width_table(path, false),
+        ["generate-width-table", path, "--check"] => generate_width_table(path, true),
+        ["generate-emoji-table", path] => generate_emoji_table(path, false),
+        ["generate-emoji-table", path, "--check"] => generate_emoji_table(path, true),
+        ["generate-category-table", path] => generate_category_table(path, false),
+        ["generate-category-table", path, "--check"] => generate_category_table(path, true),
+        ["generate-word-break-table", path] => generate_word_break_table(path, false),
+        ["generate-word-break-table", path, "--check"] => generate_word_break_table(path, true),
+        ["generate-word-break-tests", path] => generate_word_break_tests(path, false),
+        ["generate-word-break-tests", path, "--check"] => generate_word_break_tests(path, true),
+        ["generate-line-break-table", dir] => generate_line_break_table(dir, false),
+        ["generate-line-break-table", dir, "--check"] => generate_line_break_table(dir, true),
+        ["generate-line-break-tests", path] => generate_line_break_tests(path, false),
+        ["generate-line-break-tests", path, "--check"] => generate_line_break_tests(path, true),
+        _ => Err("usage: cargo xtask (shrink-tables | \
+             apply-derived-properties <version> <dir> | \
+             generate-property-table <version> <dir> | \
+             generate-age-table <DerivedAge.txt> | generate-script-tables <dir> | \
+             generate-name-tables <UnicodeData.txt> | \
+             generate-width-table <EastAsianWidth.txt> | \
+             generate-emoji-table <emoji-data.txt> | \
+             generate-category-table <UnicodeData.txt> | \
+             generate-word-break-table <WordBreakProperty.txt> | \
+             generate-word-break-tests <WordBreakTest.txt> | \
+             generate-line-break-table <dir> | \
+             generate-line-break-tests <LineBreakTest.txt>) [--check]"
+            .to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("error: {msg}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The Unicode versions that there are property lookup tables for, each in
+/// its own file in `src/properties`.
+const TABLE_VERSIONS: &[&str] = &["15.1", "16.0"];
+
+fn table_path(version: &str) -> Result<PathBuf, String> {
+    if !TABLE_VERSIONS.contains(&version) {
+        return Err(format!("there is no property table for Unicode {version}"));
+    }
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let name = format!("table_{}.rs", version.replace('.', "_"));
+    Ok(manifest_dir.join("../src/properties").join(name))
+}
+
+fn age_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/age/table.rs")
+}
+
+fn script_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/script/table.rs")
+}
+
+fn name_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/names/table.rs")
+}
+
+fn width_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/width/table.rs")
+}
+
+fn emoji_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/emoji/table.rs")
+}
+
+fn category_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/category/table.rs")
+}
+
+fn word_break_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/word/table.rs")
+}
+
+fn word_break_tests_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/word/test_table.rs")
+}
+
+fn line_break_table_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/line/table.rs")
+}
+
+fn line_break_tests_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest_dir.join("../src/line/test_table.rs")
+}
+
+fn read_table(version: &str) -> Result<Trie, String> {
+    let path = table_path(version)?;
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    Trie::parse(&src)
+}
+
+fn write_table(version: &str, trie: &Trie) -> Result<(), String> {
+    let path = table_path(version)?;
+    std::fs::write(&path, trie.render())
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+fn shrink_tables(check: bool) -> Result<(), String> {
+    for version in TABLE_VERSIONS {
+        shrink_table(version, check)?;
+    }
+    Ok(())
+}
+
+fn shrink_table(version: &str, check: bool) -> Result<(), String> {
+    let old = read_table(version)?;
+    let new = old.dedup()?;
+    trie::verify_same_lookups(&old, &new)?;
+
+    println!(
+        "Unicode {version}:\nvalues: {} -> {} bytes\nindex: {} -> {} bytes\ntotal: {} -> {} bytes ({} saved)",
+        old.values.len(),
+        new.values.len(),
+        old.index.len(),
+        new.index.len(),
+        old.size(),
+        new.size(),
+        old.size() as isize - new.size() as isize,
+    );
+    if check {
+        if new != old {
+            return Err(format!(
+                "the Unicode {version} tables are not fully deduplicated"
+            ));
+        }
+        return Ok(());
+    }
+    if new != old {
+        write_table(version, &new)?;
+    }
+    Ok(())
+}
+
+fn apply_derived_properties(version: &str, data_dir: &str, check: bool) -> Result<(), String> {
+    let read = |path: PathBuf| {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    let data_dir = PathBuf::from(data_dir);
+    let bits = derived::parse(
+        &read(data_dir.join("DerivedCoreProperties.txt"))?,
+        &read(data_dir.join("PropList.txt"))?,
+    )?;
+    let old = read_table(version)?;
+    let new = old.map_values(|s, value| derived::apply(&bits, s, value))?;
+    let mut buf = [0; 4];
+    for c in '\0'..=char::MAX {
+        let s = c.encode_utf8(&mut buf).as_bytes();
+        let (want, got) = (derived::apply(&bits, s, old.lookup(s)), new.lookup(s));
+        if want != got {
+            return Err(format!(
+                "wrong properties for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("Unicode {version}: {} -> {} bytes", old.size(), new.size());
+    if check {
+        if new != old {
+            return Err(format!(
+                "the Unicode {version} derived properties are not up to date"
+            ));
+        }
+        return Ok(());
+    }
+    if new != old {
+        write_table(version, &new)?;
+    }
+    Ok(())
+}
+
+fn generate_property_table(version: &str, data_dir: &str, check: bool) -> Result<(), String> {
+    let read = |path: PathBuf| {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    let data_dir = PathBuf::from(data_dir);
+    let values = properties::parse(
+        &read(data_dir.join("GraphemeBreakProperty.txt"))?,
+        &read(data_dir.join("emoji-data.txt"))?,
+        &read(data_dir.join("DerivedCoreProperties.txt"))?,
+        &read(data_dir.join("PropList.txt"))?,
+    )?;
+    let old = read_table(version)?;
+    let new = old.map_values(|s, value| properties::apply(&values, s, value))?;
+    let mut buf = [0; 4];
+    for c in '\0'..=char::MAX {
+        let s = c.encode_utf8(&mut buf).as_bytes();
+        let (want, got) = (values[c as usize], new.lookup(s));
+        if want != got {
+            return Err(format!(
+                "wrong properties for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    if check {
+        if new != old {
+            return Err(format!(
+                "the Unicode {version} property table is not up to date"
+            ));
+        }
+        return Ok(());
+    }
+    if new != old {
+        write_table(version, &new)?;
+    }
+    Ok(())
+}
+
+fn generate_age_table(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let ranges = age::parse(&data)?;
+    let path = age_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let old = age::Table::parse(&src)?;
+    let new = age::Table {
+        preamble: old.preamble.clone(),
+        runs: age::runs(&ranges),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (age::value_for(&ranges, c), new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong age for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the age table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_script_tables(data_dir: &str, check: bool) -> Result<(), String> {
+    let read = |path: PathBuf| {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    let data_dir = PathBuf::from(data_dir);
+    let new = script::Scripts::parse(
+        &read(data_dir.join("PropertyValueAliases.txt"))?,
+        &read(data_dir.join("Scripts.txt"))?,
+        &read(data_dir.join("ScriptExtensions.txt"))?,
+    )?;
+    let path = script_table_path();
+    let src = read(path.clone())?;
+    let preamble = match src.find(script::GENERATED_MARKER) {
+        Some(i) => &src[..i],
+        None => return Err(format!("no generated part in {}", path.display())),
+    };
+
+    let (script_runs, extension_runs) = (new.script_runs(), new.extension_runs());
+    for c in '\0'..=char::MAX {
+        let want = new.extensions_for(c);
+        let got = match ucd::lookup_runs(&extension_runs, c) {
+            0 => vec![ucd::lookup_runs(&script_runs, c)],
+            i => new.sets[i as usize - 1].clone(),
+        };
+        if want != got {
+            return Err(format!(
+                "wrong script extensions for {c:?}: got {got:?}, want {want:?}"
+            ));
+        }
+    }
+
+    println!(
+        "scripts: {}\nsets of extensions: {}\ntotal: {} bytes",
+        new.names.len(),
+        new.sets.len(),
+        new.size(),
+    );
+    let rendered = new.render(preamble);
+    if check {
+        if rendered != src {
+            return Err("the script tables are not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_name_tables(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let names = names::Names::parse(&data)?;
+    let new = names::Tables::build(&names)?;
+    let path = name_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let preamble = match src.find(names::GENERATED_MARKER) {
+        Some(i) => &src[..i],
+        None => return Err(format!("no generated part in {}", path.display())),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (names.name_of(c), new.lookup(c));
+        if want != got {
+            return Err(format!("wrong name for {c:?}: got {got:?}, want {want:?}"));
+        }
+    }
+
+    println!(
+        "names: {}\nwords: {}\ntotal: {} bytes",
+        names.explicit.len(),
+        new.words.len(),
+        new.size(),
+    );
+    let rendered = new.render(preamble);
+    if check {
+        if rendered != src {
+            return Err("the name tables are not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_width_table(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let values = width::parse(&data)?;
+    let path = width_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let old = width::Table::parse(&src)?;
+    let new = width::Table {
+        preamble: old.preamble.clone(),
+        runs: ucd::value_runs(&values),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (values[c as usize], new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong East_Asian_Width for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the width table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_emoji_table(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let values = emoji::parse(&data)?;
+    let path = emoji_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let old = emoji::Table::parse(&src)?;
+    let new = emoji::Table {
+        preamble: old.preamble.clone(),
+        runs: ucd::value_runs(&values),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (values[c as usize], new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong emoji properties for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the emoji table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_category_table(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let values = category::parse(&data)?;
+    let path = category_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let old = category::Table::parse(&src)?;
+    let new = category::Table {
+        preamble: old.preamble.clone(),
+        runs: ucd::value_runs(&values),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (values[c as usize], new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong General_Category for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the category table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_word_break_table(data_path: &str, check: bool) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let values = word::parse(&data)?;
+    let path = word_break_table_path();
+    let src = std::fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let old = word::Table::parse(&src)?;
+    let new = word::Table {
+        preamble: old.preamble.clone(),
+        runs: ucd::value_runs(&values),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (values[c as usize], new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong Word_Break for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the word break table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_word_break_tests(data_path: &str, check: bool) -> Result<(), String> {
+    let path = word_break_tests_path();
+    generate_conformance_tests(data_path, &path, "UNICODE_WORD_BREAK_TESTS", check)
+}
+
+fn generate_line_break_tests(data_path: &str, check: bool) -> Result<(), String> {
+    let path = line_break_tests_path();
+    generate_conformance_tests(data_path, &path, "UNICODE_LINE_BREAK_TESTS", check)
+}
+
+fn generate_conformance_tests(
+    data_path: &str,
+    path: &Path,
+    name: &str,
+    check: bool,
+) -> Result<(), String> {
+    let data = std::fs::read_to_string(data_path)
+        .map_err(|err| format!("failed to read {data_path}: {err}"))?;
+    let tests = conformance::parse(&data)?;
+    let src = std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+    println!("total: {} tests", tests.len());
+    let rendered = conformance::render(name, &tests);
+    if check {
+        if rendered != src {
+            return Err(format!("{} is not up to date", path.display()));
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn generate_line_break_table(data_dir: &str, check: bool) -> Result<(), String> {
+    let read = |path: PathBuf| {
+        std::fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))
+    };
+    let data_dir = PathBuf::from(data_dir);
+    let values = line::parse(
+        &read(data_dir.join("LineBreak.txt"))?,
+        &read(data_dir.join("EastAsianWidth.txt"))?,
+        &read(data_dir.join("UnicodeData.txt"))?,
+        &read(data_dir.join("emoji-data.txt"))?,
+    )?;
+    let path = line_break_table_path();
+    let src = read(path.clone())?;
+    let old = line::Table::parse(&src)?;
+    let new = line::Table {
+        preamble: old.preamble.clone(),
+        runs: ucd::value_runs(&values),
+    };
+    for c in '\0'..=char::MAX {
+        let (want, got) = (values[c as usize], new.lookup(c));
+        if want != got {
+            return Err(format!(
+                "wrong Line_Break for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+
+    println!("total: {} -> {} bytes", old.size(), new.size());
+    let rendered = new.render();
+    if check {
+        if rendered != src {
+            return Err("the line break table is not up to date".to_string());
+        }
+        return Ok(());
+    }
+    if rendered != src {
+        std::fs::write(&path, rendered)
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
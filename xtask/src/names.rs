@@ -0,0 +1,363 @@
+//! Generates the character name tables in `src/names/table.rs` from the
+//! `UnicodeData.txt` file of the Unicode character database.
+//!
+//! Most names are stored as sequences of words from a shared list, with the
+//! most common words first so that they can be referred to by a single
+//! byte. Each word is one or two bytes in `NAME_DATA`: the top bit of the
+//! first byte is set for a two-byte word, whose index is the other fourteen
+//! bits, and the next bit is set for the last word of a name. The names of
+//! consecutive characters are stored consecutively, and `NAME_RUNS` holds
+//! runs of up to [`MAX_RUN`] characters in the same form as the **Age**
+//! table, except that the low eight bits are the number of characters in
+//! the run rather than a property value. `NAME_RUN_OFFSETS` holds the
+//! offset of the first name of each run.
+//!
+//! Names that are just a prefix followed by the character's code point,
+//! like `CJK UNIFIED IDEOGRAPH-4E00`, are instead stored as ranges of
+//! characters sharing a prefix in `NAME_PREFIXES`, and the names of Hangul
+//! syllables are derived from their decompositions by the main crate.
+
+use std::collections::HashMap;
+
+use crate::ucd;
+
+/// The line that separates the handwritten source code at the start of the
+/// generated file from the generated part.
+pub const GENERATED_MARKER: &str =
+    "// Everything below this line is generated by `cargo xtask generate-name-tables`.\n";
+
+/// The maximum number of characters in each run, which limits how many
+/// names a lookup must skip over.
+pub const MAX_RUN: u32 = 32;
+
+/// The number of words that can be referred to by a single byte.
+const SHORT_WORDS: usize = 64;
+
+/// The number of words that can be referred to at all.
+const MAX_WORDS: usize = 1 << 14;
+
+/// The range of characters that are Hangul syllables, whose names are
+/// derived from their decompositions.
+const HANGUL_SYLLABLES: (u32, u32) = (0xAC00, 0xD7A3);
+
+/// The names parsed from `UnicodeData.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Names {
+    /// The characters with explicitly-listed names, sorted by code point.
+    pub explicit: Vec<(u32, String)>,
+    /// The sorted ranges of characters whose names are a prefix followed by
+    /// their code point.
+    pub prefixed: Vec<(u32, u32, String)>,
+}
+
+impl Names {
+    /// Parses the content of `UnicodeData.txt`, whose lines each begin with
+    /// a code point and a name, like `0041;LATIN CAPITAL LETTER A;Lu;...`.
+    ///
+    /// Ranges of characters are given by a pair of lines whose names are
+    /// like `<CJK Ideograph Extension A, First>` and `<..., Last>`.
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut explicit = Vec::new();
+        let mut prefixed: Vec<(u32, u32, String)> = Vec::new();
+        let mut range_start: Option<(u32, &str)> = None;
+        for (i, line) in src.lines().enumerate() {
+            let err = |msg: &str| format!("line {}: {msg}", i + 1);
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut fields = line.split(';');
+            let cp = fields.next().unwrap();
+            let cp = u32::from_str_radix(cp, 16).map_err(|e| err(&e.to_string()))?;
+            let name = fields.next().ok_or_else(|| err("missing name"))?;
+            if cp > char::MAX as u32 {
+                return Err(err("invalid code point"));
+            }
+
+            if let Some((start, kind)) = range_start.take() {
+                if name != format!("<{kind}, Last>") {
+                    return Err(err(&format!("expected the end of the {kind} range")));
+                }
+                match range_prefix(kind) {
+                    Some(Some(prefix)) => prefixed.push((start, cp, prefix.to_string())),
+                    Some(None) => {}
+                    None if kind.starts_with("Hangul Syllable") => {
+                        if (start, cp) != HANGUL_SYLLABLES {
+                            return Err(err("unexpected range of Hangul syllables"));
+                        }
+                    }
+                    None => return Err(err(&format!("unknown kind of range {kind:?}"))),
+                }
+                continue;
+            }
+            if let Some(kind) = name
+                .strip_prefix('<')
+                .and_then(|s| s.strip_suffix(", First>"))
+            {
+                range_start = Some((cp, kind));
+                continue;
+            }
+            if name == "<control>" {
+                // Control characters have no name, only a code point label.
+                continue;
+            }
+            if name.starts_with('<') || name.is_empty() {
+                return Err(err(&format!("unexpected name {name:?}")));
+            }
+
+            match name.strip_suffix(&format!("-{cp:04X}")) {
+                Some(prefix) => {
+                    let prefix = format!("{prefix}-");
+                    match prefixed.last_mut() {
+                        Some(last) if last.1 + 1 == cp && last.2 == prefix => last.1 = cp,
+                        _ => prefixed.push((cp, cp, prefix)),
+                    }
+                }
+                None => explicit.push((cp, name.to_string())),
+            }
+        }
+        if let Some((start, kind)) = range_start {
+            return Err(format!("unterminated {kind} range at {start:04X}"));
+        }
+
+        explicit.sort();
+        if let Some(w) = explicit.windows(2).find(|w| w[0].0 == w[1].0) {
+            return Err(format!("duplicate name for {:04X}", w[1].0));
+        }
+        let mut ranges: Vec<(u32, u32, u8)> = prefixed.iter().map(|r| (r.0, r.1, 0)).collect();
+        ucd::sort_ranges(&mut ranges)?;
+        prefixed.sort();
+        Ok(Self { explicit, prefixed })
+    }
+
+    /// Returns the name of the given character according to the parsed
+    /// data, or `None` if it doesn't have one.
+    pub fn name_of(&self, c: char) -> Option<String> {
+        let cp = c as u32;
+        if let Ok(i) = self.explicit.binary_search_by_key(&cp, |e| e.0) {
+            return Some(self.explicit[i].1.clone());
+        }
+        if let Some(name) = hangul_name(cp) {
+            return Some(name);
+        }
+        self.prefixed
+            .iter()
+            .find(|r| r.0 <= cp && cp <= r.1)
+            .map(|r| format!("{}{cp:04X}", r.2))
+    }
+}
+
+/// Returns the prefix for the names of characters in a range given by a
+/// pair of lines in `UnicodeData.txt`. Returns `Some(None)` for ranges of
+/// characters with no names, and `None` for unknown kinds of ranges.
+fn range_prefix(kind: &str) -> Option<Option<&'static str>> {
+    if kind.starts_with("CJK Ideograph") {
+        Some(Some("CJK UNIFIED IDEOGRAPH-"))
+    } else if kind.starts_with("Tangut Ideograph") {
+        Some(Some("TANGUT IDEOGRAPH-"))
+    } else if kind.contains("Private Use") || kind.contains("Surrogate") {
+        Some(None)
+    } else {
+        None
+    }
+}
+
+/// Returns the name of the given character if it's a Hangul syllable,
+/// following the same steps as the main crate.
+pub fn hangul_name(cp: u32) -> Option<String> {
+    const L: [&str; 19] = [
+        "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T",
+        "P", "H",
+    ];
+    const V: [&str; 21] = [
+        "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO",
+        "WE", "WI", "YU", "EU", "YI", "I",
+    ];
+    const T: [&str; 28] = [
+        "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH",
+        "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+    ];
+    if !(HANGUL_SYLLABLES.0..=HANGUL_SYLLABLES.1).contains(&cp) {
+        return None;
+    }
+    let i = (cp - HANGUL_SYLLABLES.0) as usize;
+    let (l, v, t) = (i / (21 * 28), i % (21 * 28) / 28, i % 28);
+    Some(format!("HANGUL SYLLABLE {}{}{}", L[l], V[v], T[t]))
+}
+
+/// The encoded name tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tables {
+    pub prefixes: Vec<(u32, u32, String)>,
+    pub words: Vec<String>,
+    pub data: Vec<u8>,
+    pub runs: Vec<u32>,
+    pub run_offsets: Vec<u32>,
+}
+
+impl Tables {
+    /// Encodes the given names.
+    pub fn build(names: &Names) -> Result<Self, String> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for (_, name) in &names.explicit {
+            for word in name.split(' ') {
+                *counts.entry(word).or_default() += 1;
+            }
+        }
+        // The most common words come first, and ties are broken
+        // alphabetically so that the output is deterministic.
+        let mut words: Vec<(&str, usize)> = counts.into_iter().collect();
+        words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        if words.len() > MAX_WORDS {
+            return Err(format!("too many distinct words ({})", words.len()));
+        }
+        let index: HashMap<&str, usize> = words.iter().enumerate().map(|(i, w)| (w.0, i)).collect();
+
+        let mut data = Vec::new();
+        let mut runs: Vec<u32> = Vec::new();
+        let mut run_offsets = Vec::new();
+        let mut prev = None;
+        for (cp, name) in &names.explicit {
+            let continues = match (prev, runs.last()) {
+                (Some(prev), Some(&run)) => prev + 1 == *cp && run & 0xff < MAX_RUN,
+                _ => false,
+            };
+            if continues {
+                *runs.last_mut().unwrap() += 1;
+            } else {
+                runs.push(cp << 8 | 1);
+                run_offsets.push(data.len() as u32);
+            }
+            prev = Some(*cp);
+
+            let mut name_words = name.split(' ').peekable();
+            while let Some(word) = name_words.next() {
+                let i = index[word];
+                let last = if name_words.peek().is_none() { 0x40 } else { 0 };
+                if i < SHORT_WORDS {
+                    data.push(last | i as u8);
+                } else {
+                    data.push(0x80 | last | (i >> 8) as u8);
+                    data.push(i as u8);
+                }
+            }
+        }
+
+        Ok(Self {
+            prefixes: names.prefixed.clone(),
+            words: words.into_iter().map(|w| w.0.to_string()).collect(),
+            data,
+            runs,
+            run_offsets,
+        })
+    }
+
+    /// Returns the name of the given character from the encoded tables,
+    /// following the same steps as the main crate.
+    pub fn lookup(&self, c: char) -> Option<String> {
+        let cp = c as u32;
+        if let Some(r) = self.prefixes.iter().find(|r| r.0 <= cp && cp <= r.1) {
+            return Some(format!("{}{cp:04X}", r.2));
+        }
+        if let Some(name) = hangul_name(cp) {
+            return Some(name);
+        }
+        let i = self
+            .runs
+            .partition_point(|&run| run >> 8 <= cp)
+            .checked_sub(1)?;
+        let skip = cp - (self.runs[i] >> 8);
+        if skip >= self.runs[i] & 0xff {
+            return None;
+        }
+        let mut pos = self.run_offsets[i] as usize;
+        let mut words = Vec::new();
+        let mut skipped = 0;
+        loop {
+            let b = self.data[pos];
+            let word = if b & 0x80 == 0 {
+                pos += 1;
+                (b & 0x3f) as usize
+            } else {
+                pos += 2;
+                ((b & 0x3f) as usize) << 8 | self.data[pos - 1] as usize
+            };
+            if skipped == skip {
+                words.push(self.words[word].as_str());
+            }
+            if b & 0x40 != 0 {
+                if skipped == skip {
+                    return Some(words.join(" "));
+                }
+                skipped += 1;
+            }
+        }
+    }
+
+    /// Returns the size in bytes of the generated tables, on a target with
+    /// 64-bit pointers.
+    pub fn size(&self) -> usize {
+        let words: usize = self.words.iter().map(String::len).sum();
+        self.prefixes.len() * (8 + 16)
+            + words
+            + self.words.len() * 4
+            + self.data.len()
+            + self.runs.len() * 8
+    }
+
+    /// Renders the content of a `table.rs` file, with the given handwritten
+    /// source code before the generated part.
+    pub fn render(&self, preamble: &str) -> String {
+        let mut ret = preamble.to_string();
+        ret.push_str(GENERATED_MARKER);
+        ret.push('\n');
+
+        ret.push_str("/// The ranges of characters whose names are a prefix followed by their\n");
+        ret.push_str("/// code point.\n");
+        ret.push_str(&format!(
+            "static NAME_PREFIXES: [(u32, u32, &str); {}] = [\n",
+            self.prefixes.len()
+        ));
+        for (start, end, prefix) in &self.prefixes {
+            ret.push_str(&format!("    ({start:#06x}, {end:#06x}, \"{prefix}\"),\n"));
+        }
+        ret.push_str("];\n\n");
+
+        ret.push_str("/// Every word used in the names, concatenated.\n");
+        ret.push_str("static NAME_WORDS: &str = \"\\\n");
+        let text: String = self.words.concat();
+        // Words never contain spaces, so the whitespace that follows each
+        // line continuation is never part of the text.
+        for line in text.as_bytes().chunks(90) {
+            // All of the names are ASCII, so any chunk is valid UTF-8.
+            ret.push_str("    ");
+            ret.push_str(std::str::from_utf8(line).unwrap());
+            ret.push_str("\\\n");
+        }
+        ret.push_str("\";\n\n");
+
+        let mut end = 0;
+        let ends: Vec<u32> = self
+            .words
+            .iter()
+            .map(|w| {
+                end += w.len() as u32;
+                end
+            })
+            .collect();
+        ret.push_str("/// The offset in [`NAME_WORDS`] where each word ends.\n");
+        ucd::render_u32s(&mut ret, "NAME_WORD_ENDS", &ends);
+        ret.push('\n');
+        ret.push_str("/// The words of each name, encoded as described in [`name_words`].\n");
+        ucd::render_u8s(&mut ret, "NAME_DATA", &self.data);
+        ret.push('\n');
+        ret.push_str("/// Runs of characters whose names are consecutive in [`NAME_DATA`].\n");
+        ucd::render_u32s(&mut ret, "NAME_RUNS", &self.runs);
+        ret.push('\n');
+        ret.push_str("/// The offset in [`NAME_DATA`] of the first name in each run.\n");
+        ucd::render_u32s(&mut ret, "NAME_RUN_OFFSETS", &self.run_offsets);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests;
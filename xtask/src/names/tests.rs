@@ -0,0 +1,111 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const UNICODE_DATA: &str = "\
+0009;<control>;Cc;0;S;;;;;N;CHARACTER TABULATION;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+0042;LATIN CAPITAL LETTER B;Lu;0;L;;;;;N;;;;0062;
+0061;LATIN SMALL LETTER A;Ll;0;L;;;;;N;;;0041;;0041
+3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;
+4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;
+AC00;<Hangul Syllable, First>;Lo;0;L;;;;;N;;;;;
+D7A3;<Hangul Syllable, Last>;Lo;0;L;;;;;N;;;;;
+E000;<Private Use, First>;Co;0;L;;;;;N;;;;;
+F8FF;<Private Use, Last>;Co;0;L;;;;;N;;;;;
+F900;CJK COMPATIBILITY IDEOGRAPH-F900;Lo;0;L;8C48;;;;N;;;;;
+F901;CJK COMPATIBILITY IDEOGRAPH-F901;Lo;0;L;66F4;;;;N;;;;;
+";
+
+#[test]
+fn parse_unicode_data() {
+    let got = Names::parse(UNICODE_DATA).unwrap();
+    assert_eq!(
+        got.explicit,
+        &[
+            (0x41, "LATIN CAPITAL LETTER A".to_string()),
+            (0x42, "LATIN CAPITAL LETTER B".to_string()),
+            (0x61, "LATIN SMALL LETTER A".to_string()),
+        ]
+    );
+    assert_eq!(
+        got.prefixed,
+        &[
+            (0x3400, 0x4DBF, "CJK UNIFIED IDEOGRAPH-".to_string()),
+            (0xF900, 0xF901, "CJK COMPATIBILITY IDEOGRAPH-".to_string()),
+        ]
+    );
+    assert_eq!(got.name_of('\t'), None);
+    assert_eq!(got.name_of('\u{E000}'), None);
+    assert_eq!(
+        got.name_of('\u{4DBF}').unwrap(),
+        "CJK UNIFIED IDEOGRAPH-4DBF"
+    );
+    assert_eq!(got.name_of('\u{AC01}').unwrap(), "HANGUL SYLLABLE GAG");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(Names::parse("0041").unwrap_err(), "line 1: missing name");
+    assert_eq!(
+        Names::parse("3400;<Klingon, First>\n3401;<Klingon, Last>").unwrap_err(),
+        "line 2: unknown kind of range \"Klingon\""
+    );
+    assert_eq!(
+        Names::parse("3400;<Private Use, First>\n3401;A").unwrap_err(),
+        "line 2: expected the end of the Private Use range"
+    );
+    assert_eq!(
+        Names::parse("0041;A\n0041;B").unwrap_err(),
+        "duplicate name for 0041"
+    );
+}
+
+#[test]
+fn encode_and_look_up() {
+    let names = Names::parse(UNICODE_DATA).unwrap();
+    let tables = Tables::build(&names).unwrap();
+    assert_eq!(
+        tables.words,
+        &["LATIN", "LETTER", "A", "CAPITAL", "B", "SMALL"]
+    );
+    assert_eq!(
+        tables.data,
+        &[
+            0x00, 0x03, 0x01, 0x42, 0x00, 0x03, 0x01, 0x44, 0x00, 0x05, 0x01, 0x42
+        ]
+    );
+    assert_eq!(tables.runs, &[0x41 << 8 | 2, 0x61 << 8 | 1]);
+    assert_eq!(tables.run_offsets, &[0, 8]);
+    for c in '\0'..='\u{FFFF}' {
+        assert_eq!(tables.lookup(c), names.name_of(c), "wrong name for {c:?}");
+    }
+}
+
+#[test]
+fn two_byte_words() {
+    // Enough distinct words that some need two bytes.
+    let src: String = (0..100)
+        .map(|i| format!("{:04X};WORD{i} LAST\n", 0x100 + i))
+        .collect();
+    let names = Names::parse(&src).unwrap();
+    let tables = Tables::build(&names).unwrap();
+    assert_eq!(tables.runs.len(), 100usize.div_ceil(MAX_RUN as usize));
+    assert!(tables.data.iter().any(|b| b & 0x80 != 0));
+    for i in 0..100 {
+        let c = char::from_u32(0x100 + i).unwrap();
+        assert_eq!(tables.lookup(c).unwrap(), format!("WORD{i} LAST"));
+    }
+}
+
+#[test]
+fn render() {
+    let names = Names::parse(UNICODE_DATA).unwrap();
+    let got = Tables::build(&names).unwrap().render("// preamble\n");
+    assert!(got.starts_with(&format!("// preamble\n{GENERATED_MARKER}\n")));
+    assert!(got.contains("    (0x3400, 0x4dbf, \"CJK UNIFIED IDEOGRAPH-\"),\n"));
+    assert!(got.contains("static NAME_WORDS: &str = \"\\\n    LATINLETTERACAPITALBSMALL\\\n\";\n"));
+    assert!(
+        got.contains("static NAME_RUN_OFFSETS: [u32; 2] = [\n    0x00000000, 0x00000008,\n];\n")
+    );
+}
@@ -0,0 +1,143 @@
+//! Generates the values of the character property lookup trie in
+//! `src/properties/table.rs` from the `GraphemeBreakProperty.txt`,
+//! `emoji-data.txt`, `DerivedCoreProperties.txt` and `PropList.txt` files
+//! of the Unicode character database.
+//!
+//! Each value of the trie packs several properties together: the low four
+//! bits are the **Grapheme_Cluster_Break** property value encoded as by
+//! [`encode_gcb`], with **Extended_Pictographic** treated as one more
+//! value of it, the two bits above them are the **Indic_Conjunct_Break**
+//! property value encoded as by [`encode_incb`], and the top two bits are
+//! the ones described in [`crate::derived`].
+
+use crate::{derived, emoji, ucd};
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// The bits of each value that hold the **Grapheme_Cluster_Break**
+/// property value.
+const GCB_MASK: u8 = 0x0f;
+
+/// The bits of each value that hold the **Indic_Conjunct_Break** property
+/// value.
+const INCB_MASK: u8 = 0x30;
+
+/// The **Grapheme_Cluster_Break** property value used for characters with
+/// the **Extended_Pictographic** property, which `GraphemeBreakProperty.txt`
+/// lists as **Other**.
+const EXTENDED_PICTOGRAPHIC: u8 = 0x04;
+
+/// Parses the contents of `GraphemeBreakProperty.txt`, `emoji-data.txt`,
+/// `DerivedCoreProperties.txt` and `PropList.txt`, returning the trie value
+/// of every code point in order.
+///
+/// Only the properties that the trie holds are taken from the last two
+/// files, and the others that they list are ignored. Characters that
+/// aren't listed take their values from the last `@missing` line whose
+/// range includes them, or have none of the properties if there is none.
+pub fn parse(
+    grapheme_break: &str,
+    emoji_data: &str,
+    derived_core: &str,
+    prop_list: &str,
+) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    let mut set = |line: &ucd::Line, mask: u8, value: u8| {
+        for v in &mut values[line.start as usize..=line.end as usize] {
+            *v = (*v & !mask) | value;
+        }
+    };
+
+    let missing = ucd::missing_lines(grapheme_break);
+    for lines in [
+        ucd::parse_lines(&missing)?,
+        ucd::parse_lines(grapheme_break)?,
+    ] {
+        for line in lines {
+            let value = encode_gcb(line.value).map_err(|msg| line.error(&msg))?;
+            set(&line, GCB_MASK, value);
+        }
+    }
+
+    let missing = ucd::missing_lines(derived_core);
+    for lines in [ucd::parse_lines(&missing)?, ucd::parse_lines(derived_core)?] {
+        for line in lines {
+            if let Some((property, value)) = line.value.split_once(';')
+                && property.trim() == "InCB"
+            {
+                let value = encode_incb(value.trim()).map_err(|msg| line.error(&msg))?;
+                set(&line, INCB_MASK, value);
+            }
+        }
+    }
+
+    let derived = derived::parse(derived_core, prop_list)?;
+    for (value, bits) in values.iter_mut().zip(derived) {
+        *value |= bits;
+    }
+
+    let pictographic = emoji::encode("Extended_Pictographic").unwrap();
+    let emoji = emoji::parse(emoji_data)?;
+    for (c, value) in values.iter_mut().enumerate() {
+        if emoji[c] & pictographic == 0 {
+            continue;
+        }
+        if *value & GCB_MASK != 0 {
+            return Err(format!(
+                "U+{c:04X} is Extended_Pictographic but its Grapheme_Cluster_Break isn't Other"
+            ));
+        }
+        *value |= EXTENDED_PICTOGRAPHIC;
+    }
+    Ok(values)
+}
+
+/// Returns the trie value for a long **Grapheme_Cluster_Break** property
+/// value alias, matching the `GCBProperty` enum in the main crate.
+pub fn encode_gcb(value: &str) -> Result<u8, String> {
+    Ok(match value {
+        "Other" => 0x00,
+        "CR" => 0x01,
+        "Control" => 0x02,
+        "Extend" => 0x03,
+        "L" => 0x05,
+        "LF" => 0x06,
+        "LV" => 0x07,
+        "LVT" => 0x08,
+        "Prepend" => 0x09,
+        "Regional_Indicator" => 0x0a,
+        "SpacingMark" => 0x0b,
+        "T" => 0x0c,
+        "V" => 0x0d,
+        "ZWJ" => 0x0e,
+        _ => return Err(format!("invalid Grapheme_Cluster_Break {value:?}")),
+    })
+}
+
+/// Returns the trie value for an **Indic_Conjunct_Break** property value,
+/// matching the `InCBProperty` enum in the main crate.
+pub fn encode_incb(value: &str) -> Result<u8, String> {
+    Ok(match value {
+        "None" => 0x00,
+        "Consonant" => 0x10,
+        "Extend" => 0x20,
+        "Linker" => 0x30,
+        _ => return Err(format!("invalid Indic_Conjunct_Break {value:?}")),
+    })
+}
+
+/// Returns the trie value for the given byte sequence from the result of
+/// [`parse`], given its current value.
+///
+/// As with [`crate::derived::apply`], sequences that aren't valid UTF-8
+/// keep their current values.
+pub fn apply(values: &[u8], s: &[u8], value: u8) -> u8 {
+    match std::str::from_utf8(s).ok().and_then(|s| s.chars().next()) {
+        Some(c) => values[c as usize],
+        None => value,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,82 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const GRAPHEME_BREAK: &str = "\
+# GraphemeBreakProperty-16.0.0.txt
+
+# @missing: 0000..10FFFF; Other
+
+000A          ; LF # Cc       <control-000A>
+000D          ; CR # Cc       <control-000D>
+0300..036F    ; Extend # Mn [112] COMBINING GRAVE ACCENT..COMBINING LATIN SMALL LETTER X
+094D          ; Extend # Mn       DEVANAGARI SIGN VIRAMA
+1F1E6..1F1FF  ; Regional_Indicator # So  [26] REGIONAL INDICATOR SYMBOL LETTER A..
+200D          ; ZWJ # Cf       ZERO WIDTH JOINER
+FE00..FE0F    ; Extend # Mn  [16] VARIATION SELECTOR-1..VARIATION SELECTOR-16
+";
+
+const EMOJI_DATA: &str = "\
+00A9          ; Emoji                # E0.6   [1] (©️)       copyright
+00A9          ; Extended_Pictographic# E0.6   [1] (©️)       copyright
+";
+
+const DERIVED_CORE: &str = "\
+# @missing: 0000..10FFFF; InCB; None
+
+0041..005A    ; Alphabetic # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+0915..0939    ; InCB; Consonant # Lo  [37] DEVANAGARI LETTER KA..DEVANAGARI LETTER HA
+094D          ; InCB; Linker # Mn       DEVANAGARI SIGN VIRAMA
+0300..036F    ; InCB; Extend # Mn [112] COMBINING GRAVE ACCENT..
+FE00..FE0F    ; Default_Ignorable_Code_Point # Mn  [16] VARIATION SELECTOR-1..
+";
+
+const PROP_LIST: &str = "\
+0020          ; White_Space # Zs       SPACE
+FE00..FE0F    ; Variation_Selector # Mn  [16] VARIATION SELECTOR-1..
+";
+
+#[test]
+fn parse_properties() {
+    let got = parse(GRAPHEME_BREAK, EMOJI_DATA, DERIVED_CORE, PROP_LIST).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x20], 0x00);
+    assert_eq!(got[0x41], 0x00);
+    assert_eq!(got[0x0a], 0x06);
+    assert_eq!(got[0x0d], 0x01);
+    assert_eq!(got[0xa9], 0x04);
+    assert_eq!(got[0x301], 0x23);
+    assert_eq!(got[0x915], 0x10);
+    assert_eq!(got[0x94d], 0x33);
+    assert_eq!(got[0x200d], 0x0e);
+    assert_eq!(got[0xfe0f], 0xc3);
+    assert_eq!(got[0x1f1e6], 0x0a);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse("0041 Other", "", "", "").unwrap_err(),
+        "line 1: missing ';'"
+    );
+    assert_eq!(
+        parse("\n0041; Mystery", "", "", "").unwrap_err(),
+        "line 2: invalid Grapheme_Cluster_Break \"Mystery\""
+    );
+    assert_eq!(
+        parse("", "", "0915; InCB; Vowel", "").unwrap_err(),
+        "line 1: invalid Indic_Conjunct_Break \"Vowel\""
+    );
+    assert_eq!(
+        parse("00A9; Extend", EMOJI_DATA, "", "").unwrap_err(),
+        "U+00A9 is Extended_Pictographic but its Grapheme_Cluster_Break isn't Other"
+    );
+}
+
+#[test]
+fn apply_values() {
+    let values = parse(GRAPHEME_BREAK, EMOJI_DATA, DERIVED_CORE, PROP_LIST).unwrap();
+    assert_eq!(apply(&values, "\u{94D}".as_bytes(), 0xff), 0x33);
+    assert_eq!(apply(&values, b"A", 0xff), 0x00);
+    assert_eq!(apply(&values, b"\xed\xa0\x80", 0x02), 0x02);
+}
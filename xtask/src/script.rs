@@ -0,0 +1,281 @@
+//! Generates the **Script** and **Script_Extensions** property tables in
+//! `src/script/table.rs` from the `PropertyValueAliases.txt`, `Scripts.txt`
+//! and `ScriptExtensions.txt` files of the Unicode character database.
+//!
+//! The generated file declares the `Script` enum itself, with one variant
+//! for each script listed in `PropertyValueAliases.txt`, so that a new
+//! version of the database can add scripts without any handwritten changes.
+//! The table value for each script is the position of its variant in the
+//! enum, and both properties are stored as sorted lists of runs in the same
+//! form as the **Age** table.
+//!
+//! `ScriptExtensions.txt` only lists the characters whose extensions differ
+//! from their script, so the extensions table has runs only for those,
+//! whose values are one more than the position of their set of scripts in
+//! the generated `SCRIPT_EXTENSIONS` array. All other characters are in
+//! runs whose value is zero.
+
+use std::fmt::Write;
+
+use crate::ucd;
+
+/// The line that separates the handwritten source code at the start of the
+/// generated file from the generated part.
+pub const GENERATED_MARKER: &str =
+    "// Everything below this line is generated by `cargo xtask generate-script-tables`.\n";
+
+/// The abbreviation of the script that unassigned characters belong to.
+const UNKNOWN: &str = "Zzzz";
+
+/// The property values and tables parsed from the Unicode character
+/// database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scripts {
+    /// The short and long names of each script, in the order of their
+    /// table values.
+    pub names: Vec<(String, String)>,
+    /// The sorted ranges of characters in each script, with the table
+    /// values of their scripts.
+    pub scripts: Vec<(u32, u32, u8)>,
+    /// The sorted ranges of characters whose script extensions differ from
+    /// their script, with the table values of their extensions.
+    pub extensions: Vec<(u32, u32, u8)>,
+    /// The distinct sets of scripts in `extensions`, each holding the table
+    /// values of its scripts in ascending order.
+    pub sets: Vec<Vec<u8>>,
+}
+
+impl Scripts {
+    /// Parses the content of the three files from the Unicode character
+    /// database.
+    pub fn parse(aliases: &str, scripts: &str, extensions: &str) -> Result<Self, String> {
+        let aliases = parse_aliases(aliases)?;
+        if aliases.len() > 256 {
+            return Err(format!(
+                "too many scripts for 8-bit values ({})",
+                aliases.len()
+            ));
+        }
+        let value = |name: &str| {
+            aliases
+                .iter()
+                .position(|names| names.iter().any(|n| n == name))
+                .map(|i| i as u8)
+                .ok_or_else(|| format!("unknown script {name:?}"))
+        };
+
+        let mut script_ranges = Vec::new();
+        for line in ucd::parse_lines(scripts)? {
+            let v = value(line.value).map_err(|msg| line.error(&msg))?;
+            script_ranges.push((line.start, line.end, v));
+        }
+        ucd::sort_ranges(&mut script_ranges)?;
+
+        let mut set_ranges = Vec::new();
+        for line in ucd::parse_lines(extensions)? {
+            let mut set = Vec::new();
+            for name in line.value.split_whitespace() {
+                set.push(value(name).map_err(|msg| line.error(&msg))?);
+            }
+            set.sort();
+            set.dedup();
+            if set.is_empty() {
+                return Err(line.error("no scripts"));
+            }
+            set_ranges.push((line.start, line.end, set));
+        }
+        set_ranges.sort_by_key(|r| r.0);
+
+        // The sets are numbered in order of their first appearance, so that
+        // the output doesn't depend on the order of lines in the file.
+        let mut sets: Vec<Vec<u8>> = Vec::new();
+        let mut ext_ranges = Vec::new();
+        for (start, end, set) in set_ranges {
+            let i = match sets.iter().position(|s| *s == set) {
+                Some(i) => i,
+                None if sets.len() < 255 => {
+                    sets.push(set);
+                    sets.len() - 1
+                }
+                None => {
+                    return Err("too many sets of script extensions for 8-bit values".to_string());
+                }
+            };
+            ext_ranges.push((start, end, i as u8 + 1));
+        }
+        ucd::sort_ranges(&mut ext_ranges)?;
+
+        Ok(Self {
+            names: aliases
+                .into_iter()
+                .map(|names| (names[0].clone(), names[1].clone()))
+                .collect(),
+            scripts: script_ranges,
+            extensions: ext_ranges,
+            sets,
+        })
+    }
+
+    /// Returns the table value of the script that unassigned characters
+    /// belong to.
+    pub fn unknown(&self) -> u8 {
+        // parse_aliases guarantees that this script is present.
+        self.names.iter().position(|(s, _)| s == UNKNOWN).unwrap() as u8
+    }
+
+    /// Returns the runs for the **Script** property.
+    pub fn script_runs(&self) -> Vec<u32> {
+        ucd::runs(&self.scripts, self.unknown())
+    }
+
+    /// Returns the runs for the **Script_Extensions** property.
+    pub fn extension_runs(&self) -> Vec<u32> {
+        ucd::runs(&self.extensions, 0)
+    }
+
+    /// Returns the table values of the scripts in the given character's
+    /// **Script_Extensions**, according to the parsed ranges.
+    pub fn extensions_for(&self, c: char) -> Vec<u8> {
+        match ucd::value_for(&self.extensions, c, 0) {
+            0 => vec![ucd::value_for(&self.scripts, c, self.unknown())],
+            i => self.sets[i as usize - 1].clone(),
+        }
+    }
+
+    /// Returns the size in bytes of the generated tables that the lookup
+    /// functions use, not counting the script names, on a target with
+    /// 64-bit pointers.
+    pub fn size(&self) -> usize {
+        let runs = self.script_runs().len() + self.extension_runs().len();
+        let sets: usize = self.sets.iter().map(Vec::len).sum();
+        runs * 4 + self.names.len() + self.sets.len() * 16 + sets
+    }
+
+    /// Renders the content of a `table.rs` file, with the given handwritten
+    /// source code before the generated part.
+    pub fn render(&self, preamble: &str) -> String {
+        let variant = |i: u8| variant_name(&self.names[i as usize].1);
+        let mut ret = preamble.to_string();
+        ret.push_str(GENERATED_MARKER);
+        ret.push('\n');
+
+        ret.push_str(ENUM_DOC);
+        ret.push_str("pub enum Script {\n");
+        for (short, long) in &self.names {
+            writeln!(ret, "    /// `{short}`\n    {},", variant_name(long)).unwrap();
+        }
+        ret.push_str("}\n\n");
+
+        // The arrays are laid out in the same way as rustfmt would, so that
+        // formatting the generated file doesn't change it.
+        ret.push_str("/// Every script, in the order of their table values.\n");
+        ret.push_str(ucd::ARRAY_ATTRS);
+        writeln!(ret, "static SCRIPTS: [Script; {}] = [", self.names.len()).unwrap();
+        for i in 0..self.names.len() {
+            writeln!(ret, "    Script::{},", variant(i as u8)).unwrap();
+        }
+        ret.push_str("];\n\n");
+
+        ret.push_str("/// The short and long names of every script, in the order of their\n");
+        ret.push_str("/// table values.\n");
+        writeln!(
+            ret,
+            "static SCRIPT_NAMES: [(&str, &str); {}] = [",
+            self.names.len()
+        )
+        .unwrap();
+        for (short, long) in &self.names {
+            writeln!(ret, "    (\"{short}\", \"{long}\"),").unwrap();
+        }
+        ret.push_str("];\n\n");
+
+        ret.push_str("/// The distinct sets of **Script_Extensions** that differ from the\n");
+        ret.push_str("/// **Script** of the characters they belong to.\n");
+        ret.push_str(ucd::ARRAY_ATTRS);
+        writeln!(
+            ret,
+            "static SCRIPT_EXTENSIONS: [&[Script]; {}] = [",
+            self.sets.len()
+        )
+        .unwrap();
+        for set in &self.sets {
+            let items: Vec<String> = set
+                .iter()
+                .map(|&i| format!("Script::{}", variant(i)))
+                .collect();
+            // rustfmt's default limit for an array on one line is 60
+            // characters.
+            let array = format!("[{}]", items.join(", "));
+            if array.len() <= 60 {
+                writeln!(ret, "    &{array},").unwrap();
+            } else {
+                ret.push_str("    &[\n");
+                for item in items {
+                    writeln!(ret, "        {item},").unwrap();
+                }
+                ret.push_str("    ],\n");
+            }
+        }
+        ret.push_str("];\n\n");
+
+        ucd::render_u32s(&mut ret, "SCRIPT_RUNS", &self.script_runs());
+        ret.push('\n');
+        ucd::render_u32s(&mut ret, "SCRIPT_EXTENSIONS_RUNS", &self.extension_runs());
+        ret
+    }
+}
+
+/// The documentation and attributes of the generated `Script` enum.
+const ENUM_DOC: &str = "\
+/// A value of the Unicode **Script** property, which is the script that a
+/// character belongs to.
+///
+/// Characters that are used with several scripts, like most punctuation and
+/// digits, belong to [`Script::Common`], and combining marks that take the
+/// script of the character they are applied to belong to
+/// [`Script::Inherited`]. Unassigned characters belong to
+/// [`Script::Unknown`].
+///
+/// Each variant is documented with the script's four-letter ISO 15924
+/// code, which [`Script::short_name`] also returns. New versions of the
+/// Unicode Standard add more scripts, so this enum is non-exhaustive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+";
+
+/// Parses the **Script** lines of `PropertyValueAliases.txt`, like
+/// `sc ; Copt ; Coptic ; Qaac`, returning the names of each script with
+/// the short name first and the long name second, sorted by short name.
+pub fn parse_aliases(src: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut ret = Vec::new();
+    for (i, line) in src.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        if fields[0] != "sc" {
+            continue;
+        }
+        if fields.len() < 3 {
+            return Err(format!("line {}: missing script names", i + 1));
+        }
+        ret.push(
+            fields[1..]
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>(),
+        );
+    }
+    ret.sort();
+    if !ret.iter().any(|names| names[0] == UNKNOWN) {
+        return Err(format!("no {UNKNOWN} script"));
+    }
+    Ok(ret)
+}
+
+/// Returns the name of the enum variant for the script with the given long
+/// name.
+pub fn variant_name(long: &str) -> String {
+    long.replace('_', "")
+}
+
+#[cfg(test)]
+mod tests;
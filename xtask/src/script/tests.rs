@@ -0,0 +1,120 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+const ALIASES: &str = "\
+# PropertyValueAliases-16.0.0.txt
+
+# Script (sc)
+
+sc ; Arab                             ; Arabic
+sc ; Copt                             ; Coptic                           ; Qaac
+sc ; Latn                             ; Latin
+sc ; Zinh                             ; Inherited                        ; Qaai
+sc ; Zyyy                             ; Common
+sc ; Zzzz                             ; Unknown
+
+# Sentence_Break (SB)
+
+SB ; AT                               ; ATerm
+";
+
+#[test]
+fn parse_property_value_aliases() {
+    assert_eq!(
+        parse_aliases(ALIASES).unwrap(),
+        &[
+            vec!["Arab", "Arabic"],
+            vec!["Copt", "Coptic", "Qaac"],
+            vec!["Latn", "Latin"],
+            vec!["Zinh", "Inherited", "Qaai"],
+            vec!["Zyyy", "Common"],
+            vec!["Zzzz", "Unknown"],
+        ]
+    );
+    assert_eq!(
+        parse_aliases("sc ; Latn ; Latin").unwrap_err(),
+        "no Zzzz script"
+    );
+    assert_eq!(
+        parse_aliases("sc ; Zzzz").unwrap_err(),
+        "line 1: missing script names"
+    );
+}
+
+#[test]
+fn parse_scripts() {
+    let scripts = "\
+0000..0040    ; Common # Cc  [32] <control-0000>..<control-001F>
+0041..005A    ; Latin # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+0300          ; Inherited # Mn       COMBINING GRAVE ACCENT
+0600          ; Arabic # Cf       ARABIC NUMBER SIGN
+064B          ; Inherited # Mn       ARABIC FATHATAN
+";
+    let extensions = "\
+064B          ; Arab # Mn       ARABIC FATHATAN
+0300          ; Copt Latn # Mn       COMBINING GRAVE ACCENT
+";
+    let got = Scripts::parse(ALIASES, scripts, extensions).unwrap();
+    assert_eq!(got.names[1], ("Copt".to_string(), "Coptic".to_string()));
+    assert_eq!(got.unknown(), 5);
+    assert_eq!(got.sets, &[vec![1, 2], vec![0]]);
+    assert_eq!(
+        got.script_runs(),
+        &[
+            run(0x0000, 4),
+            run(0x0041, 2),
+            run(0x005B, 5),
+            run(0x0300, 3),
+            run(0x0301, 5),
+            run(0x0600, 0),
+            run(0x0601, 5),
+            run(0x064B, 3),
+            run(0x064C, 5),
+        ]
+    );
+    assert_eq!(
+        got.extension_runs(),
+        &[
+            run(0x0000, 0),
+            run(0x0300, 1),
+            run(0x0301, 0),
+            run(0x064B, 2),
+            run(0x064C, 0),
+        ]
+    );
+    assert_eq!(got.extensions_for('A'), &[2]);
+    assert_eq!(got.extensions_for('\u{0300}'), &[1, 2]);
+    assert_eq!(got.extensions_for('\u{064B}'), &[0]);
+    assert_eq!(got.extensions_for('\u{0378}'), &[5]);
+
+    assert_eq!(
+        Scripts::parse(ALIASES, "0041 ; Klingon", "").unwrap_err(),
+        "line 1: unknown script \"Klingon\""
+    );
+    assert_eq!(
+        Scripts::parse(ALIASES, "", "0041 ; Latn\n0042 ;").unwrap_err(),
+        "line 2: no scripts"
+    );
+}
+
+#[test]
+fn render() {
+    let got = Scripts::parse(
+        ALIASES,
+        "0041 ; Latin\n0300 ; Inherited",
+        "0300 ; Latn Copt",
+    )
+    .unwrap()
+    .render("// preamble\n");
+    assert!(got.starts_with(&format!("// preamble\n{GENERATED_MARKER}\n")));
+    assert!(got.contains("pub enum Script {\n    /// `Arab`\n    Arabic,\n"));
+    assert!(got.contains("    (\"Zinh\", \"Inherited\"),\n"));
+    assert!(got.contains(
+        "static SCRIPT_EXTENSIONS: [&[Script]; 1] = [\n    &[Script::Coptic, Script::Latin],\n];"
+    ));
+}
+
+fn run(start: u32, value: u8) -> u32 {
+    start << 8 | value as u32
+}
@@ -0,0 +1,348 @@
+//! A model of the UTF-8 trie used for the character property tables in
+//! `src/properties/table.rs`, along with the functions for reading, writing,
+//! and shrinking it.
+//!
+//! The trie is made of two arrays divided into blocks of 64 entries, one
+//! entry for each possible UTF-8 continuation byte. `values` holds the
+//! property values: the first two blocks are indexed directly by ASCII
+//! characters, and each later block is a leaf node covering the final byte
+//! of a multi-byte sequence. `index` holds pointers to other nodes: its
+//! fourth block is indexed by the lead byte of a multi-byte sequence, and
+//! the others are interior nodes for the middle bytes of three- and four-byte
+//! sequences.
+//!
+//! A pointer `p` in `index` refers to the block `p + 2` of whichever array the
+//! next step of the lookup uses, because the lookup adds the continuation
+//! byte itself (0x80 through 0xbf) to `p << 6` rather than first subtracting
+//! 0x80 from it. That means that the first two blocks of `values` are not
+//! addressable as leaf nodes, and that pointer `1` in `index` always refers
+//! to the block holding the lead byte entries.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The number of entries in each block of the trie.
+pub const BLOCK_LEN: usize = 64;
+
+/// The block in `index` whose last 64 entries are indexed by lead bytes.
+const LEAD_BLOCK: usize = 3;
+
+/// The name of the array holding property values in the generated source.
+const VALUES_NAME: &str = "GRAPHEMES_VALUES";
+
+/// The name of the array holding node pointers in the generated source.
+const INDEX_NAME: &str = "GRAPHEMES_INDEX";
+
+/// The attributes written before each array in the generated source, which
+/// allow placing the arrays in a link section chosen by the build script.
+const ARRAY_ATTRS: &str = "#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!(\"GRAPHEME_MACHINE_LINK_SECTION\"))
+)]
+";
+
+/// The property lookup trie, along with the handwritten source code that
+/// appears before the arrays in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trie {
+    pub preamble: String,
+    pub values: Vec<u8>,
+    pub index: Vec<u8>,
+}
+
+impl Trie {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Trie::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let start = src
+            .find(&format!("{ARRAY_ATTRS}static {VALUES_NAME}"))
+            .ok_or_else(|| format!("no {VALUES_NAME} array"))?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            values: parse_array(src, VALUES_NAME)?,
+            index: parse_array(src, INDEX_NAME)?,
+        })
+    }
+
+    /// Renders the trie as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        render_array(&mut ret, VALUES_NAME, &self.values);
+        ret.push('\n');
+        render_array(&mut ret, INDEX_NAME, &self.index);
+        ret
+    }
+
+    /// Returns the raw property value for the given UTF-8 sequence, following
+    /// the same steps as `graphemes_lookup` in the main crate.
+    pub fn lookup(&self, s: &[u8]) -> u8 {
+        let leaf = |i: usize, b: u8| self.values[(i << 6) + b as usize];
+        let node = |i: usize, b: u8| self.index[(i << 6) + b as usize] as usize;
+        match *s {
+            [a] => self.values[a as usize],
+            [a, b] => leaf(self.index[a as usize] as usize, b),
+            [a, b, c] => leaf(node(self.index[a as usize] as usize, b), c),
+            [a, b, c, d] => leaf(node(node(self.index[a as usize] as usize, b), c), d),
+            _ => panic!("invalid UTF-8 sequence length {}", s.len()),
+        }
+    }
+
+    /// Returns the total size of both arrays in bytes.
+    pub fn size(&self) -> usize {
+        self.values.len() + self.index.len()
+    }
+
+    /// Returns an equivalent trie in which no two blocks of the same array
+    /// are identical, by merging each set of nodes that have identical
+    /// contents into a single node.
+    ///
+    /// This works from the leaves upwards, so that interior nodes whose
+    /// children were merged can themselves be merged, in the same way as
+    /// minimizing a DAFSA. Every entry of every reachable node is preserved,
+    /// even those that no valid UTF-8 sequence can reach, so that a lookup
+    /// of any sequence beginning with an ASCII character or a lead byte
+    /// gives the same result as before.
+    pub fn dedup(&self) -> Result<Self, String> {
+        let mut builder = Builder::new(self);
+        // The original generator puts an all-zero node at pointer 0, which
+        // then serves as an empty node at every depth because its children
+        // all point back to itself. Visiting it first keeps it at pointer 0
+        // in the new trie, so that the deeper empty nodes can share it too.
+        builder.node(0, 1)?;
+        let mut leads = [0u8; BLOCK_LEN];
+        for (i, lead) in leads.iter_mut().enumerate() {
+            let b = 0xc0 + i;
+            let old = self.index[b] as usize;
+            *lead = match b {
+                0xc0..=0xdf => builder.leaf(old)?,
+                0xe0..=0xef => builder.node(old, 1)?,
+                _ => builder.node(old, 2)?,
+            };
+        }
+        Ok(builder.finish(leads))
+    }
+
+    /// Returns a deduplicated trie in which each value is replaced by the
+    /// result of calling `f` with the byte sequence it's for and its value
+    /// in this trie.
+    ///
+    /// As with [`Trie::dedup`], `f` is also called for the byte sequences
+    /// that aren't valid UTF-8 but can still be looked up, and should
+    /// normally return their values unchanged.
+    pub fn map_values(&self, f: impl Fn(&[u8], u8) -> u8) -> Result<Self, String> {
+        let mut builder = Builder::new(self);
+        for (b, v) in builder.values[..0x80].iter_mut().enumerate() {
+            *v = f(&[b as u8], *v);
+        }
+        // Keep the empty nodes at pointer 0, as described in Trie::dedup.
+        builder.intern_leaf(vec![0; BLOCK_LEN])?;
+        builder.intern_node(vec![0; BLOCK_LEN])?;
+        let mut leads = [0u8; BLOCK_LEN];
+        for (i, lead) in leads.iter_mut().enumerate() {
+            let b = 0xc0 + i as u8;
+            let remain = match b {
+                0xc0..=0xdf => 1,
+                0xe0..=0xef => 2,
+                _ => 3,
+            };
+            *lead = self.map_subtree(&mut builder, &mut vec![b], remain, &f)?;
+        }
+        Ok(builder.finish(leads))
+    }
+
+    /// Builds the node for the sequences beginning with `prefix` that have
+    /// `remain` more continuation bytes, returning its new pointer.
+    fn map_subtree(
+        &self,
+        builder: &mut Builder,
+        prefix: &mut Vec<u8>,
+        remain: usize,
+        f: &impl Fn(&[u8], u8) -> u8,
+    ) -> Result<u8, String> {
+        let mut content = Vec::with_capacity(BLOCK_LEN);
+        for b in 0x80..=0xbf {
+            prefix.push(b);
+            content.push(match remain {
+                1 => f(prefix, self.lookup(prefix)),
+                _ => self.map_subtree(builder, prefix, remain - 1, f)?,
+            });
+            prefix.pop();
+        }
+        match remain {
+            1 => builder.intern_leaf(content),
+            _ => builder.intern_node(content),
+        }
+    }
+}
+
+/// Checks that every character has the same properties in both tries.
+pub fn verify_same_lookups(a: &Trie, b: &Trie) -> Result<(), String> {
+    let mut buf = [0; 4];
+    for c in '\0'..=char::MAX {
+        let s = c.encode_utf8(&mut buf).as_bytes();
+        let (want, got) = (a.lookup(s), b.lookup(s));
+        if want != got {
+            return Err(format!(
+                "wrong properties for {c:?}: got {got:#04x}, want {want:#04x}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Incrementally builds a deduplicated copy of a [`Trie`].
+struct Builder<'a> {
+    old: &'a Trie,
+    values: Vec<u8>,
+    index: Vec<u8>,
+    leaves: HashMap<Vec<u8>, u8>,
+    nodes: HashMap<Vec<u8>, u8>,
+}
+
+impl<'a> Builder<'a> {
+    fn new(old: &'a Trie) -> Self {
+        // The ASCII blocks of the values array are copied verbatim, and the
+        // first four blocks of the index array are always present because
+        // the lead byte entries are in the fourth.
+        Self {
+            old,
+            values: old.values[..2 * BLOCK_LEN].to_vec(),
+            index: vec![0; (LEAD_BLOCK + 1) * BLOCK_LEN],
+            leaves: HashMap::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Returns the new pointer for the leaf node at the given old pointer.
+    fn leaf(&mut self, old: usize) -> Result<u8, String> {
+        let content = block(&self.old.values, old)?.to_vec();
+        self.intern_leaf(content)
+    }
+
+    /// Returns the pointer for a leaf node with the given content, adding
+    /// it to the new trie if there isn't one already.
+    fn intern_leaf(&mut self, content: Vec<u8>) -> Result<u8, String> {
+        if let Some(p) = self.leaves.get(&content) {
+            return Ok(*p);
+        }
+        let p = pointer(self.values.len() / BLOCK_LEN - 2)?;
+        self.values.extend_from_slice(&content);
+        self.leaves.insert(content, p);
+        Ok(p)
+    }
+
+    /// Returns the new pointer for the interior node at the given old
+    /// pointer, whose children are `depth - 1` levels above the leaves.
+    fn node(&mut self, old: usize, depth: usize) -> Result<u8, String> {
+        let old_content = block(&self.old.index, old)?.to_vec();
+        let mut content = Vec::with_capacity(BLOCK_LEN);
+        for child in old_content {
+            content.push(match depth {
+                1 => self.leaf(child as usize)?,
+                _ => self.node(child as usize, depth - 1)?,
+            });
+        }
+        self.intern_node(content)
+    }
+
+    /// Returns the pointer for an interior node with the given content,
+    /// adding it to the new trie if there isn't one already.
+    fn intern_node(&mut self, content: Vec<u8>) -> Result<u8, String> {
+        if let Some(p) = self.nodes.get(&content) {
+            return Ok(*p);
+        }
+        // The first node goes in the otherwise-unused third block, but the
+        // fourth block is reserved for the lead byte entries.
+        let p = match self.nodes.len() {
+            0 => 0,
+            _ => pointer(self.index.len() / BLOCK_LEN - 2)?,
+        };
+        let start = (p as usize + 2) * BLOCK_LEN;
+        if start == self.index.len() {
+            self.index.extend_from_slice(&content);
+        } else {
+            self.index[start..start + BLOCK_LEN].copy_from_slice(&content);
+        }
+        self.nodes.insert(content, p);
+        Ok(p)
+    }
+
+    /// Returns the new trie, with the given pointers for the sequences
+    /// beginning with each lead byte.
+    fn finish(mut self, leads: [u8; BLOCK_LEN]) -> Trie {
+        let lead_start = LEAD_BLOCK * BLOCK_LEN;
+        self.index[lead_start..lead_start + BLOCK_LEN].copy_from_slice(&leads);
+        Trie {
+            preamble: self.old.preamble.clone(),
+            values: self.values,
+            index: self.index,
+        }
+    }
+}
+
+/// Returns the node block that the given pointer refers to in `array`.
+fn block(array: &[u8], p: usize) -> Result<&[u8], String> {
+    let start = (p + 2) * BLOCK_LEN;
+    array
+        .get(start..start + BLOCK_LEN)
+        .ok_or_else(|| format!("pointer {p:#x} is out of bounds"))
+}
+
+fn pointer(p: usize) -> Result<u8, String> {
+    u8::try_from(p).map_err(|_| format!("too many nodes for 8-bit pointers ({p})"))
+}
+
+fn parse_array(src: &str, name: &str) -> Result<Vec<u8>, String> {
+    let start = src
+        .find(&format!("static {name}"))
+        .ok_or_else(|| format!("no {name} array"))?;
+    let body_start = src[start..]
+        .find("= [")
+        .ok_or_else(|| format!("malformed {name} array"))?
+        + start
+        + 3;
+    let body_end = src[body_start..]
+        .find("];")
+        .ok_or_else(|| format!("unterminated {name} array"))?
+        + body_start;
+    let mut ret = Vec::new();
+    for line in src[body_start..body_end].lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        for item in line.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let hex = item
+                .strip_prefix("0x")
+                .ok_or_else(|| format!("invalid item {item:?} in {name}"))?;
+            let v = u8::from_str_radix(hex, 16)
+                .map_err(|err| format!("invalid item {item:?} in {name}: {err}"))?;
+            ret.push(v);
+        }
+    }
+    Ok(ret)
+}
+
+fn render_array(out: &mut String, name: &str, items: &[u8]) {
+    out.push_str(ARRAY_ATTRS);
+    writeln!(out, "static {name}: [u8; {}] = [", items.len()).unwrap();
+    for (i, block) in items.chunks(BLOCK_LEN).enumerate() {
+        writeln!(
+            out,
+            "    // block {i:#x} starting at index {:#x}",
+            i * BLOCK_LEN
+        )
+        .unwrap();
+        for row in block.chunks(16) {
+            out.push_str("   ");
+            for v in row {
+                write!(out, " {v:#04x},").unwrap();
+            }
+            out.push('\n');
+        }
+    }
+    out.push_str("];\n");
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+fn current_table() -> String {
+    std::fs::read_to_string(crate::table_path("16.0").unwrap()).unwrap()
+}
+
+#[test]
+fn render_round_trip() {
+    let src = current_table();
+    let trie = Trie::parse(&src).unwrap();
+    assert_eq!(trie.render(), src);
+}
+
+#[test]
+fn dedup_current_table() {
+    let old = Trie::parse(&current_table()).unwrap();
+    let new = old.dedup().unwrap();
+    verify_same_lookups(&old, &new).unwrap();
+    assert!(new.size() <= old.size());
+    // Deduplicating an already-deduplicated trie changes nothing.
+    assert_eq!(new.dedup().unwrap(), new);
+}
+
+#[test]
+fn map_values_current_table() {
+    // Mapping every value to itself rebuilds the same deduplicated trie.
+    let old = Trie::parse(&current_table()).unwrap();
+    let same = old.map_values(|_, v| v).unwrap();
+    assert_eq!(same, old.dedup().unwrap());
+
+    let flipped = old
+        .map_values(|s, v| if s == "é".as_bytes() { v ^ 0x80 } else { v })
+        .unwrap();
+    assert_eq!(
+        flipped.lookup("é".as_bytes()),
+        old.lookup("é".as_bytes()) ^ 0x80
+    );
+    assert_eq!(flipped.lookup("è".as_bytes()), old.lookup("è".as_bytes()));
+    assert_eq!(flipped.lookup(b"a"), old.lookup(b"a"));
+}
+
+#[test]
+fn dedup_merges_identical_subtrees() {
+    // A trie where the two-byte sequences for U+0080..U+00BF and
+    // U+00C0..U+00FF have separate but identical leaves, and the three-byte
+    // sequences beginning with 0xE1 and 0xE2 have separate but identical
+    // interior nodes.
+    let mut values = vec![0; 6 * BLOCK_LEN];
+    values[3 * BLOCK_LEN..4 * BLOCK_LEN].fill(1);
+    values[4 * BLOCK_LEN..5 * BLOCK_LEN].fill(1);
+    values[5 * BLOCK_LEN] = 2;
+    let mut index = vec![0; 7 * BLOCK_LEN];
+    index[0xc2] = 1;
+    index[0xc3] = 2;
+    index[0xe1] = 2;
+    index[0xe2] = 3;
+    index[0xe3] = 4;
+    index[4 * BLOCK_LEN..5 * BLOCK_LEN].fill(3);
+    index[5 * BLOCK_LEN..6 * BLOCK_LEN].fill(3);
+    index[6 * BLOCK_LEN] = 3;
+    let old = Trie {
+        preamble: String::new(),
+        values,
+        index,
+    };
+
+    let new = old.dedup().unwrap();
+    verify_same_lookups(&old, &new).unwrap();
+    assert_eq!(new.values.len(), 5 * BLOCK_LEN);
+    assert_eq!(new.index.len(), 6 * BLOCK_LEN);
+    assert_eq!(new.lookup("\u{00C0}".as_bytes()), 1);
+    assert_eq!(new.lookup(&[0xe1, 0x80, 0x80]), 2);
+    assert_eq!(new.index[0xc2], new.index[0xc3]);
+    assert_eq!(new.index[0xe1], new.index[0xe2]);
+}
@@ -0,0 +1,197 @@
+//! Helpers shared by the commands that generate lookup tables from files of
+//! the Unicode character database, which all use the same line format and
+//! are all rendered as sorted lists of runs.
+//!
+//! Each run is a `u32` holding its first code point shifted left by eight
+//! bits, with the table value for all of the characters up to the start of
+//! the next run in the low eight bits.
+
+use std::fmt::Write;
+
+/// The attributes written before each array in the generated source, which
+/// are the same as for the grapheme cluster break tables.
+pub const ARRAY_ATTRS: &str = "#[cfg_attr(
+    grapheme_machine_link_section,
+    unsafe(link_section = env!(\"GRAPHEME_MACHINE_LINK_SECTION\"))
+)]
+";
+
+/// A line from a file of the Unicode character database, giving a property
+/// value for a character or a range of characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub start: u32,
+    pub end: u32,
+    pub value: &'a str,
+}
+
+impl Line<'_> {
+    /// Returns an error message that refers to this line.
+    pub fn error(&self, msg: &str) -> String {
+        format!("line {}: {msg}", self.number)
+    }
+}
+
+/// Parses lines like `0600..0604    ; Arabic #  [5] ARABIC NUMBER SIGN..`,
+/// skipping comments and blank lines.
+///
+/// Files that contain more than one property, like
+/// `PropertyValueAliases.txt`, use a different format and so can't be
+/// parsed this way.
+pub fn parse_lines(src: &str) -> Result<Vec<Line<'_>>, String> {
+    let mut ret = Vec::new();
+    for (i, line) in src.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let err = |msg: &str| format!("line {}: {msg}", i + 1);
+        let (range, value) = line.split_once(';').ok_or_else(|| err("missing ';'"))?;
+        let (start, end) = match range.trim().split_once("..") {
+            Some((start, end)) => (start, end),
+            None => (range.trim(), range.trim()),
+        };
+        let code_point = |s: &str| u32::from_str_radix(s, 16).map_err(|e| err(&e.to_string()));
+        let (start, end) = (code_point(start)?, code_point(end)?);
+        if start > end || end > char::MAX as u32 {
+            return Err(err("invalid range"));
+        }
+        ret.push(Line {
+            number: i + 1,
+            start,
+            end,
+            value: value.trim(),
+        });
+    }
+    Ok(ret)
+}
+
+/// The prefix of the comment lines that give the default value for ranges
+/// of characters not listed explicitly, like `# @missing: 3400..4DBF; W`.
+const MISSING_PREFIX: &str = "# @missing:";
+
+/// Returns the content of the `@missing` lines of `src` without their
+/// prefix, and blank lines in place of all of the others, so that
+/// [`parse_lines`] can parse them with the same line numbers as `src`.
+pub fn missing_lines(src: &str) -> String {
+    src.lines()
+        .map(|line| line.strip_prefix(MISSING_PREFIX).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Sorts ranges given as `(start, end, value)` by code point, returning an
+/// error if any of them overlap.
+pub fn sort_ranges(ranges: &mut [(u32, u32, u8)]) -> Result<(), String> {
+    ranges.sort_by_key(|r| r.0);
+    if let Some(w) = ranges.windows(2).find(|w| w[0].1 >= w[1].0) {
+        return Err(format!("overlapping ranges at {:04X}", w[1].0));
+    }
+    Ok(())
+}
+
+/// Returns the table value for the given character according to `ranges`,
+/// which must be sorted as by [`sort_ranges`], or `default` if no range
+/// includes it.
+pub fn value_for(ranges: &[(u32, u32, u8)], c: char, default: u8) -> u8 {
+    let c = c as u32;
+    let i = ranges.partition_point(|r| r.1 < c);
+    match ranges.get(i) {
+        Some(r) if r.0 <= c => r.2,
+        _ => default,
+    }
+}
+
+/// Returns the runs for the given sorted ranges, merging neighbouring
+/// ranges with the same value and filling the gaps between them with runs
+/// of `default`.
+pub fn runs(ranges: &[(u32, u32, u8)], default: u8) -> Vec<u32> {
+    let mut ret: Vec<u32> = Vec::new();
+    let mut push = |start: u32, value: u8| {
+        if ret.last().is_none_or(|&run| run as u8 != value) {
+            ret.push(start << 8 | value as u32);
+        }
+    };
+    let mut next = 0;
+    for &(start, end, value) in ranges {
+        if start > next {
+            push(next, default);
+        }
+        push(start, value);
+        next = end + 1;
+    }
+    if next <= char::MAX as u32 {
+        push(next, default);
+    }
+    ret
+}
+
+/// Returns the runs for the given table values of every code point in
+/// order, merging neighbouring code points with the same value.
+pub fn value_runs(values: &[u8]) -> Vec<u32> {
+    let mut ret: Vec<u32> = Vec::new();
+    for (c, &value) in values.iter().enumerate() {
+        if ret.last().is_none_or(|&run| run as u8 != value) {
+            ret.push((c as u32) << 8 | value as u32);
+        }
+    }
+    ret
+}
+
+/// Returns the table value for the given character from a list of runs,
+/// following the same steps as the lookup functions in the main crate.
+pub fn lookup_runs(runs: &[u32], c: char) -> u8 {
+    let i = runs.partition_point(|&run| run >> 8 <= c as u32);
+    runs[i - 1] as u8
+}
+
+/// Appends the source code for a static `u32` array with the given name,
+/// such as a list of runs.
+pub fn render_u32s(out: &mut String, name: &str, values: &[u32]) {
+    out.push_str(ARRAY_ATTRS);
+    writeln!(out, "static {name}: [u32; {}] = [", values.len()).unwrap();
+    // This is the same layout that rustfmt chooses, so that formatting the
+    // generated file doesn't change it.
+    for row in values.chunks(8) {
+        let row: Vec<String> = row.iter().map(|v| format!("{v:#010x}")).collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    out.push_str("];\n");
+}
+
+/// Appends the source code for a static `u8` array with the given name, in
+/// the same layout as [`render_u32s`].
+pub fn render_u8s(out: &mut String, name: &str, values: &[u8]) {
+    out.push_str(ARRAY_ATTRS);
+    writeln!(out, "static {name}: [u8; {}] = [", values.len()).unwrap();
+    for row in values.chunks(16) {
+        let row: Vec<String> = row.iter().map(|v| format!("{v:#04x}")).collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    out.push_str("];\n");
+}
+
+/// Parses the static array of runs with the given name from source code
+/// previously written by [`render_u32s`], returning the offset where it
+/// begins along with the runs.
+pub fn parse_runs(src: &str, name: &str) -> Result<(usize, Vec<u32>), String> {
+    let start = src
+        .find(&format!("{ARRAY_ATTRS}static {name}"))
+        .ok_or_else(|| format!("no {name} array"))?;
+    let body = src[start..]
+        .split_once("= [")
+        .and_then(|(_, rest)| rest.split_once("];"))
+        .ok_or_else(|| format!("malformed {name} array"))?
+        .0;
+    let mut runs = Vec::new();
+    for item in body.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let hex = item
+            .strip_prefix("0x")
+            .ok_or_else(|| format!("invalid item {item:?} in {name}"))?;
+        let v = u32::from_str_radix(hex, 16)
+            .map_err(|err| format!("invalid item {item:?} in {name}: {err}"))?;
+        runs.push(v);
+    }
+    Ok((start, runs))
+}
@@ -0,0 +1,90 @@
+//! Generates the **East_Asian_Width** property table in `src/width/table.rs`
+//! from the `EastAsianWidth.txt` file of the Unicode character database.
+//!
+//! This uses the same sorted list of runs as the **Age** table, with the
+//! property value of all of the characters up to the start of the next run
+//! in the low eight bits of each run, encoded as by [`encode`].
+
+use crate::ucd;
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "EAW_RUNS";
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// Parses the content of `EastAsianWidth.txt`, returning the encoded
+/// property value of every code point in order.
+///
+/// The file lists each assigned character or range of characters with its
+/// value, like `3400..4DBF;W  # Lo [6592] CJK UNIFIED IDEOGRAPH-3400..`.
+/// Characters that aren't listed take their value from the last
+/// `@missing` line whose range includes them, or **Neutral** if there is
+/// none.
+pub fn parse(src: &str) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    let missing = ucd::missing_lines(src);
+    for lines in [ucd::parse_lines(&missing)?, ucd::parse_lines(src)?] {
+        for line in lines {
+            let value = encode(line.value).map_err(|msg| line.error(&msg))?;
+            values[line.start as usize..=line.end as usize].fill(value);
+        }
+    }
+    Ok(values)
+}
+
+/// Returns the table value for a short **East_Asian_Width** property
+/// value alias, in the same order as the `EastAsianWidth` enum in the main
+/// crate.
+pub fn encode(value: &str) -> Result<u8, String> {
+    Ok(match value {
+        "N" => 0,
+        "A" => 1,
+        "H" => 2,
+        "Na" => 3,
+        "W" => 4,
+        "F" => 5,
+        _ => return Err(format!("invalid East_Asian_Width {value:?}")),
+    })
+}
+
+/// The generated width table, along with the handwritten source code that
+/// appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `eaw_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parse_east_asian_width() {
+    let src = "\
+# EastAsianWidth-16.0.0.txt
+
+# @missing: 0000..10FFFF; N
+# @missing: 3400..4DBF; W
+
+0020..007E     ; Na # [95] SPACE..TILDE
+00A1           ; A  #      INVERTED EXCLAMATION MARK
+3400..3402     ; W  #  [3] CJK UNIFIED IDEOGRAPH-3400..
+FF01           ; F  #      FULLWIDTH EXCLAMATION MARK
+FF61           ; H  #      HALFWIDTH IDEOGRAPHIC FULL STOP
+";
+    let got = parse(src).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x1f], 0);
+    assert_eq!(got[0x41], 3);
+    assert_eq!(got[0xa1], 1);
+    assert_eq!(got[0x3401], 4);
+    // Unlisted characters take the value of the last @missing line.
+    assert_eq!(got[0x4dbf], 4);
+    assert_eq!(got[0x4dc0], 0);
+    assert_eq!(got[0xff01], 5);
+    assert_eq!(got[0xff61], 2);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("0041 Na").unwrap_err(), "line 1: missing ';'");
+    assert_eq!(
+        parse("\n0041 ; X").unwrap_err(),
+        "line 2: invalid East_Asian_Width \"X\""
+    );
+    assert_eq!(
+        parse("# @missing: 0000..10FFFF; Q").unwrap_err(),
+        "line 1: invalid East_Asian_Width \"Q\""
+    );
+}
+
+#[test]
+fn build_runs() {
+    let values = parse("0041..005A ; Na\n005B ; Na\n1F600 ; W\n").unwrap();
+    let table = Table {
+        preamble: String::new(),
+        runs: ucd::value_runs(&values),
+    };
+    assert_eq!(
+        table.runs,
+        &[
+            run(0x0000, 0),
+            run(0x0041, 3),
+            run(0x005c, 0),
+            run(0x1f600, 4),
+            run(0x1f601, 0),
+        ]
+    );
+    assert_eq!(table.lookup('\0'), 0);
+    assert_eq!(table.lookup('['), 3);
+    assert_eq!(table.lookup('\u{1F600}'), 4);
+    assert_eq!(table.lookup(char::MAX), 0);
+}
+
+#[test]
+fn render_round_trip() {
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: vec![run(0x0000, 0), run(0x0041, 3), run(0x10ffff, 0)],
+    };
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}
+
+fn run(start: u32, value: u8) -> u32 {
+    start << 8 | value as u32
+}
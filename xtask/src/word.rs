@@ -0,0 +1,100 @@
+//! Generates the **Word_Break** property table in `src/word/table.rs` from
+//! the `WordBreakProperty.txt` file of the Unicode character database.
+//!
+//! This uses the same sorted list of runs as the **Age** table, with the
+//! property value of all of the characters up to the start of the next run
+//! in the low eight bits of each run, encoded as by [`encode`].
+
+use crate::ucd;
+
+/// The name of the array holding the runs in the generated source.
+const RUNS_NAME: &str = "WORD_BREAK_RUNS";
+
+/// The number of code points, and so the length of the result of [`parse`].
+const CODE_POINTS: usize = char::MAX as usize + 1;
+
+/// Parses the content of `WordBreakProperty.txt`, returning the encoded
+/// property value of every code point in order.
+///
+/// The file lists each character or range of characters with its value,
+/// like `0041..005A ; ALetter # L&  [26] LATIN CAPITAL LETTER A..`, and
+/// characters that aren't listed have the value **Other**.
+pub fn parse(src: &str) -> Result<Vec<u8>, String> {
+    let mut values = vec![0; CODE_POINTS];
+    for line in ucd::parse_lines(src)? {
+        let value = encode(line.value).map_err(|msg| line.error(&msg))?;
+        values[line.start as usize..=line.end as usize].fill(value);
+    }
+    Ok(values)
+}
+
+/// Returns the table value for a long **Word_Break** property value alias,
+/// in the same order as the `WBProperty` enum in the main crate.
+pub fn encode(value: &str) -> Result<u8, String> {
+    const ALIASES: [&str; 19] = [
+        "Other",
+        "CR",
+        "LF",
+        "Newline",
+        "Extend",
+        "ZWJ",
+        "Regional_Indicator",
+        "Format",
+        "Katakana",
+        "Hebrew_Letter",
+        "ALetter",
+        "Single_Quote",
+        "Double_Quote",
+        "MidNumLet",
+        "MidLetter",
+        "MidNum",
+        "Numeric",
+        "ExtendNumLet",
+        "WSegSpace",
+    ];
+    match ALIASES.iter().position(|&alias| alias == value) {
+        Some(i) => Ok(i as u8),
+        None => Err(format!("invalid Word_Break {value:?}")),
+    }
+}
+
+/// The generated word break table, along with the handwritten source code that
+/// appears before the array in the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub preamble: String,
+    pub runs: Vec<u32>,
+}
+
+impl Table {
+    /// Parses the content of a `table.rs` file previously written by
+    /// [`Table::render`].
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let (start, runs) = ucd::parse_runs(src, RUNS_NAME)?;
+        Ok(Self {
+            preamble: src[..start].to_string(),
+            runs,
+        })
+    }
+
+    /// Renders the table as the content of a `table.rs` file.
+    pub fn render(&self) -> String {
+        let mut ret = self.preamble.clone();
+        ucd::render_u32s(&mut ret, RUNS_NAME, &self.runs);
+        ret
+    }
+
+    /// Returns the table value for the given character, following the same
+    /// steps as `word_break_lookup` in the main crate.
+    pub fn lookup(&self, c: char) -> u8 {
+        ucd::lookup_runs(&self.runs, c)
+    }
+
+    /// Returns the size of the array in bytes.
+    pub fn size(&self) -> usize {
+        self.runs.len() * 4
+    }
+}
+
+#[cfg(test)]
+mod tests;
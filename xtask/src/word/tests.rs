@@ -0,0 +1,48 @@
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn parse_word_break() {
+    let src = "\
+# WordBreakProperty-16.0.0.txt
+
+# @missing: 0000..10FFFF; Other
+
+000D          ; CR # Cc       <control-000D>
+0027          ; Single_Quote # Po       APOSTROPHE
+0041..005A    ; ALetter # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+";
+    let got = parse(src).unwrap();
+    assert_eq!(got.len(), 0x110000);
+    assert_eq!(got[0x0c], 0);
+    assert_eq!(got[0x0d], 1);
+    assert_eq!(got[0x27], 11);
+    assert_eq!(got[0x41], 10);
+    assert_eq!(got[0x5a], 10);
+    assert_eq!(got[0x5b], 0);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse("0041 ALetter").unwrap_err(), "line 1: missing ';'");
+    assert_eq!(
+        parse("\n0041 ; Letter").unwrap_err(),
+        "line 2: invalid Word_Break \"Letter\""
+    );
+}
+
+#[test]
+fn render_round_trip() {
+    let values = parse("0030..0039 ; Numeric\n0041 ; ALetter\n").unwrap();
+    let table = Table {
+        preamble: "// preamble\n".to_string(),
+        runs: ucd::value_runs(&values),
+    };
+    assert_eq!(table.runs, &[0x0000, 0x3010, 0x3a00, 0x410a, 0x4200]);
+    assert_eq!(table.lookup('5'), 16);
+    assert_eq!(table.lookup('A'), 10);
+    assert_eq!(table.lookup(char::MAX), 0);
+    let src = table.render();
+    assert_eq!(Table::parse(&src).unwrap(), table);
+}